@@ -0,0 +1,81 @@
+use super::AsyncNanonisClient;
+use crate::atom_track::{commands, ATControl, AtomTrackProps, QuickCompType};
+use crate::error::NanonisError;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::atom_track_ctrl_set`](crate::NanonisClient::atom_track_ctrl_set).
+    pub async fn atom_track_ctrl_set(
+        &mut self,
+        control: ATControl,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        let status = if enabled { 1u16 } else { 0u16 };
+        self.send(&commands::AtomTrackCtrlSet {
+            control: control.into(),
+            enabled: status,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::atom_track_status_get`](crate::NanonisClient::atom_track_status_get).
+    pub async fn atom_track_status_get(
+        &mut self,
+        control: ATControl,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::AtomTrackStatusGet {
+                control: control.into(),
+            })
+            .await?;
+
+        Ok(response.status != 0)
+    }
+
+    /// Async version of [`NanonisClient::atom_track_props_set`](crate::NanonisClient::atom_track_props_set).
+    pub async fn atom_track_props_set(
+        &mut self,
+        props: &AtomTrackProps,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::AtomTrackPropsSet {
+            integral_gain: props.integral_gain,
+            frequency_hz: props.frequency_hz,
+            amplitude_m: props.amplitude_m,
+            phase_deg: props.phase_deg,
+            switch_off_delay_s: props.switch_off_delay_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::atom_track_props_get`](crate::NanonisClient::atom_track_props_get).
+    pub async fn atom_track_props_get(&mut self) -> Result<AtomTrackProps, NanonisError> {
+        let response = self.send(&commands::AtomTrackPropsGet {}).await?;
+
+        Ok(AtomTrackProps {
+            integral_gain: response.integral_gain,
+            frequency_hz: response.frequency_hz,
+            amplitude_m: response.amplitude_m,
+            phase_deg: response.phase_deg,
+            switch_off_delay_s: response.switch_off_delay_s,
+        })
+    }
+
+    /// Async version of [`NanonisClient::atom_track_quick_comp_start`](crate::NanonisClient::atom_track_quick_comp_start).
+    pub async fn atom_track_quick_comp_start(
+        &mut self,
+        comp_type: QuickCompType,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::AtomTrackQuickCompStart {
+            comp_type: comp_type.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::atom_track_drift_comp`](crate::NanonisClient::atom_track_drift_comp).
+    pub async fn atom_track_drift_comp(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::AtomTrackDriftComp {}).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use super::AsyncNanonisClient;
+use crate::auto_approach::commands;
+use crate::error::NanonisError;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::auto_approach_open`](crate::NanonisClient::auto_approach_open).
+    pub async fn auto_approach_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::AutoApproachOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::auto_approach_on_off_set`](crate::NanonisClient::auto_approach_on_off_set).
+    pub async fn auto_approach_on_off_set(&mut self, on_off: bool) -> Result<(), NanonisError> {
+        let value = if on_off { 1u16 } else { 0u16 };
+        self.send(&commands::AutoApproachOnOffSet { on_off: value })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::auto_approach_on_off_get`](crate::NanonisClient::auto_approach_on_off_get).
+    pub async fn auto_approach_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::AutoApproachOnOffGet {}).await?;
+        let status = response.on_off;
+        Ok(status == 1)
+    }
+}
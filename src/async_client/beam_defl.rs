@@ -0,0 +1,98 @@
+use super::AsyncNanonisClient;
+use crate::beam_defl::{commands, BeamDeflConfig, DeflectionSignal};
+use crate::error::NanonisError;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::beam_defl_hor_config_set`](crate::NanonisClient::beam_defl_hor_config_set).
+    pub async fn beam_defl_hor_config_set(
+        &mut self,
+        config: &BeamDeflConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BeamDeflHorConfigSet {
+            name: config.name.clone(),
+            units: config.units.clone(),
+            calibration: config.calibration,
+            offset: config.offset,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::beam_defl_hor_config_get`](crate::NanonisClient::beam_defl_hor_config_get).
+    pub async fn beam_defl_hor_config_get(&mut self) -> Result<BeamDeflConfig, NanonisError> {
+        let response = self.send(&commands::BeamDeflHorConfigGet {}).await?;
+
+        Ok(BeamDeflConfig {
+            name: response.name,
+            units: response.units,
+            calibration: response.calibration,
+            offset: response.offset,
+        })
+    }
+
+    /// Async version of [`NanonisClient::beam_defl_ver_config_set`](crate::NanonisClient::beam_defl_ver_config_set).
+    pub async fn beam_defl_ver_config_set(
+        &mut self,
+        config: &BeamDeflConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BeamDeflVerConfigSet {
+            name: config.name.clone(),
+            units: config.units.clone(),
+            calibration: config.calibration,
+            offset: config.offset,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::beam_defl_ver_config_get`](crate::NanonisClient::beam_defl_ver_config_get).
+    pub async fn beam_defl_ver_config_get(&mut self) -> Result<BeamDeflConfig, NanonisError> {
+        let response = self.send(&commands::BeamDeflVerConfigGet {}).await?;
+
+        Ok(BeamDeflConfig {
+            name: response.name,
+            units: response.units,
+            calibration: response.calibration,
+            offset: response.offset,
+        })
+    }
+
+    /// Async version of [`NanonisClient::beam_defl_int_config_set`](crate::NanonisClient::beam_defl_int_config_set).
+    pub async fn beam_defl_int_config_set(
+        &mut self,
+        config: &BeamDeflConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BeamDeflIntConfigSet {
+            name: config.name.clone(),
+            units: config.units.clone(),
+            calibration: config.calibration,
+            offset: config.offset,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::beam_defl_int_config_get`](crate::NanonisClient::beam_defl_int_config_get).
+    pub async fn beam_defl_int_config_get(&mut self) -> Result<BeamDeflConfig, NanonisError> {
+        let response = self.send(&commands::BeamDeflIntConfigGet {}).await?;
+
+        Ok(BeamDeflConfig {
+            name: response.name,
+            units: response.units,
+            calibration: response.calibration,
+            offset: response.offset,
+        })
+    }
+
+    /// Async version of [`NanonisClient::beam_defl_auto_offset`](crate::NanonisClient::beam_defl_auto_offset).
+    pub async fn beam_defl_auto_offset(
+        &mut self,
+        signal: DeflectionSignal,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BeamDeflAutoOffset {
+            signal: signal.into(),
+        })
+        .await?;
+        Ok(())
+    }
+}
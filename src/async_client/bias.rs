@@ -0,0 +1,71 @@
+use super::AsyncNanonisClient;
+use crate::bias::commands;
+use crate::error::NanonisError;
+use crate::units::{Seconds, Volts};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::bias_set`](crate::NanonisClient::bias_set).
+    pub async fn bias_set(&mut self, voltage: impl Into<Volts>) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSet {
+            voltage: voltage.into().as_f32(),
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::bias_get`](crate::NanonisClient::bias_get).
+    pub async fn bias_get(&mut self) -> Result<f32, NanonisError> {
+        Ok(self.send(&commands::BiasGet {}).await?.voltage)
+    }
+
+    /// Async version of [`NanonisClient::bias_range_set`](crate::NanonisClient::bias_range_set).
+    pub async fn bias_range_set(&mut self, bias_range_index: u16) -> Result<(), NanonisError> {
+        self.send(&commands::BiasRangeSet {
+            range_index: bias_range_index,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::bias_range_get`](crate::NanonisClient::bias_range_get).
+    pub async fn bias_range_get(&mut self) -> Result<(Vec<String>, u16), NanonisError> {
+        let response = self.send(&commands::BiasRangeGet {}).await?;
+        Ok((response.ranges, response.range_index))
+    }
+
+    /// Async version of [`NanonisClient::bias_calibr_set`](crate::NanonisClient::bias_calibr_set).
+    pub async fn bias_calibr_set(
+        &mut self,
+        calibration: f32,
+        offset: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasCalibrSet {
+            calibration,
+            offset,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::bias_calibr_get`](crate::NanonisClient::bias_calibr_get).
+    pub async fn bias_calibr_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let response = self.send(&commands::BiasCalibrGet {}).await?;
+        Ok((response.calibration, response.offset))
+    }
+
+    /// Async version of [`NanonisClient::bias_pulse`](crate::NanonisClient::bias_pulse).
+    pub async fn bias_pulse(
+        &mut self,
+        wait_until_done: bool,
+        pulse_width_s: impl Into<Seconds>,
+        bias_value_v: impl Into<Volts>,
+        z_controller_hold: u16,
+        pulse_mode: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasPulse {
+            wait_until_done: wait_until_done as u32,
+            pulse_width_s: pulse_width_s.into().as_f32(),
+            bias_value_v: bias_value_v.into().as_f32(),
+            z_controller_hold,
+            pulse_mode,
+        })
+        .await
+    }
+}
@@ -0,0 +1,424 @@
+use super::AsyncNanonisClient;
+use crate::bias_spectr::{
+    commands, AltZCtrlConfig, BiasSpectrAdvProps, BiasSpectrProps, BiasSpectrPropsBuilder,
+    BiasSpectrResult, BiasSpectrTiming, DigitalSync, MLSSegment, OptionalFlag, PulseSeqSyncConfig,
+    SweepMode, TTLLine, TTLPolarity, TTLSyncConfig,
+};
+use crate::error::NanonisError;
+use crate::signals::SlotRef;
+use std::time::Duration;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::bias_spectr_open`](crate::NanonisClient::bias_spectr_open).
+    pub async fn bias_spectr_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_start`](crate::NanonisClient::bias_spectr_start).
+    pub async fn bias_spectr_start(
+        &mut self,
+        get_data: bool,
+        save_base_name: &str,
+    ) -> Result<BiasSpectrResult, NanonisError> {
+        let get_data_flag = if get_data { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::BiasSpectrStart {
+                get_data: get_data_flag,
+                save_base_name: save_base_name.to_string(),
+            })
+            .await?;
+
+        let channel_names = response.channel_names;
+        let parameters = response.parameters;
+        let data_2d = response.data_2d;
+
+        Ok(BiasSpectrResult {
+            channel_names,
+            data: data_2d,
+            parameters,
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_stop`](crate::NanonisClient::bias_spectr_stop).
+    pub async fn bias_spectr_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_status_get`](crate::NanonisClient::bias_spectr_status_get).
+    pub async fn bias_spectr_status_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::BiasSpectrStatusGet {}).await?;
+        Ok(response.running != 0)
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_chs_set`](crate::NanonisClient::bias_spectr_chs_set).
+    pub async fn bias_spectr_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let mut channel_indexes = Vec::new();
+        for channel in channels {
+            channel_indexes.push(self.resolve_slot(channel).await?);
+        }
+        self.send(&commands::BiasSpectrChsSet { channel_indexes })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_chs_get`](crate::NanonisClient::bias_spectr_chs_get).
+    pub async fn bias_spectr_chs_get(&mut self) -> Result<(Vec<i32>, Vec<String>), NanonisError> {
+        let response = self.send(&commands::BiasSpectrChsGet {}).await?;
+
+        Ok((response.indexes, response.names))
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_props_set`](crate::NanonisClient::bias_spectr_props_set).
+    pub async fn bias_spectr_props_set(
+        &mut self,
+        config: BiasSpectrPropsBuilder,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrPropsSet {
+            save_all: config.save_all.into(),
+            num_sweeps: config.num_sweeps,
+            backward_sweep: config.backward_sweep.into(),
+            num_points: config.num_points,
+            z_offset_m: config.z_offset_m,
+            autosave: config.autosave.into(),
+            show_save_dialog: config.show_save_dialog.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_props_get`](crate::NanonisClient::bias_spectr_props_get).
+    pub async fn bias_spectr_props_get(&mut self) -> Result<BiasSpectrProps, NanonisError> {
+        let response = self.send(&commands::BiasSpectrPropsGet {}).await?;
+
+        Ok(BiasSpectrProps {
+            save_all: response.save_all != 0,
+            num_sweeps: response.num_sweeps,
+            backward_sweep: response.backward_sweep != 0,
+            num_points: response.num_points,
+            channels: response.channels,
+            parameters: response.parameters,
+            fixed_parameters: response.fixed_parameters,
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_adv_props_set`](crate::NanonisClient::bias_spectr_adv_props_set).
+    pub async fn bias_spectr_adv_props_set(
+        &mut self,
+        reset_bias: OptionalFlag,
+        z_controller_hold: OptionalFlag,
+        record_final_z: OptionalFlag,
+        lockin_run: OptionalFlag,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrAdvPropsSet {
+            reset_bias: reset_bias.into(),
+            z_controller_hold: z_controller_hold.into(),
+            record_final_z: record_final_z.into(),
+            lockin_run: lockin_run.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_adv_props_get`](crate::NanonisClient::bias_spectr_adv_props_get).
+    pub async fn bias_spectr_adv_props_get(&mut self) -> Result<BiasSpectrAdvProps, NanonisError> {
+        let response = self.send(&commands::BiasSpectrAdvPropsGet {}).await?;
+
+        Ok(BiasSpectrAdvProps {
+            reset_bias: response.reset_bias != 0,
+            z_controller_hold: response.z_controller_hold != 0,
+            record_final_z: response.record_final_z != 0,
+            lockin_run: response.lockin_run != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_limits_set`](crate::NanonisClient::bias_spectr_limits_set).
+    pub async fn bias_spectr_limits_set(
+        &mut self,
+        start_value_v: f32,
+        end_value_v: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrLimitsSet {
+            start_value_v,
+            end_value_v,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_limits_get`](crate::NanonisClient::bias_spectr_limits_get).
+    pub async fn bias_spectr_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let response = self.send(&commands::BiasSpectrLimitsGet {}).await?;
+
+        Ok((response.start_value_v, response.end_value_v))
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_timing_set`](crate::NanonisClient::bias_spectr_timing_set).
+    pub async fn bias_spectr_timing_set(
+        &mut self,
+        timing: &BiasSpectrTiming,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrTimingSet {
+            z_averaging_time: timing.z_averaging_time.as_secs_f32(),
+            z_offset_m: timing.z_offset_m,
+            initial_settling_time: timing.initial_settling_time.as_secs_f32(),
+            max_slew_rate: timing.max_slew_rate,
+            settling_time: timing.settling_time.as_secs_f32(),
+            integration_time: timing.integration_time.as_secs_f32(),
+            end_settling_time: timing.end_settling_time.as_secs_f32(),
+            z_control_time: timing.z_control_time.as_secs_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_timing_get`](crate::NanonisClient::bias_spectr_timing_get).
+    pub async fn bias_spectr_timing_get(&mut self) -> Result<BiasSpectrTiming, NanonisError> {
+        let response = self.send(&commands::BiasSpectrTimingGet {}).await?;
+
+        Ok(BiasSpectrTiming {
+            z_averaging_time: Duration::from_secs_f32(response.z_averaging_time),
+            z_offset_m: response.z_offset_m,
+            initial_settling_time: Duration::from_secs_f32(response.initial_settling_time),
+            max_slew_rate: response.max_slew_rate,
+            settling_time: Duration::from_secs_f32(response.settling_time),
+            integration_time: Duration::from_secs_f32(response.integration_time),
+            end_settling_time: Duration::from_secs_f32(response.end_settling_time),
+            z_control_time: Duration::from_secs_f32(response.z_control_time),
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_dig_sync_set`](crate::NanonisClient::bias_spectr_dig_sync_set).
+    pub async fn bias_spectr_dig_sync_set(
+        &mut self,
+        mode: DigitalSync,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrDigSyncSet { mode: mode.into() })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_dig_sync_get`](crate::NanonisClient::bias_spectr_dig_sync_get).
+    pub async fn bias_spectr_dig_sync_get(&mut self) -> Result<DigitalSync, NanonisError> {
+        let response = self.send(&commands::BiasSpectrDigSyncGet {}).await?;
+
+        DigitalSync::try_from(response.mode)
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_ttl_sync_set`](crate::NanonisClient::bias_spectr_ttl_sync_set).
+    pub async fn bias_spectr_ttl_sync_set(
+        &mut self,
+        config: &TTLSyncConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrTtlSyncSet {
+            line: config.line.into(),
+            polarity: config.polarity.into(),
+            time_to_on: config.time_to_on.as_secs_f32(),
+            on_duration: config.on_duration.as_secs_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_ttl_sync_get`](crate::NanonisClient::bias_spectr_ttl_sync_get).
+    pub async fn bias_spectr_ttl_sync_get(&mut self) -> Result<TTLSyncConfig, NanonisError> {
+        let response = self.send(&commands::BiasSpectrTtlSyncGet {}).await?;
+
+        Ok(TTLSyncConfig {
+            line: TTLLine::try_from(response.line)?,
+            polarity: TTLPolarity::try_from(response.polarity)?,
+            time_to_on: Duration::from_secs_f32(response.time_to_on),
+            on_duration: Duration::from_secs_f32(response.on_duration),
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_pulse_seq_sync_set`](crate::NanonisClient::bias_spectr_pulse_seq_sync_set).
+    pub async fn bias_spectr_pulse_seq_sync_set(
+        &mut self,
+        config: &PulseSeqSyncConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrPulseSeqSyncSet {
+            sequence_nr: config.sequence_nr,
+            num_periods: config.num_periods,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_pulse_seq_sync_get`](crate::NanonisClient::bias_spectr_pulse_seq_sync_get).
+    pub async fn bias_spectr_pulse_seq_sync_get(
+        &mut self,
+    ) -> Result<PulseSeqSyncConfig, NanonisError> {
+        let response = self.send(&commands::BiasSpectrPulseSeqSyncGet {}).await?;
+
+        Ok(PulseSeqSyncConfig {
+            sequence_nr: response.sequence_nr,
+            num_periods: response.num_periods,
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_alt_z_ctrl_set`](crate::NanonisClient::bias_spectr_alt_z_ctrl_set).
+    pub async fn bias_spectr_alt_z_ctrl_set(
+        &mut self,
+        config: &AltZCtrlConfig,
+    ) -> Result<(), NanonisError> {
+        let enabled_flag = if config.enabled {
+            OptionalFlag::On
+        } else {
+            OptionalFlag::Off
+        };
+
+        self.send(&commands::BiasSpectrAltZCtrlSet {
+            enabled_flag: enabled_flag.into(),
+            setpoint: config.setpoint,
+            settling_time: config.settling_time.as_secs_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_alt_z_ctrl_get`](crate::NanonisClient::bias_spectr_alt_z_ctrl_get).
+    pub async fn bias_spectr_alt_z_ctrl_get(&mut self) -> Result<AltZCtrlConfig, NanonisError> {
+        let response = self.send(&commands::BiasSpectrAltZCtrlGet {}).await?;
+
+        Ok(AltZCtrlConfig {
+            enabled: response.enabled != 0,
+            setpoint: response.setpoint,
+            settling_time: Duration::from_secs_f32(response.settling_time),
+        })
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_z_off_revert_set`](crate::NanonisClient::bias_spectr_z_off_revert_set).
+    pub async fn bias_spectr_z_off_revert_set(
+        &mut self,
+        revert: OptionalFlag,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSpectrZOffRevertSet {
+            revert: revert.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_z_off_revert_get`](crate::NanonisClient::bias_spectr_z_off_revert_get).
+    pub async fn bias_spectr_z_off_revert_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::BiasSpectrZOffRevertGet {}).await?;
+
+        Ok(response.revert != 0)
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_mls_lockin_per_seg_set`](crate::NanonisClient::bias_spectr_mls_lockin_per_seg_set).
+    pub async fn bias_spectr_mls_lockin_per_seg_set(
+        &mut self,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::BiasSpectrMlsLockinPerSegSet { enabled: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_mls_lockin_per_seg_get`](crate::NanonisClient::bias_spectr_mls_lockin_per_seg_get).
+    pub async fn bias_spectr_mls_lockin_per_seg_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::BiasSpectrMlsLockinPerSegGet {})
+            .await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_mls_mode_set`](crate::NanonisClient::bias_spectr_mls_mode_set).
+    pub async fn bias_spectr_mls_mode_set(&mut self, mode: SweepMode) -> Result<(), NanonisError> {
+        let mode_str: &str = mode.into();
+        self.send(&commands::BiasSpectrMlsModeSet {
+            mode_str: mode_str.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_mls_mode_get`](crate::NanonisClient::bias_spectr_mls_mode_get).
+    pub async fn bias_spectr_mls_mode_get(&mut self) -> Result<SweepMode, NanonisError> {
+        let response = self.send(&commands::BiasSpectrMlsModeGet {}).await?;
+
+        let modes = response.modes;
+        if let Some(mode_str) = modes.first() {
+            SweepMode::try_from(mode_str.as_str())
+        } else {
+            Ok(SweepMode::Linear)
+        }
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_mls_vals_set`](crate::NanonisClient::bias_spectr_mls_vals_set).
+    pub async fn bias_spectr_mls_vals_set(
+        &mut self,
+        segments: &[MLSSegment],
+    ) -> Result<(), NanonisError> {
+        let num_segments = segments.len() as i32;
+        let bias_start: Vec<f32> = segments.iter().map(|s| s.bias_start).collect();
+        let bias_end: Vec<f32> = segments.iter().map(|s| s.bias_end).collect();
+        let initial_settling: Vec<f32> = segments
+            .iter()
+            .map(|s| s.initial_settling_time.as_secs_f32())
+            .collect();
+        let settling: Vec<f32> = segments
+            .iter()
+            .map(|s| s.settling_time.as_secs_f32())
+            .collect();
+        let integration: Vec<f32> = segments
+            .iter()
+            .map(|s| s.integration_time.as_secs_f32())
+            .collect();
+        let slew_rate: Vec<f32> = segments.iter().map(|s| s.max_slew_rate).collect();
+        let steps: Vec<i32> = segments.iter().map(|s| s.steps).collect();
+
+        self.send(&commands::BiasSpectrMlsValsSet {
+            num_segments,
+            bias_start,
+            bias_end,
+            initial_settling,
+            settling,
+            integration,
+            slew_rate,
+            steps,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_spectr_mls_vals_get`](crate::NanonisClient::bias_spectr_mls_vals_get).
+    pub async fn bias_spectr_mls_vals_get(&mut self) -> Result<Vec<MLSSegment>, NanonisError> {
+        let response = self.send(&commands::BiasSpectrMlsValsGet {}).await?;
+
+        let num_segments = response.num_segments as usize;
+        let bias_start = response.bias_start;
+        let bias_end = response.bias_end;
+        let initial_settling = response.initial_settling;
+        let settling = response.settling;
+        let integration = response.integration;
+        let slew_rate = response.slew_rate;
+        let steps = response.steps;
+
+        let mut segments = Vec::with_capacity(num_segments);
+        for i in 0..num_segments {
+            segments.push(MLSSegment {
+                bias_start: *bias_start.get(i).unwrap_or(&0.0),
+                bias_end: *bias_end.get(i).unwrap_or(&0.0),
+                initial_settling_time: Duration::from_secs_f32(
+                    *initial_settling.get(i).unwrap_or(&0.0),
+                ),
+                settling_time: Duration::from_secs_f32(*settling.get(i).unwrap_or(&0.0)),
+                integration_time: Duration::from_secs_f32(*integration.get(i).unwrap_or(&0.0)),
+                max_slew_rate: *slew_rate.get(i).unwrap_or(&1.0),
+                steps: *steps.get(i).unwrap_or(&100),
+            });
+        }
+
+        Ok(segments)
+    }
+}
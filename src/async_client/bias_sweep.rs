@@ -0,0 +1,92 @@
+use super::AsyncNanonisClient;
+use crate::bias_sweep::commands;
+use crate::error::NanonisError;
+use crate::types::Array2D;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::bias_sweep_open`](crate::NanonisClient::bias_sweep_open).
+    pub async fn bias_sweep_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSwpOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_sweep_start`](crate::NanonisClient::bias_sweep_start).
+    pub async fn bias_sweep_start(
+        &mut self,
+        get_data: bool,
+        sweep_direction: bool,
+        z_controller_status: u32,
+        save_base_name: &str,
+        reset_bias: bool,
+    ) -> Result<(Vec<String>, Array2D<f32>), NanonisError> {
+        let get_data_flag = if get_data { 1u32 } else { 0u32 };
+        let direction_flag = if sweep_direction { 1u32 } else { 0u32 };
+        let reset_flag = if reset_bias { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::BiasSwpStart {
+                get_data: get_data_flag,
+                sweep_direction: direction_flag,
+                z_controller_status,
+                save_base_name: save_base_name.to_string(),
+                reset_bias: reset_flag,
+            })
+            .await?;
+
+        let channel_names = response.channel_names;
+        let data_2d = response.data_2d;
+
+        Ok((channel_names, data_2d))
+    }
+
+    /// Async version of [`NanonisClient::bias_sweep_props_set`](crate::NanonisClient::bias_sweep_props_set).
+    pub async fn bias_sweep_props_set(
+        &mut self,
+        number_of_steps: u16,
+        period_ms: u16,
+        autosave: u16,
+        save_dialog_box: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSwpPropsSet {
+            number_of_steps,
+            period_ms,
+            autosave,
+            save_dialog_box,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_sweep_limits_set`](crate::NanonisClient::bias_sweep_limits_set).
+    pub async fn bias_sweep_limits_set(
+        &mut self,
+        lower_limit: f32,
+        upper_limit: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSwpLimitsSet {
+            lower_limit,
+            upper_limit,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::bias_sweep_limits_get`](crate::NanonisClient::bias_sweep_limits_get).
+    pub async fn bias_sweep_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let response = self.send(&commands::BiasSwpLimitsGet {}).await?;
+
+        Ok((response.lower_limit, response.upper_limit))
+    }
+
+    /// Async version of [`NanonisClient::bias_sweep_props_get`](crate::NanonisClient::bias_sweep_props_get).
+    pub async fn bias_sweep_props_get(&mut self) -> Result<(u16, u16, bool, bool), NanonisError> {
+        let response = self.send(&commands::BiasSwpPropsGet {}).await?;
+
+        Ok((
+            response.num_steps,
+            response.period_ms,
+            response.autosave != 0,
+            response.save_dialog != 0,
+        ))
+    }
+}
@@ -0,0 +1,73 @@
+use super::AsyncNanonisClient;
+use crate::cpd_comp::{commands, CPDCompData, CPDCompParams, CPDFitCoefficients, CPDSweepData};
+use crate::error::NanonisError;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::cpd_comp_open`](crate::NanonisClient::cpd_comp_open).
+    pub async fn cpd_comp_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::CpdCompOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::cpd_comp_close`](crate::NanonisClient::cpd_comp_close).
+    pub async fn cpd_comp_close(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::CpdCompClose {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::cpd_comp_params_set`](crate::NanonisClient::cpd_comp_params_set).
+    pub async fn cpd_comp_params_set(
+        &mut self,
+        params: &CPDCompParams,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::CpdCompParamsSet {
+            speed_hz: params.speed_hz,
+            range_v: params.range_v,
+            averaging: params.averaging,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::cpd_comp_params_get`](crate::NanonisClient::cpd_comp_params_get).
+    pub async fn cpd_comp_params_get(&mut self) -> Result<CPDCompParams, NanonisError> {
+        let response = self.send(&commands::CpdCompParamsGet {}).await?;
+
+        Ok(CPDCompParams {
+            speed_hz: response.speed_hz,
+            range_v: response.range_v,
+            averaging: response.averaging,
+        })
+    }
+
+    /// Async version of [`NanonisClient::cpd_comp_data_get`](crate::NanonisClient::cpd_comp_data_get).
+    pub async fn cpd_comp_data_get(&mut self) -> Result<CPDCompData, NanonisError> {
+        let response = self.send(&commands::CpdCompDataGet {}).await?;
+
+        let forward_bias = response.forward_bias;
+        let forward_freq = response.forward_freq;
+        let forward_fit = response.forward_fit;
+
+        let backward_bias = response.backward_bias;
+        let backward_freq = response.backward_freq;
+        let backward_fit = response.backward_fit;
+
+        Ok(CPDCompData {
+            forward: CPDSweepData {
+                bias_v: forward_bias,
+                freq_shift: forward_freq,
+                freq_shift_fit: forward_fit,
+            },
+            backward: CPDSweepData {
+                bias_v: backward_bias,
+                freq_shift: backward_freq,
+                freq_shift_fit: backward_fit,
+            },
+            cpd_estimate_v: response.cpd_estimate_v,
+            fit_coefficients: CPDFitCoefficients {
+                a: response.a,
+                b: response.b,
+            },
+        })
+    }
+}
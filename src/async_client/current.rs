@@ -0,0 +1,81 @@
+use super::AsyncNanonisClient;
+use crate::current::commands;
+use crate::error::NanonisError;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::current_get`](crate::NanonisClient::current_get).
+    pub async fn current_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::CurrentGet {}).await?;
+
+        Ok(response.current)
+    }
+
+    /// Async version of [`NanonisClient::current_100_get`](crate::NanonisClient::current_100_get).
+    pub async fn current_100_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::Current100Get {}).await?;
+
+        Ok(response.current)
+    }
+
+    /// Async version of [`NanonisClient::current_beem_get`](crate::NanonisClient::current_beem_get).
+    pub async fn current_beem_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::CurrentBeemGet {}).await?;
+
+        Ok(response.current)
+    }
+
+    /// Async version of [`NanonisClient::current_gain_set`](crate::NanonisClient::current_gain_set).
+    pub async fn current_gain_set(
+        &mut self,
+        gain_index: i32,
+        filter_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::CurrentGainSet {
+            gain_index,
+            filter_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::current_gains_get`](crate::NanonisClient::current_gains_get).
+    pub async fn current_gains_get(
+        &mut self,
+    ) -> Result<(Vec<String>, i32, Vec<String>, i32), NanonisError> {
+        let response = self.send(&commands::CurrentGainsGet {}).await?;
+        Ok((
+            response.gains,
+            response.gain_index,
+            response.filters,
+            response.filter_index,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::current_calibr_set`](crate::NanonisClient::current_calibr_set).
+    pub async fn current_calibr_set(
+        &mut self,
+        gain_index: i32,
+        calibration: f64,
+        offset: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::CurrentCalibrSet {
+            gain_index,
+            calibration,
+            offset,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::current_calibr_get`](crate::NanonisClient::current_calibr_get).
+    pub async fn current_calibr_get(
+        &mut self,
+        gain_index: i32,
+    ) -> Result<(f64, f64), NanonisError> {
+        let response = self
+            .send(&commands::CurrentCalibrGet { gain_index })
+            .await?;
+
+        Ok((response.calibration, response.offset))
+    }
+}
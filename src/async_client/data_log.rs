@@ -0,0 +1,95 @@
+use super::AsyncNanonisClient;
+use crate::data_log::{commands, DataLogProps, DataLogStatus};
+use crate::error::NanonisError;
+use crate::signals::SlotRef;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::data_log_open`](crate::NanonisClient::data_log_open).
+    pub async fn data_log_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::DataLogOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::data_log_start`](crate::NanonisClient::data_log_start).
+    pub async fn data_log_start(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::DataLogStart {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::data_log_stop`](crate::NanonisClient::data_log_stop).
+    pub async fn data_log_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::DataLogStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::data_log_status_get`](crate::NanonisClient::data_log_status_get).
+    pub async fn data_log_status_get(&mut self) -> Result<DataLogStatus, NanonisError> {
+        let response = self.send(&commands::DataLogStatusGet {}).await?;
+
+        Ok(DataLogStatus {
+            start_time: response.start_time,
+            elapsed_hours: response.elapsed_hours,
+            elapsed_minutes: response.elapsed_minutes,
+            elapsed_seconds: response.elapsed_seconds,
+            stop_time: response.stop_time,
+            saved_file_path: response.saved_file_path,
+            saved_points: response.saved_points,
+        })
+    }
+
+    /// Async version of [`NanonisClient::data_log_chs_set`](crate::NanonisClient::data_log_chs_set).
+    pub async fn data_log_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let mut channel_indexes = Vec::new();
+        for channel in channels {
+            channel_indexes.push(self.resolve_slot(channel).await?);
+        }
+        self.send(&commands::DataLogChsSet { channel_indexes })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::data_log_chs_get`](crate::NanonisClient::data_log_chs_get).
+    pub async fn data_log_chs_get(&mut self) -> Result<Vec<i32>, NanonisError> {
+        let response = self.send(&commands::DataLogChsGet {}).await?;
+
+        Ok(response.channel_indexes)
+    }
+
+    /// Async version of [`NanonisClient::data_log_props_set`](crate::NanonisClient::data_log_props_set).
+    pub async fn data_log_props_set(
+        &mut self,
+        props: &DataLogProps,
+        modules: &[String],
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::DataLogPropsSet {
+            mode: props.mode.into(),
+            duration_hours: props.duration_hours,
+            duration_minutes: props.duration_minutes,
+            duration_seconds: props.duration_seconds,
+            averaging: props.averaging,
+            basename: props.basename.clone(),
+            comment: props.comment.clone(),
+            modules: modules.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::data_log_props_get`](crate::NanonisClient::data_log_props_get).
+    pub async fn data_log_props_get(&mut self) -> Result<DataLogProps, NanonisError> {
+        let response = self.send(&commands::DataLogPropsGet {}).await?;
+
+        Ok(DataLogProps {
+            mode: response.mode.try_into()?,
+            duration_hours: response.duration_hours,
+            duration_minutes: response.duration_minutes,
+            duration_seconds: response.duration_seconds,
+            averaging: response.averaging,
+            basename: response.basename,
+            comment: response.comment,
+        })
+    }
+}
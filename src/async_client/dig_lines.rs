@@ -0,0 +1,69 @@
+use super::AsyncNanonisClient;
+use crate::dig_lines::{commands, DigitalLineConfig, DigitalPort, PulseConfig};
+use crate::error::NanonisError;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::dig_lines_props_set`](crate::NanonisClient::dig_lines_props_set).
+    pub async fn dig_lines_props_set(
+        &mut self,
+        config: &DigitalLineConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::DigLinesPropsSet {
+            line: config.line,
+            port: config.port.into(),
+            direction: config.direction.into(),
+            polarity: config.polarity.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::dig_lines_out_status_set`](crate::NanonisClient::dig_lines_out_status_set).
+    pub async fn dig_lines_out_status_set(
+        &mut self,
+        port: DigitalPort,
+        line: u32,
+        active: bool,
+    ) -> Result<(), NanonisError> {
+        let status = if active { 1u32 } else { 0u32 };
+        self.send(&commands::DigLinesOutStatusSet {
+            port: port.into(),
+            line,
+            active: status,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::dig_lines_ttl_val_get`](crate::NanonisClient::dig_lines_ttl_val_get).
+    pub async fn dig_lines_ttl_val_get(
+        &mut self,
+        port: DigitalPort,
+    ) -> Result<Vec<u32>, NanonisError> {
+        let response = self
+            .send(&commands::DigLinesTtlValGet { port: port.into() })
+            .await?;
+
+        Ok(response.values)
+    }
+
+    /// Async version of [`NanonisClient::dig_lines_pulse`](crate::NanonisClient::dig_lines_pulse).
+    pub async fn dig_lines_pulse(&mut self, config: &PulseConfig) -> Result<(), NanonisError> {
+        let wait_flag = if config.wait_until_finished {
+            1u32
+        } else {
+            0u32
+        };
+
+        self.send(&commands::DigLinesPulse {
+            port: config.port.into(),
+            lines: config.lines.clone(),
+            pulse_width_s: config.pulse_width_s,
+            pulse_pause_s: config.pulse_pause_s,
+            num_pulses: config.num_pulses,
+            wait_until_finished: wait_flag,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::folme::{
+    commands, FolMeOversampling, FolMePSExperiment, FolMePSProps, FolMeSpeed, PSAutoResume,
+    PSBasenameMode,
+};
+use crate::types::Position;
+use crate::units::MetersPerSecond;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::folme_xy_pos_get`](crate::NanonisClient::folme_xy_pos_get).
+    pub async fn folme_xy_pos_get(
+        &mut self,
+        wait_for_newest_data: bool,
+    ) -> Result<Position, NanonisError> {
+        let wait_flag = if wait_for_newest_data { 1u32 } else { 0u32 };
+        let response = self
+            .send(&commands::FolMeXyPosGet {
+                wait_for_newest_data: wait_flag,
+            })
+            .await?;
+
+        Ok(Position {
+            x: response.x,
+            y: response.y,
+        })
+    }
+
+    /// Async version of [`NanonisClient::folme_xy_pos_set`](crate::NanonisClient::folme_xy_pos_set).
+    pub async fn folme_xy_pos_set(
+        &mut self,
+        position: Position,
+        wait_until_finished: bool,
+    ) -> Result<(), NanonisError> {
+        let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
+        self.send(&commands::FolMeXyPosSet {
+            x: position.x,
+            y: position.y,
+            wait_until_finished: wait_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::folme_speed_set`](crate::NanonisClient::folme_speed_set).
+    pub async fn folme_speed_set(
+        &mut self,
+        speed: impl Into<MetersPerSecond>,
+        custom_speed: bool,
+    ) -> Result<(), NanonisError> {
+        let custom_speed_flag = if custom_speed { 1u32 } else { 0u32 };
+        self.send(&commands::FolMeSpeedSet {
+            speed: speed.into().as_f32(),
+            custom_speed: custom_speed_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::folme_speed_get`](crate::NanonisClient::folme_speed_get).
+    pub async fn folme_speed_get(&mut self) -> Result<FolMeSpeed, NanonisError> {
+        let response = self.send(&commands::FolMeSpeedGet {}).await?;
+
+        Ok(FolMeSpeed {
+            speed_m_s: response.speed_m_s,
+            custom_speed: response.custom_speed != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::folme_oversampl_set`](crate::NanonisClient::folme_oversampl_set).
+    pub async fn folme_oversampl_set(&mut self, oversampling: i32) -> Result<(), NanonisError> {
+        self.send(&commands::FolMeOversamplSet { oversampling })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::folme_oversampl_get`](crate::NanonisClient::folme_oversampl_get).
+    pub async fn folme_oversampl_get(&mut self) -> Result<FolMeOversampling, NanonisError> {
+        let response = self.send(&commands::FolMeOversamplGet {}).await?;
+
+        Ok(FolMeOversampling {
+            oversampling: response.oversampling,
+            sampling_rate: response.sampling_rate,
+        })
+    }
+
+    /// Async version of [`NanonisClient::folme_stop`](crate::NanonisClient::folme_stop).
+    pub async fn folme_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::FolMeStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::folme_ps_on_off_get`](crate::NanonisClient::folme_ps_on_off_get).
+    pub async fn folme_ps_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::FolMePsOnOffGet {}).await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::folme_ps_on_off_set`](crate::NanonisClient::folme_ps_on_off_set).
+    pub async fn folme_ps_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
+        self.send(&commands::FolMePsOnOffSet {
+            enabled: if enabled { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::folme_ps_exp_get`](crate::NanonisClient::folme_ps_exp_get).
+    pub async fn folme_ps_exp_get(&mut self) -> Result<FolMePSExperiment, NanonisError> {
+        let response = self.send(&commands::FolMePsExpGet {}).await?;
+
+        Ok(FolMePSExperiment {
+            selected: response.selected,
+            experiments: response.experiments,
+        })
+    }
+
+    /// Async version of [`NanonisClient::folme_ps_exp_set`](crate::NanonisClient::folme_ps_exp_set).
+    pub async fn folme_ps_exp_set(&mut self, experiment_index: u16) -> Result<(), NanonisError> {
+        self.send(&commands::FolMePsExpSet { experiment_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::folme_ps_props_get`](crate::NanonisClient::folme_ps_props_get).
+    pub async fn folme_ps_props_get(&mut self) -> Result<FolMePSProps, NanonisError> {
+        let response = self.send(&commands::FolMePsPropsGet {}).await?;
+
+        Ok(FolMePSProps {
+            auto_resume: response.auto_resume != 0,
+            use_own_basename: response.use_own_basename != 0,
+            basename: response.basename,
+            external_vi_path: response.external_vi_path,
+            pre_measure_delay_s: response.pre_measure_delay_s,
+        })
+    }
+
+    /// Async version of [`NanonisClient::folme_ps_props_set`](crate::NanonisClient::folme_ps_props_set).
+    pub async fn folme_ps_props_set(
+        &mut self,
+        auto_resume: PSAutoResume,
+        basename_mode: PSBasenameMode,
+        basename: &str,
+        external_vi_path: &str,
+        pre_measure_delay_s: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::FolMePsPropsSet {
+            auto_resume: auto_resume.into(),
+            basename_mode: basename_mode.into(),
+            basename: basename.to_string(),
+            external_vi_path: external_vi_path.to_string(),
+            pre_measure_delay_s,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::gen_pi_ctrl::{commands, ACMode, AOProps, GenPICtrlProps};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::gen_pi_ctrl_on_off_set`](crate::NanonisClient::gen_pi_ctrl_on_off_set).
+    pub async fn gen_pi_ctrl_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
+        let status = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::GenPiCtrlOnOffSet { enabled: status })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_on_off_get`](crate::NanonisClient::gen_pi_ctrl_on_off_get).
+    pub async fn gen_pi_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::GenPiCtrlOnOffGet {}).await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_ao_val_set`](crate::NanonisClient::gen_pi_ctrl_ao_val_set).
+    pub async fn gen_pi_ctrl_ao_val_set(&mut self, output_value: f32) -> Result<(), NanonisError> {
+        self.send(&commands::GenPiCtrlAoValSet { output_value })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_ao_val_get`](crate::NanonisClient::gen_pi_ctrl_ao_val_get).
+    pub async fn gen_pi_ctrl_ao_val_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::GenPiCtrlAoValGet {}).await?;
+
+        Ok(response.output_value)
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_ao_props_set`](crate::NanonisClient::gen_pi_ctrl_ao_props_set).
+    pub async fn gen_pi_ctrl_ao_props_set(&mut self, props: &AOProps) -> Result<(), NanonisError> {
+        self.send(&commands::GenPiCtrlAoPropsSet {
+            signal_name: props.signal_name.clone(),
+            units: props.units.clone(),
+            upper_limit: props.upper_limit,
+            lower_limit: props.lower_limit,
+            calibration_per_volt: props.calibration_per_volt,
+            offset: props.offset,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_ao_props_get`](crate::NanonisClient::gen_pi_ctrl_ao_props_get).
+    pub async fn gen_pi_ctrl_ao_props_get(&mut self) -> Result<AOProps, NanonisError> {
+        let response = self.send(&commands::GenPiCtrlAoPropsGet {}).await?;
+
+        Ok(AOProps {
+            signal_name: response.signal_name,
+            units: response.units,
+            upper_limit: response.upper_limit,
+            lower_limit: response.lower_limit,
+            calibration_per_volt: response.calibration_per_volt,
+            offset: response.offset,
+        })
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_mod_ch_set`](crate::NanonisClient::gen_pi_ctrl_mod_ch_set).
+    pub async fn gen_pi_ctrl_mod_ch_set(&mut self, output_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::GenPiCtrlModChSet { output_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_mod_ch_get`](crate::NanonisClient::gen_pi_ctrl_mod_ch_get).
+    pub async fn gen_pi_ctrl_mod_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::GenPiCtrlModChGet {}).await?;
+
+        Ok(response.output_index)
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_demod_ch_set`](crate::NanonisClient::gen_pi_ctrl_demod_ch_set).
+    pub async fn gen_pi_ctrl_demod_ch_set(
+        &mut self,
+        input_index: i32,
+        ac_mode: ACMode,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::GenPiCtrlDemodChSet {
+            input_index,
+            ac_mode: ac_mode.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_demod_ch_get`](crate::NanonisClient::gen_pi_ctrl_demod_ch_get).
+    pub async fn gen_pi_ctrl_demod_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::GenPiCtrlDemodChGet {}).await?;
+
+        Ok(response.input_index)
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_props_set`](crate::NanonisClient::gen_pi_ctrl_props_set).
+    pub async fn gen_pi_ctrl_props_set(
+        &mut self,
+        props: &GenPICtrlProps,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::GenPiCtrlPropsSet {
+            setpoint: props.setpoint,
+            p_gain: props.p_gain,
+            time_constant: props.time_constant,
+            slope: props.slope.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_pi_ctrl_props_get`](crate::NanonisClient::gen_pi_ctrl_props_get).
+    pub async fn gen_pi_ctrl_props_get(&mut self) -> Result<GenPICtrlProps, NanonisError> {
+        let response = self.send(&commands::GenPiCtrlPropsGet {}).await?;
+
+        Ok(GenPICtrlProps {
+            setpoint: response.setpoint,
+            p_gain: response.p_gain,
+            time_constant: response.time_constant,
+            slope: response.slope.try_into()?,
+        })
+    }
+}
@@ -0,0 +1,147 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::gen_swp::{commands, GenSwpProps, GenSwpResult};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::gen_swp_open`](crate::NanonisClient::gen_swp_open).
+    pub async fn gen_swp_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::GenSwpOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_acq_chs_set`](crate::NanonisClient::gen_swp_acq_chs_set).
+    pub async fn gen_swp_acq_chs_set(
+        &mut self,
+        channel_indexes: &[i32],
+        channel_names: &[String],
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::GenSwpAcqChsSet {
+            channel_indexes: channel_indexes.to_vec(),
+            channel_names: channel_names.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_acq_chs_get`](crate::NanonisClient::gen_swp_acq_chs_get).
+    pub async fn gen_swp_acq_chs_get(&mut self) -> Result<(Vec<i32>, Vec<String>), NanonisError> {
+        let response = self.send(&commands::GenSwpAcqChsGet {}).await?;
+
+        Ok((response.indexes, response.names))
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_swp_signal_set`](crate::NanonisClient::gen_swp_swp_signal_set).
+    pub async fn gen_swp_swp_signal_set(&mut self, signal_name: &str) -> Result<(), NanonisError> {
+        self.send(&commands::GenSwpSwpSignalSet {
+            signal_name: signal_name.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_swp_signal_get`](crate::NanonisClient::gen_swp_swp_signal_get).
+    pub async fn gen_swp_swp_signal_get(&mut self) -> Result<String, NanonisError> {
+        let response = self.send(&commands::GenSwpSwpSignalGet {}).await?;
+
+        Ok(response.signal_name)
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_swp_signal_list_get`](crate::NanonisClient::gen_swp_swp_signal_list_get).
+    pub async fn gen_swp_swp_signal_list_get(&mut self) -> Result<Vec<String>, NanonisError> {
+        let response = self.send(&commands::GenSwpSwpSignalListGet {}).await?;
+
+        Ok(response.signal_names)
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_limits_set`](crate::NanonisClient::gen_swp_limits_set).
+    pub async fn gen_swp_limits_set(
+        &mut self,
+        lower_limit: f32,
+        upper_limit: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::GenSwpLimitsSet {
+            lower_limit,
+            upper_limit,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_limits_get`](crate::NanonisClient::gen_swp_limits_get).
+    pub async fn gen_swp_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let response = self.send(&commands::GenSwpLimitsGet {}).await?;
+
+        Ok((response.lower_limit, response.upper_limit))
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_props_set`](crate::NanonisClient::gen_swp_props_set).
+    pub async fn gen_swp_props_set(&mut self, props: &GenSwpProps) -> Result<(), NanonisError> {
+        let autosave_flag = if props.autosave { 1i32 } else { 2i32 };
+        let dialog_flag = if props.save_dialog { 1i32 } else { 2i32 };
+
+        self.send(&commands::GenSwpPropsSet {
+            initial_settling_time_ms: props.initial_settling_time_ms,
+            max_slew_rate: props.max_slew_rate,
+            num_steps: props.num_steps,
+            period_ms: props.period_ms,
+            autosave_flag,
+            dialog_flag,
+            settling_time_ms: props.settling_time_ms,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_props_get`](crate::NanonisClient::gen_swp_props_get).
+    pub async fn gen_swp_props_get(&mut self) -> Result<GenSwpProps, NanonisError> {
+        let response = self.send(&commands::GenSwpPropsGet {}).await?;
+
+        Ok(GenSwpProps {
+            initial_settling_time_ms: response.initial_settling_time_ms,
+            max_slew_rate: response.max_slew_rate,
+            num_steps: response.num_steps,
+            period_ms: response.period_ms,
+            autosave: response.autosave != 0,
+            save_dialog: response.save_dialog != 0,
+            settling_time_ms: response.settling_time_ms,
+        })
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_start`](crate::NanonisClient::gen_swp_start).
+    pub async fn gen_swp_start(
+        &mut self,
+        get_data: bool,
+        sweep_direction: bool,
+        save_base_name: &str,
+        reset_signal: bool,
+        z_controller: u16,
+    ) -> Result<GenSwpResult, NanonisError> {
+        let get_data_flag = if get_data { 1u32 } else { 0u32 };
+        let direction_flag = if sweep_direction { 1u32 } else { 0u32 };
+        let reset_flag = if reset_signal { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::GenSwpStart {
+                get_data: get_data_flag,
+                sweep_direction: direction_flag,
+                save_base_name: save_base_name.to_string(),
+                reset_signal: reset_flag,
+                z_controller,
+            })
+            .await?;
+
+        let channel_names = response.channel_names;
+        let data_2d = response.data_2d;
+
+        Ok(GenSwpResult {
+            channel_names,
+            data: data_2d,
+        })
+    }
+
+    /// Async version of [`NanonisClient::gen_swp_stop`](crate::NanonisClient::gen_swp_stop).
+    pub async fn gen_swp_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::GenSwpStop {}).await?;
+        Ok(())
+    }
+}
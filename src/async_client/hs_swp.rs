@@ -0,0 +1,373 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::hs_swp::{
+    commands, HSSwpAutoReverse, HSSwpAvailableChannels, HSSwpLimits, HSSwpSaveOptions,
+    HSSwpSignalList, HSSwpTiming, HSSwpZCtrl,
+};
+use crate::signals::SignalRef;
+use crate::units::Seconds;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::hs_swp_acq_chs_set`](crate::NanonisClient::hs_swp_acq_chs_set).
+    pub async fn hs_swp_acq_chs_set(
+        &mut self,
+        channel_indices: &[i32],
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpAcqChsSet {
+            channel_indices: channel_indices.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_acq_chs_get`](crate::NanonisClient::hs_swp_acq_chs_get).
+    pub async fn hs_swp_acq_chs_get(&mut self) -> Result<HSSwpAvailableChannels, NanonisError> {
+        let response = self.send(&commands::HsSwpAcqChsGet {}).await?;
+
+        Ok(HSSwpAvailableChannels {
+            selected_indices: response.selected_indices,
+            available_names: response.available_names,
+            available_indices: response.available_indices,
+        })
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_auto_reverse_set`](crate::NanonisClient::hs_swp_auto_reverse_set).
+    pub async fn hs_swp_auto_reverse_set(
+        &mut self,
+        config: &HSSwpAutoReverse,
+    ) -> Result<(), NanonisError> {
+        let on_off = if config.enabled { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpAutoReverseSet {
+            enabled: on_off,
+            condition: config.condition.into(),
+            signal_index: config.signal_index,
+            threshold: config.threshold,
+            linkage: config.linkage.into(),
+            condition2: config.condition2.into(),
+            signal2_index: config.signal2_index,
+            threshold2: config.threshold2,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_auto_reverse_get`](crate::NanonisClient::hs_swp_auto_reverse_get).
+    pub async fn hs_swp_auto_reverse_get(&mut self) -> Result<HSSwpAutoReverse, NanonisError> {
+        let response = self.send(&commands::HsSwpAutoReverseGet {}).await?;
+
+        Ok(HSSwpAutoReverse {
+            enabled: response.enabled != 0,
+            condition: response.condition.try_into()?,
+            signal_index: response.signal_index,
+            threshold: response.threshold,
+            linkage: response.linkage.try_into()?,
+            condition2: response.condition2.try_into()?,
+            signal2_index: response.signal2_index,
+            threshold2: response.threshold2,
+        })
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_end_settl_set`](crate::NanonisClient::hs_swp_end_settl_set).
+    pub async fn hs_swp_end_settl_set(
+        &mut self,
+        time_s: impl Into<Seconds>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpEndSettlSet {
+            time_s: time_s.into().as_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_end_settl_get`](crate::NanonisClient::hs_swp_end_settl_get).
+    pub async fn hs_swp_end_settl_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::HsSwpEndSettlGet {}).await?;
+
+        Ok(response.time_s)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_num_sweeps_set`](crate::NanonisClient::hs_swp_num_sweeps_set).
+    pub async fn hs_swp_num_sweeps_set(
+        &mut self,
+        num_sweeps: u32,
+        continuous: bool,
+    ) -> Result<(), NanonisError> {
+        let cont_flag = if continuous { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpNumSweepsSet {
+            num_sweeps,
+            continuous: cont_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_num_sweeps_get`](crate::NanonisClient::hs_swp_num_sweeps_get).
+    pub async fn hs_swp_num_sweeps_get(&mut self) -> Result<(u32, bool), NanonisError> {
+        let response = self.send(&commands::HsSwpNumSweepsGet {}).await?;
+
+        Ok((response.num_sweeps, response.continuous != 0))
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_reset_signals_set`](crate::NanonisClient::hs_swp_reset_signals_set).
+    pub async fn hs_swp_reset_signals_set(&mut self, reset: bool) -> Result<(), NanonisError> {
+        let flag = if reset { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpResetSignalsSet { reset: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_reset_signals_get`](crate::NanonisClient::hs_swp_reset_signals_get).
+    pub async fn hs_swp_reset_signals_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::HsSwpResetSignalsGet {}).await?;
+
+        Ok(response.reset != 0)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_save_basename_set`](crate::NanonisClient::hs_swp_save_basename_set).
+    pub async fn hs_swp_save_basename_set(
+        &mut self,
+        basename: &str,
+        path: &str,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpSaveBasenameSet {
+            basename: basename.to_string(),
+            path: path.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_save_basename_get`](crate::NanonisClient::hs_swp_save_basename_get).
+    pub async fn hs_swp_save_basename_get(&mut self) -> Result<(String, String), NanonisError> {
+        let response = self.send(&commands::HsSwpSaveBasenameGet {}).await?;
+
+        Ok((response.basename, response.path))
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_save_data_set`](crate::NanonisClient::hs_swp_save_data_set).
+    pub async fn hs_swp_save_data_set(&mut self, save: bool) -> Result<(), NanonisError> {
+        let flag = if save { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpSaveDataSet { save: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_save_data_get`](crate::NanonisClient::hs_swp_save_data_get).
+    pub async fn hs_swp_save_data_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::HsSwpSaveDataGet {}).await?;
+
+        Ok(response.save != 0)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_save_options_set`](crate::NanonisClient::hs_swp_save_options_set).
+    pub async fn hs_swp_save_options_set(
+        &mut self,
+        options: &HSSwpSaveOptions,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpSaveOptionsSet {
+            comment: options.comment.clone(),
+            modules: options.modules.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_save_options_get`](crate::NanonisClient::hs_swp_save_options_get).
+    pub async fn hs_swp_save_options_get(&mut self) -> Result<HSSwpSaveOptions, NanonisError> {
+        let response = self.send(&commands::HsSwpSaveOptionsGet {}).await?;
+
+        Ok(HSSwpSaveOptions {
+            comment: response.comment,
+            modules: response.modules,
+        })
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_start`](crate::NanonisClient::hs_swp_start).
+    pub async fn hs_swp_start(
+        &mut self,
+        wait_until_done: bool,
+        timeout_ms: i32,
+    ) -> Result<(), NanonisError> {
+        let wait_flag = if wait_until_done { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpStart {
+            wait_until_done: wait_flag,
+            timeout_ms,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_stop`](crate::NanonisClient::hs_swp_stop).
+    pub async fn hs_swp_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_status_get`](crate::NanonisClient::hs_swp_status_get).
+    pub async fn hs_swp_status_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::HsSwpStatusGet {}).await?;
+
+        Ok(response.running != 0)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_sig_list_get`](crate::NanonisClient::hs_swp_swp_ch_sig_list_get).
+    pub async fn hs_swp_swp_ch_sig_list_get(&mut self) -> Result<HSSwpSignalList, NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChSigListGet {}).await?;
+
+        Ok(HSSwpSignalList {
+            names: response.names,
+            indices: response.indices,
+        })
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_signal_set`](crate::NanonisClient::hs_swp_swp_ch_signal_set).
+    pub async fn hs_swp_swp_ch_signal_set(
+        &mut self,
+        signal: impl Into<SignalRef>,
+        timed_sweep: bool,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        let timed_flag = if timed_sweep { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpSwpChSignalSet {
+            signal_index,
+            timed_sweep: timed_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_signal_get`](crate::NanonisClient::hs_swp_swp_ch_signal_get).
+    pub async fn hs_swp_swp_ch_signal_get(&mut self) -> Result<(i32, bool), NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChSignalGet {}).await?;
+
+        Ok((response.signal_index, response.timed_sweep != 0))
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_limits_set`](crate::NanonisClient::hs_swp_swp_ch_limits_set).
+    pub async fn hs_swp_swp_ch_limits_set(
+        &mut self,
+        limits: &HSSwpLimits,
+    ) -> Result<(), NanonisError> {
+        let rel_flag = if limits.relative { 1i32 } else { 0i32 };
+        self.send(&commands::HsSwpSwpChLimitsSet {
+            relative: rel_flag,
+            start: limits.start,
+            stop: limits.stop,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_limits_get`](crate::NanonisClient::hs_swp_swp_ch_limits_get).
+    pub async fn hs_swp_swp_ch_limits_get(&mut self) -> Result<HSSwpLimits, NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChLimitsGet {}).await?;
+
+        Ok(HSSwpLimits {
+            relative: response.relative != 0,
+            start: response.start,
+            stop: response.stop,
+        })
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_num_pts_set`](crate::NanonisClient::hs_swp_swp_ch_num_pts_set).
+    pub async fn hs_swp_swp_ch_num_pts_set(&mut self, num_points: u32) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpSwpChNumPtsSet { num_points })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_num_pts_get`](crate::NanonisClient::hs_swp_swp_ch_num_pts_get).
+    pub async fn hs_swp_swp_ch_num_pts_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChNumPtsGet {}).await?;
+
+        Ok(response.num_points)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_timing_set`](crate::NanonisClient::hs_swp_swp_ch_timing_set).
+    pub async fn hs_swp_swp_ch_timing_set(
+        &mut self,
+        timing: &HSSwpTiming,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpSwpChTimingSet {
+            initial_settling_s: timing.initial_settling_s,
+            settling_s: timing.settling_s,
+            integration_s: timing.integration_s,
+            max_slew_rate: timing.max_slew_rate,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_timing_get`](crate::NanonisClient::hs_swp_swp_ch_timing_get).
+    pub async fn hs_swp_swp_ch_timing_get(&mut self) -> Result<HSSwpTiming, NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChTimingGet {}).await?;
+
+        Ok(HSSwpTiming {
+            initial_settling_s: response.initial_settling_s,
+            settling_s: response.settling_s,
+            integration_s: response.integration_s,
+            max_slew_rate: response.max_slew_rate,
+        })
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_bwd_sw_set`](crate::NanonisClient::hs_swp_swp_ch_bwd_sw_set).
+    pub async fn hs_swp_swp_ch_bwd_sw_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::HsSwpSwpChBwdSwSet { enabled: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_bwd_sw_get`](crate::NanonisClient::hs_swp_swp_ch_bwd_sw_get).
+    pub async fn hs_swp_swp_ch_bwd_sw_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChBwdSwGet {}).await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_bwd_delay_set`](crate::NanonisClient::hs_swp_swp_ch_bwd_delay_set).
+    pub async fn hs_swp_swp_ch_bwd_delay_set(
+        &mut self,
+        delay_s: impl Into<Seconds>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpSwpChBwdDelaySet {
+            delay_s: delay_s.into().as_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_swp_ch_bwd_delay_get`](crate::NanonisClient::hs_swp_swp_ch_bwd_delay_get).
+    pub async fn hs_swp_swp_ch_bwd_delay_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::HsSwpSwpChBwdDelayGet {}).await?;
+
+        Ok(response.delay_s)
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_z_ctrl_off_set`](crate::NanonisClient::hs_swp_z_ctrl_off_set).
+    pub async fn hs_swp_z_ctrl_off_set(&mut self, config: &HSSwpZCtrl) -> Result<(), NanonisError> {
+        let switch_off = if config.switch_off { 0i32 } else { 1i32 }; // 0=switch off, 1=don't switch
+        self.send(&commands::HsSwpZCtrlOffSet {
+            switch_off,
+            controller_index: config.controller_index,
+            averaging_time_s: config.averaging_time_s,
+            z_offset_m: config.z_offset_m,
+            control_time_s: config.control_time_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::hs_swp_z_ctrl_off_get`](crate::NanonisClient::hs_swp_z_ctrl_off_get).
+    pub async fn hs_swp_z_ctrl_off_get(&mut self) -> Result<HSSwpZCtrl, NanonisError> {
+        let response = self.send(&commands::HsSwpZCtrlOffGet {}).await?;
+
+        Ok(HSSwpZCtrl {
+            switch_off: response.switch_off == 0, // 0=switch off, 1=don't switch
+            controller_index: response.controller_index,
+            averaging_time_s: response.averaging_time_s,
+            z_offset_m: response.z_offset_m,
+            control_time_s: response.control_time_s,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::interf::{commands, InterfCtrlProps};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::interf_ctrl_on_off_set`](crate::NanonisClient::interf_ctrl_on_off_set).
+    pub async fn interf_ctrl_on_off_set(&mut self, on: bool) -> Result<(), NanonisError> {
+        self.send(&commands::InterfCtrlOnOffSet {
+            on: if on { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::interf_ctrl_on_off_get`](crate::NanonisClient::interf_ctrl_on_off_get).
+    pub async fn interf_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::InterfCtrlOnOffGet {}).await?;
+
+        Ok(response.on != 0)
+    }
+
+    /// Async version of [`NanonisClient::interf_ctrl_props_set`](crate::NanonisClient::interf_ctrl_props_set).
+    pub async fn interf_ctrl_props_set(
+        &mut self,
+        props: &InterfCtrlProps,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::InterfCtrlPropsSet {
+            integral: props.integral,
+            proportional: props.proportional,
+            positive_sign: if props.positive_sign { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::interf_ctrl_props_get`](crate::NanonisClient::interf_ctrl_props_get).
+    pub async fn interf_ctrl_props_get(&mut self) -> Result<InterfCtrlProps, NanonisError> {
+        let response = self.send(&commands::InterfCtrlPropsGet {}).await?;
+
+        Ok(InterfCtrlProps {
+            integral: response.integral,
+            proportional: response.proportional,
+            positive_sign: response.positive_sign != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::interf_w_piezo_set`](crate::NanonisClient::interf_w_piezo_set).
+    pub async fn interf_w_piezo_set(&mut self, w_piezo: f32) -> Result<(), NanonisError> {
+        self.send(&commands::InterfWPiezoSet { w_piezo }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::interf_w_piezo_get`](crate::NanonisClient::interf_w_piezo_get).
+    pub async fn interf_w_piezo_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::InterfWPiezoGet {}).await?;
+
+        Ok(response.w_piezo)
+    }
+
+    /// Async version of [`NanonisClient::interf_val_get`](crate::NanonisClient::interf_val_get).
+    pub async fn interf_val_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::InterfValGet {}).await?;
+
+        Ok(response.value)
+    }
+
+    /// Async version of [`NanonisClient::interf_ctrl_calibr_open`](crate::NanonisClient::interf_ctrl_calibr_open).
+    pub async fn interf_ctrl_calibr_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::InterfCtrlCalibrOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::interf_ctrl_reset`](crate::NanonisClient::interf_ctrl_reset).
+    pub async fn interf_ctrl_reset(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::InterfCtrlReset {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::interf_ctrl_null_defl`](crate::NanonisClient::interf_ctrl_null_defl).
+    pub async fn interf_ctrl_null_defl(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::InterfCtrlNullDefl {}).await?;
+        Ok(())
+    }
+}
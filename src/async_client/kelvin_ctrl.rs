@@ -0,0 +1,157 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::kelvin_ctrl::{
+    commands, KelvinACMode, KelvinBiasLimits, KelvinGain, KelvinModParams, KelvinModStatus,
+};
+use crate::signals::SignalRef;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::kelvin_ctrl_on_off_set`](crate::NanonisClient::kelvin_ctrl_on_off_set).
+    pub async fn kelvin_ctrl_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::KelvinCtrlCtrlOnOffSet { enabled: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_on_off_get`](crate::NanonisClient::kelvin_ctrl_on_off_get).
+    pub async fn kelvin_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlCtrlOnOffGet {}).await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_setpnt_set`](crate::NanonisClient::kelvin_ctrl_setpnt_set).
+    pub async fn kelvin_ctrl_setpnt_set(&mut self, setpoint: f32) -> Result<(), NanonisError> {
+        self.send(&commands::KelvinCtrlSetpntSet { setpoint })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_setpnt_get`](crate::NanonisClient::kelvin_ctrl_setpnt_get).
+    pub async fn kelvin_ctrl_setpnt_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlSetpntGet {}).await?;
+
+        Ok(response.setpoint)
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_gain_set`](crate::NanonisClient::kelvin_ctrl_gain_set).
+    pub async fn kelvin_ctrl_gain_set(&mut self, gain: &KelvinGain) -> Result<(), NanonisError> {
+        self.send(&commands::KelvinCtrlGainSet {
+            p_gain: gain.p_gain,
+            time_constant_s: gain.time_constant_s,
+            slope: gain.slope.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_gain_get`](crate::NanonisClient::kelvin_ctrl_gain_get).
+    pub async fn kelvin_ctrl_gain_get(&mut self) -> Result<KelvinGain, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlGainGet {}).await?;
+
+        Ok(KelvinGain {
+            p_gain: response.p_gain,
+            time_constant_s: response.time_constant_s,
+            slope: response.slope.try_into()?,
+        })
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_mod_params_set`](crate::NanonisClient::kelvin_ctrl_mod_params_set).
+    pub async fn kelvin_ctrl_mod_params_set(
+        &mut self,
+        params: &KelvinModParams,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::KelvinCtrlModParamsSet {
+            frequency_hz: params.frequency_hz,
+            amplitude: params.amplitude,
+            phase_deg: params.phase_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_mod_params_get`](crate::NanonisClient::kelvin_ctrl_mod_params_get).
+    pub async fn kelvin_ctrl_mod_params_get(&mut self) -> Result<KelvinModParams, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlModParamsGet {}).await?;
+
+        Ok(KelvinModParams {
+            frequency_hz: response.frequency_hz,
+            amplitude: response.amplitude,
+            phase_deg: response.phase_deg,
+        })
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_mod_on_off_set`](crate::NanonisClient::kelvin_ctrl_mod_on_off_set).
+    pub async fn kelvin_ctrl_mod_on_off_set(
+        &mut self,
+        ac_mode: KelvinACMode,
+        modulation: bool,
+    ) -> Result<(), NanonisError> {
+        let mod_flag = if modulation { 1u16 } else { 0u16 };
+        self.send(&commands::KelvinCtrlModOnOffSet {
+            ac_mode: ac_mode.into(),
+            modulation: mod_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_mod_on_off_get`](crate::NanonisClient::kelvin_ctrl_mod_on_off_get).
+    pub async fn kelvin_ctrl_mod_on_off_get(&mut self) -> Result<KelvinModStatus, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlModOnOffGet {}).await?;
+
+        Ok(KelvinModStatus {
+            ac_mode: response.ac_mode != 0,
+            modulation: response.modulation != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_signal_set`](crate::NanonisClient::kelvin_ctrl_signal_set).
+    pub async fn kelvin_ctrl_signal_set(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::KelvinCtrlCtrlSignalSet { signal_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_signal_get`](crate::NanonisClient::kelvin_ctrl_signal_get).
+    pub async fn kelvin_ctrl_signal_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlCtrlSignalGet {}).await?;
+
+        Ok(response.signal_index)
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_amp_get`](crate::NanonisClient::kelvin_ctrl_amp_get).
+    pub async fn kelvin_ctrl_amp_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlAmpGet {}).await?;
+
+        Ok(response.amplitude)
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_bias_limits_set`](crate::NanonisClient::kelvin_ctrl_bias_limits_set).
+    pub async fn kelvin_ctrl_bias_limits_set(
+        &mut self,
+        limits: &KelvinBiasLimits,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::KelvinCtrlBiasLimitsSet {
+            high_limit_v: limits.high_limit_v,
+            low_limit_v: limits.low_limit_v,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::kelvin_ctrl_bias_limits_get`](crate::NanonisClient::kelvin_ctrl_bias_limits_get).
+    pub async fn kelvin_ctrl_bias_limits_get(&mut self) -> Result<KelvinBiasLimits, NanonisError> {
+        let response = self.send(&commands::KelvinCtrlBiasLimitsGet {}).await?;
+
+        Ok(KelvinBiasLimits {
+            high_limit_v: response.high_limit_v,
+            low_limit_v: response.low_limit_v,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::laser::commands;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::laser_on_off_set`](crate::NanonisClient::laser_on_off_set).
+    pub async fn laser_on_off_set(&mut self, on: bool) -> Result<(), NanonisError> {
+        self.send(&commands::LaserOnOffSet {
+            on: if on { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::laser_on_off_get`](crate::NanonisClient::laser_on_off_get).
+    pub async fn laser_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::LaserOnOffGet {}).await?;
+
+        Ok(response.on != 0)
+    }
+
+    /// Async version of [`NanonisClient::laser_props_set`](crate::NanonisClient::laser_props_set).
+    pub async fn laser_props_set(&mut self, setpoint: f32) -> Result<(), NanonisError> {
+        self.send(&commands::LaserPropsSet { setpoint }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::laser_props_get`](crate::NanonisClient::laser_props_get).
+    pub async fn laser_props_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::LaserPropsGet {}).await?;
+
+        Ok(response.setpoint)
+    }
+
+    /// Async version of [`NanonisClient::laser_power_get`](crate::NanonisClient::laser_power_get).
+    pub async fn laser_power_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::LaserPowerGet {}).await?;
+
+        Ok(response.power)
+    }
+}
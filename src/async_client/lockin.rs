@@ -0,0 +1,386 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::lockin::{commands, FilterConfig, RTSignalMode};
+use crate::signals::SignalRef;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::lockin_mod_on_off_set`](crate::NanonisClient::lockin_mod_on_off_set).
+    pub async fn lockin_mod_on_off_set(
+        &mut self,
+        modulator_num: i32,
+        on: bool,
+    ) -> Result<(), NanonisError> {
+        let on_flag = if on { 1u32 } else { 0u32 };
+        self.send(&commands::LockInModOnOffSet {
+            modulator_num,
+            on: on_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_on_off_get`](crate::NanonisClient::lockin_mod_on_off_get).
+    pub async fn lockin_mod_on_off_get(
+        &mut self,
+        modulator_num: i32,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::LockInModOnOffGet { modulator_num })
+            .await?;
+        Ok(response.on != 0)
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_signal_set`](crate::NanonisClient::lockin_mod_signal_set).
+    pub async fn lockin_mod_signal_set(
+        &mut self,
+        modulator_num: i32,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::LockInModSignalSet {
+            modulator_num,
+            signal_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_signal_get`](crate::NanonisClient::lockin_mod_signal_get).
+    pub async fn lockin_mod_signal_get(&mut self, modulator_num: i32) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::LockInModSignalGet { modulator_num })
+            .await?;
+        Ok(response.signal_index)
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_phas_reg_set`](crate::NanonisClient::lockin_mod_phas_reg_set).
+    pub async fn lockin_mod_phas_reg_set(
+        &mut self,
+        modulator_num: i32,
+        phase_register_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInModPhasRegSet {
+            modulator_num,
+            phase_register_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_phas_reg_get`](crate::NanonisClient::lockin_mod_phas_reg_get).
+    pub async fn lockin_mod_phas_reg_get(
+        &mut self,
+        modulator_num: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::LockInModPhasRegGet { modulator_num })
+            .await?;
+        Ok(response.phase_register_index)
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_harmonic_set`](crate::NanonisClient::lockin_mod_harmonic_set).
+    pub async fn lockin_mod_harmonic_set(
+        &mut self,
+        modulator_num: i32,
+        harmonic: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInModHarmonicSet {
+            modulator_num,
+            harmonic,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_harmonic_get`](crate::NanonisClient::lockin_mod_harmonic_get).
+    pub async fn lockin_mod_harmonic_get(
+        &mut self,
+        modulator_num: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::LockInModHarmonicGet { modulator_num })
+            .await?;
+        Ok(response.harmonic)
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_phas_set`](crate::NanonisClient::lockin_mod_phas_set).
+    pub async fn lockin_mod_phas_set(
+        &mut self,
+        modulator_num: i32,
+        phase_deg: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInModPhasSet {
+            modulator_num,
+            phase_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_phas_get`](crate::NanonisClient::lockin_mod_phas_get).
+    pub async fn lockin_mod_phas_get(&mut self, modulator_num: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::LockInModPhasGet { modulator_num })
+            .await?;
+        Ok(response.phase_deg)
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_amp_set`](crate::NanonisClient::lockin_mod_amp_set).
+    pub async fn lockin_mod_amp_set(
+        &mut self,
+        modulator_num: i32,
+        amplitude: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInModAmpSet {
+            modulator_num,
+            amplitude,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_amp_get`](crate::NanonisClient::lockin_mod_amp_get).
+    pub async fn lockin_mod_amp_get(&mut self, modulator_num: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::LockInModAmpGet { modulator_num })
+            .await?;
+        Ok(response.amplitude)
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_phas_freq_set`](crate::NanonisClient::lockin_mod_phas_freq_set).
+    pub async fn lockin_mod_phas_freq_set(
+        &mut self,
+        modulator_num: i32,
+        frequency_hz: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInModPhasFreqSet {
+            modulator_num,
+            frequency_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_mod_phas_freq_get`](crate::NanonisClient::lockin_mod_phas_freq_get).
+    pub async fn lockin_mod_phas_freq_get(
+        &mut self,
+        modulator_num: i32,
+    ) -> Result<f64, NanonisError> {
+        let response = self
+            .send(&commands::LockInModPhasFreqGet { modulator_num })
+            .await?;
+        Ok(response.frequency_hz)
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_signal_set`](crate::NanonisClient::lockin_demod_signal_set).
+    pub async fn lockin_demod_signal_set(
+        &mut self,
+        demodulator_num: i32,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::LockInDemodSignalSet {
+            demodulator_num,
+            signal_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_signal_get`](crate::NanonisClient::lockin_demod_signal_get).
+    pub async fn lockin_demod_signal_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodSignalGet { demodulator_num })
+            .await?;
+        Ok(response.signal_index)
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_harmonic_set`](crate::NanonisClient::lockin_demod_harmonic_set).
+    pub async fn lockin_demod_harmonic_set(
+        &mut self,
+        demodulator_num: i32,
+        harmonic: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInDemodHarmonicSet {
+            demodulator_num,
+            harmonic,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_harmonic_get`](crate::NanonisClient::lockin_demod_harmonic_get).
+    pub async fn lockin_demod_harmonic_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodHarmonicGet { demodulator_num })
+            .await?;
+        Ok(response.harmonic)
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_hp_filter_set`](crate::NanonisClient::lockin_demod_hp_filter_set).
+    pub async fn lockin_demod_hp_filter_set(
+        &mut self,
+        demodulator_num: i32,
+        filter_order: i32,
+        cutoff_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInDemodHpFilterSet {
+            demodulator_num,
+            filter_order,
+            cutoff_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_hp_filter_get`](crate::NanonisClient::lockin_demod_hp_filter_get).
+    pub async fn lockin_demod_hp_filter_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<FilterConfig, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodHpFilterGet { demodulator_num })
+            .await?;
+        Ok(FilterConfig {
+            order: response.order,
+            cutoff_hz: response.cutoff_hz,
+        })
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_lp_filter_set`](crate::NanonisClient::lockin_demod_lp_filter_set).
+    pub async fn lockin_demod_lp_filter_set(
+        &mut self,
+        demodulator_num: i32,
+        filter_order: i32,
+        cutoff_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInDemodLpFilterSet {
+            demodulator_num,
+            filter_order,
+            cutoff_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_lp_filter_get`](crate::NanonisClient::lockin_demod_lp_filter_get).
+    pub async fn lockin_demod_lp_filter_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<FilterConfig, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodLpFilterGet { demodulator_num })
+            .await?;
+        Ok(FilterConfig {
+            order: response.order,
+            cutoff_hz: response.cutoff_hz,
+        })
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_phas_reg_set`](crate::NanonisClient::lockin_demod_phas_reg_set).
+    pub async fn lockin_demod_phas_reg_set(
+        &mut self,
+        demodulator_num: i32,
+        phase_register_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInDemodPhasRegSet {
+            demodulator_num,
+            phase_register_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_phas_reg_get`](crate::NanonisClient::lockin_demod_phas_reg_get).
+    pub async fn lockin_demod_phas_reg_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodPhasRegGet { demodulator_num })
+            .await?;
+        Ok(response.phase_register_index)
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_phas_set`](crate::NanonisClient::lockin_demod_phas_set).
+    pub async fn lockin_demod_phas_set(
+        &mut self,
+        demodulator_num: i32,
+        phase_deg: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInDemodPhasSet {
+            demodulator_num,
+            phase_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_phas_get`](crate::NanonisClient::lockin_demod_phas_get).
+    pub async fn lockin_demod_phas_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodPhasGet { demodulator_num })
+            .await?;
+        Ok(response.phase_deg)
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_sync_filter_set`](crate::NanonisClient::lockin_demod_sync_filter_set).
+    pub async fn lockin_demod_sync_filter_set(
+        &mut self,
+        demodulator_num: i32,
+        on: bool,
+    ) -> Result<(), NanonisError> {
+        let on_flag = if on { 1u32 } else { 0u32 };
+        self.send(&commands::LockInDemodSyncFilterSet {
+            demodulator_num,
+            on: on_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_sync_filter_get`](crate::NanonisClient::lockin_demod_sync_filter_get).
+    pub async fn lockin_demod_sync_filter_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodSyncFilterGet { demodulator_num })
+            .await?;
+        Ok(response.on != 0)
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_rt_signals_set`](crate::NanonisClient::lockin_demod_rt_signals_set).
+    pub async fn lockin_demod_rt_signals_set(
+        &mut self,
+        demodulator_num: i32,
+        mode: RTSignalMode,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInDemodRtSignalsSet {
+            demodulator_num,
+            mode: mode.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_demod_rt_signals_get`](crate::NanonisClient::lockin_demod_rt_signals_get).
+    pub async fn lockin_demod_rt_signals_get(
+        &mut self,
+        demodulator_num: i32,
+    ) -> Result<RTSignalMode, NanonisError> {
+        let response = self
+            .send(&commands::LockInDemodRtSignalsGet { demodulator_num })
+            .await?;
+        RTSignalMode::try_from(response.mode)
+    }
+}
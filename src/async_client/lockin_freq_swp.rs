@@ -0,0 +1,113 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::lockin_freq_swp::{commands, FreqSwpDirection, LockInFreqSwpProps, LockInFreqSwpResult};
+use crate::signals::SignalRef;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::lockin_freq_swp_open`](crate::NanonisClient::lockin_freq_swp_open).
+    pub async fn lockin_freq_swp_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::LockInFreqSwpOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_start`](crate::NanonisClient::lockin_freq_swp_start).
+    pub async fn lockin_freq_swp_start(
+        &mut self,
+        get_data: bool,
+        direction: FreqSwpDirection,
+    ) -> Result<LockInFreqSwpResult, NanonisError> {
+        let get_data_flag = if get_data { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::LockInFreqSwpStart {
+                get_data: get_data_flag,
+                direction: direction.into(),
+            })
+            .await?;
+
+        let channel_names = response.channel_names;
+        let data_2d = response.data_2d;
+
+        Ok(LockInFreqSwpResult {
+            channel_names,
+            data: data_2d,
+        })
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_signal_set`](crate::NanonisClient::lockin_freq_swp_signal_set).
+    pub async fn lockin_freq_swp_signal_set(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::LockInFreqSwpSignalSet { signal_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_signal_get`](crate::NanonisClient::lockin_freq_swp_signal_get).
+    pub async fn lockin_freq_swp_signal_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::LockInFreqSwpSignalGet {}).await?;
+
+        Ok(response.signal_index)
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_limits_set`](crate::NanonisClient::lockin_freq_swp_limits_set).
+    pub async fn lockin_freq_swp_limits_set(
+        &mut self,
+        lower_limit_hz: f32,
+        upper_limit_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::LockInFreqSwpLimitsSet {
+            lower_limit_hz,
+            upper_limit_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_limits_get`](crate::NanonisClient::lockin_freq_swp_limits_get).
+    pub async fn lockin_freq_swp_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let response = self.send(&commands::LockInFreqSwpLimitsGet {}).await?;
+
+        Ok((response.lower_limit_hz, response.upper_limit_hz))
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_props_set`](crate::NanonisClient::lockin_freq_swp_props_set).
+    pub async fn lockin_freq_swp_props_set(
+        &mut self,
+        props: &LockInFreqSwpProps,
+    ) -> Result<(), NanonisError> {
+        let autosave_flag = if props.autosave { 1u32 } else { 0u32 };
+        let dialog_flag = if props.save_dialog { 1u32 } else { 0u32 };
+
+        self.send(&commands::LockInFreqSwpPropsSet {
+            num_steps: props.num_steps,
+            integration_periods: props.integration_periods,
+            min_integration_time_s: props.min_integration_time_s,
+            settling_periods: props.settling_periods,
+            min_settling_time_s: props.min_settling_time_s,
+            autosave: autosave_flag,
+            save_dialog: dialog_flag,
+            basename: props.basename.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::lockin_freq_swp_props_get`](crate::NanonisClient::lockin_freq_swp_props_get).
+    pub async fn lockin_freq_swp_props_get(&mut self) -> Result<LockInFreqSwpProps, NanonisError> {
+        let response = self.send(&commands::LockInFreqSwpPropsGet {}).await?;
+
+        Ok(LockInFreqSwpProps {
+            num_steps: response.num_steps,
+            integration_periods: response.integration_periods,
+            min_integration_time_s: response.min_integration_time_s,
+            settling_periods: response.settling_periods,
+            min_settling_time_s: response.min_settling_time_s,
+            autosave: response.autosave != 0,
+            save_dialog: response.save_dialog != 0,
+            basename: response.basename,
+        })
+    }
+}
@@ -0,0 +1,191 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::marks::{commands, LineMark, PointMark};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::marks_point_draw`](crate::NanonisClient::marks_point_draw).
+    pub async fn marks_point_draw(
+        &mut self,
+        x_m: f32,
+        y_m: f32,
+        text: &str,
+        color: u32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::MarksPointDraw {
+            x_m,
+            y_m,
+            text: text.to_string(),
+            color,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_points_draw`](crate::NanonisClient::marks_points_draw).
+    pub async fn marks_points_draw(
+        &mut self,
+        x_coords_m: &[f32],
+        y_coords_m: &[f32],
+        texts: &[String],
+        colors: &[u32],
+    ) -> Result<(), NanonisError> {
+        let num_points = x_coords_m.len() as i32;
+
+        self.send(&commands::MarksPointsDraw {
+            num_points,
+            x_coords_m: x_coords_m.to_vec(),
+            y_coords_m: y_coords_m.to_vec(),
+            texts: texts.to_vec(),
+            colors: colors.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_line_draw`](crate::NanonisClient::marks_line_draw).
+    pub async fn marks_line_draw(
+        &mut self,
+        start_x_m: f32,
+        start_y_m: f32,
+        end_x_m: f32,
+        end_y_m: f32,
+        color: u32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::MarksLineDraw {
+            start_x_m,
+            start_y_m,
+            end_x_m,
+            end_y_m,
+            color,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_lines_draw`](crate::NanonisClient::marks_lines_draw).
+    pub async fn marks_lines_draw(
+        &mut self,
+        start_x_m: &[f32],
+        start_y_m: &[f32],
+        end_x_m: &[f32],
+        end_y_m: &[f32],
+        colors: &[u32],
+    ) -> Result<(), NanonisError> {
+        let num_lines = start_x_m.len() as i32;
+
+        self.send(&commands::MarksLinesDraw {
+            num_lines,
+            start_x_m: start_x_m.to_vec(),
+            start_y_m: start_y_m.to_vec(),
+            end_x_m: end_x_m.to_vec(),
+            end_y_m: end_y_m.to_vec(),
+            colors: colors.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_points_erase`](crate::NanonisClient::marks_points_erase).
+    pub async fn marks_points_erase(&mut self, point_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::MarksPointsErase { point_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_lines_erase`](crate::NanonisClient::marks_lines_erase).
+    pub async fn marks_lines_erase(&mut self, line_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::MarksLinesErase { line_index }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_points_visible_set`](crate::NanonisClient::marks_points_visible_set).
+    pub async fn marks_points_visible_set(
+        &mut self,
+        point_index: i32,
+        visible: bool,
+    ) -> Result<(), NanonisError> {
+        // Note: 0 = visible, 1 = invisible in the protocol
+        let flag = if visible { 0u16 } else { 1u16 };
+        self.send(&commands::MarksPointsVisibleSet { point_index, flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_lines_visible_set`](crate::NanonisClient::marks_lines_visible_set).
+    pub async fn marks_lines_visible_set(
+        &mut self,
+        line_index: i32,
+        visible: bool,
+    ) -> Result<(), NanonisError> {
+        // Note: 0 = visible, 1 = invisible in the protocol
+        let flag = if visible { 0u16 } else { 1u16 };
+        self.send(&commands::MarksLinesVisibleSet { line_index, flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::marks_points_get`](crate::NanonisClient::marks_points_get).
+    pub async fn marks_points_get(&mut self) -> Result<Vec<PointMark>, NanonisError> {
+        let response = self.send(&commands::MarksPointsGet {}).await?;
+
+        let num_points = response.num_points as usize;
+        let x_coords = response.x_coords;
+        let y_coords = response.y_coords;
+        let texts = response.texts;
+        let colors = response.colors;
+        let visible = response.visible;
+
+        let mut points = Vec::with_capacity(num_points);
+        for i in 0..num_points {
+            if i < x_coords.len()
+                && i < y_coords.len()
+                && i < texts.len()
+                && i < colors.len()
+                && i < visible.len()
+            {
+                points.push(PointMark {
+                    x_m: x_coords[i],
+                    y_m: y_coords[i],
+                    text: texts[i].clone(),
+                    color: colors[i],
+                    visible: visible[i] != 0,
+                });
+            }
+        }
+        Ok(points)
+    }
+
+    /// Async version of [`NanonisClient::marks_lines_get`](crate::NanonisClient::marks_lines_get).
+    pub async fn marks_lines_get(&mut self) -> Result<Vec<LineMark>, NanonisError> {
+        let response = self.send(&commands::MarksLinesGet {}).await?;
+
+        let num_lines = response.num_lines as usize;
+        let start_x = response.start_x;
+        let start_y = response.start_y;
+        let end_x = response.end_x;
+        let end_y = response.end_y;
+        let colors = response.colors;
+        let visible = response.visible;
+
+        let mut lines = Vec::with_capacity(num_lines);
+        for i in 0..num_lines {
+            if i < start_x.len()
+                && i < start_y.len()
+                && i < end_x.len()
+                && i < end_y.len()
+                && i < colors.len()
+                && i < visible.len()
+            {
+                lines.push(LineMark {
+                    start_x_m: start_x[i],
+                    start_y_m: start_y[i],
+                    end_x_m: end_x[i],
+                    end_y_m: end_y[i],
+                    color: colors[i],
+                    visible: visible[i] != 0,
+                });
+            }
+        }
+        Ok(lines)
+    }
+}
@@ -0,0 +1,292 @@
+use crate::client::ConnectionConfig;
+use crate::client::signals::SignalRegistry;
+use crate::command::{Command, CommandResponse};
+use crate::error::NanonisError;
+use crate::protocol::{Protocol, HEADER_SIZE, MAX_RESPONSE_SIZE};
+use crate::types::NanonisValue;
+use log::{debug, warn};
+use std::future::Future;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+mod atom_track;
+mod auto_approach;
+mod beam_defl;
+mod bias;
+mod bias_spectr;
+mod bias_sweep;
+mod cpd_comp;
+mod current;
+mod data_log;
+mod dig_lines;
+mod folme;
+mod gen_pi_ctrl;
+mod gen_swp;
+mod hs_swp;
+mod interf;
+mod kelvin_ctrl;
+mod laser;
+mod lockin;
+mod lockin_freq_swp;
+mod marks;
+mod motor;
+mod mpass;
+mod oc_sync;
+mod oscilloscope;
+mod pattern;
+mod pi_ctrl;
+mod piezo;
+mod pll;
+mod pll_freq_swp;
+mod pll_signal_anlzr;
+mod safe_tip;
+mod scan;
+mod script;
+mod signal_chart;
+mod signals;
+mod spectrum_anlzr;
+mod stream;
+mod tcplog;
+mod tip_recovery;
+mod user_in;
+mod user_out;
+mod util;
+mod z_ctrl;
+mod z_spectr;
+
+pub use stream::{AsyncTCPLoggerStream, SignalFrameStream};
+
+/// Asynchronous client for communicating with Nanonis SPM systems via TCP.
+///
+/// Async counterpart of [`NanonisClient`](crate::NanonisClient) for tokio
+/// applications, available with the `tokio` cargo feature. It uses the same
+/// protocol serialization and the same typed command descriptors: every
+/// command method of `NanonisClient` has an `async fn` here with the same
+/// name, arguments and return type, and any descriptor can be sent with
+/// [`send`](Self::send). [`quick_send`](Self::quick_send) remains for
+/// commands the crate has no descriptor for.
+///
+/// Timeouts from [`ConnectionConfig`] apply to connecting and to every read
+/// and write.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{AsyncNanonisClient, MockServer, Volts};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), nanonis_rs::NanonisError> {
+/// let server = MockServer::simulated()?;
+/// let mut client = AsyncNanonisClient::connect("127.0.0.1", server.port()).await?;
+///
+/// client.bias_set(Volts::new(0.8)).await?;
+/// assert_eq!(client.bias_get().await?, 0.8);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncNanonisClient {
+    stream: TcpStream,
+    config: ConnectionConfig,
+    signal_registry: Option<SignalRegistry>,
+}
+
+impl AsyncNanonisClient {
+    /// Connect with the default configuration.
+    ///
+    /// # Arguments
+    /// * `addr` - Server address (e.g., "127.0.0.1")
+    /// * `port` - Server port (e.g., 6501)
+    pub async fn connect(addr: &str, port: u16) -> Result<Self, NanonisError> {
+        Self::connect_with_config(addr, port, ConnectionConfig::default()).await
+    }
+
+    /// Connect with custom timeouts.
+    pub async fn connect_with_config(
+        addr: &str,
+        port: u16,
+        config: ConnectionConfig,
+    ) -> Result<Self, NanonisError> {
+        debug!("Connecting to Nanonis at {addr}");
+
+        let stream = tokio::time::timeout(config.connect_timeout, TcpStream::connect((addr, port)))
+            .await
+            .map_err(|_| NanonisError::Timeout(format!("Connection to {addr} timed out")))?
+            .map_err(|e| {
+                warn!("Failed to connect to {addr}: {e}");
+                NanonisError::Io {
+                    source: e,
+                    context: format!("Failed to connect to {addr}"),
+                }
+            })?;
+        stream.set_nodelay(true)?;
+
+        debug!("Successfully connected to Nanonis");
+
+        Ok(Self {
+            stream,
+            config,
+            signal_registry: None,
+        })
+    }
+
+    /// Get the current connection configuration
+    pub fn config(&self) -> &ConnectionConfig {
+        &self.config
+    }
+
+    /// Send a quick command with minimal response handling.
+    ///
+    /// Async version of
+    /// [`NanonisClient::quick_send`](crate::NanonisClient::quick_send), and
+    /// the way to send commands without a typed descriptor.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{AsyncNanonisClient, MockServer, NanonisValue};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), nanonis_rs::NanonisError> {
+    /// let server = MockServer::simulated()?;
+    /// let mut client = AsyncNanonisClient::connect("127.0.0.1", server.port()).await?;
+    ///
+    /// // Raw values, the same reply as `motor_step_counter_get`
+    /// let counts = client
+    ///     .quick_send(
+    ///         "Motor.StepCounterGet",
+    ///         vec![NanonisValue::U32(0); 3],
+    ///         vec!["I", "I", "I"],
+    ///         vec!["i", "i", "i"],
+    ///     )
+    ///     .await?;
+    /// assert_eq!(counts.len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quick_send(
+        &mut self,
+        command: &str,
+        args: Vec<NanonisValue>,
+        argument_types: Vec<&str>,
+        return_types: Vec<&str>,
+    ) -> Result<Vec<NanonisValue>, NanonisError> {
+        let read_timeout = self.config.read_timeout;
+        self.exchange(command, args, argument_types, return_types, read_timeout)
+            .await
+    }
+
+    /// Write one command and read its reply, waiting at most `read_timeout`
+    /// for each read.
+    async fn exchange(
+        &mut self,
+        command: &str,
+        args: Vec<NanonisValue>,
+        argument_types: Vec<&str>,
+        return_types: Vec<&str>,
+        read_timeout: Duration,
+    ) -> Result<Vec<NanonisValue>, NanonisError> {
+        debug!("=== COMMAND START: {} ===", command);
+
+        let mut message = Protocol::create_command_header(command, 0);
+        for (arg, arg_type) in args.iter().zip(argument_types.iter()) {
+            Protocol::serialize_value(arg, arg_type, &mut message)?;
+        }
+        let body_size = (message.len() - HEADER_SIZE) as u32;
+        message[32..36].copy_from_slice(&body_size.to_be_bytes());
+
+        let write_timeout = self.config.write_timeout;
+        with_timeout(
+            write_timeout,
+            "Writing command",
+            self.stream.write_all(&message),
+        )
+        .await?;
+
+        let mut response_header = [0u8; HEADER_SIZE];
+        with_timeout(
+            read_timeout,
+            "Reading response header",
+            self.stream.read_exact(&mut response_header),
+        )
+        .await?;
+
+        let body_size = Protocol::validate_response_header(&response_header, command)? as usize;
+        if body_size > MAX_RESPONSE_SIZE {
+            return Err(NanonisError::Protocol(format!(
+                "Response size {} exceeds maximum {}",
+                body_size, MAX_RESPONSE_SIZE
+            )));
+        }
+
+        let mut response_body = vec![0u8; body_size];
+        with_timeout(
+            read_timeout,
+            "Reading response body",
+            self.stream.read_exact(&mut response_body),
+        )
+        .await?;
+
+        let result = Protocol::parse_response_with_error_check(&response_body, &return_types)?;
+
+        debug!("=== COMMAND SUCCESS: {} ===", command);
+        Ok(result)
+    }
+
+    /// Send a typed command and decode its response.
+    ///
+    /// Async version of [`NanonisClient::send`](crate::NanonisClient::send).
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{AsyncNanonisClient, MockServer};
+    /// use nanonis_rs::z_ctrl::commands::ZCtrlZPosGet;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), nanonis_rs::NanonisError> {
+    /// let server = MockServer::simulated()?;
+    /// let mut client = AsyncNanonisClient::connect("127.0.0.1", server.port()).await?;
+    ///
+    /// let z = client.send(&ZCtrlZPosGet {}).await?.z_position;
+    /// assert!(z.is_finite());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send<C: Command>(&mut self, command: &C) -> Result<C::Response, NanonisError> {
+        let read_timeout = self.config.read_timeout;
+        self.send_with_read_timeout(command, read_timeout).await
+    }
+
+    /// Send a typed command whose reply may take longer than the configured
+    /// read timeout, e.g. because the server waits for an operation to end.
+    async fn send_with_read_timeout<C: Command>(
+        &mut self,
+        command: &C,
+        read_timeout: Duration,
+    ) -> Result<C::Response, NanonisError> {
+        let values = self
+            .exchange(
+                C::NAME,
+                command.args(),
+                C::ARG_TYPES.to_vec(),
+                C::RESPONSE_TYPES.to_vec(),
+                read_timeout,
+            )
+            .await?;
+        C::Response::from_values(values)
+    }
+}
+
+/// Run an I/O future, mapping elapsed timeouts and I/O errors to
+/// [`NanonisError`].
+async fn with_timeout<T>(
+    timeout: Duration,
+    context: &str,
+    future: impl Future<Output = std::io::Result<T>>,
+) -> Result<T, NanonisError> {
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_| NanonisError::Timeout(format!("{context} timed out")))?
+        .map_err(|e| NanonisError::Io {
+            source: e,
+            context: context.to_string(),
+        })
+}
@@ -0,0 +1,125 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::motor::{
+    commands, Amplitude, Frequency, MotorAxis, MotorDirection, MotorGroup, MovementMode, Position3D,
+};
+use std::time::Duration;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::motor_start_move`](crate::NanonisClient::motor_start_move).
+    pub async fn motor_start_move(
+        &mut self,
+        direction: impl Into<MotorDirection>,
+        number_of_steps: impl Into<u16>,
+        group: impl Into<MotorGroup>,
+        wait_until_finished: bool,
+    ) -> Result<(), NanonisError> {
+        let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
+        self.send(&commands::MotorStartMove {
+            direction: direction.into().into(),
+            number_of_steps: number_of_steps.into(),
+            group: group.into().into(),
+            wait_until_finished: wait_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::motor_start_closed_loop`](crate::NanonisClient::motor_start_closed_loop).
+    pub async fn motor_start_closed_loop(
+        &mut self,
+        movement_mode: MovementMode,
+        target_position: Position3D,
+        wait_until_finished: bool,
+        group: MotorGroup,
+    ) -> Result<(), NanonisError> {
+        let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
+        self.send(&commands::MotorStartClosedLoop {
+            movement_mode: movement_mode.into(),
+            x: target_position.x,
+            y: target_position.y,
+            z: target_position.z,
+            wait_until_finished: wait_flag,
+            group: group.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::motor_stop_move`](crate::NanonisClient::motor_stop_move).
+    pub async fn motor_stop_move(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::MotorStopMove {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::motor_pos_get`](crate::NanonisClient::motor_pos_get).
+    pub async fn motor_pos_get(
+        &mut self,
+        group: MotorGroup,
+        timeout: Duration,
+    ) -> Result<Position3D, NanonisError> {
+        let response = self
+            .send(&commands::MotorPosGet {
+                group: group.into(),
+                timeout: timeout.as_millis() as u32,
+            })
+            .await?;
+
+        let x = response.x;
+        let y = response.y;
+        let z = response.z;
+        Ok(Position3D::new(x, y, z))
+    }
+
+    /// Async version of [`NanonisClient::motor_step_counter_get`](crate::NanonisClient::motor_step_counter_get).
+    pub async fn motor_step_counter_get(
+        &mut self,
+        reset_x: bool,
+        reset_y: bool,
+        reset_z: bool,
+    ) -> Result<(i32, i32, i32), NanonisError> {
+        let reset_x_flag = if reset_x { 1u32 } else { 0u32 };
+        let reset_y_flag = if reset_y { 1u32 } else { 0u32 };
+        let reset_z_flag = if reset_z { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::MotorStepCounterGet {
+                reset_x: reset_x_flag,
+                reset_y: reset_y_flag,
+                reset_z: reset_z_flag,
+            })
+            .await?;
+
+        Ok((response.step_x, response.step_y, response.step_z))
+    }
+
+    /// Async version of [`NanonisClient::motor_freq_amp_get`](crate::NanonisClient::motor_freq_amp_get).
+    pub async fn motor_freq_amp_get(
+        &mut self,
+        axis: MotorAxis,
+    ) -> Result<(Frequency, Amplitude), NanonisError> {
+        let response = self
+            .send(&commands::MotorFreqAmpGet { axis: axis.into() })
+            .await?;
+
+        let frequency = Frequency::hz(response.frequency);
+        let amplitude = Amplitude::volts(response.amplitude);
+        Ok((frequency, amplitude))
+    }
+
+    /// Async version of [`NanonisClient::motor_freq_amp_set`](crate::NanonisClient::motor_freq_amp_set).
+    pub async fn motor_freq_amp_set(
+        &mut self,
+        frequency: impl Into<Frequency>,
+        amplitude: impl Into<Amplitude>,
+        axis: impl Into<MotorAxis>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::MotorFreqAmpSet {
+            frequency: frequency.into().into(),
+            amplitude: amplitude.into().into(),
+            axis: axis.into().into(),
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::mpass::commands;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::mpass_activate`](crate::NanonisClient::mpass_activate).
+    pub async fn mpass_activate(&mut self, on: bool) -> Result<(), NanonisError> {
+        self.send(&commands::MPassActivate {
+            on: if on { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::mpass_load`](crate::NanonisClient::mpass_load).
+    pub async fn mpass_load(&mut self, file_path: &str) -> Result<(), NanonisError> {
+        self.send(&commands::MPassLoad {
+            file_path: file_path.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::mpass_save`](crate::NanonisClient::mpass_save).
+    pub async fn mpass_save(&mut self, file_path: &str) -> Result<(), NanonisError> {
+        self.send(&commands::MPassSave {
+            file_path: file_path.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::oc_sync::{commands, LinkAnglesMode, OCSyncAngles, OCSyncLinkStatus};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::oc_sync_angles_set`](crate::NanonisClient::oc_sync_angles_set).
+    pub async fn oc_sync_angles_set(&mut self, angles: &OCSyncAngles) -> Result<(), NanonisError> {
+        self.send(&commands::OcSyncAnglesSet {
+            ch1_on_deg: angles.ch1_on_deg,
+            ch1_off_deg: angles.ch1_off_deg,
+            ch2_on_deg: angles.ch2_on_deg,
+            ch2_off_deg: angles.ch2_off_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::oc_sync_angles_get`](crate::NanonisClient::oc_sync_angles_get).
+    pub async fn oc_sync_angles_get(&mut self) -> Result<OCSyncAngles, NanonisError> {
+        let response = self.send(&commands::OcSyncAnglesGet {}).await?;
+
+        Ok(OCSyncAngles {
+            ch1_on_deg: response.ch1_on_deg,
+            ch1_off_deg: response.ch1_off_deg,
+            ch2_on_deg: response.ch2_on_deg,
+            ch2_off_deg: response.ch2_off_deg,
+        })
+    }
+
+    /// Async version of [`NanonisClient::oc_sync_link_angles_set`](crate::NanonisClient::oc_sync_link_angles_set).
+    pub async fn oc_sync_link_angles_set(
+        &mut self,
+        ch1_mode: LinkAnglesMode,
+        ch2_mode: LinkAnglesMode,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OcSyncLinkAnglesSet {
+            ch1_mode: ch1_mode.into(),
+            ch2_mode: ch2_mode.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::oc_sync_link_angles_get`](crate::NanonisClient::oc_sync_link_angles_get).
+    pub async fn oc_sync_link_angles_get(&mut self) -> Result<OCSyncLinkStatus, NanonisError> {
+        let response = self.send(&commands::OcSyncLinkAnglesGet {}).await?;
+
+        Ok(OCSyncLinkStatus {
+            ch1_linked: response.ch1_linked != 0,
+            ch2_linked: response.ch2_linked != 0,
+        })
+    }
+}
@@ -0,0 +1,597 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::oscilloscope::{commands, OscilloscopeIndex, SampleCount, TriggerLevel, TriggerMode};
+use crate::signals::SlotRef;
+use crate::signals::{SignalIndex, SignalRef};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::osci1t_ch_set`](crate::NanonisClient::osci1t_ch_set).
+    pub async fn osci1t_ch_set(&mut self, channel: impl Into<SlotRef>) -> Result<(), NanonisError> {
+        let channel_index = self.resolve_slot(channel).await?;
+        self.send(&commands::Osci1TChSet { channel_index }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci1t_ch_get`](crate::NanonisClient::osci1t_ch_get).
+    pub async fn osci1t_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::Osci1TChGet {}).await?;
+        Ok(response.channel_index)
+    }
+
+    /// Async version of [`NanonisClient::osci1t_timebase_set`](crate::NanonisClient::osci1t_timebase_set).
+    pub async fn osci1t_timebase_set(&mut self, timebase_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::Osci1TTimebaseSet { timebase_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci1t_timebase_get`](crate::NanonisClient::osci1t_timebase_get).
+    pub async fn osci1t_timebase_get(&mut self) -> Result<(i32, Vec<f32>), NanonisError> {
+        let response = self.send(&commands::Osci1TTimebaseGet {}).await?;
+        Ok((response.timebase_index, response.timebases))
+    }
+
+    /// Async version of [`NanonisClient::osci1t_trig_set`](crate::NanonisClient::osci1t_trig_set).
+    pub async fn osci1t_trig_set(
+        &mut self,
+        trigger_mode: u16,
+        trigger_slope: u16,
+        trigger_level: f64,
+        trigger_hysteresis: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::Osci1TTrigSet {
+            trigger_mode,
+            trigger_slope,
+            trigger_level,
+            trigger_hysteresis,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci1t_trig_get`](crate::NanonisClient::osci1t_trig_get).
+    pub async fn osci1t_trig_get(&mut self) -> Result<(u16, u16, f64, f64), NanonisError> {
+        let response = self.send(&commands::Osci1TTrigGet {}).await?;
+        let trigger_mode = response.trigger_mode;
+        let trigger_slope = response.trigger_slope;
+        let trigger_level = response.trigger_level;
+        let trigger_hysteresis = response.trigger_hysteresis;
+        Ok((
+            trigger_mode,
+            trigger_slope,
+            trigger_level,
+            trigger_hysteresis,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::osci1t_run`](crate::NanonisClient::osci1t_run).
+    pub async fn osci1t_run(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::Osci1TRun {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci1t_data_get`](crate::NanonisClient::osci1t_data_get).
+    pub async fn osci1t_data_get(
+        &mut self,
+        data_to_get: u16,
+    ) -> Result<(f64, f64, i32, Vec<f64>), NanonisError> {
+        let response = self.send(&commands::Osci1TDataGet { data_to_get }).await?;
+
+        Ok((response.t0, response.dt, response.size, response.data))
+    }
+
+    /// Async version of [`NanonisClient::osci2t_ch_set`](crate::NanonisClient::osci2t_ch_set).
+    pub async fn osci2t_ch_set(
+        &mut self,
+        channel_a: impl Into<SlotRef>,
+        channel_b: impl Into<SlotRef>,
+    ) -> Result<(), NanonisError> {
+        let channel_a_index = self.resolve_slot(channel_a).await?;
+        let channel_b_index = self.resolve_slot(channel_b).await?;
+        self.send(&commands::Osci2TChSet {
+            channel_a_index,
+            channel_b_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci2t_ch_get`](crate::NanonisClient::osci2t_ch_get).
+    pub async fn osci2t_ch_get(&mut self) -> Result<(i32, i32), NanonisError> {
+        let response = self.send(&commands::Osci2TChGet {}).await?;
+        Ok((response.channel_a, response.channel_b))
+    }
+
+    /// Async version of [`NanonisClient::osci2t_timebase_set`](crate::NanonisClient::osci2t_timebase_set).
+    pub async fn osci2t_timebase_set(&mut self, timebase_index: u16) -> Result<(), NanonisError> {
+        self.send(&commands::Osci2TTimebaseSet { timebase_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci2t_timebase_get`](crate::NanonisClient::osci2t_timebase_get).
+    pub async fn osci2t_timebase_get(&mut self) -> Result<(u16, Vec<f32>), NanonisError> {
+        let response = self.send(&commands::Osci2TTimebaseGet {}).await?;
+        Ok((response.timebase_index, response.timebases))
+    }
+
+    /// Async version of [`NanonisClient::osci2t_oversampl_set`](crate::NanonisClient::osci2t_oversampl_set).
+    pub async fn osci2t_oversampl_set(
+        &mut self,
+        oversampling_index: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::Osci2TOversamplSet { oversampling_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci2t_oversampl_get`](crate::NanonisClient::osci2t_oversampl_get).
+    pub async fn osci2t_oversampl_get(&mut self) -> Result<u16, NanonisError> {
+        let response = self.send(&commands::Osci2TOversamplGet {}).await?;
+        Ok(response.oversampling_index)
+    }
+
+    /// Async version of [`NanonisClient::osci2t_trig_set`](crate::NanonisClient::osci2t_trig_set).
+    pub async fn osci2t_trig_set(
+        &mut self,
+        trigger_mode: u16,
+        trig_channel: u16,
+        trigger_slope: u16,
+        trigger_level: f64,
+        trigger_hysteresis: f64,
+        trig_position: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::Osci2TTrigSet {
+            trigger_mode,
+            trig_channel,
+            trigger_slope,
+            trigger_level,
+            trigger_hysteresis,
+            trig_position,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci2t_trig_get`](crate::NanonisClient::osci2t_trig_get).
+    pub async fn osci2t_trig_get(
+        &mut self,
+    ) -> Result<(u16, u16, u16, f64, f64, f64), NanonisError> {
+        let response = self.send(&commands::Osci2TTrigGet {}).await?;
+        let trigger_mode = response.trigger_mode;
+        let trig_channel = response.trig_channel;
+        let trigger_slope = response.trigger_slope;
+        let trigger_level = response.trigger_level;
+        let trigger_hysteresis = response.trigger_hysteresis;
+        let trig_position = response.trig_position;
+        Ok((
+            trigger_mode,
+            trig_channel,
+            trigger_slope,
+            trigger_level,
+            trigger_hysteresis,
+            trig_position,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::osci2t_run`](crate::NanonisClient::osci2t_run).
+    pub async fn osci2t_run(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::Osci2TRun {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci2t_data_get`](crate::NanonisClient::osci2t_data_get).
+    pub async fn osci2t_data_get(
+        &mut self,
+        data_to_get: u16,
+    ) -> Result<(f64, f64, Vec<f64>, Vec<f64>), NanonisError> {
+        let response = self.send(&commands::Osci2TDataGet { data_to_get }).await?;
+
+        Ok((
+            response.t0,
+            response.dt,
+            response.channel_a_data,
+            response.channel_b_data,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_ch_set`](crate::NanonisClient::osci_hr_ch_set).
+    pub async fn osci_hr_ch_set(
+        &mut self,
+        osci_index: impl Into<OscilloscopeIndex>,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::OsciHrChSet {
+            osci_index: osci_index.into().into(),
+            signal_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_ch_get`](crate::NanonisClient::osci_hr_ch_get).
+    pub async fn osci_hr_ch_get(
+        &mut self,
+        osci_index: impl Into<OscilloscopeIndex>,
+    ) -> Result<SignalIndex, NanonisError> {
+        let response = self
+            .send(&commands::OsciHrChGet {
+                osci_index: osci_index.into().into(),
+            })
+            .await?;
+        Ok(SignalIndex::new(response.signal_index as u8))
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_oversampl_set`](crate::NanonisClient::osci_hr_oversampl_set).
+    pub async fn osci_hr_oversampl_set(
+        &mut self,
+        oversampling_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrOversamplSet { oversampling_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_oversampl_get`](crate::NanonisClient::osci_hr_oversampl_get).
+    pub async fn osci_hr_oversampl_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::OsciHrOversamplGet {}).await?;
+        Ok(response.oversampling_index)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_calibr_mode_set`](crate::NanonisClient::osci_hr_calibr_mode_set).
+    pub async fn osci_hr_calibr_mode_set(
+        &mut self,
+        osci_index: i32,
+        calibration_mode: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrCalibrModeSet {
+            osci_index,
+            calibration_mode,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_calibr_mode_get`](crate::NanonisClient::osci_hr_calibr_mode_get).
+    pub async fn osci_hr_calibr_mode_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
+        let response = self
+            .send(&commands::OsciHrCalibrModeGet { osci_index })
+            .await?;
+        Ok(response.calibration_mode)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_samples_set`](crate::NanonisClient::osci_hr_samples_set).
+    pub async fn osci_hr_samples_set(
+        &mut self,
+        number_of_samples: impl Into<SampleCount>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrSamplesSet {
+            number_of_samples: number_of_samples.into().into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_samples_get`](crate::NanonisClient::osci_hr_samples_get).
+    pub async fn osci_hr_samples_get(&mut self) -> Result<SampleCount, NanonisError> {
+        let response = self.send(&commands::OsciHrSamplesGet {}).await?;
+        Ok(SampleCount::new(response.number_of_samples))
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_pre_trig_set`](crate::NanonisClient::osci_hr_pre_trig_set).
+    pub async fn osci_hr_pre_trig_set(
+        &mut self,
+        pre_trigger_samples: u32,
+        pre_trigger_s: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPreTrigSet {
+            pre_trigger_samples,
+            pre_trigger_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_pre_trig_get`](crate::NanonisClient::osci_hr_pre_trig_get).
+    pub async fn osci_hr_pre_trig_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::OsciHrPreTrigGet {}).await?;
+        Ok(response.pre_trigger_samples)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_run`](crate::NanonisClient::osci_hr_run).
+    pub async fn osci_hr_run(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrRun {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_osci_data_get`](crate::NanonisClient::osci_hr_osci_data_get).
+    pub async fn osci_hr_osci_data_get(
+        &mut self,
+        osci_index: i32,
+        data_to_get: u16,
+        timeout_s: f64,
+    ) -> Result<(String, f64, Vec<f32>, bool), NanonisError> {
+        let response = self
+            .send(&commands::OsciHrOsciDataGet {
+                osci_index,
+                data_to_get,
+                timeout_s,
+            })
+            .await?;
+
+        let timestamp = response.timestamp;
+        let time_delta = response.time_delta;
+        let data_values = response.data_values;
+        let timeout_occurred = response.timeout_occurred == 1;
+        Ok((timestamp, time_delta, data_values, timeout_occurred))
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_mode_set`](crate::NanonisClient::osci_hr_trig_mode_set).
+    pub async fn osci_hr_trig_mode_set(
+        &mut self,
+        trigger_mode: impl Into<TriggerMode>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigModeSet {
+            trigger_mode: trigger_mode.into().into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_mode_get`](crate::NanonisClient::osci_hr_trig_mode_get).
+    pub async fn osci_hr_trig_mode_get(&mut self) -> Result<TriggerMode, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigModeGet {}).await?;
+        let mode_val = response.trigger_mode;
+        match mode_val {
+            0 => Ok(TriggerMode::Immediate),
+            1 => Ok(TriggerMode::Level),
+            2 => Ok(TriggerMode::Digital),
+            _ => Err(NanonisError::Protocol(format!(
+                "Unknown trigger mode: {}",
+                mode_val
+            ))),
+        }
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_ch_set`](crate::NanonisClient::osci_hr_trig_lev_ch_set).
+    pub async fn osci_hr_trig_lev_ch_set(
+        &mut self,
+        level_trigger_channel: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let level_trigger_channel_index = self.resolve_signal(level_trigger_channel).await?;
+        self.send(&commands::OsciHrTrigLevChSet {
+            level_trigger_channel_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_ch_get`](crate::NanonisClient::osci_hr_trig_lev_ch_get).
+    pub async fn osci_hr_trig_lev_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigLevChGet {}).await?;
+        Ok(response.level_trigger_channel_index)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_val_set`](crate::NanonisClient::osci_hr_trig_lev_val_set).
+    pub async fn osci_hr_trig_lev_val_set(
+        &mut self,
+        level_trigger_value: impl Into<TriggerLevel>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigLevValSet {
+            level_trigger_value: level_trigger_value.into().into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_val_get`](crate::NanonisClient::osci_hr_trig_lev_val_get).
+    pub async fn osci_hr_trig_lev_val_get(&mut self) -> Result<TriggerLevel, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigLevValGet {}).await?;
+        Ok(TriggerLevel(response.level_trigger_value))
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_arm_mode_set`](crate::NanonisClient::osci_hr_trig_arm_mode_set).
+    pub async fn osci_hr_trig_arm_mode_set(
+        &mut self,
+        trigger_arming_mode: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigArmModeSet {
+            trigger_arming_mode,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_arm_mode_get`](crate::NanonisClient::osci_hr_trig_arm_mode_get).
+    pub async fn osci_hr_trig_arm_mode_get(&mut self) -> Result<u16, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigArmModeGet {}).await?;
+        Ok(response.trigger_arming_mode)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_hyst_set`](crate::NanonisClient::osci_hr_trig_lev_hyst_set).
+    pub async fn osci_hr_trig_lev_hyst_set(&mut self, hysteresis: f64) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigLevHystSet { hysteresis })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_hyst_get`](crate::NanonisClient::osci_hr_trig_lev_hyst_get).
+    pub async fn osci_hr_trig_lev_hyst_get(&mut self) -> Result<f64, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigLevHystGet {}).await?;
+        Ok(response.hysteresis)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_slope_set`](crate::NanonisClient::osci_hr_trig_lev_slope_set).
+    pub async fn osci_hr_trig_lev_slope_set(&mut self, slope: u16) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigLevSlopeSet { slope })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_lev_slope_get`](crate::NanonisClient::osci_hr_trig_lev_slope_get).
+    pub async fn osci_hr_trig_lev_slope_get(&mut self) -> Result<u16, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigLevSlopeGet {}).await?;
+        Ok(response.slope)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_dig_ch_set`](crate::NanonisClient::osci_hr_trig_dig_ch_set).
+    pub async fn osci_hr_trig_dig_ch_set(
+        &mut self,
+        digital_trigger_channel: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigDigChSet {
+            digital_trigger_channel,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_dig_ch_get`](crate::NanonisClient::osci_hr_trig_dig_ch_get).
+    pub async fn osci_hr_trig_dig_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigDigChGet {}).await?;
+        Ok(response.digital_trigger_channel)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_dig_slope_set`](crate::NanonisClient::osci_hr_trig_dig_slope_set).
+    pub async fn osci_hr_trig_dig_slope_set(&mut self, slope: u16) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigDigSlopeSet { slope })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_dig_slope_get`](crate::NanonisClient::osci_hr_trig_dig_slope_get).
+    pub async fn osci_hr_trig_dig_slope_get(&mut self) -> Result<u16, NanonisError> {
+        let response = self.send(&commands::OsciHrTrigDigSlopeGet {}).await?;
+        Ok(response.slope)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_trig_rearm`](crate::NanonisClient::osci_hr_trig_rearm).
+    pub async fn osci_hr_trig_rearm(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrTrigRearm {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_show`](crate::NanonisClient::osci_hr_psd_show).
+    pub async fn osci_hr_psd_show(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPsdShow {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_weight_set`](crate::NanonisClient::osci_hr_psd_weight_set).
+    pub async fn osci_hr_psd_weight_set(
+        &mut self,
+        osci_index: i32,
+        weighting: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPsdWeightSet {
+            osci_index,
+            weighting,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_weight_get`](crate::NanonisClient::osci_hr_psd_weight_get).
+    pub async fn osci_hr_psd_weight_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
+        let response = self
+            .send(&commands::OsciHrPsdWeightGet { osci_index })
+            .await?;
+        Ok(response.weighting)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_window_set`](crate::NanonisClient::osci_hr_psd_window_set).
+    pub async fn osci_hr_psd_window_set(
+        &mut self,
+        osci_index: i32,
+        window: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPsdWindowSet { osci_index, window })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_window_get`](crate::NanonisClient::osci_hr_psd_window_get).
+    pub async fn osci_hr_psd_window_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
+        let response = self
+            .send(&commands::OsciHrPsdWindowGet { osci_index })
+            .await?;
+        Ok(response.window)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_avrg_type_set`](crate::NanonisClient::osci_hr_psd_avrg_type_set).
+    pub async fn osci_hr_psd_avrg_type_set(
+        &mut self,
+        osci_index: i32,
+        averaging_type: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPsdAvrgTypeSet {
+            osci_index,
+            averaging_type,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_avrg_type_get`](crate::NanonisClient::osci_hr_psd_avrg_type_get).
+    pub async fn osci_hr_psd_avrg_type_get(
+        &mut self,
+        osci_index: i32,
+    ) -> Result<u16, NanonisError> {
+        let response = self
+            .send(&commands::OsciHrPsdAvrgTypeGet { osci_index })
+            .await?;
+        Ok(response.averaging_type)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_avrg_count_set`](crate::NanonisClient::osci_hr_psd_avrg_count_set).
+    pub async fn osci_hr_psd_avrg_count_set(
+        &mut self,
+        osci_index: i32,
+        count: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPsdAvrgCountSet { osci_index, count })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_avrg_count_get`](crate::NanonisClient::osci_hr_psd_avrg_count_get).
+    pub async fn osci_hr_psd_avrg_count_get(
+        &mut self,
+        osci_index: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::OsciHrPsdAvrgCountGet { osci_index })
+            .await?;
+        Ok(response.count)
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_avrg_restart`](crate::NanonisClient::osci_hr_psd_avrg_restart).
+    pub async fn osci_hr_psd_avrg_restart(&mut self, osci_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::OsciHrPsdAvrgRestart { osci_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::osci_hr_psd_data_get`](crate::NanonisClient::osci_hr_psd_data_get).
+    pub async fn osci_hr_psd_data_get(
+        &mut self,
+        osci_index: i32,
+        data_to_get: u16,
+        timeout_s: f64,
+    ) -> Result<(f64, f64, Vec<f32>, bool), NanonisError> {
+        let response = self
+            .send(&commands::OsciHrPsdDataGet {
+                osci_index,
+                data_to_get,
+                timeout_s,
+            })
+            .await?;
+
+        let frequency_start = response.frequency_start;
+        let frequency_delta = response.frequency_delta;
+        let psd_data = response.psd_data;
+        let timeout_occurred = response.timeout_occurred == 1;
+        Ok((frequency_start, frequency_delta, psd_data, timeout_occurred))
+    }
+}
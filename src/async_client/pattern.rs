@@ -0,0 +1,181 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::pattern::{commands, CloudConfig, GridConfig, LineConfig, PatternProps, PatternType};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::pattern_exp_open`](crate::NanonisClient::pattern_exp_open).
+    pub async fn pattern_exp_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PatternExpOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_exp_start`](crate::NanonisClient::pattern_exp_start).
+    pub async fn pattern_exp_start(&mut self, pattern: PatternType) -> Result<(), NanonisError> {
+        self.send(&commands::PatternExpStart {
+            pattern: pattern.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_exp_pause`](crate::NanonisClient::pattern_exp_pause).
+    pub async fn pattern_exp_pause(&mut self, pause: bool) -> Result<(), NanonisError> {
+        let flag = if pause { 1u32 } else { 0u32 };
+        self.send(&commands::PatternExpPause { pause: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_exp_stop`](crate::NanonisClient::pattern_exp_stop).
+    pub async fn pattern_exp_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PatternExpStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_exp_status_get`](crate::NanonisClient::pattern_exp_status_get).
+    pub async fn pattern_exp_status_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::PatternExpStatusGet {}).await?;
+
+        Ok(response.running != 0)
+    }
+
+    /// Async version of [`NanonisClient::pattern_grid_set`](crate::NanonisClient::pattern_grid_set).
+    pub async fn pattern_grid_set(
+        &mut self,
+        set_active: bool,
+        config: &GridConfig,
+        use_scan_frame: bool,
+    ) -> Result<(), NanonisError> {
+        let active_flag = if set_active { 1u32 } else { 0u32 };
+        let frame_flag = if use_scan_frame { 1u32 } else { 0u32 };
+
+        self.send(&commands::PatternGridSet {
+            set_active: active_flag,
+            num_points_x: config.num_points_x,
+            num_points_y: config.num_points_y,
+            use_scan_frame: frame_flag,
+            center_x_m: config.center_x_m,
+            center_y_m: config.center_y_m,
+            width_m: config.width_m,
+            height_m: config.height_m,
+            angle_deg: config.angle_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_grid_get`](crate::NanonisClient::pattern_grid_get).
+    pub async fn pattern_grid_get(&mut self) -> Result<GridConfig, NanonisError> {
+        let response = self.send(&commands::PatternGridGet {}).await?;
+
+        Ok(GridConfig {
+            num_points_x: response.num_points_x,
+            num_points_y: response.num_points_y,
+            center_x_m: response.center_x_m,
+            center_y_m: response.center_y_m,
+            width_m: response.width_m,
+            height_m: response.height_m,
+            angle_deg: response.angle_deg,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pattern_line_set`](crate::NanonisClient::pattern_line_set).
+    pub async fn pattern_line_set(
+        &mut self,
+        set_active: bool,
+        config: &LineConfig,
+        use_scan_frame: bool,
+    ) -> Result<(), NanonisError> {
+        let active_flag = if set_active { 1u32 } else { 0u32 };
+        let frame_flag = if use_scan_frame { 1u32 } else { 0u32 };
+
+        self.send(&commands::PatternLineSet {
+            set_active: active_flag,
+            num_points: config.num_points,
+            use_scan_frame: frame_flag,
+            point1_x_m: config.point1_x_m,
+            point1_y_m: config.point1_y_m,
+            point2_x_m: config.point2_x_m,
+            point2_y_m: config.point2_y_m,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_line_get`](crate::NanonisClient::pattern_line_get).
+    pub async fn pattern_line_get(&mut self) -> Result<LineConfig, NanonisError> {
+        let response = self.send(&commands::PatternLineGet {}).await?;
+
+        Ok(LineConfig {
+            num_points: response.num_points,
+            point1_x_m: response.point1_x_m,
+            point1_y_m: response.point1_y_m,
+            point2_x_m: response.point2_x_m,
+            point2_y_m: response.point2_y_m,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pattern_cloud_set`](crate::NanonisClient::pattern_cloud_set).
+    pub async fn pattern_cloud_set(
+        &mut self,
+        set_active: bool,
+        config: &CloudConfig,
+    ) -> Result<(), NanonisError> {
+        let active_flag = if set_active { 1u32 } else { 0u32 };
+        let num_points = config.x_coords_m.len() as i32;
+
+        self.send(&commands::PatternCloudSet {
+            set_active: active_flag,
+            num_points,
+            x_coords_m: config.x_coords_m.clone(),
+            y_coords_m: config.y_coords_m.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_cloud_get`](crate::NanonisClient::pattern_cloud_get).
+    pub async fn pattern_cloud_get(&mut self) -> Result<CloudConfig, NanonisError> {
+        let response = self.send(&commands::PatternCloudGet {}).await?;
+
+        Ok(CloudConfig {
+            x_coords_m: response.x_coords_m,
+            y_coords_m: response.y_coords_m,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pattern_props_set`](crate::NanonisClient::pattern_props_set).
+    pub async fn pattern_props_set(
+        &mut self,
+        selected_experiment: &str,
+        basename: &str,
+        external_vi_path: &str,
+        pre_measure_delay_s: f32,
+        save_scan_channels: bool,
+    ) -> Result<(), NanonisError> {
+        let save_flag = if save_scan_channels { 1u32 } else { 0u32 };
+
+        self.send(&commands::PatternPropsSet {
+            selected_experiment: selected_experiment.to_string(),
+            basename: basename.to_string(),
+            external_vi_path: external_vi_path.to_string(),
+            pre_measure_delay_s,
+            save_scan_channels: save_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pattern_props_get`](crate::NanonisClient::pattern_props_get).
+    pub async fn pattern_props_get(&mut self) -> Result<PatternProps, NanonisError> {
+        let response = self.send(&commands::PatternPropsGet {}).await?;
+
+        Ok(PatternProps {
+            available_experiments: response.available_experiments,
+            selected_experiment: response.selected_experiment,
+            external_vi_path: response.external_vi_path,
+            pre_measure_delay_s: response.pre_measure_delay_s,
+            save_scan_channels: response.save_scan_channels != 0,
+        })
+    }
+}
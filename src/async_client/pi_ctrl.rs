@@ -0,0 +1,159 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::pi_ctrl::{commands, ControlSignalInfo, PICtrlLimits, PICtrlProps};
+use crate::signals::SignalRef;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::pi_ctrl_on_off_set`](crate::NanonisClient::pi_ctrl_on_off_set).
+    pub async fn pi_ctrl_on_off_set(
+        &mut self,
+        controller_index: i32,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        let status = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::PiCtrlOnOffSet {
+            controller_index,
+            enabled: status,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_on_off_get`](crate::NanonisClient::pi_ctrl_on_off_get).
+    pub async fn pi_ctrl_on_off_get(
+        &mut self,
+        controller_index: i32,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::PiCtrlOnOffGet { controller_index })
+            .await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_ctrl_ch_set`](crate::NanonisClient::pi_ctrl_ctrl_ch_set).
+    pub async fn pi_ctrl_ctrl_ch_set(
+        &mut self,
+        controller_index: i32,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::PiCtrlCtrlChSet {
+            controller_index,
+            signal_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_ctrl_ch_get`](crate::NanonisClient::pi_ctrl_ctrl_ch_get).
+    pub async fn pi_ctrl_ctrl_ch_get(
+        &mut self,
+        controller_index: i32,
+    ) -> Result<ControlSignalInfo, NanonisError> {
+        let response = self
+            .send(&commands::PiCtrlCtrlChGet { controller_index })
+            .await?;
+
+        Ok(ControlSignalInfo {
+            current_index: response.current_index,
+            signal_names: response.signal_names,
+            signal_indexes: response.signal_indexes,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_input_ch_set`](crate::NanonisClient::pi_ctrl_input_ch_set).
+    pub async fn pi_ctrl_input_ch_set(
+        &mut self,
+        controller_index: i32,
+        input: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let input_index = self.resolve_signal(input).await?;
+        self.send(&commands::PiCtrlInputChSet {
+            controller_index,
+            input_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_input_ch_get`](crate::NanonisClient::pi_ctrl_input_ch_get).
+    pub async fn pi_ctrl_input_ch_get(
+        &mut self,
+        controller_index: i32,
+    ) -> Result<ControlSignalInfo, NanonisError> {
+        let response = self
+            .send(&commands::PiCtrlInputChGet { controller_index })
+            .await?;
+
+        Ok(ControlSignalInfo {
+            current_index: response.current_index,
+            signal_names: response.signal_names,
+            signal_indexes: response.signal_indexes,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_props_set`](crate::NanonisClient::pi_ctrl_props_set).
+    pub async fn pi_ctrl_props_set(
+        &mut self,
+        controller_index: i32,
+        props: &PICtrlProps,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiCtrlPropsSet {
+            controller_index,
+            setpoint: props.setpoint,
+            p_gain: props.p_gain,
+            i_gain: props.i_gain,
+            slope: props.slope.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_props_get`](crate::NanonisClient::pi_ctrl_props_get).
+    pub async fn pi_ctrl_props_get(
+        &mut self,
+        controller_index: i32,
+    ) -> Result<PICtrlProps, NanonisError> {
+        let response = self
+            .send(&commands::PiCtrlPropsGet { controller_index })
+            .await?;
+
+        Ok(PICtrlProps {
+            setpoint: response.setpoint,
+            p_gain: response.p_gain,
+            i_gain: response.i_gain,
+            slope: response.slope.try_into()?,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_ctrl_ch_props_set`](crate::NanonisClient::pi_ctrl_ctrl_ch_props_set).
+    pub async fn pi_ctrl_ctrl_ch_props_set(
+        &mut self,
+        controller_index: i32,
+        limits: &PICtrlLimits,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiCtrlCtrlChPropsSet {
+            controller_index,
+            lower_limit: limits.lower_limit,
+            upper_limit: limits.upper_limit,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pi_ctrl_ctrl_ch_props_get`](crate::NanonisClient::pi_ctrl_ctrl_ch_props_get).
+    pub async fn pi_ctrl_ctrl_ch_props_get(
+        &mut self,
+        controller_index: i32,
+    ) -> Result<PICtrlLimits, NanonisError> {
+        let response = self
+            .send(&commands::PiCtrlCtrlChPropsGet { controller_index })
+            .await?;
+
+        Ok(PICtrlLimits {
+            lower_limit: response.lower_limit,
+            upper_limit: response.upper_limit,
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::piezo::{
+    commands, DriftCompConfig, DriftCompStatus, HVAInfo, HVAStatusLED, HysteresisAxisPoints,
+    HysteresisValues, PiezoRange, PiezoSensitivity, PiezoToggle, TiltCorrection, XYZLimits,
+};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::piezo_tilt_set`](crate::NanonisClient::piezo_tilt_set).
+    pub async fn piezo_tilt_set(
+        &mut self,
+        tilt_x_deg: f32,
+        tilt_y_deg: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoTiltSet {
+            tilt_x_deg,
+            tilt_y_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_tilt_get`](crate::NanonisClient::piezo_tilt_get).
+    pub async fn piezo_tilt_get(&mut self) -> Result<TiltCorrection, NanonisError> {
+        let response = self.send(&commands::PiezoTiltGet {}).await?;
+
+        Ok(TiltCorrection {
+            tilt_x_deg: response.tilt_x_deg,
+            tilt_y_deg: response.tilt_y_deg,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_range_set`](crate::NanonisClient::piezo_range_set).
+    pub async fn piezo_range_set(&mut self, range: &PiezoRange) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoRangeSet {
+            range_x_m: range.range_x_m,
+            range_y_m: range.range_y_m,
+            range_z_m: range.range_z_m,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_range_get`](crate::NanonisClient::piezo_range_get).
+    pub async fn piezo_range_get(&mut self) -> Result<PiezoRange, NanonisError> {
+        let response = self.send(&commands::PiezoRangeGet {}).await?;
+
+        Ok(PiezoRange {
+            range_x_m: response.range_x_m,
+            range_y_m: response.range_y_m,
+            range_z_m: response.range_z_m,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_sens_set`](crate::NanonisClient::piezo_sens_set).
+    pub async fn piezo_sens_set(
+        &mut self,
+        sensitivity: &PiezoSensitivity,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoSensSet {
+            sens_x_m_per_v: sensitivity.sens_x_m_per_v,
+            sens_y_m_per_v: sensitivity.sens_y_m_per_v,
+            sens_z_m_per_v: sensitivity.sens_z_m_per_v,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_sens_get`](crate::NanonisClient::piezo_sens_get).
+    pub async fn piezo_sens_get(&mut self) -> Result<PiezoSensitivity, NanonisError> {
+        let response = self.send(&commands::PiezoSensGet {}).await?;
+
+        Ok(PiezoSensitivity {
+            sens_x_m_per_v: response.sens_x_m_per_v,
+            sens_y_m_per_v: response.sens_y_m_per_v,
+            sens_z_m_per_v: response.sens_z_m_per_v,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_drift_comp_set`](crate::NanonisClient::piezo_drift_comp_set).
+    pub async fn piezo_drift_comp_set(
+        &mut self,
+        config: &DriftCompConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoDriftCompSet {
+            enabled: config.enabled.into(),
+            vx_m_s: config.vx_m_s,
+            vy_m_s: config.vy_m_s,
+            vz_m_s: config.vz_m_s,
+            saturation_limit: config.saturation_limit,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_drift_comp_get`](crate::NanonisClient::piezo_drift_comp_get).
+    pub async fn piezo_drift_comp_get(&mut self) -> Result<DriftCompStatus, NanonisError> {
+        let response = self.send(&commands::PiezoDriftCompGet {}).await?;
+
+        Ok(DriftCompStatus {
+            enabled: response.enabled != 0,
+            vx_m_s: response.vx_m_s,
+            vy_m_s: response.vy_m_s,
+            vz_m_s: response.vz_m_s,
+            x_saturated: response.x_saturated != 0,
+            y_saturated: response.y_saturated != 0,
+            z_saturated: response.z_saturated != 0,
+            saturation_limit: response.saturation_limit,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_calibr_get`](crate::NanonisClient::piezo_calibr_get).
+    pub async fn piezo_calibr_get(&mut self) -> Result<PiezoSensitivity, NanonisError> {
+        let response = self.send(&commands::PiezoCalibrGet {}).await?;
+
+        Ok(PiezoSensitivity {
+            sens_x_m_per_v: response.sens_x_m_per_v,
+            sens_y_m_per_v: response.sens_y_m_per_v,
+            sens_z_m_per_v: response.sens_z_m_per_v,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_hva_info_get`](crate::NanonisClient::piezo_hva_info_get).
+    pub async fn piezo_hva_info_get(&mut self) -> Result<HVAInfo, NanonisError> {
+        let response = self.send(&commands::PiezoHvaInfoGet {}).await?;
+
+        Ok(HVAInfo {
+            gain_aux: response.gain_aux,
+            gain_x: response.gain_x,
+            gain_y: response.gain_y,
+            gain_z: response.gain_z,
+            xy_enabled: response.xy_enabled != 0,
+            z_enabled: response.z_enabled != 0,
+            aux_enabled: response.aux_enabled != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_hva_status_led_get`](crate::NanonisClient::piezo_hva_status_led_get).
+    pub async fn piezo_hva_status_led_get(&mut self) -> Result<HVAStatusLED, NanonisError> {
+        let response = self.send(&commands::PiezoHvaStatusLedGet {}).await?;
+
+        Ok(HVAStatusLED {
+            overheated: response.overheated != 0,
+            hv_supply: response.hv_supply != 0,
+            high_temperature: response.high_temperature != 0,
+            output_connector: response.output_connector != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_xyz_limits_set`](crate::NanonisClient::piezo_xyz_limits_set).
+    pub async fn piezo_xyz_limits_set(
+        &mut self,
+        enable: PiezoToggle,
+        limits: &XYZLimits,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoXyzLimitsSet {
+            enable: enable.into(),
+            x_low_v: limits.x_low_v,
+            x_high_v: limits.x_high_v,
+            y_low_v: limits.y_low_v,
+            y_high_v: limits.y_high_v,
+            z_low_v: limits.z_low_v,
+            z_high_v: limits.z_high_v,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_xyz_limits_get`](crate::NanonisClient::piezo_xyz_limits_get).
+    pub async fn piezo_xyz_limits_get(&mut self) -> Result<XYZLimits, NanonisError> {
+        let response = self.send(&commands::PiezoXyzLimitsGet {}).await?;
+
+        Ok(XYZLimits {
+            enabled: response.enabled != 0,
+            x_low_v: response.x_low_v,
+            x_high_v: response.x_high_v,
+            y_low_v: response.y_low_v,
+            y_high_v: response.y_high_v,
+            z_low_v: response.z_low_v,
+            z_high_v: response.z_high_v,
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_hyst_on_off_set`](crate::NanonisClient::piezo_hyst_on_off_set).
+    pub async fn piezo_hyst_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::PiezoHystOnOffSet { enabled: flag })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_hyst_on_off_get`](crate::NanonisClient::piezo_hyst_on_off_get).
+    pub async fn piezo_hyst_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::PiezoHystOnOffGet {}).await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::piezo_hyst_vals_set`](crate::NanonisClient::piezo_hyst_vals_set).
+    pub async fn piezo_hyst_vals_set(
+        &mut self,
+        values: &HysteresisValues,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoHystValsSet {
+            num_fast_x_points: values.fast_axis.x_points.len() as i32,
+            fast_x_points: values.fast_axis.x_points.clone(),
+            num_fast_y_points: values.fast_axis.y_points.len() as i32,
+            fast_y_points: values.fast_axis.y_points.clone(),
+            num_slow_x_points: values.slow_axis.x_points.len() as i32,
+            slow_x_points: values.slow_axis.x_points.clone(),
+            num_slow_y_points: values.slow_axis.y_points.len() as i32,
+            slow_y_points: values.slow_axis.y_points.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_hyst_vals_get`](crate::NanonisClient::piezo_hyst_vals_get).
+    pub async fn piezo_hyst_vals_get(&mut self) -> Result<HysteresisValues, NanonisError> {
+        let response = self.send(&commands::PiezoHystValsGet {}).await?;
+
+        Ok(HysteresisValues {
+            fast_axis: HysteresisAxisPoints {
+                x_points: response.fast_x_points,
+                y_points: response.fast_y_points,
+            },
+            slow_axis: HysteresisAxisPoints {
+                x_points: response.slow_x_points,
+                y_points: response.slow_y_points,
+            },
+        })
+    }
+
+    /// Async version of [`NanonisClient::piezo_hyst_file_load`](crate::NanonisClient::piezo_hyst_file_load).
+    pub async fn piezo_hyst_file_load(&mut self, file_path: &str) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoHystFileLoad {
+            file_path: file_path.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::piezo_hyst_file_save`](crate::NanonisClient::piezo_hyst_file_save).
+    pub async fn piezo_hyst_file_save(&mut self, file_path: &str) -> Result<(), NanonisError> {
+        self.send(&commands::PiezoHystFileSave {
+            file_path: file_path.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+}
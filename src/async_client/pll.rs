@@ -0,0 +1,591 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::pll::{
+    commands, PLLDemodInput, PLLExcRange, PLLInputProps, PLLOverwrite, PLLPhasCtrlGain,
+};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::pll_inp_calibr_set`](crate::NanonisClient::pll_inp_calibr_set).
+    pub async fn pll_inp_calibr_set(
+        &mut self,
+        modulator_index: i32,
+        calibration_m_per_v: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllInpCalibrSet {
+            modulator_index,
+            calibration_m_per_v,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_inp_calibr_get`](crate::NanonisClient::pll_inp_calibr_get).
+    pub async fn pll_inp_calibr_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllInpCalibrGet { modulator_index })
+            .await?;
+
+        Ok(response.calibration_m_per_v)
+    }
+
+    /// Async version of [`NanonisClient::pll_inp_range_set`](crate::NanonisClient::pll_inp_range_set).
+    pub async fn pll_inp_range_set(
+        &mut self,
+        modulator_index: i32,
+        input_range_m: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllInpRangeSet {
+            modulator_index,
+            input_range_m,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_inp_range_get`](crate::NanonisClient::pll_inp_range_get).
+    pub async fn pll_inp_range_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllInpRangeGet { modulator_index })
+            .await?;
+
+        Ok(response.input_range_m)
+    }
+
+    /// Async version of [`NanonisClient::pll_inp_props_set`](crate::NanonisClient::pll_inp_props_set).
+    pub async fn pll_inp_props_set(
+        &mut self,
+        modulator_index: i32,
+        props: &PLLInputProps,
+    ) -> Result<(), NanonisError> {
+        let diff_flag = if props.differential_input { 1u16 } else { 0u16 };
+        let div_flag = if props.divider_1_10 { 1u16 } else { 0u16 };
+        self.send(&commands::PllInpPropsSet {
+            modulator_index,
+            differential_input: diff_flag,
+            divider_1_10: div_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_inp_props_get`](crate::NanonisClient::pll_inp_props_get).
+    pub async fn pll_inp_props_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<PLLInputProps, NanonisError> {
+        let response = self
+            .send(&commands::PllInpPropsGet { modulator_index })
+            .await?;
+
+        Ok(PLLInputProps {
+            differential_input: response.differential_input != 0,
+            divider_1_10: response.divider_1_10 != 0,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_add_on_off_set`](crate::NanonisClient::pll_add_on_off_set).
+    pub async fn pll_add_on_off_set(
+        &mut self,
+        modulator_index: i32,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::PllAddOnOffSet {
+            modulator_index,
+            enabled: flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_add_on_off_get`](crate::NanonisClient::pll_add_on_off_get).
+    pub async fn pll_add_on_off_get(&mut self, modulator_index: i32) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::PllAddOnOffGet { modulator_index })
+            .await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::pll_out_on_off_set`](crate::NanonisClient::pll_out_on_off_set).
+    pub async fn pll_out_on_off_set(
+        &mut self,
+        modulator_index: i32,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::PllOutOnOffSet {
+            modulator_index,
+            enabled: flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_out_on_off_get`](crate::NanonisClient::pll_out_on_off_get).
+    pub async fn pll_out_on_off_get(&mut self, modulator_index: i32) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::PllOutOnOffGet { modulator_index })
+            .await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::pll_exc_range_set`](crate::NanonisClient::pll_exc_range_set).
+    pub async fn pll_exc_range_set(
+        &mut self,
+        modulator_index: i32,
+        range: PLLExcRange,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllExcRangeSet {
+            modulator_index,
+            range: range.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_exc_range_get`](crate::NanonisClient::pll_exc_range_get).
+    pub async fn pll_exc_range_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<PLLExcRange, NanonisError> {
+        let response = self
+            .send(&commands::PllExcRangeGet { modulator_index })
+            .await?;
+
+        response.range.try_into()
+    }
+
+    /// Async version of [`NanonisClient::pll_excitation_set`](crate::NanonisClient::pll_excitation_set).
+    pub async fn pll_excitation_set(
+        &mut self,
+        modulator_index: i32,
+        excitation_v: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllExcitationSet {
+            modulator_index,
+            excitation_v,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_excitation_get`](crate::NanonisClient::pll_excitation_get).
+    pub async fn pll_excitation_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllExcitationGet { modulator_index })
+            .await?;
+
+        Ok(response.excitation_v)
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_setpnt_set`](crate::NanonisClient::pll_amp_ctrl_setpnt_set).
+    pub async fn pll_amp_ctrl_setpnt_set(
+        &mut self,
+        modulator_index: i32,
+        setpoint_m: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllAmpCtrlSetpntSet {
+            modulator_index,
+            setpoint_m,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_setpnt_get`](crate::NanonisClient::pll_amp_ctrl_setpnt_get).
+    pub async fn pll_amp_ctrl_setpnt_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllAmpCtrlSetpntGet { modulator_index })
+            .await?;
+
+        Ok(response.setpoint_m)
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_on_off_set`](crate::NanonisClient::pll_amp_ctrl_on_off_set).
+    pub async fn pll_amp_ctrl_on_off_set(
+        &mut self,
+        modulator_index: i32,
+        status: bool,
+    ) -> Result<(), NanonisError> {
+        let status_u32 = if status { 1u32 } else { 0u32 };
+
+        self.send(&commands::PllAmpCtrlOnOffSet {
+            modulator_index,
+            status: status_u32,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_on_off_get`](crate::NanonisClient::pll_amp_ctrl_on_off_get).
+    pub async fn pll_amp_ctrl_on_off_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::PllAmpCtrlOnOffGet { modulator_index })
+            .await?;
+
+        Ok(response.status != 0)
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_gain_set`](crate::NanonisClient::pll_amp_ctrl_gain_set).
+    pub async fn pll_amp_ctrl_gain_set(
+        &mut self,
+        modulator_index: i32,
+        p_gain_v_div_m: f32,
+        time_constant_s: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllAmpCtrlGainSet {
+            modulator_index,
+            p_gain_v_div_m,
+            time_constant_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_gain_get`](crate::NanonisClient::pll_amp_ctrl_gain_get).
+    pub async fn pll_amp_ctrl_gain_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<(f32, f32), NanonisError> {
+        let response = self
+            .send(&commands::PllAmpCtrlGainGet { modulator_index })
+            .await?;
+
+        Ok((response.p_gain, response.time_const))
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_bandwidth_set`](crate::NanonisClient::pll_amp_ctrl_bandwidth_set).
+    pub async fn pll_amp_ctrl_bandwidth_set(
+        &mut self,
+        modulator_index: i32,
+        bandwidth_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllAmpCtrlBandwidthSet {
+            modulator_index,
+            bandwidth_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_amp_ctrl_bandwidth_get`](crate::NanonisClient::pll_amp_ctrl_bandwidth_get).
+    pub async fn pll_amp_ctrl_bandwidth_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllAmpCtrlBandwidthGet { modulator_index })
+            .await?;
+
+        Ok(response.bandwidth_hz)
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_ctrl_on_off_set`](crate::NanonisClient::pll_phas_ctrl_on_off_set).
+    pub async fn pll_phas_ctrl_on_off_set(
+        &mut self,
+        modulator_index: i32,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        let flag = if enabled { 1u32 } else { 0u32 };
+        self.send(&commands::PllPhasCtrlOnOffSet {
+            modulator_index,
+            enabled: flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_ctrl_on_off_get`](crate::NanonisClient::pll_phas_ctrl_on_off_get).
+    pub async fn pll_phas_ctrl_on_off_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::PllPhasCtrlOnOffGet { modulator_index })
+            .await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_ctrl_gain_set`](crate::NanonisClient::pll_phas_ctrl_gain_set).
+    pub async fn pll_phas_ctrl_gain_set(
+        &mut self,
+        modulator_index: i32,
+        p_gain_hz_per_deg: f32,
+        time_constant_s: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllPhasCtrlGainSet {
+            modulator_index,
+            p_gain_hz_per_deg,
+            time_constant_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_ctrl_gain_get`](crate::NanonisClient::pll_phas_ctrl_gain_get).
+    pub async fn pll_phas_ctrl_gain_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<PLLPhasCtrlGain, NanonisError> {
+        let response = self
+            .send(&commands::PllPhasCtrlGainGet { modulator_index })
+            .await?;
+
+        Ok(PLLPhasCtrlGain {
+            p_gain_hz_per_deg: response.p_gain_hz_per_deg,
+            time_constant_s: response.time_constant_s,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_ctrl_bandwidth_set`](crate::NanonisClient::pll_phas_ctrl_bandwidth_set).
+    pub async fn pll_phas_ctrl_bandwidth_set(
+        &mut self,
+        modulator_index: i32,
+        bandwidth_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllPhasCtrlBandwidthSet {
+            modulator_index,
+            bandwidth_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_ctrl_bandwidth_get`](crate::NanonisClient::pll_phas_ctrl_bandwidth_get).
+    pub async fn pll_phas_ctrl_bandwidth_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllPhasCtrlBandwidthGet { modulator_index })
+            .await?;
+
+        Ok(response.bandwidth_hz)
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_range_set`](crate::NanonisClient::pll_freq_range_set).
+    pub async fn pll_freq_range_set(
+        &mut self,
+        modulator_index: i32,
+        freq_range_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqRangeSet {
+            modulator_index,
+            freq_range_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_range_get`](crate::NanonisClient::pll_freq_range_get).
+    pub async fn pll_freq_range_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllFreqRangeGet { modulator_index })
+            .await?;
+
+        Ok(response.freq_range_hz)
+    }
+
+    /// Async version of [`NanonisClient::pll_center_freq_set`](crate::NanonisClient::pll_center_freq_set).
+    pub async fn pll_center_freq_set(
+        &mut self,
+        modulator_index: i32,
+        center_freq_hz: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllCenterFreqSet {
+            modulator_index,
+            center_freq_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_center_freq_get`](crate::NanonisClient::pll_center_freq_get).
+    pub async fn pll_center_freq_get(&mut self, modulator_index: i32) -> Result<f64, NanonisError> {
+        let response = self
+            .send(&commands::PllCenterFreqGet { modulator_index })
+            .await?;
+
+        Ok(response.center_freq_hz)
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_shift_set`](crate::NanonisClient::pll_freq_shift_set).
+    pub async fn pll_freq_shift_set(
+        &mut self,
+        modulator_index: i32,
+        freq_shift_hz: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqShiftSet {
+            modulator_index,
+            freq_shift_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_shift_get`](crate::NanonisClient::pll_freq_shift_get).
+    pub async fn pll_freq_shift_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllFreqShiftGet { modulator_index })
+            .await?;
+
+        Ok(response.freq_shift_hz)
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_shift_auto_center`](crate::NanonisClient::pll_freq_shift_auto_center).
+    pub async fn pll_freq_shift_auto_center(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqShiftAutoCenter { modulator_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_exc_overwrite_set`](crate::NanonisClient::pll_freq_exc_overwrite_set).
+    pub async fn pll_freq_exc_overwrite_set(
+        &mut self,
+        modulator_index: i32,
+        overwrite: &PLLOverwrite,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqExcOverwriteSet {
+            modulator_index,
+            excitation_signal_index: overwrite.excitation_signal_index,
+            frequency_signal_index: overwrite.frequency_signal_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_exc_overwrite_get`](crate::NanonisClient::pll_freq_exc_overwrite_get).
+    pub async fn pll_freq_exc_overwrite_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<PLLOverwrite, NanonisError> {
+        let response = self
+            .send(&commands::PllFreqExcOverwriteGet { modulator_index })
+            .await?;
+
+        Ok(PLLOverwrite {
+            excitation_signal_index: response.excitation_signal_index,
+            frequency_signal_index: response.frequency_signal_index,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_input_set`](crate::NanonisClient::pll_demod_input_set).
+    pub async fn pll_demod_input_set(
+        &mut self,
+        demodulator_index: u16,
+        input: &PLLDemodInput,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllDemodInputSet {
+            demodulator_index,
+            input: input.input,
+            freq_generator: input.freq_generator,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_input_get`](crate::NanonisClient::pll_demod_input_get).
+    pub async fn pll_demod_input_get(
+        &mut self,
+        demodulator_index: u16,
+    ) -> Result<PLLDemodInput, NanonisError> {
+        let response = self
+            .send(&commands::PllDemodInputGet { demodulator_index })
+            .await?;
+
+        Ok(PLLDemodInput {
+            input: response.input,
+            freq_generator: response.freq_generator,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_harmonic_set`](crate::NanonisClient::pll_demod_harmonic_set).
+    pub async fn pll_demod_harmonic_set(
+        &mut self,
+        demodulator_index: u16,
+        harmonic: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllDemodHarmonicSet {
+            demodulator_index,
+            harmonic,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_harmonic_get`](crate::NanonisClient::pll_demod_harmonic_get).
+    pub async fn pll_demod_harmonic_get(
+        &mut self,
+        demodulator_index: u16,
+    ) -> Result<u16, NanonisError> {
+        let response = self
+            .send(&commands::PllDemodHarmonicGet { demodulator_index })
+            .await?;
+
+        Ok(response.harmonic)
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_phas_ref_set`](crate::NanonisClient::pll_demod_phas_ref_set).
+    pub async fn pll_demod_phas_ref_set(
+        &mut self,
+        demodulator_index: u16,
+        phase_deg: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllDemodPhasRefSet {
+            demodulator_index,
+            phase_deg,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_phas_ref_get`](crate::NanonisClient::pll_demod_phas_ref_get).
+    pub async fn pll_demod_phas_ref_get(
+        &mut self,
+        demodulator_index: u16,
+    ) -> Result<f32, NanonisError> {
+        let response = self
+            .send(&commands::PllDemodPhasRefGet { demodulator_index })
+            .await?;
+
+        Ok(response.phase_deg)
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_filter_set`](crate::NanonisClient::pll_demod_filter_set).
+    pub async fn pll_demod_filter_set(
+        &mut self,
+        demodulator_index: u16,
+        filter_order: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllDemodFilterSet {
+            demodulator_index,
+            filter_order,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_demod_filter_get`](crate::NanonisClient::pll_demod_filter_get).
+    pub async fn pll_demod_filter_get(
+        &mut self,
+        demodulator_index: u16,
+    ) -> Result<u16, NanonisError> {
+        let response = self
+            .send(&commands::PllDemodFilterGet { demodulator_index })
+            .await?;
+
+        Ok(response.filter_order)
+    }
+}
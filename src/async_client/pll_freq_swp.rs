@@ -0,0 +1,128 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::pll_freq_swp::{
+    commands, PLLFreqSwpCharacteristics, PLLFreqSwpData, PLLFreqSwpParams, PLLPhasSwpData,
+};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::pll_freq_swp_open`](crate::NanonisClient::pll_freq_swp_open).
+    pub async fn pll_freq_swp_open(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqSwpOpen { modulator_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_swp_params_set`](crate::NanonisClient::pll_freq_swp_params_set).
+    pub async fn pll_freq_swp_params_set(
+        &mut self,
+        modulator_index: i32,
+        params: &PLLFreqSwpParams,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqSwpParamsSet {
+            modulator_index,
+            num_points: params.num_points,
+            period_s: params.period_s,
+            settling_time_s: params.settling_time_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_swp_params_get`](crate::NanonisClient::pll_freq_swp_params_get).
+    pub async fn pll_freq_swp_params_get(
+        &mut self,
+        modulator_index: i32,
+    ) -> Result<PLLFreqSwpParams, NanonisError> {
+        let response = self
+            .send(&commands::PllFreqSwpParamsGet { modulator_index })
+            .await?;
+
+        Ok(PLLFreqSwpParams {
+            num_points: response.num_points,
+            period_s: response.period_s,
+            settling_time_s: response.settling_time_s,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_swp_start`](crate::NanonisClient::pll_freq_swp_start).
+    pub async fn pll_freq_swp_start(
+        &mut self,
+        modulator_index: i32,
+        get_data: bool,
+        sweep_up: bool,
+    ) -> Result<Option<PLLFreqSwpData>, NanonisError> {
+        let get_flag = if get_data { 1u32 } else { 0u32 };
+        let dir_flag = if sweep_up { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::PllFreqSwpStart {
+                modulator_index,
+                get_data: get_flag,
+                sweep_up: dir_flag,
+            })
+            .await?;
+
+        if get_data {
+            let channel_names = response.channel_names;
+            let characteristics = PLLFreqSwpCharacteristics {
+                resonance_freq_hz: response.resonance_freq_hz,
+                q_factor: response.q_factor,
+                phase_deg: response.phase_deg,
+                amp_exc_ratio_nm_per_mv: response.amp_exc_ratio_nm_per_mv,
+                fit_length: response.fit_length,
+                num_points: response.num_points,
+            };
+            let data = response.data;
+
+            Ok(Some(PLLFreqSwpData {
+                channel_names,
+                data,
+                characteristics,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Async version of [`NanonisClient::pll_freq_swp_stop`](crate::NanonisClient::pll_freq_swp_stop).
+    pub async fn pll_freq_swp_stop(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::PllFreqSwpStop { modulator_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_swp_start`](crate::NanonisClient::pll_phas_swp_start).
+    pub async fn pll_phas_swp_start(
+        &mut self,
+        modulator_index: i32,
+        get_data: bool,
+    ) -> Result<Option<PLLPhasSwpData>, NanonisError> {
+        let get_flag = if get_data { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::PllPhasSwpStart {
+                modulator_index,
+                get_data: get_flag,
+            })
+            .await?;
+
+        if get_data {
+            let channel_names = response.channel_names;
+            let data = response.data;
+
+            Ok(Some(PLLPhasSwpData {
+                channel_names,
+                data,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Async version of [`NanonisClient::pll_phas_swp_stop`](crate::NanonisClient::pll_phas_swp_stop).
+    pub async fn pll_phas_swp_stop(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::PllPhasSwpStop { modulator_index })
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::pll_signal_anlzr::{
+    commands, FFTAnalyzerData, FFTAveragingMode, FFTProps, FFTWeightingMode, FFTWindow,
+    OsciAnalyzerData, PLLAnlzrTimebase, PLLAnlzrTrigger,
+};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::pll_signal_anlzr_open`](crate::NanonisClient::pll_signal_anlzr_open).
+    pub async fn pll_signal_anlzr_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_ch_set`](crate::NanonisClient::pll_signal_anlzr_ch_set).
+    pub async fn pll_signal_anlzr_ch_set(
+        &mut self,
+        channel_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrChSet { channel_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_ch_get`](crate::NanonisClient::pll_signal_anlzr_ch_get).
+    pub async fn pll_signal_anlzr_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::PllSignalAnlzrChGet {}).await?;
+
+        Ok(response.channel_index)
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_timebase_set`](crate::NanonisClient::pll_signal_anlzr_timebase_set).
+    pub async fn pll_signal_anlzr_timebase_set(
+        &mut self,
+        timebase_index: i32,
+        update_rate: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrTimebaseSet {
+            timebase_index,
+            update_rate,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_timebase_get`](crate::NanonisClient::pll_signal_anlzr_timebase_get).
+    pub async fn pll_signal_anlzr_timebase_get(
+        &mut self,
+    ) -> Result<PLLAnlzrTimebase, NanonisError> {
+        let response = self.send(&commands::PllSignalAnlzrTimebaseGet {}).await?;
+
+        Ok(PLLAnlzrTimebase {
+            timebase_index: response.timebase_index,
+            update_rate: response.update_rate,
+            available_timebases: response.available_timebases,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_trig_auto`](crate::NanonisClient::pll_signal_anlzr_trig_auto).
+    pub async fn pll_signal_anlzr_trig_auto(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrTrigAuto {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_trig_rearm`](crate::NanonisClient::pll_signal_anlzr_trig_rearm).
+    pub async fn pll_signal_anlzr_trig_rearm(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrTrigRearm {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_trig_set`](crate::NanonisClient::pll_signal_anlzr_trig_set).
+    pub async fn pll_signal_anlzr_trig_set(
+        &mut self,
+        trigger: &PLLAnlzrTrigger,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrTrigSet {
+            mode: trigger.mode.into(),
+            source_index: trigger.source_index,
+            slope: trigger.slope.into(),
+            level: trigger.level,
+            position_s: trigger.position_s,
+            arming: trigger.arming.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_trig_get`](crate::NanonisClient::pll_signal_anlzr_trig_get).
+    pub async fn pll_signal_anlzr_trig_get(&mut self) -> Result<PLLAnlzrTrigger, NanonisError> {
+        let response = self.send(&commands::PllSignalAnlzrTrigGet {}).await?;
+
+        Ok(PLLAnlzrTrigger {
+            mode: response.mode.try_into()?,
+            source_index: response.source_index,
+            slope: response.slope.try_into()?,
+            level: response.level,
+            position_s: response.position_s,
+            arming: response.arming.try_into()?,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_osci_data_get`](crate::NanonisClient::pll_signal_anlzr_osci_data_get).
+    pub async fn pll_signal_anlzr_osci_data_get(
+        &mut self,
+    ) -> Result<OsciAnalyzerData, NanonisError> {
+        let response = self.send(&commands::PllSignalAnlzrOsciDataGet {}).await?;
+
+        Ok(OsciAnalyzerData {
+            t0: response.t0,
+            dt: response.dt,
+            data: response.data,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_fft_props_set`](crate::NanonisClient::pll_signal_anlzr_fft_props_set).
+    pub async fn pll_signal_anlzr_fft_props_set(
+        &mut self,
+        props: &FFTProps,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrFftPropsSet {
+            window: props.window.into(),
+            averaging: props.averaging.into(),
+            weighting: props.weighting.into(),
+            count: props.count,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_fft_props_get`](crate::NanonisClient::pll_signal_anlzr_fft_props_get).
+    pub async fn pll_signal_anlzr_fft_props_get(&mut self) -> Result<FFTProps, NanonisError> {
+        let response = self.send(&commands::PllSignalAnlzrFftPropsGet {}).await?;
+
+        // Note: returned values have different offset than set values
+        Ok(FFTProps {
+            window: FFTWindow::NoChange, // Would need TryFrom for returned values
+            averaging: FFTAveragingMode::NoChange,
+            weighting: FFTWeightingMode::NoChange,
+            count: response.count,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_fft_avg_restart`](crate::NanonisClient::pll_signal_anlzr_fft_avg_restart).
+    pub async fn pll_signal_anlzr_fft_avg_restart(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PllSignalAnlzrFftAvgRestart {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_signal_anlzr_fft_data_get`](crate::NanonisClient::pll_signal_anlzr_fft_data_get).
+    pub async fn pll_signal_anlzr_fft_data_get(&mut self) -> Result<FFTAnalyzerData, NanonisError> {
+        let response = self.send(&commands::PllSignalAnlzrFftDataGet {}).await?;
+
+        Ok(FFTAnalyzerData {
+            f0: response.f0,
+            df: response.df,
+            data: response.data,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_open`](crate::NanonisClient::pll_zoom_fft_open).
+    pub async fn pll_zoom_fft_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PllZoomFftOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_ch_set`](crate::NanonisClient::pll_zoom_fft_ch_set).
+    pub async fn pll_zoom_fft_ch_set(&mut self, channel_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::PllZoomFftChSet { channel_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_ch_get`](crate::NanonisClient::pll_zoom_fft_ch_get).
+    pub async fn pll_zoom_fft_ch_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::PllZoomFftChGet {}).await?;
+
+        Ok(response.channel_index)
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_avg_restart`](crate::NanonisClient::pll_zoom_fft_avg_restart).
+    pub async fn pll_zoom_fft_avg_restart(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::PllZoomFftAvgRestart {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_props_set`](crate::NanonisClient::pll_zoom_fft_props_set).
+    pub async fn pll_zoom_fft_props_set(&mut self, props: &FFTProps) -> Result<(), NanonisError> {
+        self.send(&commands::PllZoomFftPropsSet {
+            window: props.window.into(),
+            averaging: props.averaging.into(),
+            weighting: props.weighting.into(),
+            count: props.count,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_props_get`](crate::NanonisClient::pll_zoom_fft_props_get).
+    pub async fn pll_zoom_fft_props_get(&mut self) -> Result<FFTProps, NanonisError> {
+        let response = self.send(&commands::PllZoomFftPropsGet {}).await?;
+
+        Ok(FFTProps {
+            window: FFTWindow::NoChange,
+            averaging: FFTAveragingMode::NoChange,
+            weighting: FFTWeightingMode::NoChange,
+            count: response.count,
+        })
+    }
+
+    /// Async version of [`NanonisClient::pll_zoom_fft_data_get`](crate::NanonisClient::pll_zoom_fft_data_get).
+    pub async fn pll_zoom_fft_data_get(&mut self) -> Result<FFTAnalyzerData, NanonisError> {
+        let response = self.send(&commands::PllZoomFftDataGet {}).await?;
+
+        Ok(FFTAnalyzerData {
+            f0: response.f0,
+            df: response.df,
+            data: response.data,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::safe_tip::commands;
+use crate::units::Setpoint;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::safe_tip_on_off_set`](crate::NanonisClient::safe_tip_on_off_set).
+    pub async fn safe_tip_on_off_set(&mut self, safe_tip_on: bool) -> Result<(), NanonisError> {
+        let status = if safe_tip_on { 1u16 } else { 2u16 };
+
+        self.send(&commands::SafeTipOnOffSet { status }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::safe_tip_on_off_get`](crate::NanonisClient::safe_tip_on_off_get).
+    pub async fn safe_tip_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::SafeTipOnOffGet {}).await?;
+
+        Ok(response.status == 1)
+    }
+
+    /// Async version of [`NanonisClient::safe_tip_signal_get`](crate::NanonisClient::safe_tip_signal_get).
+    pub async fn safe_tip_signal_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::SafeTipSignalGet {}).await?;
+
+        Ok(response.value)
+    }
+
+    /// Async version of [`NanonisClient::safe_tip_props_set`](crate::NanonisClient::safe_tip_props_set).
+    pub async fn safe_tip_props_set(
+        &mut self,
+        auto_recovery: bool,
+        auto_pause_scan: bool,
+        threshold: impl Into<Setpoint>,
+    ) -> Result<(), NanonisError> {
+        let recovery_flag = if auto_recovery { 1u16 } else { 0u16 };
+        let pause_flag = if auto_pause_scan { 1u16 } else { 0u16 };
+
+        self.send(&commands::SafeTipPropsSet {
+            auto_recovery: recovery_flag,
+            auto_pause_scan: pause_flag,
+            threshold: threshold.into().as_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::safe_tip_props_get`](crate::NanonisClient::safe_tip_props_get).
+    pub async fn safe_tip_props_get(&mut self) -> Result<(bool, bool, f32), NanonisError> {
+        let response = self.send(&commands::SafeTipPropsGet {}).await?;
+
+        let auto_recovery = response.auto_recovery == 1;
+        let auto_pause_scan = response.auto_pause_scan == 1;
+        let threshold = response.threshold;
+        Ok((auto_recovery, auto_pause_scan, threshold))
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_tip_lift_set`](crate::NanonisClient::z_ctrl_tip_lift_set).
+    pub async fn z_ctrl_tip_lift_set(&mut self, tip_lift_m: f32) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlTipLiftSet { tip_lift_m }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_tip_lift_get`](crate::NanonisClient::z_ctrl_tip_lift_get).
+    pub async fn z_ctrl_tip_lift_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::ZCtrlTipLiftGet {}).await?;
+
+        Ok(response.tip_lift_m)
+    }
+
+    /// Async version of [`NanonisClient::safety_status_comprehensive`](crate::NanonisClient::safety_status_comprehensive).
+    pub async fn safety_status_comprehensive(
+        &mut self,
+    ) -> Result<(bool, f32, f32, f32, bool), NanonisError> {
+        let safe_tip_on = self.safe_tip_on_off_get().await?;
+        let signal_value = self.safe_tip_signal_get().await?;
+        let (_, _, threshold) = self.safe_tip_props_get().await?;
+        let tip_lift = self.z_ctrl_tip_lift_get().await?;
+        let z_ctrl_on = self.z_ctrl_on_off_get().await?;
+
+        Ok((safe_tip_on, signal_value, threshold, tip_lift, z_ctrl_on))
+    }
+}
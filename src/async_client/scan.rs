@@ -0,0 +1,237 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::scan::{
+    commands, ScanAction, ScanConfig, ScanDirection, ScanFrame, ScanProps, ScanPropsBuilder,
+};
+use crate::signals::SlotRef;
+use crate::types::{Array2D, Position};
+use std::time::Duration;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::scan_action`](crate::NanonisClient::scan_action).
+    pub async fn scan_action(
+        &mut self,
+        scan_action: ScanAction,
+        scan_direction: ScanDirection,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScanAction {
+            action: scan_action.into(),
+            direction: scan_direction.into(),
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::scan_frame_set`](crate::NanonisClient::scan_frame_set).
+    pub async fn scan_frame_set(&mut self, frame: ScanFrame) -> Result<(), NanonisError> {
+        self.send(&commands::ScanFrameSet {
+            center_x: frame.center.x as f32,
+            center_y: frame.center.y as f32,
+            width: frame.width_m,
+            height: frame.height_m,
+            angle: frame.angle_deg,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::scan_frame_get`](crate::NanonisClient::scan_frame_get).
+    pub async fn scan_frame_get(&mut self) -> Result<ScanFrame, NanonisError> {
+        let frame = self.send(&commands::ScanFrameGet {}).await?;
+        Ok(ScanFrame {
+            center: Position {
+                x: frame.center_x as f64,
+                y: frame.center_y as f64,
+            },
+            width_m: frame.width,
+            height_m: frame.height,
+            angle_deg: frame.angle,
+        })
+    }
+
+    /// Async version of [`NanonisClient::scan_buffer_set`](crate::NanonisClient::scan_buffer_set).
+    pub async fn scan_buffer_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+        pixels: i32,
+        lines: i32,
+    ) -> Result<(), NanonisError> {
+        let mut channel_indexes = Vec::new();
+        for channel in channels {
+            channel_indexes.push(self.resolve_slot(channel).await?);
+        }
+        self.send(&commands::ScanBufferSet {
+            channel_indexes,
+            pixels,
+            lines,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::scan_buffer_get`](crate::NanonisClient::scan_buffer_get).
+    pub async fn scan_buffer_get(&mut self) -> Result<(Vec<i32>, i32, i32), NanonisError> {
+        let buffer = self.send(&commands::ScanBufferGet {}).await?;
+        Ok((buffer.channel_indexes, buffer.pixels, buffer.lines))
+    }
+
+    /// Async version of [`NanonisClient::scan_status_get`](crate::NanonisClient::scan_status_get).
+    pub async fn scan_status_get(&mut self) -> Result<bool, NanonisError> {
+        Ok(self.send(&commands::ScanStatusGet {}).await?.status == 1)
+    }
+
+    /// Async version of [`NanonisClient::scan_frame_data_grab`](crate::NanonisClient::scan_frame_data_grab).
+    pub async fn scan_frame_data_grab(
+        &mut self,
+        channel_index: u32,
+        data_direction: bool,
+    ) -> Result<(String, Array2D<f32>, bool), NanonisError> {
+        let grab = self
+            .send(&commands::ScanFrameDataGrab {
+                channel_index,
+                data_direction: data_direction as u32,
+            })
+            .await?;
+        Ok((grab.channel_name, grab.data, grab.scan_direction == 1))
+    }
+
+    /// Async version of [`NanonisClient::scan_wait_end_of_scan`](crate::NanonisClient::scan_wait_end_of_scan).
+    ///
+    /// The reply is awaited for up to `timeout` plus the configured read
+    /// timeout, so long scans do not trip the read timeout.
+    pub async fn scan_wait_end_of_scan(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(bool, String), NanonisError> {
+        let read_timeout = timeout + self.config.read_timeout;
+        let response = self
+            .send_with_read_timeout(
+                &commands::ScanWaitEndOfScan {
+                    timeout_ms: timeout.as_millis() as i32,
+                },
+                read_timeout,
+            )
+            .await?;
+        Ok((response.timeout_occurred == 1, response.file_path))
+    }
+
+    /// Async version of [`NanonisClient::scan_props_get`](crate::NanonisClient::scan_props_get).
+    pub async fn scan_props_get(&mut self) -> Result<ScanProps, NanonisError> {
+        ScanProps::try_from(self.send(&commands::ScanPropsGet {}).await?)
+    }
+
+    /// Async version of [`NanonisClient::scan_config_set`](crate::NanonisClient::scan_config_set).
+    pub async fn scan_config_set(&mut self, config: ScanConfig) -> Result<(), NanonisError> {
+        self.send(&commands::ScanSpeedSet {
+            forward_linear_speed_m_s: config.forward_linear_speed_m_s,
+            backward_linear_speed_m_s: config.backward_linear_speed_m_s,
+            forward_time_per_line_s: config.forward_time_per_line_s,
+            backward_time_per_line_s: config.backward_time_per_line_s,
+            keep_parameter_constant: config.keep_parameter_constant,
+            speed_ratio: config.speed_ratio,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::scan_speed_get`](crate::NanonisClient::scan_speed_get).
+    pub async fn scan_speed_get(&mut self) -> Result<ScanConfig, NanonisError> {
+        let response = self.send(&commands::ScanSpeedGet {}).await?;
+
+        Ok(ScanConfig {
+            forward_linear_speed_m_s: response.forward_linear_speed_m_s,
+            backward_linear_speed_m_s: response.backward_linear_speed_m_s,
+            forward_time_per_line_s: response.forward_time_per_line_s,
+            backward_time_per_line_s: response.backward_time_per_line_s,
+            keep_parameter_constant: response.keep_parameter_constant,
+            speed_ratio: response.speed_ratio,
+        })
+    }
+
+    /// Async version of [`NanonisClient::scan_xy_pos_get`](crate::NanonisClient::scan_xy_pos_get).
+    pub async fn scan_xy_pos_get(
+        &mut self,
+        wait_newest_data: bool,
+    ) -> Result<(f32, f32), NanonisError> {
+        let wait_flag = if wait_newest_data { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::ScanXyPosGet {
+                wait_newest_data: wait_flag,
+            })
+            .await?;
+
+        Ok((response.x, response.y))
+    }
+
+    /// Async version of [`NanonisClient::scan_save`](crate::NanonisClient::scan_save).
+    pub async fn scan_save(
+        &mut self,
+        wait_until_saved: bool,
+        timeout_ms: i32,
+    ) -> Result<bool, NanonisError> {
+        let wait_flag = if wait_until_saved { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::ScanSave {
+                wait_until_saved: wait_flag,
+                timeout_ms,
+            })
+            .await?;
+
+        Ok(response.timed_out == 1)
+    }
+
+    /// Async version of [`NanonisClient::scan_props_set`](crate::NanonisClient::scan_props_set).
+    pub async fn scan_props_set(&mut self, builder: ScanPropsBuilder) -> Result<(), NanonisError> {
+        // Convert boolean options to u32 (0=no change, 1=On, 2=Off)
+        let continuous_flag = match builder.continuous_scan {
+            None => 0u32,
+            Some(true) => 1u32,
+            Some(false) => 2u32,
+        };
+
+        let bouncy_flag = match builder.bouncy_scan {
+            None => 0u32,
+            Some(true) => 1u32,
+            Some(false) => 2u32,
+        };
+
+        let autosave_flag = match builder.autosave {
+            None => 0u32,
+            Some(mode) => mode.into(),
+        };
+
+        let autopaste_flag = match builder.autopaste {
+            None => 0u32,
+            Some(mode) => mode.into(),
+        };
+
+        // For strings/arrays: empty string/array means no change
+        let series_name = builder.series_name.unwrap_or_default();
+        let comment = builder.comment.unwrap_or_default();
+        let modules_names = builder.modules_names.unwrap_or_default();
+
+        self.send(&commands::ScanPropsSet {
+            continuous_flag,
+            bouncy_flag,
+            autosave_flag,
+            series_name,
+            comment,
+            modules_names,
+            autopaste_flag,
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::scan_background_paste`](crate::NanonisClient::scan_background_paste).
+    pub async fn scan_background_paste(&mut self, x: f64, y: f64) -> Result<(), NanonisError> {
+        self.send(&commands::ScanBackgroundPaste { x, y }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::scan_background_delete`](crate::NanonisClient::scan_background_delete).
+    pub async fn scan_background_delete(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ScanBackgroundDelete {}).await?;
+        Ok(())
+    }
+}
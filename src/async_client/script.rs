@@ -0,0 +1,189 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::script::{commands, AcquireBuffer, ScriptData};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::script_open`](crate::NanonisClient::script_open).
+    pub async fn script_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_load`](crate::NanonisClient::script_load).
+    pub async fn script_load(
+        &mut self,
+        script_index: i32,
+        file_path: &str,
+        load_session: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptLoad {
+            script_index,
+            file_path: file_path.to_string(),
+            load_session: if load_session { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_save`](crate::NanonisClient::script_save).
+    pub async fn script_save(
+        &mut self,
+        script_index: i32,
+        file_path: &str,
+        save_session: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptSave {
+            script_index,
+            file_path: file_path.to_string(),
+            save_session: if save_session { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_deploy`](crate::NanonisClient::script_deploy).
+    pub async fn script_deploy(&mut self, script_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptDeploy { script_index }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_undeploy`](crate::NanonisClient::script_undeploy).
+    pub async fn script_undeploy(&mut self, script_index: i32) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptUndeploy { script_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_run`](crate::NanonisClient::script_run).
+    pub async fn script_run(&mut self, script_index: i32, wait: bool) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptRun {
+            script_index,
+            wait: if wait { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_stop`](crate::NanonisClient::script_stop).
+    pub async fn script_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_chs_get`](crate::NanonisClient::script_chs_get).
+    pub async fn script_chs_get(
+        &mut self,
+        buffer: AcquireBuffer,
+    ) -> Result<Vec<i32>, NanonisError> {
+        let response = self
+            .send(&commands::ScriptChsGet {
+                buffer: buffer.into(),
+            })
+            .await?;
+        Ok(response.channel_indexes)
+    }
+
+    /// Async version of [`NanonisClient::script_chs_set`](crate::NanonisClient::script_chs_set).
+    pub async fn script_chs_set(
+        &mut self,
+        buffer: AcquireBuffer,
+        channel_indexes: &[i32],
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptChsSet {
+            buffer: buffer.into(),
+            channel_indexes: channel_indexes.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_data_get`](crate::NanonisClient::script_data_get).
+    pub async fn script_data_get(
+        &mut self,
+        buffer: AcquireBuffer,
+        sweep_number: i32,
+    ) -> Result<ScriptData, NanonisError> {
+        let response = self
+            .send(&commands::ScriptDataGet {
+                buffer: buffer.into(),
+                sweep_number,
+            })
+            .await?;
+
+        Ok(ScriptData {
+            data: response.data,
+        })
+    }
+
+    /// Async version of [`NanonisClient::script_autosave`](crate::NanonisClient::script_autosave).
+    pub async fn script_autosave(
+        &mut self,
+        buffer: AcquireBuffer,
+        sweep_number: i32,
+        all_sweeps_to_same_file: bool,
+        folder_path: &str,
+        basename: &str,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptAutosave {
+            buffer: buffer.into(),
+            sweep_number,
+            all_sweeps_to_same_file: if all_sweeps_to_same_file { 1 } else { 0 },
+            folder_path: folder_path.to_string(),
+            basename: basename.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_lut_open`](crate::NanonisClient::script_lut_open).
+    pub async fn script_lut_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptLutOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_lut_load`](crate::NanonisClient::script_lut_load).
+    pub async fn script_lut_load(
+        &mut self,
+        lut_index: i32,
+        file_path: &str,
+        values: &[f32],
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptLutLoad {
+            lut_index,
+            file_path: file_path.to_string(),
+            values: values.to_vec(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_lut_save`](crate::NanonisClient::script_lut_save).
+    pub async fn script_lut_save(
+        &mut self,
+        lut_index: i32,
+        file_path: &str,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptLutSave {
+            lut_index,
+            file_path: file_path.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::script_lut_deploy`](crate::NanonisClient::script_lut_deploy).
+    pub async fn script_lut_deploy(
+        &mut self,
+        lut_index: i32,
+        wait: bool,
+        timeout_ms: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ScriptLutDeploy {
+            lut_index,
+            wait: if wait { 1 } else { 0 },
+            timeout_ms,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::signal_chart::{commands, SignalChartChannels};
+use crate::signals::SignalRef;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::signal_chart_open`](crate::NanonisClient::signal_chart_open).
+    pub async fn signal_chart_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::SignalChartOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::signal_chart_chs_set`](crate::NanonisClient::signal_chart_chs_set).
+    pub async fn signal_chart_chs_set(
+        &mut self,
+        channel_a: impl Into<SignalRef>,
+        channel_b: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let channel_a_index = self.resolve_signal(channel_a).await?;
+        let channel_b_index = self.resolve_signal(channel_b).await?;
+        self.send(&commands::SignalChartChsSet {
+            channel_a_index,
+            channel_b_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::signal_chart_chs_get`](crate::NanonisClient::signal_chart_chs_get).
+    pub async fn signal_chart_chs_get(&mut self) -> Result<SignalChartChannels, NanonisError> {
+        let response = self.send(&commands::SignalChartChsGet {}).await?;
+
+        Ok(SignalChartChannels {
+            channel_a_index: response.channel_a_index,
+            channel_b_index: response.channel_b_index,
+        })
+    }
+}
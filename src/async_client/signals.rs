@@ -0,0 +1,175 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::signals::{commands, SignalRef, SignalRegistry, SlotRef};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::signal_names_get`](crate::NanonisClient::signal_names_get).
+    pub async fn signal_names_get(&mut self) -> Result<Vec<String>, NanonisError> {
+        Ok(self.send(&commands::SignalsNamesGet {}).await?.names)
+    }
+
+    /// Async version of [`NanonisClient::signals_calibr_get`](crate::NanonisClient::signals_calibr_get).
+    pub async fn signals_calibr_get(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(f32, f32), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        let calibr = self
+            .send(&commands::SignalsCalibrGet { signal_index })
+            .await?;
+        Ok((calibr.calibration, calibr.offset))
+    }
+
+    /// Async version of [`NanonisClient::signals_range_get`](crate::NanonisClient::signals_range_get).
+    pub async fn signals_range_get(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(f32, f32), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        let range = self
+            .send(&commands::SignalsRangeGet { signal_index })
+            .await?;
+        Ok((range.max_limit, range.min_limit))
+    }
+
+    /// Async version of [`NanonisClient::signals_vals_get`](crate::NanonisClient::signals_vals_get).
+    pub async fn signals_vals_get<S: Into<SignalRef>>(
+        &mut self,
+        signals: impl IntoIterator<Item = S>,
+        wait_for_newest_data: bool,
+    ) -> Result<Vec<f32>, NanonisError> {
+        let mut signal_indexes = Vec::new();
+        for signal in signals {
+            signal_indexes.push(self.resolve_signal(signal).await?);
+        }
+        Ok(self
+            .send(&commands::SignalsValsGet {
+                signal_indexes,
+                wait_for_newest_data: wait_for_newest_data as u32,
+            })
+            .await?
+            .values)
+    }
+
+    /// Async version of [`NanonisClient::signal_val_get`](crate::NanonisClient::signal_val_get).
+    pub async fn signal_val_get(
+        &mut self,
+        signal: impl Into<SignalRef>,
+        wait_for_newest_data: bool,
+    ) -> Result<f32, NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        Ok(self
+            .send(&commands::SignalsValGet {
+                signal_index,
+                wait_for_newest_data: wait_for_newest_data as u32,
+            })
+            .await?
+            .value)
+    }
+
+    /// Async version of [`NanonisClient::signals_in_slots_get`](crate::NanonisClient::signals_in_slots_get).
+    pub async fn signals_in_slots_get(&mut self) -> Result<(Vec<String>, Vec<i32>), NanonisError> {
+        let response = self.send(&commands::SignalsInSlotsGet {}).await?;
+        Ok((response.names, response.indexes))
+    }
+
+    /// Async version of [`NanonisClient::signal_registry`](crate::NanonisClient::signal_registry).
+    pub async fn signal_registry(&mut self) -> Result<&SignalRegistry, NanonisError> {
+        if self.signal_registry.is_none() {
+            let names = self.signal_names_get().await?;
+            let (_, slotted) = self.signals_in_slots_get().await?;
+            let mut calibrations = Vec::with_capacity(names.len());
+            let mut ranges = Vec::with_capacity(names.len());
+            // Typed commands directly, as the getters resolve through here
+            for signal_index in 0..names.len() as i32 {
+                let calibr = self
+                    .send(&commands::SignalsCalibrGet { signal_index })
+                    .await?;
+                calibrations.push((calibr.calibration, calibr.offset));
+                let range = self
+                    .send(&commands::SignalsRangeGet { signal_index })
+                    .await?;
+                ranges.push((range.max_limit, range.min_limit));
+            }
+            self.signal_registry = Some(SignalRegistry::from_parts(
+                names,
+                &slotted,
+                &calibrations,
+                &ranges,
+            ));
+        }
+        Ok(self.signal_registry.as_ref().expect("loaded above"))
+    }
+
+    /// Async version of [`NanonisClient::signal_registry_refresh`](crate::NanonisClient::signal_registry_refresh).
+    pub async fn signal_registry_refresh(&mut self) -> Result<&SignalRegistry, NanonisError> {
+        self.signal_registry = None;
+        self.signal_registry().await
+    }
+
+    /// Index for a signal reference, resolving names and slots through the
+    /// cached registry and reloading it once on a failed lookup.
+    pub(crate) async fn resolve_signal(&mut self, signal: impl Into<SignalRef>) -> Result<i32, NanonisError> {
+        let signal = match signal.into() {
+            SignalRef::Index(index) => return Ok(index),
+            signal => signal,
+        };
+        let cached = self.signal_registry.is_some();
+        match self.signal_registry().await?.index_of(&signal) {
+            Err(_) if cached => self.signal_registry_refresh().await?.index_of(&signal),
+            result => result,
+        }
+    }
+
+    /// Signal slot for a channel reference, mapping names and signal
+    /// indexes to the slot their signal is assigned to.
+    pub(crate) async fn resolve_slot(
+        &mut self,
+        channel: impl Into<SlotRef>,
+    ) -> Result<i32, NanonisError> {
+        let channel = match channel.into() {
+            SlotRef::Slot(slot) => return Ok(slot),
+            channel => channel,
+        };
+        let cached = self.signal_registry.is_some();
+        match self.signal_registry().await?.slot_of(&channel) {
+            Err(_) if cached => self.signal_registry_refresh().await?.slot_of(&channel),
+            result => result,
+        }
+    }
+
+    /// Async version of [`NanonisClient::signals_meas_names_get`](crate::NanonisClient::signals_meas_names_get).
+    pub async fn signals_meas_names_get(&mut self) -> Result<Vec<String>, NanonisError> {
+        let response = self.send(&commands::SignalsMeasNamesGet {}).await?;
+
+        let meas_names = response.meas_names;
+        Ok(meas_names)
+    }
+
+    /// Async version of [`NanonisClient::signals_add_rt_get`](crate::NanonisClient::signals_add_rt_get).
+    pub async fn signals_add_rt_get(
+        &mut self,
+    ) -> Result<(Vec<String>, String, String), NanonisError> {
+        let response = self.send(&commands::SignalsAddRtGet {}).await?;
+
+        Ok((
+            response.available_signals,
+            response.internal_23,
+            response.internal_24,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::signals_add_rt_set`](crate::NanonisClient::signals_add_rt_set).
+    pub async fn signals_add_rt_set(
+        &mut self,
+        additional_rt_signal_1: i32,
+        additional_rt_signal_2: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SignalsAddRtSet {
+            additional_rt_signal_1,
+            additional_rt_signal_2,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::signals::SlotRef;
+use crate::spectrum_anlzr::{
+    commands, SpectrumAnalyzerInstance, SpectrumAveraging, SpectrumAveragingMode, SpectrumBandRMS,
+    SpectrumCursorPos, SpectrumCursorType, SpectrumData, SpectrumFFTWindow, SpectrumFreqRange,
+    SpectrumFreqResolution, SpectrumWeightingMode,
+};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::spectrum_anlzr_ch_set`](crate::NanonisClient::spectrum_anlzr_ch_set).
+    pub async fn spectrum_anlzr_ch_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        channel: impl Into<SlotRef>,
+    ) -> Result<(), NanonisError> {
+        let channel_index = self.resolve_slot(channel).await?;
+        self.send(&commands::SpectrumAnlzrChSet {
+            instance: instance.into(),
+            channel_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_ch_get`](crate::NanonisClient::spectrum_anlzr_ch_get).
+    pub async fn spectrum_anlzr_ch_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrChGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(response.channel_index)
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_freq_range_set`](crate::NanonisClient::spectrum_anlzr_freq_range_set).
+    pub async fn spectrum_anlzr_freq_range_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        range_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrFreqRangeSet {
+            instance: instance.into(),
+            range_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_freq_range_get`](crate::NanonisClient::spectrum_anlzr_freq_range_get).
+    pub async fn spectrum_anlzr_freq_range_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<SpectrumFreqRange, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrFreqRangeGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(SpectrumFreqRange {
+            selected_index: response.selected_index,
+            available_ranges_hz: response.available_ranges_hz,
+        })
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_freq_res_set`](crate::NanonisClient::spectrum_anlzr_freq_res_set).
+    pub async fn spectrum_anlzr_freq_res_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        resolution_index: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrFreqResSet {
+            instance: instance.into(),
+            resolution_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_freq_res_get`](crate::NanonisClient::spectrum_anlzr_freq_res_get).
+    pub async fn spectrum_anlzr_freq_res_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<SpectrumFreqResolution, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrFreqResGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(SpectrumFreqResolution {
+            selected_index: response.selected_index,
+            available_resolutions_hz: response.available_resolutions_hz,
+        })
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_fft_window_set`](crate::NanonisClient::spectrum_anlzr_fft_window_set).
+    pub async fn spectrum_anlzr_fft_window_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        window: SpectrumFFTWindow,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrFftWindowSet {
+            instance: instance.into(),
+            window: window.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_fft_window_get`](crate::NanonisClient::spectrum_anlzr_fft_window_get).
+    pub async fn spectrum_anlzr_fft_window_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<SpectrumFFTWindow, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrFftWindowGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        SpectrumFFTWindow::try_from(response.window)
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_averag_set`](crate::NanonisClient::spectrum_anlzr_averag_set).
+    pub async fn spectrum_anlzr_averag_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        averaging: &SpectrumAveraging,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrAveragSet {
+            instance: instance.into(),
+            mode: averaging.mode.into(),
+            weighting: averaging.weighting.into(),
+            count: averaging.count,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_averag_get`](crate::NanonisClient::spectrum_anlzr_averag_get).
+    pub async fn spectrum_anlzr_averag_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<SpectrumAveraging, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrAveragGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(SpectrumAveraging {
+            mode: SpectrumAveragingMode::try_from(response.mode)?,
+            weighting: SpectrumWeightingMode::try_from(response.weighting)?,
+            count: response.count,
+        })
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_ac_coupling_set`](crate::NanonisClient::spectrum_anlzr_ac_coupling_set).
+    pub async fn spectrum_anlzr_ac_coupling_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        enabled: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrAcCouplingSet {
+            instance: instance.into(),
+            enabled: if enabled { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_ac_coupling_get`](crate::NanonisClient::spectrum_anlzr_ac_coupling_get).
+    pub async fn spectrum_anlzr_ac_coupling_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<bool, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrAcCouplingGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(response.enabled != 0)
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_cursor_pos_set`](crate::NanonisClient::spectrum_anlzr_cursor_pos_set).
+    pub async fn spectrum_anlzr_cursor_pos_set(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        cursor_type: SpectrumCursorType,
+        cursor1_x_hz: f64,
+        cursor2_x_hz: f64,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrCursorPosSet {
+            instance: instance.into(),
+            cursor_type: cursor_type.into(),
+            cursor1_x_hz,
+            cursor2_x_hz,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_cursor_pos_get`](crate::NanonisClient::spectrum_anlzr_cursor_pos_get).
+    pub async fn spectrum_anlzr_cursor_pos_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+        cursor_type: SpectrumCursorType,
+    ) -> Result<SpectrumCursorPos, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrCursorPosGet {
+                instance: instance.into(),
+                cursor_type: cursor_type.into(),
+            })
+            .await?;
+
+        Ok(SpectrumCursorPos {
+            cursor1_x_hz: response.cursor1_x_hz,
+            cursor2_x_hz: response.cursor2_x_hz,
+            cursor1_y: response.cursor1_y,
+        })
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_band_rms_get`](crate::NanonisClient::spectrum_anlzr_band_rms_get).
+    pub async fn spectrum_anlzr_band_rms_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<SpectrumBandRMS, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrBandRmsGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(SpectrumBandRMS {
+            rms: response.rms,
+            min_freq_hz: response.min_freq_hz,
+            max_freq_hz: response.max_freq_hz,
+        })
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_dc_get`](crate::NanonisClient::spectrum_anlzr_dc_get).
+    pub async fn spectrum_anlzr_dc_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<f64, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrDcGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(response.dc_value)
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_run`](crate::NanonisClient::spectrum_anlzr_run).
+    pub async fn spectrum_anlzr_run(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::SpectrumAnlzrRun {
+            instance: instance.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::spectrum_anlzr_data_get`](crate::NanonisClient::spectrum_anlzr_data_get).
+    pub async fn spectrum_anlzr_data_get(
+        &mut self,
+        instance: SpectrumAnalyzerInstance,
+    ) -> Result<SpectrumData, NanonisError> {
+        let response = self
+            .send(&commands::SpectrumAnlzrDataGet {
+                instance: instance.into(),
+            })
+            .await?;
+
+        Ok(SpectrumData {
+            f0_hz: response.f0_hz,
+            df_hz: response.df_hz,
+            data: response.data,
+        })
+    }
+}
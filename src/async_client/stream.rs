@@ -0,0 +1,249 @@
+use crate::client::ConnectionConfig;
+use crate::error::NanonisError;
+use crate::signals::SignalFrame;
+use crate::tcplogger_stream::{scan_frame, Scan, FRAME_HEADER_SIZE};
+use futures_core::Stream;
+use log::warn;
+use std::future::Future;
+use std::io::ErrorKind;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpStream;
+
+/// Async TCP Logger stream - connects to data stream only, no control
+///
+/// Async counterpart of [`TCPLoggerStream`](crate::TCPLoggerStream).
+/// Frames are read one at a time with [`read_frame`](Self::read_frame), or
+/// consumed as a [`Stream`] with [`into_stream`](Self::into_stream).
+///
+/// # Examples
+/// ```
+/// use futures_util::{StreamExt, TryStreamExt};
+/// use nanonis_rs::AsyncTCPLoggerStream;
+/// # use tokio::io::AsyncWriteExt;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+/// # let port = listener.local_addr()?.port();
+/// # tokio::spawn(async move {
+/// #     let (mut socket, _) = listener.accept().await.unwrap();
+/// #     for counter in 0..3u64 {
+/// #         let mut frame = Vec::new();
+/// #         frame.extend_from_slice(&2u32.to_be_bytes());
+/// #         frame.extend_from_slice(&1f32.to_be_bytes());
+/// #         frame.extend_from_slice(&counter.to_be_bytes());
+/// #         frame.extend_from_slice(&4u16.to_be_bytes());
+/// #         frame.extend_from_slice(&0.5f32.to_be_bytes());
+/// #         frame.extend_from_slice(&1.5f32.to_be_bytes());
+/// #         socket.write_all(&frame).await.unwrap();
+/// #     }
+/// # });
+/// let stream = AsyncTCPLoggerStream::connect("127.0.0.1", port).await?;
+/// let frames: Vec<_> = stream.into_stream().take(3).try_collect().await?;
+///
+/// assert_eq!(frames.len(), 3);
+/// assert_eq!(frames[2].counter, 2);
+/// assert_eq!(frames[2].data, vec![0.5, 1.5]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncTCPLoggerStream {
+    stream: TcpStream,
+    buffer: Vec<u8>,
+    /// Bytes received but not consumed yet, starting at a frame boundary
+    /// unless the stream is out of sync
+    filled: usize,
+    /// Bytes discarded while searching for the next frame header
+    skipped: usize,
+}
+
+impl AsyncTCPLoggerStream {
+    /// Connect to TCP Logger data stream only, with the default
+    /// configuration
+    ///
+    /// # Arguments
+    /// * `addr` - Server address (e.g., "127.0.0.1")
+    /// * `stream_port` - TCP Logger data stream port (typically 6590)
+    pub async fn connect(addr: &str, stream_port: u16) -> Result<Self, NanonisError> {
+        Self::connect_with_config(addr, stream_port, ConnectionConfig::default()).await
+    }
+
+    /// Connect to TCP Logger data stream only, giving up after
+    /// [`ConnectionConfig::connect_timeout`].
+    pub async fn connect_with_config(
+        addr: &str,
+        stream_port: u16,
+        config: ConnectionConfig,
+    ) -> Result<Self, NanonisError> {
+        let stream = tokio::time::timeout(
+            config.connect_timeout,
+            TcpStream::connect((addr, stream_port)),
+        )
+        .await
+        .map_err(|_| {
+            NanonisError::Timeout(format!(
+                "Connection to TCP stream at {addr}:{stream_port} timed out"
+            ))
+        })?
+        .map_err(|e| NanonisError::Io {
+            source: e,
+            context: format!("Failed to connect to TCP stream at {addr}:{stream_port}"),
+        })?;
+
+        Ok(Self {
+            stream,
+            buffer: Vec::with_capacity(1024),
+            filled: 0,
+            skipped: 0,
+        })
+    }
+
+    /// Read a single data frame from the stream
+    ///
+    /// If the stream is out of sync, bytes are skipped one at a time until
+    /// a valid frame header is found that is followed by another one.
+    ///
+    /// This method is cancel safe: the bytes of a partly read frame are
+    /// kept when the future is dropped, e.g. by `tokio::time::timeout` or
+    /// in `tokio::select!`, and the next call completes the frame.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::AsyncTCPLoggerStream;
+    /// use std::time::Duration;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    /// let port = listener.local_addr()?.port();
+    /// let mut stream = AsyncTCPLoggerStream::connect("127.0.0.1", port).await?;
+    /// let (mut logger, _) = listener.accept().await?;
+    ///
+    /// let mut frame = Vec::new();
+    /// frame.extend_from_slice(&1u32.to_be_bytes());
+    /// frame.extend_from_slice(&1f32.to_be_bytes());
+    /// frame.extend_from_slice(&7u64.to_be_bytes());
+    /// frame.extend_from_slice(&4u16.to_be_bytes());
+    /// frame.extend_from_slice(&0.5f32.to_be_bytes());
+    ///
+    /// // Half a frame, then the read is cancelled
+    /// logger.write_all(&frame[..10]).await?;
+    /// let read = tokio::time::timeout(Duration::from_millis(50), stream.read_frame()).await;
+    /// assert!(read.is_err());
+    ///
+    /// logger.write_all(&frame[10..]).await?;
+    /// let frame = stream.read_frame().await?;
+    /// assert_eq!((frame.counter, frame.data), (7, vec![0.5]));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_frame(&mut self) -> Result<SignalFrame, NanonisError> {
+        loop {
+            match scan_frame(&self.buffer[..self.filled], self.skipped > 0) {
+                Scan::Need(size) => {
+                    let context = if self.filled < FRAME_HEADER_SIZE {
+                        "Reading TCP Logger frame header"
+                    } else {
+                        "Reading TCP Logger frame data"
+                    };
+                    self.fill(size, context).await?;
+                }
+                Scan::Invalid(e) => {
+                    if self.skipped == 0 {
+                        warn!("TCP Logger stream out of sync: {e}");
+                    }
+                    self.skipped += 1;
+                    self.buffer.copy_within(1..self.filled, 0);
+                    self.filled -= 1;
+                }
+                Scan::Frame(header, size) => {
+                    if self.skipped > 0 {
+                        warn!(
+                            "TCP Logger stream resynchronised after skipping {} bytes",
+                            self.skipped
+                        );
+                        self.skipped = 0;
+                    }
+                    let data = header.parse_data(&self.buffer[FRAME_HEADER_SIZE..size])?;
+                    // Keep a following header read to confirm this frame
+                    self.buffer.copy_within(size..self.filled, 0);
+                    self.filled -= size;
+                    return Ok(header.into_frame(data, None));
+                }
+            }
+        }
+    }
+
+    /// Read until the first `size` bytes of `buffer` are filled, keeping
+    /// the progress in `filled` across failed or cancelled reads.
+    async fn fill(&mut self, size: usize, context: &str) -> Result<(), NanonisError> {
+        if self.buffer.len() < size {
+            self.buffer.resize(size, 0);
+        }
+        while self.filled < size {
+            match self.stream.read(&mut self.buffer[self.filled..size]).await {
+                Ok(0) => {
+                    return Err(NanonisError::Io {
+                        source: ErrorKind::UnexpectedEof.into(),
+                        context: context.to_string(),
+                    })
+                }
+                Ok(n) => self.filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    return Err(NanonisError::Io {
+                        source: e,
+                        context: context.to_string(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert into a [`Stream`] of frames.
+    ///
+    /// The stream yields frames until a read fails; the error is yielded as
+    /// the last item.
+    pub fn into_stream(self) -> SignalFrameStream {
+        SignalFrameStream {
+            pending: Some(read_next(self)),
+        }
+    }
+}
+
+type PendingRead =
+    Pin<Box<dyn Future<Output = (AsyncTCPLoggerStream, Result<SignalFrame, NanonisError>)> + Send>>;
+
+fn read_next(mut stream: AsyncTCPLoggerStream) -> PendingRead {
+    Box::pin(async move {
+        let result = stream.read_frame().await;
+        (stream, result)
+    })
+}
+
+/// [`Stream`] of [`SignalFrame`]s, created by
+/// [`AsyncTCPLoggerStream::into_stream`].
+pub struct SignalFrameStream {
+    pending: Option<PendingRead>,
+}
+
+impl Stream for SignalFrameStream {
+    type Item = Result<SignalFrame, NanonisError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let Some(pending) = self.pending.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let (stream, result) = ready!(pending.as_mut().poll(cx));
+        self.pending = match result {
+            Ok(_) => Some(read_next(stream)),
+            Err(_) => None,
+        };
+        Poll::Ready(Some(result))
+    }
+}
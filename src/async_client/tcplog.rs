@@ -0,0 +1,78 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::signals::SlotRef;
+use crate::tcplog::{commands, TCPLogStatus};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::tcplog_start`](crate::NanonisClient::tcplog_start).
+    pub async fn tcplog_start(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::TcpLogStart {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tcplog_stop`](crate::NanonisClient::tcplog_stop).
+    pub async fn tcplog_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::TcpLogStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tcplog_chs_set`](crate::NanonisClient::tcplog_chs_set).
+    pub async fn tcplog_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let mut channel_indexes = Vec::new();
+        for channel in channels {
+            channel_indexes.push(self.resolve_slot(channel).await?);
+        }
+        for &index in &channel_indexes {
+            if !(0..=23).contains(&index) {
+                return Err(NanonisError::Protocol(
+                    format!("Invalid signal slot index: {}. Must be between 0-23 (signal slots, not full signal indices)", index)
+                ));
+            }
+        }
+        let num_channels = channel_indexes.len() as i32;
+
+        self.send(&commands::TcpLogChsSet {
+            num_channels,
+            channel_indexes,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tcplog_oversampl_set`](crate::NanonisClient::tcplog_oversampl_set).
+    pub async fn tcplog_oversampl_set(
+        &mut self,
+        oversampling_value: i32,
+    ) -> Result<(), NanonisError> {
+        if !(0..=1000).contains(&oversampling_value) {
+            return Err(NanonisError::Protocol(format!(
+                "Invalid oversampling value: {}. Must be between 0-1000",
+                oversampling_value
+            )));
+        }
+
+        self.send(&commands::TcpLogOversamplSet { oversampling_value })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tcplog_status_get`](crate::NanonisClient::tcplog_status_get).
+    pub async fn tcplog_status_get(&mut self) -> Result<TCPLogStatus, NanonisError> {
+        let response = self.send(&commands::TcpLogStatusGet {}).await?;
+
+        match response.status {
+            0 => Ok(TCPLogStatus::Disconnected),
+            1 => Ok(TCPLogStatus::Idle),
+            2 => Ok(TCPLogStatus::Start),
+            3 => Ok(TCPLogStatus::Stop),
+            4 => Ok(TCPLogStatus::Running),
+            5 => Ok(TCPLogStatus::TCPConnect),
+            6 => Ok(TCPLogStatus::TCPDisconnect),
+            7 => Ok(TCPLogStatus::BufferOverflow),
+            _ => Err(NanonisError::Protocol("Invalid Status value".to_string())),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::tip_recovery::{commands, TipShaperConfig, TipShaperProps};
+use crate::types::Array2D;
+use std::time::Duration;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::tip_rec_buffer_size_set`](crate::NanonisClient::tip_rec_buffer_size_set).
+    pub async fn tip_rec_buffer_size_set(&mut self, buffer_size: i32) -> Result<(), NanonisError> {
+        self.send(&commands::TipRecBufferSizeSet { buffer_size })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tip_rec_buffer_size_get`](crate::NanonisClient::tip_rec_buffer_size_get).
+    pub async fn tip_rec_buffer_size_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::TipRecBufferSizeGet {}).await?;
+
+        Ok(response.buffer_size)
+    }
+
+    /// Async version of [`NanonisClient::tip_rec_buffer_clear`](crate::NanonisClient::tip_rec_buffer_clear).
+    pub async fn tip_rec_buffer_clear(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::TipRecBufferClear {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tip_rec_data_get`](crate::NanonisClient::tip_rec_data_get).
+    pub async fn tip_rec_data_get(&mut self) -> Result<(Vec<i32>, Array2D<f32>), NanonisError> {
+        let response = self.send(&commands::TipRecDataGet {}).await?;
+
+        let channel_indexes = response.channel_indexes;
+        let data_2d = response.data_2d;
+
+        Ok((channel_indexes, data_2d))
+    }
+
+    /// Async version of [`NanonisClient::tip_rec_data_save`](crate::NanonisClient::tip_rec_data_save).
+    pub async fn tip_rec_data_save(
+        &mut self,
+        clear_buffer: bool,
+        basename: &str,
+    ) -> Result<(), NanonisError> {
+        let clear_flag = if clear_buffer { 1u32 } else { 0u32 };
+
+        self.send(&commands::TipRecDataSave {
+            clear_buffer: clear_flag,
+            basename: basename.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tip_shaper_start`](crate::NanonisClient::tip_shaper_start).
+    pub async fn tip_shaper_start(
+        &mut self,
+        wait_until_finished: bool,
+        timeout: Duration,
+    ) -> Result<(), NanonisError> {
+        let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
+        let timeout = timeout.as_millis().min(u32::MAX as u128) as i32;
+
+        self.send(&commands::TipShaperStart {
+            wait_until_finished: wait_flag,
+            timeout,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tip_shaper_props_set`](crate::NanonisClient::tip_shaper_props_set).
+    pub async fn tip_shaper_props_set(
+        &mut self,
+        config: TipShaperConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::TipShaperPropsSet {
+            switch_off_delay: config.switch_off_delay.as_secs_f32(),
+            change_bias: config.change_bias.into(),
+            bias_v: config.bias_v,
+            tip_lift_m: config.tip_lift_m,
+            lift_time_1: config.lift_time_1.as_secs_f32(),
+            bias_lift_v: config.bias_lift_v,
+            bias_settling_time: config.bias_settling_time.as_secs_f32(),
+            lift_height_m: config.lift_height_m,
+            lift_time_2: config.lift_time_2.as_secs_f32(),
+            end_wait_time: config.end_wait_time.as_secs_f32(),
+            restore_feedback: config.restore_feedback.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::tip_shaper_props_get`](crate::NanonisClient::tip_shaper_props_get).
+    pub async fn tip_shaper_props_get(&mut self) -> Result<TipShaperProps, NanonisError> {
+        let response = self.send(&commands::TipShaperPropsGet {}).await?;
+
+        Ok((
+            response.switch_off_delay,
+            response.change_bias,
+            response.bias_v,
+            response.tip_lift_m,
+            response.lift_time_1,
+            response.bias_lift_v,
+            response.bias_settling_time,
+            response.lift_height_m,
+            response.lift_time_2,
+            response.end_wait_time,
+            response.restore_feedback,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::tip_shaper_config_get`](crate::NanonisClient::tip_shaper_config_get).
+    pub async fn tip_shaper_config_get(&mut self) -> Result<TipShaperConfig, NanonisError> {
+        let response = self.send(&commands::TipShaperPropsGet {}).await?;
+
+        let restore_feedback = match response.restore_feedback {
+            0 => true,
+            1 => false,
+            _ => panic!("Wrong return value for restore_feedback"),
+        };
+
+        let change_bias = match response.change_bias {
+            0 => true,
+            1 => false,
+            _ => panic!("Wrong return value for change_bias"),
+        };
+
+        Ok(TipShaperConfig {
+            switch_off_delay: Duration::from_secs_f32(response.switch_off_delay),
+            change_bias,
+            bias_v: response.bias_v,
+            tip_lift_m: response.tip_lift_m,
+            lift_time_1: Duration::from_secs_f32(response.lift_time_1),
+            bias_lift_v: response.bias_lift_v,
+            bias_settling_time: Duration::from_secs_f32(response.bias_settling_time),
+            lift_height_m: response.lift_height_m,
+            lift_time_2: Duration::from_secs_f32(response.lift_time_2),
+            end_wait_time: Duration::from_secs_f32(response.end_wait_time),
+            restore_feedback,
+        })
+    }
+}
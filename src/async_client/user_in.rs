@@ -0,0 +1,21 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::user_in::commands;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::user_in_calibr_set`](crate::NanonisClient::user_in_calibr_set).
+    pub async fn user_in_calibr_set(
+        &mut self,
+        input_index: i32,
+        calibration_per_volt: f32,
+        offset_physical_units: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UserInCalibrSet {
+            input_index,
+            calibration_per_volt,
+            offset_physical_units,
+        })
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::signals::SignalRef;
+use crate::user_out::{commands, CalcOperation, CalcSignalConfig, OutputMode};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::user_out_mode_set`](crate::NanonisClient::user_out_mode_set).
+    pub async fn user_out_mode_set(
+        &mut self,
+        output_index: i32,
+        output_mode: OutputMode,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UserOutModeSet {
+            output_index,
+            output_mode: output_mode.into(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_mode_get`](crate::NanonisClient::user_out_mode_get).
+    pub async fn user_out_mode_get(
+        &mut self,
+        output_index: i32,
+    ) -> Result<OutputMode, NanonisError> {
+        let response = self
+            .send(&commands::UserOutModeGet { output_index })
+            .await?;
+        let mode_val = response.output_mode;
+        OutputMode::try_from(mode_val)
+    }
+
+    /// Async version of [`NanonisClient::user_out_monitor_ch_set`](crate::NanonisClient::user_out_monitor_ch_set).
+    pub async fn user_out_monitor_ch_set(
+        &mut self,
+        output_index: i32,
+        monitor_channel: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let monitor_channel_index = self.resolve_signal(monitor_channel).await?;
+        self.send(&commands::UserOutMonitorChSet {
+            output_index,
+            monitor_channel_index,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_monitor_ch_get`](crate::NanonisClient::user_out_monitor_ch_get).
+    pub async fn user_out_monitor_ch_get(
+        &mut self,
+        output_index: i32,
+    ) -> Result<i32, NanonisError> {
+        let response = self
+            .send(&commands::UserOutMonitorChGet { output_index })
+            .await?;
+        Ok(response.monitor_channel_index)
+    }
+
+    /// Async version of [`NanonisClient::user_out_val_set`](crate::NanonisClient::user_out_val_set).
+    pub async fn user_out_val_set(
+        &mut self,
+        output_index: i32,
+        output_value: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UserOutValSet {
+            output_index,
+            output_value,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_calibr_set`](crate::NanonisClient::user_out_calibr_set).
+    pub async fn user_out_calibr_set(
+        &mut self,
+        output_index: i32,
+        calibration_per_volt: f32,
+        offset_in_physical_units: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UserOutCalibrSet {
+            output_index,
+            calibration_per_volt,
+            offset_in_physical_units,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_calc_signal_name_set`](crate::NanonisClient::user_out_calc_signal_name_set).
+    pub async fn user_out_calc_signal_name_set(
+        &mut self,
+        output_index: i32,
+        calculated_signal_name: String,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UserOutCalcSignalNameSet {
+            output_index,
+            calculated_signal_name,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_calc_signal_name_get`](crate::NanonisClient::user_out_calc_signal_name_get).
+    pub async fn user_out_calc_signal_name_get(
+        &mut self,
+        output_index: i32,
+    ) -> Result<String, NanonisError> {
+        let response = self
+            .send(&commands::UserOutCalcSignalNameGet { output_index })
+            .await?;
+        Ok(response.signal_name)
+    }
+
+    /// Async version of [`NanonisClient::user_out_calc_signal_config_set`](crate::NanonisClient::user_out_calc_signal_config_set).
+    pub async fn user_out_calc_signal_config_set(
+        &mut self,
+        output_index: i32,
+        config: CalcSignalConfig,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UserOutCalcSignalConfigSet {
+            output_index,
+            signal_1: config.signal_1,
+            operation: config.operation.into(),
+            signal_2: config.signal_2,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_calc_signal_config_get`](crate::NanonisClient::user_out_calc_signal_config_get).
+    pub async fn user_out_calc_signal_config_get(
+        &mut self,
+        output_index: i32,
+    ) -> Result<CalcSignalConfig, NanonisError> {
+        let response = self
+            .send(&commands::UserOutCalcSignalConfigGet { output_index })
+            .await?;
+        let signal_1 = response.signal_1;
+        let operation = CalcOperation::try_from(response.operation)?;
+        let signal_2 = response.signal_2;
+        Ok(CalcSignalConfig::new(signal_1, operation, signal_2))
+    }
+
+    /// Async version of [`NanonisClient::user_out_limits_set`](crate::NanonisClient::user_out_limits_set).
+    pub async fn user_out_limits_set(
+        &mut self,
+        output_index: i32,
+        upper_limit: f32,
+        lower_limit: f32,
+        raw_limits: bool,
+    ) -> Result<(), NanonisError> {
+        let raw_flag = if raw_limits { 1u32 } else { 0u32 };
+        self.send(&commands::UserOutLimitsSet {
+            output_index,
+            upper_limit,
+            lower_limit,
+            raw_limits: raw_flag,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::user_out_limits_get`](crate::NanonisClient::user_out_limits_get).
+    pub async fn user_out_limits_get(
+        &mut self,
+        output_index: i32,
+        raw_limits: bool,
+    ) -> Result<(f32, f32), NanonisError> {
+        let raw_flag = if raw_limits { 1u32 } else { 0u32 };
+        let response = self
+            .send(&commands::UserOutLimitsGet {
+                output_index,
+                raw_limits: raw_flag,
+            })
+            .await?;
+        Ok((response.upper_limit, response.lower_limit))
+    }
+}
@@ -0,0 +1,156 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::util::{commands, VersionInfo};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::util_session_path_get`](crate::NanonisClient::util_session_path_get).
+    pub async fn util_session_path_get(&mut self) -> Result<String, NanonisError> {
+        let response = self.send(&commands::UtilSessionPathGet {}).await?;
+        Ok(response.session_path)
+    }
+
+    /// Async version of [`NanonisClient::util_settings_load`](crate::NanonisClient::util_settings_load).
+    pub async fn util_settings_load(
+        &mut self,
+        settings_file_path: &str,
+        load_session_settings: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilSettingsLoad {
+            settings_file_path: settings_file_path.to_string(),
+            load_session_settings: if load_session_settings { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_settings_save`](crate::NanonisClient::util_settings_save).
+    pub async fn util_settings_save(
+        &mut self,
+        settings_file_path: &str,
+        save_session_settings: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilSettingsSave {
+            settings_file_path: settings_file_path.to_string(),
+            save_session_settings: if save_session_settings { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_layout_load`](crate::NanonisClient::util_layout_load).
+    pub async fn util_layout_load(
+        &mut self,
+        layout_file_path: &str,
+        load_session_layout: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilLayoutLoad {
+            layout_file_path: layout_file_path.to_string(),
+            load_session_layout: if load_session_layout { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_layout_save`](crate::NanonisClient::util_layout_save).
+    pub async fn util_layout_save(
+        &mut self,
+        layout_file_path: &str,
+        save_session_layout: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilLayoutSave {
+            layout_file_path: layout_file_path.to_string(),
+            save_session_layout: if save_session_layout { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_lock`](crate::NanonisClient::util_lock).
+    pub async fn util_lock(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::UtilLock {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_unlock`](crate::NanonisClient::util_unlock).
+    pub async fn util_unlock(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::UtilUnLock {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_rt_freq_set`](crate::NanonisClient::util_rt_freq_set).
+    pub async fn util_rt_freq_set(&mut self, rt_frequency: f32) -> Result<(), NanonisError> {
+        self.send(&commands::UtilRtFreqSet { rt_frequency }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_rt_freq_get`](crate::NanonisClient::util_rt_freq_get).
+    pub async fn util_rt_freq_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::UtilRtFreqGet {}).await?;
+
+        Ok(response.rt_frequency)
+    }
+
+    /// Async version of [`NanonisClient::util_acq_period_set`](crate::NanonisClient::util_acq_period_set).
+    pub async fn util_acq_period_set(
+        &mut self,
+        acquisition_period: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilAcqPeriodSet { acquisition_period })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_acq_period_get`](crate::NanonisClient::util_acq_period_get).
+    pub async fn util_acq_period_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::UtilAcqPeriodGet {}).await?;
+
+        Ok(response.acquisition_period)
+    }
+
+    /// Async version of [`NanonisClient::util_rt_oversampl_set`](crate::NanonisClient::util_rt_oversampl_set).
+    pub async fn util_rt_oversampl_set(
+        &mut self,
+        rt_oversampling: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilRtOversamplSet { rt_oversampling })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_rt_oversampl_get`](crate::NanonisClient::util_rt_oversampl_get).
+    pub async fn util_rt_oversampl_get(&mut self) -> Result<i32, NanonisError> {
+        let response = self.send(&commands::UtilRtOversamplGet {}).await?;
+
+        Ok(response.rt_oversampling)
+    }
+
+    /// Async version of [`NanonisClient::util_quit`](crate::NanonisClient::util_quit).
+    pub async fn util_quit(
+        &mut self,
+        use_stored_values: bool,
+        settings_name: &str,
+        layout_name: &str,
+        save_signals: bool,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::UtilQuit {
+            use_stored_values: if use_stored_values { 1 } else { 0 },
+            settings_name: settings_name.to_string(),
+            layout_name: layout_name.to_string(),
+            save_signals: if save_signals { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::util_version_get`](crate::NanonisClient::util_version_get).
+    pub async fn util_version_get(&mut self) -> Result<VersionInfo, NanonisError> {
+        let response = self.send(&commands::UtilVersionGet {}).await?;
+
+        Ok(VersionInfo {
+            product_line: response.product_line,
+            version: response.version,
+            host_app_release: response.host_app_release,
+            rt_engine_release: response.rt_engine_release,
+        })
+    }
+}
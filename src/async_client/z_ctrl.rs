@@ -0,0 +1,196 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::units::{Meters, Seconds, Setpoint};
+use crate::z_ctrl::{commands, ZControllerStatus};
+use std::time::Duration;
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::z_ctrl_on_off_set`](crate::NanonisClient::z_ctrl_on_off_set).
+    pub async fn z_ctrl_on_off_set(&mut self, controller_on: bool) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlOnOffSet {
+            status: controller_on as u32,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_on_off_get`](crate::NanonisClient::z_ctrl_on_off_get).
+    pub async fn z_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        Ok(self.send(&commands::ZCtrlOnOffGet {}).await?.status == 1)
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_z_pos_set`](crate::NanonisClient::z_ctrl_z_pos_set).
+    pub async fn z_ctrl_z_pos_set(
+        &mut self,
+        z_position_m: impl Into<Meters>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlZPosSet {
+            z_position: z_position_m.into().as_f32(),
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_z_pos_get`](crate::NanonisClient::z_ctrl_z_pos_get).
+    pub async fn z_ctrl_z_pos_get(&mut self) -> Result<f32, NanonisError> {
+        Ok(self.send(&commands::ZCtrlZPosGet {}).await?.z_position)
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_setpoint_set`](crate::NanonisClient::z_ctrl_setpoint_set).
+    pub async fn z_ctrl_setpoint_set(&mut self, setpoint: impl Into<Setpoint>) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlSetpntSet {
+            setpoint: setpoint.into().as_f32(),
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_setpoint_get`](crate::NanonisClient::z_ctrl_setpoint_get).
+    pub async fn z_ctrl_setpoint_get(&mut self) -> Result<f32, NanonisError> {
+        Ok(self.send(&commands::ZCtrlSetpntGet {}).await?.setpoint)
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_gain_set`](crate::NanonisClient::z_ctrl_gain_set).
+    pub async fn z_ctrl_gain_set(
+        &mut self,
+        p_gain: f32,
+        time_constant_s: impl Into<Seconds>,
+        i_gain: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlGainSet {
+            p_gain,
+            time_constant: time_constant_s.into().as_f32(),
+            i_gain,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_gain_get`](crate::NanonisClient::z_ctrl_gain_get).
+    pub async fn z_ctrl_gain_get(&mut self) -> Result<(f32, f32, f32), NanonisError> {
+        let gains = self.send(&commands::ZCtrlGainGet {}).await?;
+        Ok((gains.p_gain, gains.time_constant, gains.i_gain))
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_home`](crate::NanonisClient::z_ctrl_home).
+    pub async fn z_ctrl_home(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlHome {}).await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_withdraw`](crate::NanonisClient::z_ctrl_withdraw).
+    pub async fn z_ctrl_withdraw(
+        &mut self,
+        wait_until_finished: bool,
+        timeout_ms: Duration,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlWithdraw {
+            wait_until_finished: wait_until_finished as u32,
+            timeout_ms: timeout_ms.as_millis() as i32,
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_limits_set`](crate::NanonisClient::z_ctrl_limits_set).
+    pub async fn z_ctrl_limits_set(
+        &mut self,
+        high_limit_m: impl Into<Meters>,
+        low_limit_m: impl Into<Meters>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlLimitsSet {
+            high_limit: high_limit_m.into().as_f32(),
+            low_limit: low_limit_m.into().as_f32(),
+        })
+        .await
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_limits_get`](crate::NanonisClient::z_ctrl_limits_get).
+    pub async fn z_ctrl_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let limits = self.send(&commands::ZCtrlLimitsGet {}).await?;
+        Ok((limits.high_limit, limits.low_limit))
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_status_get`](crate::NanonisClient::z_ctrl_status_get).
+    pub async fn z_ctrl_status_get(&mut self) -> Result<ZControllerStatus, NanonisError> {
+        ZControllerStatus::try_from(self.send(&commands::ZCtrlStatusGet {}).await?.status)
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_switch_off_delay_set`](crate::NanonisClient::z_ctrl_switch_off_delay_set).
+    pub async fn z_ctrl_switch_off_delay_set(
+        &mut self,
+        delay_s: impl Into<Seconds>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlSwitchOffDelaySet {
+            delay_s: delay_s.into().as_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_switch_off_delay_get`](crate::NanonisClient::z_ctrl_switch_off_delay_get).
+    pub async fn z_ctrl_switch_off_delay_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::ZCtrlSwitchOffDelayGet {}).await?;
+        Ok(response.delay_s)
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_home_props_set`](crate::NanonisClient::z_ctrl_home_props_set).
+    pub async fn z_ctrl_home_props_set(
+        &mut self,
+        home_mode: u16,
+        home_position_m: impl Into<Meters>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlHomePropsSet {
+            home_mode,
+            home_position_m: home_position_m.into().as_f32(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_home_props_get`](crate::NanonisClient::z_ctrl_home_props_get).
+    pub async fn z_ctrl_home_props_get(&mut self) -> Result<(bool, f32), NanonisError> {
+        let response = self.send(&commands::ZCtrlHomePropsGet {}).await?;
+
+        Ok((response.home_mode != 0, response.home_position_m))
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_active_ctrl_set`](crate::NanonisClient::z_ctrl_active_ctrl_set).
+    pub async fn z_ctrl_active_ctrl_set(
+        &mut self,
+        controller_index: i32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlActiveCtrlSet { controller_index })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_ctrl_list_get`](crate::NanonisClient::z_ctrl_ctrl_list_get).
+    pub async fn z_ctrl_ctrl_list_get(&mut self) -> Result<(Vec<String>, i32), NanonisError> {
+        let response = self.send(&commands::ZCtrlCtrlListGet {}).await?;
+
+        Ok((response.names, response.active_index))
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_withdraw_rate_set`](crate::NanonisClient::z_ctrl_withdraw_rate_set).
+    pub async fn z_ctrl_withdraw_rate_set(&mut self, rate_m_s: f32) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlWithdrawRateSet { rate_m_s })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_withdraw_rate_get`](crate::NanonisClient::z_ctrl_withdraw_rate_get).
+    pub async fn z_ctrl_withdraw_rate_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::ZCtrlWithdrawRateGet {}).await?;
+        Ok(response.rate_m_s)
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_limits_enabled_set`](crate::NanonisClient::z_ctrl_limits_enabled_set).
+    pub async fn z_ctrl_limits_enabled_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlLimitsEnabledSet {
+            enabled: if enabled { 1 } else { 0 },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_ctrl_limits_enabled_get`](crate::NanonisClient::z_ctrl_limits_enabled_get).
+    pub async fn z_ctrl_limits_enabled_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::ZCtrlLimitsEnabledGet {}).await?;
+        Ok(response.enabled != 0)
+    }
+}
@@ -0,0 +1,338 @@
+use super::AsyncNanonisClient;
+use crate::error::NanonisError;
+use crate::signals::{SignalRef, SlotRef};
+use crate::z_spectr::{commands, ZSpectroscopyResult};
+
+impl AsyncNanonisClient {
+    /// Async version of [`NanonisClient::z_spectr_open`](crate::NanonisClient::z_spectr_open).
+    pub async fn z_spectr_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrOpen {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_start`](crate::NanonisClient::z_spectr_start).
+    pub async fn z_spectr_start(
+        &mut self,
+        get_data: bool,
+        save_base_name: &str,
+    ) -> Result<ZSpectroscopyResult, NanonisError> {
+        let get_data_flag = if get_data { 1u32 } else { 0u32 };
+
+        let response = self
+            .send(&commands::ZSpectrStart {
+                get_data: get_data_flag,
+                save_base_name: save_base_name.to_string(),
+            })
+            .await?;
+
+        Ok((
+            response.channel_names,
+            response.data_2d,
+            response.parameters,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_stop`](crate::NanonisClient::z_spectr_stop).
+    pub async fn z_spectr_stop(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrStop {}).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_status_get`](crate::NanonisClient::z_spectr_status_get).
+    pub async fn z_spectr_status_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::ZSpectrStatusGet {}).await?;
+
+        Ok(response.running == 1)
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_chs_set`](crate::NanonisClient::z_spectr_chs_set).
+    pub async fn z_spectr_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let mut channel_indexes = Vec::new();
+        for channel in channels {
+            channel_indexes.push(self.resolve_slot(channel).await?);
+        }
+        self.send(&commands::ZSpectrChsSet { channel_indexes })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_chs_get`](crate::NanonisClient::z_spectr_chs_get).
+    pub async fn z_spectr_chs_get(&mut self) -> Result<(Vec<i32>, Vec<String>), NanonisError> {
+        let response = self.send(&commands::ZSpectrChsGet {}).await?;
+
+        Ok((response.channel_indexes, response.channel_names))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_range_set`](crate::NanonisClient::z_spectr_range_set).
+    pub async fn z_spectr_range_set(
+        &mut self,
+        z_offset_m: f32,
+        z_sweep_distance_m: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrRangeSet {
+            z_offset_m,
+            z_sweep_distance_m,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_range_get`](crate::NanonisClient::z_spectr_range_get).
+    pub async fn z_spectr_range_get(&mut self) -> Result<(f32, f32), NanonisError> {
+        let response = self.send(&commands::ZSpectrRangeGet {}).await?;
+
+        Ok((response.z_offset_m, response.z_sweep_distance_m))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_timing_set`](crate::NanonisClient::z_spectr_timing_set).
+    pub async fn z_spectr_timing_set(
+        &mut self,
+        z_averaging_time_s: f32,
+        initial_settling_time_s: f32,
+        maximum_slew_rate_vdivs: f32,
+        settling_time_s: f32,
+        integration_time_s: f32,
+        end_settling_time_s: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrTimingSet {
+            z_averaging_time_s,
+            initial_settling_time_s,
+            maximum_slew_rate_vdivs,
+            settling_time_s,
+            integration_time_s,
+            end_settling_time_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_timing_get`](crate::NanonisClient::z_spectr_timing_get).
+    pub async fn z_spectr_timing_get(
+        &mut self,
+    ) -> Result<(f32, f32, f32, f32, f32, f32), NanonisError> {
+        let response = self.send(&commands::ZSpectrTimingGet {}).await?;
+
+        Ok((
+            response.z_averaging_time_s,
+            response.initial_settling_time_s,
+            response.maximum_slew_rate_vdivs,
+            response.settling_time_s,
+            response.integration_time_s,
+            response.end_settling_time_s,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_retract_set`](crate::NanonisClient::z_spectr_retract_set).
+    pub async fn z_spectr_retract_set(
+        &mut self,
+        enable: bool,
+        threshold: f32,
+        signal: impl Into<SignalRef>,
+        comparison: u16,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        let enable_flag = if enable { 1u16 } else { 0u16 };
+
+        self.send(&commands::ZSpectrRetractSet {
+            enable: enable_flag,
+            threshold,
+            signal_index,
+            comparison,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_retract_get`](crate::NanonisClient::z_spectr_retract_get).
+    pub async fn z_spectr_retract_get(&mut self) -> Result<(bool, f32, i32, u16), NanonisError> {
+        let response = self.send(&commands::ZSpectrRetractGet {}).await?;
+
+        let enabled = response.enabled == 1;
+        let threshold = response.threshold;
+        let signal_index = response.signal_index;
+        let comparison = response.comparison;
+        Ok((enabled, threshold, signal_index, comparison))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_props_set`](crate::NanonisClient::z_spectr_props_set).
+    pub async fn z_spectr_props_set(
+        &mut self,
+        backward_sweep: u16,
+        num_points: i32,
+        num_sweeps: u16,
+        autosave: u16,
+        show_save_dialog: u16,
+        save_all: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrPropsSet {
+            backward_sweep,
+            num_points,
+            num_sweeps,
+            autosave,
+            show_save_dialog,
+            save_all,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_props_get`](crate::NanonisClient::z_spectr_props_get).
+    pub async fn z_spectr_props_get(
+        &mut self,
+    ) -> Result<(bool, i32, u16, bool, bool, bool), NanonisError> {
+        let response = self.send(&commands::ZSpectrPropsGet {}).await?;
+
+        Ok((
+            response.backward_sweep != 0,
+            response.num_points,
+            response.num_sweeps,
+            response.autosave != 0,
+            response.show_save_dialog != 0,
+            response.save_all != 0,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_adv_props_set`](crate::NanonisClient::z_spectr_adv_props_set).
+    pub async fn z_spectr_adv_props_set(
+        &mut self,
+        time_between_sweeps_s: f32,
+        record_final_z: u16,
+        lockin_run: u16,
+        reset_z: u16,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrAdvPropsSet {
+            time_between_sweeps_s,
+            record_final_z,
+            lockin_run,
+            reset_z,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_adv_props_get`](crate::NanonisClient::z_spectr_adv_props_get).
+    pub async fn z_spectr_adv_props_get(
+        &mut self,
+    ) -> Result<(f32, bool, bool, bool), NanonisError> {
+        let response = self.send(&commands::ZSpectrAdvPropsGet {}).await?;
+
+        Ok((
+            response.time_between_sweeps_s,
+            response.record_final_z != 0,
+            response.lockin_run != 0,
+            response.reset_z != 0,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_retract_delay_set`](crate::NanonisClient::z_spectr_retract_delay_set).
+    pub async fn z_spectr_retract_delay_set(&mut self, delay_s: f32) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrRetractDelaySet { delay_s })
+            .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_retract_delay_get`](crate::NanonisClient::z_spectr_retract_delay_get).
+    pub async fn z_spectr_retract_delay_get(&mut self) -> Result<f32, NanonisError> {
+        let response = self.send(&commands::ZSpectrRetractDelayGet {}).await?;
+        Ok(response.delay_s)
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_retract_second_set`](crate::NanonisClient::z_spectr_retract_second_set).
+    pub async fn z_spectr_retract_second_set(
+        &mut self,
+        condition: i32,
+        threshold: f32,
+        signal: impl Into<SignalRef>,
+        comparison: u16,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal).await?;
+        self.send(&commands::ZSpectrRetractSecondSet {
+            condition,
+            threshold,
+            signal_index,
+            comparison,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_retract_second_get`](crate::NanonisClient::z_spectr_retract_second_get).
+    pub async fn z_spectr_retract_second_get(
+        &mut self,
+    ) -> Result<(i32, f32, i32, u16), NanonisError> {
+        let response = self.send(&commands::ZSpectrRetractSecondGet {}).await?;
+
+        Ok((
+            response.condition,
+            response.threshold,
+            response.signal_index,
+            response.comparison,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_dig_sync_set`](crate::NanonisClient::z_spectr_dig_sync_set).
+    pub async fn z_spectr_dig_sync_set(&mut self, dig_sync: u16) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrDigSyncSet { dig_sync }).await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_dig_sync_get`](crate::NanonisClient::z_spectr_dig_sync_get).
+    pub async fn z_spectr_dig_sync_get(&mut self) -> Result<u16, NanonisError> {
+        let response = self.send(&commands::ZSpectrDigSyncGet {}).await?;
+        Ok(response.dig_sync)
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_ttl_sync_set`](crate::NanonisClient::z_spectr_ttl_sync_set).
+    pub async fn z_spectr_ttl_sync_set(
+        &mut self,
+        ttl_line: u16,
+        polarity: u16,
+        time_to_on_s: f32,
+        on_duration_s: f32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrTtlSyncSet {
+            ttl_line,
+            polarity,
+            time_to_on_s,
+            on_duration_s,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_ttl_sync_get`](crate::NanonisClient::z_spectr_ttl_sync_get).
+    pub async fn z_spectr_ttl_sync_get(&mut self) -> Result<(u16, u16, f32, f32), NanonisError> {
+        let response = self.send(&commands::ZSpectrTtlSyncGet {}).await?;
+
+        Ok((
+            response.ttl_line,
+            response.polarity,
+            response.time_to_on_s,
+            response.on_duration_s,
+        ))
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_pulse_seq_sync_set`](crate::NanonisClient::z_spectr_pulse_seq_sync_set).
+    pub async fn z_spectr_pulse_seq_sync_set(
+        &mut self,
+        pulse_seq_nr: u16,
+        num_periods: u32,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZSpectrPulseSeqSyncSet {
+            pulse_seq_nr,
+            num_periods,
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Async version of [`NanonisClient::z_spectr_pulse_seq_sync_get`](crate::NanonisClient::z_spectr_pulse_seq_sync_get).
+    pub async fn z_spectr_pulse_seq_sync_get(&mut self) -> Result<(u16, u32), NanonisError> {
+        let response = self.send(&commands::ZSpectrPulseSeqSyncGet {}).await?;
+
+        Ok((response.pulse_seq_nr, response.num_periods))
+    }
+}
@@ -0,0 +1,69 @@
+//! Typed descriptors for the `AtomTrack.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `AtomTrack.CtrlSet`: turn the selected Atom Tracking control on or off.
+    pub struct AtomTrackCtrlSet: "AtomTrack.CtrlSet" {
+        /// Which control to switch (modulation, controller, or drift measurement)
+        pub control: u16 = "H",
+        /// True to enable, false to disable
+        pub enabled: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `AtomTrack.StatusGet`: get the status of the selected Atom Tracking control.
+    pub struct AtomTrackStatusGet: "AtomTrack.StatusGet" {
+        /// Which control to query
+        pub control: u16 = "H",
+    } -> AtomTrackStatusGetResponse {
+        /// Control status: 0 = off, 1 = on
+        pub status: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `AtomTrack.PropsSet`: set the Atom Tracking parameters.
+    pub struct AtomTrackPropsSet: "AtomTrack.PropsSet" {
+        /// Integral gain of the controller
+        pub integral_gain: f32 = "f",
+        /// Modulation frequency in Hz
+        pub frequency_hz: f32 = "f",
+        /// Modulation amplitude in meters
+        pub amplitude_m: f32 = "f",
+        /// Modulation phase in degrees
+        pub phase_deg: f32 = "f",
+        /// Switch off delay in seconds
+        pub switch_off_delay_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `AtomTrack.PropsGet`: get the Atom Tracking parameters.
+    pub struct AtomTrackPropsGet: "AtomTrack.PropsGet" {} -> AtomTrackPropsGetResponse {
+        /// Integral gain of the controller
+        pub integral_gain: f32 = "f",
+        /// Modulation frequency in Hz
+        pub frequency_hz: f32 = "f",
+        /// Modulation amplitude in meters
+        pub amplitude_m: f32 = "f",
+        /// Modulation phase in degrees
+        pub phase_deg: f32 = "f",
+        /// Switch off delay in seconds
+        pub switch_off_delay_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `AtomTrack.QuickCompStart`: start the Tilt or Drift compensation.
+    pub struct AtomTrackQuickCompStart: "AtomTrack.QuickCompStart" {
+        /// Which compensation to start (tilt or drift)
+        pub comp_type: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `AtomTrack.DriftComp`: apply the Drift measurement to the Drift compensation and turn on compensation.
+    pub struct AtomTrackDriftComp: "AtomTrack.DriftComp" {} -> ()
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Atom Tracking control type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -76,15 +77,10 @@ impl NanonisClient {
         enabled: bool,
     ) -> Result<(), NanonisError> {
         let status = if enabled { 1u16 } else { 0u16 };
-        self.quick_send(
-            "AtomTrack.CtrlSet",
-            vec![
-                NanonisValue::U16(control.into()),
-                NanonisValue::U16(status),
-            ],
-            vec!["H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::AtomTrackCtrlSet {
+            control: control.into(),
+            enabled: status,
+        })?;
         Ok(())
     }
 
@@ -99,18 +95,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn atom_track_status_get(&mut self, control: ATControl) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "AtomTrack.StatusGet",
-            vec![NanonisValue::U16(control.into())],
-            vec!["H"],
-            vec!["H"],
-        )?;
+        let response = self.send(&commands::AtomTrackStatusGet { control: control.into() })?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u16()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.status != 0)
     }
 
     /// Set the Atom Tracking parameters.
@@ -138,18 +125,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn atom_track_props_set(&mut self, props: &AtomTrackProps) -> Result<(), NanonisError> {
-        self.quick_send(
-            "AtomTrack.PropsSet",
-            vec![
-                NanonisValue::F32(props.integral_gain),
-                NanonisValue::F32(props.frequency_hz),
-                NanonisValue::F32(props.amplitude_m),
-                NanonisValue::F32(props.phase_deg),
-                NanonisValue::F32(props.switch_off_delay_s),
-            ],
-            vec!["f", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::AtomTrackPropsSet {
+            integral_gain: props.integral_gain,
+            frequency_hz: props.frequency_hz,
+            amplitude_m: props.amplitude_m,
+            phase_deg: props.phase_deg,
+            switch_off_delay_s: props.switch_off_delay_s,
+        })?;
         Ok(())
     }
 
@@ -161,24 +143,15 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn atom_track_props_get(&mut self) -> Result<AtomTrackProps, NanonisError> {
-        let result = self.quick_send(
-            "AtomTrack.PropsGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "f", "f", "f"],
-        )?;
+        let response = self.send(&commands::AtomTrackPropsGet {})?;
 
-        if result.len() >= 5 {
-            Ok(AtomTrackProps {
-                integral_gain: result[0].as_f32()?,
-                frequency_hz: result[1].as_f32()?,
-                amplitude_m: result[2].as_f32()?,
-                phase_deg: result[3].as_f32()?,
-                switch_off_delay_s: result[4].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(AtomTrackProps {
+            integral_gain: response.integral_gain,
+            frequency_hz: response.frequency_hz,
+            amplitude_m: response.amplitude_m,
+            phase_deg: response.phase_deg,
+            switch_off_delay_s: response.switch_off_delay_s,
+        })
     }
 
     /// Start the Tilt or Drift compensation.
@@ -192,12 +165,7 @@ impl NanonisClient {
         &mut self,
         comp_type: QuickCompType,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "AtomTrack.QuickCompStart",
-            vec![NanonisValue::U16(comp_type.into())],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::AtomTrackQuickCompStart { comp_type: comp_type.into() })?;
         Ok(())
     }
 
@@ -206,7 +174,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn atom_track_drift_comp(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("AtomTrack.DriftComp", vec![], vec![], vec![])?;
+        self.send(&commands::AtomTrackDriftComp {})?;
         Ok(())
     }
 }
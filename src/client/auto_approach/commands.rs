@@ -0,0 +1,24 @@
+//! Typed descriptors for the `AutoApproach.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `AutoApproach.Open`: open the Auto-Approach module.
+    pub struct AutoApproachOpen: "AutoApproach.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `AutoApproach.OnOffSet`: start or stop the Z auto-approach procedure.
+    pub struct AutoApproachOnOffSet: "AutoApproach.OnOffSet" {
+        /// 1 = start, 0 = stop
+        pub on_off: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `AutoApproach.OnOffGet`: get the on-off status of the Z auto-approach procedure.
+    pub struct AutoApproachOnOffGet: "AutoApproach.OnOffGet" {} -> AutoApproachOnOffGetResponse {
+        /// 1 = running, 0 = stopped
+        pub on_off: u16 = "H",
+    }
+}
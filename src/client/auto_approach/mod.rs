@@ -0,0 +1,26 @@
+pub mod commands;
+
+use super::NanonisClient;
+use crate::error::NanonisError;
+
+impl NanonisClient {
+    /// Open the Auto-Approach module
+    pub fn auto_approach_open(&mut self) -> Result<(), NanonisError> {
+        self.send(&commands::AutoApproachOpen {})?;
+        Ok(())
+    }
+
+    /// Start or stop the Z auto-approach procedure
+    pub fn auto_approach_on_off_set(&mut self, on_off: bool) -> Result<(), NanonisError> {
+        let value = if on_off { 1u16 } else { 0u16 };
+        self.send(&commands::AutoApproachOnOffSet { on_off: value })?;
+        Ok(())
+    }
+
+    /// Get the on-off status of the Z auto-approach procedure
+    pub fn auto_approach_on_off_get(&mut self) -> Result<bool, NanonisError> {
+        let response = self.send(&commands::AutoApproachOnOffGet {})?;
+        let status = response.on_off;
+        Ok(status == 1)
+    }
+}
@@ -0,0 +1,107 @@
+//! Typed descriptors for the `BeamDefl.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `BeamDefl.HorConfigSet`: set the horizontal deflection configuration.
+    pub struct BeamDeflHorConfigSet: "BeamDefl.HorConfigSet" {
+        /// Signal name
+        pub name: String = "+*c",
+        /// Physical units
+        pub units: String = "+*c",
+        /// Calibration value
+        pub calibration: f32 = "f",
+        /// Offset value
+        pub offset: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BeamDefl.HorConfigGet`: get the horizontal deflection configuration.
+    pub struct BeamDeflHorConfigGet: "BeamDefl.HorConfigGet" {} -> BeamDeflHorConfigGetResponse {
+        /// Length of the name in bytes
+        pub name_size: i32 = "i",
+        /// Signal name
+        pub name: String = "*-c",
+        /// Length of the units in bytes
+        pub units_size: i32 = "i",
+        /// Physical units
+        pub units: String = "*-c",
+        /// Calibration value
+        pub calibration: f32 = "f",
+        /// Offset value
+        pub offset: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BeamDefl.VerConfigSet`: set the vertical deflection configuration.
+    pub struct BeamDeflVerConfigSet: "BeamDefl.VerConfigSet" {
+        /// Signal name
+        pub name: String = "+*c",
+        /// Physical units
+        pub units: String = "+*c",
+        /// Calibration value
+        pub calibration: f32 = "f",
+        /// Offset value
+        pub offset: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BeamDefl.VerConfigGet`: get the vertical deflection configuration.
+    pub struct BeamDeflVerConfigGet: "BeamDefl.VerConfigGet" {} -> BeamDeflVerConfigGetResponse {
+        /// Length of the name in bytes
+        pub name_size: i32 = "i",
+        /// Signal name
+        pub name: String = "*-c",
+        /// Length of the units in bytes
+        pub units_size: i32 = "i",
+        /// Physical units
+        pub units: String = "*-c",
+        /// Calibration value
+        pub calibration: f32 = "f",
+        /// Offset value
+        pub offset: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BeamDefl.IntConfigSet`: set the intensity signal configuration.
+    pub struct BeamDeflIntConfigSet: "BeamDefl.IntConfigSet" {
+        /// Signal name
+        pub name: String = "+*c",
+        /// Physical units
+        pub units: String = "+*c",
+        /// Calibration value
+        pub calibration: f32 = "f",
+        /// Offset value
+        pub offset: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BeamDefl.IntConfigGet`: get the intensity signal configuration.
+    pub struct BeamDeflIntConfigGet: "BeamDefl.IntConfigGet" {} -> BeamDeflIntConfigGetResponse {
+        /// Length of the name in bytes
+        pub name_size: i32 = "i",
+        /// Signal name
+        pub name: String = "*-c",
+        /// Length of the units in bytes
+        pub units_size: i32 = "i",
+        /// Physical units
+        pub units: String = "*-c",
+        /// Calibration value
+        pub calibration: f32 = "f",
+        /// Offset value
+        pub offset: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BeamDefl.AutoOffset`: auto-offset the beam deflection signal.
+    pub struct BeamDeflAutoOffset: "BeamDefl.AutoOffset" {
+        /// Which deflection signal to offset
+        pub signal: u16 = "H",
+    } -> ()
+}
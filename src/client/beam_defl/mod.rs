@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Deflection signal type for beam deflection module.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -41,17 +42,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_hor_config_set(&mut self, config: &BeamDeflConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BeamDefl.HorConfigSet",
-            vec![
-                NanonisValue::String(config.name.clone()),
-                NanonisValue::String(config.units.clone()),
-                NanonisValue::F32(config.calibration),
-                NanonisValue::F32(config.offset),
-            ],
-            vec!["+*c", "+*c", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BeamDeflHorConfigSet {
+            name: config.name.clone(),
+            units: config.units.clone(),
+            calibration: config.calibration,
+            offset: config.offset,
+        })?;
         Ok(())
     }
 
@@ -63,18 +59,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_hor_config_get(&mut self) -> Result<BeamDeflConfig, NanonisError> {
-        let result = self.quick_send(
-            "BeamDefl.HorConfigGet",
-            vec![],
-            vec![],
-            vec!["i", "*-c", "i", "*-c", "f", "f"],
-        )?;
+        let response = self.send(&commands::BeamDeflHorConfigGet {})?;
 
         Ok(BeamDeflConfig {
-            name: result[1].as_string()?.to_string(),
-            units: result[3].as_string()?.to_string(),
-            calibration: result[4].as_f32()?,
-            offset: result[5].as_f32()?,
+            name: response.name,
+            units: response.units,
+            calibration: response.calibration,
+            offset: response.offset,
         })
     }
 
@@ -86,17 +77,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_ver_config_set(&mut self, config: &BeamDeflConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BeamDefl.VerConfigSet",
-            vec![
-                NanonisValue::String(config.name.clone()),
-                NanonisValue::String(config.units.clone()),
-                NanonisValue::F32(config.calibration),
-                NanonisValue::F32(config.offset),
-            ],
-            vec!["+*c", "+*c", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BeamDeflVerConfigSet {
+            name: config.name.clone(),
+            units: config.units.clone(),
+            calibration: config.calibration,
+            offset: config.offset,
+        })?;
         Ok(())
     }
 
@@ -108,18 +94,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_ver_config_get(&mut self) -> Result<BeamDeflConfig, NanonisError> {
-        let result = self.quick_send(
-            "BeamDefl.VerConfigGet",
-            vec![],
-            vec![],
-            vec!["i", "*-c", "i", "*-c", "f", "f"],
-        )?;
+        let response = self.send(&commands::BeamDeflVerConfigGet {})?;
 
         Ok(BeamDeflConfig {
-            name: result[1].as_string()?.to_string(),
-            units: result[3].as_string()?.to_string(),
-            calibration: result[4].as_f32()?,
-            offset: result[5].as_f32()?,
+            name: response.name,
+            units: response.units,
+            calibration: response.calibration,
+            offset: response.offset,
         })
     }
 
@@ -131,17 +112,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_int_config_set(&mut self, config: &BeamDeflConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BeamDefl.IntConfigSet",
-            vec![
-                NanonisValue::String(config.name.clone()),
-                NanonisValue::String(config.units.clone()),
-                NanonisValue::F32(config.calibration),
-                NanonisValue::F32(config.offset),
-            ],
-            vec!["+*c", "+*c", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BeamDeflIntConfigSet {
+            name: config.name.clone(),
+            units: config.units.clone(),
+            calibration: config.calibration,
+            offset: config.offset,
+        })?;
         Ok(())
     }
 
@@ -153,18 +129,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_int_config_get(&mut self) -> Result<BeamDeflConfig, NanonisError> {
-        let result = self.quick_send(
-            "BeamDefl.IntConfigGet",
-            vec![],
-            vec![],
-            vec!["i", "*-c", "i", "*-c", "f", "f"],
-        )?;
+        let response = self.send(&commands::BeamDeflIntConfigGet {})?;
 
         Ok(BeamDeflConfig {
-            name: result[1].as_string()?.to_string(),
-            units: result[3].as_string()?.to_string(),
-            calibration: result[4].as_f32()?,
-            offset: result[5].as_f32()?,
+            name: response.name,
+            units: response.units,
+            calibration: response.calibration,
+            offset: response.offset,
         })
     }
 
@@ -178,12 +149,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn beam_defl_auto_offset(&mut self, signal: DeflectionSignal) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BeamDefl.AutoOffset",
-            vec![NanonisValue::U16(signal.into())],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::BeamDeflAutoOffset { signal: signal.into() })?;
         Ok(())
     }
 }
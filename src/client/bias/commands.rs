@@ -0,0 +1,77 @@
+//! Typed descriptors for the `Bias.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Bias.Set`: set the bias voltage.
+    pub struct BiasSet: "Bias.Set" {
+        /// Bias voltage in volts
+        pub voltage: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Bias.Get`: get the bias voltage.
+    pub struct BiasGet: "Bias.Get" {} -> BiasGetResponse {
+        /// Bias voltage in volts
+        pub voltage: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Bias.RangeSet`: select one of the available bias ranges.
+    pub struct BiasRangeSet: "Bias.RangeSet" {
+        /// Index into the list returned by [`BiasRangeGet`]
+        pub range_index: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Bias.RangeGet`: get the available bias ranges and the selected one.
+    pub struct BiasRangeGet: "Bias.RangeGet" {} -> BiasRangeGetResponse {
+        /// Size of the ranges array in bytes
+        pub ranges_size: i32 = "i",
+        /// Number of ranges
+        pub num_ranges: i32 = "i",
+        /// Range descriptions
+        pub ranges: Vec<String> = "*+c",
+        /// Index of the selected range
+        pub range_index: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `Bias.CalibrSet`: set calibration and offset of the selected range.
+    pub struct BiasCalibrSet: "Bias.CalibrSet" {
+        /// Calibration factor
+        pub calibration: f32 = "f",
+        /// Offset
+        pub offset: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Bias.CalibrGet`: get calibration and offset of the selected range.
+    pub struct BiasCalibrGet: "Bias.CalibrGet" {} -> BiasCalibrGetResponse {
+        /// Calibration factor
+        pub calibration: f32 = "f",
+        /// Offset
+        pub offset: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Bias.Pulse`: generate one bias pulse.
+    pub struct BiasPulse: "Bias.Pulse" {
+        /// 1 to wait until the pulse is done, 0 otherwise
+        pub wait_until_done: u32 = "I",
+        /// Pulse width in seconds
+        pub pulse_width_s: f32 = "f",
+        /// Bias value in volts
+        pub bias_value_v: f32 = "f",
+        /// Z-controller hold: 0=no change, 1=hold, 2=don't hold
+        pub z_controller_hold: u16 = "H",
+        /// Pulse mode: 0=no change, 1=relative, 2=absolute
+        pub pulse_mode: u16 = "H",
+    } -> ()
+}
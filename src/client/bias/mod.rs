@@ -1,9 +1,11 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::units::{Seconds, Volts};
 
 impl NanonisClient {
     /// Set the bias voltage applied to the scanning probe tip.
@@ -12,7 +14,8 @@ impl NanonisClient {
     /// for tip-sample interaction control.
     ///
     /// # Arguments
-    /// * `voltage` - The bias voltage to apply (in volts)
+    /// * `voltage` - The bias voltage to apply as [`Volts`](crate::Volts); a raw
+    ///   value in volts goes through [`Volts::new`](crate::Volts::new)
     ///
     /// # Errors
     /// Returns `NanonisError` if:
@@ -21,25 +24,34 @@ impl NanonisClient {
     ///
     /// # Examples
     /// ```no_run
-    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::{NanonisClient, Volts};
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
     /// // Set bias to 1.5V
-    /// client.bias_set(1.5)?;
+    /// client.bias_set(Volts::new(1.5))?;
     ///
-    /// // Set bias to -0.5V
-    /// client.bias_set(-0.5)?;
+    /// // Set bias to -500mV
+    /// client.bias_set(Volts::millivolts(-500.0))?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn bias_set(&mut self, voltage: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Bias.Set",
-            vec![NanonisValue::F32(voltage)],
-            vec!["f"],
-            vec![],
-        )?;
-        Ok(())
+    ///
+    /// A raw value, e.g. read from a config file, is wrapped explicitly:
+    /// ```
+    /// use nanonis_rs::{MockServer, Volts};
+    ///
+    /// let server = MockServer::simulated()?;
+    /// let mut client = server.client()?;
+    ///
+    /// let raw_volts: f64 = 0.25;
+    /// client.bias_set(Volts::new(raw_volts))?;
+    /// assert_eq!(client.bias_get()?, 0.25);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn bias_set(&mut self, voltage: impl Into<Volts>) -> Result<(), NanonisError> {
+        self.send(&commands::BiasSet {
+            voltage: voltage.into().as_f32(),
+        })
     }
 
     /// Get the current bias voltage applied to the scanning probe tip.
@@ -65,13 +77,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Bias.Get", vec![], vec![], vec!["f"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => {
-                Err(NanonisError::Protocol("No bias value returned".to_string()))
-            }
-        }
+        Ok(self.send(&commands::BiasGet {})?.voltage)
     }
 
     /// Set the range of the bias voltage, if different ranges are available.
@@ -105,13 +111,9 @@ impl NanonisClient {
         &mut self,
         bias_range_index: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Bias.RangeSet",
-            vec![NanonisValue::U16(bias_range_index)],
-            vec!["H"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::BiasRangeSet {
+            range_index: bias_range_index,
+        })
     }
 
     /// Get the selectable ranges of bias voltage and the index of the selected one.
@@ -142,21 +144,8 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_range_get(&mut self) -> Result<(Vec<String>, u16), NanonisError> {
-        let result = self.quick_send(
-            "Bias.RangeGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c", "H"],
-        )?;
-        if result.len() >= 4 {
-            let ranges = result[2].as_string_array()?.to_vec();
-            let current_index = result[3].as_u16()?;
-            Ok((ranges, current_index))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid bias range response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasRangeGet {})?;
+        Ok((response.ranges, response.range_index))
     }
 
     /// Set the calibration and offset of bias voltage.
@@ -189,13 +178,10 @@ impl NanonisClient {
         calibration: f32,
         offset: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Bias.CalibrSet",
-            vec![NanonisValue::F32(calibration), NanonisValue::F32(offset)],
-            vec!["f", "f"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::BiasCalibrSet {
+            calibration,
+            offset,
+        })
     }
 
     /// Get the calibration and offset of bias voltage.
@@ -222,17 +208,8 @@ impl NanonisClient {
     /// Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_calibr_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result =
-            self.quick_send("Bias.CalibrGet", vec![], vec![], vec!["f", "f"])?;
-        if result.len() >= 2 {
-            let calibration = result[0].as_f32()?;
-            let offset = result[1].as_f32()?;
-            Ok((calibration, offset))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid bias calibration response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasCalibrGet {})?;
+        Ok((response.calibration, response.offset))
     }
 
     /// Generate one bias pulse.
@@ -255,42 +232,36 @@ impl NanonisClient {
     ///
     /// # Examples
     /// ```no_run
-    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::{NanonisClient, Seconds, Volts};
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
     /// // Apply a 100ms pulse at +2V, holding Z-controller, absolute voltage
-    /// client.bias_pulse(true, 0.1, 2.0, 1, 2)?;
+    /// client.bias_pulse(true, Seconds::milliseconds(100.0), Volts::new(2.0), 1, 2)?;
     ///
     /// // Quick +0.5V pulse relative to current bias, don't wait
-    /// client.bias_pulse(false, 0.01, 0.5, 0, 1)?;
+    /// client.bias_pulse(false, Seconds::milliseconds(10.0), Volts::millivolts(500.0), 0, 1)?;
     ///
     /// // Long conditioning pulse at -3V absolute, hold Z-controller
-    /// client.bias_pulse(true, 1.0, -3.0, 1, 2)?;
+    /// client.bias_pulse(true, Seconds::new(1.0), Volts::new(-3.0), 1, 2)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_pulse(
         &mut self,
         wait_until_done: bool,
-        pulse_width_s: f32,
-        bias_value_v: f32,
+        pulse_width_s: impl Into<Seconds>,
+        bias_value_v: impl Into<Volts>,
         z_controller_hold: u16,
         pulse_mode: u16,
     ) -> Result<(), NanonisError> {
         let wait_flag = if wait_until_done { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "Bias.Pulse",
-            vec![
-                NanonisValue::U32(wait_flag),
-                NanonisValue::F32(pulse_width_s),
-                NanonisValue::F32(bias_value_v),
-                NanonisValue::U16(z_controller_hold),
-                NanonisValue::U16(pulse_mode),
-            ],
-            vec!["I", "f", "f", "H", "H"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::BiasPulse {
+            wait_until_done: wait_flag,
+            pulse_width_s: pulse_width_s.into().as_f32(),
+            bias_value_v: bias_value_v.into().as_f32(),
+            z_controller_hold,
+            pulse_mode,
+        })
     }
 }
@@ -0,0 +1,401 @@
+//! Typed descriptors for the `BiasSpectr.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `BiasSpectr.Open`: open the Bias Spectroscopy module.
+    pub struct BiasSpectrOpen: "BiasSpectr.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.Start`: start a bias spectroscopy measurement.
+    pub struct BiasSpectrStart: "BiasSpectr.Start" blocking if get_data {
+        /// If true, returns measurement data
+        pub get_data: u32 = "I",
+        /// Base filename for saving (empty for no change)
+        pub save_base_name: String = "+*c",
+    } -> BiasSpectrStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Names of recorded channels
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// Measurement data, one row per channel
+        pub data_2d: Array2D<f32> = "2f",
+        /// Number of parameters
+        pub num_parameters: i32 = "i",
+        /// Fixed and measured parameters of the measurement
+        pub parameters: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.Stop`: stop the current bias spectroscopy measurement.
+    pub struct BiasSpectrStop: "BiasSpectr.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.StatusGet`: get the status of the bias spectroscopy measurement.
+    pub struct BiasSpectrStatusGet: "BiasSpectr.StatusGet" {} -> BiasSpectrStatusGetResponse {
+        /// 1 if a measurement is running
+        pub running: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.ChsSet`: set the list of recorded channels in bias spectroscopy.
+    pub struct BiasSpectrChsSet: "BiasSpectr.ChsSet" {
+        /// Signal slot indexes (0-23) of the recorded channels
+        pub channel_indexes: Vec<i32> = "+*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.ChsGet`: get the list of recorded channels in bias spectroscopy.
+    pub struct BiasSpectrChsGet: "BiasSpectr.ChsGet" {} -> BiasSpectrChsGetResponse {
+        /// Number of indexes
+        pub num_indexes: i32 = "i",
+        /// Indexes of recorded channels
+        pub indexes: Vec<i32> = "*i",
+        /// Size of the names array in bytes
+        pub names_size: i32 = "i",
+        /// Number of names
+        pub num_names: i32 = "i",
+        /// Names of recorded channels
+        pub names: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.PropsSet`: set the bias spectroscopy properties.
+    pub struct BiasSpectrPropsSet: "BiasSpectr.PropsSet" {
+        /// Whether to save individual sweep data with average
+        pub save_all: u16 = "H",
+        /// Number of sweeps
+        pub num_sweeps: i32 = "i",
+        /// Whether to acquire backward sweep
+        pub backward_sweep: u16 = "H",
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// Z offset in meters applied before the sweep
+        pub z_offset_m: f32 = "f",
+        /// Enable autosave
+        pub autosave: u16 = "H",
+        /// Show save dialog
+        pub show_save_dialog: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.PropsGet`: get the bias spectroscopy properties.
+    pub struct BiasSpectrPropsGet: "BiasSpectr.PropsGet" {} -> BiasSpectrPropsGetResponse {
+        /// Whether to save individual sweep data with average
+        pub save_all: u16 = "H",
+        /// Number of sweeps
+        pub num_sweeps: i32 = "i",
+        /// Whether to acquire backward sweep
+        pub backward_sweep: u16 = "H",
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// Size of the channels array in bytes
+        pub channels_size: i32 = "i",
+        /// Number of channels
+        pub num_channels: i32 = "i",
+        /// List of recorded channel names
+        pub channels: Vec<String> = "*+c",
+        /// Size of the parameters array in bytes
+        pub parameters_size: i32 = "i",
+        /// Number of parameters
+        pub num_parameters: i32 = "i",
+        /// Names of the fixed and measured parameters
+        pub parameters: Vec<String> = "*+c",
+        /// Size of the fixed parameters array in bytes
+        pub fixed_parameters_size: i32 = "i",
+        /// Number of fixed parameters
+        pub num_fixed_parameters: i32 = "i",
+        /// Fixed parameters
+        pub fixed_parameters: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.AdvPropsSet`: set the advanced bias spectroscopy properties.
+    pub struct BiasSpectrAdvPropsSet: "BiasSpectr.AdvPropsSet" {
+        /// Reset bias to initial value after sweep: NoChange/On/Off
+        pub reset_bias: u16 = "H",
+        /// Hold Z-controller during sweep: NoChange/On/Off
+        pub z_controller_hold: u16 = "H",
+        /// Record final Z position: NoChange/On/Off
+        pub record_final_z: u16 = "H",
+        /// Run lock-in during measurement: NoChange/On/Off
+        pub lockin_run: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.AdvPropsGet`: get the advanced bias spectroscopy properties.
+    pub struct BiasSpectrAdvPropsGet: "BiasSpectr.AdvPropsGet" {} -> BiasSpectrAdvPropsGetResponse {
+        /// Reset bias to initial value after sweep
+        pub reset_bias: u16 = "H",
+        /// Hold Z-controller during sweep
+        pub z_controller_hold: u16 = "H",
+        /// Record final Z position
+        pub record_final_z: u16 = "H",
+        /// Run lock-in during measurement
+        pub lockin_run: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.LimitsSet`: set the bias spectroscopy sweep limits.
+    pub struct BiasSpectrLimitsSet: "BiasSpectr.LimitsSet" {
+        /// Starting bias voltage in volts
+        pub start_value_v: f32 = "f",
+        /// Ending bias voltage in volts
+        pub end_value_v: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.LimitsGet`: get the bias spectroscopy sweep limits.
+    pub struct BiasSpectrLimitsGet: "BiasSpectr.LimitsGet" {} -> BiasSpectrLimitsGetResponse {
+        /// Starting bias voltage in volts
+        pub start_value_v: f32 = "f",
+        /// Ending bias voltage in volts
+        pub end_value_v: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.TimingSet`: set the bias spectroscopy timing parameters.
+    pub struct BiasSpectrTimingSet: "BiasSpectr.TimingSet" {
+        /// Time for Z averaging before sweep
+        pub z_averaging_time: f32 = "f",
+        /// Z offset in meters
+        pub z_offset_m: f32 = "f",
+        /// Initial settling time in seconds
+        pub initial_settling_time: f32 = "f",
+        /// Maximum slew rate in V/s
+        pub max_slew_rate: f32 = "f",
+        /// Settling time in seconds
+        pub settling_time: f32 = "f",
+        /// Integration time in seconds
+        pub integration_time: f32 = "f",
+        /// End settling time after sweep
+        pub end_settling_time: f32 = "f",
+        /// Z control time at end
+        pub z_control_time: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.TimingGet`: get the bias spectroscopy timing parameters.
+    pub struct BiasSpectrTimingGet: "BiasSpectr.TimingGet" {} -> BiasSpectrTimingGetResponse {
+        /// Time for Z averaging before sweep
+        pub z_averaging_time: f32 = "f",
+        /// Z offset in meters
+        pub z_offset_m: f32 = "f",
+        /// Initial settling time in seconds
+        pub initial_settling_time: f32 = "f",
+        /// Maximum slew rate in V/s
+        pub max_slew_rate: f32 = "f",
+        /// Settling time in seconds
+        pub settling_time: f32 = "f",
+        /// Integration time in seconds
+        pub integration_time: f32 = "f",
+        /// End settling time after sweep
+        pub end_settling_time: f32 = "f",
+        /// Z control time at end
+        pub z_control_time: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.DigSyncSet`: set the digital synchronization mode.
+    pub struct BiasSpectrDigSyncSet: "BiasSpectr.DigSyncSet" {
+        /// The [`DigitalSync`] mode to set
+        pub mode: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.DigSyncGet`: get the digital synchronization mode.
+    pub struct BiasSpectrDigSyncGet: "BiasSpectr.DigSyncGet" {} -> BiasSpectrDigSyncGetResponse {
+        /// The [`DigitalSync`] mode to set
+        pub mode: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.TTLSyncSet`: set the TTL synchronization configuration.
+    pub struct BiasSpectrTtlSyncSet: "BiasSpectr.TTLSyncSet" {
+        /// TTL line to use
+        pub line: u16 = "H",
+        /// Polarity of switching
+        pub polarity: u16 = "H",
+        /// Time to wait before activating
+        pub time_to_on: f32 = "f",
+        /// Duration to keep activated
+        pub on_duration: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.TTLSyncGet`: get the TTL synchronization configuration.
+    pub struct BiasSpectrTtlSyncGet: "BiasSpectr.TTLSyncGet" {} -> BiasSpectrTtlSyncGetResponse {
+        /// TTL line to use
+        pub line: u16 = "H",
+        /// Polarity of switching
+        pub polarity: u16 = "H",
+        /// Time to wait before activating
+        pub time_to_on: f32 = "f",
+        /// Duration to keep activated
+        pub on_duration: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.PulseSeqSyncSet`: set the pulse sequence synchronization configuration.
+    pub struct BiasSpectrPulseSeqSyncSet: "BiasSpectr.PulseSeqSyncSet" {
+        /// Pulse sequence number
+        pub sequence_nr: u16 = "H",
+        /// Number of periods to execute
+        pub num_periods: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.PulseSeqSyncGet`: get the pulse sequence synchronization configuration.
+    pub struct BiasSpectrPulseSeqSyncGet: "BiasSpectr.PulseSeqSyncGet" {} -> BiasSpectrPulseSeqSyncGetResponse {
+        /// Pulse sequence number
+        pub sequence_nr: u16 = "H",
+        /// Number of periods to execute
+        pub num_periods: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.AltZCtrlSet`: set the alternate Z-controller setpoint configuration.
+    pub struct BiasSpectrAltZCtrlSet: "BiasSpectr.AltZCtrlSet" {
+        /// 0 = no change, 1 = on, 2 = off
+        pub enabled_flag: u16 = "H",
+        /// Alternate setpoint value
+        pub setpoint: f32 = "f",
+        /// Settling time in seconds
+        pub settling_time: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.AltZCtrlGet`: get the alternate Z-controller setpoint configuration.
+    pub struct BiasSpectrAltZCtrlGet: "BiasSpectr.AltZCtrlGet" {} -> BiasSpectrAltZCtrlGetResponse {
+        /// Whether alternate setpoint is enabled
+        pub enabled: u16 = "H",
+        /// Alternate setpoint value
+        pub setpoint: f32 = "f",
+        /// Settling time in seconds
+        pub settling_time: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.ZOffRevertSet`: set the Z offset revert flag.
+    pub struct BiasSpectrZOffRevertSet: "BiasSpectr.ZOffRevertSet" {
+        /// Whether to revert Z offset: NoChange/On/Off
+        pub revert: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.ZOffRevertGet`: get the Z offset revert flag.
+    pub struct BiasSpectrZOffRevertGet: "BiasSpectr.ZOffRevertGet" {} -> BiasSpectrZOffRevertGetResponse {
+        /// Whether to revert Z offset: NoChange/On/Off
+        pub revert: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.MLSLockinPerSegSet`: set the MLS lock-in per segment flag.
+    pub struct BiasSpectrMlsLockinPerSegSet: "BiasSpectr.MLSLockinPerSegSet" {
+        /// Whether to enable per-segment lock-in configuration
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.MLSLockinPerSegGet`: get the MLS lock-in per segment flag.
+    pub struct BiasSpectrMlsLockinPerSegGet: "BiasSpectr.MLSLockinPerSegGet" {} -> BiasSpectrMlsLockinPerSegGetResponse {
+        /// Whether to enable per-segment lock-in configuration
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.MLSModeSet`: set the MLS sweep mode.
+    pub struct BiasSpectrMlsModeSet: "BiasSpectr.MLSModeSet" {
+        /// Sweep mode name, e.g. "Linear" or "MLS"
+        pub mode_str: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.MLSModeGet`: get the current MLS sweep mode.
+    pub struct BiasSpectrMlsModeGet: "BiasSpectr.MLSModeGet" {} -> BiasSpectrMlsModeGetResponse {
+        /// Size of the modes array in bytes
+        pub modes_size: i32 = "i",
+        /// Number of modes
+        pub num_modes: i32 = "i",
+        /// Available sweep mode names
+        pub modes: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSpectr.MLSValsSet`: set the MLS segment values.
+    pub struct BiasSpectrMlsValsSet: "BiasSpectr.MLSValsSet" {
+        /// Number of segments
+        pub num_segments: i32 = "i",
+        /// Bias start value in volts
+        pub bias_start: Vec<f32> = "*f",
+        /// Bias end value in volts
+        pub bias_end: Vec<f32> = "*f",
+        /// Initial settling time of each segment in seconds
+        pub initial_settling: Vec<f32> = "*f",
+        /// Settling time of each segment in seconds
+        pub settling: Vec<f32> = "*f",
+        /// Integration time of each segment in seconds
+        pub integration: Vec<f32> = "*f",
+        /// Maximum slew rate of each segment in V/s
+        pub slew_rate: Vec<f32> = "*f",
+        /// Number of steps in segment
+        pub steps: Vec<i32> = "*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSpectr.MLSValsGet`: get the MLS segment values.
+    pub struct BiasSpectrMlsValsGet: "BiasSpectr.MLSValsGet" {} -> BiasSpectrMlsValsGetResponse {
+        /// Number of segments
+        pub num_segments: i32 = "i",
+        /// Bias start value in volts
+        pub bias_start: Vec<f32> = "*f",
+        /// Bias end value in volts
+        pub bias_end: Vec<f32> = "*f",
+        /// Initial settling time of each segment in seconds
+        pub initial_settling: Vec<f32> = "*f",
+        /// Settling time of each segment in seconds
+        pub settling: Vec<f32> = "*f",
+        /// Integration time of each segment in seconds
+        pub integration: Vec<f32> = "*f",
+        /// Maximum slew rate of each segment in V/s
+        pub slew_rate: Vec<f32> = "*f",
+        /// Number of steps in segment
+        pub steps: Vec<i32> = "*i",
+    }
+}
@@ -1,9 +1,11 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
+use super::signals::SlotRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 use std::time::Duration;
 
 impl NanonisClient {
@@ -24,7 +26,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("BiasSpectr.Open", vec![], vec![], vec![])?;
+        self.send(&commands::BiasSpectrOpen {})?;
         Ok(())
     }
 
@@ -52,10 +54,38 @@ impl NanonisClient {
     /// // Start spectroscopy and get data
     /// let result = client.bias_spectr_start(true, "sts_001")?;
     /// println!("Recorded {} channels", result.channel_names.len());
-    /// println!("Data shape: {} x {}", result.data.len(),
-    ///          result.data.first().map(|r| r.len()).unwrap_or(0));
+    /// println!("Data shape: {} x {}", result.data.rows(), result.data.cols());
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// The data arrives as a `2f` array with one row per channel:
+    /// ```
+    /// use nanonis_rs::{Array2D, MockServer, NanonisValue};
+    ///
+    /// let server = MockServer::start()?;
+    /// let names = vec!["Bias (V)".to_string(), "Current (A)".to_string()];
+    /// let data = Array2D::from_vec(2, 3, vec![-1.0, 0.0, 1.0, -1e-9, 0.0, 1e-9])?;
+    /// server.respond(
+    ///     "BiasSpectr.Start",
+    ///     vec![
+    ///         NanonisValue::I32(0),
+    ///         NanonisValue::I32(2),
+    ///         NanonisValue::ArrayString(names),
+    ///         NanonisValue::I32(2),
+    ///         NanonisValue::I32(3),
+    ///         NanonisValue::Array2DF32(data),
+    ///         NanonisValue::I32(1),
+    ///         NanonisValue::ArrayF32(vec![0.5]),
+    ///     ],
+    ///     vec!["i", "i", "*+c", "i", "i", "2f", "i", "*f"],
+    /// );
+    ///
+    /// let result = server.client()?.bias_spectr_start(true, "")?;
+    /// assert_eq!(result.channel_names, ["Bias (V)", "Current (A)"]);
+    /// assert_eq!(result.data.get(1, 2), Some(&1e-9));
+    /// assert_eq!(result.parameters, [0.5]);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn bias_spectr_start(
         &mut self,
         get_data: bool,
@@ -63,46 +93,20 @@ impl NanonisClient {
     ) -> Result<BiasSpectrResult, NanonisError> {
         let get_data_flag = if get_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "BiasSpectr.Start",
-            vec![
-                NanonisValue::U32(get_data_flag),
-                NanonisValue::String(save_base_name.to_string()),
-            ],
-            vec!["I", "+*c"],
-            vec!["i", "i", "*+c", "i", "i", "2f", "i", "*f"],
-        )?;
-
-        if result.len() >= 8 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let rows = result[3].as_i32()? as usize;
-            let cols = result[4].as_i32()? as usize;
-
-            // Parse 2D data array
-            let flat_data = result[5].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                if end_idx <= flat_data.len() {
-                    data_2d.push(flat_data[start_idx..end_idx].to_vec());
-                }
-            }
+        let response = self.send(&commands::BiasSpectrStart {
+            get_data: get_data_flag,
+            save_base_name: save_base_name.to_string(),
+        })?;
 
-            let parameters = result[7].as_f32_array()?.to_vec();
+        let channel_names = response.channel_names;
+        let parameters = response.parameters;
+        let data_2d = response.data_2d;
 
-            Ok(BiasSpectrResult {
-                channel_names,
-                data: data_2d,
-                parameters,
-            })
-        } else {
-            Ok(BiasSpectrResult {
-                channel_names: vec![],
-                data: vec![],
-                parameters: vec![],
-            })
-        }
+        Ok(BiasSpectrResult {
+            channel_names,
+            data: data_2d,
+            parameters,
+        })
     }
 
     /// Stop the current bias spectroscopy measurement.
@@ -119,7 +123,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("BiasSpectr.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::BiasSpectrStop {})?;
         Ok(())
     }
 
@@ -142,40 +146,38 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_status_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("BiasSpectr.StatusGet", vec![], vec![], vec!["I"])?;
-        if let Some(val) = result.first() {
-            Ok(val.as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid status response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasSpectrStatusGet {})?;
+        Ok(response.running != 0)
     }
 
     /// Set the list of recorded channels in bias spectroscopy.
     ///
     /// # Arguments
-    /// * `channel_indexes` - Indexes of channels to record (0-23 for signals in Signals Manager)
+    /// * `channels` - Slots (0-23) to record, or signals by name or
+    ///   [`SignalIndex`](crate::signals::SignalIndex) assigned to a slot
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails or invalid indexes provided.
+    /// Returns `NanonisError` if a signal does not resolve or has no slot,
+    /// communication fails or invalid indexes provided.
     ///
     /// # Examples
     /// ```no_run
     /// use nanonis_rs::NanonisClient;
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
-    /// // Record channels 0, 1, and 5
-    /// client.bias_spectr_chs_set(&[0, 1, 5])?;
+    /// // Record current and height
+    /// client.bias_spectr_chs_set(["Current", "Z"])?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn bias_spectr_chs_set(&mut self, channel_indexes: &[i32]) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.ChsSet",
-            vec![NanonisValue::ArrayI32(channel_indexes.to_vec())],
-            vec!["+*i"],
-            vec![],
-        )?;
+    pub fn bias_spectr_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let channel_indexes = channels
+            .into_iter()
+            .map(|channel| self.resolve_slot(channel))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.send(&commands::BiasSpectrChsSet { channel_indexes })?;
         Ok(())
     }
 
@@ -201,22 +203,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_chs_get(&mut self) -> Result<(Vec<i32>, Vec<String>), NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.ChsGet",
-            vec![],
-            vec![],
-            vec!["i", "*i", "i", "i", "*+c"],
-        )?;
+        let response = self.send(&commands::BiasSpectrChsGet {})?;
 
-        if result.len() >= 5 {
-            let indexes = result[1].as_i32_array()?.to_vec();
-            let names = result[4].as_string_array()?.to_vec();
-            Ok((indexes, names))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid channels response".to_string(),
-            ))
-        }
+        Ok((response.indexes, response.names))
     }
 
     /// Set the bias spectroscopy properties.
@@ -251,20 +240,15 @@ impl NanonisClient {
         &mut self,
         config: BiasSpectrPropsBuilder,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.PropsSet",
-            vec![
-                NanonisValue::U16(config.save_all.into()),
-                NanonisValue::I32(config.num_sweeps),
-                NanonisValue::U16(config.backward_sweep.into()),
-                NanonisValue::I32(config.num_points),
-                NanonisValue::F32(config.z_offset_m),
-                NanonisValue::U16(config.autosave.into()),
-                NanonisValue::U16(config.show_save_dialog.into()),
-            ],
-            vec!["H", "i", "H", "i", "f", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrPropsSet {
+            save_all: config.save_all.into(),
+            num_sweeps: config.num_sweeps,
+            backward_sweep: config.backward_sweep.into(),
+            num_points: config.num_points,
+            z_offset_m: config.z_offset_m,
+            autosave: config.autosave.into(),
+            show_save_dialog: config.show_save_dialog.into(),
+        })?;
         Ok(())
     }
 
@@ -286,30 +270,17 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_props_get(&mut self) -> Result<BiasSpectrProps, NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.PropsGet",
-            vec![],
-            vec![],
-            vec![
-                "H", "i", "H", "i", "i", "i", "*+c", "i", "i", "*+c", "i", "i", "*+c",
-            ],
-        )?;
-
-        if result.len() >= 13 {
-            Ok(BiasSpectrProps {
-                save_all: result[0].as_u16()? != 0,
-                num_sweeps: result[1].as_i32()?,
-                backward_sweep: result[2].as_u16()? != 0,
-                num_points: result[3].as_i32()?,
-                channels: result[6].as_string_array()?.to_vec(),
-                parameters: result[9].as_string_array()?.to_vec(),
-                fixed_parameters: result[12].as_string_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid props response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasSpectrPropsGet {})?;
+
+        Ok(BiasSpectrProps {
+            save_all: response.save_all != 0,
+            num_sweeps: response.num_sweeps,
+            backward_sweep: response.backward_sweep != 0,
+            num_points: response.num_points,
+            channels: response.channels,
+            parameters: response.parameters,
+            fixed_parameters: response.fixed_parameters,
+        })
     }
 
     /// Set the advanced bias spectroscopy properties.
@@ -344,17 +315,12 @@ impl NanonisClient {
         record_final_z: OptionalFlag,
         lockin_run: OptionalFlag,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.AdvPropsSet",
-            vec![
-                NanonisValue::U16(reset_bias.into()),
-                NanonisValue::U16(z_controller_hold.into()),
-                NanonisValue::U16(record_final_z.into()),
-                NanonisValue::U16(lockin_run.into()),
-            ],
-            vec!["H", "H", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrAdvPropsSet {
+            reset_bias: reset_bias.into(),
+            z_controller_hold: z_controller_hold.into(),
+            record_final_z: record_final_z.into(),
+            lockin_run: lockin_run.into(),
+        })?;
         Ok(())
     }
 
@@ -376,25 +342,14 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_adv_props_get(&mut self) -> Result<BiasSpectrAdvProps, NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.AdvPropsGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "H", "H"],
-        )?;
-
-        if result.len() >= 4 {
-            Ok(BiasSpectrAdvProps {
-                reset_bias: result[0].as_u16()? != 0,
-                z_controller_hold: result[1].as_u16()? != 0,
-                record_final_z: result[2].as_u16()? != 0,
-                lockin_run: result[3].as_u16()? != 0,
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid adv props response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasSpectrAdvPropsGet {})?;
+
+        Ok(BiasSpectrAdvProps {
+            reset_bias: response.reset_bias != 0,
+            z_controller_hold: response.z_controller_hold != 0,
+            record_final_z: response.record_final_z != 0,
+            lockin_run: response.lockin_run != 0,
+        })
     }
 
     /// Set the bias spectroscopy sweep limits.
@@ -420,15 +375,7 @@ impl NanonisClient {
         start_value_v: f32,
         end_value_v: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.LimitsSet",
-            vec![
-                NanonisValue::F32(start_value_v),
-                NanonisValue::F32(end_value_v),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrLimitsSet { start_value_v, end_value_v })?;
         Ok(())
     }
 
@@ -450,15 +397,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result = self.quick_send("BiasSpectr.LimitsGet", vec![], vec![], vec!["f", "f"])?;
+        let response = self.send(&commands::BiasSpectrLimitsGet {})?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid limits response".to_string(),
-            ))
-        }
+        Ok((response.start_value_v, response.end_value_v))
     }
 
     /// Set the bias spectroscopy timing parameters.
@@ -485,21 +426,16 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_timing_set(&mut self, timing: &BiasSpectrTiming) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.TimingSet",
-            vec![
-                NanonisValue::F32(timing.z_averaging_time.as_secs_f32()),
-                NanonisValue::F32(timing.z_offset_m),
-                NanonisValue::F32(timing.initial_settling_time.as_secs_f32()),
-                NanonisValue::F32(timing.max_slew_rate),
-                NanonisValue::F32(timing.settling_time.as_secs_f32()),
-                NanonisValue::F32(timing.integration_time.as_secs_f32()),
-                NanonisValue::F32(timing.end_settling_time.as_secs_f32()),
-                NanonisValue::F32(timing.z_control_time.as_secs_f32()),
-            ],
-            vec!["f", "f", "f", "f", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrTimingSet {
+            z_averaging_time: timing.z_averaging_time.as_secs_f32(),
+            z_offset_m: timing.z_offset_m,
+            initial_settling_time: timing.initial_settling_time.as_secs_f32(),
+            max_slew_rate: timing.max_slew_rate,
+            settling_time: timing.settling_time.as_secs_f32(),
+            integration_time: timing.integration_time.as_secs_f32(),
+            end_settling_time: timing.end_settling_time.as_secs_f32(),
+            z_control_time: timing.z_control_time.as_secs_f32(),
+        })?;
         Ok(())
     }
 
@@ -521,29 +457,18 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_timing_get(&mut self) -> Result<BiasSpectrTiming, NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.TimingGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "f", "f", "f", "f", "f", "f"],
-        )?;
-
-        if result.len() >= 8 {
-            Ok(BiasSpectrTiming {
-                z_averaging_time: Duration::from_secs_f32(result[0].as_f32()?),
-                z_offset_m: result[1].as_f32()?,
-                initial_settling_time: Duration::from_secs_f32(result[2].as_f32()?),
-                max_slew_rate: result[3].as_f32()?,
-                settling_time: Duration::from_secs_f32(result[4].as_f32()?),
-                integration_time: Duration::from_secs_f32(result[5].as_f32()?),
-                end_settling_time: Duration::from_secs_f32(result[6].as_f32()?),
-                z_control_time: Duration::from_secs_f32(result[7].as_f32()?),
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid timing response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasSpectrTimingGet {})?;
+
+        Ok(BiasSpectrTiming {
+            z_averaging_time: Duration::from_secs_f32(response.z_averaging_time),
+            z_offset_m: response.z_offset_m,
+            initial_settling_time: Duration::from_secs_f32(response.initial_settling_time),
+            max_slew_rate: response.max_slew_rate,
+            settling_time: Duration::from_secs_f32(response.settling_time),
+            integration_time: Duration::from_secs_f32(response.integration_time),
+            end_settling_time: Duration::from_secs_f32(response.end_settling_time),
+            z_control_time: Duration::from_secs_f32(response.z_control_time),
+        })
     }
 
     /// Set the digital synchronization mode.
@@ -564,12 +489,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_dig_sync_set(&mut self, mode: DigitalSync) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.DigSyncSet",
-            vec![NanonisValue::U16(mode.into())],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrDigSyncSet { mode: mode.into() })?;
         Ok(())
     }
 
@@ -591,15 +511,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_dig_sync_get(&mut self) -> Result<DigitalSync, NanonisError> {
-        let result = self.quick_send("BiasSpectr.DigSyncGet", vec![], vec![], vec!["H"])?;
+        let response = self.send(&commands::BiasSpectrDigSyncGet {})?;
 
-        if let Some(val) = result.first() {
-            DigitalSync::try_from(val.as_u16()?)
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid dig sync response".to_string(),
-            ))
-        }
+        DigitalSync::try_from(response.mode)
     }
 
     /// Set the TTL synchronization configuration.
@@ -627,17 +541,12 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_ttl_sync_set(&mut self, config: &TTLSyncConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.TTLSyncSet",
-            vec![
-                NanonisValue::U16(config.line.into()),
-                NanonisValue::U16(config.polarity.into()),
-                NanonisValue::F32(config.time_to_on.as_secs_f32()),
-                NanonisValue::F32(config.on_duration.as_secs_f32()),
-            ],
-            vec!["H", "H", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrTtlSyncSet {
+            line: config.line.into(),
+            polarity: config.polarity.into(),
+            time_to_on: config.time_to_on.as_secs_f32(),
+            on_duration: config.on_duration.as_secs_f32(),
+        })?;
         Ok(())
     }
 
@@ -659,25 +568,14 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_ttl_sync_get(&mut self) -> Result<TTLSyncConfig, NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.TTLSyncGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "f", "f"],
-        )?;
-
-        if result.len() >= 4 {
-            Ok(TTLSyncConfig {
-                line: TTLLine::try_from(result[0].as_u16()?)?,
-                polarity: TTLPolarity::try_from(result[1].as_u16()?)?,
-                time_to_on: Duration::from_secs_f32(result[2].as_f32()?),
-                on_duration: Duration::from_secs_f32(result[3].as_f32()?),
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid TTL sync response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::BiasSpectrTtlSyncGet {})?;
+
+        Ok(TTLSyncConfig {
+            line: TTLLine::try_from(response.line)?,
+            polarity: TTLPolarity::try_from(response.polarity)?,
+            time_to_on: Duration::from_secs_f32(response.time_to_on),
+            on_duration: Duration::from_secs_f32(response.on_duration),
+        })
     }
 
     /// Set the pulse sequence synchronization configuration.
@@ -705,15 +603,10 @@ impl NanonisClient {
         &mut self,
         config: &PulseSeqSyncConfig,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.PulseSeqSyncSet",
-            vec![
-                NanonisValue::U16(config.sequence_nr),
-                NanonisValue::U32(config.num_periods),
-            ],
-            vec!["H", "I"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrPulseSeqSyncSet {
+            sequence_nr: config.sequence_nr,
+            num_periods: config.num_periods,
+        })?;
         Ok(())
     }
 
@@ -735,19 +628,12 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_pulse_seq_sync_get(&mut self) -> Result<PulseSeqSyncConfig, NanonisError> {
-        let result =
-            self.quick_send("BiasSpectr.PulseSeqSyncGet", vec![], vec![], vec!["H", "I"])?;
+        let response = self.send(&commands::BiasSpectrPulseSeqSyncGet {})?;
 
-        if result.len() >= 2 {
-            Ok(PulseSeqSyncConfig {
-                sequence_nr: result[0].as_u16()?,
-                num_periods: result[1].as_u32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid pulse seq sync response".to_string(),
-            ))
-        }
+        Ok(PulseSeqSyncConfig {
+            sequence_nr: response.sequence_nr,
+            num_periods: response.num_periods,
+        })
     }
 
     /// Set the alternate Z-controller setpoint configuration.
@@ -780,16 +666,11 @@ impl NanonisClient {
             OptionalFlag::Off
         };
 
-        self.quick_send(
-            "BiasSpectr.AltZCtrlSet",
-            vec![
-                NanonisValue::U16(enabled_flag.into()),
-                NanonisValue::F32(config.setpoint),
-                NanonisValue::F32(config.settling_time.as_secs_f32()),
-            ],
-            vec!["H", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrAltZCtrlSet {
+            enabled_flag: enabled_flag.into(),
+            setpoint: config.setpoint,
+            settling_time: config.settling_time.as_secs_f32(),
+        })?;
         Ok(())
     }
 
@@ -811,20 +692,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_alt_z_ctrl_get(&mut self) -> Result<AltZCtrlConfig, NanonisError> {
-        let result =
-            self.quick_send("BiasSpectr.AltZCtrlGet", vec![], vec![], vec!["H", "f", "f"])?;
+        let response = self.send(&commands::BiasSpectrAltZCtrlGet {})?;
 
-        if result.len() >= 3 {
-            Ok(AltZCtrlConfig {
-                enabled: result[0].as_u16()? != 0,
-                setpoint: result[1].as_f32()?,
-                settling_time: Duration::from_secs_f32(result[2].as_f32()?),
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid alt z ctrl response".to_string(),
-            ))
-        }
+        Ok(AltZCtrlConfig {
+            enabled: response.enabled != 0,
+            setpoint: response.setpoint,
+            settling_time: Duration::from_secs_f32(response.settling_time),
+        })
     }
 
     /// Set the Z offset revert flag.
@@ -847,12 +721,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_z_off_revert_set(&mut self, revert: OptionalFlag) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSpectr.ZOffRevertSet",
-            vec![NanonisValue::U16(revert.into())],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrZOffRevertSet { revert: revert.into() })?;
         Ok(())
     }
 
@@ -874,15 +743,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_z_off_revert_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("BiasSpectr.ZOffRevertGet", vec![], vec![], vec!["H"])?;
+        let response = self.send(&commands::BiasSpectrZOffRevertGet {})?;
 
-        if let Some(val) = result.first() {
-            Ok(val.as_u16()? != 0)
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid z off revert response".to_string(),
-            ))
-        }
+        Ok(response.revert != 0)
     }
 
     /// Set the MLS lock-in per segment flag.
@@ -905,12 +768,7 @@ impl NanonisClient {
     /// ```
     pub fn bias_spectr_mls_lockin_per_seg_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "BiasSpectr.MLSLockinPerSegSet",
-            vec![NanonisValue::U32(flag)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrMlsLockinPerSegSet { enabled: flag })?;
         Ok(())
     }
 
@@ -932,16 +790,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_mls_lockin_per_seg_get(&mut self) -> Result<bool, NanonisError> {
-        let result =
-            self.quick_send("BiasSpectr.MLSLockinPerSegGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::BiasSpectrMlsLockinPerSegGet {})?;
 
-        if let Some(val) = result.first() {
-            Ok(val.as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid MLS lockin per seg response".to_string(),
-            ))
-        }
+        Ok(response.enabled != 0)
     }
 
     /// Set the MLS sweep mode.
@@ -963,12 +814,7 @@ impl NanonisClient {
     /// ```
     pub fn bias_spectr_mls_mode_set(&mut self, mode: SweepMode) -> Result<(), NanonisError> {
         let mode_str: &str = mode.into();
-        self.quick_send(
-            "BiasSpectr.MLSModeSet",
-            vec![NanonisValue::String(mode_str.to_string())],
-            vec!["+*c"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrMlsModeSet { mode_str: mode_str.to_string() })?;
         Ok(())
     }
 
@@ -990,24 +836,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_mls_mode_get(&mut self) -> Result<SweepMode, NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.MLSModeGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c"],
-        )?;
+        let response = self.send(&commands::BiasSpectrMlsModeGet {})?;
 
-        if result.len() >= 3 {
-            let modes = result[2].as_string_array()?;
-            if let Some(mode_str) = modes.first() {
-                SweepMode::try_from(mode_str.as_str())
-            } else {
-                Ok(SweepMode::Linear)
-            }
+        let modes = response.modes;
+        if let Some(mode_str) = modes.first() {
+            SweepMode::try_from(mode_str.as_str())
         } else {
-            Err(NanonisError::Protocol(
-                "Invalid MLS mode response".to_string(),
-            ))
+            Ok(SweepMode::Linear)
         }
     }
 
@@ -1062,21 +897,16 @@ impl NanonisClient {
         let slew_rate: Vec<f32> = segments.iter().map(|s| s.max_slew_rate).collect();
         let steps: Vec<i32> = segments.iter().map(|s| s.steps).collect();
 
-        self.quick_send(
-            "BiasSpectr.MLSValsSet",
-            vec![
-                NanonisValue::I32(num_segments),
-                NanonisValue::ArrayF32(bias_start),
-                NanonisValue::ArrayF32(bias_end),
-                NanonisValue::ArrayF32(initial_settling),
-                NanonisValue::ArrayF32(settling),
-                NanonisValue::ArrayF32(integration),
-                NanonisValue::ArrayF32(slew_rate),
-                NanonisValue::ArrayI32(steps),
-            ],
-            vec!["i", "*f", "*f", "*f", "*f", "*f", "*f", "*i"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSpectrMlsValsSet {
+            num_segments,
+            bias_start,
+            bias_end,
+            initial_settling,
+            settling,
+            integration,
+            slew_rate,
+            steps,
+        })?;
         Ok(())
     }
 
@@ -1101,43 +931,32 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_spectr_mls_vals_get(&mut self) -> Result<Vec<MLSSegment>, NanonisError> {
-        let result = self.quick_send(
-            "BiasSpectr.MLSValsGet",
-            vec![],
-            vec![],
-            vec!["i", "*f", "*f", "*f", "*f", "*f", "*f", "*i"],
-        )?;
-
-        if result.len() >= 8 {
-            let num_segments = result[0].as_i32()? as usize;
-            let bias_start = result[1].as_f32_array()?;
-            let bias_end = result[2].as_f32_array()?;
-            let initial_settling = result[3].as_f32_array()?;
-            let settling = result[4].as_f32_array()?;
-            let integration = result[5].as_f32_array()?;
-            let slew_rate = result[6].as_f32_array()?;
-            let steps = result[7].as_i32_array()?;
-
-            let mut segments = Vec::with_capacity(num_segments);
-            for i in 0..num_segments {
-                segments.push(MLSSegment {
-                    bias_start: *bias_start.get(i).unwrap_or(&0.0),
-                    bias_end: *bias_end.get(i).unwrap_or(&0.0),
-                    initial_settling_time: Duration::from_secs_f32(
-                        *initial_settling.get(i).unwrap_or(&0.0),
-                    ),
-                    settling_time: Duration::from_secs_f32(*settling.get(i).unwrap_or(&0.0)),
-                    integration_time: Duration::from_secs_f32(*integration.get(i).unwrap_or(&0.0)),
-                    max_slew_rate: *slew_rate.get(i).unwrap_or(&1.0),
-                    steps: *steps.get(i).unwrap_or(&100),
-                });
-            }
-
-            Ok(segments)
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid MLS vals response".to_string(),
-            ))
+        let response = self.send(&commands::BiasSpectrMlsValsGet {})?;
+
+        let num_segments = response.num_segments as usize;
+        let bias_start = response.bias_start;
+        let bias_end = response.bias_end;
+        let initial_settling = response.initial_settling;
+        let settling = response.settling;
+        let integration = response.integration;
+        let slew_rate = response.slew_rate;
+        let steps = response.steps;
+
+        let mut segments = Vec::with_capacity(num_segments);
+        for i in 0..num_segments {
+            segments.push(MLSSegment {
+                bias_start: *bias_start.get(i).unwrap_or(&0.0),
+                bias_end: *bias_end.get(i).unwrap_or(&0.0),
+                initial_settling_time: Duration::from_secs_f32(
+                    *initial_settling.get(i).unwrap_or(&0.0),
+                ),
+                settling_time: Duration::from_secs_f32(*settling.get(i).unwrap_or(&0.0)),
+                integration_time: Duration::from_secs_f32(*integration.get(i).unwrap_or(&0.0)),
+                max_slew_rate: *slew_rate.get(i).unwrap_or(&1.0),
+                steps: *steps.get(i).unwrap_or(&100),
+            });
         }
+
+        Ok(segments)
     }
 }
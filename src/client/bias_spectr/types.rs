@@ -1,5 +1,7 @@
 // ==================== Bias Spectroscopy Types ====================
 
+use crate::types::Array2D;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Digital synchronization mode for bias spectroscopy.
@@ -113,7 +115,8 @@ impl TryFrom<u16> for TTLPolarity {
 }
 
 /// Optional flag for settings that support "no change" option.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum OptionalFlag {
     /// No change to current setting
     #[default]
@@ -273,7 +276,8 @@ impl Default for BiasSpectrAdvProps {
 ///     .autosave(OptionalFlag::On)
 ///     .build();
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct BiasSpectrPropsBuilder {
     /// Whether to save individual sweep data with average
     pub save_all: OptionalFlag,
@@ -413,8 +417,8 @@ impl Default for AltZCtrlConfig {
 pub struct BiasSpectrResult {
     /// Names of recorded channels
     pub channel_names: Vec<String>,
-    /// 2D data array `[rows][columns]`
-    pub data: Vec<Vec<f32>>,
+    /// 2D data array, one row per channel
+    pub data: Array2D<f32>,
     /// Measurement parameters
     pub parameters: Vec<f32>,
 }
@@ -0,0 +1,86 @@
+//! Typed descriptors for the `BiasSwp.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `BiasSwp.Open`: open the Bias Sweep module.
+    pub struct BiasSwpOpen: "BiasSwp.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `BiasSwp.Start`: start a bias sweep measurement.
+    pub struct BiasSwpStart: "BiasSwp.Start" blocking if get_data {
+        /// If `true`, returns measurement data; if `false`, only starts measurement
+        pub get_data: u32 = "I",
+        /// Sweep direction: `true` starts from lower limit, `false` from upper
+        pub sweep_direction: u32 = "I",
+        /// Z-controller behavior: 0=no change, 1=turn off, 2=don't turn off
+        pub z_controller_status: u32 = "I",
+        /// Base filename for saving data (empty for no change)
+        pub save_base_name: String = "+*c",
+        /// Whether to reset bias after sweep: `true` for on, `false` for off
+        pub reset_bias: u32 = "I",
+    } -> BiasSwpStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Channel names
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// 2D measurement data `[rows][columns]`
+        pub data_2d: Array2D<f32> = "2f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSwp.PropsSet`: set the bias sweep configuration parameters.
+    pub struct BiasSwpPropsSet: "BiasSwp.PropsSet" {
+        /// Number of bias steps in the sweep (0 = no change)
+        pub number_of_steps: u16 = "H",
+        /// Period between steps in milliseconds (0 = no change)
+        pub period_ms: u16 = "H",
+        /// Auto-save behavior: 0=no change, 1=on, 2=off
+        pub autosave: u16 = "H",
+        /// Show save dialog: 0=no change, 1=on, 2=off
+        pub save_dialog_box: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSwp.LimitsSet`: set the bias sweep voltage limits.
+    pub struct BiasSwpLimitsSet: "BiasSwp.LimitsSet" {
+        /// Lower voltage limit in volts
+        pub lower_limit: f32 = "f",
+        /// Upper voltage limit in volts
+        pub upper_limit: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `BiasSwp.LimitsGet`: get the current bias sweep voltage limits.
+    pub struct BiasSwpLimitsGet: "BiasSwp.LimitsGet" {} -> BiasSwpLimitsGetResponse {
+        /// Lower voltage limit in volts
+        pub lower_limit: f32 = "f",
+        /// Upper voltage limit in volts
+        pub upper_limit: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `BiasSwp.PropsGet`: get the current bias sweep configuration parameters.
+    pub struct BiasSwpPropsGet: "BiasSwp.PropsGet" {} -> BiasSwpPropsGetResponse {
+        /// Number of bias steps in the sweep
+        pub num_steps: u16 = "H",
+        /// Period between steps in milliseconds
+        pub period_ms: u16 = "H",
+        /// Autosave enabled
+        pub autosave: u32 = "I",
+        /// Save dialog box enabled
+        pub save_dialog: u32 = "I",
+    }
+}
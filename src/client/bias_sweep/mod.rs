@@ -1,6 +1,8 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 
 impl NanonisClient {
     /// Open the Bias Sweep module.
@@ -23,7 +25,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_sweep_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("BiasSwp.Open", vec![], vec![], vec![])?;
+        self.send(&commands::BiasSwpOpen {})?;
         Ok(())
     }
 
@@ -42,7 +44,7 @@ impl NanonisClient {
     /// # Returns
     /// If `get_data` is true, returns a tuple containing:
     /// - `Vec<String>` - Channel names
-    /// - `Vec<Vec<f32>>` - 2D measurement data `[rows][columns]`
+    /// - `Array2D<f32>` - 2D measurement data `[rows][columns]`
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails or sweep cannot start.
@@ -74,44 +76,23 @@ impl NanonisClient {
         z_controller_status: u32,
         save_base_name: &str,
         reset_bias: bool,
-    ) -> Result<(Vec<String>, Vec<Vec<f32>>), NanonisError> {
+    ) -> Result<(Vec<String>, Array2D<f32>), NanonisError> {
         let get_data_flag = if get_data { 1u32 } else { 0u32 };
         let direction_flag = if sweep_direction { 1u32 } else { 0u32 };
         let reset_flag = if reset_bias { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "BiasSwp.Start",
-            vec![
-                NanonisValue::U32(get_data_flag),
-                NanonisValue::U32(direction_flag),
-                NanonisValue::U32(z_controller_status),
-                NanonisValue::String(save_base_name.to_string()),
-                NanonisValue::U32(reset_flag),
-            ],
-            vec!["I", "I", "I", "+*c", "I"],
-            vec!["i", "i", "*+c", "i", "i", "2f"],
-        )?;
-
-        if result.len() >= 6 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let rows = result[3].as_i32()? as usize;
-            let cols = result[4].as_i32()? as usize;
+        let response = self.send(&commands::BiasSwpStart {
+            get_data: get_data_flag,
+            sweep_direction: direction_flag,
+            z_controller_status,
+            save_base_name: save_base_name.to_string(),
+            reset_bias: reset_flag,
+        })?;
 
-            // Parse 2D data array
-            let flat_data = result[5].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                data_2d.push(flat_data[start_idx..end_idx].to_vec());
-            }
+        let channel_names = response.channel_names;
+        let data_2d = response.data_2d;
 
-            Ok((channel_names, data_2d))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid bias sweep start response".to_string(),
-            ))
-        }
+        Ok((channel_names, data_2d))
     }
 
     /// Set the bias sweep configuration parameters.
@@ -148,17 +129,12 @@ impl NanonisClient {
         autosave: u16,
         save_dialog_box: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSwp.PropsSet",
-            vec![
-                NanonisValue::U16(number_of_steps),
-                NanonisValue::U16(period_ms),
-                NanonisValue::U16(autosave),
-                NanonisValue::U16(save_dialog_box),
-            ],
-            vec!["H", "H", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSwpPropsSet {
+            number_of_steps,
+            period_ms,
+            autosave,
+            save_dialog_box,
+        })?;
         Ok(())
     }
 
@@ -195,15 +171,7 @@ impl NanonisClient {
         lower_limit: f32,
         upper_limit: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "BiasSwp.LimitsSet",
-            vec![
-                NanonisValue::F32(lower_limit),
-                NanonisValue::F32(upper_limit),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::BiasSwpLimitsSet { lower_limit, upper_limit })?;
         Ok(())
     }
 
@@ -236,16 +204,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_sweep_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result =
-            self.quick_send("BiasSwp.LimitsGet", vec![], vec![], vec!["f", "f"])?;
+        let response = self.send(&commands::BiasSwpLimitsGet {})?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid bias sweep limits response".to_string(),
-            ))
-        }
+        Ok((response.lower_limit, response.upper_limit))
     }
 
     /// Get the current bias sweep configuration parameters.
@@ -275,24 +236,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn bias_sweep_props_get(&mut self) -> Result<(u16, u16, bool, bool), NanonisError> {
-        let result = self.quick_send(
-            "BiasSwp.PropsGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "I", "I"],
-        )?;
+        let response = self.send(&commands::BiasSwpPropsGet {})?;
 
-        if result.len() >= 4 {
-            Ok((
-                result[0].as_u16()?,
-                result[1].as_u16()?,
-                result[2].as_u32()? != 0,
-                result[3].as_u32()? != 0,
-            ))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid bias sweep props response".to_string(),
-            ))
-        }
+        Ok((
+            response.num_steps,
+            response.period_ms,
+            response.autosave != 0,
+            response.save_dialog != 0,
+        ))
     }
 }
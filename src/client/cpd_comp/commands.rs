@@ -0,0 +1,65 @@
+//! Typed descriptors for the `CPDComp.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `CPDComp.Open`: open the CPD compensation module.
+    pub struct CpdCompOpen: "CPDComp.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `CPDComp.Close`: close the CPD compensation module.
+    pub struct CpdCompClose: "CPDComp.Close" {} -> ()
+}
+
+nanonis_command! {
+    /// `CPDComp.ParamsSet`: set the CPD compensation parameters.
+    pub struct CpdCompParamsSet: "CPDComp.ParamsSet" {
+        /// Sweep speed in Hz
+        pub speed_hz: f32 = "f",
+        /// Voltage range in volts
+        pub range_v: f32 = "f",
+        /// Number of averaging cycles
+        pub averaging: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `CPDComp.ParamsGet`: get the CPD compensation parameters.
+    pub struct CpdCompParamsGet: "CPDComp.ParamsGet" {} -> CpdCompParamsGetResponse {
+        /// Sweep speed in Hz
+        pub speed_hz: f32 = "f",
+        /// Voltage range in volts
+        pub range_v: f32 = "f",
+        /// Number of averaging cycles
+        pub averaging: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `CPDComp.DataGet`: get the CPD compensation data.
+    pub struct CpdCompDataGet: "CPDComp.DataGet" {} -> CpdCompDataGetResponse {
+        /// Number of forward sweep points
+        pub num_forward_bias: i32 = "i",
+        /// Bias values of the forward sweep in volts
+        pub forward_bias: Vec<f32> = "*f",
+        /// Frequency shift of the forward sweep in Hz
+        pub forward_freq: Vec<f32> = "*f",
+        /// Fitted frequency shift of the forward sweep in Hz
+        pub forward_fit: Vec<f32> = "*f",
+        /// Number of backward sweep points
+        pub num_backward_bias: i32 = "i",
+        /// Bias values of the backward sweep in volts
+        pub backward_bias: Vec<f32> = "*f",
+        /// Frequency shift of the backward sweep in Hz
+        pub backward_freq: Vec<f32> = "*f",
+        /// Fitted frequency shift of the backward sweep in Hz
+        pub backward_fit: Vec<f32> = "*f",
+        /// CPD estimate in volts
+        pub cpd_estimate_v: f32 = "f",
+        /// Quadratic coefficient 'a'
+        pub a: f64 = "d",
+        /// Linear coefficient 'b'
+        pub b: f64 = "d",
+    }
+}
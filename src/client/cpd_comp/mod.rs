@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// CPD compensation parameters.
 #[derive(Debug, Clone, Copy, Default)]
@@ -66,7 +67,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn cpd_comp_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("CPDComp.Open", vec![], vec![], vec![])?;
+        self.send(&commands::CpdCompOpen {})?;
         Ok(())
     }
 
@@ -84,7 +85,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn cpd_comp_close(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("CPDComp.Close", vec![], vec![], vec![])?;
+        self.send(&commands::CpdCompClose {})?;
         Ok(())
     }
 
@@ -111,16 +112,11 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn cpd_comp_params_set(&mut self, params: &CPDCompParams) -> Result<(), NanonisError> {
-        self.quick_send(
-            "CPDComp.ParamsSet",
-            vec![
-                NanonisValue::F32(params.speed_hz),
-                NanonisValue::F32(params.range_v),
-                NanonisValue::I32(params.averaging),
-            ],
-            vec!["f", "f", "i"],
-            vec![],
-        )?;
+        self.send(&commands::CpdCompParamsSet {
+            speed_hz: params.speed_hz,
+            range_v: params.range_v,
+            averaging: params.averaging,
+        })?;
         Ok(())
     }
 
@@ -142,17 +138,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn cpd_comp_params_get(&mut self) -> Result<CPDCompParams, NanonisError> {
-        let result = self.quick_send("CPDComp.ParamsGet", vec![], vec![], vec!["f", "f", "i"])?;
+        let response = self.send(&commands::CpdCompParamsGet {})?;
 
-        if result.len() >= 3 {
-            Ok(CPDCompParams {
-                speed_hz: result[0].as_f32()?,
-                range_v: result[1].as_f32()?,
-                averaging: result[2].as_i32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(CPDCompParams {
+            speed_hz: response.speed_hz,
+            range_v: response.range_v,
+            averaging: response.averaging,
+        })
     }
 
     /// Get the CPD compensation data.
@@ -177,45 +169,32 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn cpd_comp_data_get(&mut self) -> Result<CPDCompData, NanonisError> {
-        let result = self.quick_send(
-            "CPDComp.DataGet",
-            vec![],
-            vec![],
-            vec![
-                "i", "*f", "*f", "*f", // Forward: size, bias, freq_shift, fit
-                "i", "*f", "*f", "*f", // Backward: size, bias, freq_shift, fit
-                "f", "d", "d", // CPD estimate, a, b coefficients
-            ],
-        )?;
+        let response = self.send(&commands::CpdCompDataGet {})?;
 
-        if result.len() >= 11 {
-            let forward_bias = result[1].as_f32_array()?.to_vec();
-            let forward_freq = result[2].as_f32_array()?.to_vec();
-            let forward_fit = result[3].as_f32_array()?.to_vec();
+        let forward_bias = response.forward_bias;
+        let forward_freq = response.forward_freq;
+        let forward_fit = response.forward_fit;
 
-            let backward_bias = result[5].as_f32_array()?.to_vec();
-            let backward_freq = result[6].as_f32_array()?.to_vec();
-            let backward_fit = result[7].as_f32_array()?.to_vec();
+        let backward_bias = response.backward_bias;
+        let backward_freq = response.backward_freq;
+        let backward_fit = response.backward_fit;
 
-            Ok(CPDCompData {
-                forward: CPDSweepData {
-                    bias_v: forward_bias,
-                    freq_shift: forward_freq,
-                    freq_shift_fit: forward_fit,
-                },
-                backward: CPDSweepData {
-                    bias_v: backward_bias,
-                    freq_shift: backward_freq,
-                    freq_shift_fit: backward_fit,
-                },
-                cpd_estimate_v: result[8].as_f32()?,
-                fit_coefficients: CPDFitCoefficients {
-                    a: result[9].as_f64()?,
-                    b: result[10].as_f64()?,
-                },
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(CPDCompData {
+            forward: CPDSweepData {
+                bias_v: forward_bias,
+                freq_shift: forward_freq,
+                freq_shift_fit: forward_fit,
+            },
+            backward: CPDSweepData {
+                bias_v: backward_bias,
+                freq_shift: backward_freq,
+                freq_shift_fit: backward_fit,
+            },
+            cpd_estimate_v: response.cpd_estimate_v,
+            fit_coefficients: CPDFitCoefficients {
+                a: response.a,
+                b: response.b,
+            },
+        })
     }
 }
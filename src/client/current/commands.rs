@@ -0,0 +1,84 @@
+//! Typed descriptors for the `Current.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Current.Get`: get the current tunneling current value.
+    pub struct CurrentGet: "Current.Get" {} -> CurrentGetResponse {
+        /// Tunneling current in amperes
+        pub current: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Current.100Get`: get the current value from the "Current 100" module.
+    pub struct Current100Get: "Current.100Get" {} -> Current100GetResponse {
+        /// Current in amperes
+        pub current: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Current.BEEMGet`: get the BEEM current value from the corresponding module.
+    pub struct CurrentBeemGet: "Current.BEEMGet" {} -> CurrentBeemGetResponse {
+        /// BEEM current in amperes
+        pub current: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Current.GainSet`: set the gain and filter of the current amplifier.
+    pub struct CurrentGainSet: "Current.GainSet" {
+        /// Index from the list of available gains
+        pub gain_index: i32 = "i",
+        /// Index from the list of available filters
+        pub filter_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Current.GainsGet`: get the available gains and filters of the current amplifier.
+    pub struct CurrentGainsGet: "Current.GainsGet" {} -> CurrentGainsGetResponse {
+        /// Size of the gains array in bytes
+        pub gains_size: i32 = "i",
+        /// Number of gains
+        pub num_gains: i32 = "i",
+        /// Array of available gain descriptions
+        pub gains: Vec<String> = "*+c",
+        /// Index of currently selected gain
+        pub gain_index: i32 = "i",
+        /// Size of the filters array in bytes
+        pub filters_size: i32 = "i",
+        /// Number of filters
+        pub num_filters: i32 = "i",
+        /// Array of available filter descriptions
+        pub filters: Vec<String> = "*+c",
+        /// Index of currently selected filter
+        pub filter_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `Current.CalibrSet`: set the calibration and offset for a specific gain in the Current module.
+    pub struct CurrentCalibrSet: "Current.CalibrSet" {
+        /// Index of the gain to calibrate (-1 for currently selected gain)
+        pub gain_index: i32 = "i",
+        /// Calibration factor (typically A/V or similar)
+        pub calibration: f64 = "d",
+        /// Offset value in the same units
+        pub offset: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Current.CalibrGet`: get the calibration and offset for a specific gain in the Current module.
+    pub struct CurrentCalibrGet: "Current.CalibrGet" {
+        /// Index of the gain to query (-1 for currently selected gain)
+        pub gain_index: i32 = "i",
+    } -> CurrentCalibrGetResponse {
+        /// Calibration factor
+        pub calibration: f64 = "d",
+        /// Offset value
+        pub offset: f64 = "d",
+    }
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     /// Get the current tunneling current value.
@@ -33,14 +34,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn current_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Current.Get", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::CurrentGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No current value returned".to_string(),
-            )),
-        }
+        Ok(response.current)
     }
 
     /// Get the current value from the "Current 100" module.
@@ -66,14 +62,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn current_100_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Current.100Get", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::Current100Get {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No current 100 value returned".to_string(),
-            )),
-        }
+        Ok(response.current)
     }
 
     /// Get the BEEM current value from the corresponding module.
@@ -101,14 +92,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn current_beem_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Current.BEEMGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::CurrentBeemGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No BEEM current value returned".to_string(),
-            )),
-        }
+        Ok(response.current)
     }
 
     /// Set the gain and filter of the current amplifier.
@@ -146,15 +132,7 @@ impl NanonisClient {
         gain_index: i32,
         filter_index: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Current.GainSet",
-            vec![
-                NanonisValue::I32(gain_index),
-                NanonisValue::I32(filter_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::CurrentGainSet { gain_index, filter_index })?;
         Ok(())
     }
 
@@ -192,25 +170,14 @@ impl NanonisClient {
     /// ```
     pub fn current_gains_get(
         &mut self,
-    ) -> Result<(Vec<String>, u16, Vec<String>, i32), NanonisError> {
-        let result = self.quick_send(
-            "Current.GainsGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c", "i", "i", "i", "*+c", "i"],
-        )?;
-
-        if result.len() >= 8 {
-            let gains = result[2].as_string_array()?.to_vec();
-            let gain_index = result[3].as_u16()?;
-            let filters = result[6].as_string_array()?.to_vec();
-            let filter_index = result[7].as_i32()?;
-            Ok((gains, gain_index, filters, filter_index))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid current gains response".to_string(),
-            ))
-        }
+    ) -> Result<(Vec<String>, i32, Vec<String>, i32), NanonisError> {
+        let response = self.send(&commands::CurrentGainsGet {})?;
+        Ok((
+            response.gains,
+            response.gain_index,
+            response.filters,
+            response.filter_index,
+        ))
     }
 
     /// Set the calibration and offset for a specific gain in the Current module.
@@ -245,16 +212,7 @@ impl NanonisClient {
         calibration: f64,
         offset: f64,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Current.CalibrSet",
-            vec![
-                NanonisValue::I32(gain_index),
-                NanonisValue::F64(calibration),
-                NanonisValue::F64(offset),
-            ],
-            vec!["i", "d", "d"],
-            vec![],
-        )?;
+        self.send(&commands::CurrentCalibrSet { gain_index, calibration, offset })?;
         Ok(())
     }
 
@@ -288,21 +246,8 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn current_calibr_get(&mut self, gain_index: i32) -> Result<(f64, f64), NanonisError> {
-        let result = self.quick_send(
-            "Current.CalibrGet",
-            vec![NanonisValue::I32(gain_index)],
-            vec!["i"],
-            vec!["d", "d"],
-        )?;
+        let response = self.send(&commands::CurrentCalibrGet { gain_index })?;
 
-        if result.len() >= 2 {
-            let calibration = result[0].as_f64()?;
-            let offset = result[1].as_f64()?;
-            Ok((calibration, offset))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid current calibration response".to_string(),
-            ))
-        }
+        Ok((response.calibration, response.offset))
     }
 }
@@ -0,0 +1,108 @@
+//! Typed descriptors for the `DataLog.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `DataLog.Open`: open the Data Logger module.
+    pub struct DataLogOpen: "DataLog.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `DataLog.Start`: start the acquisition in the Data Logger module.
+    pub struct DataLogStart: "DataLog.Start" {} -> ()
+}
+
+nanonis_command! {
+    /// `DataLog.Stop`: stop the acquisition in the Data Logger module.
+    pub struct DataLogStop: "DataLog.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `DataLog.StatusGet`: get the status of the Data Logger module.
+    pub struct DataLogStatusGet: "DataLog.StatusGet" {} -> DataLogStatusGetResponse {
+        /// Length of the start time in bytes
+        pub start_time_size: i32 = "i",
+        /// Timestamp when acquisition started
+        pub start_time: String = "*-c",
+        /// Hours elapsed since acquisition started
+        pub elapsed_hours: u16 = "H",
+        /// Minutes displayed
+        pub elapsed_minutes: u16 = "H",
+        /// Seconds displayed
+        pub elapsed_seconds: f32 = "f",
+        /// Length of the stop time in bytes
+        pub stop_time_size: i32 = "i",
+        /// Timestamp when acquisition stopped
+        pub stop_time: String = "*-c",
+        /// Length of the saved file path in bytes
+        pub saved_file_path_size: i32 = "i",
+        /// Path to last saved file
+        pub saved_file_path: String = "*-c",
+        /// Number of points saved
+        pub saved_points: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `DataLog.ChsSet`: set the list of recorded channels in the Data Logger.
+    pub struct DataLogChsSet: "DataLog.ChsSet" {
+        /// Signal slot indexes (0-23) of the recorded channels
+        pub channel_indexes: Vec<i32> = "+*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `DataLog.ChsGet`: get the list of recorded channels in the Data Logger.
+    pub struct DataLogChsGet: "DataLog.ChsGet" {} -> DataLogChsGetResponse {
+        /// Number of channels
+        pub num_channels: i32 = "i",
+        /// Signal slot indexes (0-23) of the recorded channels
+        pub channel_indexes: Vec<i32> = "*i",
+    }
+}
+
+nanonis_command! {
+    /// `DataLog.PropsSet`: set the acquisition configuration for the Data Logger.
+    pub struct DataLogPropsSet: "DataLog.PropsSet" {
+        /// Acquisition mode
+        pub mode: u16 = "H",
+        /// Acquisition duration hours
+        pub duration_hours: i32 = "i",
+        /// Acquisition duration minutes
+        pub duration_minutes: i32 = "i",
+        /// Acquisition duration seconds
+        pub duration_seconds: f32 = "f",
+        /// Averaging count (samples averaged per data point)
+        pub averaging: i32 = "i",
+        /// Base filename for saved files
+        pub basename: String = "+*c",
+        /// Comment saved in file
+        pub comment: String = "+*c",
+        /// List of module names whose parameters will be saved in file header
+        pub modules: Vec<String> = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `DataLog.PropsGet`: get the acquisition configuration for the Data Logger.
+    pub struct DataLogPropsGet: "DataLog.PropsGet" {} -> DataLogPropsGetResponse {
+        /// Acquisition mode
+        pub mode: u16 = "H",
+        /// Acquisition duration hours
+        pub duration_hours: i32 = "i",
+        /// Acquisition duration minutes
+        pub duration_minutes: i32 = "i",
+        /// Acquisition duration seconds
+        pub duration_seconds: f32 = "f",
+        /// Averaging count (samples averaged per data point)
+        pub averaging: i32 = "i",
+        /// Length of the basename in bytes
+        pub basename_size: i32 = "i",
+        /// Base filename for saved files
+        pub basename: String = "*-c",
+        /// Length of the comment in bytes
+        pub comment_size: i32 = "i",
+        /// Comment saved in file
+        pub comment: String = "*-c",
+    }
+}
@@ -1,6 +1,8 @@
+pub mod commands;
+
+use super::signals::SlotRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Data Logger acquisition mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -102,7 +104,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn data_log_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("DataLog.Open", vec![], vec![], vec![])?;
+        self.send(&commands::DataLogOpen {})?;
         Ok(())
     }
 
@@ -113,7 +115,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn data_log_start(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("DataLog.Start", vec![], vec![], vec![])?;
+        self.send(&commands::DataLogStart {})?;
         Ok(())
     }
 
@@ -122,7 +124,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn data_log_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("DataLog.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::DataLogStop {})?;
         Ok(())
     }
 
@@ -134,42 +136,37 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn data_log_status_get(&mut self) -> Result<DataLogStatus, NanonisError> {
-        let result = self.quick_send(
-            "DataLog.StatusGet",
-            vec![],
-            vec![],
-            vec!["i", "*-c", "H", "H", "f", "i", "*-c", "i", "*-c", "i"],
-        )?;
-
-        if result.len() >= 10 {
-            Ok(DataLogStatus {
-                start_time: result[1].as_string()?.to_string(),
-                elapsed_hours: result[2].as_u16()?,
-                elapsed_minutes: result[3].as_u16()?,
-                elapsed_seconds: result[4].as_f32()?,
-                stop_time: result[6].as_string()?.to_string(),
-                saved_file_path: result[8].as_string()?.to_string(),
-                saved_points: result[9].as_i32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::DataLogStatusGet {})?;
+
+        Ok(DataLogStatus {
+            start_time: response.start_time,
+            elapsed_hours: response.elapsed_hours,
+            elapsed_minutes: response.elapsed_minutes,
+            elapsed_seconds: response.elapsed_seconds,
+            stop_time: response.stop_time,
+            saved_file_path: response.saved_file_path,
+            saved_points: response.saved_points,
+        })
     }
 
     /// Set the list of recorded channels in the Data Logger.
     ///
     /// # Arguments
-    /// * `channel_indexes` - Channel indexes (0-23 for signals in the Signals Manager)
+    /// * `channels` - Slots (0-23) to record, or signals by name or
+    ///   [`SignalIndex`](crate::signals::SignalIndex) assigned to a slot
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
-    pub fn data_log_chs_set(&mut self, channel_indexes: &[i32]) -> Result<(), NanonisError> {
-        self.quick_send(
-            "DataLog.ChsSet",
-            vec![NanonisValue::ArrayI32(channel_indexes.to_vec())],
-            vec!["+*i"],
-            vec![],
-        )?;
+    /// Returns `NanonisError` if a signal does not resolve or has no slot,
+    /// or communication fails.
+    pub fn data_log_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let channel_indexes = channels
+            .into_iter()
+            .map(|channel| self.resolve_slot(channel))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.send(&commands::DataLogChsSet { channel_indexes })?;
         Ok(())
     }
 
@@ -181,13 +178,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn data_log_chs_get(&mut self) -> Result<Vec<i32>, NanonisError> {
-        let result = self.quick_send("DataLog.ChsGet", vec![], vec![], vec!["i", "*i"])?;
+        let response = self.send(&commands::DataLogChsGet {})?;
 
-        if result.len() >= 2 {
-            Ok(result[1].as_i32_array()?.to_vec())
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.channel_indexes)
     }
 
     /// Set the acquisition configuration for the Data Logger.
@@ -222,21 +215,16 @@ impl NanonisClient {
         props: &DataLogProps,
         modules: &[String],
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "DataLog.PropsSet",
-            vec![
-                NanonisValue::U16(props.mode.into()),
-                NanonisValue::I32(props.duration_hours),
-                NanonisValue::I32(props.duration_minutes),
-                NanonisValue::F32(props.duration_seconds),
-                NanonisValue::I32(props.averaging),
-                NanonisValue::String(props.basename.clone()),
-                NanonisValue::String(props.comment.clone()),
-                NanonisValue::ArrayString(modules.to_vec()),
-            ],
-            vec!["H", "i", "i", "f", "i", "+*c", "+*c", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::DataLogPropsSet {
+            mode: props.mode.into(),
+            duration_hours: props.duration_hours,
+            duration_minutes: props.duration_minutes,
+            duration_seconds: props.duration_seconds,
+            averaging: props.averaging,
+            basename: props.basename.clone(),
+            comment: props.comment.clone(),
+            modules: modules.to_vec(),
+        })?;
         Ok(())
     }
 
@@ -248,25 +236,16 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn data_log_props_get(&mut self) -> Result<DataLogProps, NanonisError> {
-        let result = self.quick_send(
-            "DataLog.PropsGet",
-            vec![],
-            vec![],
-            vec!["H", "i", "i", "f", "i", "i", "*-c", "i", "*-c"],
-        )?;
-
-        if result.len() >= 9 {
-            Ok(DataLogProps {
-                mode: result[0].as_u16()?.try_into()?,
-                duration_hours: result[1].as_i32()?,
-                duration_minutes: result[2].as_i32()?,
-                duration_seconds: result[3].as_f32()?,
-                averaging: result[4].as_i32()?,
-                basename: result[6].as_string()?.to_string(),
-                comment: result[8].as_string()?.to_string(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::DataLogPropsGet {})?;
+
+        Ok(DataLogProps {
+            mode: response.mode.try_into()?,
+            duration_hours: response.duration_hours,
+            duration_minutes: response.duration_minutes,
+            duration_seconds: response.duration_seconds,
+            averaging: response.averaging,
+            basename: response.basename,
+            comment: response.comment,
+        })
     }
 }
@@ -0,0 +1,60 @@
+//! Typed descriptors for the `DigLines.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `DigLines.PropsSet`: configure the properties of a digital line.
+    pub struct DigLinesPropsSet: "DigLines.PropsSet" {
+        /// Digital line number (1-8)
+        pub line: u32 = "I",
+        /// Port selection
+        pub port: u32 = "I",
+        /// Line direction (input/output)
+        pub direction: u32 = "I",
+        /// Line polarity
+        pub polarity: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `DigLines.OutStatusSet`: set the status of a digital output line.
+    pub struct DigLinesOutStatusSet: "DigLines.OutStatusSet" {
+        /// Port selection
+        pub port: u32 = "I",
+        /// Digital line number (1-8)
+        pub line: u32 = "I",
+        /// True for active, false for inactive
+        pub active: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `DigLines.TTLValGet`: read the TTL voltages present at the pins of the selected port.
+    pub struct DigLinesTtlValGet: "DigLines.TTLValGet" {
+        /// Port selection
+        pub port: u16 = "H",
+    } -> DigLinesTtlValGetResponse {
+        /// Number of lines
+        pub num_lines: i32 = "i",
+        /// TTL value of each line
+        pub values: Vec<u32> = "*I",
+    }
+}
+
+nanonis_command! {
+    /// `DigLines.Pulse`: configure and start the pulse generator on the selected digital outputs.
+    pub struct DigLinesPulse: "DigLines.Pulse" {
+        /// Port selection
+        pub port: u16 = "H",
+        /// Digital lines to pulse (1-8)
+        pub lines: Vec<u8> = "+*b",
+        /// Pulse width in seconds
+        pub pulse_width_s: f32 = "f",
+        /// Pulse pause in seconds
+        pub pulse_pause_s: f32 = "f",
+        /// Number of pulses (1-32767)
+        pub num_pulses: i32 = "i",
+        /// Wait until all pulses are generated before returning
+        pub wait_until_finished: u32 = "I",
+    } -> ()
+}
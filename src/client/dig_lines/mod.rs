@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Digital port selection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -139,17 +140,12 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn dig_lines_props_set(&mut self, config: &DigitalLineConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "DigLines.PropsSet",
-            vec![
-                NanonisValue::U32(config.line),
-                NanonisValue::U32(config.port.into()),
-                NanonisValue::U32(config.direction.into()),
-                NanonisValue::U32(config.polarity.into()),
-            ],
-            vec!["I", "I", "I", "I"],
-            vec![],
-        )?;
+        self.send(&commands::DigLinesPropsSet {
+            line: config.line,
+            port: config.port.into(),
+            direction: config.direction.into(),
+            polarity: config.polarity.into(),
+        })?;
         Ok(())
     }
 
@@ -169,16 +165,11 @@ impl NanonisClient {
         active: bool,
     ) -> Result<(), NanonisError> {
         let status = if active { 1u32 } else { 0u32 };
-        self.quick_send(
-            "DigLines.OutStatusSet",
-            vec![
-                NanonisValue::U32(port.into()),
-                NanonisValue::U32(line),
-                NanonisValue::U32(status),
-            ],
-            vec!["I", "I", "I"],
-            vec![],
-        )?;
+        self.send(&commands::DigLinesOutStatusSet {
+            port: port.into(),
+            line,
+            active: status,
+        })?;
         Ok(())
     }
 
@@ -193,18 +184,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn dig_lines_ttl_val_get(&mut self, port: DigitalPort) -> Result<Vec<u32>, NanonisError> {
-        let result = self.quick_send(
-            "DigLines.TTLValGet",
-            vec![NanonisValue::U16(port.into())],
-            vec!["H"],
-            vec!["i", "*I"],
-        )?;
+        let response = self.send(&commands::DigLinesTtlValGet { port: port.into() })?;
 
-        if result.len() >= 2 {
-            Ok(result[1].as_u32_array()?.to_vec())
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.values)
     }
 
     /// Configure and start the pulse generator on the selected digital outputs.
@@ -239,19 +221,14 @@ impl NanonisClient {
             0u32
         };
 
-        self.quick_send(
-            "DigLines.Pulse",
-            vec![
-                NanonisValue::U16(config.port.into()),
-                NanonisValue::ArrayU8(config.lines.clone()),
-                NanonisValue::F32(config.pulse_width_s),
-                NanonisValue::F32(config.pulse_pause_s),
-                NanonisValue::I32(config.num_pulses),
-                NanonisValue::U32(wait_flag),
-            ],
-            vec!["H", "+*b", "f", "f", "i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::DigLinesPulse {
+            port: config.port.into(),
+            lines: config.lines.clone(),
+            pulse_width_s: config.pulse_width_s,
+            pulse_pause_s: config.pulse_pause_s,
+            num_pulses: config.num_pulses,
+            wait_until_finished: wait_flag,
+        })?;
         Ok(())
     }
 }
@@ -0,0 +1,145 @@
+//! Typed descriptors for the `FolMe.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `FolMe.XYPosGet`: get the current x-y position.
+    pub struct FolMeXyPosGet: "FolMe.XYPosGet" {
+        /// If true, waits for newest data
+        pub wait_for_newest_data: u32 = "I",
+    } -> FolMeXyPosGetResponse {
+        /// X position in meters
+        pub x: f64 = "d",
+        /// Y position in meters
+        pub y: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `FolMe.XYPosSet`: set the x-y position.
+    pub struct FolMeXyPosSet: "FolMe.XYPosSet" {
+        /// X position in meters
+        pub x: f64 = "d",
+        /// Y position in meters
+        pub y: f64 = "d",
+        /// If true, waits until move completes
+        pub wait_until_finished: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `FolMe.SpeedSet`: set the tip speed when moving in Follow Me mode.
+    pub struct FolMeSpeedSet: "FolMe.SpeedSet" {
+        /// Speed, e.g. [`MetersPerSecond::nanometers_per_second`]
+        pub speed: f32 = "f",
+        /// True to use custom speed, false for scan speed
+        pub custom_speed: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `FolMe.SpeedGet`: get the tip speed configuration for Follow Me mode.
+    pub struct FolMeSpeedGet: "FolMe.SpeedGet" {} -> FolMeSpeedGetResponse {
+        /// Speed in m/s
+        pub speed_m_s: f32 = "f",
+        /// True if using custom speed, false if using scan speed
+        pub custom_speed: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `FolMe.OversamplSet`: set the oversampling for data acquisition in Follow Me mode.
+    pub struct FolMeOversamplSet: "FolMe.OversamplSet" {
+        /// Oversampling factor
+        pub oversampling: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `FolMe.OversamplGet`: get the oversampling and sampling rate for Follow Me mode.
+    pub struct FolMeOversamplGet: "FolMe.OversamplGet" {} -> FolMeOversamplGetResponse {
+        /// Oversampling factor
+        pub oversampling: i32 = "i",
+        /// Sampling rate in samples/s
+        pub sampling_rate: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `FolMe.Stop`: stop the tip movement in Follow Me mode.
+    pub struct FolMeStop: "FolMe.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `FolMe.PSOnOffGet`: get the Point & Shoot status in Follow Me mode.
+    pub struct FolMePsOnOffGet: "FolMe.PSOnOffGet" {} -> FolMePsOnOffGetResponse {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `FolMe.PSOnOffSet`: enable or disable Point & Shoot in Follow Me mode.
+    pub struct FolMePsOnOffSet: "FolMe.PSOnOffSet" {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `FolMe.PSExpGet`: get the Point & Shoot experiment configuration.
+    pub struct FolMePsExpGet: "FolMe.PSExpGet" {} -> FolMePsExpGetResponse {
+        /// Selected experiment index
+        pub selected: u16 = "H",
+        /// Size of the experiments array in bytes
+        pub experiments_size: i32 = "i",
+        /// Number of experiments
+        pub num_experiments: i32 = "i",
+        /// List of available experiments
+        pub experiments: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `FolMe.PSExpSet`: set the Point & Shoot experiment.
+    pub struct FolMePsExpSet: "FolMe.PSExpSet" {
+        /// Index of the experiment to select
+        pub experiment_index: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `FolMe.PSPropsGet`: get the Point & Shoot properties.
+    pub struct FolMePsPropsGet: "FolMe.PSPropsGet" {} -> FolMePsPropsGetResponse {
+        /// True if scan resumes after experiment
+        pub auto_resume: u32 = "I",
+        /// True to use experiment's basename, false for P&S basename
+        pub use_own_basename: u32 = "I",
+        /// Length of the basename in bytes
+        pub basename_size: i32 = "i",
+        /// Basename for Point & Shoot files
+        pub basename: String = "*-c",
+        /// Length of the external VI path in bytes
+        pub external_vi_path_size: i32 = "i",
+        /// Path to external VI
+        pub external_vi_path: String = "*-c",
+        /// Delay before measurement in seconds
+        pub pre_measure_delay_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `FolMe.PSPropsSet`: set the Point & Shoot properties.
+    pub struct FolMePsPropsSet: "FolMe.PSPropsSet" {
+        /// Resume mode after experiment
+        pub auto_resume: u32 = "I",
+        /// Basename selection mode
+        pub basename_mode: u32 = "I",
+        /// Basename for Point & Shoot files
+        pub basename: String = "+*c",
+        /// Path to external VI
+        pub external_vi_path: String = "+*c",
+        /// Delay before measurement in seconds
+        pub pre_measure_delay_s: f32 = "f",
+    } -> ()
+}
@@ -1,6 +1,9 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::{NanonisValue, Position};
+use crate::types::Position;
+use crate::units::MetersPerSecond;
 
 /// Follow Me speed configuration.
 #[derive(Debug, Clone, Copy, Default)]
@@ -92,23 +95,12 @@ impl NanonisClient {
         wait_for_newest_data: bool,
     ) -> Result<Position, NanonisError> {
         let wait_flag = if wait_for_newest_data { 1u32 } else { 0u32 };
-        let result = self.quick_send(
-            "FolMe.XYPosGet",
-            vec![NanonisValue::U32(wait_flag)],
-            vec!["I"],
-            vec!["d", "d"],
-        )?;
+        let response = self.send(&commands::FolMeXyPosGet { wait_for_newest_data: wait_flag })?;
 
-        if result.len() >= 2 {
-            Ok(Position {
-                x: result[0].as_f64()?,
-                y: result[1].as_f64()?,
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid position response".to_string(),
-            ))
-        }
+        Ok(Position {
+            x: response.x,
+            y: response.y,
+        })
     }
 
     /// Set the x-y position.
@@ -125,38 +117,32 @@ impl NanonisClient {
         wait_until_finished: bool,
     ) -> Result<(), NanonisError> {
         let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
-        self.quick_send(
-            "FolMe.XYPosSet",
-            vec![
-                NanonisValue::F64(position.x),
-                NanonisValue::F64(position.y),
-                NanonisValue::U32(wait_flag),
-            ],
-            vec!["d", "d", "I"],
-            vec![],
-        )?;
+        self.send(&commands::FolMeXyPosSet {
+            x: position.x,
+            y: position.y,
+            wait_until_finished: wait_flag,
+        })?;
         Ok(())
     }
 
     /// Set the tip speed when moving in Follow Me mode.
     ///
     /// # Arguments
-    /// * `speed_m_s` - Speed in m/s
+    /// * `speed` - Speed, e.g. [`MetersPerSecond::nanometers_per_second`]
     /// * `custom_speed` - True to use custom speed, false for scan speed
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails.
-    pub fn folme_speed_set(&mut self, speed_m_s: f32, custom_speed: bool) -> Result<(), NanonisError> {
+    pub fn folme_speed_set(
+        &mut self,
+        speed: impl Into<MetersPerSecond>,
+        custom_speed: bool,
+    ) -> Result<(), NanonisError> {
         let custom_speed_flag = if custom_speed { 1u32 } else { 0u32 };
-        self.quick_send(
-            "FolMe.SpeedSet",
-            vec![
-                NanonisValue::F32(speed_m_s),
-                NanonisValue::U32(custom_speed_flag),
-            ],
-            vec!["f", "I"],
-            vec![],
-        )?;
+        self.send(&commands::FolMeSpeedSet {
+            speed: speed.into().as_f32(),
+            custom_speed: custom_speed_flag,
+        })?;
         Ok(())
     }
 
@@ -168,11 +154,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_speed_get(&mut self) -> Result<FolMeSpeed, NanonisError> {
-        let result = self.quick_send("FolMe.SpeedGet", vec![], vec![], vec!["f", "I"])?;
+        let response = self.send(&commands::FolMeSpeedGet {})?;
 
         Ok(FolMeSpeed {
-            speed_m_s: result[0].as_f32()?,
-            custom_speed: result[1].as_u32()? != 0,
+            speed_m_s: response.speed_m_s,
+            custom_speed: response.custom_speed != 0,
         })
     }
 
@@ -184,12 +170,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_oversampl_set(&mut self, oversampling: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "FolMe.OversamplSet",
-            vec![NanonisValue::I32(oversampling)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::FolMeOversamplSet { oversampling })?;
         Ok(())
     }
 
@@ -201,11 +182,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_oversampl_get(&mut self) -> Result<FolMeOversampling, NanonisError> {
-        let result = self.quick_send("FolMe.OversamplGet", vec![], vec![], vec!["i", "f"])?;
+        let response = self.send(&commands::FolMeOversamplGet {})?;
 
         Ok(FolMeOversampling {
-            oversampling: result[0].as_i32()?,
-            sampling_rate: result[1].as_f32()?,
+            oversampling: response.oversampling,
+            sampling_rate: response.sampling_rate,
         })
     }
 
@@ -214,7 +195,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("FolMe.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::FolMeStop {})?;
         Ok(())
     }
 
@@ -226,9 +207,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_ps_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("FolMe.PSOnOffGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::FolMePsOnOffGet {})?;
 
-        Ok(result[0].as_u32()? != 0)
+        Ok(response.enabled != 0)
     }
 
     /// Enable or disable Point & Shoot in Follow Me mode.
@@ -239,12 +220,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_ps_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
-        self.quick_send(
-            "FolMe.PSOnOffSet",
-            vec![NanonisValue::U32(if enabled { 1 } else { 0 })],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::FolMePsOnOffSet { enabled: if enabled { 1 } else { 0 } })?;
         Ok(())
     }
 
@@ -256,16 +232,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_ps_exp_get(&mut self) -> Result<FolMePSExperiment, NanonisError> {
-        let result = self.quick_send(
-            "FolMe.PSExpGet",
-            vec![],
-            vec![],
-            vec!["H", "i", "i", "*+c"],
-        )?;
+        let response = self.send(&commands::FolMePsExpGet {})?;
 
         Ok(FolMePSExperiment {
-            selected: result[0].as_u16()?,
-            experiments: result[3].as_string_array()?.to_vec(),
+            selected: response.selected,
+            experiments: response.experiments,
         })
     }
 
@@ -277,12 +248,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_ps_exp_set(&mut self, experiment_index: u16) -> Result<(), NanonisError> {
-        self.quick_send(
-            "FolMe.PSExpSet",
-            vec![NanonisValue::U16(experiment_index)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::FolMePsExpSet { experiment_index })?;
         Ok(())
     }
 
@@ -294,19 +260,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn folme_ps_props_get(&mut self) -> Result<FolMePSProps, NanonisError> {
-        let result = self.quick_send(
-            "FolMe.PSPropsGet",
-            vec![],
-            vec![],
-            vec!["I", "I", "i", "*-c", "i", "*-c", "f"],
-        )?;
+        let response = self.send(&commands::FolMePsPropsGet {})?;
 
         Ok(FolMePSProps {
-            auto_resume: result[0].as_u32()? != 0,
-            use_own_basename: result[1].as_u32()? != 0,
-            basename: result[3].as_string()?.to_string(),
-            external_vi_path: result[5].as_string()?.to_string(),
-            pre_measure_delay_s: result[6].as_f32()?,
+            auto_resume: response.auto_resume != 0,
+            use_own_basename: response.use_own_basename != 0,
+            basename: response.basename,
+            external_vi_path: response.external_vi_path,
+            pre_measure_delay_s: response.pre_measure_delay_s,
         })
     }
 
@@ -329,18 +290,13 @@ impl NanonisClient {
         external_vi_path: &str,
         pre_measure_delay_s: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "FolMe.PSPropsSet",
-            vec![
-                NanonisValue::U32(auto_resume.into()),
-                NanonisValue::U32(basename_mode.into()),
-                NanonisValue::String(basename.to_string()),
-                NanonisValue::String(external_vi_path.to_string()),
-                NanonisValue::F32(pre_measure_delay_s),
-            ],
-            vec!["I", "I", "+*c", "+*c", "f"],
-            vec![],
-        )?;
+        self.send(&commands::FolMePsPropsSet {
+            auto_resume: auto_resume.into(),
+            basename_mode: basename_mode.into(),
+            basename: basename.to_string(),
+            external_vi_path: external_vi_path.to_string(),
+            pre_measure_delay_s,
+        })?;
         Ok(())
     }
 }
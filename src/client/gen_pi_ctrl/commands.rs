@@ -0,0 +1,137 @@
+//! Typed descriptors for the `GenPICtrl.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `GenPICtrl.OnOffSet`: enable or disable the Generic PI Controller.
+    pub struct GenPiCtrlOnOffSet: "GenPICtrl.OnOffSet" {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenPICtrl.OnOffGet`: get the on/off status of the Generic PI Controller.
+    pub struct GenPiCtrlOnOffGet: "GenPICtrl.OnOffGet" {} -> GenPiCtrlOnOffGetResponse {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `GenPICtrl.AOValSet`: set the output signal value of the User Output controlled by the Generic PI controller.
+    pub struct GenPiCtrlAoValSet: "GenPICtrl.AOValSet" {
+        /// Output value
+        pub output_value: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenPICtrl.AOValGet`: get the output signal value of the User Output controlled by the Generic PI controller.
+    pub struct GenPiCtrlAoValGet: "GenPICtrl.AOValGet" {} -> GenPiCtrlAoValGetResponse {
+        /// Output value
+        pub output_value: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `GenPICtrl.AOPropsSet`: set the properties of the User Output controlled by the Generic PI controller.
+    pub struct GenPiCtrlAoPropsSet: "GenPICtrl.AOPropsSet" {
+        /// Signal name
+        pub signal_name: String = "+*c",
+        /// Physical units
+        pub units: String = "+*c",
+        /// Upper physical limit
+        pub upper_limit: f32 = "f",
+        /// Lower physical limit
+        pub lower_limit: f32 = "f",
+        /// Calibration per volt
+        pub calibration_per_volt: f32 = "f",
+        /// Offset in physical units
+        pub offset: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenPICtrl.AOPropsGet`: get the properties of the User Output controlled by the Generic PI controller.
+    pub struct GenPiCtrlAoPropsGet: "GenPICtrl.AOPropsGet" {} -> GenPiCtrlAoPropsGetResponse {
+        /// Length of the signal name in bytes
+        pub signal_name_size: i32 = "i",
+        /// Signal name
+        pub signal_name: String = "*-c",
+        /// Length of the units in bytes
+        pub units_size: i32 = "i",
+        /// Physical units
+        pub units: String = "*-c",
+        /// Upper physical limit
+        pub upper_limit: f32 = "f",
+        /// Lower physical limit
+        pub lower_limit: f32 = "f",
+        /// Calibration per volt
+        pub calibration_per_volt: f32 = "f",
+        /// Offset in physical units
+        pub offset: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `GenPICtrl.ModChSet`: set the index of the User Output controlled by the Generic PI controller.
+    pub struct GenPiCtrlModChSet: "GenPICtrl.ModChSet" {
+        /// Output index (1 to number of available outputs)
+        pub output_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenPICtrl.ModChGet`: get the index of the User Output controlled by the Generic PI controller.
+    pub struct GenPiCtrlModChGet: "GenPICtrl.ModChGet" {} -> GenPiCtrlModChGetResponse {
+        /// Output index (1 to number of available outputs)
+        pub output_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `GenPICtrl.DemodChSet`: set the index of the signal demodulated by the Generic PI controller.
+    pub struct GenPiCtrlDemodChSet: "GenPICtrl.DemodChSet" {
+        /// Input index (0-127 for signals, -1 for no change)
+        pub input_index: i32 = "i",
+        /// AC mode setting
+        pub ac_mode: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenPICtrl.DemodChGet`: get the index of the signal demodulated by the Generic PI controller.
+    pub struct GenPiCtrlDemodChGet: "GenPICtrl.DemodChGet" {} -> GenPiCtrlDemodChGetResponse {
+        /// Input index (0-127 for signals, -1 for no change)
+        pub input_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `GenPICtrl.PropsSet`: set the properties of the Generic PI controller.
+    pub struct GenPiCtrlPropsSet: "GenPICtrl.PropsSet" {
+        /// Setpoint value
+        pub setpoint: f32 = "f",
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant
+        pub time_constant: f32 = "f",
+        /// Slope direction
+        pub slope: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenPICtrl.PropsGet`: get the properties of the Generic PI controller.
+    pub struct GenPiCtrlPropsGet: "GenPICtrl.PropsGet" {} -> GenPiCtrlPropsGetResponse {
+        /// Setpoint value
+        pub setpoint: f32 = "f",
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant
+        pub time_constant: f32 = "f",
+        /// Slope direction
+        pub slope: u16 = "H",
+    }
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Slope direction for Generic PI controller.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -115,12 +116,7 @@ impl NanonisClient {
     /// ```
     pub fn gen_pi_ctrl_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
         let status = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "GenPICtrl.OnOffSet",
-            vec![NanonisValue::U32(status)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::GenPiCtrlOnOffSet { enabled: status })?;
         Ok(())
     }
 
@@ -132,13 +128,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("GenPICtrl.OnOffGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::GenPiCtrlOnOffGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.enabled != 0)
     }
 
     /// Set the output signal value of the User Output controlled by the Generic PI controller.
@@ -149,12 +141,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_ao_val_set(&mut self, output_value: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenPICtrl.AOValSet",
-            vec![NanonisValue::F32(output_value)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::GenPiCtrlAoValSet { output_value })?;
         Ok(())
     }
 
@@ -166,13 +153,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_ao_val_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("GenPICtrl.AOValGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::GenPiCtrlAoValGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.output_value)
     }
 
     /// Set the properties of the User Output controlled by the Generic PI controller.
@@ -183,19 +166,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_ao_props_set(&mut self, props: &AOProps) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenPICtrl.AOPropsSet",
-            vec![
-                NanonisValue::String(props.signal_name.clone()),
-                NanonisValue::String(props.units.clone()),
-                NanonisValue::F32(props.upper_limit),
-                NanonisValue::F32(props.lower_limit),
-                NanonisValue::F32(props.calibration_per_volt),
-                NanonisValue::F32(props.offset),
-            ],
-            vec!["+*c", "+*c", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::GenPiCtrlAoPropsSet {
+            signal_name: props.signal_name.clone(),
+            units: props.units.clone(),
+            upper_limit: props.upper_limit,
+            lower_limit: props.lower_limit,
+            calibration_per_volt: props.calibration_per_volt,
+            offset: props.offset,
+        })?;
         Ok(())
     }
 
@@ -207,25 +185,16 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_ao_props_get(&mut self) -> Result<AOProps, NanonisError> {
-        let result = self.quick_send(
-            "GenPICtrl.AOPropsGet",
-            vec![],
-            vec![],
-            vec!["i", "*-c", "i", "*-c", "f", "f", "f", "f"],
-        )?;
+        let response = self.send(&commands::GenPiCtrlAoPropsGet {})?;
 
-        if result.len() >= 8 {
-            Ok(AOProps {
-                signal_name: result[1].as_string()?.to_string(),
-                units: result[3].as_string()?.to_string(),
-                upper_limit: result[4].as_f32()?,
-                lower_limit: result[5].as_f32()?,
-                calibration_per_volt: result[6].as_f32()?,
-                offset: result[7].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(AOProps {
+            signal_name: response.signal_name,
+            units: response.units,
+            upper_limit: response.upper_limit,
+            lower_limit: response.lower_limit,
+            calibration_per_volt: response.calibration_per_volt,
+            offset: response.offset,
+        })
     }
 
     /// Set the index of the User Output controlled by the Generic PI controller.
@@ -236,12 +205,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_mod_ch_set(&mut self, output_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenPICtrl.ModChSet",
-            vec![NanonisValue::I32(output_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::GenPiCtrlModChSet { output_index })?;
         Ok(())
     }
 
@@ -253,13 +217,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_mod_ch_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("GenPICtrl.ModChGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::GenPiCtrlModChGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.output_index)
     }
 
     /// Set the index of the signal demodulated by the Generic PI controller.
@@ -275,15 +235,7 @@ impl NanonisClient {
         input_index: i32,
         ac_mode: ACMode,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenPICtrl.DemodChSet",
-            vec![
-                NanonisValue::I32(input_index),
-                NanonisValue::U16(ac_mode.into()),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::GenPiCtrlDemodChSet { input_index, ac_mode: ac_mode.into() })?;
         Ok(())
     }
 
@@ -295,13 +247,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_demod_ch_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("GenPICtrl.DemodChGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::GenPiCtrlDemodChGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.input_index)
     }
 
     /// Set the properties of the Generic PI controller.
@@ -328,17 +276,12 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn gen_pi_ctrl_props_set(&mut self, props: &GenPICtrlProps) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenPICtrl.PropsSet",
-            vec![
-                NanonisValue::F32(props.setpoint),
-                NanonisValue::F32(props.p_gain),
-                NanonisValue::F32(props.time_constant),
-                NanonisValue::U16(props.slope.into()),
-            ],
-            vec!["f", "f", "f", "H"],
-            vec![],
-        )?;
+        self.send(&commands::GenPiCtrlPropsSet {
+            setpoint: props.setpoint,
+            p_gain: props.p_gain,
+            time_constant: props.time_constant,
+            slope: props.slope.into(),
+        })?;
         Ok(())
     }
 
@@ -350,18 +293,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_pi_ctrl_props_get(&mut self) -> Result<GenPICtrlProps, NanonisError> {
-        let result =
-            self.quick_send("GenPICtrl.PropsGet", vec![], vec![], vec!["f", "f", "f", "H"])?;
+        let response = self.send(&commands::GenPiCtrlPropsGet {})?;
 
-        if result.len() >= 4 {
-            Ok(GenPICtrlProps {
-                setpoint: result[0].as_f32()?,
-                p_gain: result[1].as_f32()?,
-                time_constant: result[2].as_f32()?,
-                slope: result[3].as_u16()?.try_into()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(GenPICtrlProps {
+            setpoint: response.setpoint,
+            p_gain: response.p_gain,
+            time_constant: response.time_constant,
+            slope: response.slope.try_into()?,
+        })
     }
 }
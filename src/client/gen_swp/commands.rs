@@ -0,0 +1,159 @@
+//! Typed descriptors for the `GenSwp.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `GenSwp.Open`: open the Generic Sweeper module.
+    pub struct GenSwpOpen: "GenSwp.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `GenSwp.AcqChsSet`: set the list of recorded channels for the Generic Sweeper.
+    pub struct GenSwpAcqChsSet: "GenSwp.AcqChsSet" {
+        /// Indexes of channels to record
+        pub channel_indexes: Vec<i32> = "+*i",
+        /// Names of channels to record
+        pub channel_names: Vec<String> = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenSwp.AcqChsGet`: get the list of recorded channels for the Generic Sweeper.
+    pub struct GenSwpAcqChsGet: "GenSwp.AcqChsGet" {} -> GenSwpAcqChsGetResponse {
+        /// Number of indexes
+        pub num_indexes: i32 = "i",
+        /// Indexes of the recorded channels
+        pub indexes: Vec<i32> = "*i",
+        /// Size of the names array in bytes
+        pub names_size: i32 = "i",
+        /// Number of names
+        pub num_names: i32 = "i",
+        /// Names of the recorded channels
+        pub names: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `GenSwp.SwpSignalSet`: set the sweep signal for the Generic Sweeper.
+    pub struct GenSwpSwpSignalSet: "GenSwp.SwpSignalSet" {
+        /// Name of the signal to sweep
+        pub signal_name: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenSwp.SwpSignalGet`: get the selected sweep signal for the Generic Sweeper.
+    pub struct GenSwpSwpSignalGet: "GenSwp.SwpSignalGet" {} -> GenSwpSwpSignalGetResponse {
+        /// Length of the signal name in bytes
+        pub signal_name_size: i32 = "i",
+        /// Name of the signal to sweep
+        pub signal_name: String = "*-c",
+    }
+}
+
+nanonis_command! {
+    /// `GenSwp.SwpSignalListGet`: get the list of available sweep signals for the Generic Sweeper.
+    pub struct GenSwpSwpSignalListGet: "GenSwp.SwpSignalListGet" {} -> GenSwpSwpSignalListGetResponse {
+        /// Size of the signal names array in bytes
+        pub signal_names_size: i32 = "i",
+        /// Number of signal names
+        pub num_signal_names: i32 = "i",
+        /// Names of the available sweep signals
+        pub signal_names: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `GenSwp.LimitsSet`: set the sweep limits for the Generic Sweeper.
+    pub struct GenSwpLimitsSet: "GenSwp.LimitsSet" {
+        /// Lower limit of sweep range
+        pub lower_limit: f32 = "f",
+        /// Upper limit of sweep range
+        pub upper_limit: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenSwp.LimitsGet`: get the sweep limits for the Generic Sweeper.
+    pub struct GenSwpLimitsGet: "GenSwp.LimitsGet" {} -> GenSwpLimitsGetResponse {
+        /// Lower limit of sweep range
+        pub lower_limit: f32 = "f",
+        /// Upper limit of sweep range
+        pub upper_limit: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `GenSwp.PropsSet`: set the Generic Sweeper properties.
+    pub struct GenSwpPropsSet: "GenSwp.PropsSet" {
+        /// Initial settling time in milliseconds
+        pub initial_settling_time_ms: f32 = "f",
+        /// Maximum slew rate in units/s
+        pub max_slew_rate: f32 = "f",
+        /// Number of sweep steps
+        pub num_steps: i32 = "i",
+        /// Period in milliseconds
+        pub period_ms: u16 = "H",
+        /// 0 = no change, 1 = on, 2 = off
+        pub autosave_flag: i32 = "i",
+        /// 0 = no change, 1 = on, 2 = off
+        pub dialog_flag: i32 = "i",
+        /// Settling time in milliseconds
+        pub settling_time_ms: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `GenSwp.PropsGet`: get the Generic Sweeper properties.
+    pub struct GenSwpPropsGet: "GenSwp.PropsGet" {} -> GenSwpPropsGetResponse {
+        /// Initial settling time in milliseconds
+        pub initial_settling_time_ms: f32 = "f",
+        /// Maximum slew rate in units/s
+        pub max_slew_rate: f32 = "f",
+        /// Number of sweep steps
+        pub num_steps: i32 = "i",
+        /// Period in milliseconds
+        pub period_ms: u16 = "H",
+        /// Autosave enabled
+        pub autosave: u32 = "I",
+        /// Show save dialog
+        pub save_dialog: u32 = "I",
+        /// Settling time in milliseconds
+        pub settling_time_ms: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `GenSwp.Start`: start a sweep in the Generic Sweeper.
+    pub struct GenSwpStart: "GenSwp.Start" blocking if get_data {
+        /// If true, returns measurement data
+        pub get_data: u32 = "I",
+        /// `true` = lower to upper, `false` = upper to lower
+        pub sweep_direction: u32 = "I",
+        /// Base filename for saving (empty for no change)
+        pub save_base_name: String = "+*c",
+        /// Reset signal after sweep
+        pub reset_signal: u32 = "I",
+        /// Z-controller behavior: 0=no change, 1=turn off, 2=don't turn off
+        pub z_controller: u16 = "H",
+    } -> GenSwpStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Names of recorded channels
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// Sweep data, one row per channel
+        pub data_2d: Array2D<f32> = "2f",
+    }
+}
+
+nanonis_command! {
+    /// `GenSwp.Stop`: stop the sweep in the Generic Sweeper.
+    pub struct GenSwpStop: "GenSwp.Stop" {} -> ()
+}
@@ -1,6 +1,8 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 
 /// Generic sweeper properties configuration.
 #[derive(Debug, Clone)]
@@ -41,7 +43,7 @@ pub struct GenSwpResult {
     /// Names of recorded channels
     pub channel_names: Vec<String>,
     /// 2D data array `[rows][columns]`
-    pub data: Vec<Vec<f32>>,
+    pub data: Array2D<f32>,
 }
 
 impl NanonisClient {
@@ -59,7 +61,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn gen_swp_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("GenSwp.Open", vec![], vec![], vec![])?;
+        self.send(&commands::GenSwpOpen {})?;
         Ok(())
     }
 
@@ -76,15 +78,10 @@ impl NanonisClient {
         channel_indexes: &[i32],
         channel_names: &[String],
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenSwp.AcqChsSet",
-            vec![
-                NanonisValue::ArrayI32(channel_indexes.to_vec()),
-                NanonisValue::ArrayString(channel_names.to_vec()),
-            ],
-            vec!["+*i", "*+c"],
-            vec![],
-        )?;
+        self.send(&commands::GenSwpAcqChsSet {
+            channel_indexes: channel_indexes.to_vec(),
+            channel_names: channel_names.to_vec(),
+        })?;
         Ok(())
     }
 
@@ -96,20 +93,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_swp_acq_chs_get(&mut self) -> Result<(Vec<i32>, Vec<String>), NanonisError> {
-        let result = self.quick_send(
-            "GenSwp.AcqChsGet",
-            vec![],
-            vec![],
-            vec!["i", "*i", "i", "i", "*+c"],
-        )?;
+        let response = self.send(&commands::GenSwpAcqChsGet {})?;
 
-        if result.len() >= 5 {
-            let indexes = result[1].as_i32_array()?.to_vec();
-            let names = result[4].as_string_array()?.to_vec();
-            Ok((indexes, names))
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok((response.indexes, response.names))
     }
 
     /// Set the sweep signal for the Generic Sweeper.
@@ -129,12 +115,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn gen_swp_swp_signal_set(&mut self, signal_name: &str) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenSwp.SwpSignalSet",
-            vec![NanonisValue::String(signal_name.to_string())],
-            vec!["+*c"],
-            vec![],
-        )?;
+        self.send(&commands::GenSwpSwpSignalSet { signal_name: signal_name.to_string() })?;
         Ok(())
     }
 
@@ -146,13 +127,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_swp_swp_signal_get(&mut self) -> Result<String, NanonisError> {
-        let result = self.quick_send("GenSwp.SwpSignalGet", vec![], vec![], vec!["i", "*-c"])?;
+        let response = self.send(&commands::GenSwpSwpSignalGet {})?;
 
-        if result.len() >= 2 {
-            Ok(result[1].as_string()?.to_string())
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.signal_name)
     }
 
     /// Get the list of available sweep signals for the Generic Sweeper.
@@ -163,14 +140,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_swp_swp_signal_list_get(&mut self) -> Result<Vec<String>, NanonisError> {
-        let result =
-            self.quick_send("GenSwp.SwpSignalListGet", vec![], vec![], vec!["i", "i", "*+c"])?;
+        let response = self.send(&commands::GenSwpSwpSignalListGet {})?;
 
-        if result.len() >= 3 {
-            Ok(result[2].as_string_array()?.to_vec())
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.signal_names)
     }
 
     /// Set the sweep limits for the Generic Sweeper.
@@ -186,15 +158,7 @@ impl NanonisClient {
         lower_limit: f32,
         upper_limit: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "GenSwp.LimitsSet",
-            vec![
-                NanonisValue::F32(lower_limit),
-                NanonisValue::F32(upper_limit),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::GenSwpLimitsSet { lower_limit, upper_limit })?;
         Ok(())
     }
 
@@ -206,13 +170,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_swp_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result = self.quick_send("GenSwp.LimitsGet", vec![], vec![], vec!["f", "f"])?;
+        let response = self.send(&commands::GenSwpLimitsGet {})?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok((response.lower_limit, response.upper_limit))
     }
 
     /// Set the Generic Sweeper properties.
@@ -241,20 +201,15 @@ impl NanonisClient {
         let autosave_flag = if props.autosave { 1i32 } else { 2i32 };
         let dialog_flag = if props.save_dialog { 1i32 } else { 2i32 };
 
-        self.quick_send(
-            "GenSwp.PropsSet",
-            vec![
-                NanonisValue::F32(props.initial_settling_time_ms),
-                NanonisValue::F32(props.max_slew_rate),
-                NanonisValue::I32(props.num_steps),
-                NanonisValue::U16(props.period_ms),
-                NanonisValue::I32(autosave_flag),
-                NanonisValue::I32(dialog_flag),
-                NanonisValue::F32(props.settling_time_ms),
-            ],
-            vec!["f", "f", "i", "H", "i", "i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::GenSwpPropsSet {
+            initial_settling_time_ms: props.initial_settling_time_ms,
+            max_slew_rate: props.max_slew_rate,
+            num_steps: props.num_steps,
+            period_ms: props.period_ms,
+            autosave_flag,
+            dialog_flag,
+            settling_time_ms: props.settling_time_ms,
+        })?;
         Ok(())
     }
 
@@ -266,26 +221,17 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_swp_props_get(&mut self) -> Result<GenSwpProps, NanonisError> {
-        let result = self.quick_send(
-            "GenSwp.PropsGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "i", "H", "I", "I", "f"],
-        )?;
+        let response = self.send(&commands::GenSwpPropsGet {})?;
 
-        if result.len() >= 7 {
-            Ok(GenSwpProps {
-                initial_settling_time_ms: result[0].as_f32()?,
-                max_slew_rate: result[1].as_f32()?,
-                num_steps: result[2].as_i32()?,
-                period_ms: result[3].as_u16()?,
-                autosave: result[4].as_u32()? != 0,
-                save_dialog: result[5].as_u32()? != 0,
-                settling_time_ms: result[6].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(GenSwpProps {
+            initial_settling_time_ms: response.initial_settling_time_ms,
+            max_slew_rate: response.max_slew_rate,
+            num_steps: response.num_steps,
+            period_ms: response.period_ms,
+            autosave: response.autosave != 0,
+            save_dialog: response.save_dialog != 0,
+            settling_time_ms: response.settling_time_ms,
+        })
     }
 
     /// Start a sweep in the Generic Sweeper.
@@ -324,44 +270,21 @@ impl NanonisClient {
         let direction_flag = if sweep_direction { 1u32 } else { 0u32 };
         let reset_flag = if reset_signal { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "GenSwp.Start",
-            vec![
-                NanonisValue::U32(get_data_flag),
-                NanonisValue::U32(direction_flag),
-                NanonisValue::String(save_base_name.to_string()),
-                NanonisValue::U32(reset_flag),
-                NanonisValue::U16(z_controller),
-            ],
-            vec!["I", "I", "+*c", "I", "H"],
-            vec!["i", "i", "*+c", "i", "i", "2f"],
-        )?;
+        let response = self.send(&commands::GenSwpStart {
+            get_data: get_data_flag,
+            sweep_direction: direction_flag,
+            save_base_name: save_base_name.to_string(),
+            reset_signal: reset_flag,
+            z_controller,
+        })?;
 
-        if result.len() >= 6 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let rows = result[3].as_i32()? as usize;
-            let cols = result[4].as_i32()? as usize;
+        let channel_names = response.channel_names;
+        let data_2d = response.data_2d;
 
-            let flat_data = result[5].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                if end_idx <= flat_data.len() {
-                    data_2d.push(flat_data[start_idx..end_idx].to_vec());
-                }
-            }
-
-            Ok(GenSwpResult {
-                channel_names,
-                data: data_2d,
-            })
-        } else {
-            Ok(GenSwpResult {
-                channel_names: vec![],
-                data: vec![],
-            })
-        }
+        Ok(GenSwpResult {
+            channel_names,
+            data: data_2d,
+        })
     }
 
     /// Stop the sweep in the Generic Sweeper.
@@ -369,7 +292,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn gen_swp_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("GenSwp.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::GenSwpStop {})?;
         Ok(())
     }
 }
@@ -0,0 +1,378 @@
+//! Typed descriptors for the `HSSwp.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `HSSwp.AcqChsSet`: set the acquisition channels for the high-speed sweeper.
+    pub struct HsSwpAcqChsSet: "HSSwp.AcqChsSet" {
+        /// Indices of channels to record
+        pub channel_indices: Vec<i32> = "+*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.AcqChsGet`: get the acquisition channels for the high-speed sweeper.
+    pub struct HsSwpAcqChsGet: "HSSwp.AcqChsGet" {} -> HsSwpAcqChsGetResponse {
+        /// Number of selected indices
+        pub num_selected_indices: i32 = "i",
+        /// Currently selected channel indices
+        pub selected_indices: Vec<u32> = "*I",
+        /// Size of the available names array in bytes
+        pub available_names_size: i32 = "i",
+        /// Number of available names
+        pub num_available_names: i32 = "i",
+        /// Names of available channels
+        pub available_names: Vec<String> = "*+c",
+        /// Number of available indices
+        pub num_available_indices: i32 = "i",
+        /// Indices of available channels
+        pub available_indices: Vec<i32> = "*i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.AutoReverseSet`: set the auto-reverse configuration.
+    pub struct HsSwpAutoReverseSet: "HSSwp.AutoReverseSet" {
+        /// Enable auto-reverse
+        pub enabled: i32 = "i",
+        /// First condition comparison type
+        pub condition: i32 = "i",
+        /// First condition signal index
+        pub signal_index: i32 = "i",
+        /// First condition threshold
+        pub threshold: f32 = "f",
+        /// Linkage to second condition
+        pub linkage: i32 = "i",
+        /// Second condition comparison type
+        pub condition2: i32 = "i",
+        /// Second condition signal index
+        pub signal2_index: i32 = "i",
+        /// Second condition threshold
+        pub threshold2: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.AutoReverseGet`: get the auto-reverse configuration.
+    pub struct HsSwpAutoReverseGet: "HSSwp.AutoReverseGet" {} -> HsSwpAutoReverseGetResponse {
+        /// Enable auto-reverse
+        pub enabled: i32 = "i",
+        /// First condition comparison type
+        pub condition: i32 = "i",
+        /// First condition signal index
+        pub signal_index: i32 = "i",
+        /// First condition threshold
+        pub threshold: f32 = "f",
+        /// Linkage to second condition
+        pub linkage: i32 = "i",
+        /// Second condition comparison type
+        pub condition2: i32 = "i",
+        /// Second condition signal index
+        pub signal2_index: i32 = "i",
+        /// Second condition threshold
+        pub threshold2: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.EndSettlSet`: set the end settling time.
+    pub struct HsSwpEndSettlSet: "HSSwp.EndSettlSet" {
+        /// End settling time
+        pub time_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.EndSettlGet`: get the end settling time.
+    pub struct HsSwpEndSettlGet: "HSSwp.EndSettlGet" {} -> HsSwpEndSettlGetResponse {
+        /// End settling time
+        pub time_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.NumSweepsSet`: set the number of sweeps.
+    pub struct HsSwpNumSweepsSet: "HSSwp.NumSweepsSet" {
+        /// Number of sweeps (ignored if continuous)
+        pub num_sweeps: u32 = "I",
+        /// Enable continuous sweep mode
+        pub continuous: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.NumSweepsGet`: get the number of sweeps.
+    pub struct HsSwpNumSweepsGet: "HSSwp.NumSweepsGet" {} -> HsSwpNumSweepsGetResponse {
+        /// Number of sweeps (ignored if continuous)
+        pub num_sweeps: u32 = "I",
+        /// Enable continuous sweep mode
+        pub continuous: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.ResetSignalsSet`: set whether signals are reset at sweep end.
+    pub struct HsSwpResetSignalsSet: "HSSwp.ResetSignalsSet" {
+        /// True to reset signals at sweep end
+        pub reset: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.ResetSignalsGet`: get whether signals are reset at sweep end.
+    pub struct HsSwpResetSignalsGet: "HSSwp.ResetSignalsGet" {} -> HsSwpResetSignalsGetResponse {
+        /// True to reset signals at sweep end
+        pub reset: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SaveBasenameSet`: set the save basename and path.
+    pub struct HsSwpSaveBasenameSet: "HSSwp.SaveBasenameSet" {
+        /// Base name for saved files
+        pub basename: String = "+*c",
+        /// Directory path for saved files
+        pub path: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SaveBasenameGet`: get the save basename and path.
+    pub struct HsSwpSaveBasenameGet: "HSSwp.SaveBasenameGet" {} -> HsSwpSaveBasenameGetResponse {
+        /// Length of the basename in bytes
+        pub basename_size: i32 = "i",
+        /// Base name for saved files
+        pub basename: String = "*-c",
+        /// Length of the path in bytes
+        pub path_size: i32 = "i",
+        /// Directory path for saved files
+        pub path: String = "*-c",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SaveDataSet`: set whether data is saved.
+    pub struct HsSwpSaveDataSet: "HSSwp.SaveDataSet" {
+        /// True to save data
+        pub save: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SaveDataGet`: get whether data is saved.
+    pub struct HsSwpSaveDataGet: "HSSwp.SaveDataGet" {} -> HsSwpSaveDataGetResponse {
+        /// True to save data
+        pub save: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SaveOptionsSet`: set save options.
+    pub struct HsSwpSaveOptionsSet: "HSSwp.SaveOptionsSet" {
+        /// Comment for file header
+        pub comment: String = "+*c",
+        /// Module names for parameters to save
+        pub modules: Vec<String> = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SaveOptionsGet`: get save options.
+    pub struct HsSwpSaveOptionsGet: "HSSwp.SaveOptionsGet" {} -> HsSwpSaveOptionsGetResponse {
+        /// Length of the comment in bytes
+        pub comment_size: i32 = "i",
+        /// Comment for file header
+        pub comment: String = "*-c",
+        /// Size of the modules array in bytes
+        pub modules_size: i32 = "i",
+        /// Number of modules
+        pub num_modules: i32 = "i",
+        /// Module names for parameters to save
+        pub modules: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.Start`: start a high-speed sweep.
+    pub struct HsSwpStart: "HSSwp.Start" blocking if wait_until_done {
+        /// Wait for sweep to complete before returning
+        pub wait_until_done: i32 = "i",
+        /// Timeout in milliseconds (-1 for indefinite)
+        pub timeout_ms: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.Stop`: stop the high-speed sweep.
+    pub struct HsSwpStop: "HSSwp.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.StatusGet`: get the sweep status.
+    pub struct HsSwpStatusGet: "HSSwp.StatusGet" {} -> HsSwpStatusGetResponse {
+        /// 1 if a sweep is running
+        pub running: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChSigListGet`: get the list of available sweep signals.
+    pub struct HsSwpSwpChSigListGet: "HSSwp.SwpChSigListGet" {} -> HsSwpSwpChSigListGetResponse {
+        /// Signal names
+        pub names: Vec<String> = "+*c",
+        /// Signal indices
+        pub indices: Vec<i32> = "+*i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChSignalSet`: set the sweep channel signal.
+    pub struct HsSwpSwpChSignalSet: "HSSwp.SwpChSignalSet" {
+        /// First condition signal index
+        pub signal_index: i32 = "i",
+        /// Use timed sweep mode (ignores signal)
+        pub timed_sweep: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChSignalGet`: get the sweep channel signal.
+    pub struct HsSwpSwpChSignalGet: "HSSwp.SwpChSignalGet" {} -> HsSwpSwpChSignalGetResponse {
+        /// First condition signal index
+        pub signal_index: i32 = "i",
+        /// Use timed sweep mode (ignores signal)
+        pub timed_sweep: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChLimitsSet`: set the sweep channel limits.
+    pub struct HsSwpSwpChLimitsSet: "HSSwp.SwpChLimitsSet" {
+        /// Use relative limits (vs absolute)
+        pub relative: i32 = "i",
+        /// Start value
+        pub start: f32 = "f",
+        /// Stop value
+        pub stop: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChLimitsGet`: get the sweep channel limits.
+    pub struct HsSwpSwpChLimitsGet: "HSSwp.SwpChLimitsGet" {} -> HsSwpSwpChLimitsGetResponse {
+        /// Use relative limits (vs absolute)
+        pub relative: i32 = "i",
+        /// Start value
+        pub start: f32 = "f",
+        /// Stop value
+        pub stop: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChNumPtsSet`: set the number of sweep points.
+    pub struct HsSwpSwpChNumPtsSet: "HSSwp.SwpChNumPtsSet" {
+        /// Number of points in sweep
+        pub num_points: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChNumPtsGet`: get the number of sweep points.
+    pub struct HsSwpSwpChNumPtsGet: "HSSwp.SwpChNumPtsGet" {} -> HsSwpSwpChNumPtsGetResponse {
+        /// Number of points
+        pub num_points: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChTimingSet`: set the sweep timing parameters.
+    pub struct HsSwpSwpChTimingSet: "HSSwp.SwpChTimingSet" {
+        /// Initial settling time in seconds
+        pub initial_settling_s: f32 = "f",
+        /// Settling time in seconds
+        pub settling_s: f32 = "f",
+        /// Integration time in seconds
+        pub integration_s: f32 = "f",
+        /// Maximum slew rate in units/s
+        pub max_slew_rate: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChTimingGet`: get the sweep timing parameters.
+    pub struct HsSwpSwpChTimingGet: "HSSwp.SwpChTimingGet" {} -> HsSwpSwpChTimingGetResponse {
+        /// Initial settling time in seconds
+        pub initial_settling_s: f32 = "f",
+        /// Settling time in seconds
+        pub settling_s: f32 = "f",
+        /// Integration time in seconds
+        pub integration_s: f32 = "f",
+        /// Maximum slew rate in units/s
+        pub max_slew_rate: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChBwdSwSet`: set whether backward sweep is enabled.
+    pub struct HsSwpSwpChBwdSwSet: "HSSwp.SwpChBwdSwSet" {
+        /// Enable backward sweep
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChBwdSwGet`: get whether backward sweep is enabled.
+    pub struct HsSwpSwpChBwdSwGet: "HSSwp.SwpChBwdSwGet" {} -> HsSwpSwpChBwdSwGetResponse {
+        /// Enable backward sweep
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChBwdDelaySet`: set the backward sweep delay.
+    pub struct HsSwpSwpChBwdDelaySet: "HSSwp.SwpChBwdDelaySet" {
+        /// Delay between forward and backward sweep
+        pub delay_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.SwpChBwdDelayGet`: get the backward sweep delay.
+    pub struct HsSwpSwpChBwdDelayGet: "HSSwp.SwpChBwdDelayGet" {} -> HsSwpSwpChBwdDelayGetResponse {
+        /// Delay between forward and backward sweep
+        pub delay_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `HSSwp.ZCtrlOffSet`: set the Z-controller behavior during sweep.
+    pub struct HsSwpZCtrlOffSet: "HSSwp.ZCtrlOffSet" {
+        /// Whether to switch off Z-controller during sweep
+        pub switch_off: i32 = "i",
+        /// Z-controller index (1 = tip 1, 2-4 for multiprobe)
+        pub controller_index: i32 = "i",
+        /// Time to average Z position before switch-off
+        pub averaging_time_s: f32 = "f",
+        /// Z offset for tip retraction
+        pub z_offset_m: f32 = "f",
+        /// Time to wait after switching back on
+        pub control_time_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `HSSwp.ZCtrlOffGet`: get the Z-controller behavior during sweep.
+    pub struct HsSwpZCtrlOffGet: "HSSwp.ZCtrlOffGet" {} -> HsSwpZCtrlOffGetResponse {
+        /// Whether to switch off Z-controller during sweep
+        pub switch_off: i32 = "i",
+        /// Z-controller index (1 = tip 1, 2-4 for multiprobe)
+        pub controller_index: i32 = "i",
+        /// Time to average Z position before switch-off
+        pub averaging_time_s: f32 = "f",
+        /// Z offset for tip retraction
+        pub z_offset_m: f32 = "f",
+        /// Time to wait after switching back on
+        pub control_time_s: f32 = "f",
+    }
+}
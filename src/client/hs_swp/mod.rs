@@ -1,6 +1,9 @@
+pub mod commands;
+
+use super::signals::SignalRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::units::Seconds;
 
 /// Comparison condition for auto-reverse.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -168,12 +171,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_acq_chs_set(&mut self, channel_indices: &[i32]) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.AcqChsSet",
-            vec![NanonisValue::ArrayI32(channel_indices.to_vec())],
-            vec!["+*i"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpAcqChsSet { channel_indices: channel_indices.to_vec() })?;
         Ok(())
     }
 
@@ -185,22 +183,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_acq_chs_get(&mut self) -> Result<HSSwpAvailableChannels, NanonisError> {
-        let result = self.quick_send(
-            "HSSwp.AcqChsGet",
-            vec![],
-            vec![],
-            vec!["i", "*I", "i", "i", "*+c", "i", "*i"],
-        )?;
-
-        if result.len() >= 7 {
-            Ok(HSSwpAvailableChannels {
-                selected_indices: result[1].as_u32_array()?.to_vec(),
-                available_names: result[4].as_string_array()?.to_vec(),
-                available_indices: result[6].as_i32_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpAcqChsGet {})?;
+
+        Ok(HSSwpAvailableChannels {
+            selected_indices: response.selected_indices,
+            available_names: response.available_names,
+            available_indices: response.available_indices,
+        })
     }
 
     /// Set the auto-reverse configuration.
@@ -215,21 +204,16 @@ impl NanonisClient {
         config: &HSSwpAutoReverse,
     ) -> Result<(), NanonisError> {
         let on_off = if config.enabled { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.AutoReverseSet",
-            vec![
-                NanonisValue::I32(on_off),
-                NanonisValue::I32(config.condition.into()),
-                NanonisValue::I32(config.signal_index),
-                NanonisValue::F32(config.threshold),
-                NanonisValue::I32(config.linkage.into()),
-                NanonisValue::I32(config.condition2.into()),
-                NanonisValue::I32(config.signal2_index),
-                NanonisValue::F32(config.threshold2),
-            ],
-            vec!["i", "i", "i", "f", "i", "i", "i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpAutoReverseSet {
+            enabled: on_off,
+            condition: config.condition.into(),
+            signal_index: config.signal_index,
+            threshold: config.threshold,
+            linkage: config.linkage.into(),
+            condition2: config.condition2.into(),
+            signal2_index: config.signal2_index,
+            threshold2: config.threshold2,
+        })?;
         Ok(())
     }
 
@@ -241,43 +225,29 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_auto_reverse_get(&mut self) -> Result<HSSwpAutoReverse, NanonisError> {
-        let result = self.quick_send(
-            "HSSwp.AutoReverseGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "i", "f", "i", "i", "i", "f"],
-        )?;
-
-        if result.len() >= 8 {
-            Ok(HSSwpAutoReverse {
-                enabled: result[0].as_i32()? != 0,
-                condition: result[1].as_i32()?.try_into()?,
-                signal_index: result[2].as_i32()?,
-                threshold: result[3].as_f32()?,
-                linkage: result[4].as_i32()?.try_into()?,
-                condition2: result[5].as_i32()?.try_into()?,
-                signal2_index: result[6].as_i32()?,
-                threshold2: result[7].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpAutoReverseGet {})?;
+
+        Ok(HSSwpAutoReverse {
+            enabled: response.enabled != 0,
+            condition: response.condition.try_into()?,
+            signal_index: response.signal_index,
+            threshold: response.threshold,
+            linkage: response.linkage.try_into()?,
+            condition2: response.condition2.try_into()?,
+            signal2_index: response.signal2_index,
+            threshold2: response.threshold2,
+        })
     }
 
     /// Set the end settling time.
     ///
     /// # Arguments
-    /// * `time_s` - End settling time in seconds
+    /// * `time_s` - End settling time
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails.
-    pub fn hs_swp_end_settl_set(&mut self, time_s: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.EndSettlSet",
-            vec![NanonisValue::F32(time_s)],
-            vec!["f"],
-            vec![],
-        )?;
+    pub fn hs_swp_end_settl_set(&mut self, time_s: impl Into<Seconds>) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpEndSettlSet { time_s: time_s.into().as_f32() })?;
         Ok(())
     }
 
@@ -289,13 +259,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_end_settl_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("HSSwp.EndSettlGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::HsSwpEndSettlGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.time_s)
     }
 
     /// Set the number of sweeps.
@@ -312,12 +278,7 @@ impl NanonisClient {
         continuous: bool,
     ) -> Result<(), NanonisError> {
         let cont_flag = if continuous { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.NumSweepsSet",
-            vec![NanonisValue::U32(num_sweeps), NanonisValue::I32(cont_flag)],
-            vec!["I", "i"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpNumSweepsSet { num_sweeps, continuous: cont_flag })?;
         Ok(())
     }
 
@@ -329,13 +290,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_num_sweeps_get(&mut self) -> Result<(u32, bool), NanonisError> {
-        let result = self.quick_send("HSSwp.NumSweepsGet", vec![], vec![], vec!["I", "i"])?;
+        let response = self.send(&commands::HsSwpNumSweepsGet {})?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_u32()?, result[1].as_i32()? != 0))
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok((response.num_sweeps, response.continuous != 0))
     }
 
     /// Set whether signals are reset at sweep end.
@@ -347,12 +304,7 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_reset_signals_set(&mut self, reset: bool) -> Result<(), NanonisError> {
         let flag = if reset { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.ResetSignalsSet",
-            vec![NanonisValue::I32(flag)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpResetSignalsSet { reset: flag })?;
         Ok(())
     }
 
@@ -364,13 +316,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_reset_signals_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("HSSwp.ResetSignalsGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::HsSwpResetSignalsGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.reset != 0)
     }
 
     /// Set the save basename and path.
@@ -386,15 +334,10 @@ impl NanonisClient {
         basename: &str,
         path: &str,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.SaveBasenameSet",
-            vec![
-                NanonisValue::String(basename.to_string()),
-                NanonisValue::String(path.to_string()),
-            ],
-            vec!["+*c", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSaveBasenameSet {
+            basename: basename.to_string(),
+            path: path.to_string(),
+        })?;
         Ok(())
     }
 
@@ -406,17 +349,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_save_basename_get(&mut self) -> Result<(String, String), NanonisError> {
-        let result =
-            self.quick_send("HSSwp.SaveBasenameGet", vec![], vec![], vec!["i", "*-c", "*-c"])?;
-
-        if result.len() >= 3 {
-            Ok((
-                result[1].as_string()?.to_string(),
-                result[2].as_string()?.to_string(),
-            ))
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpSaveBasenameGet {})?;
+
+        Ok((
+            response.basename,
+            response.path,
+        ))
     }
 
     /// Set whether data is saved.
@@ -428,12 +366,7 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_save_data_set(&mut self, save: bool) -> Result<(), NanonisError> {
         let flag = if save { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.SaveDataSet",
-            vec![NanonisValue::I32(flag)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSaveDataSet { save: flag })?;
         Ok(())
     }
 
@@ -445,13 +378,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_save_data_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("HSSwp.SaveDataGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::HsSwpSaveDataGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.save != 0)
     }
 
     /// Set save options.
@@ -462,15 +391,10 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_save_options_set(&mut self, options: &HSSwpSaveOptions) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.SaveOptionsSet",
-            vec![
-                NanonisValue::String(options.comment.clone()),
-                NanonisValue::ArrayString(options.modules.clone()),
-            ],
-            vec!["+*c", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSaveOptionsSet {
+            comment: options.comment.clone(),
+            modules: options.modules.clone(),
+        })?;
         Ok(())
     }
 
@@ -482,21 +406,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_save_options_get(&mut self) -> Result<HSSwpSaveOptions, NanonisError> {
-        let result = self.quick_send(
-            "HSSwp.SaveOptionsGet",
-            vec![],
-            vec![],
-            vec!["i", "*-c", "i", "i", "*+c"],
-        )?;
-
-        if result.len() >= 5 {
-            Ok(HSSwpSaveOptions {
-                comment: result[1].as_string()?.to_string(),
-                modules: result[4].as_string_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpSaveOptionsGet {})?;
+
+        Ok(HSSwpSaveOptions {
+            comment: response.comment,
+            modules: response.modules,
+        })
     }
 
     /// Start a high-speed sweep.
@@ -509,12 +424,7 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_start(&mut self, wait_until_done: bool, timeout_ms: i32) -> Result<(), NanonisError> {
         let wait_flag = if wait_until_done { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.Start",
-            vec![NanonisValue::I32(wait_flag), NanonisValue::I32(timeout_ms)],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpStart { wait_until_done: wait_flag, timeout_ms })?;
         Ok(())
     }
 
@@ -523,7 +433,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("HSSwp.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::HsSwpStop {})?;
         Ok(())
     }
 
@@ -535,13 +445,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_status_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("HSSwp.StatusGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::HsSwpStatusGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.running != 0)
     }
 
     /// Get the list of available sweep signals.
@@ -552,39 +458,34 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_sig_list_get(&mut self) -> Result<HSSwpSignalList, NanonisError> {
-        let result =
-            self.quick_send("HSSwp.SwpChSigListGet", vec![], vec![], vec!["+*c", "+*i"])?;
-
-        if result.len() >= 2 {
-            Ok(HSSwpSignalList {
-                names: result[0].as_string_array()?.to_vec(),
-                indices: result[1].as_i32_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpSwpChSigListGet {})?;
+
+        Ok(HSSwpSignalList {
+            names: response.names,
+            indices: response.indices,
+        })
     }
 
     /// Set the sweep channel signal.
     ///
     /// # Arguments
-    /// * `signal_index` - Index of sweep signal
+    /// * `signal` - Sweep signal by index or name
     /// * `timed_sweep` - Use timed sweep mode (ignores signal)
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if the signal does not resolve or
+    /// communication fails.
     pub fn hs_swp_swp_ch_signal_set(
         &mut self,
-        signal_index: i32,
+        signal: impl Into<SignalRef>,
         timed_sweep: bool,
     ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal)?;
         let timed_flag = if timed_sweep { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.SwpChSignalSet",
-            vec![NanonisValue::I32(signal_index), NanonisValue::I32(timed_flag)],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSwpChSignalSet {
+            signal_index,
+            timed_sweep: timed_flag,
+        })?;
         Ok(())
     }
 
@@ -596,13 +497,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_signal_get(&mut self) -> Result<(i32, bool), NanonisError> {
-        let result = self.quick_send("HSSwp.SwpChSignalGet", vec![], vec![], vec!["i", "i"])?;
+        let response = self.send(&commands::HsSwpSwpChSignalGet {})?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_i32()?, result[1].as_i32()? != 0))
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok((response.signal_index, response.timed_sweep != 0))
     }
 
     /// Set the sweep channel limits.
@@ -614,16 +511,11 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_limits_set(&mut self, limits: &HSSwpLimits) -> Result<(), NanonisError> {
         let rel_flag = if limits.relative { 1i32 } else { 0i32 };
-        self.quick_send(
-            "HSSwp.SwpChLimitsSet",
-            vec![
-                NanonisValue::I32(rel_flag),
-                NanonisValue::F32(limits.start),
-                NanonisValue::F32(limits.stop),
-            ],
-            vec!["i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSwpChLimitsSet {
+            relative: rel_flag,
+            start: limits.start,
+            stop: limits.stop,
+        })?;
         Ok(())
     }
 
@@ -635,17 +527,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_limits_get(&mut self) -> Result<HSSwpLimits, NanonisError> {
-        let result = self.quick_send("HSSwp.SwpChLimitsGet", vec![], vec![], vec!["i", "f", "f"])?;
-
-        if result.len() >= 3 {
-            Ok(HSSwpLimits {
-                relative: result[0].as_i32()? != 0,
-                start: result[1].as_f32()?,
-                stop: result[2].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpSwpChLimitsGet {})?;
+
+        Ok(HSSwpLimits {
+            relative: response.relative != 0,
+            start: response.start,
+            stop: response.stop,
+        })
     }
 
     /// Set the number of sweep points.
@@ -656,12 +544,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_num_pts_set(&mut self, num_points: u32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.SwpChNumPtsSet",
-            vec![NanonisValue::U32(num_points)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSwpChNumPtsSet { num_points })?;
         Ok(())
     }
 
@@ -673,13 +556,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_num_pts_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("HSSwp.SwpChNumPtsGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::HsSwpSwpChNumPtsGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.num_points)
     }
 
     /// Set the sweep timing parameters.
@@ -690,17 +569,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_timing_set(&mut self, timing: &HSSwpTiming) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.SwpChTimingSet",
-            vec![
-                NanonisValue::F32(timing.initial_settling_s),
-                NanonisValue::F32(timing.settling_s),
-                NanonisValue::F32(timing.integration_s),
-                NanonisValue::F32(timing.max_slew_rate),
-            ],
-            vec!["f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSwpChTimingSet {
+            initial_settling_s: timing.initial_settling_s,
+            settling_s: timing.settling_s,
+            integration_s: timing.integration_s,
+            max_slew_rate: timing.max_slew_rate,
+        })?;
         Ok(())
     }
 
@@ -712,19 +586,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_timing_get(&mut self) -> Result<HSSwpTiming, NanonisError> {
-        let result =
-            self.quick_send("HSSwp.SwpChTimingGet", vec![], vec![], vec!["f", "f", "f", "f"])?;
-
-        if result.len() >= 4 {
-            Ok(HSSwpTiming {
-                initial_settling_s: result[0].as_f32()?,
-                settling_s: result[1].as_f32()?,
-                integration_s: result[2].as_f32()?,
-                max_slew_rate: result[3].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpSwpChTimingGet {})?;
+
+        Ok(HSSwpTiming {
+            initial_settling_s: response.initial_settling_s,
+            settling_s: response.settling_s,
+            integration_s: response.integration_s,
+            max_slew_rate: response.max_slew_rate,
+        })
     }
 
     /// Set whether backward sweep is enabled.
@@ -736,12 +605,7 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_bwd_sw_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "HSSwp.SwpChBwdSwSet",
-            vec![NanonisValue::U32(flag)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpSwpChBwdSwSet { enabled: flag })?;
         Ok(())
     }
 
@@ -753,29 +617,23 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_bwd_sw_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("HSSwp.SwpChBwdSwGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::HsSwpSwpChBwdSwGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.enabled != 0)
     }
 
     /// Set the backward sweep delay.
     ///
     /// # Arguments
-    /// * `delay_s` - Delay between forward and backward sweep in seconds
+    /// * `delay_s` - Delay between forward and backward sweep
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails.
-    pub fn hs_swp_swp_ch_bwd_delay_set(&mut self, delay_s: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "HSSwp.SwpChBwdDelaySet",
-            vec![NanonisValue::F32(delay_s)],
-            vec!["f"],
-            vec![],
-        )?;
+    pub fn hs_swp_swp_ch_bwd_delay_set(
+        &mut self,
+        delay_s: impl Into<Seconds>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::HsSwpSwpChBwdDelaySet { delay_s: delay_s.into().as_f32() })?;
         Ok(())
     }
 
@@ -787,13 +645,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_swp_ch_bwd_delay_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("HSSwp.SwpChBwdDelayGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::HsSwpSwpChBwdDelayGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.delay_s)
     }
 
     /// Set the Z-controller behavior during sweep.
@@ -805,18 +659,13 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_z_ctrl_off_set(&mut self, config: &HSSwpZCtrl) -> Result<(), NanonisError> {
         let switch_off = if config.switch_off { 0i32 } else { 1i32 }; // 0=switch off, 1=don't switch
-        self.quick_send(
-            "HSSwp.ZCtrlOffSet",
-            vec![
-                NanonisValue::I32(switch_off),
-                NanonisValue::I32(config.controller_index),
-                NanonisValue::F32(config.averaging_time_s),
-                NanonisValue::F32(config.z_offset_m),
-                NanonisValue::F32(config.control_time_s),
-            ],
-            vec!["i", "i", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::HsSwpZCtrlOffSet {
+            switch_off,
+            controller_index: config.controller_index,
+            averaging_time_s: config.averaging_time_s,
+            z_offset_m: config.z_offset_m,
+            control_time_s: config.control_time_s,
+        })?;
         Ok(())
     }
 
@@ -828,19 +677,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn hs_swp_z_ctrl_off_get(&mut self) -> Result<HSSwpZCtrl, NanonisError> {
-        let result =
-            self.quick_send("HSSwp.ZCtrlOffGet", vec![], vec![], vec!["i", "i", "f", "f", "f"])?;
-
-        if result.len() >= 5 {
-            Ok(HSSwpZCtrl {
-                switch_off: result[0].as_i32()? == 0, // 0=switch off, 1=don't switch
-                controller_index: result[1].as_i32()?,
-                averaging_time_s: result[2].as_f32()?,
-                z_offset_m: result[3].as_f32()?,
-                control_time_s: result[4].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::HsSwpZCtrlOffGet {})?;
+
+        Ok(HSSwpZCtrl {
+            switch_off: response.switch_off == 0, // 0=switch off, 1=don't switch
+            controller_index: response.controller_index,
+            averaging_time_s: response.averaging_time_s,
+            z_offset_m: response.z_offset_m,
+            control_time_s: response.control_time_s,
+        })
     }
 }
@@ -0,0 +1,82 @@
+//! Typed descriptors for the `Interf.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Interf.CtrlOnOffSet`: switch the interferometer controller on or off.
+    pub struct InterfCtrlOnOffSet: "Interf.CtrlOnOffSet" {
+        /// True to enable, false to disable
+        pub on: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Interf.CtrlOnOffGet`: get the status of the interferometer controller.
+    pub struct InterfCtrlOnOffGet: "Interf.CtrlOnOffGet" {} -> InterfCtrlOnOffGetResponse {
+        /// True to enable, false to disable
+        pub on: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Interf.CtrlPropsSet`: set the interferometer controller properties.
+    pub struct InterfCtrlPropsSet: "Interf.CtrlPropsSet" {
+        /// Integral gain
+        pub integral: f32 = "f",
+        /// Proportional gain
+        pub proportional: f32 = "f",
+        /// Sign (true = positive, false = negative)
+        pub positive_sign: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Interf.CtrlPropsGet`: get the interferometer controller properties.
+    pub struct InterfCtrlPropsGet: "Interf.CtrlPropsGet" {} -> InterfCtrlPropsGetResponse {
+        /// Integral gain
+        pub integral: f32 = "f",
+        /// Proportional gain
+        pub proportional: f32 = "f",
+        /// Sign (true = positive, false = negative)
+        pub positive_sign: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Interf.WPiezoSet`: set the W-piezo position.
+    pub struct InterfWPiezoSet: "Interf.WPiezoSet" {
+        /// W-piezo position value
+        pub w_piezo: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Interf.WPiezoGet`: get the W-piezo position.
+    pub struct InterfWPiezoGet: "Interf.WPiezoGet" {} -> InterfWPiezoGetResponse {
+        /// W-piezo position value
+        pub w_piezo: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Interf.ValGet`: get the interferometer value.
+    pub struct InterfValGet: "Interf.ValGet" {} -> InterfValGetResponse {
+        /// Interferometer value
+        pub value: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Interf.CtrlCalibrOpen`: open the calibration panel for the interferometer controller.
+    pub struct InterfCtrlCalibrOpen: "Interf.CtrlCalibrOpen" {} -> ()
+}
+
+nanonis_command! {
+    /// `Interf.CtrlReset`: reset the interferometer controller.
+    pub struct InterfCtrlReset: "Interf.CtrlReset" {} -> ()
+}
+
+nanonis_command! {
+    /// `Interf.CtrlNullDefl`: apply null deflection to the interferometer controller.
+    pub struct InterfCtrlNullDefl: "Interf.CtrlNullDefl" {} -> ()
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Interferometer controller properties.
 #[derive(Debug, Clone, Copy, Default)]
@@ -24,12 +25,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_on_off_set(&mut self, on: bool) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Interf.CtrlOnOffSet",
-            vec![NanonisValue::U32(if on { 1 } else { 0 })],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::InterfCtrlOnOffSet { on: if on { 1 } else { 0 } })?;
         Ok(())
     }
 
@@ -41,9 +37,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("Interf.CtrlOnOffGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::InterfCtrlOnOffGet {})?;
 
-        Ok(result[0].as_u32()? != 0)
+        Ok(response.on != 0)
     }
 
     /// Set the interferometer controller properties.
@@ -54,16 +50,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_props_set(&mut self, props: &InterfCtrlProps) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Interf.CtrlPropsSet",
-            vec![
-                NanonisValue::F32(props.integral),
-                NanonisValue::F32(props.proportional),
-                NanonisValue::U32(if props.positive_sign { 1 } else { 0 }),
-            ],
-            vec!["f", "f", "I"],
-            vec![],
-        )?;
+        self.send(&commands::InterfCtrlPropsSet {
+            integral: props.integral,
+            proportional: props.proportional,
+            positive_sign: if props.positive_sign { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -75,12 +66,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_props_get(&mut self) -> Result<InterfCtrlProps, NanonisError> {
-        let result = self.quick_send("Interf.CtrlPropsGet", vec![], vec![], vec!["f", "f", "I"])?;
+        let response = self.send(&commands::InterfCtrlPropsGet {})?;
 
         Ok(InterfCtrlProps {
-            integral: result[0].as_f32()?,
-            proportional: result[1].as_f32()?,
-            positive_sign: result[2].as_u32()? != 0,
+            integral: response.integral,
+            proportional: response.proportional,
+            positive_sign: response.positive_sign != 0,
         })
     }
 
@@ -94,12 +85,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_w_piezo_set(&mut self, w_piezo: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Interf.WPiezoSet",
-            vec![NanonisValue::F32(w_piezo)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::InterfWPiezoSet { w_piezo })?;
         Ok(())
     }
 
@@ -111,9 +97,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_w_piezo_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Interf.WPiezoGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::InterfWPiezoGet {})?;
 
-        result[0].as_f32()
+        Ok(response.w_piezo)
     }
 
     /// Get the interferometer value.
@@ -124,9 +110,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_val_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Interf.ValGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::InterfValGet {})?;
 
-        result[0].as_f32()
+        Ok(response.value)
     }
 
     /// Open the calibration panel for the interferometer controller.
@@ -134,7 +120,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_calibr_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Interf.CtrlCalibrOpen", vec![], vec![], vec![])?;
+        self.send(&commands::InterfCtrlCalibrOpen {})?;
         Ok(())
     }
 
@@ -143,7 +129,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_reset(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Interf.CtrlReset", vec![], vec![], vec![])?;
+        self.send(&commands::InterfCtrlReset {})?;
         Ok(())
     }
 
@@ -152,7 +138,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn interf_ctrl_null_defl(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Interf.CtrlNullDefl", vec![], vec![], vec![])?;
+        self.send(&commands::InterfCtrlNullDefl {})?;
         Ok(())
     }
 }
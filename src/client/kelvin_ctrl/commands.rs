@@ -0,0 +1,147 @@
+//! Typed descriptors for the `KelvinCtrl.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `KelvinCtrl.CtrlOnOffSet`: enable or disable the Kelvin controller.
+    pub struct KelvinCtrlCtrlOnOffSet: "KelvinCtrl.CtrlOnOffSet" {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.CtrlOnOffGet`: get the Kelvin controller on/off status.
+    pub struct KelvinCtrlCtrlOnOffGet: "KelvinCtrl.CtrlOnOffGet" {} -> KelvinCtrlCtrlOnOffGetResponse {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.SetpntSet`: set the Kelvin controller setpoint.
+    pub struct KelvinCtrlSetpntSet: "KelvinCtrl.SetpntSet" {
+        /// Setpoint value
+        pub setpoint: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.SetpntGet`: get the Kelvin controller setpoint.
+    pub struct KelvinCtrlSetpntGet: "KelvinCtrl.SetpntGet" {} -> KelvinCtrlSetpntGetResponse {
+        /// Setpoint value
+        pub setpoint: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.GainSet`: set the Kelvin controller gain parameters.
+    pub struct KelvinCtrlGainSet: "KelvinCtrl.GainSet" {
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant_s: f32 = "f",
+        /// Slope direction
+        pub slope: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.GainGet`: get the Kelvin controller gain parameters.
+    pub struct KelvinCtrlGainGet: "KelvinCtrl.GainGet" {} -> KelvinCtrlGainGetResponse {
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant_s: f32 = "f",
+        /// Slope direction
+        pub slope: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.ModParamsSet`: set the Kelvin controller modulation parameters.
+    pub struct KelvinCtrlModParamsSet: "KelvinCtrl.ModParamsSet" {
+        /// Modulation frequency in Hz
+        pub frequency_hz: f32 = "f",
+        /// Modulation amplitude
+        pub amplitude: f32 = "f",
+        /// Modulation phase in degrees
+        pub phase_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.ModParamsGet`: get the Kelvin controller modulation parameters.
+    pub struct KelvinCtrlModParamsGet: "KelvinCtrl.ModParamsGet" {} -> KelvinCtrlModParamsGetResponse {
+        /// Modulation frequency in Hz
+        pub frequency_hz: f32 = "f",
+        /// Modulation amplitude
+        pub amplitude: f32 = "f",
+        /// Modulation phase in degrees
+        pub phase_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.ModOnOffSet`: set the Kelvin controller AC mode and modulation status.
+    pub struct KelvinCtrlModOnOffSet: "KelvinCtrl.ModOnOffSet" {
+        /// AC mode setting
+        pub ac_mode: u16 = "H",
+        /// True to enable modulation, false to disable
+        pub modulation: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.ModOnOffGet`: get the Kelvin controller AC mode and modulation status.
+    pub struct KelvinCtrlModOnOffGet: "KelvinCtrl.ModOnOffGet" {} -> KelvinCtrlModOnOffGetResponse {
+        /// AC mode enabled
+        pub ac_mode: u16 = "H",
+        /// Modulation enabled
+        pub modulation: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.CtrlSignalSet`: set the Kelvin controller demodulated/control signal index.
+    pub struct KelvinCtrlCtrlSignalSet: "KelvinCtrl.CtrlSignalSet" {
+        /// Index of the demodulated signal used as control input
+        pub signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.CtrlSignalGet`: get the Kelvin controller demodulated/control signal index.
+    pub struct KelvinCtrlCtrlSignalGet: "KelvinCtrl.CtrlSignalGet" {} -> KelvinCtrlCtrlSignalGetResponse {
+        /// Index of the demodulated signal used as control input
+        pub signal_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.AmpGet`: get the amplitude of the demodulated/control signal.
+    pub struct KelvinCtrlAmpGet: "KelvinCtrl.AmpGet" {} -> KelvinCtrlAmpGetResponse {
+        /// Modulation amplitude
+        pub amplitude: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.BiasLimitsSet`: set the Kelvin controller bias limits.
+    pub struct KelvinCtrlBiasLimitsSet: "KelvinCtrl.BiasLimitsSet" {
+        /// High bias limit in volts
+        pub high_limit_v: f32 = "f",
+        /// Low bias limit in volts
+        pub low_limit_v: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `KelvinCtrl.BiasLimitsGet`: get the Kelvin controller bias limits.
+    pub struct KelvinCtrlBiasLimitsGet: "KelvinCtrl.BiasLimitsGet" {} -> KelvinCtrlBiasLimitsGetResponse {
+        /// High bias limit in volts
+        pub high_limit_v: f32 = "f",
+        /// Low bias limit in volts
+        pub low_limit_v: f32 = "f",
+    }
+}
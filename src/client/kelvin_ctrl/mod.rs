@@ -1,9 +1,12 @@
+pub mod commands;
+
 use super::NanonisClient;
+use super::signals::SignalRef;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use serde::{Deserialize, Serialize};
 
 /// Kelvin controller slope direction.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum KelvinSlope {
     /// No change to current setting
     #[default]
@@ -112,12 +115,7 @@ impl NanonisClient {
     /// ```
     pub fn kelvin_ctrl_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "KelvinCtrl.CtrlOnOffSet",
-            vec![NanonisValue::U32(flag)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::KelvinCtrlCtrlOnOffSet { enabled: flag })?;
         Ok(())
     }
 
@@ -129,13 +127,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("KelvinCtrl.CtrlOnOffGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::KelvinCtrlCtrlOnOffGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.enabled != 0)
     }
 
     /// Set the Kelvin controller setpoint.
@@ -146,12 +140,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_setpnt_set(&mut self, setpoint: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "KelvinCtrl.SetpntSet",
-            vec![NanonisValue::F32(setpoint)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::KelvinCtrlSetpntSet { setpoint })?;
         Ok(())
     }
 
@@ -163,13 +152,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_setpnt_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("KelvinCtrl.SetpntGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::KelvinCtrlSetpntGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.setpoint)
     }
 
     /// Set the Kelvin controller gain parameters.
@@ -180,16 +165,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_gain_set(&mut self, gain: &KelvinGain) -> Result<(), NanonisError> {
-        self.quick_send(
-            "KelvinCtrl.GainSet",
-            vec![
-                NanonisValue::F32(gain.p_gain),
-                NanonisValue::F32(gain.time_constant_s),
-                NanonisValue::U16(gain.slope.into()),
-            ],
-            vec!["f", "f", "H"],
-            vec![],
-        )?;
+        self.send(&commands::KelvinCtrlGainSet {
+            p_gain: gain.p_gain,
+            time_constant_s: gain.time_constant_s,
+            slope: gain.slope.into(),
+        })?;
         Ok(())
     }
 
@@ -201,18 +181,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_gain_get(&mut self) -> Result<KelvinGain, NanonisError> {
-        let result =
-            self.quick_send("KelvinCtrl.GainGet", vec![], vec![], vec!["f", "f", "H"])?;
-
-        if result.len() >= 3 {
-            Ok(KelvinGain {
-                p_gain: result[0].as_f32()?,
-                time_constant_s: result[1].as_f32()?,
-                slope: result[2].as_u16()?.try_into()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::KelvinCtrlGainGet {})?;
+
+        Ok(KelvinGain {
+            p_gain: response.p_gain,
+            time_constant_s: response.time_constant_s,
+            slope: response.slope.try_into()?,
+        })
     }
 
     /// Set the Kelvin controller modulation parameters.
@@ -226,16 +201,11 @@ impl NanonisClient {
         &mut self,
         params: &KelvinModParams,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "KelvinCtrl.ModParamsSet",
-            vec![
-                NanonisValue::F32(params.frequency_hz),
-                NanonisValue::F32(params.amplitude),
-                NanonisValue::F32(params.phase_deg),
-            ],
-            vec!["f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::KelvinCtrlModParamsSet {
+            frequency_hz: params.frequency_hz,
+            amplitude: params.amplitude,
+            phase_deg: params.phase_deg,
+        })?;
         Ok(())
     }
 
@@ -247,18 +217,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_mod_params_get(&mut self) -> Result<KelvinModParams, NanonisError> {
-        let result =
-            self.quick_send("KelvinCtrl.ModParamsGet", vec![], vec![], vec!["f", "f", "f"])?;
-
-        if result.len() >= 3 {
-            Ok(KelvinModParams {
-                frequency_hz: result[0].as_f32()?,
-                amplitude: result[1].as_f32()?,
-                phase_deg: result[2].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::KelvinCtrlModParamsGet {})?;
+
+        Ok(KelvinModParams {
+            frequency_hz: response.frequency_hz,
+            amplitude: response.amplitude,
+            phase_deg: response.phase_deg,
+        })
     }
 
     /// Set the Kelvin controller AC mode and modulation status.
@@ -275,15 +240,10 @@ impl NanonisClient {
         modulation: bool,
     ) -> Result<(), NanonisError> {
         let mod_flag = if modulation { 1u16 } else { 0u16 };
-        self.quick_send(
-            "KelvinCtrl.ModOnOffSet",
-            vec![
-                NanonisValue::U16(ac_mode.into()),
-                NanonisValue::U16(mod_flag),
-            ],
-            vec!["H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::KelvinCtrlModOnOffSet {
+            ac_mode: ac_mode.into(),
+            modulation: mod_flag,
+        })?;
         Ok(())
     }
 
@@ -295,33 +255,27 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_mod_on_off_get(&mut self) -> Result<KelvinModStatus, NanonisError> {
-        let result =
-            self.quick_send("KelvinCtrl.ModOnOffGet", vec![], vec![], vec!["H", "H"])?;
-
-        if result.len() >= 2 {
-            Ok(KelvinModStatus {
-                ac_mode: result[0].as_u16()? != 0,
-                modulation: result[1].as_u16()? != 0,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::KelvinCtrlModOnOffGet {})?;
+
+        Ok(KelvinModStatus {
+            ac_mode: response.ac_mode != 0,
+            modulation: response.modulation != 0,
+        })
     }
 
     /// Set the Kelvin controller demodulated/control signal index.
     ///
     /// # Arguments
-    /// * `signal_index` - Signal index
+    /// * `signal` - Signal index or name
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
-    pub fn kelvin_ctrl_signal_set(&mut self, signal_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "KelvinCtrl.CtrlSignalSet",
-            vec![NanonisValue::I32(signal_index)],
-            vec!["i"],
-            vec![],
-        )?;
+    /// Returns `NanonisError` if communication fails or the name is unknown.
+    pub fn kelvin_ctrl_signal_set(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::KelvinCtrlCtrlSignalSet { signal_index })?;
         Ok(())
     }
 
@@ -333,13 +287,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_signal_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("KelvinCtrl.CtrlSignalGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::KelvinCtrlCtrlSignalGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.signal_index)
     }
 
     /// Get the amplitude of the demodulated/control signal.
@@ -350,13 +300,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_amp_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("KelvinCtrl.AmpGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::KelvinCtrlAmpGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.amplitude)
     }
 
     /// Set the Kelvin controller bias limits.
@@ -372,15 +318,10 @@ impl NanonisClient {
         &mut self,
         limits: &KelvinBiasLimits,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "KelvinCtrl.BiasLimitsSet",
-            vec![
-                NanonisValue::F32(limits.high_limit_v),
-                NanonisValue::F32(limits.low_limit_v),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::KelvinCtrlBiasLimitsSet {
+            high_limit_v: limits.high_limit_v,
+            low_limit_v: limits.low_limit_v,
+        })?;
         Ok(())
     }
 
@@ -392,16 +333,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn kelvin_ctrl_bias_limits_get(&mut self) -> Result<KelvinBiasLimits, NanonisError> {
-        let result =
-            self.quick_send("KelvinCtrl.BiasLimitsGet", vec![], vec![], vec!["f", "f"])?;
-
-        if result.len() >= 2 {
-            Ok(KelvinBiasLimits {
-                high_limit_v: result[0].as_f32()?,
-                low_limit_v: result[1].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::KelvinCtrlBiasLimitsGet {})?;
+
+        Ok(KelvinBiasLimits {
+            high_limit_v: response.high_limit_v,
+            low_limit_v: response.low_limit_v,
+        })
     }
 }
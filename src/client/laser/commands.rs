@@ -0,0 +1,43 @@
+//! Typed descriptors for the `Laser.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Laser.OnOffSet`: switch the laser on or off.
+    pub struct LaserOnOffSet: "Laser.OnOffSet" {
+        /// True to enable, false to disable
+        pub on: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Laser.OnOffGet`: get the laser status.
+    pub struct LaserOnOffGet: "Laser.OnOffGet" {} -> LaserOnOffGetResponse {
+        /// True to enable, false to disable
+        pub on: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Laser.PropsSet`: set the laser setpoint.
+    pub struct LaserPropsSet: "Laser.PropsSet" {
+        /// Laser setpoint value
+        pub setpoint: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Laser.PropsGet`: get the laser setpoint.
+    pub struct LaserPropsGet: "Laser.PropsGet" {} -> LaserPropsGetResponse {
+        /// Laser setpoint value
+        pub setpoint: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Laser.PowerGet`: get the current laser power.
+    pub struct LaserPowerGet: "Laser.PowerGet" {} -> LaserPowerGetResponse {
+        /// Laser power
+        pub power: f32 = "f",
+    }
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     // ==================== Laser ====================
@@ -13,12 +14,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn laser_on_off_set(&mut self, on: bool) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Laser.OnOffSet",
-            vec![NanonisValue::U32(if on { 1 } else { 0 })],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::LaserOnOffSet { on: if on { 1 } else { 0 } })?;
         Ok(())
     }
 
@@ -30,9 +26,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn laser_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("Laser.OnOffGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::LaserOnOffGet {})?;
 
-        Ok(result[0].as_u32()? != 0)
+        Ok(response.on != 0)
     }
 
     /// Set the laser setpoint.
@@ -43,12 +39,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn laser_props_set(&mut self, setpoint: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Laser.PropsSet",
-            vec![NanonisValue::F32(setpoint)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::LaserPropsSet { setpoint })?;
         Ok(())
     }
 
@@ -60,9 +51,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn laser_props_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Laser.PropsGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::LaserPropsGet {})?;
 
-        result[0].as_f32()
+        Ok(response.setpoint)
     }
 
     /// Get the current laser power.
@@ -73,8 +64,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn laser_power_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Laser.PowerGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::LaserPowerGet {})?;
 
-        result[0].as_f32()
+        Ok(response.power)
     }
 }
@@ -0,0 +1,326 @@
+//! Typed descriptors for the `LockIn.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `LockIn.ModOnOffSet`: turn the specified Lock-In modulator on or off.
+    pub struct LockInModOnOffSet: "LockIn.ModOnOffSet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// `true` to turn on, `false` to turn off
+        pub on: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModOnOffGet`: get the on/off status of the specified Lock-In modulator.
+    pub struct LockInModOnOffGet: "LockIn.ModOnOffGet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModOnOffGetResponse {
+        /// `true` to turn on, `false` to turn off
+        pub on: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.ModSignalSet`: set the modulated signal of the specified Lock-In modulator.
+    pub struct LockInModSignalSet: "LockIn.ModSignalSet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// Index of the modulated signal
+        pub signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModSignalGet`: get the modulated signal index of the specified Lock-In modulator.
+    pub struct LockInModSignalGet: "LockIn.ModSignalGet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModSignalGetResponse {
+        /// Index of the modulated signal
+        pub signal_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.ModPhasRegSet`: set the phase register index of the specified Lock-In modulator.
+    pub struct LockInModPhasRegSet: "LockIn.ModPhasRegSet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// Phase register index (1-8)
+        pub phase_register_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModPhasRegGet`: get the phase register index of the specified Lock-In modulator.
+    pub struct LockInModPhasRegGet: "LockIn.ModPhasRegGet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModPhasRegGetResponse {
+        /// Phase register index (1-8)
+        pub phase_register_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.ModHarmonicSet`: set the harmonic of the specified Lock-In modulator.
+    pub struct LockInModHarmonicSet: "LockIn.ModHarmonicSet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// Harmonic number (1 = base frequency)
+        pub harmonic: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModHarmonicGet`: get the harmonic of the specified Lock-In modulator.
+    pub struct LockInModHarmonicGet: "LockIn.ModHarmonicGet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModHarmonicGetResponse {
+        /// Harmonic number (1 = base frequency)
+        pub harmonic: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.ModPhasSet`: set the modulation phase offset of the specified Lock-In modulator.
+    pub struct LockInModPhasSet: "LockIn.ModPhasSet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// Phase offset in degrees
+        pub phase_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModPhasGet`: get the modulation phase offset of the specified Lock-In modulator.
+    pub struct LockInModPhasGet: "LockIn.ModPhasGet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModPhasGetResponse {
+        /// Phase offset in degrees
+        pub phase_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.ModAmpSet`: set the modulation amplitude of the specified Lock-In modulator.
+    pub struct LockInModAmpSet: "LockIn.ModAmpSet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// Modulation amplitude
+        pub amplitude: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModAmpGet`: get the modulation amplitude of the specified Lock-In modulator.
+    pub struct LockInModAmpGet: "LockIn.ModAmpGet" {
+        /// Modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModAmpGetResponse {
+        /// Modulation amplitude
+        pub amplitude: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.ModPhasFreqSet`: set the frequency of the specified Lock-In phase register/modulator.
+    pub struct LockInModPhasFreqSet: "LockIn.ModPhasFreqSet" {
+        /// Phase register/modulator number (1-8)
+        pub modulator_num: i32 = "i",
+        /// Frequency in Hz
+        pub frequency_hz: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.ModPhasFreqGet`: get the frequency of the specified Lock-In phase register/modulator.
+    pub struct LockInModPhasFreqGet: "LockIn.ModPhasFreqGet" {
+        /// Phase register/modulator number (1-8)
+        pub modulator_num: i32 = "i",
+    } -> LockInModPhasFreqGetResponse {
+        /// Frequency in Hz
+        pub frequency_hz: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodSignalSet`: set the demodulated signal of the specified Lock-In demodulator.
+    pub struct LockInDemodSignalSet: "LockIn.DemodSignalSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// Index of the demodulated signal
+        pub signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodSignalGet`: get the demodulated signal index of the specified Lock-In demodulator.
+    pub struct LockInDemodSignalGet: "LockIn.DemodSignalGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodSignalGetResponse {
+        /// Index of the demodulated signal
+        pub signal_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodHarmonicSet`: set the harmonic of the specified Lock-In demodulator.
+    pub struct LockInDemodHarmonicSet: "LockIn.DemodHarmonicSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// Harmonic number (1 = base frequency)
+        pub harmonic: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodHarmonicGet`: get the harmonic of the specified Lock-In demodulator.
+    pub struct LockInDemodHarmonicGet: "LockIn.DemodHarmonicGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodHarmonicGetResponse {
+        /// Harmonic number (1 = base frequency)
+        pub harmonic: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodHPFilterSet`: set the high-pass filter properties for the specified demodulator.
+    pub struct LockInDemodHpFilterSet: "LockIn.DemodHPFilterSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// Filter order (-1=no change, 0=off, 1-8=active)
+        pub filter_order: i32 = "i",
+        /// Cutoff frequency in Hz (0=no change)
+        pub cutoff_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodHPFilterGet`: get the high-pass filter properties for the specified demodulator.
+    pub struct LockInDemodHpFilterGet: "LockIn.DemodHPFilterGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodHpFilterGetResponse {
+        /// Filter order (0 = off, 1-8 active)
+        pub order: i32 = "i",
+        /// Cutoff frequency in Hz
+        pub cutoff_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodLPFilterSet`: set the low-pass filter properties for the specified demodulator.
+    pub struct LockInDemodLpFilterSet: "LockIn.DemodLPFilterSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// Filter order (-1=no change, 0=off, 1-8=active)
+        pub filter_order: i32 = "i",
+        /// Cutoff frequency in Hz (0=no change)
+        pub cutoff_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodLPFilterGet`: get the low-pass filter properties for the specified demodulator.
+    pub struct LockInDemodLpFilterGet: "LockIn.DemodLPFilterGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodLpFilterGetResponse {
+        /// Filter order (0 = off, 1-8 active)
+        pub order: i32 = "i",
+        /// Cutoff frequency in Hz
+        pub cutoff_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodPhasRegSet`: set the phase register index of the specified Lock-In demodulator.
+    pub struct LockInDemodPhasRegSet: "LockIn.DemodPhasRegSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// Phase register index (1-8)
+        pub phase_register_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodPhasRegGet`: get the phase register index of the specified Lock-In demodulator.
+    pub struct LockInDemodPhasRegGet: "LockIn.DemodPhasRegGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodPhasRegGetResponse {
+        /// Phase register index (1-8)
+        pub phase_register_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodPhasSet`: set the reference phase of the specified Lock-In demodulator.
+    pub struct LockInDemodPhasSet: "LockIn.DemodPhasSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// Reference phase in degrees
+        pub phase_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodPhasGet`: get the reference phase of the specified Lock-In demodulator.
+    pub struct LockInDemodPhasGet: "LockIn.DemodPhasGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodPhasGetResponse {
+        /// Reference phase in degrees
+        pub phase_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodSyncFilterSet`: set the sync filter on/off for the specified demodulator.
+    pub struct LockInDemodSyncFilterSet: "LockIn.DemodSyncFilterSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// `true` to enable sync filter, `false` to disable
+        pub on: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodSyncFilterGet`: get the sync filter status for the specified demodulator.
+    pub struct LockInDemodSyncFilterGet: "LockIn.DemodSyncFilterGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodSyncFilterGetResponse {
+        /// `true` to enable sync filter, `false` to disable
+        pub on: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `LockIn.DemodRTSignalsSet`: set the RT signals mode for the specified demodulator.
+    pub struct LockInDemodRtSignalsSet: "LockIn.DemodRTSignalsSet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+        /// [`RTSignalMode::XY`] or [`RTSignalMode::RPhi`]
+        pub mode: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockIn.DemodRTSignalsGet`: get the RT signals mode for the specified demodulator.
+    pub struct LockInDemodRtSignalsGet: "LockIn.DemodRTSignalsGet" {
+        /// Demodulator number (1-8)
+        pub demodulator_num: i32 = "i",
+    } -> LockInDemodRtSignalsGetResponse {
+        /// [`RTSignalMode::XY`] or [`RTSignalMode::RPhi`]
+        pub mode: u32 = "I",
+    }
+}
@@ -1,9 +1,11 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
 use super::NanonisClient;
+use super::signals::SignalRef;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     // ==================== Modulator Methods ====================
@@ -31,12 +33,7 @@ impl NanonisClient {
         on: bool,
     ) -> Result<(), NanonisError> {
         let on_flag = if on { 1u32 } else { 0u32 };
-        self.quick_send(
-            "LockIn.ModOnOffSet",
-            vec![NanonisValue::I32(modulator_num), NanonisValue::U32(on_flag)],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::LockInModOnOffSet { modulator_num, on: on_flag })?;
         Ok(())
     }
 
@@ -61,27 +58,18 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn lockin_mod_on_off_get(&mut self, modulator_num: i32) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModOnOffGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModOnOffGet { modulator_num })?;
+        Ok(response.on != 0)
     }
 
     /// Set the modulated signal of the specified Lock-In modulator.
     ///
     /// # Arguments
     /// * `modulator_num` - Modulator number (1-8)
-    /// * `signal_index` - Signal index (0-127)
+    /// * `signal` - Signal index (0-127) or name
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if communication fails or the name is unknown.
     ///
     /// # Examples
     /// ```no_run
@@ -89,22 +77,16 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     /// client.lockin_mod_signal_set(1, 14)?; // Set modulator 1 to signal 14
+    /// client.lockin_mod_signal_set(1, "Bias (V)")?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn lockin_mod_signal_set(
         &mut self,
         modulator_num: i32,
-        signal_index: i32,
+        signal: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.ModSignalSet",
-            vec![
-                NanonisValue::I32(modulator_num),
-                NanonisValue::I32(signal_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::LockInModSignalSet { modulator_num, signal_index })?;
         Ok(())
     }
 
@@ -119,17 +101,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn lockin_mod_signal_get(&mut self, modulator_num: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModSignalGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModSignalGet { modulator_num })?;
+        Ok(response.signal_index)
     }
 
     /// Set the phase register index of the specified Lock-In modulator.
@@ -154,15 +127,7 @@ impl NanonisClient {
         modulator_num: i32,
         phase_register_index: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.ModPhasRegSet",
-            vec![
-                NanonisValue::I32(modulator_num),
-                NanonisValue::I32(phase_register_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::LockInModPhasRegSet { modulator_num, phase_register_index })?;
         Ok(())
     }
 
@@ -174,17 +139,8 @@ impl NanonisClient {
     /// # Returns
     /// Phase register index (1-8).
     pub fn lockin_mod_phas_reg_get(&mut self, modulator_num: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModPhasRegGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModPhasRegGet { modulator_num })?;
+        Ok(response.phase_register_index)
     }
 
     /// Set the harmonic of the specified Lock-In modulator.
@@ -200,15 +156,7 @@ impl NanonisClient {
         modulator_num: i32,
         harmonic: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.ModHarmonicSet",
-            vec![
-                NanonisValue::I32(modulator_num),
-                NanonisValue::I32(harmonic),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::LockInModHarmonicSet { modulator_num, harmonic })?;
         Ok(())
     }
 
@@ -220,17 +168,8 @@ impl NanonisClient {
     /// # Returns
     /// Harmonic number (1 = base frequency).
     pub fn lockin_mod_harmonic_get(&mut self, modulator_num: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModHarmonicGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModHarmonicGet { modulator_num })?;
+        Ok(response.harmonic)
     }
 
     /// Set the modulation phase offset of the specified Lock-In modulator.
@@ -246,15 +185,7 @@ impl NanonisClient {
         modulator_num: i32,
         phase_deg: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.ModPhasSet",
-            vec![
-                NanonisValue::I32(modulator_num),
-                NanonisValue::F32(phase_deg),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::LockInModPhasSet { modulator_num, phase_deg })?;
         Ok(())
     }
 
@@ -266,17 +197,8 @@ impl NanonisClient {
     /// # Returns
     /// Phase offset in degrees.
     pub fn lockin_mod_phas_get(&mut self, modulator_num: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModPhasGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModPhasGet { modulator_num })?;
+        Ok(response.phase_deg)
     }
 
     /// Set the modulation amplitude of the specified Lock-In modulator.
@@ -292,15 +214,7 @@ impl NanonisClient {
         modulator_num: i32,
         amplitude: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.ModAmpSet",
-            vec![
-                NanonisValue::I32(modulator_num),
-                NanonisValue::F32(amplitude),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::LockInModAmpSet { modulator_num, amplitude })?;
         Ok(())
     }
 
@@ -312,17 +226,8 @@ impl NanonisClient {
     /// # Returns
     /// Modulation amplitude.
     pub fn lockin_mod_amp_get(&mut self, modulator_num: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModAmpGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModAmpGet { modulator_num })?;
+        Ok(response.amplitude)
     }
 
     /// Set the frequency of the specified Lock-In phase register/modulator.
@@ -347,15 +252,7 @@ impl NanonisClient {
         modulator_num: i32,
         frequency_hz: f64,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.ModPhasFreqSet",
-            vec![
-                NanonisValue::I32(modulator_num),
-                NanonisValue::F64(frequency_hz),
-            ],
-            vec!["i", "d"],
-            vec![],
-        )?;
+        self.send(&commands::LockInModPhasFreqSet { modulator_num, frequency_hz })?;
         Ok(())
     }
 
@@ -367,17 +264,8 @@ impl NanonisClient {
     /// # Returns
     /// Frequency in Hz.
     pub fn lockin_mod_phas_freq_get(&mut self, modulator_num: i32) -> Result<f64, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.ModPhasFreqGet",
-            vec![NanonisValue::I32(modulator_num)],
-            vec!["i"],
-            vec!["d"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_f64()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInModPhasFreqGet { modulator_num })?;
+        Ok(response.frequency_hz)
     }
 
     // ==================== Demodulator Methods ====================
@@ -386,24 +274,17 @@ impl NanonisClient {
     ///
     /// # Arguments
     /// * `demodulator_num` - Demodulator number (1-8)
-    /// * `signal_index` - Signal index (0-127)
+    /// * `signal` - Signal index (0-127) or name
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if communication fails or the name is unknown.
     pub fn lockin_demod_signal_set(
         &mut self,
         demodulator_num: i32,
-        signal_index: i32,
+        signal: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodSignalSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::I32(signal_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::LockInDemodSignalSet { demodulator_num, signal_index })?;
         Ok(())
     }
 
@@ -415,17 +296,8 @@ impl NanonisClient {
     /// # Returns
     /// Signal index (0-127).
     pub fn lockin_demod_signal_get(&mut self, demodulator_num: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodSignalGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodSignalGet { demodulator_num })?;
+        Ok(response.signal_index)
     }
 
     /// Set the harmonic of the specified Lock-In demodulator.
@@ -438,15 +310,7 @@ impl NanonisClient {
         demodulator_num: i32,
         harmonic: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodHarmonicSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::I32(harmonic),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodHarmonicSet { demodulator_num, harmonic })?;
         Ok(())
     }
 
@@ -458,17 +322,8 @@ impl NanonisClient {
     /// # Returns
     /// Harmonic number (1 = base frequency).
     pub fn lockin_demod_harmonic_get(&mut self, demodulator_num: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodHarmonicGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodHarmonicGet { demodulator_num })?;
+        Ok(response.harmonic)
     }
 
     /// Set the high-pass filter properties for the specified demodulator.
@@ -483,16 +338,11 @@ impl NanonisClient {
         filter_order: i32,
         cutoff_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodHPFilterSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::I32(filter_order),
-                NanonisValue::F32(cutoff_hz),
-            ],
-            vec!["i", "i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodHpFilterSet {
+            demodulator_num,
+            filter_order,
+            cutoff_hz,
+        })?;
         Ok(())
     }
 
@@ -507,20 +357,11 @@ impl NanonisClient {
         &mut self,
         demodulator_num: i32,
     ) -> Result<FilterConfig, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodHPFilterGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["i", "f"],
-        )?;
-        if result.len() >= 2 {
-            Ok(FilterConfig {
-                order: result[0].as_i32()?,
-                cutoff_hz: result[1].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodHpFilterGet { demodulator_num })?;
+        Ok(FilterConfig {
+            order: response.order,
+            cutoff_hz: response.cutoff_hz,
+        })
     }
 
     /// Set the low-pass filter properties for the specified demodulator.
@@ -535,16 +376,11 @@ impl NanonisClient {
         filter_order: i32,
         cutoff_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodLPFilterSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::I32(filter_order),
-                NanonisValue::F32(cutoff_hz),
-            ],
-            vec!["i", "i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodLpFilterSet {
+            demodulator_num,
+            filter_order,
+            cutoff_hz,
+        })?;
         Ok(())
     }
 
@@ -559,20 +395,11 @@ impl NanonisClient {
         &mut self,
         demodulator_num: i32,
     ) -> Result<FilterConfig, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodLPFilterGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["i", "f"],
-        )?;
-        if result.len() >= 2 {
-            Ok(FilterConfig {
-                order: result[0].as_i32()?,
-                cutoff_hz: result[1].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodLpFilterGet { demodulator_num })?;
+        Ok(FilterConfig {
+            order: response.order,
+            cutoff_hz: response.cutoff_hz,
+        })
     }
 
     /// Set the phase register index of the specified Lock-In demodulator.
@@ -585,15 +412,10 @@ impl NanonisClient {
         demodulator_num: i32,
         phase_register_index: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodPhasRegSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::I32(phase_register_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodPhasRegSet {
+            demodulator_num,
+            phase_register_index,
+        })?;
         Ok(())
     }
 
@@ -608,17 +430,8 @@ impl NanonisClient {
         &mut self,
         demodulator_num: i32,
     ) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodPhasRegGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodPhasRegGet { demodulator_num })?;
+        Ok(response.phase_register_index)
     }
 
     /// Set the reference phase of the specified Lock-In demodulator.
@@ -631,15 +444,7 @@ impl NanonisClient {
         demodulator_num: i32,
         phase_deg: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodPhasSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::F32(phase_deg),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodPhasSet { demodulator_num, phase_deg })?;
         Ok(())
     }
 
@@ -651,17 +456,8 @@ impl NanonisClient {
     /// # Returns
     /// Reference phase in degrees.
     pub fn lockin_demod_phas_get(&mut self, demodulator_num: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodPhasGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodPhasGet { demodulator_num })?;
+        Ok(response.phase_deg)
     }
 
     /// Set the sync filter on/off for the specified demodulator.
@@ -678,15 +474,7 @@ impl NanonisClient {
         on: bool,
     ) -> Result<(), NanonisError> {
         let on_flag = if on { 1u32 } else { 0u32 };
-        self.quick_send(
-            "LockIn.DemodSyncFilterSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::U32(on_flag),
-            ],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodSyncFilterSet { demodulator_num, on: on_flag })?;
         Ok(())
     }
 
@@ -701,17 +489,8 @@ impl NanonisClient {
         &mut self,
         demodulator_num: i32,
     ) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodSyncFilterGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-        if let Some(val) = result.first() {
-            Ok(val.as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodSyncFilterGet { demodulator_num })?;
+        Ok(response.on != 0)
     }
 
     /// Set the RT signals mode for the specified demodulator.
@@ -724,15 +503,10 @@ impl NanonisClient {
         demodulator_num: i32,
         mode: RTSignalMode,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockIn.DemodRTSignalsSet",
-            vec![
-                NanonisValue::I32(demodulator_num),
-                NanonisValue::U32(mode.into()),
-            ],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::LockInDemodRtSignalsSet {
+            demodulator_num,
+            mode: mode.into(),
+        })?;
         Ok(())
     }
 
@@ -747,16 +521,7 @@ impl NanonisClient {
         &mut self,
         demodulator_num: i32,
     ) -> Result<RTSignalMode, NanonisError> {
-        let result = self.quick_send(
-            "LockIn.DemodRTSignalsGet",
-            vec![NanonisValue::I32(demodulator_num)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-        if let Some(val) = result.first() {
-            RTSignalMode::try_from(val.as_u32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::LockInDemodRtSignalsGet { demodulator_num })?;
+        RTSignalMode::try_from(response.mode)
     }
 }
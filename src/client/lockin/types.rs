@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 // ==================== Lock-In Amplifier Types ====================
 
 /// Demodulator RT signal output mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum RTSignalMode {
     /// X/Y (Cartesian) output
     #[default]
@@ -32,7 +34,7 @@ impl TryFrom<u32> for RTSignalMode {
 }
 
 /// Lock-in modulator configuration.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModulatorConfig {
     /// Modulator number (1-8)
     pub number: i32,
@@ -68,7 +70,7 @@ impl Default for ModulatorConfig {
 }
 
 /// Lock-in demodulator configuration.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DemodulatorConfig {
     /// Demodulator number (1-8)
     pub number: i32,
@@ -0,0 +1,114 @@
+//! Typed descriptors for the `LockInFreqSwp.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `LockInFreqSwp.Open`: open the Lock-In Frequency Sweep (Transfer Function) module.
+    pub struct LockInFreqSwpOpen: "LockInFreqSwp.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.Start`: start a Lock-In frequency sweep.
+    pub struct LockInFreqSwpStart: "LockInFreqSwp.Start" blocking if get_data {
+        /// If true, returns measurement data
+        pub get_data: u32 = "I",
+        /// Sweep direction (up or down)
+        pub direction: u32 = "I",
+    } -> LockInFreqSwpStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Names of recorded channels
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// Sweep data, one row per channel
+        pub data_2d: Array2D<f32> = "2f",
+    }
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.SignalSet`: set the sweep signal for the Lock-In frequency sweep module.
+    pub struct LockInFreqSwpSignalSet: "LockInFreqSwp.SignalSet" {
+        /// Index of the sweep signal
+        pub signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.SignalGet`: get the sweep signal for the Lock-In frequency sweep module.
+    pub struct LockInFreqSwpSignalGet: "LockInFreqSwp.SignalGet" {} -> LockInFreqSwpSignalGetResponse {
+        /// Index of the sweep signal
+        pub signal_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.LimitsSet`: set the frequency limits for the Lock-In frequency sweep.
+    pub struct LockInFreqSwpLimitsSet: "LockInFreqSwp.LimitsSet" {
+        /// Lower frequency limit in Hz
+        pub lower_limit_hz: f32 = "f",
+        /// Upper frequency limit in Hz
+        pub upper_limit_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.LimitsGet`: get the frequency limits for the Lock-In frequency sweep.
+    pub struct LockInFreqSwpLimitsGet: "LockInFreqSwp.LimitsGet" {} -> LockInFreqSwpLimitsGetResponse {
+        /// Lower frequency limit in Hz
+        pub lower_limit_hz: f32 = "f",
+        /// Upper frequency limit in Hz
+        pub upper_limit_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.PropsSet`: set the Lock-In frequency sweep properties.
+    pub struct LockInFreqSwpPropsSet: "LockInFreqSwp.PropsSet" {
+        /// Number of frequency steps (logarithmic distribution)
+        pub num_steps: u16 = "H",
+        /// Number of lock-in periods to average per measurement
+        pub integration_periods: u16 = "H",
+        /// Minimum integration time in seconds
+        pub min_integration_time_s: f32 = "f",
+        /// Number of lock-in periods to wait before acquiring
+        pub settling_periods: u16 = "H",
+        /// Minimum settling time in seconds
+        pub min_settling_time_s: f32 = "f",
+        /// Automatically save data at end of sweep
+        pub autosave: u32 = "I",
+        /// Show save dialog when saving
+        pub save_dialog: u32 = "I",
+        /// Base filename for saved files
+        pub basename: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `LockInFreqSwp.PropsGet`: get the Lock-In frequency sweep properties.
+    pub struct LockInFreqSwpPropsGet: "LockInFreqSwp.PropsGet" {} -> LockInFreqSwpPropsGetResponse {
+        /// Number of frequency steps (logarithmic distribution)
+        pub num_steps: u16 = "H",
+        /// Number of lock-in periods to average per measurement
+        pub integration_periods: u16 = "H",
+        /// Minimum integration time in seconds
+        pub min_integration_time_s: f32 = "f",
+        /// Number of lock-in periods to wait before acquiring
+        pub settling_periods: u16 = "H",
+        /// Minimum settling time in seconds
+        pub min_settling_time_s: f32 = "f",
+        /// Automatically save data at end of sweep
+        pub autosave: u32 = "I",
+        /// Show save dialog when saving
+        pub save_dialog: u32 = "I",
+        /// Length of the basename in bytes
+        pub basename_size: i32 = "i",
+        /// Base filename for saved files
+        pub basename: String = "*-c",
+    }
+}
@@ -1,6 +1,9 @@
+pub mod commands;
+
 use super::NanonisClient;
+use super::signals::SignalRef;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 
 /// Lock-In frequency sweep properties configuration.
 #[derive(Debug, Clone)]
@@ -45,7 +48,7 @@ pub struct LockInFreqSwpResult {
     pub channel_names: Vec<String>,
     /// 2D data array `[rows][columns]`
     /// First row is swept frequency, additional rows are channel data
-    pub data: Vec<Vec<f32>>,
+    pub data: Array2D<f32>,
 }
 
 /// Sweep direction for lock-in frequency sweep.
@@ -82,7 +85,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn lockin_freq_swp_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("LockInFreqSwp.Open", vec![], vec![], vec![])?;
+        self.send(&commands::LockInFreqSwpOpen {})?;
         Ok(())
     }
 
@@ -115,57 +118,33 @@ impl NanonisClient {
     ) -> Result<LockInFreqSwpResult, NanonisError> {
         let get_data_flag = if get_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "LockInFreqSwp.Start",
-            vec![
-                NanonisValue::U32(get_data_flag),
-                NanonisValue::U32(direction.into()),
-            ],
-            vec!["I", "I"],
-            vec!["i", "i", "*+c", "i", "i", "2f"],
-        )?;
-
-        if result.len() >= 6 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let rows = result[3].as_i32()? as usize;
-            let cols = result[4].as_i32()? as usize;
+        let response = self.send(&commands::LockInFreqSwpStart {
+            get_data: get_data_flag,
+            direction: direction.into(),
+        })?;
 
-            let flat_data = result[5].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                if end_idx <= flat_data.len() {
-                    data_2d.push(flat_data[start_idx..end_idx].to_vec());
-                }
-            }
+        let channel_names = response.channel_names;
+        let data_2d = response.data_2d;
 
-            Ok(LockInFreqSwpResult {
-                channel_names,
-                data: data_2d,
-            })
-        } else {
-            Ok(LockInFreqSwpResult {
-                channel_names: vec![],
-                data: vec![],
-            })
-        }
+        Ok(LockInFreqSwpResult {
+            channel_names,
+            data: data_2d,
+        })
     }
 
     /// Set the sweep signal for the Lock-In frequency sweep module.
     ///
     /// # Arguments
-    /// * `signal_index` - Sweep signal index, or -1 for no signal
+    /// * `signal` - Sweep signal index or name, or -1 for no signal
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
-    pub fn lockin_freq_swp_signal_set(&mut self, signal_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockInFreqSwp.SignalSet",
-            vec![NanonisValue::I32(signal_index)],
-            vec!["i"],
-            vec![],
-        )?;
+    /// Returns `NanonisError` if communication fails or the name is unknown.
+    pub fn lockin_freq_swp_signal_set(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::LockInFreqSwpSignalSet { signal_index })?;
         Ok(())
     }
 
@@ -177,13 +156,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn lockin_freq_swp_signal_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("LockInFreqSwp.SignalGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::LockInFreqSwpSignalGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.signal_index)
     }
 
     /// Set the frequency limits for the Lock-In frequency sweep.
@@ -199,15 +174,7 @@ impl NanonisClient {
         lower_limit_hz: f32,
         upper_limit_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "LockInFreqSwp.LimitsSet",
-            vec![
-                NanonisValue::F32(lower_limit_hz),
-                NanonisValue::F32(upper_limit_hz),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::LockInFreqSwpLimitsSet { lower_limit_hz, upper_limit_hz })?;
         Ok(())
     }
 
@@ -219,13 +186,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn lockin_freq_swp_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result = self.quick_send("LockInFreqSwp.LimitsGet", vec![], vec![], vec!["f", "f"])?;
+        let response = self.send(&commands::LockInFreqSwpLimitsGet {})?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok((response.lower_limit_hz, response.upper_limit_hz))
     }
 
     /// Set the Lock-In frequency sweep properties.
@@ -257,21 +220,16 @@ impl NanonisClient {
         let autosave_flag = if props.autosave { 1u32 } else { 0u32 };
         let dialog_flag = if props.save_dialog { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "LockInFreqSwp.PropsSet",
-            vec![
-                NanonisValue::U16(props.num_steps),
-                NanonisValue::U16(props.integration_periods),
-                NanonisValue::F32(props.min_integration_time_s),
-                NanonisValue::U16(props.settling_periods),
-                NanonisValue::F32(props.min_settling_time_s),
-                NanonisValue::U32(autosave_flag),
-                NanonisValue::U32(dialog_flag),
-                NanonisValue::String(props.basename.clone()),
-            ],
-            vec!["H", "H", "f", "H", "f", "I", "I", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::LockInFreqSwpPropsSet {
+            num_steps: props.num_steps,
+            integration_periods: props.integration_periods,
+            min_integration_time_s: props.min_integration_time_s,
+            settling_periods: props.settling_periods,
+            min_settling_time_s: props.min_settling_time_s,
+            autosave: autosave_flag,
+            save_dialog: dialog_flag,
+            basename: props.basename.clone(),
+        })?;
         Ok(())
     }
 
@@ -283,26 +241,17 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn lockin_freq_swp_props_get(&mut self) -> Result<LockInFreqSwpProps, NanonisError> {
-        let result = self.quick_send(
-            "LockInFreqSwp.PropsGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "f", "H", "f", "I", "I", "i", "*-c"],
-        )?;
+        let response = self.send(&commands::LockInFreqSwpPropsGet {})?;
 
-        if result.len() >= 9 {
-            Ok(LockInFreqSwpProps {
-                num_steps: result[0].as_u16()?,
-                integration_periods: result[1].as_u16()?,
-                min_integration_time_s: result[2].as_f32()?,
-                settling_periods: result[3].as_u16()?,
-                min_settling_time_s: result[4].as_f32()?,
-                autosave: result[5].as_u32()? != 0,
-                save_dialog: result[6].as_u32()? != 0,
-                basename: result[8].as_string()?.to_string(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(LockInFreqSwpProps {
+            num_steps: response.num_steps,
+            integration_periods: response.integration_periods,
+            min_integration_time_s: response.min_integration_time_s,
+            settling_periods: response.settling_periods,
+            min_settling_time_s: response.min_settling_time_s,
+            autosave: response.autosave != 0,
+            save_dialog: response.save_dialog != 0,
+            basename: response.basename,
+        })
     }
 }
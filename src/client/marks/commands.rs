@@ -0,0 +1,143 @@
+//! Typed descriptors for the `Marks.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Marks.PointDraw`: draw text at a specified point in the scan frame.
+    pub struct MarksPointDraw: "Marks.PointDraw" {
+        /// X coordinate in meters
+        pub x_m: f32 = "f",
+        /// Y coordinate in meters
+        pub y_m: f32 = "f",
+        /// Text to draw
+        pub text: String = "+*c",
+        /// RGB color value
+        pub color: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.PointsDraw`: draw text at multiple points in the scan frame.
+    pub struct MarksPointsDraw: "Marks.PointsDraw" {
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// X coordinates in meters
+        pub x_coords_m: Vec<f32> = "*f",
+        /// Y coordinates in meters
+        pub y_coords_m: Vec<f32> = "*f",
+        /// Text labels for each point
+        pub texts: Vec<String> = "+*c",
+        /// RGB colors for each point
+        pub colors: Vec<u32> = "*I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.LineDraw`: draw a line in the scan frame.
+    pub struct MarksLineDraw: "Marks.LineDraw" {
+        /// Start X coordinate in meters
+        pub start_x_m: f32 = "f",
+        /// Start Y coordinate in meters
+        pub start_y_m: f32 = "f",
+        /// End X coordinate in meters
+        pub end_x_m: f32 = "f",
+        /// End Y coordinate in meters
+        pub end_y_m: f32 = "f",
+        /// RGB color value
+        pub color: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.LinesDraw`: draw multiple lines in the scan frame.
+    pub struct MarksLinesDraw: "Marks.LinesDraw" {
+        /// Number of lines
+        pub num_lines: i32 = "i",
+        /// Start X coordinates in meters
+        pub start_x_m: Vec<f32> = "*f",
+        /// Start Y coordinates in meters
+        pub start_y_m: Vec<f32> = "*f",
+        /// End X coordinates in meters
+        pub end_x_m: Vec<f32> = "*f",
+        /// End Y coordinates in meters
+        pub end_y_m: Vec<f32> = "*f",
+        /// RGB colors for each line
+        pub colors: Vec<u32> = "*I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.PointsErase`: erase a point mark from the scan frame.
+    pub struct MarksPointsErase: "Marks.PointsErase" {
+        /// Index of point to erase (-1 to erase all)
+        pub point_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.LinesErase`: erase a line mark from the scan frame.
+    pub struct MarksLinesErase: "Marks.LinesErase" {
+        /// Index of line to erase (-1 to erase all)
+        pub line_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.PointsVisibleSet`: show or hide a point mark.
+    pub struct MarksPointsVisibleSet: "Marks.PointsVisibleSet" {
+        /// Index of point (-1 for all)
+        pub point_index: i32 = "i",
+        /// 0 = show, 1 = hide
+        pub flag: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.LinesVisibleSet`: show or hide a line mark.
+    pub struct MarksLinesVisibleSet: "Marks.LinesVisibleSet" {
+        /// Index of line (-1 for all)
+        pub line_index: i32 = "i",
+        /// 0 = show, 1 = hide
+        pub flag: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Marks.PointsGet`: get information about all drawn point marks.
+    pub struct MarksPointsGet: "Marks.PointsGet" {} -> MarksPointsGetResponse {
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// X coordinates in meters
+        pub x_coords: Vec<f32> = "*f",
+        /// Y coordinates in meters
+        pub y_coords: Vec<f32> = "*f",
+        /// Number of texts
+        pub num_texts: i32 = "i",
+        /// Text of each point
+        pub texts: Vec<String> = "*+c",
+        /// RGB color of each point
+        pub colors: Vec<u32> = "*I",
+        /// Visible flag
+        pub visible: Vec<u32> = "*I",
+    }
+}
+
+nanonis_command! {
+    /// `Marks.LinesGet`: get information about all drawn line marks.
+    pub struct MarksLinesGet: "Marks.LinesGet" {} -> MarksLinesGetResponse {
+        /// Number of lines
+        pub num_lines: i32 = "i",
+        /// Start X coordinates in meters
+        pub start_x: Vec<f32> = "*f",
+        /// Start Y coordinates in meters
+        pub start_y: Vec<f32> = "*f",
+        /// End X coordinates in meters
+        pub end_x: Vec<f32> = "*f",
+        /// End Y coordinates in meters
+        pub end_y: Vec<f32> = "*f",
+        /// RGB color of each line
+        pub colors: Vec<u32> = "*I",
+        /// Visible flag
+        pub visible: Vec<u32> = "*I",
+    }
+}
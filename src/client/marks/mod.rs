@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Point mark information.
 #[derive(Debug, Clone)]
@@ -62,17 +63,7 @@ impl NanonisClient {
         text: &str,
         color: u32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Marks.PointDraw",
-            vec![
-                NanonisValue::F32(x_m),
-                NanonisValue::F32(y_m),
-                NanonisValue::String(text.to_string()),
-                NanonisValue::U32(color),
-            ],
-            vec!["f", "f", "+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::MarksPointDraw { x_m, y_m, text: text.to_string(), color })?;
         Ok(())
     }
 
@@ -95,18 +86,13 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let num_points = x_coords_m.len() as i32;
 
-        self.quick_send(
-            "Marks.PointsDraw",
-            vec![
-                NanonisValue::I32(num_points),
-                NanonisValue::ArrayF32(x_coords_m.to_vec()),
-                NanonisValue::ArrayF32(y_coords_m.to_vec()),
-                NanonisValue::ArrayString(texts.to_vec()),
-                NanonisValue::ArrayU32(colors.to_vec()),
-            ],
-            vec!["i", "*f", "*f", "+*c", "*I"],
-            vec![],
-        )?;
+        self.send(&commands::MarksPointsDraw {
+            num_points,
+            x_coords_m: x_coords_m.to_vec(),
+            y_coords_m: y_coords_m.to_vec(),
+            texts: texts.to_vec(),
+            colors: colors.to_vec(),
+        })?;
         Ok(())
     }
 
@@ -129,18 +115,13 @@ impl NanonisClient {
         end_y_m: f32,
         color: u32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Marks.LineDraw",
-            vec![
-                NanonisValue::F32(start_x_m),
-                NanonisValue::F32(start_y_m),
-                NanonisValue::F32(end_x_m),
-                NanonisValue::F32(end_y_m),
-                NanonisValue::U32(color),
-            ],
-            vec!["f", "f", "f", "f", "I"],
-            vec![],
-        )?;
+        self.send(&commands::MarksLineDraw {
+            start_x_m,
+            start_y_m,
+            end_x_m,
+            end_y_m,
+            color,
+        })?;
         Ok(())
     }
 
@@ -165,19 +146,14 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let num_lines = start_x_m.len() as i32;
 
-        self.quick_send(
-            "Marks.LinesDraw",
-            vec![
-                NanonisValue::I32(num_lines),
-                NanonisValue::ArrayF32(start_x_m.to_vec()),
-                NanonisValue::ArrayF32(start_y_m.to_vec()),
-                NanonisValue::ArrayF32(end_x_m.to_vec()),
-                NanonisValue::ArrayF32(end_y_m.to_vec()),
-                NanonisValue::ArrayU32(colors.to_vec()),
-            ],
-            vec!["i", "*f", "*f", "*f", "*f", "*I"],
-            vec![],
-        )?;
+        self.send(&commands::MarksLinesDraw {
+            num_lines,
+            start_x_m: start_x_m.to_vec(),
+            start_y_m: start_y_m.to_vec(),
+            end_x_m: end_x_m.to_vec(),
+            end_y_m: end_y_m.to_vec(),
+            colors: colors.to_vec(),
+        })?;
         Ok(())
     }
 
@@ -189,12 +165,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn marks_points_erase(&mut self, point_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Marks.PointsErase",
-            vec![NanonisValue::I32(point_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::MarksPointsErase { point_index })?;
         Ok(())
     }
 
@@ -206,12 +177,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn marks_lines_erase(&mut self, line_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Marks.LinesErase",
-            vec![NanonisValue::I32(line_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::MarksLinesErase { line_index })?;
         Ok(())
     }
 
@@ -230,12 +196,7 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         // Note: 0 = visible, 1 = invisible in the protocol
         let flag = if visible { 0u16 } else { 1u16 };
-        self.quick_send(
-            "Marks.PointsVisibleSet",
-            vec![NanonisValue::I32(point_index), NanonisValue::U16(flag)],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::MarksPointsVisibleSet { point_index, flag })?;
         Ok(())
     }
 
@@ -254,12 +215,7 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         // Note: 0 = visible, 1 = invisible in the protocol
         let flag = if visible { 0u16 } else { 1u16 };
-        self.quick_send(
-            "Marks.LinesVisibleSet",
-            vec![NanonisValue::I32(line_index), NanonisValue::U16(flag)],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::MarksLinesVisibleSet { line_index, flag })?;
         Ok(())
     }
 
@@ -271,42 +227,33 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn marks_points_get(&mut self) -> Result<Vec<PointMark>, NanonisError> {
-        let result = self.quick_send(
-            "Marks.PointsGet",
-            vec![],
-            vec![],
-            vec!["i", "*f", "*f", "i", "*+c", "*I", "*I"],
-        )?;
+        let response = self.send(&commands::MarksPointsGet {})?;
 
-        if result.len() >= 7 {
-            let num_points = result[0].as_i32()? as usize;
-            let x_coords = result[1].as_f32_array()?;
-            let y_coords = result[2].as_f32_array()?;
-            let texts = result[4].as_string_array()?;
-            let colors = result[5].as_u32_array()?;
-            let visible = result[6].as_u32_array()?;
+        let num_points = response.num_points as usize;
+        let x_coords = response.x_coords;
+        let y_coords = response.y_coords;
+        let texts = response.texts;
+        let colors = response.colors;
+        let visible = response.visible;
 
-            let mut points = Vec::with_capacity(num_points);
-            for i in 0..num_points {
-                if i < x_coords.len()
-                    && i < y_coords.len()
-                    && i < texts.len()
-                    && i < colors.len()
-                    && i < visible.len()
-                {
-                    points.push(PointMark {
-                        x_m: x_coords[i],
-                        y_m: y_coords[i],
-                        text: texts[i].clone(),
-                        color: colors[i],
-                        visible: visible[i] != 0,
-                    });
-                }
+        let mut points = Vec::with_capacity(num_points);
+        for i in 0..num_points {
+            if i < x_coords.len()
+                && i < y_coords.len()
+                && i < texts.len()
+                && i < colors.len()
+                && i < visible.len()
+            {
+                points.push(PointMark {
+                    x_m: x_coords[i],
+                    y_m: y_coords[i],
+                    text: texts[i].clone(),
+                    color: colors[i],
+                    visible: visible[i] != 0,
+                });
             }
-            Ok(points)
-        } else {
-            Ok(vec![])
         }
+        Ok(points)
     }
 
     /// Get information about all drawn line marks.
@@ -317,44 +264,35 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn marks_lines_get(&mut self) -> Result<Vec<LineMark>, NanonisError> {
-        let result = self.quick_send(
-            "Marks.LinesGet",
-            vec![],
-            vec![],
-            vec!["i", "*f", "*f", "*f", "*f", "*I", "*I"],
-        )?;
+        let response = self.send(&commands::MarksLinesGet {})?;
 
-        if result.len() >= 7 {
-            let num_lines = result[0].as_i32()? as usize;
-            let start_x = result[1].as_f32_array()?;
-            let start_y = result[2].as_f32_array()?;
-            let end_x = result[3].as_f32_array()?;
-            let end_y = result[4].as_f32_array()?;
-            let colors = result[5].as_u32_array()?;
-            let visible = result[6].as_u32_array()?;
+        let num_lines = response.num_lines as usize;
+        let start_x = response.start_x;
+        let start_y = response.start_y;
+        let end_x = response.end_x;
+        let end_y = response.end_y;
+        let colors = response.colors;
+        let visible = response.visible;
 
-            let mut lines = Vec::with_capacity(num_lines);
-            for i in 0..num_lines {
-                if i < start_x.len()
-                    && i < start_y.len()
-                    && i < end_x.len()
-                    && i < end_y.len()
-                    && i < colors.len()
-                    && i < visible.len()
-                {
-                    lines.push(LineMark {
-                        start_x_m: start_x[i],
-                        start_y_m: start_y[i],
-                        end_x_m: end_x[i],
-                        end_y_m: end_y[i],
-                        color: colors[i],
-                        visible: visible[i] != 0,
-                    });
-                }
+        let mut lines = Vec::with_capacity(num_lines);
+        for i in 0..num_lines {
+            if i < start_x.len()
+                && i < start_y.len()
+                && i < end_x.len()
+                && i < end_y.len()
+                && i < colors.len()
+                && i < visible.len()
+            {
+                lines.push(LineMark {
+                    start_x_m: start_x[i],
+                    start_y_m: start_y[i],
+                    end_x_m: end_x[i],
+                    end_y_m: end_y[i],
+                    color: colors[i],
+                    visible: visible[i] != 0,
+                });
             }
-            Ok(lines)
-        } else {
-            Ok(vec![])
         }
+        Ok(lines)
     }
 }
@@ -1,9 +1,15 @@
 use super::protocol::{Protocol, HEADER_SIZE};
+use crate::command::{Command, CommandResponse};
 use crate::error::NanonisError;
+use crate::recording::Recorder;
+use crate::shared_client::SharedNanonisClient;
+use crate::transport::{write_error, TcpTransport, Transport};
 use crate::types::NanonisValue;
 use log::{debug, warn};
 use std::io::Write;
-use std::net::{SocketAddr, TcpStream};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
 pub mod atom_track;
@@ -36,12 +42,15 @@ pub mod piezo;
 pub mod pll;
 pub mod pll_freq_swp;
 pub mod pll_signal_anlzr;
+pub mod recipe;
 pub mod safe_tip;
 pub mod scan;
 pub mod script;
+pub mod shutdown;
 pub mod signal_chart;
 pub mod signals;
 pub mod spectrum_anlzr;
+pub mod state;
 pub mod tcplog;
 pub mod tip_recovery;
 pub mod user_in;
@@ -92,6 +101,73 @@ impl Default for ConnectionConfig {
     }
 }
 
+/// Reconnect behavior of a [`NanonisClient`] whose connection dropped.
+///
+/// Enabled with [`NanonisClientBuilder::reconnect`]. When a command fails
+/// because the connection was closed or reset, the client re-establishes the
+/// connection, making up to `max_retries` connection attempts. The delay
+/// before each attempt starts at `backoff` and doubles after every failure.
+///
+/// Commands whose name ends in `Get` only read state and are sent again
+/// after reconnecting, up to `max_retries` times. All other commands, and
+/// `Get` commands with side effects such as `Motor.StepCounterGet` with
+/// its reset flags, may already have taken effect, so they are never
+/// repeated: they fail with [`NanonisError::ConnectionLost`] and the next
+/// command reconnects.
+///
+/// # Examples
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use nanonis_rs::{MockResponse, MockServer, NanonisClient, NanonisValue, ReconnectPolicy};
+/// use nanonis_rs::scan::{ScanAction, ScanDirection};
+///
+/// let server = MockServer::start()?;
+/// let calls = AtomicUsize::new(0);
+/// server.on("Bias.Get", move |_| {
+///     // Drop the connection on the first request
+///     if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+///         MockResponse::disconnect()
+///     } else {
+///         MockResponse::values(vec![NanonisValue::F32(0.5)], vec!["f"])
+///     }
+/// });
+///
+/// let mut client = NanonisClient::builder()
+///     .address("127.0.0.1")
+///     .port(server.port())
+///     .reconnect(ReconnectPolicy::default())
+///     .build()?;
+///
+/// assert_eq!(client.bias_get()?, 0.5);
+///
+/// // Commands that change state are not repeated
+/// server.on("Scan.Action", |_| MockResponse::disconnect());
+/// let err = client
+///     .scan_action(ScanAction::Start, ScanDirection::Down)
+///     .unwrap_err();
+/// assert!(err.is_connection_lost());
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Maximum number of connection attempts, and of repeated `*Get` commands
+    pub max_retries: u32,
+    /// Delay before the first connection attempt, doubled after each failure
+    pub backoff: Duration,
+    /// Timeout of each connection attempt
+    pub connect_timeout: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(100),
+            connect_timeout: Duration::from_secs(2),
+        }
+    }
+}
+
 /// Builder for constructing [`NanonisClient`] instances with flexible configuration.
 ///
 /// The builder pattern allows you to configure various aspects of the client
@@ -126,13 +202,15 @@ impl Default for ConnectionConfig {
 ///     .build()?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct NanonisClientBuilder {
     address: Option<String>,
     port: Option<u16>,
     config: ConnectionConfig,
     debug: bool,
-    safe_tip_on_drop: bool,
+    shutdown_policy: Option<shutdown::ShutdownPolicy>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    pool_ports: Vec<u16>,
 }
 
 impl NanonisClientBuilder {
@@ -178,23 +256,109 @@ impl NanonisClientBuilder {
 
     /// Enable automatic tip safety on client drop.
     ///
-    /// When enabled, the client will automatically withdraw the Z-controller
-    /// and move motors to a safe position when dropped. This is a safety feature
-    /// to protect the tip if the program exits unexpectedly.
+    /// When enabled, the client runs the default
+    /// [`ShutdownPolicy`](shutdown::ShutdownPolicy) when dropped: it withdraws
+    /// the Z-controller and retracts the Z motor of group 1 by 15 steps.
+    /// Use [`shutdown_policy`](Self::shutdown_policy) for other setups.
     ///
     /// **Warning**: This will move hardware on every client drop, including normal
     /// program termination. Only enable if you want this behavior.
     ///
     /// Default: `false` (disabled)
     pub fn safe_tip_on_drop(mut self, enabled: bool) -> Self {
-        self.safe_tip_on_drop = enabled;
+        self.shutdown_policy = enabled.then(shutdown::ShutdownPolicy::default);
+        self
+    }
+
+    /// Run `policy` when the client is dropped or
+    /// [`NanonisClient::shutdown`] is called.
+    ///
+    /// Default: none
+    pub fn shutdown_policy(mut self, policy: shutdown::ShutdownPolicy) -> Self {
+        self.shutdown_policy = Some(policy);
+        self
+    }
+
+    /// Reconnect automatically after the connection drops.
+    ///
+    /// See [`ReconnectPolicy`] for which commands are repeated.
+    ///
+    /// Default: disabled
+    pub fn reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = Some(policy);
+        self
+    }
+
+    /// Set the ports of a connection pool built with
+    /// [`build_pool`](Self::build_pool), e.g. `[6501, 6502, 6503, 6504]`.
+    pub fn ports(mut self, ports: impl IntoIterator<Item = u16>) -> Self {
+        self.pool_ports = ports.into_iter().collect();
         self
     }
 
+    /// Build a [`SharedNanonisClient`] with one connection per port.
+    ///
+    /// Every connection uses the settings of this builder. The shutdown
+    /// policy applies to the first connection only. Without [`ports`](Self::ports)
+    /// the pool holds a single connection to [`port`](Self::port).
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{MockServer, NanonisClient};
+    /// use nanonis_rs::scan::{ScanAction, ScanDirection};
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// let server = MockServer::simulated()?;
+    /// let pool = NanonisClient::builder()
+    ///     .address("127.0.0.1")
+    ///     .ports([server.port(), server.port()])
+    ///     .build_pool()?;
+    /// # pool.call(|client| client.scan_buffer_set(vec![0], 64, 8))?;
+    ///
+    /// pool.call(|client| client.scan_action(ScanAction::Start, ScanDirection::Down))?;
+    /// let scan = {
+    ///     let pool = pool.clone();
+    ///     thread::spawn(move || pool.scan_wait_end_of_scan(Duration::from_secs(10)))
+    /// };
+    ///
+    /// // Reads are answered while the scan wait blocks the other connection
+    /// pool.call(|client| client.bias_get())?;
+    /// assert!(!scan.is_finished());
+    /// scan.join().unwrap()?;
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn build_pool(self) -> Result<SharedNanonisClient, NanonisError> {
+        let ports = if self.pool_ports.is_empty() {
+            self.port.into_iter().collect()
+        } else {
+            self.pool_ports.clone()
+        };
+        if ports.is_empty() {
+            return Err(NanonisError::Protocol("Port must be specified".to_string()));
+        }
+
+        let clients = ports
+            .iter()
+            .enumerate()
+            .map(|(index, &port)| {
+                let mut builder = self.clone();
+                builder.port = Some(port);
+                if index > 0 {
+                    builder.shutdown_policy = None;
+                }
+                builder.build()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        SharedNanonisClient::from_clients(clients)
+    }
+
     /// Build the NanonisClient
     pub fn build(self) -> Result<NanonisClient, NanonisError> {
         let address = self
             .address
+            .clone()
             .ok_or_else(|| NanonisError::Protocol("Address must be specified".to_string()))?;
 
         let port = self
@@ -207,34 +371,80 @@ impl NanonisClientBuilder {
 
         debug!("Connecting to Nanonis at {address}");
 
-        let stream = TcpStream::connect_timeout(&socket_addr, self.config.connect_timeout)
-            .map_err(|e| {
-                warn!("Failed to connect to {address}: {e}");
-                if e.kind() == std::io::ErrorKind::TimedOut {
-                    NanonisError::Timeout(format!("Connection to {address} timed out"))
-                } else {
-                    NanonisError::Io {
-                        source: e,
-                        context: format!("Failed to connect to {address}"),
-                    }
-                }
-            })?;
-
-        // Set socket timeouts
-        stream.set_read_timeout(Some(self.config.read_timeout))?;
-        stream.set_write_timeout(Some(self.config.write_timeout))?;
+        let transport = TcpTransport::connect(socket_addr, &self.config)?;
 
         debug!("Successfully connected to Nanonis");
 
-        Ok(NanonisClient {
-            stream,
+        Ok(self.build_with_transport(transport))
+    }
+
+    /// Build the NanonisClient on top of an already open transport.
+    ///
+    /// Address, port and timeouts of this builder are not used; the
+    /// transport applies its own. Reconnecting calls
+    /// [`Transport::reconnect`].
+    pub fn build_with_transport(self, transport: impl Transport + 'static) -> NanonisClient {
+        NanonisClient {
+            stream: Box::new(transport),
+            broken: false,
             debug: self.debug,
             config: self.config,
-            safe_tip_on_drop: self.safe_tip_on_drop,
-        })
+            shutdown_policy: self.shutdown_policy,
+            reconnect_policy: self.reconnect_policy,
+            recorder: None,
+            signal_registry: None,
+            offload: None,
+        }
     }
 }
 
+/// Whether `error` means the connection is gone and must be re-established.
+pub(crate) fn is_connection_error(error: &NanonisError) -> bool {
+    use std::io::ErrorKind;
+
+    match error {
+        NanonisError::Io { source, .. } => matches!(
+            source.kind(),
+            ErrorKind::BrokenPipe
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::NotConnected
+                | ErrorKind::UnexpectedEof
+        ),
+        _ => false,
+    }
+}
+
+/// `*Get` commands that also change state and must not be sent twice.
+const NON_IDEMPOTENT_GETS: &[&str] = &[
+    // Optionally resets the step counters
+    "Motor.StepCounterGet",
+];
+
+/// Whether `command` only reads state and can safely be sent twice.
+fn is_idempotent(command: &str) -> bool {
+    !NON_IDEMPOTENT_GETS.contains(&command)
+        && command
+            .rsplit('.')
+            .next()
+            .is_some_and(|name| name.ends_with("Get"))
+}
+
+/// Runs a blocking command on another connection of a pool, see
+/// [`SharedNanonisClient`]. The client passed in may serve other calls
+/// meanwhile.
+pub(crate) type Offload = Arc<
+    dyn Fn(
+            &mut NanonisClient,
+            &str,
+            Vec<NanonisValue>,
+            &[&str],
+            &[&str],
+        ) -> Result<Vec<NanonisValue>, NanonisError>
+        + Send
+        + Sync,
+>;
+
 /// High-level client for communicating with Nanonis SPM systems via TCP.
 ///
 /// `NanonisClient` provides a type-safe, Rust-friendly interface to the Nanonis
@@ -260,7 +470,7 @@ impl NanonisClientBuilder {
 ///
 /// Basic usage:
 /// ```no_run
-/// use nanonis_rs::NanonisClient;
+/// use nanonis_rs::{NanonisClient, Volts};
 ///
 /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
 ///
@@ -268,7 +478,7 @@ impl NanonisClientBuilder {
 /// let signals = client.signal_names_get()?;
 ///
 /// // Set bias voltage
-/// client.bias_set(1.0)?;
+/// client.bias_set(Volts::new(1.0))?;
 ///
 /// // Read signal values
 /// let values = client.signals_vals_get(vec![0, 1, 2], true)?;
@@ -289,10 +499,15 @@ impl NanonisClientBuilder {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub struct NanonisClient {
-    stream: TcpStream,
+    stream: Box<dyn Transport>,
+    broken: bool,
     debug: bool,
     config: ConnectionConfig,
-    safe_tip_on_drop: bool,
+    shutdown_policy: Option<shutdown::ShutdownPolicy>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    recorder: Option<Recorder>,
+    signal_registry: Option<signals::SignalRegistry>,
+    offload: Option<Offload>,
 }
 
 impl NanonisClient {
@@ -350,6 +565,16 @@ impl NanonisClient {
         NanonisClientBuilder::default()
     }
 
+    /// Create a client that talks over a custom [`Transport`].
+    ///
+    /// Use this to route traffic through a tunnel or relay, or to test with
+    /// a [`MemoryTransport`](crate::MemoryTransport). See
+    /// [`NanonisClientBuilder::build_with_transport`] to combine a custom
+    /// transport with other builder settings.
+    pub fn from_transport(transport: impl Transport + 'static) -> Self {
+        Self::builder().build_with_transport(transport)
+    }
+
     /// Create a new client with custom configuration (legacy method).
     ///
     /// **Deprecated**: Use [`NanonisClient::builder()`] instead for more flexibility.
@@ -371,11 +596,75 @@ impl NanonisClient {
         &self.config
     }
 
+    /// Record all traffic of this client to a file.
+    ///
+    /// Every request/response pair sent through [`quick_send`](Self::quick_send)
+    /// is appended as one line with header, body and timestamp. Load the file
+    /// with [`Recording::load`](crate::Recording::load) to replay it offline.
+    /// An existing file is truncated. Write failures during recording are
+    /// logged and do not fail the command.
+    pub fn record_to(&mut self, path: impl AsRef<Path>) -> Result<(), NanonisError> {
+        self.recorder = Some(Recorder::create(path.as_ref())?);
+        Ok(())
+    }
+
+    /// Stop recording and close the recording file.
+    pub fn stop_recording(&mut self) {
+        self.recorder = None;
+    }
+
+    /// Hand blocking commands to `offload` instead of sending them here.
+    pub(crate) fn set_offload(&mut self, offload: Offload) {
+        self.offload = Some(offload);
+    }
+
+    /// Re-establish a dropped connection according to the reconnect policy.
+    fn reconnect(&mut self) -> Result<(), NanonisError> {
+        let Some(policy) = self.reconnect_policy.clone() else {
+            return Err(NanonisError::Protocol(
+                "Connection lost and no reconnect policy configured".to_string(),
+            ));
+        };
+
+        let mut delay = policy.backoff;
+        let mut last_error = None;
+        for attempt in 1..=policy.max_retries.max(1) {
+            std::thread::sleep(delay);
+            debug!("Reconnecting to Nanonis (attempt {attempt})");
+
+            match self.stream.reconnect(policy.connect_timeout) {
+                Ok(()) => {
+                    self.broken = false;
+                    debug!("Reconnected to Nanonis");
+                    return Ok(());
+                }
+                Err(e) => {
+                    warn!("Reconnect attempt {attempt} failed: {e}");
+                    last_error = Some(e);
+                }
+            }
+            delay *= 2;
+        }
+
+        Err(last_error.map_or_else(
+            || NanonisError::Protocol("Failed to reconnect to Nanonis".to_string()),
+            |e| NanonisError::Io {
+                source: e,
+                context: "Reconnecting to Nanonis".to_string(),
+            },
+        ))
+    }
+
     /// Send a quick command with minimal response handling.
     ///
     /// This is a low-level method for sending custom commands that don't fit
     /// the standard method patterns. Most users should use the specific
     /// command methods instead.
+    ///
+    /// Raw commands are never recognized as blocking, so inside a
+    /// [`SharedNanonisClient`] pool they occupy the connection they are sent
+    /// on. Send blocking commands through their descriptor with
+    /// [`send`](Self::send), see [`Command::blocks`].
     pub fn quick_send(
         &mut self,
         command: &str,
@@ -383,6 +672,25 @@ impl NanonisClient {
         argument_types: Vec<&str>,
         return_types: Vec<&str>,
     ) -> Result<Vec<NanonisValue>, NanonisError> {
+        self.dispatch(command, args, argument_types, return_types, false)
+    }
+
+    /// Send a command, handing it to the offload if it is `blocking`.
+    fn dispatch(
+        &mut self,
+        command: &str,
+        args: Vec<NanonisValue>,
+        argument_types: Vec<&str>,
+        return_types: Vec<&str>,
+        blocking: bool,
+    ) -> Result<Vec<NanonisValue>, NanonisError> {
+        if let Some(offload) = self.offload.clone() {
+            if blocking {
+                debug!("Running {command} on a blocking connection");
+                return offload(self, command, args, &argument_types, &return_types);
+            }
+        }
+
         debug!("=== COMMAND START: {} ===", command);
         debug!("Arguments: {:?}", args);
         debug!("Argument types: {:?}", argument_types);
@@ -413,24 +721,59 @@ impl NanonisClient {
             debug!("Body bytes: {:02x?}", body);
         }
 
+        let mut attempt = 0;
+        loop {
+            if self.broken {
+                self.reconnect()?;
+            }
+
+            match self.exchange(command, &header, &body, &return_types) {
+                Err(e) if self.reconnect_policy.is_some() && is_connection_error(&e) => {
+                    self.broken = true;
+                    warn!("Connection lost during {command}: {e}");
+
+                    if !is_idempotent(command) {
+                        return Err(match e {
+                            NanonisError::Io { source, .. } => NanonisError::ConnectionLost {
+                                command: command.to_string(),
+                                source,
+                            },
+                            other => other,
+                        });
+                    }
+
+                    let max_retries = self.reconnect_policy.as_ref().map_or(0, |p| p.max_retries);
+                    if attempt >= max_retries {
+                        return Err(e);
+                    }
+                    attempt += 1;
+                    debug!("Retrying {command} (attempt {attempt}/{max_retries})");
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Write one serialized command and read its reply.
+    fn exchange(
+        &mut self,
+        command: &str,
+        header: &[u8],
+        body: &[u8],
+        return_types: &[&str],
+    ) -> Result<Vec<NanonisValue>, NanonisError> {
         // Send command
         debug!("Sending header ({} bytes)...", header.len());
-        self.stream.write_all(&header).map_err(|e| {
+        self.stream.write_all(header).map_err(|e| {
             debug!("Failed to write header: {}", e);
-            NanonisError::Io {
-                source: e,
-                context: "Writing command header".to_string(),
-            }
+            write_error(e, "Writing command header")
         })?;
 
         if !body.is_empty() {
             debug!("Sending body ({} bytes)...", body.len());
-            self.stream.write_all(&body).map_err(|e| {
+            self.stream.write_all(body).map_err(|e| {
                 debug!("Failed to write body: {}", e);
-                NanonisError::Io {
-                    source: e,
-                    context: "Writing command body".to_string(),
-                }
+                write_error(e, "Writing command body")
             })?;
         }
 
@@ -477,9 +820,13 @@ impl NanonisClient {
             Vec::new()
         };
 
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(command, header, body, &response_header, &response_body);
+        }
+
         // Parse response with error checking
         debug!("Parsing response with types: {:?}", return_types);
-        let result = Protocol::parse_response_with_error_check(&response_body, &return_types)
+        let result = Protocol::parse_response_with_error_check(&response_body, return_types)
             .map_err(|e| {
                 debug!("Failed to parse response: {}", e);
                 e
@@ -492,12 +839,35 @@ impl NanonisClient {
     }
 }
 
+impl NanonisClient {
+    /// Send a typed command and decode its response.
+    ///
+    /// The argument and response type codes come from the command
+    /// declaration, see [`nanonis_command!`](crate::nanonis_command).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::bias::commands::BiasGet;
+    ///
+    /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
+    /// let bias = client.send(&BiasGet {})?.voltage;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn send<C: Command>(&mut self, command: &C) -> Result<C::Response, NanonisError> {
+        let values = self.dispatch(
+            C::NAME,
+            command.args(),
+            C::ARG_TYPES.to_vec(),
+            C::RESPONSE_TYPES.to_vec(),
+            command.blocks(),
+        )?;
+        C::Response::from_values(values)
+    }
+}
+
 impl Drop for NanonisClient {
     fn drop(&mut self) {
-        if self.safe_tip_on_drop {
-            use motor::{MotorDirection, MotorGroup};
-            let _ = self.z_ctrl_withdraw(false, Duration::from_secs(2));
-            let _ = self.motor_start_move(MotorDirection::ZMinus, 15u16, MotorGroup::Group1, false);
-        }
+        self.shutdown();
     }
 }
@@ -0,0 +1,101 @@
+//! Typed descriptors for the `Motor.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Motor.StartMove`: move the coarse positioning device (motor, piezo actuator).
+    pub struct MotorStartMove: "Motor.StartMove" blocking if wait_until_finished {
+        /// Direction: 0 = X+, 1 = X-, 2 = Y+, 3 = Y-, 4 = Z+, 5 = Z-
+        pub direction: u32 = "I",
+        /// Number of steps to move
+        pub number_of_steps: u16 = "H",
+        /// Motor group (0-5)
+        pub group: u32 = "I",
+        /// 1 to wait until the move finishes
+        pub wait_until_finished: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Motor.StartClosedLoop`: move the coarse positioning device in closed loop.
+    pub struct MotorStartClosedLoop: "Motor.StartClosedLoop" blocking if wait_until_finished {
+        /// 0 = relative, 1 = absolute
+        pub movement_mode: u32 = "I",
+        /// Target X in meters
+        pub x: f64 = "d",
+        /// Target Y in meters
+        pub y: f64 = "d",
+        /// Target Z in meters
+        pub z: f64 = "d",
+        /// 1 to wait until the move finishes
+        pub wait_until_finished: u32 = "I",
+        /// Motor group (0-5)
+        pub group: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Motor.StopMove`: stop the motor motion.
+    pub struct MotorStopMove: "Motor.StopMove" {} -> ()
+}
+
+nanonis_command! {
+    /// `Motor.PosGet`: get the positions of the motor control module.
+    pub struct MotorPosGet: "Motor.PosGet" {
+        /// Motor group (0-5)
+        pub group: u32 = "I",
+        /// Timeout in milliseconds
+        pub timeout: u32 = "I",
+    } -> MotorPosGetResponse {
+        /// X position in meters
+        pub x: f64 = "d",
+        /// Y position in meters
+        pub y: f64 = "d",
+        /// Z position in meters
+        pub z: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `Motor.StepCounterGet`: get step counter values and optionally reset them (Attocube ANC150 only).
+    pub struct MotorStepCounterGet: "Motor.StepCounterGet" {
+        /// 1 to reset the X counter
+        pub reset_x: u32 = "I",
+        /// 1 to reset the Y counter
+        pub reset_y: u32 = "I",
+        /// 1 to reset the Z counter
+        pub reset_z: u32 = "I",
+    } -> MotorStepCounterGetResponse {
+        /// X step count
+        pub step_x: i32 = "i",
+        /// Y step count
+        pub step_y: i32 = "i",
+        /// Z step count
+        pub step_z: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `Motor.FreqAmpGet`: get frequency and amplitude of the motor control module (PD5, PMD4 and Attocube ANC150 only).
+    pub struct MotorFreqAmpGet: "Motor.FreqAmpGet" {
+        /// Axis: 0 = all, 1 = X, 2 = Y, 3 = Z
+        pub axis: u16 = "H",
+    } -> MotorFreqAmpGetResponse {
+        /// Frequency in Hz
+        pub frequency: f32 = "f",
+        /// Amplitude in volts
+        pub amplitude: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Motor.FreqAmpSet`: set frequency and amplitude of the motor control module (PD5, PMD4 and Attocube ANC150 only).
+    pub struct MotorFreqAmpSet: "Motor.FreqAmpSet" {
+        /// Frequency in Hz
+        pub frequency: f32 = "f",
+        /// Amplitude in volts
+        pub amplitude: f32 = "f",
+        /// Axis: 0 = all, 1 = X, 2 = Y, 3 = Z
+        pub axis: u16 = "H",
+    } -> ()
+}
@@ -1,9 +1,10 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 use std::time::Duration;
 
 impl NanonisClient {
@@ -16,17 +17,12 @@ impl NanonisClient {
         wait_until_finished: bool,
     ) -> Result<(), NanonisError> {
         let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
-        self.quick_send(
-            "Motor.StartMove",
-            vec![
-                NanonisValue::U32(direction.into().into()),
-                NanonisValue::U16(number_of_steps.into()),
-                NanonisValue::U32(group.into().into()),
-                NanonisValue::U32(wait_flag),
-            ],
-            vec!["I", "H", "I", "I"],
-            vec![],
-        )?;
+        self.send(&commands::MotorStartMove {
+            direction: direction.into().into(),
+            number_of_steps: number_of_steps.into(),
+            group: group.into().into(),
+            wait_until_finished: wait_flag,
+        })?;
         Ok(())
     }
 
@@ -39,25 +35,20 @@ impl NanonisClient {
         group: MotorGroup,
     ) -> Result<(), NanonisError> {
         let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
-        self.quick_send(
-            "Motor.StartClosedLoop",
-            vec![
-                NanonisValue::U32(movement_mode.into()),
-                NanonisValue::F64(target_position.x),
-                NanonisValue::F64(target_position.y),
-                NanonisValue::F64(target_position.z),
-                NanonisValue::U32(wait_flag),
-                NanonisValue::U32(group.into()),
-            ],
-            vec!["I", "d", "d", "d", "I", "I"],
-            vec![],
-        )?;
+        self.send(&commands::MotorStartClosedLoop {
+            movement_mode: movement_mode.into(),
+            x: target_position.x,
+            y: target_position.y,
+            z: target_position.z,
+            wait_until_finished: wait_flag,
+            group: group.into(),
+        })?;
         Ok(())
     }
 
     /// Stop the motor motion
     pub fn motor_stop_move(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Motor.StopMove", vec![], vec![], vec![])?;
+        self.send(&commands::MotorStopMove {})?;
         Ok(())
     }
 
@@ -67,26 +58,15 @@ impl NanonisClient {
         group: MotorGroup,
         timeout: Duration,
     ) -> Result<Position3D, NanonisError> {
-        let result = self.quick_send(
-            "Motor.PosGet",
-            vec![
-                NanonisValue::U32(group.into()),
-                NanonisValue::U32(timeout.as_millis() as u32),
-            ],
-            vec!["I", "I"],
-            vec!["d", "d", "d"],
-        )?;
+        let response = self.send(&commands::MotorPosGet {
+            group: group.into(),
+            timeout: timeout.as_millis() as u32,
+        })?;
 
-        if result.len() >= 3 {
-            let x = result[0].as_f64()?;
-            let y = result[1].as_f64()?;
-            let z = result[2].as_f64()?;
-            Ok(Position3D::new(x, y, z))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid motor position response".to_string(),
-            ))
-        }
+        let x = response.x;
+        let y = response.y;
+        let z = response.z;
+        Ok(Position3D::new(x, y, z))
     }
 
     /// Get step counter values and optionally reset them
@@ -101,27 +81,13 @@ impl NanonisClient {
         let reset_y_flag = if reset_y { 1u32 } else { 0u32 };
         let reset_z_flag = if reset_z { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "Motor.StepCounterGet",
-            vec![
-                NanonisValue::U32(reset_x_flag),
-                NanonisValue::U32(reset_y_flag),
-                NanonisValue::U32(reset_z_flag),
-            ],
-            vec!["I", "I", "I"],
-            vec!["i", "i", "i"],
-        )?;
+        let response = self.send(&commands::MotorStepCounterGet {
+            reset_x: reset_x_flag,
+            reset_y: reset_y_flag,
+            reset_z: reset_z_flag,
+        })?;
 
-        if result.len() >= 3 {
-            let step_x = result[0].as_i32()?;
-            let step_y = result[1].as_i32()?;
-            let step_z = result[2].as_i32()?;
-            Ok((step_x, step_y, step_z))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid step counter response".to_string(),
-            ))
-        }
+        Ok((response.step_x, response.step_y, response.step_z))
     }
 
     /// Get frequency and amplitude of the motor control module
@@ -130,22 +96,11 @@ impl NanonisClient {
         &mut self,
         axis: MotorAxis,
     ) -> Result<(Frequency, Amplitude), NanonisError> {
-        let result = self.quick_send(
-            "Motor.FreqAmpGet",
-            vec![NanonisValue::U16(axis.into())],
-            vec!["H"],
-            vec!["f", "f"],
-        )?;
+        let response = self.send(&commands::MotorFreqAmpGet { axis: axis.into() })?;
 
-        if result.len() >= 2 {
-            let frequency = Frequency::hz(result[0].as_f32()?);
-            let amplitude = Amplitude::volts(result[1].as_f32()?);
-            Ok((frequency, amplitude))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid frequency/amplitude response".to_string(),
-            ))
-        }
+        let frequency = Frequency::hz(response.frequency);
+        let amplitude = Amplitude::volts(response.amplitude);
+        Ok((frequency, amplitude))
     }
 
     /// Set frequency and amplitude of the motor control module
@@ -156,16 +111,11 @@ impl NanonisClient {
         amplitude: impl Into<Amplitude>,
         axis: impl Into<MotorAxis>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Motor.FreqAmpSet",
-            vec![
-                NanonisValue::F32(frequency.into().into()),
-                NanonisValue::F32(amplitude.into().into()),
-                NanonisValue::U16(axis.into().into()),
-            ],
-            vec!["f", "f", "H"],
-            vec![],
-        )?;
+        self.send(&commands::MotorFreqAmpSet {
+            frequency: frequency.into().into(),
+            amplitude: amplitude.into().into(),
+            axis: axis.into().into(),
+        })?;
         Ok(())
     }
 }
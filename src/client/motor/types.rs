@@ -1,4 +1,5 @@
 use crate::error::NanonisError;
+use crate::units::{Hertz, Volts};
 
 // ==================== Motor Control Types ====================
 
@@ -120,6 +121,12 @@ impl From<f64> for Frequency {
     }
 }
 
+impl From<Hertz> for Frequency {
+    fn from(freq: Hertz) -> Self {
+        Frequency(freq.as_f32())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Amplitude(pub f32);
 
@@ -147,6 +154,12 @@ impl From<f64> for Amplitude {
     }
 }
 
+impl From<Volts> for Amplitude {
+    fn from(amp: Volts) -> Self {
+        Amplitude(amp.as_f32())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MotorAxis {
     All = 0,
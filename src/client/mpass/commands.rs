@@ -0,0 +1,27 @@
+//! Typed descriptors for the `MPass.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `MPass.Activate`: activate or deactivate Multi-Pass in the Scan Control module.
+    pub struct MPassActivate: "MPass.Activate" {
+        /// True to activate, false to deactivate
+        pub on: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `MPass.Load`: load a Multi-Pass configuration file (.mpas).
+    pub struct MPassLoad: "MPass.Load" {
+        /// Path to the .mpas file (empty to load from session)
+        pub file_path: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `MPass.Save`: save the current Multi-Pass configuration to a file (.mpas).
+    pub struct MPassSave: "MPass.Save" {
+        /// Path to save the .mpas file (empty to save to session)
+        pub file_path: String = "+*c",
+    } -> ()
+}
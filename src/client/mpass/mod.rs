@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     // ==================== Multi-Pass ====================
@@ -13,12 +14,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn mpass_activate(&mut self, on: bool) -> Result<(), NanonisError> {
-        self.quick_send(
-            "MPass.Activate",
-            vec![NanonisValue::U32(if on { 1 } else { 0 })],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::MPassActivate { on: if on { 1 } else { 0 } })?;
         Ok(())
     }
 
@@ -30,12 +26,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn mpass_load(&mut self, file_path: &str) -> Result<(), NanonisError> {
-        self.quick_send(
-            "MPass.Load",
-            vec![NanonisValue::String(file_path.to_string())],
-            vec!["+*c"],
-            vec![],
-        )?;
+        self.send(&commands::MPassLoad { file_path: file_path.to_string() })?;
         Ok(())
     }
 
@@ -47,12 +38,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn mpass_save(&mut self, file_path: &str) -> Result<(), NanonisError> {
-        self.quick_send(
-            "MPass.Save",
-            vec![NanonisValue::String(file_path.to_string())],
-            vec!["+*c"],
-            vec![],
-        )?;
+        self.send(&commands::MPassSave { file_path: file_path.to_string() })?;
         Ok(())
     }
 }
@@ -0,0 +1,51 @@
+//! Typed descriptors for the `OCSync.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `OCSync.AnglesSet`: set the angle values for digital channels 1 and 2.
+    pub struct OcSyncAnglesSet: "OCSync.AnglesSet" {
+        /// Channel 1 on angle (degrees)
+        pub ch1_on_deg: f32 = "f",
+        /// Channel 1 off angle (degrees)
+        pub ch1_off_deg: f32 = "f",
+        /// Channel 2 on angle (degrees)
+        pub ch2_on_deg: f32 = "f",
+        /// Channel 2 off angle (degrees)
+        pub ch2_off_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OCSync.AnglesGet`: get the angle values for digital channels 1 and 2.
+    pub struct OcSyncAnglesGet: "OCSync.AnglesGet" {} -> OcSyncAnglesGetResponse {
+        /// Channel 1 on angle (degrees)
+        pub ch1_on_deg: f32 = "f",
+        /// Channel 1 off angle (degrees)
+        pub ch1_off_deg: f32 = "f",
+        /// Channel 2 on angle (degrees)
+        pub ch2_on_deg: f32 = "f",
+        /// Channel 2 off angle (degrees)
+        pub ch2_off_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `OCSync.LinkAnglesSet`: set the link angles status for channels 1 and 2.
+    pub struct OcSyncLinkAnglesSet: "OCSync.LinkAnglesSet" {
+        /// Channel 1 link mode
+        pub ch1_mode: u32 = "I",
+        /// Channel 2 link mode
+        pub ch2_mode: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OCSync.LinkAnglesGet`: get the link angles status for channels 1 and 2.
+    pub struct OcSyncLinkAnglesGet: "OCSync.LinkAnglesGet" {} -> OcSyncLinkAnglesGetResponse {
+        /// Channel 1 link status (true = linked)
+        pub ch1_linked: u32 = "I",
+        /// Channel 2 link status (true = linked)
+        pub ch2_linked: u32 = "I",
+    }
+}
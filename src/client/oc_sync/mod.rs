@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Link angles mode for OC Sync module.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -53,17 +54,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn oc_sync_angles_set(&mut self, angles: &OCSyncAngles) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OCSync.AnglesSet",
-            vec![
-                NanonisValue::F32(angles.ch1_on_deg),
-                NanonisValue::F32(angles.ch1_off_deg),
-                NanonisValue::F32(angles.ch2_on_deg),
-                NanonisValue::F32(angles.ch2_off_deg),
-            ],
-            vec!["f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::OcSyncAnglesSet {
+            ch1_on_deg: angles.ch1_on_deg,
+            ch1_off_deg: angles.ch1_off_deg,
+            ch2_on_deg: angles.ch2_on_deg,
+            ch2_off_deg: angles.ch2_off_deg,
+        })?;
         Ok(())
     }
 
@@ -75,14 +71,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn oc_sync_angles_get(&mut self) -> Result<OCSyncAngles, NanonisError> {
-        let result =
-            self.quick_send("OCSync.AnglesGet", vec![], vec![], vec!["f", "f", "f", "f"])?;
+        let response = self.send(&commands::OcSyncAnglesGet {})?;
 
         Ok(OCSyncAngles {
-            ch1_on_deg: result[0].as_f32()?,
-            ch1_off_deg: result[1].as_f32()?,
-            ch2_on_deg: result[2].as_f32()?,
-            ch2_off_deg: result[3].as_f32()?,
+            ch1_on_deg: response.ch1_on_deg,
+            ch1_off_deg: response.ch1_off_deg,
+            ch2_on_deg: response.ch2_on_deg,
+            ch2_off_deg: response.ch2_off_deg,
         })
     }
 
@@ -101,15 +96,10 @@ impl NanonisClient {
         ch1_mode: LinkAnglesMode,
         ch2_mode: LinkAnglesMode,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OCSync.LinkAnglesSet",
-            vec![
-                NanonisValue::U32(ch1_mode.into()),
-                NanonisValue::U32(ch2_mode.into()),
-            ],
-            vec!["I", "I"],
-            vec![],
-        )?;
+        self.send(&commands::OcSyncLinkAnglesSet {
+            ch1_mode: ch1_mode.into(),
+            ch2_mode: ch2_mode.into(),
+        })?;
         Ok(())
     }
 
@@ -121,11 +111,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn oc_sync_link_angles_get(&mut self) -> Result<OCSyncLinkStatus, NanonisError> {
-        let result = self.quick_send("OCSync.LinkAnglesGet", vec![], vec![], vec!["I", "I"])?;
+        let response = self.send(&commands::OcSyncLinkAnglesGet {})?;
 
         Ok(OCSyncLinkStatus {
-            ch1_linked: result[0].as_u32()? != 0,
-            ch2_linked: result[1].as_u32()? != 0,
+            ch1_linked: response.ch1_linked != 0,
+            ch2_linked: response.ch2_linked != 0,
         })
     }
 }
@@ -0,0 +1,582 @@
+//! Typed descriptors for the `Osci1T.*`, `Osci2T.*` and `OsciHR.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Osci1T.ChSet`: set the channel displayed in the Oscilloscope 1-Channel.
+    pub struct Osci1TChSet: "Osci1T.ChSet" {
+        /// Signal slot index (0-23)
+        pub channel_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci1T.ChGet`: get the channel displayed in the Oscilloscope 1-Channel.
+    pub struct Osci1TChGet: "Osci1T.ChGet" {} -> Osci1TChGetResponse {
+        /// Signal slot index (0-23)
+        pub channel_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `Osci1T.TimebaseSet`: set the timebase of the Oscilloscope 1-Channel.
+    pub struct Osci1TTimebaseSet: "Osci1T.TimebaseSet" {
+        /// Index into the available timebases
+        pub timebase_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci1T.TimebaseGet`: get the timebase of the Oscilloscope 1-Channel.
+    pub struct Osci1TTimebaseGet: "Osci1T.TimebaseGet" {} -> Osci1TTimebaseGetResponse {
+        /// Index of the selected timebase
+        pub timebase_index: i32 = "i",
+        /// Number of timebases
+        pub num_timebases: i32 = "i",
+        /// Available timebases in seconds
+        pub timebases: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `Osci1T.TrigSet`: set the trigger configuration of the Oscilloscope 1-Channel.
+    pub struct Osci1TTrigSet: "Osci1T.TrigSet" {
+        /// 0 = immediate, 1 = level, 2 = auto
+        pub trigger_mode: u16 = "H",
+        /// 0 = falling, 1 = rising
+        pub trigger_slope: u16 = "H",
+        /// Trigger level
+        pub trigger_level: f64 = "d",
+        /// Trigger hysteresis
+        pub trigger_hysteresis: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci1T.TrigGet`: get the trigger configuration of the Oscilloscope 1-Channel.
+    pub struct Osci1TTrigGet: "Osci1T.TrigGet" {} -> Osci1TTrigGetResponse {
+        /// 0 = immediate, 1 = level, 2 = auto
+        pub trigger_mode: u16 = "H",
+        /// 0 = falling, 1 = rising
+        pub trigger_slope: u16 = "H",
+        /// Trigger level
+        pub trigger_level: f64 = "d",
+        /// Trigger hysteresis
+        pub trigger_hysteresis: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `Osci1T.Run`: start the Oscilloscope 1-Channel.
+    pub struct Osci1TRun: "Osci1T.Run" {} -> ()
+}
+
+nanonis_command! {
+    /// `Osci1T.DataGet`: get the graph data of the Oscilloscope 1-Channel.
+    pub struct Osci1TDataGet: "Osci1T.DataGet" blocking if data_to_get {
+        /// 0 = current, 1 = next trigger, 2 = wait 2 triggers
+        pub data_to_get: u16 = "H",
+    } -> Osci1TDataGetResponse {
+        /// Timestamp of the first sample
+        pub t0: f64 = "d",
+        /// Time between samples in seconds
+        pub dt: f64 = "d",
+        /// Number of samples
+        pub size: i32 = "i",
+        /// Sample values
+        pub data: Vec<f64> = "*d",
+    }
+}
+
+nanonis_command! {
+    /// `Osci2T.ChSet`: set the channels displayed in the Oscilloscope 2-Channels.
+    pub struct Osci2TChSet: "Osci2T.ChSet" {
+        /// Signal slot index (0-23) of channel A
+        pub channel_a_index: i32 = "i",
+        /// Signal slot index (0-23) of channel B
+        pub channel_b_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci2T.ChGet`: get the channels displayed in the Oscilloscope 2-Channels.
+    pub struct Osci2TChGet: "Osci2T.ChGet" {} -> Osci2TChGetResponse {
+        /// Signal slot index (0-23) of channel A
+        pub channel_a: i32 = "i",
+        /// Signal slot index (0-23) of channel B
+        pub channel_b: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `Osci2T.TimebaseSet`: set the timebase of the Oscilloscope 2-Channels.
+    pub struct Osci2TTimebaseSet: "Osci2T.TimebaseSet" {
+        /// Index into the available timebases
+        pub timebase_index: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci2T.TimebaseGet`: get the timebase of the Oscilloscope 2-Channels.
+    pub struct Osci2TTimebaseGet: "Osci2T.TimebaseGet" {} -> Osci2TTimebaseGetResponse {
+        /// Index of the selected timebase
+        pub timebase_index: u16 = "H",
+        /// Number of timebases
+        pub num_timebases: i32 = "i",
+        /// Available timebases in seconds
+        pub timebases: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `Osci2T.OversamplSet`: set the oversampling of the Oscilloscope 2-Channels.
+    pub struct Osci2TOversamplSet: "Osci2T.OversamplSet" {
+        /// 0 = 50 samples, 1 = 20, 2 = 10, 3 = 5, 4 = 2, 5 = 1 sample
+        pub oversampling_index: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci2T.OversamplGet`: get the oversampling of the Oscilloscope 2-Channels.
+    pub struct Osci2TOversamplGet: "Osci2T.OversamplGet" {} -> Osci2TOversamplGetResponse {
+        /// 0 = 50 samples, 1 = 20, 2 = 10, 3 = 5, 4 = 2, 5 = 1 sample
+        pub oversampling_index: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `Osci2T.TrigSet`: set the trigger configuration of the Oscilloscope 2-Channels.
+    pub struct Osci2TTrigSet: "Osci2T.TrigSet" {
+        /// 0 = immediate, 1 = level, 2 = auto
+        pub trigger_mode: u16 = "H",
+        /// Trigger channel: 0 = A, 1 = B
+        pub trig_channel: u16 = "H",
+        /// 0 = falling, 1 = rising
+        pub trigger_slope: u16 = "H",
+        /// Trigger level
+        pub trigger_level: f64 = "d",
+        /// Trigger hysteresis
+        pub trigger_hysteresis: f64 = "d",
+        /// Trigger position in seconds
+        pub trig_position: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Osci2T.TrigGet`: get the trigger configuration of the Oscilloscope 2-Channels.
+    pub struct Osci2TTrigGet: "Osci2T.TrigGet" {} -> Osci2TTrigGetResponse {
+        /// 0 = immediate, 1 = level, 2 = auto
+        pub trigger_mode: u16 = "H",
+        /// Trigger channel: 0 = A, 1 = B
+        pub trig_channel: u16 = "H",
+        /// 0 = falling, 1 = rising
+        pub trigger_slope: u16 = "H",
+        /// Trigger level
+        pub trigger_level: f64 = "d",
+        /// Trigger hysteresis
+        pub trigger_hysteresis: f64 = "d",
+        /// Trigger position in seconds
+        pub trig_position: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `Osci2T.Run`: start the Oscilloscope 2-Channels.
+    pub struct Osci2TRun: "Osci2T.Run" {} -> ()
+}
+
+nanonis_command! {
+    /// `Osci2T.DataGet`: get the graph data of the Oscilloscope 2-Channels.
+    pub struct Osci2TDataGet: "Osci2T.DataGet" blocking if data_to_get {
+        /// 0 = current, 1 = next trigger, 2 = wait 2 triggers
+        pub data_to_get: u16 = "H",
+    } -> Osci2TDataGetResponse {
+        /// Timestamp of the first sample
+        pub t0: f64 = "d",
+        /// Time between samples in seconds
+        pub dt: f64 = "d",
+        /// Number of channel A samples
+        pub num_channel_a_data: i32 = "i",
+        /// Channel A samples
+        pub channel_a_data: Vec<f64> = "*d",
+        /// Number of channel B samples
+        pub num_channel_b_data: i32 = "i",
+        /// Channel B samples
+        pub channel_b_data: Vec<f64> = "*d",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.ChSet`: set the measured signal index of the selected channel from the Oscilloscope High Resolution.
+    pub struct OsciHrChSet: "OsciHR.ChSet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// Signal slot index (0-23)
+        pub signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.ChGet`: get the measured signal index of the selected channel from the Oscilloscope High Resolution.
+    pub struct OsciHrChGet: "OsciHR.ChGet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> OsciHrChGetResponse {
+        /// Signal slot index (0-23)
+        pub signal_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.OversamplSet`: set the oversampling index of the Oscilloscope High Resolution.
+    pub struct OsciHrOversamplSet: "OsciHR.OversamplSet" {
+        /// Index of the oversampling factor
+        pub oversampling_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.OversamplGet`: get the oversampling index of the Oscilloscope High Resolution.
+    pub struct OsciHrOversamplGet: "OsciHR.OversamplGet" {} -> OsciHrOversamplGetResponse {
+        /// Index of the oversampling factor
+        pub oversampling_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.CalibrModeSet`: set the calibration mode of the selected channel of the Oscilloscope High Resolution.
+    pub struct OsciHrCalibrModeSet: "OsciHR.CalibrModeSet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// 0 = raw values, 1 = calibrated values
+        pub calibration_mode: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.CalibrModeGet`: get the calibration mode of the selected channel of the Oscilloscope High Resolution.
+    pub struct OsciHrCalibrModeGet: "OsciHR.CalibrModeGet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> OsciHrCalibrModeGetResponse {
+        /// 0 = raw values, 1 = calibrated values
+        pub calibration_mode: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.SamplesSet`: set the number of samples to acquire in the Oscilloscope High Resolution.
+    pub struct OsciHrSamplesSet: "OsciHR.SamplesSet" {
+        /// Number of samples to acquire
+        pub number_of_samples: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.SamplesGet`: get the number of samples to acquire in the Oscilloscope High Resolution.
+    pub struct OsciHrSamplesGet: "OsciHR.SamplesGet" {} -> OsciHrSamplesGetResponse {
+        /// Number of samples to acquire
+        pub number_of_samples: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.PreTrigSet`: set the Pre-Trigger Samples or Seconds in the Oscilloscope High Resolution.
+    pub struct OsciHrPreTrigSet: "OsciHR.PreTrigSet" {
+        /// Pre-trigger samples
+        pub pre_trigger_samples: u32 = "I",
+        /// Pre-trigger time in seconds
+        pub pre_trigger_s: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PreTrigGet`: get the Pre-Trigger Samples in the Oscilloscope High Resolution.
+    pub struct OsciHrPreTrigGet: "OsciHR.PreTrigGet" {} -> OsciHrPreTrigGetResponse {
+        /// Pre-trigger samples
+        pub pre_trigger_samples: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.Run`: start the Oscilloscope High Resolution module.
+    pub struct OsciHrRun: "OsciHR.Run" {} -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.OsciDataGet`: get the graph data of the selected channel of the Oscilloscope High Resolution.
+    pub struct OsciHrOsciDataGet: "OsciHR.OsciDataGet" blocking if data_to_get {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// 0 = current, 1 = next trigger
+        pub data_to_get: u16 = "H",
+        /// Timeout in seconds
+        pub timeout_s: f64 = "d",
+    } -> OsciHrOsciDataGetResponse {
+        /// Length of the timestamp in bytes
+        pub timestamp_size: i32 = "i",
+        /// Timestamp of the first sample
+        pub timestamp: String = "*-c",
+        /// Time between samples in seconds
+        pub time_delta: f64 = "d",
+        /// Number of data values
+        pub num_data_values: i32 = "i",
+        /// Sample values
+        pub data_values: Vec<f32> = "*f",
+        /// 1 if the wait timed out
+        pub timeout_occurred: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigModeSet`: set the trigger mode in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigModeSet: "OsciHR.TrigModeSet" {
+        /// 0 = immediate, 1 = level, 2 = digital
+        pub trigger_mode: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigModeGet`: get the trigger mode in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigModeGet: "OsciHR.TrigModeGet" {} -> OsciHrTrigModeGetResponse {
+        /// 0 = immediate, 1 = level, 2 = digital
+        pub trigger_mode: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevChSet`: set the Level Trigger Channel in the Oscilloscope High Resolution, by signal index or name.
+    pub struct OsciHrTrigLevChSet: "OsciHR.TrigLevChSet" {
+        /// Signal index of the level trigger channel
+        pub level_trigger_channel_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevChGet`: get the Level Trigger Channel index in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevChGet: "OsciHR.TrigLevChGet" {} -> OsciHrTrigLevChGetResponse {
+        /// Signal index of the level trigger channel
+        pub level_trigger_channel_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevValSet`: set the Level Trigger value in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevValSet: "OsciHR.TrigLevValSet" {
+        /// Level trigger value
+        pub level_trigger_value: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevValGet`: get the Level Trigger value in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevValGet: "OsciHR.TrigLevValGet" {} -> OsciHrTrigLevValGetResponse {
+        /// Level trigger value
+        pub level_trigger_value: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigArmModeSet`: set the Trigger Arming Mode in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigArmModeSet: "OsciHR.TrigArmModeSet" {
+        /// 0 = single shot, 1 = continuous
+        pub trigger_arming_mode: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigArmModeGet`: get the Trigger Arming Mode in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigArmModeGet: "OsciHR.TrigArmModeGet" {} -> OsciHrTrigArmModeGetResponse {
+        /// 0 = single shot, 1 = continuous
+        pub trigger_arming_mode: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevHystSet`: set the Level Trigger Hysteresis in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevHystSet: "OsciHR.TrigLevHystSet" {
+        /// Level trigger hysteresis
+        pub hysteresis: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevHystGet`: get the Level Trigger Hysteresis in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevHystGet: "OsciHR.TrigLevHystGet" {} -> OsciHrTrigLevHystGetResponse {
+        /// Level trigger hysteresis
+        pub hysteresis: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevSlopeSet`: set the Level Trigger Slope in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevSlopeSet: "OsciHR.TrigLevSlopeSet" {
+        /// 0 = falling, 1 = rising
+        pub slope: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigLevSlopeGet`: get the Level Trigger Slope in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigLevSlopeGet: "OsciHR.TrigLevSlopeGet" {} -> OsciHrTrigLevSlopeGetResponse {
+        /// 0 = falling, 1 = rising
+        pub slope: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigDigChSet`: set the Digital Trigger Channel index in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigDigChSet: "OsciHR.TrigDigChSet" {
+        /// Digital trigger channel index
+        pub digital_trigger_channel: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigDigChGet`: get the Digital Trigger Channel index in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigDigChGet: "OsciHR.TrigDigChGet" {} -> OsciHrTrigDigChGetResponse {
+        /// Digital trigger channel index
+        pub digital_trigger_channel: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigDigSlopeSet`: set the Digital Trigger Slope in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigDigSlopeSet: "OsciHR.TrigDigSlopeSet" {
+        /// Digital trigger slope (0 = Rising, 1 = Falling, 2 = Both)
+        pub slope: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigDigSlopeGet`: get the Digital Trigger Slope in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigDigSlopeGet: "OsciHR.TrigDigSlopeGet" {} -> OsciHrTrigDigSlopeGetResponse {
+        /// Digital trigger slope (0 = Rising, 1 = Falling, 2 = Both)
+        pub slope: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.TrigRearm`: rearm the trigger in the Oscilloscope High Resolution.
+    pub struct OsciHrTrigRearm: "OsciHR.TrigRearm" {} -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDShow`: show the PSD (Power Spectral Density) view in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdShow: "OsciHR.PSDShow" {} -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDWeightSet`: set the PSD weighting mode in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdWeightSet: "OsciHR.PSDWeightSet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// Weighting mode (0 = None, 1 = A-weighting, 2 = C-weighting)
+        pub weighting: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDWeightGet`: get the PSD weighting mode in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdWeightGet: "OsciHR.PSDWeightGet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> OsciHrPsdWeightGetResponse {
+        /// Weighting mode (0 = None, 1 = A-weighting, 2 = C-weighting)
+        pub weighting: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDWindowSet`: set the PSD window function in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdWindowSet: "OsciHR.PSDWindowSet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// Window function (0 = None, 1 = Hann, 2 = Hamming, 3 = Blackman-Harris, 4 = Flat Top)
+        pub window: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDWindowGet`: get the PSD window function in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdWindowGet: "OsciHR.PSDWindowGet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> OsciHrPsdWindowGetResponse {
+        /// Window function (0 = None, 1 = Hann, 2 = Hamming, 3 = Blackman-Harris, 4 = Flat Top)
+        pub window: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDAvrgTypeSet`: set the PSD averaging type in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdAvrgTypeSet: "OsciHR.PSDAvrgTypeSet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// Averaging type (0 = None, 1 = Linear, 2 = Exponential)
+        pub averaging_type: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDAvrgTypeGet`: get the PSD averaging type in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdAvrgTypeGet: "OsciHR.PSDAvrgTypeGet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> OsciHrPsdAvrgTypeGetResponse {
+        /// Averaging type (0 = None, 1 = Linear, 2 = Exponential)
+        pub averaging_type: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDAvrgCountSet`: set the PSD averaging count in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdAvrgCountSet: "OsciHR.PSDAvrgCountSet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// Number of averages
+        pub count: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDAvrgCountGet`: get the PSD averaging count in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdAvrgCountGet: "OsciHR.PSDAvrgCountGet" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> OsciHrPsdAvrgCountGetResponse {
+        /// Number of averages
+        pub count: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDAvrgRestart`: restart PSD averaging in the Oscilloscope High Resolution.
+    pub struct OsciHrPsdAvrgRestart: "OsciHR.PSDAvrgRestart" {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `OsciHR.PSDDataGet`: get the PSD data from the Oscilloscope High Resolution.
+    pub struct OsciHrPsdDataGet: "OsciHR.PSDDataGet" blocking if data_to_get {
+        /// Oscilloscope channel index
+        pub osci_index: i32 = "i",
+        /// 0 = Current data, 1 = Wait for next acquisition
+        pub data_to_get: u16 = "H",
+        /// Timeout in seconds
+        pub timeout_s: f64 = "d",
+    } -> OsciHrPsdDataGetResponse {
+        /// Frequency of the first bin in Hz
+        pub frequency_start: f64 = "d",
+        /// Frequency step between bins in Hz
+        pub frequency_delta: f64 = "d",
+        /// Number of PSD values
+        pub num_psd_data: i32 = "i",
+        /// PSD values
+        pub psd_data: Vec<f32> = "*f",
+        /// 1 if the wait timed out
+        pub timeout_occurred: u32 = "I",
+    }
+}
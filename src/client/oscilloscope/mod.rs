@@ -1,6 +1,8 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
 mod osci_1t;
 mod osci_2t;
 mod osci_hr;
@@ -1,42 +1,29 @@
+use super::commands;
 use super::super::NanonisClient;
+use crate::client::signals::SlotRef;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     /// Set the channels to display in the Oscilloscope 2-Channels
-    /// channel_a_index: 0-23, channel A signal index  
-    /// channel_b_index: 0-23, channel B signal index
+    /// channel_a, channel_b: slots (0-23) of the Signals Manager, or signals
+    /// by name or [`SignalIndex`](crate::signals::SignalIndex) assigned to a
+    /// slot
     pub fn osci2t_ch_set(
         &mut self,
-        channel_a_index: i32,
-        channel_b_index: i32,
+        channel_a: impl Into<SlotRef>,
+        channel_b: impl Into<SlotRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Osci2T.ChSet",
-            vec![
-                NanonisValue::I32(channel_a_index),
-                NanonisValue::I32(channel_b_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let channel_a_index = self.resolve_slot(channel_a)?;
+        let channel_b_index = self.resolve_slot(channel_b)?;
+        self.send(&commands::Osci2TChSet { channel_a_index, channel_b_index })?;
         Ok(())
     }
 
     /// Get the channels displayed in the Oscilloscope 2-Channels
     /// Returns: (channel_a_index, channel_b_index)
     pub fn osci2t_ch_get(&mut self) -> Result<(i32, i32), NanonisError> {
-        let result =
-            self.quick_send("Osci2T.ChGet", vec![], vec![], vec!["i", "i"])?;
-        if result.len() >= 2 {
-            let channel_a = result[0].as_i32()?;
-            let channel_b = result[1].as_i32()?;
-            Ok((channel_a, channel_b))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid channel response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::Osci2TChGet {})?;
+        Ok((response.channel_a, response.channel_b))
     }
 
     /// Set the timebase in the Oscilloscope 2-Channels
@@ -45,33 +32,15 @@ impl NanonisClient {
         &mut self,
         timebase_index: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Osci2T.TimebaseSet",
-            vec![NanonisValue::U16(timebase_index)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::Osci2TTimebaseSet { timebase_index })?;
         Ok(())
     }
 
     /// Get the timebase in the Oscilloscope 2-Channels
     /// Returns: (timebase_index, timebases_array)
     pub fn osci2t_timebase_get(&mut self) -> Result<(u16, Vec<f32>), NanonisError> {
-        let result = self.quick_send(
-            "Osci2T.TimebaseGet",
-            vec![],
-            vec![],
-            vec!["H", "i", "*f"],
-        )?;
-        if result.len() >= 3 {
-            let timebase_index = result[0].as_u16()?;
-            let timebases = result[2].as_f32_array()?.to_vec();
-            Ok((timebase_index, timebases))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid timebase response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::Osci2TTimebaseGet {})?;
+        Ok((response.timebase_index, response.timebases))
     }
 
     /// Set the oversampling in the Oscilloscope 2-Channels
@@ -80,26 +49,15 @@ impl NanonisClient {
         &mut self,
         oversampling_index: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Osci2T.OversamplSet",
-            vec![NanonisValue::U16(oversampling_index)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::Osci2TOversamplSet { oversampling_index })?;
         Ok(())
     }
 
     /// Get the oversampling in the Oscilloscope 2-Channels
     /// Returns: oversampling index (0=50 samples, 1=20, 2=10, 3=5, 4=2, 5=1 sample)
     pub fn osci2t_oversampl_get(&mut self) -> Result<u16, NanonisError> {
-        let result =
-            self.quick_send("Osci2T.OversamplGet", vec![], vec![], vec!["H"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No oversampling index returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::Osci2TOversamplGet {})?;
+        Ok(response.oversampling_index)
     }
 
     /// Set the trigger configuration in the Oscilloscope 2-Channels
@@ -115,19 +73,14 @@ impl NanonisClient {
         trigger_hysteresis: f64,
         trig_position: f64,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Osci2T.TrigSet",
-            vec![
-                NanonisValue::U16(trigger_mode),
-                NanonisValue::U16(trig_channel),
-                NanonisValue::U16(trigger_slope),
-                NanonisValue::F64(trigger_level),
-                NanonisValue::F64(trigger_hysteresis),
-                NanonisValue::F64(trig_position),
-            ],
-            vec!["H", "H", "H", "d", "d", "d"],
-            vec![],
-        )?;
+        self.send(&commands::Osci2TTrigSet {
+            trigger_mode,
+            trig_channel,
+            trigger_slope,
+            trigger_level,
+            trigger_hysteresis,
+            trig_position,
+        })?;
         Ok(())
     }
 
@@ -136,37 +89,26 @@ impl NanonisClient {
     pub fn osci2t_trig_get(
         &mut self,
     ) -> Result<(u16, u16, u16, f64, f64, f64), NanonisError> {
-        let result = self.quick_send(
-            "Osci2T.TrigGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "H", "d", "d", "d"],
-        )?;
-        if result.len() >= 6 {
-            let trigger_mode = result[0].as_u16()?;
-            let trig_channel = result[1].as_u16()?;
-            let trigger_slope = result[2].as_u16()?;
-            let trigger_level = result[3].as_f64()?;
-            let trigger_hysteresis = result[4].as_f64()?;
-            let trig_position = result[5].as_f64()?;
-            Ok((
-                trigger_mode,
-                trig_channel,
-                trigger_slope,
-                trigger_level,
-                trigger_hysteresis,
-                trig_position,
-            ))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid trigger configuration response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::Osci2TTrigGet {})?;
+        let trigger_mode = response.trigger_mode;
+        let trig_channel = response.trig_channel;
+        let trigger_slope = response.trigger_slope;
+        let trigger_level = response.trigger_level;
+        let trigger_hysteresis = response.trigger_hysteresis;
+        let trig_position = response.trig_position;
+        Ok((
+            trigger_mode,
+            trig_channel,
+            trigger_slope,
+            trigger_level,
+            trigger_hysteresis,
+            trig_position,
+        ))
     }
 
     /// Start the Oscilloscope 2-Channels
     pub fn osci2t_run(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Osci2T.Run", vec![], vec![], vec![])?;
+        self.send(&commands::Osci2TRun {})?;
         Ok(())
     }
 
@@ -177,23 +119,8 @@ impl NanonisClient {
         &mut self,
         data_to_get: u16,
     ) -> Result<(f64, f64, Vec<f64>, Vec<f64>), NanonisError> {
-        let result = self.quick_send(
-            "Osci2T.DataGet",
-            vec![NanonisValue::U16(data_to_get)],
-            vec!["H"],
-            vec!["d", "d", "i", "*d", "i", "*d"],
-        )?;
+        let response = self.send(&commands::Osci2TDataGet { data_to_get })?;
 
-        if result.len() >= 6 {
-            let t0 = result[0].as_f64()?;
-            let dt = result[1].as_f64()?;
-            let channel_a_data = result[3].as_f64_array()?.to_vec();
-            let channel_b_data = result[5].as_f64_array()?.to_vec();
-            Ok((t0, dt, channel_a_data, channel_b_data))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid oscilloscope 2T data response".to_string(),
-            ))
-        }
+        Ok((response.t0, response.dt, response.channel_a_data, response.channel_b_data))
     }
 }
@@ -1,25 +1,21 @@
+use super::commands;
 use super::super::NanonisClient;
 use super::*;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
-use crate::client::signals::SignalIndex;
+use crate::client::signals::{SignalIndex, SignalRef};
 
 impl NanonisClient {
     /// Set the measured signal index of the selected channel from the Oscilloscope High Resolution
     pub fn osci_hr_ch_set(
         &mut self,
         osci_index: impl Into<OscilloscopeIndex>,
-        signal: impl Into<SignalIndex>,
+        signal: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.ChSet",
-            vec![
-                NanonisValue::I32(osci_index.into().into()),
-                NanonisValue::I32(signal.into().into()),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::OsciHrChSet {
+            osci_index: osci_index.into().into(),
+            signal_index,
+        })?;
         Ok(())
     }
 
@@ -28,40 +24,20 @@ impl NanonisClient {
         &mut self,
         osci_index: impl Into<OscilloscopeIndex>,
     ) -> Result<SignalIndex, NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.ChGet",
-            vec![NanonisValue::I32(osci_index.into().into())],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(SignalIndex::new(value.as_i32()? as u8)),
-            None => Err(NanonisError::Protocol(
-                "No signal index returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrChGet { osci_index: osci_index.into().into() })?;
+        Ok(SignalIndex::new(response.signal_index as u8))
     }
 
     /// Set the oversampling index of the Oscilloscope High Resolution
     pub fn osci_hr_oversampl_set(&mut self, oversampling_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.OversamplSet",
-            vec![NanonisValue::I32(oversampling_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrOversamplSet { oversampling_index })?;
         Ok(())
     }
 
     /// Get the oversampling index of the Oscilloscope High Resolution
     pub fn osci_hr_oversampl_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("OsciHR.OversamplGet", vec![], vec![], vec!["i"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No oversampling index returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrOversamplGet {})?;
+        Ok(response.oversampling_index)
     }
 
     /// Set the calibration mode of the selected channel from the Oscilloscope High Resolution
@@ -71,33 +47,15 @@ impl NanonisClient {
         osci_index: i32,
         calibration_mode: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.CalibrModeSet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::U16(calibration_mode),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrCalibrModeSet { osci_index, calibration_mode })?;
         Ok(())
     }
 
     /// Get the calibration mode of the selected channel from the Oscilloscope High Resolution
     /// Returns: 0 = Raw values, 1 = Calibrated values
     pub fn osci_hr_calibr_mode_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.CalibrModeGet",
-            vec![NanonisValue::I32(osci_index)],
-            vec!["i"],
-            vec!["H"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No calibration mode returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrCalibrModeGet { osci_index })?;
+        Ok(response.calibration_mode)
     }
 
     /// Set the number of samples to acquire in the Oscilloscope High Resolution
@@ -105,24 +63,16 @@ impl NanonisClient {
         &mut self,
         number_of_samples: impl Into<SampleCount>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.SamplesSet",
-            vec![NanonisValue::I32(number_of_samples.into().into())],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrSamplesSet {
+            number_of_samples: number_of_samples.into().into(),
+        })?;
         Ok(())
     }
 
     /// Get the number of samples to acquire in the Oscilloscope High Resolution
     pub fn osci_hr_samples_get(&mut self) -> Result<SampleCount, NanonisError> {
-        let result = self.quick_send("OsciHR.SamplesGet", vec![], vec![], vec!["i"])?;
-        match result.first() {
-            Some(value) => Ok(SampleCount::new(value.as_i32()?)),
-            None => Err(NanonisError::Protocol(
-                "No sample count returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrSamplesGet {})?;
+        Ok(SampleCount::new(response.number_of_samples))
     }
 
     /// Set the Pre-Trigger Samples or Seconds in the Oscilloscope High Resolution
@@ -131,32 +81,19 @@ impl NanonisClient {
         pre_trigger_samples: u32,
         pre_trigger_s: f64,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.PreTrigSet",
-            vec![
-                NanonisValue::U32(pre_trigger_samples),
-                NanonisValue::F64(pre_trigger_s),
-            ],
-            vec!["I", "d"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrPreTrigSet { pre_trigger_samples, pre_trigger_s })?;
         Ok(())
     }
 
     /// Get the Pre-Trigger Samples in the Oscilloscope High Resolution
     pub fn osci_hr_pre_trig_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("OsciHR.PreTrigGet", vec![], vec![], vec!["i"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No pre-trigger samples returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrPreTrigGet {})?;
+        Ok(response.pre_trigger_samples)
     }
 
     /// Start the Oscilloscope High Resolution module
     pub fn osci_hr_run(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("OsciHR.Run", vec![], vec![], vec![])?;
+        self.send(&commands::OsciHrRun {})?;
         Ok(())
     }
 
@@ -169,28 +106,17 @@ impl NanonisClient {
         data_to_get: u16,
         timeout_s: f64,
     ) -> Result<(String, f64, Vec<f32>, bool), NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.OsciDataGet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::U16(data_to_get),
-                NanonisValue::F64(timeout_s),
-            ],
-            vec!["i", "H", "d"],
-            vec!["i", "*-c", "d", "i", "*f", "I"],
-        )?;
-
-        if result.len() >= 6 {
-            let timestamp = result[1].as_string()?.to_string();
-            let time_delta = result[2].as_f64()?;
-            let data_values = result[4].as_f32_array()?.to_vec();
-            let timeout_occurred = result[5].as_u32()? == 1;
-            Ok((timestamp, time_delta, data_values, timeout_occurred))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid oscilloscope data response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::OsciHrOsciDataGet {
+            osci_index,
+            data_to_get,
+            timeout_s,
+        })?;
+
+        let timestamp = response.timestamp;
+        let time_delta = response.time_delta;
+        let data_values = response.data_values;
+        let timeout_occurred = response.timeout_occurred == 1;
+        Ok((timestamp, time_delta, data_values, timeout_occurred))
     }
 
     /// Set the trigger mode in the Oscilloscope High Resolution
@@ -198,60 +124,42 @@ impl NanonisClient {
         &mut self,
         trigger_mode: impl Into<TriggerMode>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigModeSet",
-            vec![NanonisValue::U16(trigger_mode.into().into())],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigModeSet {
+            trigger_mode: trigger_mode.into().into(),
+        })?;
         Ok(())
     }
 
     /// Get the trigger mode in the Oscilloscope High Resolution
     pub fn osci_hr_trig_mode_get(&mut self) -> Result<TriggerMode, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigModeGet", vec![], vec![], vec!["H"])?;
-        match result.first() {
-            Some(value) => {
-                let mode_val = value.as_u16()?;
-                match mode_val {
-                    0 => Ok(TriggerMode::Immediate),
-                    1 => Ok(TriggerMode::Level),
-                    2 => Ok(TriggerMode::Digital),
-                    _ => Err(NanonisError::Protocol(format!(
-                        "Unknown trigger mode: {}",
-                        mode_val
-                    ))),
-                }
-            }
-            None => Err(NanonisError::Protocol(
-                "No trigger mode returned".to_string(),
-            )),
+        let response = self.send(&commands::OsciHrTrigModeGet {})?;
+        let mode_val = response.trigger_mode;
+        match mode_val {
+            0 => Ok(TriggerMode::Immediate),
+            1 => Ok(TriggerMode::Level),
+            2 => Ok(TriggerMode::Digital),
+            _ => Err(NanonisError::Protocol(format!(
+                "Unknown trigger mode: {}",
+                mode_val
+            ))),
         }
     }
 
-    /// Set the Level Trigger Channel index in the Oscilloscope High Resolution
+    /// Set the Level Trigger Channel in the Oscilloscope High Resolution, by
+    /// signal index or name
     pub fn osci_hr_trig_lev_ch_set(
         &mut self,
-        level_trigger_channel_index: i32,
+        level_trigger_channel: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigLevChSet",
-            vec![NanonisValue::I32(level_trigger_channel_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        let level_trigger_channel_index = self.resolve_signal(level_trigger_channel)?;
+        self.send(&commands::OsciHrTrigLevChSet { level_trigger_channel_index })?;
         Ok(())
     }
 
     /// Get the Level Trigger Channel index in the Oscilloscope High Resolution
     pub fn osci_hr_trig_lev_ch_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigLevChGet", vec![], vec![], vec!["i"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No level trigger channel returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigLevChGet {})?;
+        Ok(response.level_trigger_channel_index)
     }
 
     /// Set the Level Trigger value in the Oscilloscope High Resolution
@@ -259,24 +167,16 @@ impl NanonisClient {
         &mut self,
         level_trigger_value: impl Into<TriggerLevel>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigLevValSet",
-            vec![NanonisValue::F64(level_trigger_value.into().into())],
-            vec!["d"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigLevValSet {
+            level_trigger_value: level_trigger_value.into().into(),
+        })?;
         Ok(())
     }
 
     /// Get the Level Trigger value in the Oscilloscope High Resolution
     pub fn osci_hr_trig_lev_val_get(&mut self) -> Result<TriggerLevel, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigLevValGet", vec![], vec![], vec!["d"])?;
-        match result.first() {
-            Some(value) => Ok(TriggerLevel(value.as_f64()?)),
-            None => Err(NanonisError::Protocol(
-                "No level trigger value returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigLevValGet {})?;
+        Ok(TriggerLevel(response.level_trigger_value))
     }
 
     /// Set the Trigger Arming Mode in the Oscilloscope High Resolution
@@ -284,68 +184,38 @@ impl NanonisClient {
         &mut self,
         trigger_arming_mode: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigArmModeSet",
-            vec![NanonisValue::U16(trigger_arming_mode)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigArmModeSet { trigger_arming_mode })?;
         Ok(())
     }
 
     /// Get the Trigger Arming Mode in the Oscilloscope High Resolution
     pub fn osci_hr_trig_arm_mode_get(&mut self) -> Result<u16, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigArmModeGet", vec![], vec![], vec!["H"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No trigger arming mode returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigArmModeGet {})?;
+        Ok(response.trigger_arming_mode)
     }
 
     /// Set the Level Trigger Hysteresis in the Oscilloscope High Resolution
     pub fn osci_hr_trig_lev_hyst_set(&mut self, hysteresis: f64) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigLevHystSet",
-            vec![NanonisValue::F64(hysteresis)],
-            vec!["d"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigLevHystSet { hysteresis })?;
         Ok(())
     }
 
     /// Get the Level Trigger Hysteresis in the Oscilloscope High Resolution
     pub fn osci_hr_trig_lev_hyst_get(&mut self) -> Result<f64, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigLevHystGet", vec![], vec![], vec!["d"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_f64()?),
-            None => Err(NanonisError::Protocol(
-                "No trigger hysteresis returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigLevHystGet {})?;
+        Ok(response.hysteresis)
     }
 
     /// Set the Level Trigger Slope in the Oscilloscope High Resolution
     pub fn osci_hr_trig_lev_slope_set(&mut self, slope: u16) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigLevSlopeSet",
-            vec![NanonisValue::U16(slope)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigLevSlopeSet { slope })?;
         Ok(())
     }
 
     /// Get the Level Trigger Slope in the Oscilloscope High Resolution
     pub fn osci_hr_trig_lev_slope_get(&mut self) -> Result<u16, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigLevSlopeGet", vec![], vec![], vec!["H"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No trigger slope returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigLevSlopeGet {})?;
+        Ok(response.slope)
     }
 
     /// Set the Digital Trigger Channel index in the Oscilloscope High Resolution.
@@ -359,12 +229,7 @@ impl NanonisClient {
         &mut self,
         digital_trigger_channel: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigDigChSet",
-            vec![NanonisValue::I32(digital_trigger_channel)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigDigChSet { digital_trigger_channel })?;
         Ok(())
     }
 
@@ -376,13 +241,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_trig_dig_ch_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigDigChGet", vec![], vec![], vec!["i"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No digital trigger channel returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigDigChGet {})?;
+        Ok(response.digital_trigger_channel)
     }
 
     /// Set the Digital Trigger Slope in the Oscilloscope High Resolution.
@@ -393,12 +253,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_trig_dig_slope_set(&mut self, slope: u16) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.TrigDigSlopeSet",
-            vec![NanonisValue::U16(slope)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrTrigDigSlopeSet { slope })?;
         Ok(())
     }
 
@@ -410,13 +265,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_trig_dig_slope_get(&mut self) -> Result<u16, NanonisError> {
-        let result = self.quick_send("OsciHR.TrigDigSlopeGet", vec![], vec![], vec!["H"])?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No digital trigger slope returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrTrigDigSlopeGet {})?;
+        Ok(response.slope)
     }
 
     /// Rearm the trigger in the Oscilloscope High Resolution.
@@ -424,7 +274,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_trig_rearm(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("OsciHR.TrigRearm", vec![], vec![], vec![])?;
+        self.send(&commands::OsciHrTrigRearm {})?;
         Ok(())
     }
 
@@ -433,7 +283,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_psd_show(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("OsciHR.PSDShow", vec![], vec![], vec![])?;
+        self.send(&commands::OsciHrPsdShow {})?;
         Ok(())
     }
 
@@ -450,15 +300,7 @@ impl NanonisClient {
         osci_index: i32,
         weighting: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.PSDWeightSet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::U16(weighting),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrPsdWeightSet { osci_index, weighting })?;
         Ok(())
     }
 
@@ -473,18 +315,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_psd_weight_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.PSDWeightGet",
-            vec![NanonisValue::I32(osci_index)],
-            vec!["i"],
-            vec!["H"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No PSD weighting returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrPsdWeightGet { osci_index })?;
+        Ok(response.weighting)
     }
 
     /// Set the PSD window function in the Oscilloscope High Resolution.
@@ -500,15 +332,7 @@ impl NanonisClient {
         osci_index: i32,
         window: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.PSDWindowSet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::U16(window),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrPsdWindowSet { osci_index, window })?;
         Ok(())
     }
 
@@ -523,18 +347,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_psd_window_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.PSDWindowGet",
-            vec![NanonisValue::I32(osci_index)],
-            vec!["i"],
-            vec!["H"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No PSD window returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrPsdWindowGet { osci_index })?;
+        Ok(response.window)
     }
 
     /// Set the PSD averaging type in the Oscilloscope High Resolution.
@@ -550,15 +364,7 @@ impl NanonisClient {
         osci_index: i32,
         averaging_type: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.PSDAvrgTypeSet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::U16(averaging_type),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrPsdAvrgTypeSet { osci_index, averaging_type })?;
         Ok(())
     }
 
@@ -573,18 +379,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_psd_avrg_type_get(&mut self, osci_index: i32) -> Result<u16, NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.PSDAvrgTypeGet",
-            vec![NanonisValue::I32(osci_index)],
-            vec!["i"],
-            vec!["H"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(value.as_u16()?),
-            None => Err(NanonisError::Protocol(
-                "No PSD averaging type returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrPsdAvrgTypeGet { osci_index })?;
+        Ok(response.averaging_type)
     }
 
     /// Set the PSD averaging count in the Oscilloscope High Resolution.
@@ -600,15 +396,7 @@ impl NanonisClient {
         osci_index: i32,
         count: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.PSDAvrgCountSet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::I32(count),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrPsdAvrgCountSet { osci_index, count })?;
         Ok(())
     }
 
@@ -623,18 +411,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_psd_avrg_count_get(&mut self, osci_index: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.PSDAvrgCountGet",
-            vec![NanonisValue::I32(osci_index)],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No PSD averaging count returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::OsciHrPsdAvrgCountGet { osci_index })?;
+        Ok(response.count)
     }
 
     /// Restart PSD averaging in the Oscilloscope High Resolution.
@@ -645,12 +423,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn osci_hr_psd_avrg_restart(&mut self, osci_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "OsciHR.PSDAvrgRestart",
-            vec![NanonisValue::I32(osci_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::OsciHrPsdAvrgRestart { osci_index })?;
         Ok(())
     }
 
@@ -672,27 +445,16 @@ impl NanonisClient {
         data_to_get: u16,
         timeout_s: f64,
     ) -> Result<(f64, f64, Vec<f32>, bool), NanonisError> {
-        let result = self.quick_send(
-            "OsciHR.PSDDataGet",
-            vec![
-                NanonisValue::I32(osci_index),
-                NanonisValue::U16(data_to_get),
-                NanonisValue::F64(timeout_s),
-            ],
-            vec!["i", "H", "d"],
-            vec!["d", "d", "i", "*f", "I"],
-        )?;
-
-        if result.len() >= 5 {
-            let frequency_start = result[0].as_f64()?;
-            let frequency_delta = result[1].as_f64()?;
-            let psd_data = result[3].as_f32_array()?.to_vec();
-            let timeout_occurred = result[4].as_u32()? == 1;
-            Ok((frequency_start, frequency_delta, psd_data, timeout_occurred))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid PSD data response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::OsciHrPsdDataGet {
+            osci_index,
+            data_to_get,
+            timeout_s,
+        })?;
+
+        let frequency_start = response.frequency_start;
+        let frequency_delta = response.frequency_delta;
+        let psd_data = response.psd_data;
+        let timeout_occurred = response.timeout_occurred == 1;
+        Ok((frequency_start, frequency_delta, psd_data, timeout_occurred))
     }
 }
@@ -0,0 +1,183 @@
+//! Typed descriptors for the `Pattern.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Pattern.ExpOpen`: open the selected grid experiment.
+    pub struct PatternExpOpen: "Pattern.ExpOpen" {} -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.ExpStart`: start the selected grid experiment.
+    pub struct PatternExpStart: "Pattern.ExpStart" {
+        /// Pattern type to switch to before starting
+        pub pattern: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.ExpPause`: pause or resume the selected grid experiment.
+    pub struct PatternExpPause: "Pattern.ExpPause" {
+        /// True to pause, false to resume
+        pub pause: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.ExpStop`: stop the selected grid experiment.
+    pub struct PatternExpStop: "Pattern.ExpStop" {} -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.ExpStatusGet`: get the status of the selected grid experiment.
+    pub struct PatternExpStatusGet: "Pattern.ExpStatusGet" {} -> PatternExpStatusGetResponse {
+        /// 1 if an experiment is running
+        pub running: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Pattern.GridSet`: set the grid pattern parameters.
+    pub struct PatternGridSet: "Pattern.GridSet" {
+        /// If true, switch to grid pattern
+        pub set_active: u32 = "I",
+        /// Number of points in X direction
+        pub num_points_x: i32 = "i",
+        /// Number of points in Y direction
+        pub num_points_y: i32 = "i",
+        /// If true, use scan frame size
+        pub use_scan_frame: u32 = "I",
+        /// X coordinate of grid center (meters)
+        pub center_x_m: f32 = "f",
+        /// Y coordinate of grid center (meters)
+        pub center_y_m: f32 = "f",
+        /// Grid width (meters)
+        pub width_m: f32 = "f",
+        /// Grid height (meters)
+        pub height_m: f32 = "f",
+        /// Grid rotation angle (degrees)
+        pub angle_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.GridGet`: get the grid pattern parameters.
+    pub struct PatternGridGet: "Pattern.GridGet" {} -> PatternGridGetResponse {
+        /// Number of points in X direction
+        pub num_points_x: i32 = "i",
+        /// Number of points in Y direction
+        pub num_points_y: i32 = "i",
+        /// X coordinate of grid center (meters)
+        pub center_x_m: f32 = "f",
+        /// Y coordinate of grid center (meters)
+        pub center_y_m: f32 = "f",
+        /// Grid width (meters)
+        pub width_m: f32 = "f",
+        /// Grid height (meters)
+        pub height_m: f32 = "f",
+        /// Grid rotation angle (degrees)
+        pub angle_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Pattern.LineSet`: set the line pattern parameters.
+    pub struct PatternLineSet: "Pattern.LineSet" {
+        /// If true, switch to line pattern
+        pub set_active: u32 = "I",
+        /// Number of points on line
+        pub num_points: i32 = "i",
+        /// If true, use scan frame diagonal
+        pub use_scan_frame: u32 = "I",
+        /// X coordinate of point 1 (meters)
+        pub point1_x_m: f32 = "f",
+        /// Y coordinate of point 1 (meters)
+        pub point1_y_m: f32 = "f",
+        /// X coordinate of point 2 (meters)
+        pub point2_x_m: f32 = "f",
+        /// Y coordinate of point 2 (meters)
+        pub point2_y_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.LineGet`: get the line pattern parameters.
+    pub struct PatternLineGet: "Pattern.LineGet" {} -> PatternLineGetResponse {
+        /// Number of points on line
+        pub num_points: i32 = "i",
+        /// X coordinate of point 1 (meters)
+        pub point1_x_m: f32 = "f",
+        /// Y coordinate of point 1 (meters)
+        pub point1_y_m: f32 = "f",
+        /// X coordinate of point 2 (meters)
+        pub point2_x_m: f32 = "f",
+        /// Y coordinate of point 2 (meters)
+        pub point2_y_m: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Pattern.CloudSet`: set the cloud pattern parameters.
+    pub struct PatternCloudSet: "Pattern.CloudSet" {
+        /// If true, switch to cloud pattern
+        pub set_active: u32 = "I",
+        /// Number of points on line
+        pub num_points: i32 = "i",
+        /// X coordinates of points (meters)
+        pub x_coords_m: Vec<f32> = "*f",
+        /// Y coordinates of points (meters)
+        pub y_coords_m: Vec<f32> = "*f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.CloudGet`: get the cloud pattern parameters.
+    pub struct PatternCloudGet: "Pattern.CloudGet" {} -> PatternCloudGetResponse {
+        /// Number of points
+        pub num_x_coords_m: i32 = "i",
+        /// X coordinates of points (meters)
+        pub x_coords_m: Vec<f32> = "*f",
+        /// Y coordinates of points (meters)
+        pub y_coords_m: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `Pattern.PropsSet`: set the pattern experiment properties.
+    pub struct PatternPropsSet: "Pattern.PropsSet" {
+        /// Name of experiment to select
+        pub selected_experiment: String = "+*c",
+        /// Base filename for saved files
+        pub basename: String = "+*c",
+        /// Path to external VI
+        pub external_vi_path: String = "+*c",
+        /// Delay before measurement on each point
+        pub pre_measure_delay_s: f32 = "f",
+        /// Save scan channels to file
+        pub save_scan_channels: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Pattern.PropsGet`: get the pattern experiment properties.
+    pub struct PatternPropsGet: "Pattern.PropsGet" {} -> PatternPropsGetResponse {
+        /// Size of the available experiments array in bytes
+        pub available_experiments_size: i32 = "i",
+        /// Number of available experiments
+        pub num_available_experiments: i32 = "i",
+        /// List of available experiments
+        pub available_experiments: Vec<String> = "*+c",
+        /// Length of the selected experiment in bytes
+        pub selected_experiment_size: i32 = "i",
+        /// Currently selected experiment
+        pub selected_experiment: String = "*-c",
+        /// Length of the external VI path in bytes
+        pub external_vi_path_size: i32 = "i",
+        /// Path to external VI
+        pub external_vi_path: String = "*-c",
+        /// Pre-measure delay in seconds
+        pub pre_measure_delay_s: f32 = "f",
+        /// Save scan channels to file
+        pub save_scan_channels: u32 = "I",
+    }
+}
@@ -1,6 +1,9 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Position;
+use serde::{Deserialize, Serialize};
 
 /// Pattern type for grid experiments.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -23,7 +26,7 @@ impl From<PatternType> for u16 {
 }
 
 /// Grid pattern configuration.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub struct GridConfig {
     /// Number of points in X direction
     pub num_points_x: i32,
@@ -41,6 +44,29 @@ pub struct GridConfig {
     pub angle_deg: f32,
 }
 
+impl GridConfig {
+    /// Nominal tip position of the point at `x`, `y`.
+    ///
+    /// Points span the grid width and height evenly, rotated by the grid
+    /// angle about its center.
+    pub fn position(&self, x: usize, y: usize) -> Position {
+        let offset = |index: usize, count: i32, size: f32| {
+            if count > 1 {
+                (index as f64 / (count - 1) as f64 - 0.5) * size as f64
+            } else {
+                0.0
+            }
+        };
+        let dx = offset(x, self.num_points_x, self.width_m);
+        let dy = offset(y, self.num_points_y, self.height_m);
+        let (sin, cos) = (self.angle_deg as f64).to_radians().sin_cos();
+        Position {
+            x: self.center_x_m as f64 + dx * cos + dy * sin,
+            y: self.center_y_m as f64 - dx * sin + dy * cos,
+        }
+    }
+}
+
 /// Line pattern configuration.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LineConfig {
@@ -109,7 +135,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn pattern_exp_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Pattern.ExpOpen", vec![], vec![], vec![])?;
+        self.send(&commands::PatternExpOpen {})?;
         Ok(())
     }
 
@@ -124,12 +150,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_exp_start(&mut self, pattern: PatternType) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Pattern.ExpStart",
-            vec![NanonisValue::U16(pattern.into())],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::PatternExpStart { pattern: pattern.into() })?;
         Ok(())
     }
 
@@ -142,12 +163,7 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_exp_pause(&mut self, pause: bool) -> Result<(), NanonisError> {
         let flag = if pause { 1u32 } else { 0u32 };
-        self.quick_send(
-            "Pattern.ExpPause",
-            vec![NanonisValue::U32(flag)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::PatternExpPause { pause: flag })?;
         Ok(())
     }
 
@@ -156,7 +172,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_exp_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Pattern.ExpStop", vec![], vec![], vec![])?;
+        self.send(&commands::PatternExpStop {})?;
         Ok(())
     }
 
@@ -168,13 +184,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_exp_status_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("Pattern.ExpStatusGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::PatternExpStatusGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.running != 0)
     }
 
     /// Set the grid pattern parameters.
@@ -195,22 +207,17 @@ impl NanonisClient {
         let active_flag = if set_active { 1u32 } else { 0u32 };
         let frame_flag = if use_scan_frame { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "Pattern.GridSet",
-            vec![
-                NanonisValue::U32(active_flag),
-                NanonisValue::I32(config.num_points_x),
-                NanonisValue::I32(config.num_points_y),
-                NanonisValue::U32(frame_flag),
-                NanonisValue::F32(config.center_x_m),
-                NanonisValue::F32(config.center_y_m),
-                NanonisValue::F32(config.width_m),
-                NanonisValue::F32(config.height_m),
-                NanonisValue::F32(config.angle_deg),
-            ],
-            vec!["I", "i", "i", "I", "f", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PatternGridSet {
+            set_active: active_flag,
+            num_points_x: config.num_points_x,
+            num_points_y: config.num_points_y,
+            use_scan_frame: frame_flag,
+            center_x_m: config.center_x_m,
+            center_y_m: config.center_y_m,
+            width_m: config.width_m,
+            height_m: config.height_m,
+            angle_deg: config.angle_deg,
+        })?;
         Ok(())
     }
 
@@ -222,26 +229,17 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_grid_get(&mut self) -> Result<GridConfig, NanonisError> {
-        let result = self.quick_send(
-            "Pattern.GridGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "f", "f", "f", "f", "f"],
-        )?;
+        let response = self.send(&commands::PatternGridGet {})?;
 
-        if result.len() >= 7 {
-            Ok(GridConfig {
-                num_points_x: result[0].as_i32()?,
-                num_points_y: result[1].as_i32()?,
-                center_x_m: result[2].as_f32()?,
-                center_y_m: result[3].as_f32()?,
-                width_m: result[4].as_f32()?,
-                height_m: result[5].as_f32()?,
-                angle_deg: result[6].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(GridConfig {
+            num_points_x: response.num_points_x,
+            num_points_y: response.num_points_y,
+            center_x_m: response.center_x_m,
+            center_y_m: response.center_y_m,
+            width_m: response.width_m,
+            height_m: response.height_m,
+            angle_deg: response.angle_deg,
+        })
     }
 
     /// Set the line pattern parameters.
@@ -262,20 +260,15 @@ impl NanonisClient {
         let active_flag = if set_active { 1u32 } else { 0u32 };
         let frame_flag = if use_scan_frame { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "Pattern.LineSet",
-            vec![
-                NanonisValue::U32(active_flag),
-                NanonisValue::I32(config.num_points),
-                NanonisValue::U32(frame_flag),
-                NanonisValue::F32(config.point1_x_m),
-                NanonisValue::F32(config.point1_y_m),
-                NanonisValue::F32(config.point2_x_m),
-                NanonisValue::F32(config.point2_y_m),
-            ],
-            vec!["I", "i", "I", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PatternLineSet {
+            set_active: active_flag,
+            num_points: config.num_points,
+            use_scan_frame: frame_flag,
+            point1_x_m: config.point1_x_m,
+            point1_y_m: config.point1_y_m,
+            point2_x_m: config.point2_x_m,
+            point2_y_m: config.point2_y_m,
+        })?;
         Ok(())
     }
 
@@ -287,24 +280,15 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_line_get(&mut self) -> Result<LineConfig, NanonisError> {
-        let result = self.quick_send(
-            "Pattern.LineGet",
-            vec![],
-            vec![],
-            vec!["i", "f", "f", "f", "f"],
-        )?;
+        let response = self.send(&commands::PatternLineGet {})?;
 
-        if result.len() >= 5 {
-            Ok(LineConfig {
-                num_points: result[0].as_i32()?,
-                point1_x_m: result[1].as_f32()?,
-                point1_y_m: result[2].as_f32()?,
-                point2_x_m: result[3].as_f32()?,
-                point2_y_m: result[4].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(LineConfig {
+            num_points: response.num_points,
+            point1_x_m: response.point1_x_m,
+            point1_y_m: response.point1_y_m,
+            point2_x_m: response.point2_x_m,
+            point2_y_m: response.point2_y_m,
+        })
     }
 
     /// Set the cloud pattern parameters.
@@ -323,17 +307,12 @@ impl NanonisClient {
         let active_flag = if set_active { 1u32 } else { 0u32 };
         let num_points = config.x_coords_m.len() as i32;
 
-        self.quick_send(
-            "Pattern.CloudSet",
-            vec![
-                NanonisValue::U32(active_flag),
-                NanonisValue::I32(num_points),
-                NanonisValue::ArrayF32(config.x_coords_m.clone()),
-                NanonisValue::ArrayF32(config.y_coords_m.clone()),
-            ],
-            vec!["I", "i", "*f", "*f"],
-            vec![],
-        )?;
+        self.send(&commands::PatternCloudSet {
+            set_active: active_flag,
+            num_points,
+            x_coords_m: config.x_coords_m.clone(),
+            y_coords_m: config.y_coords_m.clone(),
+        })?;
         Ok(())
     }
 
@@ -344,22 +323,34 @@ impl NanonisClient {
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{MockServer, NanonisValue};
+    ///
+    /// // Both coordinate arrays share the point count
+    /// let server = MockServer::start()?;
+    /// server.respond(
+    ///     "Pattern.CloudGet",
+    ///     vec![
+    ///         NanonisValue::I32(2),
+    ///         NanonisValue::ArrayF32(vec![1e-9, 2e-9]),
+    ///         NanonisValue::ArrayF32(vec![3e-9, 4e-9]),
+    ///     ],
+    ///     vec!["i", "*f", "*f"],
+    /// );
+    ///
+    /// let cloud = server.client()?.pattern_cloud_get()?;
+    /// assert_eq!(cloud.y_coords_m, [3e-9, 4e-9]);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn pattern_cloud_get(&mut self) -> Result<CloudConfig, NanonisError> {
-        let result = self.quick_send(
-            "Pattern.CloudGet",
-            vec![],
-            vec![],
-            vec!["i", "*f", "*f"],
-        )?;
+        let response = self.send(&commands::PatternCloudGet {})?;
 
-        if result.len() >= 3 {
-            Ok(CloudConfig {
-                x_coords_m: result[1].as_f32_array()?.to_vec(),
-                y_coords_m: result[2].as_f32_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(CloudConfig {
+            x_coords_m: response.x_coords_m,
+            y_coords_m: response.y_coords_m,
+        })
     }
 
     /// Set the pattern experiment properties.
@@ -383,18 +374,13 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let save_flag = if save_scan_channels { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "Pattern.PropsSet",
-            vec![
-                NanonisValue::String(selected_experiment.to_string()),
-                NanonisValue::String(basename.to_string()),
-                NanonisValue::String(external_vi_path.to_string()),
-                NanonisValue::F32(pre_measure_delay_s),
-                NanonisValue::U32(save_flag),
-            ],
-            vec!["+*c", "+*c", "+*c", "f", "I"],
-            vec![],
-        )?;
+        self.send(&commands::PatternPropsSet {
+            selected_experiment: selected_experiment.to_string(),
+            basename: basename.to_string(),
+            external_vi_path: external_vi_path.to_string(),
+            pre_measure_delay_s,
+            save_scan_channels: save_flag,
+        })?;
         Ok(())
     }
 
@@ -406,23 +392,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pattern_props_get(&mut self) -> Result<PatternProps, NanonisError> {
-        let result = self.quick_send(
-            "Pattern.PropsGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c", "i", "*-c", "i", "*-c", "f", "I"],
-        )?;
+        let response = self.send(&commands::PatternPropsGet {})?;
 
-        if result.len() >= 9 {
-            Ok(PatternProps {
-                available_experiments: result[2].as_string_array()?.to_vec(),
-                selected_experiment: result[4].as_string()?.to_string(),
-                external_vi_path: result[6].as_string()?.to_string(),
-                pre_measure_delay_s: result[7].as_f32()?,
-                save_scan_channels: result[8].as_u32()? != 0,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PatternProps {
+            available_experiments: response.available_experiments,
+            selected_experiment: response.selected_experiment,
+            external_vi_path: response.external_vi_path,
+            pre_measure_delay_s: response.pre_measure_delay_s,
+            save_scan_channels: response.save_scan_channels != 0,
+        })
     }
 }
@@ -0,0 +1,144 @@
+//! Typed descriptors for the `PICtrl.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `PICtrl.OnOffSet`: enable or disable a PI controller.
+    pub struct PiCtrlOnOffSet: "PICtrl.OnOffSet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PICtrl.OnOffGet`: get the on/off status of a PI controller.
+    pub struct PiCtrlOnOffGet: "PICtrl.OnOffGet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+    } -> PiCtrlOnOffGetResponse {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `PICtrl.CtrlChSet`: set the control channel for a PI controller.
+    pub struct PiCtrlCtrlChSet: "PICtrl.CtrlChSet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+        /// Index of the controlled output signal
+        pub signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PICtrl.CtrlChGet`: get the control channel information for a PI controller.
+    pub struct PiCtrlCtrlChGet: "PICtrl.CtrlChGet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+    } -> PiCtrlCtrlChGetResponse {
+        /// Currently selected signal index
+        pub current_index: i32 = "i",
+        /// Size of the signal names array in bytes
+        pub signal_names_size: i32 = "i",
+        /// Number of signal names
+        pub num_signal_names: i32 = "i",
+        /// Names of available signals
+        pub signal_names: Vec<String> = "*+c",
+        /// Number of signal indexes
+        pub num_signal_indexes: i32 = "i",
+        /// Indexes of available signals
+        pub signal_indexes: Vec<i32> = "*i",
+    }
+}
+
+nanonis_command! {
+    /// `PICtrl.InputChSet`: set the input channel for a PI controller.
+    pub struct PiCtrlInputChSet: "PICtrl.InputChSet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+        /// Index of the input signal
+        pub input_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PICtrl.InputChGet`: get the input channel information for a PI controller.
+    pub struct PiCtrlInputChGet: "PICtrl.InputChGet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+    } -> PiCtrlInputChGetResponse {
+        /// Currently selected signal index
+        pub current_index: i32 = "i",
+        /// Size of the signal names array in bytes
+        pub signal_names_size: i32 = "i",
+        /// Number of signal names
+        pub num_signal_names: i32 = "i",
+        /// Names of available signals
+        pub signal_names: Vec<String> = "*+c",
+        /// Number of signal indexes
+        pub num_signal_indexes: i32 = "i",
+        /// Indexes of available signals
+        pub signal_indexes: Vec<i32> = "*i",
+    }
+}
+
+nanonis_command! {
+    /// `PICtrl.PropsSet`: set the properties of a PI controller.
+    pub struct PiCtrlPropsSet: "PICtrl.PropsSet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+        /// Setpoint value
+        pub setpoint: f32 = "f",
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Integral gain
+        pub i_gain: f32 = "f",
+        /// Slope direction
+        pub slope: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PICtrl.PropsGet`: get the properties of a PI controller.
+    pub struct PiCtrlPropsGet: "PICtrl.PropsGet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+    } -> PiCtrlPropsGetResponse {
+        /// Setpoint value
+        pub setpoint: f32 = "f",
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Integral gain
+        pub i_gain: f32 = "f",
+        /// Slope direction
+        pub slope: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `PICtrl.CtrlChPropsSet`: set the control channel output limits for a PI controller.
+    pub struct PiCtrlCtrlChPropsSet: "PICtrl.CtrlChPropsSet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+        /// Lower output limit
+        pub lower_limit: f32 = "f",
+        /// Upper output limit
+        pub upper_limit: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PICtrl.CtrlChPropsGet`: get the control channel output limits for a PI controller.
+    pub struct PiCtrlCtrlChPropsGet: "PICtrl.CtrlChPropsGet" {
+        /// Controller index (1-8)
+        pub controller_index: i32 = "i",
+    } -> PiCtrlCtrlChPropsGetResponse {
+        /// Lower output limit
+        pub lower_limit: f32 = "f",
+        /// Upper output limit
+        pub upper_limit: f32 = "f",
+    }
+}
@@ -1,6 +1,8 @@
+pub mod commands;
+
+use super::signals::SignalRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// PI Controller slope direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -92,15 +94,7 @@ impl NanonisClient {
         enabled: bool,
     ) -> Result<(), NanonisError> {
         let status = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "PICtrl.OnOffSet",
-            vec![
-                NanonisValue::I32(controller_index),
-                NanonisValue::U32(status),
-            ],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::PiCtrlOnOffSet { controller_index, enabled: status })?;
         Ok(())
     }
 
@@ -115,42 +109,27 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pi_ctrl_on_off_get(&mut self, controller_index: i32) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "PICtrl.OnOffGet",
-            vec![NanonisValue::I32(controller_index)],
-            vec!["i"],
-            vec!["I"],
-        )?;
+        let response = self.send(&commands::PiCtrlOnOffGet { controller_index })?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.enabled != 0)
     }
 
     /// Set the control channel for a PI controller.
     ///
     /// # Arguments
     /// * `controller_index` - Controller index (1-8)
-    /// * `signal_index` - Control signal index
+    /// * `signal` - Control signal by index or name
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if the signal does not resolve or
+    /// communication fails.
     pub fn pi_ctrl_ctrl_ch_set(
         &mut self,
         controller_index: i32,
-        signal_index: i32,
+        signal: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PICtrl.CtrlChSet",
-            vec![
-                NanonisValue::I32(controller_index),
-                NanonisValue::I32(signal_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::PiCtrlCtrlChSet { controller_index, signal_index })?;
         Ok(())
     }
 
@@ -168,46 +147,31 @@ impl NanonisClient {
         &mut self,
         controller_index: i32,
     ) -> Result<ControlSignalInfo, NanonisError> {
-        let result = self.quick_send(
-            "PICtrl.CtrlChGet",
-            vec![NanonisValue::I32(controller_index)],
-            vec!["i"],
-            vec!["i", "i", "i", "*+c", "i", "*i"],
-        )?;
+        let response = self.send(&commands::PiCtrlCtrlChGet { controller_index })?;
 
-        if result.len() >= 6 {
-            Ok(ControlSignalInfo {
-                current_index: result[0].as_i32()?,
-                signal_names: result[3].as_string_array()?.to_vec(),
-                signal_indexes: result[5].as_i32_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(ControlSignalInfo {
+            current_index: response.current_index,
+            signal_names: response.signal_names,
+            signal_indexes: response.signal_indexes,
+        })
     }
 
     /// Set the input channel for a PI controller.
     ///
     /// # Arguments
     /// * `controller_index` - Controller index (1-8)
-    /// * `input_index` - Input signal index
+    /// * `input` - Input signal by index or name
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if the signal does not resolve or
+    /// communication fails.
     pub fn pi_ctrl_input_ch_set(
         &mut self,
         controller_index: i32,
-        input_index: i32,
+        input: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PICtrl.InputChSet",
-            vec![
-                NanonisValue::I32(controller_index),
-                NanonisValue::I32(input_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let input_index = self.resolve_signal(input)?;
+        self.send(&commands::PiCtrlInputChSet { controller_index, input_index })?;
         Ok(())
     }
 
@@ -225,22 +189,13 @@ impl NanonisClient {
         &mut self,
         controller_index: i32,
     ) -> Result<ControlSignalInfo, NanonisError> {
-        let result = self.quick_send(
-            "PICtrl.InputChGet",
-            vec![NanonisValue::I32(controller_index)],
-            vec!["i"],
-            vec!["i", "i", "i", "*+c", "i", "*i"],
-        )?;
+        let response = self.send(&commands::PiCtrlInputChGet { controller_index })?;
 
-        if result.len() >= 6 {
-            Ok(ControlSignalInfo {
-                current_index: result[0].as_i32()?,
-                signal_names: result[3].as_string_array()?.to_vec(),
-                signal_indexes: result[5].as_i32_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(ControlSignalInfo {
+            current_index: response.current_index,
+            signal_names: response.signal_names,
+            signal_indexes: response.signal_indexes,
+        })
     }
 
     /// Set the properties of a PI controller.
@@ -272,18 +227,13 @@ impl NanonisClient {
         controller_index: i32,
         props: &PICtrlProps,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PICtrl.PropsSet",
-            vec![
-                NanonisValue::I32(controller_index),
-                NanonisValue::F32(props.setpoint),
-                NanonisValue::F32(props.p_gain),
-                NanonisValue::F32(props.i_gain),
-                NanonisValue::U16(props.slope.into()),
-            ],
-            vec!["i", "f", "f", "f", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PiCtrlPropsSet {
+            controller_index,
+            setpoint: props.setpoint,
+            p_gain: props.p_gain,
+            i_gain: props.i_gain,
+            slope: props.slope.into(),
+        })?;
         Ok(())
     }
 
@@ -298,23 +248,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pi_ctrl_props_get(&mut self, controller_index: i32) -> Result<PICtrlProps, NanonisError> {
-        let result = self.quick_send(
-            "PICtrl.PropsGet",
-            vec![NanonisValue::I32(controller_index)],
-            vec!["i"],
-            vec!["f", "f", "f", "H"],
-        )?;
+        let response = self.send(&commands::PiCtrlPropsGet { controller_index })?;
 
-        if result.len() >= 4 {
-            Ok(PICtrlProps {
-                setpoint: result[0].as_f32()?,
-                p_gain: result[1].as_f32()?,
-                i_gain: result[2].as_f32()?,
-                slope: result[3].as_u16()?.try_into()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PICtrlProps {
+            setpoint: response.setpoint,
+            p_gain: response.p_gain,
+            i_gain: response.i_gain,
+            slope: response.slope.try_into()?,
+        })
     }
 
     /// Set the control channel output limits for a PI controller.
@@ -330,16 +271,11 @@ impl NanonisClient {
         controller_index: i32,
         limits: &PICtrlLimits,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PICtrl.CtrlChPropsSet",
-            vec![
-                NanonisValue::I32(controller_index),
-                NanonisValue::F32(limits.lower_limit),
-                NanonisValue::F32(limits.upper_limit),
-            ],
-            vec!["i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PiCtrlCtrlChPropsSet {
+            controller_index,
+            lower_limit: limits.lower_limit,
+            upper_limit: limits.upper_limit,
+        })?;
         Ok(())
     }
 
@@ -357,20 +293,11 @@ impl NanonisClient {
         &mut self,
         controller_index: i32,
     ) -> Result<PICtrlLimits, NanonisError> {
-        let result = self.quick_send(
-            "PICtrl.CtrlChPropsGet",
-            vec![NanonisValue::I32(controller_index)],
-            vec!["i"],
-            vec!["f", "f"],
-        )?;
+        let response = self.send(&commands::PiCtrlCtrlChPropsGet { controller_index })?;
 
-        if result.len() >= 2 {
-            Ok(PICtrlLimits {
-                lower_limit: result[0].as_f32()?,
-                upper_limit: result[1].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PICtrlLimits {
+            lower_limit: response.lower_limit,
+            upper_limit: response.upper_limit,
+        })
     }
 }
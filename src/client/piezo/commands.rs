@@ -0,0 +1,271 @@
+//! Typed descriptors for the `Piezo.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Piezo.TiltSet`: set the piezo tilt correction parameters.
+    pub struct PiezoTiltSet: "Piezo.TiltSet" {
+        /// Tilt angle correction in X direction (degrees)
+        pub tilt_x_deg: f32 = "f",
+        /// Tilt angle correction in Y direction (degrees)
+        pub tilt_y_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.TiltGet`: get the piezo tilt correction parameters.
+    pub struct PiezoTiltGet: "Piezo.TiltGet" {} -> PiezoTiltGetResponse {
+        /// Tilt angle in X direction (degrees)
+        pub tilt_x_deg: f32 = "f",
+        /// Tilt angle in Y direction (degrees)
+        pub tilt_y_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.RangeSet`: set the piezo range values for all 3 axes.
+    pub struct PiezoRangeSet: "Piezo.RangeSet" {
+        /// Range in X direction (meters)
+        pub range_x_m: f32 = "f",
+        /// Range in Y direction (meters)
+        pub range_y_m: f32 = "f",
+        /// Range in Z direction (meters)
+        pub range_z_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.RangeGet`: get the piezo range values for all 3 axes.
+    pub struct PiezoRangeGet: "Piezo.RangeGet" {} -> PiezoRangeGetResponse {
+        /// Range in X direction (meters)
+        pub range_x_m: f32 = "f",
+        /// Range in Y direction (meters)
+        pub range_y_m: f32 = "f",
+        /// Range in Z direction (meters)
+        pub range_z_m: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.SensSet`: set the piezo sensitivity values for all 3 axes.
+    pub struct PiezoSensSet: "Piezo.SensSet" {
+        /// Sensitivity in X direction (m/V)
+        pub sens_x_m_per_v: f32 = "f",
+        /// Sensitivity in Y direction (m/V)
+        pub sens_y_m_per_v: f32 = "f",
+        /// Sensitivity in Z direction (m/V)
+        pub sens_z_m_per_v: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.SensGet`: get the piezo sensitivity values for all 3 axes.
+    pub struct PiezoSensGet: "Piezo.SensGet" {} -> PiezoSensGetResponse {
+        /// Sensitivity in X direction (m/V)
+        pub sens_x_m_per_v: f32 = "f",
+        /// Sensitivity in Y direction (m/V)
+        pub sens_y_m_per_v: f32 = "f",
+        /// Sensitivity in Z direction (m/V)
+        pub sens_z_m_per_v: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.DriftCompSet`: set the drift compensation parameters.
+    pub struct PiezoDriftCompSet: "Piezo.DriftCompSet" {
+        /// 0 = no change, 1 = on, 2 = off
+        pub enabled: u32 = "I",
+        /// Linear speed in X direction (m/s)
+        pub vx_m_s: f32 = "f",
+        /// Linear speed in Y direction (m/s)
+        pub vy_m_s: f32 = "f",
+        /// Linear speed in Z direction (m/s)
+        pub vz_m_s: f32 = "f",
+        /// Saturation limit as a fraction of the piezo range
+        pub saturation_limit: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.DriftCompGet`: get the drift compensation settings and status.
+    pub struct PiezoDriftCompGet: "Piezo.DriftCompGet" {} -> PiezoDriftCompGetResponse {
+        /// 1 if drift compensation is on
+        pub enabled: u32 = "I",
+        /// Linear speed in X direction (m/s)
+        pub vx_m_s: f32 = "f",
+        /// Linear speed in Y direction (m/s)
+        pub vy_m_s: f32 = "f",
+        /// Linear speed in Z direction (m/s)
+        pub vz_m_s: f32 = "f",
+        /// X axis reached saturation limit
+        pub x_saturated: u32 = "I",
+        /// Y axis reached saturation limit
+        pub y_saturated: u32 = "I",
+        /// Z axis reached saturation limit
+        pub z_saturated: u32 = "I",
+        /// Saturation limit as a fraction of the piezo range
+        pub saturation_limit: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.CalibrGet`: get the piezo calibration values for all 3 axes.
+    pub struct PiezoCalibrGet: "Piezo.CalibrGet" {} -> PiezoCalibrGetResponse {
+        /// Sensitivity in X direction (m/V)
+        pub sens_x_m_per_v: f32 = "f",
+        /// Sensitivity in Y direction (m/V)
+        pub sens_y_m_per_v: f32 = "f",
+        /// Sensitivity in Z direction (m/V)
+        pub sens_z_m_per_v: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.HVAInfoGet`: get the HVA (High Voltage Amplifier) gain information.
+    pub struct PiezoHvaInfoGet: "Piezo.HVAInfoGet" {} -> PiezoHvaInfoGetResponse {
+        /// AUX gain
+        pub gain_aux: f32 = "f",
+        /// X gain
+        pub gain_x: f32 = "f",
+        /// Y gain
+        pub gain_y: f32 = "f",
+        /// Z gain
+        pub gain_z: f32 = "f",
+        /// XY enabled
+        pub xy_enabled: u32 = "I",
+        /// Z enabled
+        pub z_enabled: u32 = "I",
+        /// AUX enabled
+        pub aux_enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.HVAStatusLEDGet`: get the HVA status LED indicators.
+    pub struct PiezoHvaStatusLedGet: "Piezo.HVAStatusLEDGet" {} -> PiezoHvaStatusLedGetResponse {
+        /// Overheated status
+        pub overheated: u32 = "I",
+        /// HV supply status
+        pub hv_supply: u32 = "I",
+        /// High temperature status
+        pub high_temperature: u32 = "I",
+        /// Output connector status
+        pub output_connector: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.XYZLimitsSet`: set the XYZ voltage limits.
+    pub struct PiezoXyzLimitsSet: "Piezo.XYZLimitsSet" {
+        /// Enable/disable limits (use [`PiezoToggle`])
+        pub enable: u16 = "H",
+        /// X low voltage limit (V)
+        pub x_low_v: f32 = "f",
+        /// X high voltage limit (V)
+        pub x_high_v: f32 = "f",
+        /// Y low voltage limit (V)
+        pub y_low_v: f32 = "f",
+        /// Y high voltage limit (V)
+        pub y_high_v: f32 = "f",
+        /// Z low voltage limit (V)
+        pub z_low_v: f32 = "f",
+        /// Z high voltage limit (V)
+        pub z_high_v: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.XYZLimitsGet`: get the XYZ voltage limits.
+    pub struct PiezoXyzLimitsGet: "Piezo.XYZLimitsGet" {} -> PiezoXyzLimitsGetResponse {
+        /// 1 if the voltage limits are enabled
+        pub enabled: u16 = "H",
+        /// X low voltage limit (V)
+        pub x_low_v: f32 = "f",
+        /// X high voltage limit (V)
+        pub x_high_v: f32 = "f",
+        /// Y low voltage limit (V)
+        pub y_low_v: f32 = "f",
+        /// Y high voltage limit (V)
+        pub y_high_v: f32 = "f",
+        /// Z low voltage limit (V)
+        pub z_low_v: f32 = "f",
+        /// Z high voltage limit (V)
+        pub z_high_v: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.HystOnOffSet`: enable or disable hysteresis compensation.
+    pub struct PiezoHystOnOffSet: "Piezo.HystOnOffSet" {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.HystOnOffGet`: get hysteresis compensation enabled status.
+    pub struct PiezoHystOnOffGet: "Piezo.HystOnOffGet" {} -> PiezoHystOnOffGetResponse {
+        /// True to enable, false to disable
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.HystValsSet`: set and apply the hysteresis compensation values.
+    pub struct PiezoHystValsSet: "Piezo.HystValsSet" {
+        /// Number of fast axis X points
+        pub num_fast_x_points: i32 = "i",
+        /// X coordinates of the fast axis hysteresis points
+        pub fast_x_points: Vec<f32> = "*f",
+        /// Number of fast axis Y points
+        pub num_fast_y_points: i32 = "i",
+        /// Y coordinates of the fast axis hysteresis points
+        pub fast_y_points: Vec<f32> = "*f",
+        /// Number of slow axis X points
+        pub num_slow_x_points: i32 = "i",
+        /// X coordinates of the slow axis hysteresis points
+        pub slow_x_points: Vec<f32> = "*f",
+        /// Number of slow axis Y points
+        pub num_slow_y_points: i32 = "i",
+        /// Y coordinates of the slow axis hysteresis points
+        pub slow_y_points: Vec<f32> = "*f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.HystValsGet`: get the hysteresis compensation values.
+    pub struct PiezoHystValsGet: "Piezo.HystValsGet" {} -> PiezoHystValsGetResponse {
+        /// Number of fast axis X points
+        pub num_fast_x_points: i32 = "i",
+        /// X coordinates of the fast axis hysteresis points
+        pub fast_x_points: Vec<f32> = "*f",
+        /// Number of fast axis Y points
+        pub num_fast_y_points: i32 = "i",
+        /// Y coordinates of the fast axis hysteresis points
+        pub fast_y_points: Vec<f32> = "*f",
+        /// Number of slow axis X points
+        pub num_slow_x_points: i32 = "i",
+        /// X coordinates of the slow axis hysteresis points
+        pub slow_x_points: Vec<f32> = "*f",
+        /// Number of slow axis Y points
+        pub num_slow_y_points: i32 = "i",
+        /// Y coordinates of the slow axis hysteresis points
+        pub slow_y_points: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `Piezo.HystFileLoad`: load hysteresis compensation values from a CSV file.
+    pub struct PiezoHystFileLoad: "Piezo.HystFileLoad" {
+        /// Path to the CSV file to load
+        pub file_path: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Piezo.HystFileSave`: save hysteresis compensation values to a CSV file.
+    pub struct PiezoHystFileSave: "Piezo.HystFileSave" {
+        /// Path to the CSV file to save
+        pub file_path: String = "+*c",
+    } -> ()
+}
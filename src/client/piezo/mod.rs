@@ -1,9 +1,10 @@
 pub mod types;
 pub use types::*;
 
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     /// Set the piezo tilt correction parameters.
@@ -24,12 +25,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn piezo_tilt_set(&mut self, tilt_x_deg: f32, tilt_y_deg: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.TiltSet",
-            vec![NanonisValue::F32(tilt_x_deg), NanonisValue::F32(tilt_y_deg)],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoTiltSet { tilt_x_deg, tilt_y_deg })?;
         Ok(())
     }
 
@@ -41,16 +37,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_tilt_get(&mut self) -> Result<TiltCorrection, NanonisError> {
-        let result = self.quick_send("Piezo.TiltGet", vec![], vec![], vec!["f", "f"])?;
+        let response = self.send(&commands::PiezoTiltGet {})?;
 
-        if result.len() >= 2 {
-            Ok(TiltCorrection {
-                tilt_x_deg: result[0].as_f32()?,
-                tilt_y_deg: result[1].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(TiltCorrection {
+            tilt_x_deg: response.tilt_x_deg,
+            tilt_y_deg: response.tilt_y_deg,
+        })
     }
 
     /// Set the piezo range values for all 3 axes.
@@ -64,16 +56,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_range_set(&mut self, range: &PiezoRange) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.RangeSet",
-            vec![
-                NanonisValue::F32(range.range_x_m),
-                NanonisValue::F32(range.range_y_m),
-                NanonisValue::F32(range.range_z_m),
-            ],
-            vec!["f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoRangeSet {
+            range_x_m: range.range_x_m,
+            range_y_m: range.range_y_m,
+            range_z_m: range.range_z_m,
+        })?;
         Ok(())
     }
 
@@ -85,17 +72,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_range_get(&mut self) -> Result<PiezoRange, NanonisError> {
-        let result = self.quick_send("Piezo.RangeGet", vec![], vec![], vec!["f", "f", "f"])?;
+        let response = self.send(&commands::PiezoRangeGet {})?;
 
-        if result.len() >= 3 {
-            Ok(PiezoRange {
-                range_x_m: result[0].as_f32()?,
-                range_y_m: result[1].as_f32()?,
-                range_z_m: result[2].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PiezoRange {
+            range_x_m: response.range_x_m,
+            range_y_m: response.range_y_m,
+            range_z_m: response.range_z_m,
+        })
     }
 
     /// Set the piezo sensitivity values for all 3 axes.
@@ -109,16 +92,11 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_sens_set(&mut self, sensitivity: &PiezoSensitivity) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.SensSet",
-            vec![
-                NanonisValue::F32(sensitivity.sens_x_m_per_v),
-                NanonisValue::F32(sensitivity.sens_y_m_per_v),
-                NanonisValue::F32(sensitivity.sens_z_m_per_v),
-            ],
-            vec!["f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoSensSet {
+            sens_x_m_per_v: sensitivity.sens_x_m_per_v,
+            sens_y_m_per_v: sensitivity.sens_y_m_per_v,
+            sens_z_m_per_v: sensitivity.sens_z_m_per_v,
+        })?;
         Ok(())
     }
 
@@ -130,17 +108,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_sens_get(&mut self) -> Result<PiezoSensitivity, NanonisError> {
-        let result = self.quick_send("Piezo.SensGet", vec![], vec![], vec!["f", "f", "f"])?;
+        let response = self.send(&commands::PiezoSensGet {})?;
 
-        if result.len() >= 3 {
-            Ok(PiezoSensitivity {
-                sens_x_m_per_v: result[0].as_f32()?,
-                sens_y_m_per_v: result[1].as_f32()?,
-                sens_z_m_per_v: result[2].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PiezoSensitivity {
+            sens_x_m_per_v: response.sens_x_m_per_v,
+            sens_y_m_per_v: response.sens_y_m_per_v,
+            sens_z_m_per_v: response.sens_z_m_per_v,
+        })
     }
 
     /// Set the drift compensation parameters.
@@ -168,18 +142,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn piezo_drift_comp_set(&mut self, config: &DriftCompConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.DriftCompSet",
-            vec![
-                NanonisValue::U32(config.enabled.into()),
-                NanonisValue::F32(config.vx_m_s),
-                NanonisValue::F32(config.vy_m_s),
-                NanonisValue::F32(config.vz_m_s),
-                NanonisValue::F32(config.saturation_limit),
-            ],
-            vec!["I", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoDriftCompSet {
+            enabled: config.enabled.into(),
+            vx_m_s: config.vx_m_s,
+            vy_m_s: config.vy_m_s,
+            vz_m_s: config.vz_m_s,
+            saturation_limit: config.saturation_limit,
+        })?;
         Ok(())
     }
 
@@ -191,27 +160,18 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_drift_comp_get(&mut self) -> Result<DriftCompStatus, NanonisError> {
-        let result = self.quick_send(
-            "Piezo.DriftCompGet",
-            vec![],
-            vec![],
-            vec!["I", "f", "f", "f", "I", "I", "I", "f"],
-        )?;
+        let response = self.send(&commands::PiezoDriftCompGet {})?;
 
-        if result.len() >= 8 {
-            Ok(DriftCompStatus {
-                enabled: result[0].as_u32()? != 0,
-                vx_m_s: result[1].as_f32()?,
-                vy_m_s: result[2].as_f32()?,
-                vz_m_s: result[3].as_f32()?,
-                x_saturated: result[4].as_u32()? != 0,
-                y_saturated: result[5].as_u32()? != 0,
-                z_saturated: result[6].as_u32()? != 0,
-                saturation_limit: result[7].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(DriftCompStatus {
+            enabled: response.enabled != 0,
+            vx_m_s: response.vx_m_s,
+            vy_m_s: response.vy_m_s,
+            vz_m_s: response.vz_m_s,
+            x_saturated: response.x_saturated != 0,
+            y_saturated: response.y_saturated != 0,
+            z_saturated: response.z_saturated != 0,
+            saturation_limit: response.saturation_limit,
+        })
     }
 
     /// Get the piezo calibration values for all 3 axes.
@@ -225,17 +185,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_calibr_get(&mut self) -> Result<PiezoSensitivity, NanonisError> {
-        let result = self.quick_send("Piezo.CalibrGet", vec![], vec![], vec!["f", "f", "f"])?;
+        let response = self.send(&commands::PiezoCalibrGet {})?;
 
-        if result.len() >= 3 {
-            Ok(PiezoSensitivity {
-                sens_x_m_per_v: result[0].as_f32()?,
-                sens_y_m_per_v: result[1].as_f32()?,
-                sens_z_m_per_v: result[2].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PiezoSensitivity {
+            sens_x_m_per_v: response.sens_x_m_per_v,
+            sens_y_m_per_v: response.sens_y_m_per_v,
+            sens_z_m_per_v: response.sens_z_m_per_v,
+        })
     }
 
     /// Get the HVA (High Voltage Amplifier) gain information.
@@ -248,26 +204,17 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_hva_info_get(&mut self) -> Result<HVAInfo, NanonisError> {
-        let result = self.quick_send(
-            "Piezo.HVAInfoGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "f", "f", "I", "I", "I"],
-        )?;
+        let response = self.send(&commands::PiezoHvaInfoGet {})?;
 
-        if result.len() >= 7 {
-            Ok(HVAInfo {
-                gain_aux: result[0].as_f32()?,
-                gain_x: result[1].as_f32()?,
-                gain_y: result[2].as_f32()?,
-                gain_z: result[3].as_f32()?,
-                xy_enabled: result[4].as_u32()? != 0,
-                z_enabled: result[5].as_u32()? != 0,
-                aux_enabled: result[6].as_u32()? != 0,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(HVAInfo {
+            gain_aux: response.gain_aux,
+            gain_x: response.gain_x,
+            gain_y: response.gain_y,
+            gain_z: response.gain_z,
+            xy_enabled: response.xy_enabled != 0,
+            z_enabled: response.z_enabled != 0,
+            aux_enabled: response.aux_enabled != 0,
+        })
     }
 
     /// Get the HVA status LED indicators.
@@ -278,23 +225,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_hva_status_led_get(&mut self) -> Result<HVAStatusLED, NanonisError> {
-        let result = self.quick_send(
-            "Piezo.HVAStatusLEDGet",
-            vec![],
-            vec![],
-            vec!["I", "I", "I", "I"],
-        )?;
+        let response = self.send(&commands::PiezoHvaStatusLedGet {})?;
 
-        if result.len() >= 4 {
-            Ok(HVAStatusLED {
-                overheated: result[0].as_u32()? != 0,
-                hv_supply: result[1].as_u32()? != 0,
-                high_temperature: result[2].as_u32()? != 0,
-                output_connector: result[3].as_u32()? != 0,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(HVAStatusLED {
+            overheated: response.overheated != 0,
+            hv_supply: response.hv_supply != 0,
+            high_temperature: response.high_temperature != 0,
+            output_connector: response.output_connector != 0,
+        })
     }
 
     /// Set the XYZ voltage limits.
@@ -310,20 +248,15 @@ impl NanonisClient {
         enable: PiezoToggle,
         limits: &XYZLimits,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.XYZLimitsSet",
-            vec![
-                NanonisValue::U16(enable.into()),
-                NanonisValue::F32(limits.x_low_v),
-                NanonisValue::F32(limits.x_high_v),
-                NanonisValue::F32(limits.y_low_v),
-                NanonisValue::F32(limits.y_high_v),
-                NanonisValue::F32(limits.z_low_v),
-                NanonisValue::F32(limits.z_high_v),
-            ],
-            vec!["H", "f", "f", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoXyzLimitsSet {
+            enable: enable.into(),
+            x_low_v: limits.x_low_v,
+            x_high_v: limits.x_high_v,
+            y_low_v: limits.y_low_v,
+            y_high_v: limits.y_high_v,
+            z_low_v: limits.z_low_v,
+            z_high_v: limits.z_high_v,
+        })?;
         Ok(())
     }
 
@@ -335,26 +268,17 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_xyz_limits_get(&mut self) -> Result<XYZLimits, NanonisError> {
-        let result = self.quick_send(
-            "Piezo.XYZLimitsGet",
-            vec![],
-            vec![],
-            vec!["H", "f", "f", "f", "f", "f", "f"],
-        )?;
+        let response = self.send(&commands::PiezoXyzLimitsGet {})?;
 
-        if result.len() >= 7 {
-            Ok(XYZLimits {
-                enabled: result[0].as_u16()? != 0,
-                x_low_v: result[1].as_f32()?,
-                x_high_v: result[2].as_f32()?,
-                y_low_v: result[3].as_f32()?,
-                y_high_v: result[4].as_f32()?,
-                z_low_v: result[5].as_f32()?,
-                z_high_v: result[6].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(XYZLimits {
+            enabled: response.enabled != 0,
+            x_low_v: response.x_low_v,
+            x_high_v: response.x_high_v,
+            y_low_v: response.y_low_v,
+            y_high_v: response.y_high_v,
+            z_low_v: response.z_low_v,
+            z_high_v: response.z_high_v,
+        })
     }
 
     /// Enable or disable hysteresis compensation.
@@ -366,12 +290,7 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_hyst_on_off_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "Piezo.HystOnOffSet",
-            vec![NanonisValue::U32(flag)],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoHystOnOffSet { enabled: flag })?;
         Ok(())
     }
 
@@ -383,13 +302,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_hyst_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("Piezo.HystOnOffGet", vec![], vec![], vec!["I"])?;
+        let response = self.send(&commands::PiezoHystOnOffGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.enabled != 0)
     }
 
     /// Set and apply the hysteresis compensation values.
@@ -400,21 +315,16 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_hyst_vals_set(&mut self, values: &HysteresisValues) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.HystValsSet",
-            vec![
-                NanonisValue::I32(values.fast_axis.x_points.len() as i32),
-                NanonisValue::ArrayF32(values.fast_axis.x_points.clone()),
-                NanonisValue::I32(values.fast_axis.y_points.len() as i32),
-                NanonisValue::ArrayF32(values.fast_axis.y_points.clone()),
-                NanonisValue::I32(values.slow_axis.x_points.len() as i32),
-                NanonisValue::ArrayF32(values.slow_axis.x_points.clone()),
-                NanonisValue::I32(values.slow_axis.y_points.len() as i32),
-                NanonisValue::ArrayF32(values.slow_axis.y_points.clone()),
-            ],
-            vec!["i", "*f", "i", "*f", "i", "*f", "i", "*f"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoHystValsSet {
+            num_fast_x_points: values.fast_axis.x_points.len() as i32,
+            fast_x_points: values.fast_axis.x_points.clone(),
+            num_fast_y_points: values.fast_axis.y_points.len() as i32,
+            fast_y_points: values.fast_axis.y_points.clone(),
+            num_slow_x_points: values.slow_axis.x_points.len() as i32,
+            slow_x_points: values.slow_axis.x_points.clone(),
+            num_slow_y_points: values.slow_axis.y_points.len() as i32,
+            slow_y_points: values.slow_axis.y_points.clone(),
+        })?;
         Ok(())
     }
 
@@ -426,27 +336,18 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn piezo_hyst_vals_get(&mut self) -> Result<HysteresisValues, NanonisError> {
-        let result = self.quick_send(
-            "Piezo.HystValsGet",
-            vec![],
-            vec![],
-            vec!["i", "*f", "i", "*f", "i", "*f", "i", "*f"],
-        )?;
+        let response = self.send(&commands::PiezoHystValsGet {})?;
 
-        if result.len() >= 8 {
-            Ok(HysteresisValues {
-                fast_axis: HysteresisAxisPoints {
-                    x_points: result[1].as_f32_array()?.to_vec(),
-                    y_points: result[3].as_f32_array()?.to_vec(),
-                },
-                slow_axis: HysteresisAxisPoints {
-                    x_points: result[5].as_f32_array()?.to_vec(),
-                    y_points: result[7].as_f32_array()?.to_vec(),
-                },
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(HysteresisValues {
+            fast_axis: HysteresisAxisPoints {
+                x_points: response.fast_x_points,
+                y_points: response.fast_y_points,
+            },
+            slow_axis: HysteresisAxisPoints {
+                x_points: response.slow_x_points,
+                y_points: response.slow_y_points,
+            },
+        })
     }
 
     /// Load hysteresis compensation values from a CSV file.
@@ -457,12 +358,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails or file cannot be loaded.
     pub fn piezo_hyst_file_load(&mut self, file_path: &str) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.HystFileLoad",
-            vec![NanonisValue::String(file_path.to_string())],
-            vec!["+*c"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoHystFileLoad { file_path: file_path.to_string() })?;
         Ok(())
     }
 
@@ -474,12 +370,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails or file cannot be saved.
     pub fn piezo_hyst_file_save(&mut self, file_path: &str) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Piezo.HystFileSave",
-            vec![NanonisValue::String(file_path.to_string())],
-            vec!["+*c"],
-            vec![],
-        )?;
+        self.send(&commands::PiezoHystFileSave { file_path: file_path.to_string() })?;
         Ok(())
     }
 }
@@ -1,3 +1,6 @@
+use crate::units::Meters;
+use serde::{Deserialize, Serialize};
+
 // ==================== Piezo Types ====================
 
 /// On/Off toggle with no-change option for piezo settings.
@@ -25,7 +28,7 @@ impl From<PiezoToggle> for u16 {
 }
 
 /// XYZ tilt correction angles.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub struct TiltCorrection {
     /// Tilt angle in X direction (degrees)
     pub tilt_x_deg: f32,
@@ -44,6 +47,16 @@ pub struct PiezoRange {
     pub range_z_m: f32,
 }
 
+impl PiezoRange {
+    pub fn new(x: impl Into<Meters>, y: impl Into<Meters>, z: impl Into<Meters>) -> Self {
+        Self {
+            range_x_m: x.into().as_f32(),
+            range_y_m: y.into().as_f32(),
+            range_z_m: z.into().as_f32(),
+        }
+    }
+}
+
 /// XYZ piezo sensitivity (m/V).
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PiezoSensitivity {
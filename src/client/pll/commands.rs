@@ -0,0 +1,493 @@
+//! Typed descriptors for the `PLL.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `PLL.InpCalibrSet`: set the input calibration of the oscillation control module.
+    pub struct PllInpCalibrSet: "PLL.InpCalibrSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Input calibration in m/V
+        pub calibration_m_per_v: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.InpCalibrGet`: get the input calibration of the oscillation control module.
+    pub struct PllInpCalibrGet: "PLL.InpCalibrGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllInpCalibrGetResponse {
+        /// Input calibration in m/V
+        pub calibration_m_per_v: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.InpRangeSet`: set the input range of the oscillation control module.
+    pub struct PllInpRangeSet: "PLL.InpRangeSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Input range in meters
+        pub input_range_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.InpRangeGet`: get the input range of the oscillation control module.
+    pub struct PllInpRangeGet: "PLL.InpRangeGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllInpRangeGetResponse {
+        /// Input range in meters
+        pub input_range_m: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.InpPropsSet`: set the input properties of the oscillation control module.
+    pub struct PllInpPropsSet: "PLL.InpPropsSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Differential input enabled
+        pub differential_input: u16 = "H",
+        /// 1/10 divider enabled
+        pub divider_1_10: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.InpPropsGet`: get the input properties of the oscillation control module.
+    pub struct PllInpPropsGet: "PLL.InpPropsGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllInpPropsGetResponse {
+        /// Differential input enabled
+        pub differential_input: u16 = "H",
+        /// 1/10 divider enabled
+        pub divider_1_10: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.AddOnOffSet`: set the add external signal status.
+    pub struct PllAddOnOffSet: "PLL.AddOnOffSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// True to add external signal to output
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.AddOnOffGet`: get the add external signal status.
+    pub struct PllAddOnOffGet: "PLL.AddOnOffGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllAddOnOffGetResponse {
+        /// True to add external signal to output
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.OutOnOffSet`: set the PLL output on/off status.
+    pub struct PllOutOnOffSet: "PLL.OutOnOffSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// True to enable PLL output
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.OutOnOffGet`: get the PLL output on/off status.
+    pub struct PllOutOnOffGet: "PLL.OutOnOffGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllOutOnOffGetResponse {
+        /// True to enable PLL output
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.ExcRangeSet`: set the excitation range.
+    pub struct PllExcRangeSet: "PLL.ExcRangeSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Excitation output range
+        pub range: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.ExcRangeGet`: get the excitation range.
+    pub struct PllExcRangeGet: "PLL.ExcRangeGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllExcRangeGetResponse {
+        /// Excitation output range
+        pub range: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.ExcitationSet`: set the excitation value (drive amplitude).
+    pub struct PllExcitationSet: "PLL.ExcitationSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Excitation value in volts
+        pub excitation_v: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.ExcitationGet`: get the excitation value (drive amplitude).
+    pub struct PllExcitationGet: "PLL.ExcitationGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllExcitationGetResponse {
+        /// Excitation value in volts
+        pub excitation_v: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlSetpntSet`: set the amplitude controller setpoint for a PLL modulator.
+    pub struct PllAmpCtrlSetpntSet: "PLL.AmpCtrlSetpntSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Amplitude setpoint in meters
+        pub setpoint_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlSetpntGet`: get the amplitude controller setpoint for a PLL modulator.
+    pub struct PllAmpCtrlSetpntGet: "PLL.AmpCtrlSetpntGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllAmpCtrlSetpntGetResponse {
+        /// Amplitude setpoint in meters
+        pub setpoint_m: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlOnOffSet`: set the amplitude controller on/off status for a PLL modulator.
+    pub struct PllAmpCtrlOnOffSet: "PLL.AmpCtrlOnOffSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// True to turn on, false to turn off
+        pub status: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlOnOffGet`: get the amplitude controller on/off status for a PLL modulator.
+    pub struct PllAmpCtrlOnOffGet: "PLL.AmpCtrlOnOffGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllAmpCtrlOnOffGetResponse {
+        /// True to turn on, false to turn off
+        pub status: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlGainSet`: set the amplitude controller gain parameters for a PLL modulator.
+    pub struct PllAmpCtrlGainSet: "PLL.AmpCtrlGainSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Proportional gain in V/m
+        pub p_gain_v_div_m: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlGainGet`: get the amplitude controller gain parameters for a PLL modulator.
+    pub struct PllAmpCtrlGainGet: "PLL.AmpCtrlGainGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllAmpCtrlGainGetResponse {
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant in seconds
+        pub time_const: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlBandwidthSet`: set the amplitude controller bandwidth.
+    pub struct PllAmpCtrlBandwidthSet: "PLL.AmpCtrlBandwidthSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Bandwidth in Hz
+        pub bandwidth_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.AmpCtrlBandwidthGet`: get the amplitude controller bandwidth.
+    pub struct PllAmpCtrlBandwidthGet: "PLL.AmpCtrlBandwidthGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllAmpCtrlBandwidthGetResponse {
+        /// Bandwidth in Hz
+        pub bandwidth_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.PhasCtrlOnOffSet`: set the phase controller on/off status.
+    pub struct PllPhasCtrlOnOffSet: "PLL.PhasCtrlOnOffSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// True to enable phase controller
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.PhasCtrlOnOffGet`: get the phase controller on/off status.
+    pub struct PllPhasCtrlOnOffGet: "PLL.PhasCtrlOnOffGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllPhasCtrlOnOffGetResponse {
+        /// True to enable phase controller
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.PhasCtrlGainSet`: set the phase controller gain parameters.
+    pub struct PllPhasCtrlGainSet: "PLL.PhasCtrlGainSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Proportional gain in Hz/deg
+        pub p_gain_hz_per_deg: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.PhasCtrlGainGet`: get the phase controller gain parameters.
+    pub struct PllPhasCtrlGainGet: "PLL.PhasCtrlGainGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllPhasCtrlGainGetResponse {
+        /// Proportional gain in Hz/deg
+        pub p_gain_hz_per_deg: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.PhasCtrlBandwidthSet`: set the phase controller bandwidth.
+    pub struct PllPhasCtrlBandwidthSet: "PLL.PhasCtrlBandwidthSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Bandwidth in Hz
+        pub bandwidth_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.PhasCtrlBandwidthGet`: get the phase controller bandwidth.
+    pub struct PllPhasCtrlBandwidthGet: "PLL.PhasCtrlBandwidthGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllPhasCtrlBandwidthGetResponse {
+        /// Bandwidth in Hz
+        pub bandwidth_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.FreqRangeSet`: set the frequency range.
+    pub struct PllFreqRangeSet: "PLL.FreqRangeSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Frequency range in Hz
+        pub freq_range_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.FreqRangeGet`: get the frequency range.
+    pub struct PllFreqRangeGet: "PLL.FreqRangeGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllFreqRangeGetResponse {
+        /// Frequency range in Hz
+        pub freq_range_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.CenterFreqSet`: set the center frequency.
+    pub struct PllCenterFreqSet: "PLL.CenterFreqSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Center frequency in Hz
+        pub center_freq_hz: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.CenterFreqGet`: get the center frequency.
+    pub struct PllCenterFreqGet: "PLL.CenterFreqGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllCenterFreqGetResponse {
+        /// Center frequency in Hz
+        pub center_freq_hz: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.FreqShiftSet`: set the frequency shift.
+    pub struct PllFreqShiftSet: "PLL.FreqShiftSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Frequency shift in Hz
+        pub freq_shift_hz: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.FreqShiftGet`: get the frequency shift.
+    pub struct PllFreqShiftGet: "PLL.FreqShiftGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllFreqShiftGetResponse {
+        /// Frequency shift in Hz
+        pub freq_shift_hz: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.FreqShiftAutoCenter`: auto-center frequency shift.
+    pub struct PllFreqShiftAutoCenter: "PLL.FreqShiftAutoCenter" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.FreqExcOverwriteSet`: set the frequency/excitation overwrite signals.
+    pub struct PllFreqExcOverwriteSet: "PLL.FreqExcOverwriteSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Excitation overwrite signal index (-1 for none)
+        pub excitation_signal_index: i32 = "i",
+        /// Frequency overwrite signal index (-1 for none)
+        pub frequency_signal_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.FreqExcOverwriteGet`: get the frequency/excitation overwrite signals.
+    pub struct PllFreqExcOverwriteGet: "PLL.FreqExcOverwriteGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllFreqExcOverwriteGetResponse {
+        /// Excitation overwrite signal index (-1 for none)
+        pub excitation_signal_index: i32 = "i",
+        /// Frequency overwrite signal index (-1 for none)
+        pub frequency_signal_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.DemodInputSet`: set the demodulator input and frequency generator.
+    pub struct PllDemodInputSet: "PLL.DemodInputSet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+        /// Demodulator input configuration
+        pub input: u16 = "H",
+        /// Frequency generator selection
+        pub freq_generator: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.DemodInputGet`: get the demodulator input and frequency generator.
+    pub struct PllDemodInputGet: "PLL.DemodInputGet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+    } -> PllDemodInputGetResponse {
+        /// Input selection
+        pub input: u16 = "H",
+        /// Frequency generator selection
+        pub freq_generator: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.DemodHarmonicSet`: set the demodulator harmonic.
+    pub struct PllDemodHarmonicSet: "PLL.DemodHarmonicSet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+        /// Harmonic number
+        pub harmonic: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.DemodHarmonicGet`: get the demodulator harmonic.
+    pub struct PllDemodHarmonicGet: "PLL.DemodHarmonicGet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+    } -> PllDemodHarmonicGetResponse {
+        /// Harmonic number
+        pub harmonic: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.DemodPhasRefSet`: set the demodulator phase reference.
+    pub struct PllDemodPhasRefSet: "PLL.DemodPhasRefSet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+        /// Phase reference in degrees
+        pub phase_deg: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.DemodPhasRefGet`: get the demodulator phase reference.
+    pub struct PllDemodPhasRefGet: "PLL.DemodPhasRefGet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+    } -> PllDemodPhasRefGetResponse {
+        /// Phase reference in degrees
+        pub phase_deg: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLL.DemodFilterSet`: set the demodulator filter order.
+    pub struct PllDemodFilterSet: "PLL.DemodFilterSet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+        /// Low-pass filter order
+        pub filter_order: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLL.DemodFilterGet`: get the demodulator filter order.
+    pub struct PllDemodFilterGet: "PLL.DemodFilterGet" {
+        /// Demodulator index (starts from 1)
+        pub demodulator_index: u16 = "H",
+    } -> PllDemodFilterGetResponse {
+        /// Low-pass filter order
+        pub filter_order: u16 = "H",
+    }
+}
@@ -1,9 +1,11 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use serde::{Deserialize, Serialize};
 
 /// PLL excitation output range.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PLLExcRange {
     /// 10V range
     #[default]
@@ -105,15 +107,7 @@ impl NanonisClient {
         modulator_index: i32,
         calibration_m_per_v: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.InpCalibrSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(calibration_m_per_v),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllInpCalibrSet { modulator_index, calibration_m_per_v })?;
         Ok(())
     }
 
@@ -128,18 +122,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_inp_calibr_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.InpCalibrGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllInpCalibrGet { modulator_index })?;
+
+        Ok(response.calibration_m_per_v)
     }
 
     /// Set the input range of the oscillation control module.
@@ -155,15 +140,7 @@ impl NanonisClient {
         modulator_index: i32,
         input_range_m: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.InpRangeSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(input_range_m),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllInpRangeSet { modulator_index, input_range_m })?;
         Ok(())
     }
 
@@ -178,18 +155,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_inp_range_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.InpRangeGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllInpRangeGet { modulator_index })?;
+
+        Ok(response.input_range_m)
     }
 
     /// Set the input properties of the oscillation control module.
@@ -207,16 +175,11 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let diff_flag = if props.differential_input { 1u16 } else { 0u16 };
         let div_flag = if props.divider_1_10 { 1u16 } else { 0u16 };
-        self.quick_send(
-            "PLL.InpPropsSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::U16(diff_flag),
-                NanonisValue::U16(div_flag),
-            ],
-            vec!["i", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PllInpPropsSet {
+            modulator_index,
+            differential_input: diff_flag,
+            divider_1_10: div_flag,
+        })?;
         Ok(())
     }
 
@@ -231,21 +194,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_inp_props_get(&mut self, modulator_index: i32) -> Result<PLLInputProps, NanonisError> {
-        let result = self.quick_send(
-            "PLL.InpPropsGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["H", "H"],
-        )?;
-
-        if result.len() >= 2 {
-            Ok(PLLInputProps {
-                differential_input: result[0].as_u16()? != 0,
-                divider_1_10: result[1].as_u16()? != 0,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllInpPropsGet { modulator_index })?;
+
+        Ok(PLLInputProps {
+            differential_input: response.differential_input != 0,
+            divider_1_10: response.divider_1_10 != 0,
+        })
     }
 
     // ==================== Output Configuration ====================
@@ -264,12 +218,7 @@ impl NanonisClient {
         enabled: bool,
     ) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "PLL.AddOnOffSet",
-            vec![NanonisValue::I32(modulator_index), NanonisValue::U32(flag)],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::PllAddOnOffSet { modulator_index, enabled: flag })?;
         Ok(())
     }
 
@@ -284,18 +233,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_add_on_off_get(&mut self, modulator_index: i32) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "PLL.AddOnOffGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllAddOnOffGet { modulator_index })?;
+
+        Ok(response.enabled != 0)
     }
 
     /// Set the PLL output on/off status.
@@ -312,12 +252,7 @@ impl NanonisClient {
         enabled: bool,
     ) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "PLL.OutOnOffSet",
-            vec![NanonisValue::I32(modulator_index), NanonisValue::U32(flag)],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::PllOutOnOffSet { modulator_index, enabled: flag })?;
         Ok(())
     }
 
@@ -332,18 +267,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_out_on_off_get(&mut self, modulator_index: i32) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "PLL.OutOnOffGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllOutOnOffGet { modulator_index })?;
+
+        Ok(response.enabled != 0)
     }
 
     // ==================== Excitation ====================
@@ -361,15 +287,7 @@ impl NanonisClient {
         modulator_index: i32,
         range: PLLExcRange,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.ExcRangeSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::U16(range.into()),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PllExcRangeSet { modulator_index, range: range.into() })?;
         Ok(())
     }
 
@@ -384,18 +302,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_exc_range_get(&mut self, modulator_index: i32) -> Result<PLLExcRange, NanonisError> {
-        let result = self.quick_send(
-            "PLL.ExcRangeGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["H"],
-        )?;
-
-        if !result.is_empty() {
-            result[0].as_u16()?.try_into()
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllExcRangeGet { modulator_index })?;
+
+        response.range.try_into()
     }
 
     /// Set the excitation value (drive amplitude).
@@ -413,15 +322,7 @@ impl NanonisClient {
         modulator_index: i32,
         excitation_v: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.ExcitationSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(excitation_v),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllExcitationSet { modulator_index, excitation_v })?;
         Ok(())
     }
 
@@ -436,18 +337,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_excitation_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.ExcitationGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllExcitationGet { modulator_index })?;
+
+        Ok(response.excitation_v)
     }
 
     // ==================== Amplitude Controller ====================
@@ -481,15 +373,7 @@ impl NanonisClient {
         modulator_index: i32,
         setpoint_m: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.AmpCtrlSetpntSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(setpoint_m),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllAmpCtrlSetpntSet { modulator_index, setpoint_m })?;
         Ok(())
     }
 
@@ -524,19 +408,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn pll_amp_ctrl_setpnt_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let response = self.quick_send(
-            "PLL.AmpCtrlSetpntGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        match response.first() {
-            Some(NanonisValue::F32(setpoint)) => Ok(*setpoint),
-            _ => Err(NanonisError::Protocol(
-                "Expected f32 amplitude setpoint".to_string(),
-            )),
-        }
+        let response = self.send(&commands::PllAmpCtrlSetpntGet { modulator_index })?;
+
+        Ok(response.setpoint_m)
     }
 
     /// Set the amplitude controller on/off status for a PLL modulator.
@@ -572,15 +446,7 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let status_u32 = if status { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "PLL.AmpCtrlOnOffSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::U32(status_u32),
-            ],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::PllAmpCtrlOnOffSet { modulator_index, status: status_u32 })?;
         Ok(())
     }
 
@@ -619,19 +485,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn pll_amp_ctrl_on_off_get(&mut self, modulator_index: i32) -> Result<bool, NanonisError> {
-        let response = self.quick_send(
-            "PLL.AmpCtrlOnOffGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-
-        match response.first() {
-            Some(NanonisValue::U32(status)) => Ok(*status != 0),
-            _ => Err(NanonisError::Protocol(
-                "Expected u32 amplitude controller status".to_string(),
-            )),
-        }
+        let response = self.send(&commands::PllAmpCtrlOnOffGet { modulator_index })?;
+
+        Ok(response.status != 0)
     }
 
     /// Set the amplitude controller gain parameters for a PLL modulator.
@@ -667,16 +523,11 @@ impl NanonisClient {
         p_gain_v_div_m: f32,
         time_constant_s: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.AmpCtrlGainSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(p_gain_v_div_m),
-                NanonisValue::F32(time_constant_s),
-            ],
-            vec!["i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllAmpCtrlGainSet {
+            modulator_index,
+            p_gain_v_div_m,
+            time_constant_s,
+        })?;
         Ok(())
     }
 
@@ -717,21 +568,9 @@ impl NanonisClient {
         &mut self,
         modulator_index: i32,
     ) -> Result<(f32, f32), NanonisError> {
-        let response = self.quick_send(
-            "PLL.AmpCtrlGainGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f", "f"],
-        )?;
-
-        match (response.first(), response.get(1)) {
-            (Some(NanonisValue::F32(p_gain)), Some(NanonisValue::F32(time_const))) => {
-                Ok((*p_gain, *time_const))
-            }
-            _ => Err(NanonisError::Protocol(
-                "Expected f32 gain parameters (p_gain, time_constant)".to_string(),
-            )),
-        }
+        let response = self.send(&commands::PllAmpCtrlGainGet { modulator_index })?;
+
+        Ok((response.p_gain, response.time_const))
     }
 
     /// Set the amplitude controller bandwidth.
@@ -749,15 +588,7 @@ impl NanonisClient {
         modulator_index: i32,
         bandwidth_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.AmpCtrlBandwidthSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(bandwidth_hz),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllAmpCtrlBandwidthSet { modulator_index, bandwidth_hz })?;
         Ok(())
     }
 
@@ -772,18 +603,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_amp_ctrl_bandwidth_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.AmpCtrlBandwidthGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllAmpCtrlBandwidthGet { modulator_index })?;
+
+        Ok(response.bandwidth_hz)
     }
 
     // ==================== Phase Controller ====================
@@ -802,12 +624,7 @@ impl NanonisClient {
         enabled: bool,
     ) -> Result<(), NanonisError> {
         let flag = if enabled { 1u32 } else { 0u32 };
-        self.quick_send(
-            "PLL.PhasCtrlOnOffSet",
-            vec![NanonisValue::I32(modulator_index), NanonisValue::U32(flag)],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::PllPhasCtrlOnOffSet { modulator_index, enabled: flag })?;
         Ok(())
     }
 
@@ -822,18 +639,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_phas_ctrl_on_off_get(&mut self, modulator_index: i32) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "PLL.PhasCtrlOnOffGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["I"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_u32()? != 0)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllPhasCtrlOnOffGet { modulator_index })?;
+
+        Ok(response.enabled != 0)
     }
 
     /// Set the phase controller gain parameters.
@@ -851,16 +659,11 @@ impl NanonisClient {
         p_gain_hz_per_deg: f32,
         time_constant_s: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.PhasCtrlGainSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(p_gain_hz_per_deg),
-                NanonisValue::F32(time_constant_s),
-            ],
-            vec!["i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllPhasCtrlGainSet {
+            modulator_index,
+            p_gain_hz_per_deg,
+            time_constant_s,
+        })?;
         Ok(())
     }
 
@@ -878,21 +681,12 @@ impl NanonisClient {
         &mut self,
         modulator_index: i32,
     ) -> Result<PLLPhasCtrlGain, NanonisError> {
-        let result = self.quick_send(
-            "PLL.PhasCtrlGainGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f", "f"],
-        )?;
-
-        if result.len() >= 2 {
-            Ok(PLLPhasCtrlGain {
-                p_gain_hz_per_deg: result[0].as_f32()?,
-                time_constant_s: result[1].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllPhasCtrlGainGet { modulator_index })?;
+
+        Ok(PLLPhasCtrlGain {
+            p_gain_hz_per_deg: response.p_gain_hz_per_deg,
+            time_constant_s: response.time_constant_s,
+        })
     }
 
     /// Set the phase controller bandwidth.
@@ -910,15 +704,7 @@ impl NanonisClient {
         modulator_index: i32,
         bandwidth_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.PhasCtrlBandwidthSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(bandwidth_hz),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllPhasCtrlBandwidthSet { modulator_index, bandwidth_hz })?;
         Ok(())
     }
 
@@ -936,18 +722,9 @@ impl NanonisClient {
         &mut self,
         modulator_index: i32,
     ) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.PhasCtrlBandwidthGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllPhasCtrlBandwidthGet { modulator_index })?;
+
+        Ok(response.bandwidth_hz)
     }
 
     // ==================== Frequency ====================
@@ -965,15 +742,7 @@ impl NanonisClient {
         modulator_index: i32,
         freq_range_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.FreqRangeSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(freq_range_hz),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqRangeSet { modulator_index, freq_range_hz })?;
         Ok(())
     }
 
@@ -988,18 +757,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_freq_range_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.FreqRangeGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllFreqRangeGet { modulator_index })?;
+
+        Ok(response.freq_range_hz)
     }
 
     /// Set the center frequency.
@@ -1015,15 +775,7 @@ impl NanonisClient {
         modulator_index: i32,
         center_freq_hz: f64,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.CenterFreqSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F64(center_freq_hz),
-            ],
-            vec!["i", "d"],
-            vec![],
-        )?;
+        self.send(&commands::PllCenterFreqSet { modulator_index, center_freq_hz })?;
         Ok(())
     }
 
@@ -1038,18 +790,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_center_freq_get(&mut self, modulator_index: i32) -> Result<f64, NanonisError> {
-        let result = self.quick_send(
-            "PLL.CenterFreqGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["d"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f64()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllCenterFreqGet { modulator_index })?;
+
+        Ok(response.center_freq_hz)
     }
 
     /// Set the frequency shift.
@@ -1065,15 +808,7 @@ impl NanonisClient {
         modulator_index: i32,
         freq_shift_hz: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.FreqShiftSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::F32(freq_shift_hz),
-            ],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqShiftSet { modulator_index, freq_shift_hz })?;
         Ok(())
     }
 
@@ -1088,18 +823,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_freq_shift_get(&mut self, modulator_index: i32) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.FreqShiftGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllFreqShiftGet { modulator_index })?;
+
+        Ok(response.freq_shift_hz)
     }
 
     /// Auto-center frequency shift.
@@ -1112,12 +838,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_freq_shift_auto_center(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.FreqShiftAutoCenter",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqShiftAutoCenter { modulator_index })?;
         Ok(())
     }
 
@@ -1137,16 +858,11 @@ impl NanonisClient {
         modulator_index: i32,
         overwrite: &PLLOverwrite,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.FreqExcOverwriteSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::I32(overwrite.excitation_signal_index),
-                NanonisValue::I32(overwrite.frequency_signal_index),
-            ],
-            vec!["i", "i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqExcOverwriteSet {
+            modulator_index,
+            excitation_signal_index: overwrite.excitation_signal_index,
+            frequency_signal_index: overwrite.frequency_signal_index,
+        })?;
         Ok(())
     }
 
@@ -1164,21 +880,12 @@ impl NanonisClient {
         &mut self,
         modulator_index: i32,
     ) -> Result<PLLOverwrite, NanonisError> {
-        let result = self.quick_send(
-            "PLL.FreqExcOverwriteGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["i", "i"],
-        )?;
-
-        if result.len() >= 2 {
-            Ok(PLLOverwrite {
-                excitation_signal_index: result[0].as_i32()?,
-                frequency_signal_index: result[1].as_i32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllFreqExcOverwriteGet { modulator_index })?;
+
+        Ok(PLLOverwrite {
+            excitation_signal_index: response.excitation_signal_index,
+            frequency_signal_index: response.frequency_signal_index,
+        })
     }
 
     // ==================== Demodulator ====================
@@ -1196,16 +903,11 @@ impl NanonisClient {
         demodulator_index: u16,
         input: &PLLDemodInput,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.DemodInputSet",
-            vec![
-                NanonisValue::U16(demodulator_index),
-                NanonisValue::U16(input.input),
-                NanonisValue::U16(input.freq_generator),
-            ],
-            vec!["H", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PllDemodInputSet {
+            demodulator_index,
+            input: input.input,
+            freq_generator: input.freq_generator,
+        })?;
         Ok(())
     }
 
@@ -1223,21 +925,12 @@ impl NanonisClient {
         &mut self,
         demodulator_index: u16,
     ) -> Result<PLLDemodInput, NanonisError> {
-        let result = self.quick_send(
-            "PLL.DemodInputGet",
-            vec![NanonisValue::U16(demodulator_index)],
-            vec!["H"],
-            vec!["H", "H"],
-        )?;
-
-        if result.len() >= 2 {
-            Ok(PLLDemodInput {
-                input: result[0].as_u16()?,
-                freq_generator: result[1].as_u16()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllDemodInputGet { demodulator_index })?;
+
+        Ok(PLLDemodInput {
+            input: response.input,
+            freq_generator: response.freq_generator,
+        })
     }
 
     /// Set the demodulator harmonic.
@@ -1255,15 +948,7 @@ impl NanonisClient {
         demodulator_index: u16,
         harmonic: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.DemodHarmonicSet",
-            vec![
-                NanonisValue::U16(demodulator_index),
-                NanonisValue::U16(harmonic),
-            ],
-            vec!["H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PllDemodHarmonicSet { demodulator_index, harmonic })?;
         Ok(())
     }
 
@@ -1278,18 +963,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_demod_harmonic_get(&mut self, demodulator_index: u16) -> Result<u16, NanonisError> {
-        let result = self.quick_send(
-            "PLL.DemodHarmonicGet",
-            vec![NanonisValue::U16(demodulator_index)],
-            vec!["H"],
-            vec!["H"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_u16()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllDemodHarmonicGet { demodulator_index })?;
+
+        Ok(response.harmonic)
     }
 
     /// Set the demodulator phase reference.
@@ -1305,15 +981,7 @@ impl NanonisClient {
         demodulator_index: u16,
         phase_deg: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.DemodPhasRefSet",
-            vec![
-                NanonisValue::U16(demodulator_index),
-                NanonisValue::F32(phase_deg),
-            ],
-            vec!["H", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllDemodPhasRefSet { demodulator_index, phase_deg })?;
         Ok(())
     }
 
@@ -1328,18 +996,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_demod_phas_ref_get(&mut self, demodulator_index: u16) -> Result<f32, NanonisError> {
-        let result = self.quick_send(
-            "PLL.DemodPhasRefGet",
-            vec![NanonisValue::U16(demodulator_index)],
-            vec!["H"],
-            vec!["f"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_f32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllDemodPhasRefGet { demodulator_index })?;
+
+        Ok(response.phase_deg)
     }
 
     /// Set the demodulator filter order.
@@ -1355,15 +1014,7 @@ impl NanonisClient {
         demodulator_index: u16,
         filter_order: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLL.DemodFilterSet",
-            vec![
-                NanonisValue::U16(demodulator_index),
-                NanonisValue::U16(filter_order),
-            ],
-            vec!["H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PllDemodFilterSet { demodulator_index, filter_order })?;
         Ok(())
     }
 
@@ -1378,17 +1029,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_demod_filter_get(&mut self, demodulator_index: u16) -> Result<u16, NanonisError> {
-        let result = self.quick_send(
-            "PLL.DemodFilterGet",
-            vec![NanonisValue::U16(demodulator_index)],
-            vec!["H"],
-            vec!["H"],
-        )?;
-
-        if !result.is_empty() {
-            Ok(result[0].as_u16()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllDemodFilterGet { demodulator_index })?;
+
+        Ok(response.filter_order)
     }
 }
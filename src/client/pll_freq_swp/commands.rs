@@ -0,0 +1,117 @@
+//! Typed descriptors for the `PLLFreqSwp.*` and `PLLPhasSwp.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `PLLFreqSwp.Open`: open the PLL frequency sweep module.
+    pub struct PllFreqSwpOpen: "PLLFreqSwp.Open" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLFreqSwp.ParamsSet`: set the PLL frequency sweep parameters.
+    pub struct PllFreqSwpParamsSet: "PLLFreqSwp.ParamsSet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// Measurement/wait period in seconds
+        pub period_s: f32 = "f",
+        /// Initial settling time in seconds
+        pub settling_time_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLFreqSwp.ParamsGet`: get the PLL frequency sweep parameters.
+    pub struct PllFreqSwpParamsGet: "PLLFreqSwp.ParamsGet" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> PllFreqSwpParamsGetResponse {
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// Measurement/wait period in seconds
+        pub period_s: f32 = "f",
+        /// Initial settling time in seconds
+        pub settling_time_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `PLLFreqSwp.Start`: start a PLL frequency sweep.
+    pub struct PllFreqSwpStart: "PLLFreqSwp.Start" blocking if get_data {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// If true, return recorded channels and data
+        pub get_data: u32 = "I",
+        /// If true, sweep from lower to upper limit
+        pub sweep_up: u32 = "I",
+    } -> PllFreqSwpStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Channel names
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// Data rows (one per point)
+        pub data: Array2D<f32> = "2f",
+        /// Resonance frequency in Hz
+        pub resonance_freq_hz: f64 = "d",
+        /// Quality factor
+        pub q_factor: f64 = "d",
+        /// Phase at resonance in degrees
+        pub phase_deg: f32 = "f",
+        /// Amplitude to excitation ratio in nm/mV
+        pub amp_exc_ratio_nm_per_mv: f32 = "f",
+        /// Fit length (samples)
+        pub fit_length: i32 = "i",
+        /// Number of points
+        pub num_points: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `PLLFreqSwp.Stop`: stop the PLL frequency sweep.
+    pub struct PllFreqSwpStop: "PLLFreqSwp.Stop" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLPhasSwp.Start`: start a PLL phase sweep.
+    pub struct PllPhasSwpStart: "PLLPhasSwp.Start" blocking if get_data {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+        /// If true, return recorded channels and data
+        pub get_data: u32 = "I",
+    } -> PllPhasSwpStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Channel names
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// Data rows (one per point)
+        pub data: Array2D<f32> = "2f",
+    }
+}
+
+nanonis_command! {
+    /// `PLLPhasSwp.Stop`: stop the PLL phase sweep.
+    pub struct PllPhasSwpStop: "PLLPhasSwp.Stop" {
+        /// PLL modulator index (starts from 1)
+        pub modulator_index: i32 = "i",
+    } -> ()
+}
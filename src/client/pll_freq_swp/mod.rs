@@ -1,6 +1,8 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 
 /// PLL frequency sweep parameters.
 #[derive(Debug, Clone, Copy, Default)]
@@ -36,7 +38,7 @@ pub struct PLLFreqSwpData {
     /// Channel names
     pub channel_names: Vec<String>,
     /// Data rows (one per point)
-    pub data: Vec<Vec<f32>>,
+    pub data: Array2D<f32>,
     /// Sweep characteristics
     pub characteristics: PLLFreqSwpCharacteristics,
 }
@@ -47,7 +49,7 @@ pub struct PLLPhasSwpData {
     /// Channel names
     pub channel_names: Vec<String>,
     /// Data rows (one per point)
-    pub data: Vec<Vec<f32>>,
+    pub data: Array2D<f32>,
 }
 
 impl NanonisClient {
@@ -61,12 +63,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_freq_swp_open(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLFreqSwp.Open",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqSwpOpen { modulator_index })?;
         Ok(())
     }
 
@@ -83,17 +80,12 @@ impl NanonisClient {
         modulator_index: i32,
         params: &PLLFreqSwpParams,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLFreqSwp.ParamsSet",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::I32(params.num_points),
-                NanonisValue::F32(params.period_s),
-                NanonisValue::F32(params.settling_time_s),
-            ],
-            vec!["i", "i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqSwpParamsSet {
+            modulator_index,
+            num_points: params.num_points,
+            period_s: params.period_s,
+            settling_time_s: params.settling_time_s,
+        })?;
         Ok(())
     }
 
@@ -111,22 +103,13 @@ impl NanonisClient {
         &mut self,
         modulator_index: i32,
     ) -> Result<PLLFreqSwpParams, NanonisError> {
-        let result = self.quick_send(
-            "PLLFreqSwp.ParamsGet",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec!["i", "f", "f"],
-        )?;
+        let response = self.send(&commands::PllFreqSwpParamsGet { modulator_index })?;
 
-        if result.len() >= 3 {
-            Ok(PLLFreqSwpParams {
-                num_points: result[0].as_i32()?,
-                period_s: result[1].as_f32()?,
-                settling_time_s: result[2].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(PLLFreqSwpParams {
+            num_points: response.num_points,
+            period_s: response.period_s,
+            settling_time_s: response.settling_time_s,
+        })
     }
 
     /// Start a PLL frequency sweep.
@@ -152,32 +135,28 @@ impl NanonisClient {
         let get_flag = if get_data { 1u32 } else { 0u32 };
         let dir_flag = if sweep_up { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "PLLFreqSwp.Start",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::U32(get_flag),
-                NanonisValue::U32(dir_flag),
-            ],
-            vec!["i", "I", "I"],
-            vec!["i", "i", "*+c", "i", "i", "2f", "d", "d", "f", "f", "i", "i"],
-        )?;
-
-        if get_data && result.len() >= 12 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let data = result[5].as_f32_2d_array()?.to_vec();
+        let response = self.send(&commands::PllFreqSwpStart {
+            modulator_index,
+            get_data: get_flag,
+            sweep_up: dir_flag,
+        })?;
+
+        if get_data {
+            let channel_names = response.channel_names;
+            let characteristics = PLLFreqSwpCharacteristics {
+                resonance_freq_hz: response.resonance_freq_hz,
+                q_factor: response.q_factor,
+                phase_deg: response.phase_deg,
+                amp_exc_ratio_nm_per_mv: response.amp_exc_ratio_nm_per_mv,
+                fit_length: response.fit_length,
+                num_points: response.num_points,
+            };
+            let data = response.data;
 
             Ok(Some(PLLFreqSwpData {
                 channel_names,
                 data,
-                characteristics: PLLFreqSwpCharacteristics {
-                    resonance_freq_hz: result[6].as_f64()?,
-                    q_factor: result[7].as_f64()?,
-                    phase_deg: result[8].as_f32()?,
-                    amp_exc_ratio_nm_per_mv: result[9].as_f32()?,
-                    fit_length: result[10].as_i32()?,
-                    num_points: result[11].as_i32()?,
-                },
+                characteristics,
             }))
         } else {
             Ok(None)
@@ -192,12 +171,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_freq_swp_stop(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLFreqSwp.Stop",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::PllFreqSwpStop { modulator_index })?;
         Ok(())
     }
 
@@ -221,19 +195,14 @@ impl NanonisClient {
     ) -> Result<Option<PLLPhasSwpData>, NanonisError> {
         let get_flag = if get_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "PLLPhasSwp.Start",
-            vec![
-                NanonisValue::I32(modulator_index),
-                NanonisValue::U32(get_flag),
-            ],
-            vec!["i", "I"],
-            vec!["i", "i", "*+c", "i", "i", "2f"],
-        )?;
+        let response = self.send(&commands::PllPhasSwpStart {
+            modulator_index,
+            get_data: get_flag,
+        })?;
 
-        if get_data && result.len() >= 6 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let data = result[5].as_f32_2d_array()?.to_vec();
+        if get_data {
+            let channel_names = response.channel_names;
+            let data = response.data;
 
             Ok(Some(PLLPhasSwpData {
                 channel_names,
@@ -252,12 +221,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_phas_swp_stop(&mut self, modulator_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLPhasSwp.Stop",
-            vec![NanonisValue::I32(modulator_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::PllPhasSwpStop { modulator_index })?;
         Ok(())
     }
 }
@@ -0,0 +1,231 @@
+//! Typed descriptors for the `PLLSignalAnlzr.*` and `PLLZoomFFT.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.Open`: open the PLL signal analyzer.
+    pub struct PllSignalAnlzrOpen: "PLLSignalAnlzr.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.ChSet`: set the analyzer channel.
+    pub struct PllSignalAnlzrChSet: "PLLSignalAnlzr.ChSet" {
+        /// Channel index
+        pub channel_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.ChGet`: get the analyzer channel.
+    pub struct PllSignalAnlzrChGet: "PLLSignalAnlzr.ChGet" {} -> PllSignalAnlzrChGetResponse {
+        /// Channel index
+        pub channel_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.TimebaseSet`: set the timebase and update rate.
+    pub struct PllSignalAnlzrTimebaseSet: "PLLSignalAnlzr.TimebaseSet" {
+        /// Timebase index (-1 = no change)
+        pub timebase_index: i32 = "i",
+        /// Update rate (1 = fastest, -1 = no change)
+        pub update_rate: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.TimebaseGet`: get the timebase and update rate.
+    pub struct PllSignalAnlzrTimebaseGet: "PLLSignalAnlzr.TimebaseGet" {} -> PllSignalAnlzrTimebaseGetResponse {
+        /// Current timebase index
+        pub timebase_index: i32 = "i",
+        /// Update rate (1 = fastest)
+        pub update_rate: i32 = "i",
+        /// Size of the available timebases array in bytes
+        pub available_timebases_size: i32 = "i",
+        /// Number of available timebases
+        pub num_available_timebases: i32 = "i",
+        /// Available timebases
+        pub available_timebases: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.TrigAuto`: set trigger to automatic mode.
+    pub struct PllSignalAnlzrTrigAuto: "PLLSignalAnlzr.TrigAuto" {} -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.TrigRearm`: rearm the trigger.
+    pub struct PllSignalAnlzrTrigRearm: "PLLSignalAnlzr.TrigRearm" {} -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.TrigSet`: set the trigger configuration.
+    pub struct PllSignalAnlzrTrigSet: "PLLSignalAnlzr.TrigSet" {
+        /// Trigger mode
+        pub mode: u16 = "H",
+        /// Signal index for trigger
+        pub source_index: i32 = "i",
+        /// Trigger slope
+        pub slope: u16 = "H",
+        /// Trigger level
+        pub level: f64 = "d",
+        /// Trigger position in seconds
+        pub position_s: f64 = "d",
+        /// Arming mode
+        pub arming: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.TrigGet`: get the trigger configuration.
+    pub struct PllSignalAnlzrTrigGet: "PLLSignalAnlzr.TrigGet" {} -> PllSignalAnlzrTrigGetResponse {
+        /// Trigger mode
+        pub mode: u16 = "H",
+        /// Signal index for trigger
+        pub source_index: i32 = "i",
+        /// Trigger slope
+        pub slope: u16 = "H",
+        /// Trigger level
+        pub level: f64 = "d",
+        /// Trigger position in seconds
+        pub position_s: f64 = "d",
+        /// Arming mode
+        pub arming: u16 = "H",
+        /// Size of the sources array in bytes
+        pub sources_size: i32 = "i",
+        /// Number of sources
+        pub num_sources: i32 = "i",
+        /// Names of the available trigger sources
+        pub sources: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.OsciDataGet`: get oscilloscope data from analyzer.
+    pub struct PllSignalAnlzrOsciDataGet: "PLLSignalAnlzr.OsciDataGet" {} -> PllSignalAnlzrOsciDataGetResponse {
+        /// Timestamp of first point
+        pub t0: f64 = "d",
+        /// Time between points
+        pub dt: f64 = "d",
+        /// Size of the data array in bytes
+        pub data_size: i32 = "i",
+        /// Data array
+        pub data: Vec<f64> = "+*d",
+    }
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.FFTPropsSet`: set FFT properties.
+    pub struct PllSignalAnlzrFftPropsSet: "PLLSignalAnlzr.FFTPropsSet" {
+        /// FFT window function
+        pub window: u16 = "H",
+        /// Averaging mode
+        pub averaging: u16 = "H",
+        /// Weighting mode
+        pub weighting: u16 = "H",
+        /// Number of averages
+        pub count: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.FFTPropsGet`: get FFT properties.
+    pub struct PllSignalAnlzrFftPropsGet: "PLLSignalAnlzr.FFTPropsGet" {} -> PllSignalAnlzrFftPropsGetResponse {
+        /// FFT window function
+        pub window: u16 = "H",
+        /// Averaging mode
+        pub averaging: u16 = "H",
+        /// Weighting mode
+        pub weighting: u16 = "H",
+        /// Number of averages
+        pub count: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.FFTAvgRestart`: restart FFT averaging.
+    pub struct PllSignalAnlzrFftAvgRestart: "PLLSignalAnlzr.FFTAvgRestart" {} -> ()
+}
+
+nanonis_command! {
+    /// `PLLSignalAnlzr.FFTDataGet`: get FFT data.
+    pub struct PllSignalAnlzrFftDataGet: "PLLSignalAnlzr.FFTDataGet" {} -> PllSignalAnlzrFftDataGetResponse {
+        /// Frequency of first point
+        pub f0: f64 = "d",
+        /// Frequency step
+        pub df: f64 = "d",
+        /// Number of data points
+        pub num_data: i32 = "i",
+        /// Data array
+        pub data: Vec<f64> = "*d",
+    }
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.Open`: open the PLL Zoom FFT module.
+    pub struct PllZoomFftOpen: "PLLZoomFFT.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.ChSet`: set the Zoom FFT channel.
+    pub struct PllZoomFftChSet: "PLLZoomFFT.ChSet" {
+        /// Channel index
+        pub channel_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.ChGet`: get the Zoom FFT channel.
+    pub struct PllZoomFftChGet: "PLLZoomFFT.ChGet" {} -> PllZoomFftChGetResponse {
+        /// Channel index
+        pub channel_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.AvgRestart`: restart Zoom FFT averaging.
+    pub struct PllZoomFftAvgRestart: "PLLZoomFFT.AvgRestart" {} -> ()
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.PropsSet`: set Zoom FFT properties.
+    pub struct PllZoomFftPropsSet: "PLLZoomFFT.PropsSet" {
+        /// FFT window function
+        pub window: u16 = "H",
+        /// Averaging mode
+        pub averaging: u16 = "H",
+        /// Weighting mode
+        pub weighting: u16 = "H",
+        /// Number of averages
+        pub count: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.PropsGet`: get Zoom FFT properties.
+    pub struct PllZoomFftPropsGet: "PLLZoomFFT.PropsGet" {} -> PllZoomFftPropsGetResponse {
+        /// FFT window function
+        pub window: u16 = "H",
+        /// Averaging mode
+        pub averaging: u16 = "H",
+        /// Weighting mode
+        pub weighting: u16 = "H",
+        /// Number of averages
+        pub count: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `PLLZoomFFT.DataGet`: get Zoom FFT data.
+    pub struct PllZoomFftDataGet: "PLLZoomFFT.DataGet" {} -> PllZoomFftDataGetResponse {
+        /// Frequency of first point
+        pub f0: f64 = "d",
+        /// Frequency step
+        pub df: f64 = "d",
+        /// Number of data points
+        pub num_data: i32 = "i",
+        /// Data array
+        pub data: Vec<f64> = "*d",
+    }
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Trigger mode for PLL signal analyzer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -249,7 +250,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("PLLSignalAnlzr.Open", vec![], vec![], vec![])?;
+        self.send(&commands::PllSignalAnlzrOpen {})?;
         Ok(())
     }
 
@@ -261,12 +262,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_ch_set(&mut self, channel_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLSignalAnlzr.ChSet",
-            vec![NanonisValue::I32(channel_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::PllSignalAnlzrChSet { channel_index })?;
         Ok(())
     }
 
@@ -278,13 +274,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_ch_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("PLLSignalAnlzr.ChGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::PllSignalAnlzrChGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.channel_index)
     }
 
     /// Set the timebase and update rate.
@@ -300,15 +292,7 @@ impl NanonisClient {
         timebase_index: i32,
         update_rate: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLSignalAnlzr.TimebaseSet",
-            vec![
-                NanonisValue::I32(timebase_index),
-                NanonisValue::I32(update_rate),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::PllSignalAnlzrTimebaseSet { timebase_index, update_rate })?;
         Ok(())
     }
 
@@ -320,22 +304,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_timebase_get(&mut self) -> Result<PLLAnlzrTimebase, NanonisError> {
-        let result = self.quick_send(
-            "PLLSignalAnlzr.TimebaseGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "i", "i", "*+c"],
-        )?;
-
-        if result.len() >= 5 {
-            Ok(PLLAnlzrTimebase {
-                timebase_index: result[0].as_i32()?,
-                update_rate: result[1].as_i32()?,
-                available_timebases: result[4].as_string_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllSignalAnlzrTimebaseGet {})?;
+
+        Ok(PLLAnlzrTimebase {
+            timebase_index: response.timebase_index,
+            update_rate: response.update_rate,
+            available_timebases: response.available_timebases,
+        })
     }
 
     /// Set trigger to automatic mode.
@@ -343,7 +318,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_trig_auto(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("PLLSignalAnlzr.TrigAuto", vec![], vec![], vec![])?;
+        self.send(&commands::PllSignalAnlzrTrigAuto {})?;
         Ok(())
     }
 
@@ -352,7 +327,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_trig_rearm(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("PLLSignalAnlzr.TrigRearm", vec![], vec![], vec![])?;
+        self.send(&commands::PllSignalAnlzrTrigRearm {})?;
         Ok(())
     }
 
@@ -367,19 +342,14 @@ impl NanonisClient {
         &mut self,
         trigger: &PLLAnlzrTrigger,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLSignalAnlzr.TrigSet",
-            vec![
-                NanonisValue::U16(trigger.mode.into()),
-                NanonisValue::I32(trigger.source_index),
-                NanonisValue::U16(trigger.slope.into()),
-                NanonisValue::F64(trigger.level),
-                NanonisValue::F64(trigger.position_s),
-                NanonisValue::U16(trigger.arming.into()),
-            ],
-            vec!["H", "i", "H", "d", "d", "H"],
-            vec![],
-        )?;
+        self.send(&commands::PllSignalAnlzrTrigSet {
+            mode: trigger.mode.into(),
+            source_index: trigger.source_index,
+            slope: trigger.slope.into(),
+            level: trigger.level,
+            position_s: trigger.position_s,
+            arming: trigger.arming.into(),
+        })?;
         Ok(())
     }
 
@@ -391,25 +361,16 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_trig_get(&mut self) -> Result<PLLAnlzrTrigger, NanonisError> {
-        let result = self.quick_send(
-            "PLLSignalAnlzr.TrigGet",
-            vec![],
-            vec![],
-            vec!["H", "i", "H", "d", "d", "H", "i", "i", "*+c"],
-        )?;
-
-        if result.len() >= 6 {
-            Ok(PLLAnlzrTrigger {
-                mode: result[0].as_u16()?.try_into()?,
-                source_index: result[1].as_i32()?,
-                slope: result[2].as_u16()?.try_into()?,
-                level: result[3].as_f64()?,
-                position_s: result[4].as_f64()?,
-                arming: result[5].as_u16()?.try_into()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllSignalAnlzrTrigGet {})?;
+
+        Ok(PLLAnlzrTrigger {
+            mode: response.mode.try_into()?,
+            source_index: response.source_index,
+            slope: response.slope.try_into()?,
+            level: response.level,
+            position_s: response.position_s,
+            arming: response.arming.try_into()?,
+        })
     }
 
     /// Get oscilloscope data from analyzer.
@@ -420,18 +381,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_osci_data_get(&mut self) -> Result<OsciAnalyzerData, NanonisError> {
-        let result =
-            self.quick_send("PLLSignalAnlzr.OsciDataGet", vec![], vec![], vec!["d", "d", "i", "+*d"])?;
-
-        if result.len() >= 4 {
-            Ok(OsciAnalyzerData {
-                t0: result[0].as_f64()?,
-                dt: result[1].as_f64()?,
-                data: result[3].as_f64_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllSignalAnlzrOsciDataGet {})?;
+
+        Ok(OsciAnalyzerData {
+            t0: response.t0,
+            dt: response.dt,
+            data: response.data,
+        })
     }
 
     /// Set FFT properties.
@@ -442,17 +398,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_fft_props_set(&mut self, props: &FFTProps) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLSignalAnlzr.FFTPropsSet",
-            vec![
-                NanonisValue::U16(props.window.into()),
-                NanonisValue::U16(props.averaging.into()),
-                NanonisValue::U16(props.weighting.into()),
-                NanonisValue::I32(props.count),
-            ],
-            vec!["H", "H", "H", "i"],
-            vec![],
-        )?;
+        self.send(&commands::PllSignalAnlzrFftPropsSet {
+            window: props.window.into(),
+            averaging: props.averaging.into(),
+            weighting: props.weighting.into(),
+            count: props.count,
+        })?;
         Ok(())
     }
 
@@ -464,20 +415,15 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_fft_props_get(&mut self) -> Result<FFTProps, NanonisError> {
-        let result =
-            self.quick_send("PLLSignalAnlzr.FFTPropsGet", vec![], vec![], vec!["H", "H", "H", "i"])?;
-
-        if result.len() >= 4 {
-            // Note: returned values have different offset than set values
-            Ok(FFTProps {
-                window: FFTWindow::NoChange, // Would need TryFrom for returned values
-                averaging: FFTAveragingMode::NoChange,
-                weighting: FFTWeightingMode::NoChange,
-                count: result[3].as_i32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllSignalAnlzrFftPropsGet {})?;
+
+        // Note: returned values have different offset than set values
+        Ok(FFTProps {
+            window: FFTWindow::NoChange, // Would need TryFrom for returned values
+            averaging: FFTAveragingMode::NoChange,
+            weighting: FFTWeightingMode::NoChange,
+            count: response.count,
+        })
     }
 
     /// Restart FFT averaging.
@@ -485,7 +431,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_fft_avg_restart(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("PLLSignalAnlzr.FFTAvgRestart", vec![], vec![], vec![])?;
+        self.send(&commands::PllSignalAnlzrFftAvgRestart {})?;
         Ok(())
     }
 
@@ -497,18 +443,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_signal_anlzr_fft_data_get(&mut self) -> Result<FFTAnalyzerData, NanonisError> {
-        let result =
-            self.quick_send("PLLSignalAnlzr.FFTDataGet", vec![], vec![], vec!["d", "d", "i", "*d"])?;
-
-        if result.len() >= 4 {
-            Ok(FFTAnalyzerData {
-                f0: result[0].as_f64()?,
-                df: result[1].as_f64()?,
-                data: result[3].as_f64_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllSignalAnlzrFftDataGet {})?;
+
+        Ok(FFTAnalyzerData {
+            f0: response.f0,
+            df: response.df,
+            data: response.data,
+        })
     }
 
     // ==================== PLL Zoom FFT ====================
@@ -518,7 +459,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("PLLZoomFFT.Open", vec![], vec![], vec![])?;
+        self.send(&commands::PllZoomFftOpen {})?;
         Ok(())
     }
 
@@ -530,12 +471,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_ch_set(&mut self, channel_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLZoomFFT.ChSet",
-            vec![NanonisValue::I32(channel_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::PllZoomFftChSet { channel_index })?;
         Ok(())
     }
 
@@ -547,13 +483,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_ch_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("PLLZoomFFT.ChGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::PllZoomFftChGet {})?;
 
-        if !result.is_empty() {
-            Ok(result[0].as_i32()?)
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(response.channel_index)
     }
 
     /// Restart Zoom FFT averaging.
@@ -561,7 +493,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_avg_restart(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("PLLZoomFFT.AvgRestart", vec![], vec![], vec![])?;
+        self.send(&commands::PllZoomFftAvgRestart {})?;
         Ok(())
     }
 
@@ -573,17 +505,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_props_set(&mut self, props: &FFTProps) -> Result<(), NanonisError> {
-        self.quick_send(
-            "PLLZoomFFT.PropsSet",
-            vec![
-                NanonisValue::U16(props.window.into()),
-                NanonisValue::U16(props.averaging.into()),
-                NanonisValue::U16(props.weighting.into()),
-                NanonisValue::I32(props.count),
-            ],
-            vec!["H", "H", "H", "i"],
-            vec![],
-        )?;
+        self.send(&commands::PllZoomFftPropsSet {
+            window: props.window.into(),
+            averaging: props.averaging.into(),
+            weighting: props.weighting.into(),
+            count: props.count,
+        })?;
         Ok(())
     }
 
@@ -595,19 +522,14 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_props_get(&mut self) -> Result<FFTProps, NanonisError> {
-        let result =
-            self.quick_send("PLLZoomFFT.PropsGet", vec![], vec![], vec!["H", "H", "H", "i"])?;
-
-        if result.len() >= 4 {
-            Ok(FFTProps {
-                window: FFTWindow::NoChange,
-                averaging: FFTAveragingMode::NoChange,
-                weighting: FFTWeightingMode::NoChange,
-                count: result[3].as_i32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllZoomFftPropsGet {})?;
+
+        Ok(FFTProps {
+            window: FFTWindow::NoChange,
+            averaging: FFTAveragingMode::NoChange,
+            weighting: FFTWeightingMode::NoChange,
+            count: response.count,
+        })
     }
 
     /// Get Zoom FFT data.
@@ -618,17 +540,12 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn pll_zoom_fft_data_get(&mut self) -> Result<FFTAnalyzerData, NanonisError> {
-        let result =
-            self.quick_send("PLLZoomFFT.DataGet", vec![], vec![], vec!["d", "d", "i", "*d"])?;
-
-        if result.len() >= 4 {
-            Ok(FFTAnalyzerData {
-                f0: result[0].as_f64()?,
-                df: result[1].as_f64()?,
-                data: result[3].as_f64_array()?.to_vec(),
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        let response = self.send(&commands::PllZoomFftDataGet {})?;
+
+        Ok(FFTAnalyzerData {
+            f0: response.f0,
+            df: response.df,
+            data: response.data,
+        })
     }
 }
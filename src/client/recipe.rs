@@ -0,0 +1,899 @@
+//! Declarative experiment recipes.
+//!
+//! A [`Recipe`] is an ordered list of [`Step`]s loaded from JSON, or from
+//! TOML with the `toml` feature, and executed by a [`RecipeRunner`]:
+//!
+//! ```toml
+//! name = "Au(111) overview"
+//! output_dir = "data/2024-05-03"
+//!
+//! [[steps]]
+//! action = "set_bias"
+//! bias = 0.5
+//!
+//! [[steps]]
+//! action = "set_scan_frame"
+//! center_x = 0.0
+//! center_y = 0.0
+//! width = 50e-9
+//! height = 50e-9
+//!
+//! [[steps]]
+//! action = "scan"
+//! direction = "up"
+//! save = "overview.sxm"
+//!
+//! [[steps]]
+//! action = "grid_spectroscopy"
+//! start = -1.0
+//! end = 1.0
+//! save = "grid.3ds"
+//! grid = { num_points_x = 8, num_points_y = 8, center_x_m = 0.0, center_y_m = 0.0, width_m = 10e-9, height_m = 10e-9, angle_deg = 0.0 }
+//! ```
+//!
+//! Quantities are in SI base units. Signals are given by name or index.
+
+use super::bias_spectr::BiasSpectrPropsBuilder;
+use super::scan::{ScanConfig, ScanDirection, ScanFrame, ScanPropsBuilder};
+use super::signals::SlotRef;
+use super::NanonisClient;
+use crate::client::bias_spectr::BiasSpectrResult;
+use crate::client::pattern::GridConfig;
+use crate::error::NanonisError;
+use crate::files::dat::DatFile;
+use crate::files::grid::GridFile;
+use crate::types::Position;
+use crate::units::{Meters, Seconds, Setpoint, Volts};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// Largest bias accepted by validation.
+const MAX_BIAS_V: f64 = 10.0;
+
+/// Timeout of the `withdraw` step.
+const WITHDRAW_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A measurement series: an ordered list of steps.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::recipe::{Recipe, RecipeRunner};
+/// use nanonis_rs::MockServer;
+///
+/// let recipe = Recipe::from_json(r#"{
+///     "name": "overview",
+///     "output_dir": "nanonis-rs-recipe-example",
+///     "steps": [
+///         { "action": "set_bias", "bias": 0.5 },
+///         { "action": "set_scan_buffer", "channels": ["Current", "Z"], "pixels": 16, "lines": 4 },
+///         { "action": "set_scan_speed", "time_per_line": 0.005 },
+///         { "action": "set_scan_props", "props": { "continuous_scan": false } },
+///         { "action": "scan", "save": "overview.sxm" },
+///         { "action": "set_bias_spectr_props", "props": { "num_points": 64, "backward_sweep": "on" } },
+///         { "action": "bias_spectroscopy", "start": -1.0, "end": 1.0, "save": "iv.dat" }
+///     ]
+/// }"#)?;
+/// # let mut recipe = recipe;
+/// # recipe.output_dir = std::env::temp_dir().join(&recipe.output_dir);
+///
+/// let server = MockServer::simulated()?;
+/// # server.on("Scan.PropsSet", |_| nanonis_rs::MockResponse::empty());
+/// let mut client = server.client()?;
+/// let mut runner = RecipeRunner::new(&mut client);
+///
+/// assert!(runner.dry_run(&recipe)?.is_empty());
+/// let report = runner.run(&recipe)?;
+/// assert_eq!(report.executed, 7);
+/// assert_eq!(report.files.len(), 2);
+/// assert_eq!(client.bias_get()?, 0.5);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Recipe {
+    /// Name, used in logs and to match saved progress
+    pub name: String,
+    /// Free-form description
+    #[serde(default)]
+    pub description: String,
+    /// Directory that relative `save` paths are resolved against
+    #[serde(default)]
+    pub output_dir: PathBuf,
+    /// Steps in execution order
+    pub steps: Vec<Step>,
+}
+
+/// One step of a [`Recipe`], tagged by `action`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Step {
+    /// Set the bias voltage
+    SetBias {
+        /// Bias in volts
+        bias: Volts,
+    },
+    /// Set the Z-controller setpoint
+    SetSetpoint {
+        /// Setpoint in the unit of the controlled signal
+        setpoint: Setpoint,
+    },
+    /// Switch the Z-controller on or off
+    SetZCtrl {
+        /// Whether the controller is on
+        on: bool,
+    },
+    /// Withdraw the tip and wait until it is retracted
+    Withdraw,
+    /// Set the scan frame
+    SetScanFrame {
+        /// Center X in meters
+        center_x: Meters,
+        /// Center Y in meters
+        center_y: Meters,
+        /// Width in meters
+        width: Meters,
+        /// Height in meters
+        height: Meters,
+        /// Rotation in degrees
+        #[serde(default)]
+        angle_deg: f32,
+    },
+    /// Set the recorded channels and the image size
+    SetScanBuffer {
+        /// Slots to record, or signal names and `{ "index": n }` signal
+        /// indexes assigned to a slot
+        channels: Vec<SlotRef>,
+        /// Pixels per line
+        pixels: i32,
+        /// Lines per image
+        lines: i32,
+    },
+    /// Set the time per line in both directions
+    SetScanSpeed {
+        /// Time per line in seconds
+        time_per_line: Seconds,
+    },
+    /// Change the scan properties; fields left out keep their value
+    SetScanProps {
+        /// Properties to change
+        props: ScanPropsBuilder,
+    },
+    /// Scan one frame and wait for it to end; a continuous scan is stopped
+    /// after the frame
+    Scan {
+        /// Scan direction, `up` by default
+        #[serde(default)]
+        direction: ScanDirection,
+        /// `.sxm` file to write the image to
+        #[serde(default)]
+        save: Option<PathBuf>,
+    },
+    /// Change the bias spectroscopy properties; fields left out keep their
+    /// value
+    SetBiasSpectrProps {
+        /// Properties to change
+        props: BiasSpectrPropsBuilder,
+    },
+    /// Run one bias sweep at the present tip position
+    BiasSpectroscopy {
+        /// Sweep start in volts
+        start: Volts,
+        /// Sweep end in volts
+        end: Volts,
+        /// `.dat` file to write the spectrum to
+        #[serde(default)]
+        save: Option<PathBuf>,
+    },
+    /// Run a bias sweep on every point of a grid, row by row
+    GridSpectroscopy {
+        /// Grid geometry
+        grid: GridConfig,
+        /// Sweep start in volts
+        start: Volts,
+        /// Sweep end in volts
+        end: Volts,
+        /// `.3ds` file to write the grid to, updated after every row
+        #[serde(default)]
+        save: Option<PathBuf>,
+    },
+    /// Do nothing for a while
+    Wait {
+        /// Time to wait in seconds
+        duration: Seconds,
+    },
+    /// Save an [`InstrumentState`](super::state::InstrumentState) snapshot
+    /// as JSON
+    SaveState {
+        /// `.json` file to write the snapshot to
+        save: PathBuf,
+    },
+}
+
+impl Step {
+    /// File the step writes, if any.
+    pub fn save_path(&self) -> Option<&Path> {
+        match self {
+            Step::Scan { save, .. }
+            | Step::BiasSpectroscopy { save, .. }
+            | Step::GridSpectroscopy { save, .. } => save.as_deref(),
+            Step::SaveState { save } => Some(save),
+            _ => None,
+        }
+    }
+
+    /// Extension expected for the saved file.
+    fn save_extension(&self) -> Option<&'static str> {
+        match self {
+            Step::Scan { .. } => Some("sxm"),
+            Step::BiasSpectroscopy { .. } => Some("dat"),
+            Step::GridSpectroscopy { .. } => Some("3ds"),
+            Step::SaveState { .. } => Some("json"),
+            _ => None,
+        }
+    }
+
+    /// Problems detectable without an instrument.
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut check = |ok: bool, message: &str| {
+            if !ok {
+                problems.push(message.to_string());
+            }
+        };
+        match self {
+            Step::SetBias { bias } => check(
+                bias.0.is_finite() && bias.0.abs() <= MAX_BIAS_V,
+                "bias is outside ±10 V",
+            ),
+            Step::SetSetpoint { setpoint } => check(setpoint.0.is_finite(), "setpoint is not finite"),
+            Step::SetScanFrame { width, height, .. } => check(
+                width.0 > 0.0 && height.0 > 0.0,
+                "frame size must be positive",
+            ),
+            Step::SetScanBuffer {
+                channels,
+                pixels,
+                lines,
+            } => {
+                check(!channels.is_empty(), "no channels to record");
+                check(
+                    *pixels > 0 && *lines > 0,
+                    "pixels and lines must be positive",
+                );
+            }
+            Step::SetScanSpeed { time_per_line } => {
+                check(time_per_line.0 > 0.0, "time per line must be positive")
+            }
+            Step::SetBiasSpectrProps { props } => check(
+                props.num_sweeps >= 0 && props.num_points >= 0,
+                "number of sweeps and points must not be negative",
+            ),
+            Step::BiasSpectroscopy { start, end, .. } => {
+                check(start != end, "sweep start and end are equal")
+            }
+            Step::GridSpectroscopy {
+                grid, start, end, ..
+            } => {
+                check(start != end, "sweep start and end are equal");
+                check(
+                    grid.num_points_x > 0 && grid.num_points_y > 0,
+                    "grid needs at least one point in each direction",
+                );
+            }
+            Step::Wait { duration } => check(
+                duration.0.is_finite() && duration.0 >= 0.0,
+                "duration must be finite and not negative",
+            ),
+            _ => {}
+        }
+        if let (Some(path), Some(extension)) = (self.save_path(), self.save_extension()) {
+            check(
+                path.extension().is_some_and(|e| e == extension),
+                &format!("save path should end in .{extension}"),
+            );
+        }
+        problems
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::SetBias { bias } => write!(f, "set bias to {bias}"),
+            Step::SetSetpoint { setpoint } => write!(f, "set setpoint to {setpoint}"),
+            Step::SetZCtrl { on } => {
+                write!(f, "switch Z-controller {}", if *on { "on" } else { "off" })
+            }
+            Step::Withdraw => f.write_str("withdraw tip"),
+            Step::SetScanFrame {
+                center_x,
+                center_y,
+                width,
+                height,
+                ..
+            } => write!(
+                f,
+                "set scan frame to {width} x {height} at ({center_x}, {center_y})"
+            ),
+            Step::SetScanBuffer {
+                channels,
+                pixels,
+                lines,
+            } => write!(
+                f,
+                "record {} channels at {pixels} x {lines} pixels",
+                channels.len()
+            ),
+            Step::SetScanSpeed { time_per_line } => {
+                write!(f, "set time per line to {time_per_line}")
+            }
+            Step::SetScanProps { .. } => f.write_str("set scan properties"),
+            Step::Scan { direction, .. } => write!(f, "scan {direction:?}"),
+            Step::SetBiasSpectrProps { .. } => f.write_str("set bias spectroscopy properties"),
+            Step::BiasSpectroscopy { start, end, .. } => {
+                write!(f, "bias spectroscopy from {start} to {end}")
+            }
+            Step::GridSpectroscopy {
+                grid, start, end, ..
+            } => write!(
+                f,
+                "grid spectroscopy on {} x {} points from {start} to {end}",
+                grid.num_points_x, grid.num_points_y
+            ),
+            Step::Wait { duration } => write!(f, "wait {duration}"),
+            Step::SaveState { .. } => f.write_str("save instrument state"),
+        }?;
+        match self.save_path() {
+            Some(path) => write!(f, ", save to {}", path.display()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A problem found while validating a recipe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecipeIssue {
+    /// Index of the offending step, `None` for the recipe as a whole
+    pub step: Option<usize>,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for RecipeIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.step {
+            Some(step) => write!(f, "step {}: {}", step + 1, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl Recipe {
+    /// Parse a recipe from JSON.
+    ///
+    /// # Errors
+    /// Returns `NanonisError::InvalidFile` if the recipe is malformed.
+    pub fn from_json(json: &str) -> Result<Self, NanonisError> {
+        serde_json::from_str(json).map_err(|e| NanonisError::InvalidFile(format!("Recipe: {e}")))
+    }
+
+    /// Parse a recipe from TOML.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::recipe::{Recipe, Step};
+    ///
+    /// let recipe = Recipe::from_toml(
+    ///     r#"
+    ///     name = "approach bias"
+    ///
+    ///     [[steps]]
+    ///     action = "set_bias"
+    ///     bias = -0.2
+    ///
+    ///     [[steps]]
+    ///     action = "wait"
+    ///     duration = 0.5
+    ///     "#,
+    /// )?;
+    /// assert_eq!(recipe.steps.len(), 2);
+    /// assert!(matches!(recipe.steps[1], Step::Wait { .. }));
+    ///
+    /// // Typos are rejected rather than ignored
+    /// assert!(Recipe::from_toml("name = \"x\"\nsteps = [{ action = \"set_bias\", bais = 1.0 }]").is_err());
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `NanonisError::InvalidFile` if the recipe is malformed.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, NanonisError> {
+        toml::from_str(toml).map_err(|e| NanonisError::InvalidFile(format!("Recipe: {e}")))
+    }
+
+    /// Read a recipe from a `.json` or, with the `toml` feature, `.toml`
+    /// file.
+    ///
+    /// A relative `output_dir` is resolved against the directory of the
+    /// recipe file.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the file cannot be read or is malformed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, NanonisError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| NanonisError::Io {
+            source: e,
+            context: format!("Reading recipe {}", path.display()),
+        })?;
+        let mut recipe = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json(&text)?,
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&text)?,
+            #[cfg(not(feature = "toml"))]
+            Some("toml") => {
+                return Err(NanonisError::InvalidFile(
+                    "TOML recipes need the `toml` feature".to_string(),
+                ))
+            }
+            _ => {
+                return Err(NanonisError::InvalidFile(format!(
+                    "Unknown recipe format: {}",
+                    path.display()
+                )))
+            }
+        };
+        if recipe.output_dir.is_relative() {
+            if let Some(dir) = path.parent() {
+                recipe.output_dir = dir.join(&recipe.output_dir);
+            }
+        }
+        Ok(recipe)
+    }
+
+    /// Check the recipe without an instrument.
+    ///
+    /// Finds out-of-range values, empty sweeps and frames, save paths with
+    /// the wrong extension and steps overwriting each other's files. See
+    /// [`RecipeRunner::dry_run`] for checks against the instrument.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::recipe::{Recipe, Step};
+    /// use nanonis_rs::Seconds;
+    ///
+    /// let recipe = Recipe {
+    ///     name: "endless".to_string(),
+    ///     description: String::new(),
+    ///     output_dir: Default::default(),
+    ///     steps: vec![Step::Wait { duration: Seconds(f64::INFINITY) }],
+    /// };
+    /// assert_eq!(recipe.validate().len(), 1);
+    /// ```
+    pub fn validate(&self) -> Vec<RecipeIssue> {
+        let mut issues = Vec::new();
+        if self.steps.is_empty() {
+            issues.push(RecipeIssue {
+                step: None,
+                message: "recipe has no steps".to_string(),
+            });
+        }
+        for (index, step) in self.steps.iter().enumerate() {
+            for message in step.problems() {
+                issues.push(RecipeIssue {
+                    step: Some(index),
+                    message,
+                });
+            }
+            let Some(path) = step.save_path() else {
+                continue;
+            };
+            let earlier = self.steps[..index]
+                .iter()
+                .position(|other| other.save_path() == Some(path));
+            if let Some(earlier) = earlier {
+                issues.push(RecipeIssue {
+                    step: Some(index),
+                    message: format!(
+                        "overwrites {} saved by step {}",
+                        path.display(),
+                        earlier + 1
+                    ),
+                });
+            }
+        }
+        issues
+    }
+
+    fn output_path(&self, path: &Path) -> Result<PathBuf, NanonisError> {
+        let path = self.output_dir.join(path);
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|e| NanonisError::Io {
+                source: e,
+                context: format!("Creating {}", dir.display()),
+            })?;
+        }
+        Ok(path)
+    }
+}
+
+/// Outcome of [`RecipeRunner::run`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RecipeReport {
+    /// Steps skipped because a previous run had completed them
+    pub resumed: usize,
+    /// Steps executed in this run
+    pub executed: usize,
+    /// Files written in this run
+    pub files: Vec<PathBuf>,
+}
+
+/// Progress of a run, saved after every step so an interrupted run can
+/// continue where it stopped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Progress {
+    recipe: String,
+    steps: Vec<Step>,
+    completed: usize,
+    /// Completed rows of a grid step in progress
+    #[serde(default)]
+    rows: usize,
+}
+
+impl Progress {
+    fn matches(&self, recipe: &Recipe) -> bool {
+        self.recipe == recipe.name && self.steps == recipe.steps
+    }
+}
+
+/// Executes a [`Recipe`] on an instrument.
+///
+/// Every step is logged at info level. With a
+/// [progress file](Self::with_progress_file), the number of completed
+/// steps is saved after each step, and after each row of a grid that is
+/// saved to a file; a later run of the same recipe skips what is done. The
+/// progress file is removed once the recipe has completed.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::grid::GridFile;
+/// use nanonis_rs::pattern::GridConfig;
+/// use nanonis_rs::recipe::{Recipe, RecipeRunner, Step};
+/// use nanonis_rs::{MockServer, Volts};
+///
+/// let dir = std::env::temp_dir().join("nanonis-rs-recipe-grid-example");
+/// let recipe = Recipe {
+///     name: "grid".to_string(),
+///     description: String::new(),
+///     output_dir: dir.clone(),
+///     steps: vec![Step::GridSpectroscopy {
+///         grid: GridConfig {
+///             num_points_x: 3,
+///             num_points_y: 2,
+///             width_m: 2e-9,
+///             height_m: 1e-9,
+///             ..GridConfig::default()
+///         },
+///         start: Volts(-0.5),
+///         end: Volts(0.5),
+///         save: Some("grid.3ds".into()),
+///     }],
+/// };
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// let progress = dir.join("progress.json");
+/// RecipeRunner::new(&mut client)
+///     .with_progress_file(&progress)
+///     .run(&recipe)?;
+///
+/// let grid = GridFile::read(dir.join("grid.3ds"))?;
+/// assert!(grid.point(2, 1).is_some_and(|point| point.is_measured()));
+/// assert!(!progress.exists());
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+pub struct RecipeRunner<'a> {
+    client: &'a mut NanonisClient,
+    progress_file: Option<PathBuf>,
+}
+
+impl<'a> RecipeRunner<'a> {
+    /// Runner for `client`.
+    pub fn new(client: &'a mut NanonisClient) -> Self {
+        Self {
+            client,
+            progress_file: None,
+        }
+    }
+
+    /// Save progress to `path` and resume from it.
+    pub fn with_progress_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.progress_file = Some(path.into());
+        self
+    }
+
+    /// Validate the recipe without changing the instrument.
+    ///
+    /// In addition to [`Recipe::validate`], resolves the signal names of
+    /// scan buffer steps and checks that a saved progress file belongs to
+    /// this recipe. Each step is logged with the action it would take.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if communication fails or a progress file
+    /// cannot be read.
+    pub fn dry_run(&mut self, recipe: &Recipe) -> Result<Vec<RecipeIssue>, NanonisError> {
+        let mut issues = recipe.validate();
+        let completed = match self.load_progress()? {
+            Some(progress) if progress.matches(recipe) => progress.completed,
+            Some(_) => {
+                issues.push(RecipeIssue {
+                    step: None,
+                    message: "progress file belongs to a different recipe".to_string(),
+                });
+                0
+            }
+            None => 0,
+        };
+
+        for (index, step) in recipe.steps.iter().enumerate() {
+            let done = if index < completed { " (done)" } else { "" };
+            info!(
+                "Recipe {}: dry run step {}/{}: {step}{done}",
+                recipe.name,
+                index + 1,
+                recipe.steps.len()
+            );
+            if let Step::SetScanBuffer { channels, .. } = step {
+                for channel in channels {
+                    if let Err(e) = self.client.resolve_slot(channel.clone()) {
+                        issues.push(RecipeIssue {
+                            step: Some(index),
+                            message: e.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(issues)
+    }
+
+    /// Execute the recipe, resuming from the progress file if there is one.
+    ///
+    /// # Errors
+    /// Returns `NanonisError::InvalidFile` without touching the instrument
+    /// if [`Recipe::validate`] finds problems or the progress file belongs
+    /// to a different recipe. Otherwise stops at the first failing step and
+    /// returns its error; progress up to that step is kept.
+    pub fn run(&mut self, recipe: &Recipe) -> Result<RecipeReport, NanonisError> {
+        let issues = recipe.validate();
+        if !issues.is_empty() {
+            let issues: Vec<String> = issues.iter().map(ToString::to_string).collect();
+            return Err(NanonisError::InvalidFile(format!(
+                "Recipe {}: {}",
+                recipe.name,
+                issues.join("; ")
+            )));
+        }
+
+        let mut progress = match self.load_progress()? {
+            Some(progress) if progress.matches(recipe) => progress,
+            Some(_) => {
+                return Err(NanonisError::InvalidFile(format!(
+                    "Progress file does not belong to recipe {}; delete it to start over",
+                    recipe.name
+                )))
+            }
+            None => Progress {
+                recipe: recipe.name.clone(),
+                steps: recipe.steps.clone(),
+                completed: 0,
+                rows: 0,
+            },
+        };
+        let mut report = RecipeReport {
+            resumed: progress.completed,
+            ..RecipeReport::default()
+        };
+        if progress.completed > 0 {
+            info!(
+                "Recipe {}: resuming after step {}",
+                recipe.name, progress.completed
+            );
+        }
+
+        while progress.completed < recipe.steps.len() {
+            let index = progress.completed;
+            let step = &recipe.steps[index];
+            info!(
+                "Recipe {}: step {}/{}: {step}",
+                recipe.name,
+                index + 1,
+                recipe.steps.len()
+            );
+            if let Err(e) = self.execute(recipe, step, &mut progress, &mut report) {
+                warn!("Recipe {}: step {} failed: {e}", recipe.name, index + 1);
+                return Err(e);
+            }
+            progress.completed += 1;
+            progress.rows = 0;
+            report.executed += 1;
+            self.save_progress(&progress)?;
+        }
+
+        if let Some(path) = &self.progress_file {
+            if path.exists() {
+                fs::remove_file(path).map_err(|e| NanonisError::Io {
+                    source: e,
+                    context: format!("Removing {}", path.display()),
+                })?;
+            }
+        }
+        info!("Recipe {}: done", recipe.name);
+        Ok(report)
+    }
+
+    fn execute(
+        &mut self,
+        recipe: &Recipe,
+        step: &Step,
+        progress: &mut Progress,
+        report: &mut RecipeReport,
+    ) -> Result<(), NanonisError> {
+        let client = &mut *self.client;
+        let save = step
+            .save_path()
+            .map(|path| recipe.output_path(path))
+            .transpose()?;
+
+        match step {
+            Step::SetBias { bias } => client.bias_set(*bias)?,
+            Step::SetSetpoint { setpoint } => client.z_ctrl_setpoint_set(*setpoint)?,
+            Step::SetZCtrl { on } => client.z_ctrl_on_off_set(*on)?,
+            Step::Withdraw => client.z_ctrl_withdraw(true, WITHDRAW_TIMEOUT)?,
+            Step::SetScanFrame {
+                center_x,
+                center_y,
+                width,
+                height,
+                angle_deg,
+            } => client.scan_frame_set(ScanFrame::new(
+                Position::new(*center_x, *center_y),
+                *width,
+                *height,
+                *angle_deg,
+            ))?,
+            Step::SetScanBuffer {
+                channels,
+                pixels,
+                lines,
+            } => {
+                self.client
+                    .scan_buffer_set(channels.iter().cloned(), *pixels, *lines)?;
+            }
+            Step::SetScanSpeed { time_per_line } => {
+                let speed = client.scan_speed_get()?;
+                client.scan_config_set(ScanConfig {
+                    forward_time_per_line_s: time_per_line.as_f32(),
+                    backward_time_per_line_s: time_per_line.as_f32(),
+                    keep_parameter_constant: 2,
+                    speed_ratio: 1.0,
+                    ..speed
+                })?;
+            }
+            Step::SetScanProps { props } => client.scan_props_set(props.clone())?,
+            Step::Scan { direction, .. } => {
+                let image = client.scan_session(*direction)?.finish()?;
+                if let Some(path) = &save {
+                    image.write(path)?;
+                }
+            }
+            Step::SetBiasSpectrProps { props } => client.bias_spectr_props_set(props.clone())?,
+            Step::BiasSpectroscopy { start, end, .. } => {
+                client.bias_spectr_limits_set(start.as_f32(), end.as_f32())?;
+                let spectrum = client.bias_spectr_start(true, "")?;
+                if let Some(path) = &save {
+                    DatFile::from(spectrum).write(path)?;
+                }
+            }
+            Step::GridSpectroscopy {
+                grid, start, end, ..
+            } => {
+                client.bias_spectr_limits_set(start.as_f32(), end.as_f32())?;
+                self.grid_spectroscopy(grid, save.as_deref(), progress)?;
+            }
+            Step::Wait { duration } => thread::sleep((*duration).into()),
+            Step::SaveState { .. } => {
+                let state = client.snapshot()?;
+                if let Some(path) = &save {
+                    fs::write(path, serde_json::to_string_pretty(&state)?).map_err(|e| {
+                        NanonisError::Io {
+                            source: e,
+                            context: format!("Writing {}", path.display()),
+                        }
+                    })?;
+                }
+            }
+        }
+
+        report.files.extend(save);
+        Ok(())
+    }
+
+    /// Measure the grid row by row, continuing after `progress.rows`.
+    fn grid_spectroscopy(
+        &mut self,
+        grid: &GridConfig,
+        save: Option<&Path>,
+        progress: &mut Progress,
+    ) -> Result<(), NanonisError> {
+        let (width, height) = (grid.num_points_x as usize, grid.num_points_y as usize);
+        // Rows already measured are only kept in the saved file
+        let mut file = match save {
+            Some(path) if progress.rows > 0 => Some(GridFile::read(path)?),
+            _ => None,
+        };
+        let first_row = if file.is_some() { progress.rows } else { 0 };
+
+        for y in first_row..height {
+            for x in 0..width {
+                self.client.folme_xy_pos_set(grid.position(x, y), true)?;
+                let spectrum = self.client.bias_spectr_start(true, "")?;
+                let file = match &mut file {
+                    Some(file) => file,
+                    None => file.insert(new_grid_file(grid, &spectrum)?),
+                };
+                file.set_spectrum(x, y, &spectrum)?;
+            }
+            if let (Some(path), Some(file)) = (save, &file) {
+                file.write(path)?;
+                progress.rows = y + 1;
+                self.save_progress(progress)?;
+            }
+            info!("Grid row {}/{height} done", y + 1);
+        }
+        Ok(())
+    }
+
+    fn load_progress(&self) -> Result<Option<Progress>, NanonisError> {
+        let Some(path) = self.progress_file.as_ref().filter(|path| path.exists()) else {
+            return Ok(None);
+        };
+        let text = fs::read_to_string(path).map_err(|e| NanonisError::Io {
+            source: e,
+            context: format!("Reading {}", path.display()),
+        })?;
+        Ok(Some(serde_json::from_str(&text)?))
+    }
+
+    fn save_progress(&self, progress: &Progress) -> Result<(), NanonisError> {
+        let Some(path) = &self.progress_file else {
+            return Ok(());
+        };
+        // Write then rename so an interruption never leaves a torn file
+        let temp = path.with_extension("tmp");
+        let io_error = |e| NanonisError::Io {
+            source: e,
+            context: format!("Writing {}", path.display()),
+        };
+        fs::write(&temp, serde_json::to_string(progress)?).map_err(io_error)?;
+        fs::rename(&temp, path).map_err(io_error)
+    }
+}
+
+/// Grid file laid out after the first measured spectrum, whose first
+/// channel is the sweep signal.
+fn new_grid_file(grid: &GridConfig, spectrum: &BiasSpectrResult) -> Result<GridFile, NanonisError> {
+    let (sweep, channels) = spectrum.channel_names.split_first().ok_or_else(|| {
+        NanonisError::Protocol("Bias spectroscopy returned no channels".to_string())
+    })?;
+    Ok(GridFile::new(
+        *grid,
+        sweep.clone(),
+        channels.to_vec(),
+        spectrum.data.cols(),
+    ))
+}
@@ -0,0 +1,67 @@
+//! Typed descriptors for the `SafeTip.*` and `ZCtrl.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `SafeTip.OnOffSet`: switch the Safe Tip feature on or off.
+    pub struct SafeTipOnOffSet: "SafeTip.OnOffSet" {
+        /// 0 = no change, 1 = on, 2 = off
+        pub status: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SafeTip.OnOffGet`: get the current on-off status of the Safe Tip feature.
+    pub struct SafeTipOnOffGet: "SafeTip.OnOffGet" {} -> SafeTipOnOffGetResponse {
+        /// 1 if Safe Tip is on
+        pub status: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `SafeTip.SignalGet`: get the current Safe Tip signal value.
+    pub struct SafeTipSignalGet: "SafeTip.SignalGet" {} -> SafeTipSignalGetResponse {
+        /// Current value of the Safe Tip signal
+        pub value: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `SafeTip.PropsSet`: set the Safe Tip configuration parameters.
+    pub struct SafeTipPropsSet: "SafeTip.PropsSet" {
+        /// Enable automatic Z-controller recovery after Safe Tip event
+        pub auto_recovery: u16 = "H",
+        /// Enable automatic scan pause/hold on Safe Tip events
+        pub auto_pause_scan: u16 = "H",
+        /// Signal threshold value that triggers Safe Tip protection;
+        pub threshold: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SafeTip.PropsGet`: get the current Safe Tip configuration.
+    pub struct SafeTipPropsGet: "SafeTip.PropsGet" {} -> SafeTipPropsGetResponse {
+        /// Auto recovery enabled/disabled
+        pub auto_recovery: u16 = "H",
+        /// Auto pause scan enabled/disabled
+        pub auto_pause_scan: u16 = "H",
+        /// Threshold value for triggering Safe Tip protection
+        pub threshold: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.TipLiftSet`: set the tip lift distance for safety operations.
+    pub struct ZCtrlTipLiftSet: "ZCtrl.TipLiftSet" {
+        /// Tip lift distance in meters (positive = away from surface)
+        pub tip_lift_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.TipLiftGet`: get the current tip lift distance setting.
+    pub struct ZCtrlTipLiftGet: "ZCtrl.TipLiftGet" {} -> ZCtrlTipLiftGetResponse {
+        /// Tip lift distance in meters (positive = away from surface)
+        pub tip_lift_m: f32 = "f",
+    }
+}
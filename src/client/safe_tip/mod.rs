@@ -1,6 +1,8 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::units::Setpoint;
 
 impl NanonisClient {
     /// Switch the Safe Tip feature on or off.
@@ -35,12 +37,7 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let status = if safe_tip_on { 1u16 } else { 2u16 };
 
-        self.quick_send(
-            "SafeTip.OnOffSet",
-            vec![NanonisValue::U16(status)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::SafeTipOnOffSet { status })?;
         Ok(())
     }
 
@@ -69,15 +66,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn safe_tip_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result =
-            self.quick_send("SafeTip.OnOffGet", vec![], vec![], vec!["H"])?;
+        let response = self.send(&commands::SafeTipOnOffGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_u16()? == 1),
-            None => Err(NanonisError::Protocol(
-                "No Safe Tip status returned".to_string(),
-            )),
-        }
+        Ok(response.status == 1)
     }
 
     /// Get the current Safe Tip signal value.
@@ -108,15 +99,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn safe_tip_signal_get(&mut self) -> Result<f32, NanonisError> {
-        let result =
-            self.quick_send("SafeTip.SignalGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::SafeTipSignalGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No Safe Tip signal value returned".to_string(),
-            )),
-        }
+        Ok(response.value)
     }
 
     /// Set the Safe Tip configuration parameters.
@@ -128,43 +113,55 @@ impl NanonisClient {
     /// # Arguments
     /// * `auto_recovery` - Enable automatic Z-controller recovery after Safe Tip event
     /// * `auto_pause_scan` - Enable automatic scan pause/hold on Safe Tip events
-    /// * `threshold` - Signal threshold value that triggers Safe Tip protection
+    /// * `threshold` - Signal threshold value that triggers Safe Tip protection,
+    ///   e.g. [`Amperes`](crate::Amperes); a raw value in the unit of the
+    ///   signal goes through [`Setpoint::new`], see [`Setpoint`]
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails or invalid parameters provided.
     ///
     /// # Examples
     /// ```no_run
-    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::{Amperes, NanonisClient};
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
     /// // Configure Safe Tip with automatic recovery and scan pause
-    /// client.safe_tip_props_set(true, true, 1e-9)?;  // 1 nA threshold
+    /// client.safe_tip_props_set(true, true, Amperes::nanoamperes(1.0))?;
     ///
     /// // Conservative settings for delicate samples
-    /// client.safe_tip_props_set(true, true, 500e-12)?;  // 500 pA threshold
+    /// client.safe_tip_props_set(true, true, Amperes::picoamperes(500.0))?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// A raw value, e.g. read from a config file, is wrapped explicitly:
+    /// ```
+    /// use nanonis_rs::{MockServer, Setpoint};
+    ///
+    /// let server = MockServer::start()?;
+    /// server.respond("SafeTip.PropsSet", vec![], vec![]);
+    /// let mut client = server.client()?;
+    ///
+    /// let raw_threshold: f64 = 2e-9;
+    /// client.safe_tip_props_set(true, false, Setpoint::new(raw_threshold))?;
+    /// let args = server.requests()[0].args(&["H", "H", "f"])?;
+    /// assert_eq!(args[2].as_f32()?, 2e-9);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn safe_tip_props_set(
         &mut self,
         auto_recovery: bool,
         auto_pause_scan: bool,
-        threshold: f32,
+        threshold: impl Into<Setpoint>,
     ) -> Result<(), NanonisError> {
         let recovery_flag = if auto_recovery { 1u16 } else { 0u16 };
         let pause_flag = if auto_pause_scan { 1u16 } else { 0u16 };
 
-        self.quick_send(
-            "SafeTip.PropsSet",
-            vec![
-                NanonisValue::U16(recovery_flag),
-                NanonisValue::U16(pause_flag),
-                NanonisValue::F32(threshold),
-            ],
-            vec!["H", "H", "f"],
-            vec![],
-        )?;
+        self.send(&commands::SafeTipPropsSet {
+            auto_recovery: recovery_flag,
+            auto_pause_scan: pause_flag,
+            threshold: threshold.into().as_f32(),
+        })?;
         Ok(())
     }
 
@@ -204,23 +201,12 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn safe_tip_props_get(&mut self) -> Result<(bool, bool, f32), NanonisError> {
-        let result = self.quick_send(
-            "SafeTip.PropsGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "f"],
-        )?;
+        let response = self.send(&commands::SafeTipPropsGet {})?;
 
-        if result.len() >= 3 {
-            let auto_recovery = result[0].as_u16()? == 1;
-            let auto_pause_scan = result[1].as_u16()? == 1;
-            let threshold = result[2].as_f32()?;
-            Ok((auto_recovery, auto_pause_scan, threshold))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Safe Tip properties response".to_string(),
-            ))
-        }
+        let auto_recovery = response.auto_recovery == 1;
+        let auto_pause_scan = response.auto_pause_scan == 1;
+        let threshold = response.threshold;
+        Ok((auto_recovery, auto_pause_scan, threshold))
     }
 
     /// Set the tip lift distance for safety operations.
@@ -255,12 +241,7 @@ impl NanonisClient {
         &mut self,
         tip_lift_m: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.TipLiftSet",
-            vec![NanonisValue::F32(tip_lift_m)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::ZCtrlTipLiftSet { tip_lift_m })?;
         Ok(())
     }
 
@@ -291,15 +272,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_tip_lift_get(&mut self) -> Result<f32, NanonisError> {
-        let result =
-            self.quick_send("ZCtrl.TipLiftGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::ZCtrlTipLiftGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No tip lift distance returned".to_string(),
-            )),
-        }
+        Ok(response.tip_lift_m)
     }
 
     /// Perform a comprehensive safety check of all tip protection systems.
@@ -0,0 +1,286 @@
+//! Typed descriptors for the `Scan.*` commands.
+
+use super::{AutopasteMode, AutosaveMode, ScanProps};
+use crate::error::NanonisError;
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `Scan.Action`: start, stop, pause or resume a scan.
+    pub struct ScanAction: "Scan.Action" {
+        /// 0 = start, 1 = stop, 2 = pause, 3 = resume
+        pub action: u16 = "H",
+        /// 0 = down, 1 = up
+        pub direction: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Scan.FrameSet`: set the scan frame position, size and angle.
+    pub struct ScanFrameSet: "Scan.FrameSet" {
+        /// Frame center X in meters
+        pub center_x: f32 = "f",
+        /// Frame center Y in meters
+        pub center_y: f32 = "f",
+        /// Frame width in meters
+        pub width: f32 = "f",
+        /// Frame height in meters
+        pub height: f32 = "f",
+        /// Frame angle in degrees
+        pub angle: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Scan.FrameGet`: get the scan frame position, size and angle.
+    pub struct ScanFrameGet: "Scan.FrameGet" {} -> ScanFrameGetResponse {
+        /// Frame center X in meters
+        pub center_x: f32 = "f",
+        /// Frame center Y in meters
+        pub center_y: f32 = "f",
+        /// Frame width in meters
+        pub width: f32 = "f",
+        /// Frame height in meters
+        pub height: f32 = "f",
+        /// Frame angle in degrees
+        pub angle: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.BufferSet`: set the recorded channels and the scan resolution.
+    pub struct ScanBufferSet: "Scan.BufferSet" {
+        /// Signal slot indexes (0-23) of the recorded channels
+        pub channel_indexes: Vec<i32> = "+*i",
+        /// Pixels per line
+        pub pixels: i32 = "i",
+        /// Number of lines
+        pub lines: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Scan.BufferGet`: get the recorded channels and the scan resolution.
+    pub struct ScanBufferGet: "Scan.BufferGet" {} -> ScanBufferGetResponse {
+        /// Number of recorded channels
+        pub num_channels: i32 = "i",
+        /// Signal slot indexes (0-23) of the recorded channels
+        pub channel_indexes: Vec<i32> = "*i",
+        /// Pixels per line
+        pub pixels: i32 = "i",
+        /// Number of lines
+        pub lines: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.StatusGet`: get whether a scan is running.
+    pub struct ScanStatusGet: "Scan.StatusGet" {} -> ScanStatusGetResponse {
+        /// 0 = not running, 1 = running
+        pub status: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.FrameDataGrab`: grab the data of one channel of the scan frame.
+    pub struct ScanFrameDataGrab: "Scan.FrameDataGrab" {
+        /// Signal slot index of the channel
+        pub channel_index: u32 = "I",
+        /// 0 = backward, 1 = forward
+        pub data_direction: u32 = "I",
+    } -> ScanFrameDataGrabResponse {
+        /// Length of the channel name in bytes
+        pub name_size: i32 = "i",
+        /// Channel name
+        pub channel_name: String = "*-c",
+        /// Number of rows (scan lines)
+        pub rows: i32 = "i",
+        /// Number of columns (pixels per line)
+        pub cols: i32 = "i",
+        /// Frame data, one row per scan line
+        pub data: Array2D<f32> = "2f",
+        /// 0 = down, 1 = up
+        pub scan_direction: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.WaitEndOfScan`: wait for the end of the running scan.
+    pub struct ScanWaitEndOfScan: "Scan.WaitEndOfScan" blocking {
+        /// Timeout in milliseconds, -1 waits forever
+        pub timeout_ms: i32 = "i",
+    } -> ScanWaitEndOfScanResponse {
+        /// 0 = scan finished, 1 = timeout occurred
+        pub timeout_occurred: u32 = "I",
+        /// Length of the file path in bytes
+        pub file_path_size: u32 = "I",
+        /// Path of the auto-saved file, empty if nothing was saved
+        pub file_path: String = "*-c",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.PropsGet`: get the scan properties.
+    pub struct ScanPropsGet: "Scan.PropsGet" {} -> ScanPropsGetResponse {
+        /// 0 = off, 1 = on
+        pub continuous_scan: u32 = "I",
+        /// 0 = off, 1 = on
+        pub bouncy_scan: u32 = "I",
+        /// 0 = all, 1 = next, 2 = off
+        pub autosave: u32 = "I",
+        /// Length of the series name in bytes
+        pub series_name_size: i32 = "i",
+        /// Base name of the saved files
+        pub series_name: String = "*-c",
+        /// Length of the comment in bytes
+        pub comment_size: i32 = "i",
+        /// Comment saved in the file header
+        pub comment: String = "*-c",
+        /// Size of the module names array in bytes
+        pub modules_names_size: i32 = "i",
+        /// Number of module names
+        pub modules_names_count: i32 = "i",
+        /// Names of the modules with additional parameters
+        pub modules_names: Vec<String> = "*+c",
+        /// Number of entries in `num_params_per_module`
+        pub num_params_size: i32 = "i",
+        /// Number of parameters of each module
+        pub num_params_per_module: Vec<i32> = "*+i",
+        /// Rows of the parameter table
+        pub params_rows: i32 = "i",
+        /// Columns of the parameter table
+        pub params_cols: i32 = "i",
+        /// Parameter table, row-major
+        pub parameters: Vec<String> = "*+c",
+        /// 0 = all, 1 = next, 2 = off
+        pub autopaste: u32 = "I",
+    }
+}
+
+impl TryFrom<ScanPropsGetResponse> for ScanProps {
+    type Error = NanonisError;
+
+    fn try_from(props: ScanPropsGetResponse) -> Result<Self, Self::Error> {
+        // Convert flat parameter array to 2D (rows x cols)
+        let cols = props.params_cols.max(0) as usize;
+        let parameters = if cols == 0 {
+            Vec::new()
+        } else {
+            props
+                .parameters
+                .chunks(cols)
+                .take(props.params_rows.max(0) as usize)
+                .map(|row| row.to_vec())
+                .collect()
+        };
+
+        Ok(ScanProps {
+            continuous_scan: props.continuous_scan == 1,
+            bouncy_scan: props.bouncy_scan == 1,
+            autosave: AutosaveMode::try_from(props.autosave)?,
+            series_name: props.series_name,
+            comment: props.comment,
+            modules_names: props.modules_names,
+            num_params_per_module: props.num_params_per_module,
+            parameters,
+            autopaste: AutopasteMode::try_from(props.autopaste)?,
+        })
+    }
+}
+
+nanonis_command! {
+    /// `Scan.SpeedSet`: configure scan speed parameters.
+    pub struct ScanSpeedSet: "Scan.SpeedSet" {
+        /// Forward linear speed in m/s
+        pub forward_linear_speed_m_s: f32 = "f",
+        /// Backward linear speed in m/s
+        pub backward_linear_speed_m_s: f32 = "f",
+        /// Forward time per line in seconds
+        pub forward_time_per_line_s: f32 = "f",
+        /// Backward time per line in seconds
+        pub backward_time_per_line_s: f32 = "f",
+        /// Which parameter to keep constant: 0=no change, 1=linear speed, 2=time per line
+        pub keep_parameter_constant: u16 = "H",
+        /// Backward tip speed relative to forward speed
+        pub speed_ratio: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Scan.SpeedGet`: get the current scan speed parameters.
+    pub struct ScanSpeedGet: "Scan.SpeedGet" {} -> ScanSpeedGetResponse {
+        /// Forward linear speed (m/s)
+        pub forward_linear_speed_m_s: f32 = "f",
+        /// Backward linear speed (m/s)
+        pub backward_linear_speed_m_s: f32 = "f",
+        /// Forward time per line (s)
+        pub forward_time_per_line_s: f32 = "f",
+        /// Backward time per line (s)
+        pub backward_time_per_line_s: f32 = "f",
+        /// Keep parameter constant (0=linear speed, 1=time per line)
+        pub keep_parameter_constant: u16 = "H",
+        /// Speed ratio (backward relative to forward)
+        pub speed_ratio: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.XYPosGet`: get the current XY position during scanning.
+    pub struct ScanXyPosGet: "Scan.XYPosGet" {
+        /// If `true`, discards first value and waits for fresh data
+        pub wait_newest_data: u32 = "I",
+    } -> ScanXyPosGetResponse {
+        /// Tip X position in meters
+        pub x: f32 = "f",
+        /// Tip Y position in meters
+        pub y: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.Save`: save the current scan data buffer to file.
+    pub struct ScanSave: "Scan.Save" {
+        /// If `true`, waits for save completion before returning
+        pub wait_until_saved: u32 = "I",
+        /// Timeout in milliseconds (-1 for indefinite wait)
+        pub timeout_ms: i32 = "i",
+    } -> ScanSaveResponse {
+        /// 1 if the save timed out
+        pub timed_out: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `Scan.PropsSet`: set scan properties configuration.
+    pub struct ScanPropsSet: "Scan.PropsSet" {
+        /// 0 = no change, 1 = on, 2 = off
+        pub continuous_flag: u32 = "I",
+        /// 0 = no change, 1 = on, 2 = off
+        pub bouncy_flag: u32 = "I",
+        /// 0 = no change, 1 = all, 2 = next, 3 = off
+        pub autosave_flag: u32 = "I",
+        /// Base name of the saved files
+        pub series_name: String = "+*c",
+        /// Comment saved in the file header
+        pub comment: String = "+*c",
+        /// Names of the modules with additional parameters
+        pub modules_names: Vec<String> = "+*c",
+        /// 0 = no change, 1 = all, 2 = next, 3 = off
+        pub autopaste_flag: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Scan.BackgroundPaste`: paste background image at specified location.
+    pub struct ScanBackgroundPaste: "Scan.BackgroundPaste" {
+        /// X position in meters for paste location
+        pub x: f64 = "d",
+        /// Y position in meters for paste location
+        pub y: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Scan.BackgroundDelete`: delete the stored background image.
+    pub struct ScanBackgroundDelete: "Scan.BackgroundDelete" {} -> ()
+}
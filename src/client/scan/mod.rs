@@ -1,9 +1,14 @@
+mod session;
 mod types;
+pub use session::*;
 pub use types::*;
 
+pub mod commands;
+
+use super::signals::SlotRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::{NanonisValue, Position};
+use crate::types::{Array2D, Position};
 use std::time::Duration;
 
 impl NanonisClient {
@@ -13,78 +18,61 @@ impl NanonisClient {
         scan_action: ScanAction,
         scan_direction: ScanDirection,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Scan.Action",
-            vec![
-                NanonisValue::U16(scan_action.into()),
-                NanonisValue::U32(scan_direction.into()),
-            ],
-            vec!["H", "I"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ScanAction {
+            action: scan_action.into(),
+            direction: scan_direction.into(),
+        })
     }
 
     /// Configure the scan frame parameters
     pub fn scan_frame_set(&mut self, frame: ScanFrame) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Scan.FrameSet",
-            vec![
-                NanonisValue::F32(frame.center.x as f32),
-                NanonisValue::F32(frame.center.y as f32),
-                NanonisValue::F32(frame.width_m),
-                NanonisValue::F32(frame.height_m),
-                NanonisValue::F32(frame.angle_deg),
-            ],
-            vec!["f", "f", "f", "f", "f"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ScanFrameSet {
+            center_x: frame.center.x as f32,
+            center_y: frame.center.y as f32,
+            width: frame.width_m,
+            height: frame.height_m,
+            angle: frame.angle_deg,
+        })
     }
 
     /// Get the scan frame parameters
+    ///
+    /// The server sends the center as `f32`, like the other fields.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{MockServer, NanonisValue};
+    ///
+    /// let server = MockServer::start()?;
+    /// server.respond(
+    ///     "Scan.FrameGet",
+    ///     [1e-7, -2e-7, 5e-8, 5e-8, 30.0].map(NanonisValue::F32).to_vec(),
+    ///     vec!["f", "f", "f", "f", "f"],
+    /// );
+    ///
+    /// let frame = server.client()?.scan_frame_get()?;
+    /// assert_eq!(frame.center.x, 1e-7f32 as f64);
+    /// assert_eq!(frame.angle_deg, 30.0);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn scan_frame_get(&mut self) -> Result<ScanFrame, NanonisError> {
-        let result = self.quick_send(
-            "Scan.FrameGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "f", "f", "f"],
-        )?;
-        if result.len() >= 5 {
-            let center_x = result[0].as_f64()?;
-            let center_y = result[1].as_f64()?;
-            let width = result[2].as_f32()?;
-            let height = result[3].as_f32()?;
-            let angle = result[4].as_f32()?;
-
-            Ok(ScanFrame::new(
-                Position::new(center_x, center_y),
-                width,
-                height,
-                angle,
-            ))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid scan frame response".to_string(),
-            ))
-        }
+        let frame = self.send(&commands::ScanFrameGet {})?;
+        Ok(ScanFrame {
+            center: Position {
+                x: frame.center_x as f64,
+                y: frame.center_y as f64,
+            },
+            width_m: frame.width,
+            height_m: frame.height,
+            angle_deg: frame.angle,
+        })
     }
 
     /// Get the scan buffer parameters
     /// Returns: (channel_indexes, pixels, lines)
     pub fn scan_buffer_get(&mut self) -> Result<(Vec<i32>, i32, i32), NanonisError> {
-        let result =
-            self.quick_send("Scan.BufferGet", vec![], vec![], vec!["i", "*i", "i", "i"])?;
-        if result.len() >= 4 {
-            let channel_indexes = result[1].as_i32_array()?.to_vec();
-            let pixels = result[2].as_i32()?;
-            let lines = result[3].as_i32()?;
-            Ok((channel_indexes, pixels, lines))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid scan buffer response".to_string(),
-            ))
-        }
+        let buffer = self.send(&commands::ScanBufferGet {})?;
+        Ok((buffer.channel_indexes, buffer.pixels, buffer.lines))
     }
 
     /// Get the current scan status.
@@ -111,31 +99,29 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn scan_status_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("Scan.StatusGet", vec![], vec![], vec!["I"])?;
-
-        match result.first() {
-            Some(value) => Ok(value.as_u32()? == 1),
-            None => Err(NanonisError::Protocol(
-                "No scan status returned".to_string(),
-            )),
-        }
+        Ok(self.send(&commands::ScanStatusGet {})?.status == 1)
     }
 
     /// Configure the scan buffer parameters.
     ///
     /// Sets which channels to record during scanning and the scan resolution.
-    /// The channel indexes refer to the 24 signals assigned in the Signals Manager (0-23).
+    /// The scan buffer records the 24 signal slots of the Signals Manager.
+    /// Bare integers are slots (0-23), as returned by the matching getter;
+    /// names and [`SignalIndex`](crate::signals::SignalIndex)es are mapped
+    /// to the slot their signal is assigned to, see
+    /// [`SlotRef`](crate::signals::SlotRef).
     ///
     /// **Important**: The number of pixels is coerced to the closest multiple of 16
     /// because scan data is sent in packages of 16 pixels.
     ///
     /// # Arguments
-    /// * `channel_indexes` - Indexes of channels to record (0-23 for signals in Signals Manager)
+    /// * `channels` - Signals to record
     /// * `pixels` - Number of pixels per line (coerced to multiple of 16)
     /// * `lines` - Number of scan lines
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails or invalid parameters provided.
+    /// Returns `NanonisError` if a signal does not resolve or has no slot,
+    /// communication fails or invalid parameters provided.
     ///
     /// # Examples
     /// ```no_run
@@ -143,30 +129,55 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
-    /// // Record channels 0, 1, and 2 with 512x512 resolution
-    /// client.scan_buffer_set(vec![0, 1, 2], 512, 512)?;
+    /// // Record current and height with 512x512 resolution
+    /// client.scan_buffer_set(["Current", "Z"], 512, 512)?;
     ///
-    /// // High resolution scan with multiple channels
-    /// client.scan_buffer_set(vec![0, 1, 2, 3, 4], 1024, 1024)?;
+    /// // High resolution scan of slots 0 to 4
+    /// client.scan_buffer_set(0..5, 1024, 1024)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn scan_buffer_set(
+    ///
+    /// The slots returned by [`scan_buffer_get`](Self::scan_buffer_get) can
+    /// be passed back unchanged, also where signal indexes and slots differ:
+    /// ```
+    /// use nanonis_rs::signals::SignalIndex;
+    /// use nanonis_rs::{MockServer, SimulatedStm};
+    ///
+    /// let server = MockServer::start()?;
+    /// let sim = SimulatedStm::new();
+    /// sim.attach(&server);
+    /// // Current (signal 0) in slot 2, Z (signal 2) in slot 0
+    /// sim.update(|state| state.slot_signals.swap(0, 2));
+    /// let mut client = server.client()?;
+    ///
+    /// client.scan_buffer_set(["Current"], 16, 16)?;
+    /// assert_eq!(client.scan_buffer_get()?.0, vec![2]);
+    ///
+    /// client.scan_buffer_set([SignalIndex(2)], 16, 16)?;
+    /// let (slots, pixels, lines) = client.scan_buffer_get()?;
+    /// assert_eq!(slots, vec![0]);
+    ///
+    /// client.scan_buffer_set(slots.clone(), pixels, lines)?;
+    /// assert_eq!(client.scan_buffer_get()?.0, slots);
+    /// let (name, _, _) = client.scan_frame_data_grab(0, true)?;
+    /// assert_eq!(name, "Z (m)");
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn scan_buffer_set<S: Into<SlotRef>>(
         &mut self,
-        channel_indexes: Vec<i32>,
+        channels: impl IntoIterator<Item = S>,
         pixels: i32,
         lines: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Scan.BufferSet",
-            vec![
-                NanonisValue::ArrayI32(channel_indexes),
-                NanonisValue::I32(pixels),
-                NanonisValue::I32(lines),
-            ],
-            vec!["+*i", "i", "i"],
-            vec![],
-        )?;
-        Ok(())
+        let channel_indexes = channels
+            .into_iter()
+            .map(|channel| self.resolve_slot(channel))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.send(&commands::ScanBufferSet {
+            channel_indexes,
+            pixels,
+            lines,
+        })
     }
 
     /// Configure scan speed parameters.
@@ -206,19 +217,14 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn scan_config_set(&mut self, config: ScanConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Scan.SpeedSet",
-            vec![
-                NanonisValue::F32(config.forward_linear_speed_m_s),
-                NanonisValue::F32(config.backward_linear_speed_m_s),
-                NanonisValue::F32(config.forward_time_per_line_s),
-                NanonisValue::F32(config.backward_time_per_line_s),
-                NanonisValue::U16(config.keep_parameter_constant),
-                NanonisValue::F32(config.speed_ratio),
-            ],
-            vec!["f", "f", "f", "f", "H", "f"],
-            vec![],
-        )?;
+        self.send(&commands::ScanSpeedSet {
+            forward_linear_speed_m_s: config.forward_linear_speed_m_s,
+            backward_linear_speed_m_s: config.backward_linear_speed_m_s,
+            forward_time_per_line_s: config.forward_time_per_line_s,
+            backward_time_per_line_s: config.backward_time_per_line_s,
+            keep_parameter_constant: config.keep_parameter_constant,
+            speed_ratio: config.speed_ratio,
+        })?;
         Ok(())
     }
 
@@ -253,27 +259,16 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn scan_speed_get(&mut self) -> Result<ScanConfig, NanonisError> {
-        let result = self.quick_send(
-            "Scan.SpeedGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "f", "f", "H", "f"],
-        )?;
-
-        if result.len() >= 6 {
-            Ok(ScanConfig {
-                forward_linear_speed_m_s: result[0].as_f32()?,
-                backward_linear_speed_m_s: result[1].as_f32()?,
-                forward_time_per_line_s: result[2].as_f32()?,
-                backward_time_per_line_s: result[3].as_f32()?,
-                keep_parameter_constant: result[4].as_u16()?,
-                speed_ratio: result[5].as_f32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid scan speed response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ScanSpeedGet {})?;
+
+        Ok(ScanConfig {
+            forward_linear_speed_m_s: response.forward_linear_speed_m_s,
+            backward_linear_speed_m_s: response.backward_linear_speed_m_s,
+            forward_time_per_line_s: response.forward_time_per_line_s,
+            backward_time_per_line_s: response.backward_time_per_line_s,
+            keep_parameter_constant: response.keep_parameter_constant,
+            speed_ratio: response.speed_ratio,
+        })
     }
 
     /// Get the current XY position during scanning.
@@ -307,20 +302,9 @@ impl NanonisClient {
     pub fn scan_xy_pos_get(&mut self, wait_newest_data: bool) -> Result<(f32, f32), NanonisError> {
         let wait_flag = if wait_newest_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "Scan.XYPosGet",
-            vec![NanonisValue::U32(wait_flag)],
-            vec!["I"],
-            vec!["f", "f"],
-        )?;
+        let response = self.send(&commands::ScanXyPosGet { wait_newest_data: wait_flag })?;
 
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid XY position response".to_string(),
-            ))
-        }
+        Ok((response.x, response.y))
     }
 
     /// Save the current scan data buffer to file.
@@ -361,19 +345,9 @@ impl NanonisClient {
     ) -> Result<bool, NanonisError> {
         let wait_flag = if wait_until_saved { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "Scan.Save",
-            vec![NanonisValue::U32(wait_flag), NanonisValue::I32(timeout_ms)],
-            vec!["I", "i"],
-            vec!["I"],
-        )?;
+        let response = self.send(&commands::ScanSave { wait_until_saved: wait_flag, timeout_ms })?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_u32()? == 1),
-            None => Err(NanonisError::Protocol(
-                "No save status returned".to_string(),
-            )),
-        }
+        Ok(response.timed_out == 1)
     }
 
     /// Get scan frame data for a specific channel and direction.
@@ -388,7 +362,7 @@ impl NanonisClient {
     /// # Returns
     /// A tuple containing:
     /// - `String` - Channel name
-    /// - `Vec<Vec<f32>>` - 2D scan data array \[rows\]\[columns\]
+    /// - `Array2D<f32>` - 2D scan data, one row per scan line
     /// - `bool` - Scan direction: `true` for up, `false` for down
     ///
     /// # Errors
@@ -405,51 +379,52 @@ impl NanonisClient {
     /// // Get forward scan data for channel 0
     /// let (channel_name, data, scan_up) = client.scan_frame_data_grab(0, true)?;
     /// println!("Channel: {}, Direction: {}", channel_name, if scan_up { "up" } else { "down" });
-    /// println!("Data size: {}x{}", data.len(), data[0].len());
+    /// println!("Data size: {}x{}", data.rows(), data.cols());
     ///
     /// // Get backward scan data
     /// let (_, back_data, _) = client.scan_frame_data_grab(0, false)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// The data arrives as a `2f` array sized by the rows and columns
+    /// sent before it:
+    /// ```
+    /// use nanonis_rs::{Array2D, MockServer, NanonisValue};
+    ///
+    /// let server = MockServer::start()?;
+    /// let name = "Z (m)".to_string();
+    /// let data = Array2D::from_vec(2, 3, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0])?;
+    /// server.respond(
+    ///     "Scan.FrameDataGrab",
+    ///     vec![
+    ///         NanonisValue::I32(name.len() as i32),
+    ///         NanonisValue::String(name),
+    ///         NanonisValue::I32(2),
+    ///         NanonisValue::I32(3),
+    ///         NanonisValue::Array2DF32(data),
+    ///         NanonisValue::U32(1),
+    ///     ],
+    ///     vec!["i", "*-c", "i", "i", "2f", "I"],
+    /// );
+    ///
+    /// let (channel, data, scan_up) = server.client()?.scan_frame_data_grab(0, true)?;
+    /// assert_eq!(channel, "Z (m)");
+    /// assert_eq!(data.row(1), Some(&[3.0, 4.0, 5.0][..]));
+    /// assert!(scan_up);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn scan_frame_data_grab(
         &mut self,
         channel_index: u32,
         data_direction: bool,
-    ) -> Result<(String, Vec<Vec<f32>>, bool), NanonisError> {
+    ) -> Result<(String, Array2D<f32>, bool), NanonisError> {
         let direction_flag = if data_direction { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "Scan.FrameDataGrab",
-            vec![
-                NanonisValue::U32(channel_index),
-                NanonisValue::U32(direction_flag),
-            ],
-            vec!["I", "I"],
-            vec!["i", "*-c", "i", "i", "2f", "I"],
-        )?;
-
-        if result.len() >= 6 {
-            let channel_name = result[1].as_string()?.to_string();
-            let rows = result[2].as_i32()? as usize;
-            let cols = result[3].as_i32()? as usize;
-
-            // Parse 2D array from flat f32 array
-            let flat_data = result[4].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                data_2d.push(flat_data[start_idx..end_idx].to_vec());
-            }
-
-            let scan_direction = result[5].as_u32()? == 1;
-            Ok((channel_name, data_2d, scan_direction))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid frame data response".to_string(),
-            ))
-        }
+        let grab = self.send(&commands::ScanFrameDataGrab {
+            channel_index,
+            data_direction: direction_flag,
+        })?;
+        Ok((grab.channel_name, grab.data, grab.scan_direction == 1))
     }
 
     /// Wait for the End-of-Scan.
@@ -496,22 +471,10 @@ impl NanonisClient {
         &mut self,
         timeout: Duration,
     ) -> Result<(bool, String), NanonisError> {
-        let result = self.quick_send(
-            "Scan.WaitEndOfScan",
-            vec![NanonisValue::I32(timeout.as_millis() as i32)],
-            vec!["i"],
-            vec!["I", "I", "*-c"],
-        )?;
-
-        if result.len() >= 3 {
-            let timeout_occurred = result[0].as_u32()? == 1;
-            let file_path = result[2].as_string()?.to_string();
-            Ok((timeout_occurred, file_path))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid scan wait response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ScanWaitEndOfScan {
+            timeout_ms: timeout.as_millis() as i32,
+        })?;
+        Ok((response.timeout_occurred == 1, response.file_path))
     }
 
     /// Get scan properties configuration.
@@ -538,80 +501,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn scan_props_get(&mut self) -> Result<ScanProps, NanonisError> {
-        let result = self.quick_send(
-            "Scan.PropsGet",
-            vec![],
-            vec![],
-            vec!["I", "I", "I", "i", "*-c", "i", "*-c", "i", "i", "*+c", "i", "*+i", "i", "i", "*+c", "I"],
-        )?;
-
-        if result.len() >= 16 {
-            // Index 0: Continuous scan (0=Off, 1=On)
-            let continuous_scan = result[0].as_u32()? == 1;
-
-            // Index 1: Bouncy scan (0=Off, 1=On)
-            let bouncy_scan = result[1].as_u32()? == 1;
-
-            // Index 2: Autosave (0=All, 1=Next, 2=Off)
-            let autosave = AutosaveMode::try_from(result[2].as_u32()?)?;
-
-            // Index 3: series_name size (i)
-            // Index 4: series_name string (*-c)
-            let series_name = result[4].as_string()?.to_string();
-
-            // Index 5: comment size (i)
-            // Index 6: comment string (*-c)
-            let comment = result[6].as_string()?.to_string();
-
-            // Index 7: modules_names size (i)
-            // Index 8: modules_names count (i)
-            // Index 9: modules_names array (*+c)
-            let modules_names = result[9].as_string_array()?.to_vec();
-
-            // Index 10: num_params_per_module array size (i)
-            // Index 11: num_params_per_module array (*+i)
-            let num_params_per_module = result[11].as_i32_array()?.to_vec();
-
-            // Index 12: parameters rows (i)
-            let rows = result[12].as_i32()?;
-            // Index 13: parameters columns (i)
-            let cols = result[13].as_i32()?;
-            // Index 14: parameters 2D array (*+c)
-            let params_flat = result[14].as_string_array()?;
-
-            // Convert flat array to 2D (rows x cols)
-            let mut parameters = Vec::new();
-            for row in 0..rows as usize {
-                let mut row_params = Vec::new();
-                for col in 0..cols as usize {
-                    let idx = row * (cols as usize) + col;
-                    if idx < params_flat.len() {
-                        row_params.push(params_flat[idx].clone());
-                    }
-                }
-                parameters.push(row_params);
-            }
-
-            // Index 15: Autopaste (0=All, 1=Next, 2=Off)
-            let autopaste = AutopasteMode::try_from(result[15].as_u32()?)?;
-
-            Ok(ScanProps {
-                continuous_scan,
-                bouncy_scan,
-                autosave,
-                series_name,
-                comment,
-                modules_names,
-                num_params_per_module,
-                parameters,
-                autopaste,
-            })
-        } else {
-            Err(NanonisError::Protocol(format!(
-                "Invalid scan props response: expected 16 values, got {}",
-                result.len()
-            )))
-        }
+        ScanProps::try_from(self.send(&commands::ScanPropsGet {})?)
     }
 
     /// Set scan properties configuration.
@@ -671,20 +561,15 @@ impl NanonisClient {
         let comment = builder.comment.unwrap_or_default();
         let modules_names = builder.modules_names.unwrap_or_default();
 
-        self.quick_send(
-            "Scan.PropsSet",
-            vec![
-                NanonisValue::U32(continuous_flag),
-                NanonisValue::U32(bouncy_flag),
-                NanonisValue::U32(autosave_flag),
-                NanonisValue::String(series_name),
-                NanonisValue::String(comment),
-                NanonisValue::ArrayString(modules_names),
-                NanonisValue::U32(autopaste_flag),
-            ],
-            vec!["I", "I", "I", "+*c", "+*c", "+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::ScanPropsSet {
+            continuous_flag,
+            bouncy_flag,
+            autosave_flag,
+            series_name,
+            comment,
+            modules_names,
+            autopaste_flag,
+        })?;
 
         Ok(())
     }
@@ -701,12 +586,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails or no background is available.
     pub fn scan_background_paste(&mut self, x: f64, y: f64) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Scan.BackgroundPaste",
-            vec![NanonisValue::F64(x), NanonisValue::F64(y)],
-            vec!["d", "d"],
-            vec![],
-        )?;
+        self.send(&commands::ScanBackgroundPaste { x, y })?;
         Ok(())
     }
 
@@ -718,7 +598,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn scan_background_delete(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Scan.BackgroundDelete", vec![], vec![], vec![])?;
+        self.send(&commands::ScanBackgroundDelete {})?;
         Ok(())
     }
 }
@@ -0,0 +1,388 @@
+use super::{ScanAction, ScanConfig, ScanDirection, ScanFrame};
+use crate::client::NanonisClient;
+use crate::error::NanonisError;
+use crate::files::sxm::{SxmChannel, SxmFile};
+use crate::files::{sci, split_unit};
+use crate::signals::SignalSlot;
+use crate::types::Array2D;
+use std::thread;
+use std::time::Duration;
+
+/// Bounds for the automatic poll interval derived from the line time.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One newly completed scan line of one channel and direction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanLine {
+    /// Scan buffer slot of the channel, as in
+    /// [`scan_buffer_get`](NanonisClient::scan_buffer_get)
+    pub channel: i32,
+    /// Channel name as reported by the instrument, e.g. `"Z (m)"`
+    pub name: String,
+    /// `true` for the forward, `false` for the backward image
+    pub forward: bool,
+    /// Row of the line in the frame data
+    pub line: usize,
+    /// Pixel values of the line
+    pub data: Vec<f32>,
+}
+
+/// Event emitted while a [`ScanSession`] is running.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanEvent {
+    /// A line of a channel has been completed
+    Line(ScanLine),
+    /// The scan has ended; no further events follow
+    Finished,
+}
+
+/// Image of one channel and direction being assembled.
+#[derive(Debug)]
+struct ImageBuffer {
+    channel: i32,
+    /// Signal index of the slot, written to `:DATA_INFO:`
+    signal: i32,
+    name: String,
+    forward: bool,
+    data: Array2D<f32>,
+    emitted: Vec<bool>,
+}
+
+/// A running scan that reports each completed line as it is acquired.
+///
+/// Created by [`NanonisClient::scan_session`], which starts the scan. The
+/// session polls the instrument at a fraction of the line time. Only the
+/// backward image of the first buffered channel is downloaded on each
+/// poll, to detect progress; all channels are fetched only once new lines
+/// are complete. Lines that are not scanned yet are `NaN` in the frame
+/// data, so a line counts as complete once it holds no `NaN`.
+///
+/// When the scan ends, [`finish`](Self::finish) returns the full image as
+/// an [`SxmFile`] with the scan frame and the line times from
+/// [`scan_speed_get`](NanonisClient::scan_speed_get). A session covers a
+/// single frame: with continuous scan on, the instrument starts the next
+/// frame instead of ending, so the session stops the scan once every line
+/// of the frame has been collected.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::scan::{ScanDirection, ScanEvent};
+/// use nanonis_rs::MockServer;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// # client.scan_buffer_set(vec![0, 2], 16, 8)?;
+/// # client.scan_config_set(nanonis_rs::scan::ScanConfig {
+/// #     forward_linear_speed_m_s: 1e-6,
+/// #     backward_linear_speed_m_s: 1e-6,
+/// #     forward_time_per_line_s: 0.005,
+/// #     backward_time_per_line_s: 0.005,
+/// #     keep_parameter_constant: 2,
+/// #     speed_ratio: 1.0,
+/// # })?;
+///
+/// let mut session = client.scan_session(ScanDirection::Up)?;
+/// let mut lines = 0;
+/// loop {
+///     match session.next_event()? {
+///         ScanEvent::Line(line) => {
+///             assert_eq!(line.data.len(), 16);
+///             lines += 1;
+///         }
+///         ScanEvent::Finished => break,
+///     }
+/// }
+/// // 8 lines of 2 channels in both directions
+/// assert_eq!(lines, 8 * 2 * 2);
+///
+/// let image = session.finish()?;
+/// assert_eq!((image.pixels, image.lines), (16, 8));
+/// assert!(image.header_value("SCAN_TIME").is_some());
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+///
+/// With continuous scan on, [`finish`](Self::finish) returns after one
+/// frame and leaves the scan stopped:
+/// ```
+/// use nanonis_rs::scan::{ScanDirection, ScanPropsBuilder};
+/// use nanonis_rs::MockServer;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// # client.scan_buffer_set(vec![0, 2], 16, 8)?;
+/// # client.scan_config_set(nanonis_rs::scan::ScanConfig {
+/// #     forward_linear_speed_m_s: 1e-6,
+/// #     backward_linear_speed_m_s: 1e-6,
+/// #     forward_time_per_line_s: 0.005,
+/// #     backward_time_per_line_s: 0.005,
+/// #     keep_parameter_constant: 2,
+/// #     speed_ratio: 1.0,
+/// # })?;
+/// client.scan_props_set(ScanPropsBuilder::new().continuous_scan(true))?;
+///
+/// let image = client.scan_session(ScanDirection::Up)?.finish()?;
+/// let z = image.channel("Z").unwrap().forward.as_ref().unwrap();
+/// assert!(z.as_slice().iter().all(|v| !v.is_nan()));
+/// assert!(!client.scan_status_get()?);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+pub struct ScanSession<'a> {
+    client: &'a mut NanonisClient,
+    frame: ScanFrame,
+    config: ScanConfig,
+    direction: ScanDirection,
+    pixels: usize,
+    lines: usize,
+    images: Vec<ImageBuffer>,
+    pending: Vec<ScanLine>,
+    poll_interval: Duration,
+    running: bool,
+}
+
+impl NanonisClient {
+    /// Start a scan and follow its progress line by line.
+    ///
+    /// Reads the scan frame, buffer and speed, then starts the scan with
+    /// [`scan_action`](Self::scan_action). See [`ScanSession`].
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if communication fails or the scan buffer
+    /// holds no channels.
+    pub fn scan_session(
+        &mut self,
+        direction: ScanDirection,
+    ) -> Result<ScanSession<'_>, NanonisError> {
+        let frame = self.scan_frame_get()?;
+        let config = self.scan_speed_get()?;
+        let (channels, pixels, lines) = self.scan_buffer_get()?;
+        if channels.is_empty() {
+            return Err(NanonisError::Protocol(
+                "Scan buffer holds no channels".to_string(),
+            ));
+        }
+        let (pixels, lines) = (pixels.max(0) as usize, lines.max(0) as usize);
+
+        let mut images = Vec::with_capacity(channels.len() * 2);
+        for channel in channels {
+            let signal = self.resolve_signal(SignalSlot(channel as u8))?;
+            for forward in [true, false] {
+                images.push(ImageBuffer {
+                    channel,
+                    signal,
+                    name: String::new(),
+                    forward,
+                    data: Array2D::from_vec(lines, pixels, vec![f32::NAN; lines * pixels])?,
+                    emitted: vec![false; lines],
+                });
+            }
+        }
+
+        let line_time = Duration::from_secs_f32(
+            (config.forward_time_per_line_s + config.backward_time_per_line_s).max(0.0),
+        );
+        self.scan_action(ScanAction::Start, direction)?;
+
+        Ok(ScanSession {
+            client: self,
+            frame,
+            config,
+            direction,
+            pixels,
+            lines,
+            images,
+            pending: Vec::new(),
+            poll_interval: (line_time / 2).clamp(MIN_POLL_INTERVAL, MAX_POLL_INTERVAL),
+            running: true,
+        })
+    }
+}
+
+impl ScanSession<'_> {
+    /// Poll at a fixed interval instead of half the line time.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Block until the next line is complete or the scan has ended.
+    ///
+    /// Returns [`ScanEvent::Finished`] once the scan has stopped and all
+    /// acquired lines have been reported, and on every call after that.
+    pub fn next_event(&mut self) -> Result<ScanEvent, NanonisError> {
+        loop {
+            if !self.pending.is_empty() {
+                return Ok(ScanEvent::Line(self.pending.remove(0)));
+            }
+            if !self.running {
+                return Ok(ScanEvent::Finished);
+            }
+            self.poll()?;
+            if self.pending.is_empty() && self.running {
+                thread::sleep(self.poll_interval);
+            }
+        }
+    }
+
+    /// Poll once without blocking and return the lines completed since the
+    /// last call. Useful for driving a GUI from its own timer.
+    pub fn poll_lines(&mut self) -> Result<Vec<ScanLine>, NanonisError> {
+        if self.running {
+            self.poll()?;
+        }
+        Ok(std::mem::take(&mut self.pending))
+    }
+
+    /// Whether the scan has ended and all lines have been reported.
+    pub fn is_finished(&self) -> bool {
+        !self.running && self.pending.is_empty()
+    }
+
+    /// Scan frame read when the session started.
+    pub fn frame(&self) -> ScanFrame {
+        self.frame
+    }
+
+    /// Scan speed read when the session started.
+    pub fn config(&self) -> ScanConfig {
+        self.config
+    }
+
+    /// Image assembled so far of the channel in scan buffer slot `channel`;
+    /// lines not yet scanned are `NaN`.
+    pub fn image(&self, channel: i32, forward: bool) -> Option<&Array2D<f32>> {
+        self.images
+            .iter()
+            .find(|image| image.channel == channel && image.forward == forward)
+            .map(|image| &image.data)
+    }
+
+    /// Stop the scan and return the image acquired so far.
+    pub fn stop(mut self) -> Result<SxmFile, NanonisError> {
+        if self.running {
+            self.client.scan_action(ScanAction::Stop, self.direction)?;
+            self.running = false;
+            self.fetch()?;
+        }
+        Ok(self.to_sxm())
+    }
+
+    /// Wait for the scan to end and return the complete image.
+    ///
+    /// Lines not yet taken with [`next_event`](Self::next_event) are
+    /// discarded.
+    pub fn finish(mut self) -> Result<SxmFile, NanonisError> {
+        while self.running {
+            self.poll()?;
+            if self.running {
+                thread::sleep(self.poll_interval);
+            }
+        }
+        Ok(self.to_sxm())
+    }
+
+    /// Check progress and collect newly completed lines.
+    fn poll(&mut self) -> Result<(), NanonisError> {
+        // Read the status first so no line completed before the scan ended
+        // is missed by the grab below
+        let running = self.client.scan_status_get()?;
+
+        // The backward image of the first channel is the last to complete
+        let probe = self.images[1].channel as u32;
+        let (_, data, _) = self.client.scan_frame_data_grab(probe, false)?;
+        let new_lines =
+            (0..self.lines).any(|line| !self.images[1].emitted[line] && line_complete(&data, line));
+
+        if new_lines || !running {
+            self.fetch()?;
+        }
+
+        // A continuous scan starts the next frame instead of ending
+        if running && self.frame_complete() {
+            self.client.scan_action(ScanAction::Stop, self.direction)?;
+            self.running = false;
+            return Ok(());
+        }
+        self.running = running;
+        Ok(())
+    }
+
+    /// Whether every line of every image has been collected.
+    fn frame_complete(&self) -> bool {
+        self.images
+            .iter()
+            .all(|image| image.emitted.iter().all(|&emitted| emitted))
+    }
+
+    /// Download all images and queue the lines that became complete.
+    fn fetch(&mut self) -> Result<(), NanonisError> {
+        for image in &mut self.images {
+            let (name, data, _) = self
+                .client
+                .scan_frame_data_grab(image.channel as u32, image.forward)?;
+            if data.shape() != image.data.shape() {
+                return Err(NanonisError::Protocol(format!(
+                    "Frame data of {name} is {}x{}, expected {}x{}",
+                    data.rows(),
+                    data.cols(),
+                    self.lines,
+                    self.pixels
+                )));
+            }
+            image.name = name;
+
+            for line in 0..self.lines {
+                if image.emitted[line] || !line_complete(&data, line) {
+                    continue;
+                }
+                let values = data.row(line).unwrap_or_default();
+                image
+                    .data
+                    .row_mut(line)
+                    .expect("shape checked above")
+                    .copy_from_slice(values);
+                image.emitted[line] = true;
+                self.pending.push(ScanLine {
+                    channel: image.channel,
+                    name: image.name.clone(),
+                    forward: image.forward,
+                    line,
+                    data: values.to_vec(),
+                });
+            }
+        }
+        // Report lines in acquisition order, forward before backward
+        self.pending
+            .sort_by_key(|line| (line.line, !line.forward, line.channel));
+        Ok(())
+    }
+
+    fn to_sxm(&self) -> SxmFile {
+        let mut file = SxmFile::new(self.frame, self.pixels, self.lines, self.direction);
+        file.set_header(
+            "SCAN_TIME",
+            format!(
+                "{:>22}{:>22}",
+                sci(self.config.forward_time_per_line_s as f64, 3),
+                sci(self.config.backward_time_per_line_s as f64, 3)
+            ),
+        );
+
+        for pair in self.images.chunks(2) {
+            let (forward, backward) = (&pair[0], &pair[1]);
+            let (name, unit) = split_unit(&forward.name);
+            file.channels.push(
+                SxmChannel::new(forward.signal, name, unit)
+                    .with_forward(forward.data.clone())
+                    .with_backward(backward.data.clone()),
+            );
+        }
+        file
+    }
+}
+
+/// Whether `line` of the frame data has been scanned.
+fn line_complete(data: &Array2D<f32>, line: usize) -> bool {
+    data.row(line)
+        .is_some_and(|row| !row.is_empty() && row.iter().all(|v| !v.is_nan()))
+}
@@ -1,9 +1,11 @@
 use crate::error::NanonisError;
 use crate::types::Position;
+use crate::units::Meters;
+use serde::{Deserialize, Serialize};
 
 // ==================== Scan Types ====================
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ScanFrame {
     pub center: Position,
     pub width_m: f32,
@@ -12,11 +14,16 @@ pub struct ScanFrame {
 }
 
 impl ScanFrame {
-    pub fn new(center: Position, width_m: f32, height_m: f32, angle_deg: f32) -> Self {
+    pub fn new(
+        center: Position,
+        width_m: impl Into<Meters>,
+        height_m: impl Into<Meters>,
+        angle_deg: f32,
+    ) -> Self {
         Self {
             center,
-            width_m,
-            height_m,
+            width_m: width_m.into().as_f32(),
+            height_m: height_m.into().as_f32(),
             angle_deg,
         }
     }
@@ -59,9 +66,11 @@ impl TryFrom<u16> for ScanAction {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ScanDirection {
     Down = 0,
+    #[default]
     Up = 1,
 }
 
@@ -86,7 +95,7 @@ impl TryFrom<u32> for ScanDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ScanConfig {
     pub forward_linear_speed_m_s: f32,
     pub backward_linear_speed_m_s: f32,
@@ -136,7 +145,8 @@ impl ScanProps {
 
 /// Builder for setting scan properties.
 /// Use `None` for fields that should not be changed.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct ScanPropsBuilder {
     /// Continuous scan: None = no change, Some(true) = On, Some(false) = Off
     pub continuous_scan: Option<bool>,
@@ -203,7 +213,7 @@ impl ScanPropsBuilder {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AutosaveMode {
     /// Save all future images automatically
     All = 0,
@@ -241,7 +251,7 @@ impl TryFrom<u32> for AutosaveMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AutopasteMode {
     /// Paste all future images automatically
     All = 0,
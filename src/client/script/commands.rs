@@ -0,0 +1,159 @@
+//! Typed descriptors for the `Script.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `Script.Open`: open the Script module.
+    pub struct ScriptOpen: "Script.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `Script.Load`: load a script in the Script module.
+    pub struct ScriptLoad: "Script.Load" {
+        /// Script slot (1 to total scripts, -1 for current)
+        pub script_index: i32 = "i",
+        /// Path to the script file
+        pub file_path: String = "+*c",
+        /// If true, loads from session file instead of file_path
+        pub load_session: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.Save`: save the current script to a file.
+    pub struct ScriptSave: "Script.Save" {
+        /// Script slot (1 to total scripts, -1 for current)
+        pub script_index: i32 = "i",
+        /// Path to save the script file
+        pub file_path: String = "+*c",
+        /// If true, saves to session file instead
+        pub save_session: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.Deploy`: deploy a script in the Script module.
+    pub struct ScriptDeploy: "Script.Deploy" {
+        /// Script slot (1 to total scripts, -1 for current)
+        pub script_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.Undeploy`: undeploy a script in the Script module.
+    pub struct ScriptUndeploy: "Script.Undeploy" {
+        /// Script slot (1 to total scripts, -1 for current)
+        pub script_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.Run`: run a script in the Script module.
+    pub struct ScriptRun: "Script.Run" blocking if wait {
+        /// Script slot (1 to total scripts, -1 for current)
+        pub script_index: i32 = "i",
+        /// If true, waits until script finishes
+        pub wait: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.Stop`: stop the running script.
+    pub struct ScriptStop: "Script.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `Script.ChsGet`: get the list of acquired channels in the Script module.
+    pub struct ScriptChsGet: "Script.ChsGet" {
+        /// Acquire buffer to read from
+        pub buffer: u16 = "H",
+    } -> ScriptChsGetResponse {
+        /// Number of channels
+        pub num_channels: i32 = "i",
+        /// Channel indexes (0-23)
+        pub channel_indexes: Vec<i32> = "*i",
+    }
+}
+
+nanonis_command! {
+    /// `Script.ChsSet`: set the list of acquired channels in the Script module.
+    pub struct ScriptChsSet: "Script.ChsSet" {
+        /// Acquire buffer to configure
+        pub buffer: u16 = "H",
+        /// Channel indexes (0-23)
+        pub channel_indexes: Vec<i32> = "+*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.DataGet`: get the data acquired in the Script module.
+    pub struct ScriptDataGet: "Script.DataGet" {
+        /// Acquire buffer to read from
+        pub buffer: u16 = "H",
+        /// Sweep number (starts at 0)
+        pub sweep_number: i32 = "i",
+    } -> ScriptDataGetResponse {
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// Data rows (one per channel)
+        pub data: Array2D<f32> = "2f",
+    }
+}
+
+nanonis_command! {
+    /// `Script.Autosave`: autosave script data to file.
+    pub struct ScriptAutosave: "Script.Autosave" {
+        /// Acquire buffer(s) to save
+        pub buffer: u16 = "H",
+        /// Sweep number (-1 for all sweeps)
+        pub sweep_number: i32 = "i",
+        /// If true, saves all sweeps to one file
+        pub all_sweeps_to_same_file: u32 = "I",
+        /// Folder path (empty for last used)
+        pub folder_path: String = "+*c",
+        /// File basename (empty for last used)
+        pub basename: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.LUTOpen`: open the LUT (Look Up Table) Editor from the Script module.
+    pub struct ScriptLutOpen: "Script.LUTOpen" {} -> ()
+}
+
+nanonis_command! {
+    /// `Script.LUTLoad`: load a LUT from file or from values.
+    pub struct ScriptLutLoad: "Script.LUTLoad" {
+        /// LUT index (1 to total LUTs)
+        pub lut_index: i32 = "i",
+        /// Path to .luts file (empty to use values)
+        pub file_path: String = "+*c",
+        /// LUT values (used if file_path is empty)
+        pub values: Vec<f32> = "+*f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.LUTSave`: save a LUT to file.
+    pub struct ScriptLutSave: "Script.LUTSave" {
+        /// LUT index (1 to total LUTs)
+        pub lut_index: i32 = "i",
+        /// Path to save .luts file
+        pub file_path: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Script.LUTDeploy`: deploy a LUT from the LUT Editor.
+    pub struct ScriptLutDeploy: "Script.LUTDeploy" {
+        /// LUT index (1 to total LUTs)
+        pub lut_index: i32 = "i",
+        /// If true, waits until deployment finishes
+        pub wait: u32 = "I",
+        /// Timeout in milliseconds (-1 for forever)
+        pub timeout_ms: i32 = "i",
+    } -> ()
+}
@@ -1,6 +1,8 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 
 /// Acquire buffer selection for Script module.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -24,7 +26,7 @@ impl From<AcquireBuffer> for u16 {
 #[derive(Debug, Clone, Default)]
 pub struct ScriptData {
     /// Data rows (one per channel)
-    pub data: Vec<Vec<f32>>,
+    pub data: Array2D<f32>,
 }
 
 impl NanonisClient {
@@ -35,7 +37,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Script.Open", vec![], vec![], vec![])?;
+        self.send(&commands::ScriptOpen {})?;
         Ok(())
     }
 
@@ -54,16 +56,11 @@ impl NanonisClient {
         file_path: &str,
         load_session: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.Load",
-            vec![
-                NanonisValue::I32(script_index),
-                NanonisValue::String(file_path.to_string()),
-                NanonisValue::U32(if load_session { 1 } else { 0 }),
-            ],
-            vec!["i", "+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptLoad {
+            script_index,
+            file_path: file_path.to_string(),
+            load_session: if load_session { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -82,16 +79,11 @@ impl NanonisClient {
         file_path: &str,
         save_session: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.Save",
-            vec![
-                NanonisValue::I32(script_index),
-                NanonisValue::String(file_path.to_string()),
-                NanonisValue::U32(if save_session { 1 } else { 0 }),
-            ],
-            vec!["i", "+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptSave {
+            script_index,
+            file_path: file_path.to_string(),
+            save_session: if save_session { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -103,12 +95,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_deploy(&mut self, script_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.Deploy",
-            vec![NanonisValue::I32(script_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptDeploy { script_index })?;
         Ok(())
     }
 
@@ -120,12 +107,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_undeploy(&mut self, script_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.Undeploy",
-            vec![NanonisValue::I32(script_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptUndeploy { script_index })?;
         Ok(())
     }
 
@@ -138,15 +120,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_run(&mut self, script_index: i32, wait: bool) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.Run",
-            vec![
-                NanonisValue::I32(script_index),
-                NanonisValue::U32(if wait { 1 } else { 0 }),
-            ],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptRun { script_index, wait: if wait { 1 } else { 0 } })?;
         Ok(())
     }
 
@@ -155,7 +129,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Script.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::ScriptStop {})?;
         Ok(())
     }
 
@@ -170,14 +144,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_chs_get(&mut self, buffer: AcquireBuffer) -> Result<Vec<i32>, NanonisError> {
-        let result = self.quick_send(
-            "Script.ChsGet",
-            vec![NanonisValue::U16(buffer.into())],
-            vec!["H"],
-            vec!["i", "*i"],
-        )?;
-
-        result[1].as_i32_array().map(|a| a.to_vec())
+        let response = self.send(&commands::ScriptChsGet { buffer: buffer.into() })?;
+        Ok(response.channel_indexes)
     }
 
     /// Set the list of acquired channels in the Script module.
@@ -193,15 +161,10 @@ impl NanonisClient {
         buffer: AcquireBuffer,
         channel_indexes: &[i32],
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.ChsSet",
-            vec![
-                NanonisValue::U16(buffer.into()),
-                NanonisValue::ArrayI32(channel_indexes.to_vec()),
-            ],
-            vec!["H", "+*i"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptChsSet {
+            buffer: buffer.into(),
+            channel_indexes: channel_indexes.to_vec(),
+        })?;
         Ok(())
     }
 
@@ -221,18 +184,10 @@ impl NanonisClient {
         buffer: AcquireBuffer,
         sweep_number: i32,
     ) -> Result<ScriptData, NanonisError> {
-        let result = self.quick_send(
-            "Script.DataGet",
-            vec![
-                NanonisValue::U16(buffer.into()),
-                NanonisValue::I32(sweep_number),
-            ],
-            vec!["H", "i"],
-            vec!["i", "i", "2f"],
-        )?;
+        let response = self.send(&commands::ScriptDataGet { buffer: buffer.into(), sweep_number })?;
 
         Ok(ScriptData {
-            data: result[2].as_f32_2d_array()?.to_vec(),
+            data: response.data,
         })
     }
 
@@ -255,18 +210,13 @@ impl NanonisClient {
         folder_path: &str,
         basename: &str,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.Autosave",
-            vec![
-                NanonisValue::U16(buffer.into()),
-                NanonisValue::I32(sweep_number),
-                NanonisValue::U32(if all_sweeps_to_same_file { 1 } else { 0 }),
-                NanonisValue::String(folder_path.to_string()),
-                NanonisValue::String(basename.to_string()),
-            ],
-            vec!["H", "i", "I", "+*c", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptAutosave {
+            buffer: buffer.into(),
+            sweep_number,
+            all_sweeps_to_same_file: if all_sweeps_to_same_file { 1 } else { 0 },
+            folder_path: folder_path.to_string(),
+            basename: basename.to_string(),
+        })?;
         Ok(())
     }
 
@@ -275,7 +225,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_lut_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Script.LUTOpen", vec![], vec![], vec![])?;
+        self.send(&commands::ScriptLutOpen {})?;
         Ok(())
     }
 
@@ -294,16 +244,11 @@ impl NanonisClient {
         file_path: &str,
         values: &[f32],
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.LUTLoad",
-            vec![
-                NanonisValue::I32(lut_index),
-                NanonisValue::String(file_path.to_string()),
-                NanonisValue::ArrayF32(values.to_vec()),
-            ],
-            vec!["i", "+*c", "+*f"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptLutLoad {
+            lut_index,
+            file_path: file_path.to_string(),
+            values: values.to_vec(),
+        })?;
         Ok(())
     }
 
@@ -316,15 +261,10 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn script_lut_save(&mut self, lut_index: i32, file_path: &str) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.LUTSave",
-            vec![
-                NanonisValue::I32(lut_index),
-                NanonisValue::String(file_path.to_string()),
-            ],
-            vec!["i", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptLutSave {
+            lut_index,
+            file_path: file_path.to_string(),
+        })?;
         Ok(())
     }
 
@@ -343,16 +283,11 @@ impl NanonisClient {
         wait: bool,
         timeout_ms: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Script.LUTDeploy",
-            vec![
-                NanonisValue::I32(lut_index),
-                NanonisValue::U32(if wait { 1 } else { 0 }),
-                NanonisValue::I32(timeout_ms),
-            ],
-            vec!["i", "I", "i"],
-            vec![],
-        )?;
+        self.send(&commands::ScriptLutDeploy {
+            lut_index,
+            wait: if wait { 1 } else { 0 },
+            timeout_ms,
+        })?;
         Ok(())
     }
 }
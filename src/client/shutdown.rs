@@ -0,0 +1,487 @@
+//! Safe-shutdown policies.
+//!
+//! A [`ShutdownPolicy`] is an ordered list of [`ShutdownAction`]s that
+//! bring the instrument into a safe state. It runs when a client built with
+//! [`shutdown_policy`](crate::NanonisClientBuilder::shutdown_policy) is
+//! dropped, when [`NanonisClient::shutdown`] is called, when a
+//! [`TipWatchdog`](crate::TipWatchdog) trips, and when the process is
+//! interrupted with [`NanonisClient::shutdown_on_signals`] or
+//! [`SharedNanonisClient::shutdown_on_signals`](crate::SharedNanonisClient::shutdown_on_signals).
+//! Interrupts are SIGINT and SIGTERM on Unix, and Ctrl-C, Ctrl-Break and
+//! closing the console window on Windows.
+
+use super::motor::{MotorDirection, MotorGroup};
+use super::scan::{ScanAction, ScanDirection};
+use super::NanonisClient;
+use crate::error::NanonisError;
+use crate::transport::TcpTransport;
+use crate::units::Volts;
+use log::{info, warn};
+use std::fmt;
+use std::net::SocketAddr;
+use std::thread;
+use std::time::Duration;
+
+/// One step of a [`ShutdownPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShutdownAction {
+    /// Withdraw the tip with `z_ctrl_withdraw`, waiting up to the timeout
+    Withdraw(Duration),
+    /// Move a coarse motor away from the sample with `motor_start_move`
+    Retract {
+        /// Direction pointing away from the sample
+        direction: MotorDirection,
+        /// Number of steps
+        steps: u16,
+        /// Motor group
+        group: MotorGroup,
+    },
+    /// Set the bias to a safe value
+    SetBias(Volts),
+    /// Stop a running scan
+    StopScan,
+    /// Stop bias and Z spectroscopy, the generic sweeper and the high-speed
+    /// sweeper
+    StopSweeps,
+}
+
+impl ShutdownAction {
+    /// Execute the action.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if a command fails. [`StopSweeps`](Self::StopSweeps)
+    /// tries every sweep and reports the failures together.
+    pub fn run(&self, client: &mut NanonisClient) -> Result<(), NanonisError> {
+        match *self {
+            ShutdownAction::Withdraw(timeout) => client.z_ctrl_withdraw(true, timeout),
+            ShutdownAction::Retract {
+                direction,
+                steps,
+                group,
+            } => client.motor_start_move(direction, steps, group, true),
+            ShutdownAction::SetBias(bias) => client.bias_set(bias),
+            ShutdownAction::StopScan => client.scan_action(ScanAction::Stop, ScanDirection::Up),
+            ShutdownAction::StopSweeps => {
+                let results = [
+                    ("bias spectroscopy", client.bias_spectr_stop()),
+                    ("Z spectroscopy", client.z_spectr_stop()),
+                    ("generic sweep", client.gen_swp_stop()),
+                    ("high-speed sweep", client.hs_swp_stop()),
+                ];
+                let failures: Vec<String> = results
+                    .into_iter()
+                    .filter_map(|(name, result)| result.err().map(|e| format!("{name}: {e}")))
+                    .collect();
+                if failures.is_empty() {
+                    Ok(())
+                } else {
+                    Err(NanonisError::Protocol(format!(
+                        "Stopping sweeps failed: {}",
+                        failures.join("; ")
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ShutdownAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShutdownAction::Withdraw(timeout) => write!(f, "withdraw (timeout {timeout:?})"),
+            ShutdownAction::Retract {
+                direction,
+                steps,
+                group,
+            } => write!(f, "retract {steps} steps {direction:?} on {group:?}"),
+            ShutdownAction::SetBias(bias) => write!(f, "set bias to {bias}"),
+            ShutdownAction::StopScan => f.write_str("stop scan"),
+            ShutdownAction::StopSweeps => f.write_str("stop sweeps"),
+        }
+    }
+}
+
+/// Outcome of one [`ShutdownAction`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShutdownOutcome {
+    /// Action run
+    pub action: ShutdownAction,
+    /// Error message if the action failed
+    pub error: Option<String>,
+}
+
+/// Ordered list of actions that bring the instrument into a safe state.
+///
+/// Every action runs even if an earlier one failed. The default policy
+/// withdraws the tip and retracts the Z motor of group 1 by 15 steps; build
+/// a different one for other motor setups, or leave out the motor move
+/// where it is dangerous, e.g. with a qPlus sensor.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::shutdown::{ShutdownAction, ShutdownPolicy};
+/// use nanonis_rs::{MockServer, Volts};
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let policy = ShutdownPolicy::new()
+///     .then(ShutdownAction::StopScan)
+///     .then(ShutdownAction::Withdraw(Duration::from_secs(1)))
+///     .then(ShutdownAction::SetBias(Volts(0.1)));
+///
+/// let mut client = server.client()?;
+/// client.set_shutdown_policy(Some(policy));
+///
+/// let outcomes = client.shutdown();
+/// assert!(outcomes.iter().all(|outcome| outcome.error.is_none()));
+/// assert!(!client.z_ctrl_on_off_get()?);
+/// assert_eq!(client.bias_get()?, 0.1);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShutdownPolicy {
+    /// Actions in execution order
+    pub actions: Vec<ShutdownAction>,
+}
+
+impl Default for ShutdownPolicy {
+    fn default() -> Self {
+        Self::new()
+            .then(ShutdownAction::Withdraw(Duration::from_secs(2)))
+            .then(ShutdownAction::Retract {
+                direction: MotorDirection::ZMinus,
+                steps: 15,
+                group: MotorGroup::Group1,
+            })
+    }
+}
+
+impl ShutdownPolicy {
+    /// Policy without actions.
+    pub fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Append an action.
+    pub fn then(mut self, action: ShutdownAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Run all actions in order and log their outcome.
+    pub fn run(&self, client: &mut NanonisClient) -> Vec<ShutdownOutcome> {
+        self.actions
+            .iter()
+            .map(|&action| {
+                let error = match action.run(client) {
+                    Ok(()) => {
+                        info!("Shutdown: {action}");
+                        None
+                    }
+                    Err(e) => {
+                        warn!("Shutdown: {action} failed: {e}");
+                        Some(e.to_string())
+                    }
+                };
+                ShutdownOutcome { action, error }
+            })
+            .collect()
+    }
+}
+
+impl NanonisClient {
+    /// Run the shutdown policy now.
+    ///
+    /// The policy is consumed, so it does not run again when the client is
+    /// dropped. Returns no outcomes if the client has no policy.
+    pub fn shutdown(&mut self) -> Vec<ShutdownOutcome> {
+        match self.shutdown_policy.take() {
+            Some(policy) => policy.run(self),
+            None => Vec::new(),
+        }
+    }
+
+    /// Replace the policy run on [`shutdown`](Self::shutdown) and on drop;
+    /// `None` disables it.
+    pub fn set_shutdown_policy(&mut self, policy: Option<ShutdownPolicy>) {
+        self.shutdown_policy = policy;
+    }
+
+    /// Policy run on [`shutdown`](Self::shutdown) and on drop, if any.
+    pub fn shutdown_policy(&self) -> Option<&ShutdownPolicy> {
+        self.shutdown_policy.as_ref()
+    }
+
+    /// Run `policy` when the process is interrupted, then exit.
+    ///
+    /// This client is borrowed by the code it runs, so the policy runs over
+    /// a second connection to `port` on the same host, opened now. The
+    /// Nanonis server accepts one client per port, so `port` must differ
+    /// from the port of this client. A second interrupt while the policy
+    /// runs terminates the process at once. Can be installed once per
+    /// process, for this or a [`SharedNanonisClient`](crate::SharedNanonisClient).
+    ///
+    /// Interrupts are SIGINT and SIGTERM on Unix, after which the process
+    /// exits with status 128 + signal number, and Ctrl-C, Ctrl-Break and
+    /// closing the console on Windows, after which it exits with
+    /// `STATUS_CONTROL_C_EXIT`.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the transport has no remote address, the
+    /// second connection cannot be opened, or the handlers cannot be
+    /// installed or are installed already.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use nanonis_rs::shutdown::ShutdownPolicy;
+    /// use nanonis_rs::NanonisClient;
+    ///
+    /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
+    /// client.shutdown_on_signals(ShutdownPolicy::default(), 6502)?;
+    ///
+    /// // Interrupting this withdraws and retracts the tip
+    /// client.scan_wait_end_of_scan(std::time::Duration::from_secs(3600))?;
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    #[cfg(any(unix, windows))]
+    pub fn shutdown_on_signals(
+        &self,
+        policy: ShutdownPolicy,
+        port: u16,
+    ) -> Result<(), NanonisError> {
+        let addr = self.stream.remote_addr().ok_or_else(|| {
+            NanonisError::Protocol(
+                "Transport has no remote address to open the shutdown connection on".to_string(),
+            )
+        })?;
+        let transport = TcpTransport::connect(SocketAddr::new(addr.ip(), port), &self.config)?;
+        let mut client = NanonisClient::from_transport(transport);
+        on_interrupt(move || {
+            policy.run(&mut client);
+        })
+    }
+}
+
+/// Interrupt received by [`signals::wait`].
+#[cfg(any(unix, windows))]
+pub(crate) struct Interrupt {
+    /// Description for the log, e.g. `"signal 2"`
+    pub(crate) name: String,
+    /// Status the process exits with
+    pub(crate) exit_code: i32,
+}
+
+/// Run `shutdown` on a dedicated thread when the process is interrupted,
+/// then exit.
+#[cfg(any(unix, windows))]
+pub(crate) fn on_interrupt(shutdown: impl FnOnce() + Send + 'static) -> Result<(), NanonisError> {
+    let mut receiver = signals::install()?;
+    thread::Builder::new()
+        .name("nanonis-shutdown-signals".to_string())
+        .spawn(move || {
+            let interrupt = match signals::wait(&mut receiver) {
+                Ok(interrupt) => interrupt,
+                Err(e) => {
+                    warn!("Signal shutdown stopped: {e}");
+                    return;
+                }
+            };
+            warn!("Received {}, shutting down", interrupt.name);
+            shutdown();
+            std::process::exit(interrupt.exit_code);
+        })
+        .map_err(|e| NanonisError::Io {
+            source: e,
+            context: "Spawning signal shutdown thread".to_string(),
+        })?;
+    Ok(())
+}
+
+/// Delivery of SIGINT and SIGTERM through a self-pipe.
+#[cfg(unix)]
+mod signals {
+    use super::Interrupt;
+    use crate::error::NanonisError;
+    use std::fs::File;
+    use std::io::{self, Read};
+    use std::os::fd::FromRawFd;
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    const SIGNALS: [libc::c_int; 2] = [libc::SIGINT, libc::SIGTERM];
+
+    /// Write end of the pipe, `-1` before installation.
+    static PIPE: AtomicI32 = AtomicI32::new(-1);
+
+    extern "C" fn on_signal(signal: libc::c_int) {
+        let byte = signal as u8;
+        // write(2) is async-signal-safe
+        unsafe {
+            libc::write(PIPE.load(Ordering::Relaxed), (&byte as *const u8).cast(), 1);
+        }
+    }
+
+    /// Route SIGINT and SIGTERM to the returned pipe. Only one installation
+    /// per process is allowed.
+    pub(crate) fn install() -> Result<File, NanonisError> {
+        let mut fds = [0; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(io_error("Creating signal pipe"));
+        }
+        if PIPE
+            .compare_exchange(-1, fds[1], Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            unsafe {
+                libc::close(fds[0]);
+                libc::close(fds[1]);
+            }
+            return Err(NanonisError::Protocol(
+                "Signal shutdown is already installed".to_string(),
+            ));
+        }
+        for signal in SIGNALS {
+            let handler: extern "C" fn(libc::c_int) = on_signal;
+            set_handler(signal, handler as libc::sighandler_t)?;
+        }
+        Ok(unsafe { File::from_raw_fd(fds[0]) })
+    }
+
+    /// Block until a signal arrives, then restore the default handlers so
+    /// that a second signal terminates at once.
+    pub(crate) fn wait(pipe: &mut File) -> Result<Interrupt, NanonisError> {
+        let mut byte = [0u8];
+        loop {
+            match pipe.read(&mut byte) {
+                Ok(1) => break,
+                Ok(_) => return Err(NanonisError::Protocol("Signal pipe closed".to_string())),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    return Err(NanonisError::Io {
+                        source: e,
+                        context: "Reading signal pipe".to_string(),
+                    })
+                }
+            }
+        }
+        for signal in SIGNALS {
+            set_handler(signal, libc::SIG_DFL)?;
+        }
+        let signal = byte[0] as i32;
+        Ok(Interrupt {
+            name: format!("signal {signal}"),
+            exit_code: 128 + signal,
+        })
+    }
+
+    fn set_handler(signal: libc::c_int, handler: libc::sighandler_t) -> Result<(), NanonisError> {
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = handler;
+            action.sa_flags = libc::SA_RESTART;
+            libc::sigemptyset(&mut action.sa_mask);
+            if libc::sigaction(signal, &action, std::ptr::null_mut()) != 0 {
+                return Err(io_error("Installing signal handler"));
+            }
+        }
+        Ok(())
+    }
+
+    fn io_error(context: &str) -> NanonisError {
+        NanonisError::Io {
+            source: io::Error::last_os_error(),
+            context: context.to_string(),
+        }
+    }
+}
+
+/// Delivery of console control events through a channel.
+#[cfg(windows)]
+mod signals {
+    use super::Interrupt;
+    use crate::error::NanonisError;
+    use std::io;
+    use std::sync::mpsc::{self, Receiver, SyncSender};
+    use std::sync::Mutex;
+    use std::thread;
+
+    const CTRL_C_EVENT: u32 = 0;
+    const CTRL_BREAK_EVENT: u32 = 1;
+    const CTRL_CLOSE_EVENT: u32 = 2;
+
+    /// `STATUS_CONTROL_C_EXIT`, the status of a process ended by Ctrl-C.
+    const EXIT_CODE: i32 = 0xC000_013A_u32 as i32;
+
+    type HandlerRoutine = unsafe extern "system" fn(u32) -> i32;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn SetConsoleCtrlHandler(handler: Option<HandlerRoutine>, add: i32) -> i32;
+    }
+
+    /// Sender of the installed handler, `None` before installation.
+    static EVENTS: Mutex<Option<SyncSender<u32>>> = Mutex::new(None);
+
+    unsafe extern "system" fn on_event(event: u32) -> i32 {
+        if !matches!(event, CTRL_C_EVENT | CTRL_BREAK_EVENT | CTRL_CLOSE_EVENT) {
+            return 0;
+        }
+        let sender = EVENTS.lock().ok().and_then(|events| events.clone());
+        if let Some(sender) = sender {
+            // A full channel means an event is already being handled
+            let _ = sender.try_send(event);
+        }
+        // Returning from a close event ends the process; block until the
+        // shutdown thread exits it instead
+        loop {
+            thread::park();
+        }
+    }
+
+    /// Route console control events to the returned channel. Only one
+    /// installation per process is allowed.
+    pub(crate) fn install() -> Result<Receiver<u32>, NanonisError> {
+        let mut events = EVENTS
+            .lock()
+            .map_err(|_| NanonisError::Protocol("Signal handler state poisoned".to_string()))?;
+        if events.is_some() {
+            return Err(NanonisError::Protocol(
+                "Signal shutdown is already installed".to_string(),
+            ));
+        }
+        let (sender, receiver) = mpsc::sync_channel(1);
+        if unsafe { SetConsoleCtrlHandler(Some(on_event), 1) } == 0 {
+            return Err(io_error("Installing console control handler"));
+        }
+        *events = Some(sender);
+        Ok(receiver)
+    }
+
+    /// Block until an event arrives, then remove the handler so that a
+    /// second Ctrl-C terminates at once.
+    pub(crate) fn wait(events: &mut Receiver<u32>) -> Result<Interrupt, NanonisError> {
+        let event = events
+            .recv()
+            .map_err(|_| NanonisError::Protocol("Signal channel closed".to_string()))?;
+        if unsafe { SetConsoleCtrlHandler(Some(on_event), 0) } == 0 {
+            return Err(io_error("Removing console control handler"));
+        }
+        let name = match event {
+            CTRL_C_EVENT => "Ctrl-C",
+            CTRL_BREAK_EVENT => "Ctrl-Break",
+            _ => "console close",
+        };
+        Ok(Interrupt {
+            name: name.to_string(),
+            exit_code: EXIT_CODE,
+        })
+    }
+
+    fn io_error(context: &str) -> NanonisError {
+        NanonisError::Io {
+            source: io::Error::last_os_error(),
+            context: context.to_string(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Typed descriptors for the `SignalChart.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `SignalChart.Open`: open the signal chart module.
+    pub struct SignalChartOpen: "SignalChart.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `SignalChart.ChsSet`: set the signal chart channels.
+    pub struct SignalChartChsSet: "SignalChart.ChsSet" {
+        /// Channel A signal index (-1 for none)
+        pub channel_a_index: i32 = "i",
+        /// Channel B signal index (-1 for none)
+        pub channel_b_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SignalChart.ChsGet`: get the signal chart channels.
+    pub struct SignalChartChsGet: "SignalChart.ChsGet" {} -> SignalChartChsGetResponse {
+        /// Channel A signal index (-1 for none)
+        pub channel_a_index: i32 = "i",
+        /// Channel B signal index (-1 for none)
+        pub channel_b_index: i32 = "i",
+    }
+}
@@ -1,6 +1,8 @@
+pub mod commands;
+
+use super::signals::SignalRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Signal chart channel configuration.
 #[derive(Debug, Clone, Copy, Default)]
@@ -28,7 +30,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn signal_chart_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("SignalChart.Open", vec![], vec![], vec![])?;
+        self.send(&commands::SignalChartOpen {})?;
         Ok(())
     }
 
@@ -38,37 +40,29 @@ impl NanonisClient {
     /// Use -1 to disable a channel.
     ///
     /// # Arguments
-    /// * `channels` - A [`SignalChartChannels`] struct with channel indices
+    /// * `channel_a` - Channel A signal by index or name (-1 for none)
+    /// * `channel_b` - Channel B signal by index or name (-1 for none)
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if a signal does not resolve or communication
+    /// fails.
     ///
     /// # Examples
     /// ```no_run
     /// use nanonis_rs::NanonisClient;
-    /// use nanonis_rs::signal_chart::SignalChartChannels;
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
-    /// let channels = SignalChartChannels {
-    ///     channel_a_index: 0,  // First signal
-    ///     channel_b_index: 1,  // Second signal
-    /// };
-    /// client.signal_chart_chs_set(&channels)?;
+    /// client.signal_chart_chs_set("Current", "Z")?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn signal_chart_chs_set(
         &mut self,
-        channels: &SignalChartChannels,
+        channel_a: impl Into<SignalRef>,
+        channel_b: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SignalChart.ChsSet",
-            vec![
-                NanonisValue::I32(channels.channel_a_index),
-                NanonisValue::I32(channels.channel_b_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let channel_a_index = self.resolve_signal(channel_a)?;
+        let channel_b_index = self.resolve_signal(channel_b)?;
+        self.send(&commands::SignalChartChsSet { channel_a_index, channel_b_index })?;
         Ok(())
     }
 
@@ -92,15 +86,11 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn signal_chart_chs_get(&mut self) -> Result<SignalChartChannels, NanonisError> {
-        let result = self.quick_send("SignalChart.ChsGet", vec![], vec![], vec!["i", "i"])?;
+        let response = self.send(&commands::SignalChartChsGet {})?;
 
-        if result.len() >= 2 {
-            Ok(SignalChartChannels {
-                channel_a_index: result[0].as_i32()?,
-                channel_b_index: result[1].as_i32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol("Invalid response".to_string()))
-        }
+        Ok(SignalChartChannels {
+            channel_a_index: response.channel_a_index,
+            channel_b_index: response.channel_b_index,
+        })
     }
 }
@@ -0,0 +1,124 @@
+//! Typed descriptors for the `Signals.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Signals.NamesGet`: get the names of all signals.
+    pub struct SignalsNamesGet: "Signals.NamesGet" {} -> SignalsNamesGetResponse {
+        /// Signal names, indexed by signal index
+        pub names: Vec<String> = "+*c",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.CalibrGet`: get the calibration and offset of a signal.
+    pub struct SignalsCalibrGet: "Signals.CalibrGet" {
+        /// Signal index
+        pub signal_index: i32 = "i",
+    } -> SignalsCalibrGetResponse {
+        /// Calibration per volt
+        pub calibration: f32 = "f",
+        /// Offset in physical units
+        pub offset: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.RangeGet`: get the range limits of a signal.
+    pub struct SignalsRangeGet: "Signals.RangeGet" {
+        /// Signal index
+        pub signal_index: i32 = "i",
+    } -> SignalsRangeGetResponse {
+        /// Upper limit in physical units
+        pub max_limit: f32 = "f",
+        /// Lower limit in physical units
+        pub min_limit: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.InSlotsGet`: get the names and indexes of the signals
+    /// assigned to the 24 slots.
+    pub struct SignalsInSlotsGet: "Signals.InSlotsGet" {} -> SignalsInSlotsGetResponse {
+        /// Size of the names array in bytes
+        pub names_size: i32 = "i",
+        /// Number of names
+        pub num_names: i32 = "i",
+        /// Names of the slotted signals, in slot order
+        pub names: Vec<String> = "*+c",
+        /// Number of signal indexes
+        pub indexes_size: i32 = "i",
+        /// Signal index in each slot, in slot order
+        pub indexes: Vec<i32> = "*i",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.ValsGet`: get the values of several signals.
+    pub struct SignalsValsGet: "Signals.ValsGet" {
+        /// Signal indexes
+        pub signal_indexes: Vec<i32> = "+*i",
+        /// 0 = next available value, 1 = wait for newest data
+        pub wait_for_newest_data: u32 = "I",
+    } -> SignalsValsGetResponse {
+        /// Number of values
+        pub values_size: i32 = "i",
+        /// Values in the order of the requested indexes
+        pub values: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.ValGet`: get the value of a single signal.
+    pub struct SignalsValGet: "Signals.ValGet" {
+        /// Signal index
+        pub signal_index: i32 = "i",
+        /// 0 = next available value, 1 = wait for newest data
+        pub wait_for_newest_data: u32 = "I",
+    } -> SignalsValGetResponse {
+        /// Signal value in physical units
+        pub value: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.MeasNamesGet`: get the list of measurement channels names available in the software.
+    pub struct SignalsMeasNamesGet: "Signals.MeasNamesGet" {} -> SignalsMeasNamesGetResponse {
+        /// Size of the measurement names array in bytes
+        pub meas_names_size: i32 = "i",
+        /// Number of measurement names
+        pub num_meas_names: i32 = "i",
+        /// Names of the measurement signals
+        pub meas_names: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.AddRTGet`: get the list of additional Real-Time (RT) signals and current assignments.
+    pub struct SignalsAddRtGet: "Signals.AddRTGet" {} -> SignalsAddRtGetResponse {
+        /// Size of the available signals array in bytes
+        pub available_signals_size: i32 = "i",
+        /// Number of available signals
+        pub num_available_signals: i32 = "i",
+        /// List of additional RT signals that can be assigned to Internal 23/24
+        pub available_signals: Vec<String> = "*+c",
+        /// Length of the Internal 23 signal name in bytes
+        pub internal_23_size: i32 = "i",
+        /// Name of RT signal currently assigned to Internal 23
+        pub internal_23: String = "*-c",
+        /// Length of the Internal 24 signal name in bytes
+        pub internal_24_size: i32 = "i",
+        /// Name of RT signal currently assigned to Internal 24
+        pub internal_24: String = "*-c",
+    }
+}
+
+nanonis_command! {
+    /// `Signals.AddRTSet`: assign additional Real-Time (RT) signals to Internal 23 and 24 signals.
+    pub struct SignalsAddRtSet: "Signals.AddRTSet" {
+        /// Index of the RT signal to assign to Internal 23 (from `signals_add_rt_get()`)
+        pub additional_rt_signal_1: i32 = "i",
+        /// Index of the RT signal to assign to Internal 24 (from `signals_add_rt_get()`)
+        pub additional_rt_signal_2: i32 = "i",
+    } -> ()
+}
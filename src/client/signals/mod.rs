@@ -1,95 +1,57 @@
+mod registry;
 mod types;
+pub use registry::*;
 pub use types::*;
 
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     /// Get available signal names
     pub fn signal_names_get(&mut self) -> Result<Vec<String>, NanonisError> {
-        let result = self.quick_send("Signals.NamesGet", vec![], vec![], vec!["+*c"])?;
-        match result.first() {
-            Some(value) => {
-                let signal_names = value.as_string_array()?.to_vec();
-
-                Ok(signal_names)
-            }
-            None => Err(NanonisError::Protocol(
-                "No signal names returned".to_string(),
-            )),
-        }
+        Ok(self.send(&commands::SignalsNamesGet {})?.names)
     }
 
-    /// Get calibration and offset of a signal by index
+    /// Get calibration and offset of a signal by index or name
     pub fn signals_calibr_get(
         &mut self,
-        signal_index: SignalIndex,
+        signal: impl Into<SignalRef>,
     ) -> Result<(f32, f32), NanonisError> {
-        let result = self.quick_send(
-            "Signals.CalibrGet",
-            vec![NanonisValue::I32(signal_index.into())],
-            vec!["i"],
-            vec!["f", "f"],
-        )?;
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid calibration response".to_string(),
-            ))
-        }
+        let signal_index = self.resolve_signal(signal)?;
+        let calibr = self.send(&commands::SignalsCalibrGet { signal_index })?;
+        Ok((calibr.calibration, calibr.offset))
     }
 
-    /// Get range limits of a signal by index
+    /// Get range limits of a signal by index or name
     pub fn signals_range_get(
         &mut self,
-        signal_index: SignalIndex,
+        signal: impl Into<SignalRef>,
     ) -> Result<(f32, f32), NanonisError> {
-        let result = self.quick_send(
-            "Signals.RangeGet",
-            vec![NanonisValue::I32(signal_index.into())],
-            vec!["i"],
-            vec!["f", "f"],
-        )?;
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?)) // (max, min)
-        } else {
-            Err(NanonisError::Protocol("Invalid range response".to_string()))
-        }
+        let signal_index = self.resolve_signal(signal)?;
+        let range = self.send(&commands::SignalsRangeGet { signal_index })?;
+        Ok((range.max_limit, range.min_limit))
     }
 
-    /// Get current values of signals by index(es)
-    pub fn signals_vals_get(
+    /// Get current values of signals by index(es) or name(s)
+    pub fn signals_vals_get<S: Into<SignalRef>>(
         &mut self,
-        signal_indexes: Vec<i32>,
+        signals: impl IntoIterator<Item = S>,
         wait_for_newest_data: bool,
     ) -> Result<Vec<f32>, NanonisError> {
-        let indexes = signal_indexes;
+        let signal_indexes = signals
+            .into_iter()
+            .map(|signal| self.resolve_signal(signal))
+            .collect::<Result<Vec<_>, _>>()?;
         let wait_flag = if wait_for_newest_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "Signals.ValsGet",
-            vec![
-                NanonisValue::ArrayI32(indexes),
-                NanonisValue::U32(wait_flag),
-            ],
-            vec!["+*i", "I"],
-            vec!["i", "*f"],
-        )?;
-
-        if result.len() >= 2 {
-            match &result[1] {
-                NanonisValue::ArrayF32(values) => Ok(values.clone()),
-                _ => Err(NanonisError::Protocol(
-                    "Invalid signal values response".to_string(),
-                )),
-            }
-        } else {
-            Err(NanonisError::Protocol(
-                "Incomplete signal values response".to_string(),
-            ))
-        }
+        Ok(self
+            .send(&commands::SignalsValsGet {
+                signal_indexes,
+                wait_for_newest_data: wait_flag,
+            })?
+            .values)
     }
 
     /// Get the current value of a single selected signal.
@@ -108,7 +70,7 @@ impl NanonisClient {
     /// to ensure you get only fresh data.
     ///
     /// # Arguments
-    /// * `signal_index` - Signal index (0-127)
+    /// * `signal` - Signal index (0-127) or name, see [`SignalRef`]
     /// * `wait_for_newest_data` - If `true`, discards first value and waits for fresh data.
     ///   Takes Tap to 2*Tap seconds. If `false`, returns next available value (0 to Tap seconds).
     ///
@@ -117,7 +79,7 @@ impl NanonisClient {
     ///
     /// # Errors
     /// Returns `NanonisError` if:
-    /// - Invalid signal index provided
+    /// - Invalid signal index or unknown signal name provided
     /// - Communication timeout or protocol error
     ///
     /// # Examples
@@ -130,33 +92,27 @@ impl NanonisClient {
     /// // Read bias signal immediately
     /// let bias_value = client.signal_val_get(SignalIndex(24), false)?;
     ///
+    /// // Or by name
+    /// let current = client.signal_val_get("Current (A)", false)?;
+    ///
     /// // Wait for fresh data after signal change
     /// let fresh_value = client.signal_val_get(SignalIndex(24), true)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn signal_val_get(
         &mut self,
-        signal_index: impl Into<SignalIndex>,
+        signal: impl Into<SignalRef>,
         wait_for_newest_data: bool,
     ) -> Result<f32, NanonisError> {
+        let signal_index = self.resolve_signal(signal)?;
         let wait_flag = if wait_for_newest_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "Signals.ValGet",
-            vec![
-                NanonisValue::I32(signal_index.into().into()),
-                NanonisValue::U32(wait_flag),
-            ],
-            vec!["i", "I"],
-            vec!["f"],
-        )?;
-
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No signal value returned".to_string(),
-            )),
-        }
+        Ok(self
+            .send(&commands::SignalsValGet {
+                signal_index,
+                wait_for_newest_data: wait_flag,
+            })?
+            .value)
     }
 
     /// Get the list of measurement channels names available in the software.
@@ -189,21 +145,10 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn signals_meas_names_get(&mut self) -> Result<Vec<String>, NanonisError> {
-        let result = self.quick_send(
-            "Signals.MeasNamesGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c"],
-        )?;
+        let response = self.send(&commands::SignalsMeasNamesGet {})?;
 
-        if result.len() >= 3 {
-            let meas_names = result[2].as_string_array()?.to_vec();
-            Ok(meas_names)
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid measurement names response".to_string(),
-            ))
-        }
+        let meas_names = response.meas_names;
+        Ok(meas_names)
     }
 
     /// Get the list of additional Real-Time (RT) signals and current assignments.
@@ -242,23 +187,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn signals_add_rt_get(&mut self) -> Result<(Vec<String>, String, String), NanonisError> {
-        let result = self.quick_send(
-            "Signals.AddRTGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c", "i", "*-c", "i", "*-c"],
-        )?;
+        let response = self.send(&commands::SignalsAddRtGet {})?;
 
-        if result.len() >= 7 {
-            let available_signals = result[2].as_string_array()?.to_vec();
-            let internal_23 = result[4].as_string()?.to_string();
-            let internal_24 = result[6].as_string()?.to_string();
-            Ok((available_signals, internal_23, internal_24))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid additional RT signals response".to_string(),
-            ))
-        }
+        Ok((response.available_signals, response.internal_23, response.internal_24))
     }
 
     /// Assign additional Real-Time (RT) signals to Internal 23 and 24 signals.
@@ -308,26 +239,21 @@ impl NanonisClient {
         additional_rt_signal_1: i32,
         additional_rt_signal_2: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Signals.AddRTSet",
-            vec![
-                NanonisValue::I32(additional_rt_signal_1),
-                NanonisValue::I32(additional_rt_signal_2),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::SignalsAddRtSet {
+            additional_rt_signal_1,
+            additional_rt_signal_2,
+        })?;
         Ok(())
     }
 
-    /// Get the input slot assignments for each signal.
+    /// Get the signals assigned to the 24 slots.
     ///
-    /// Returns the hardware input slot index for each signal in the system.
-    /// Input slots represent the physical or virtual input sources that feed
-    /// into each signal channel.
+    /// The slots are the signals available to the scan buffer, the TCP
+    /// Logger and the other recording modules.
     ///
     /// # Returns
-    /// Vector of input slot indices, one for each signal in the system.
+    /// Names and signal indexes of the slotted signals, in slot order: slot
+    /// `n` holds signal `indexes[n]`.
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails or protocol error occurs.
@@ -338,27 +264,14 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
-    /// let input_slots = client.signals_in_slots_get()?;
-    /// println!("Input slot assignments for {} signals:", input_slots.len());
-    /// for (signal_idx, slot_idx) in input_slots.iter().enumerate() {
-    ///     println!("  Signal {}: Input slot {}", signal_idx, slot_idx);
+    /// let (names, indexes) = client.signals_in_slots_get()?;
+    /// for (slot, (name, index)) in names.iter().zip(&indexes).enumerate() {
+    ///     println!("  Slot {slot}: signal {index} ({name})");
     /// }
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn signals_in_slots_get(&mut self) -> Result<Vec<i32>, NanonisError> {
-        let result = self.quick_send(
-            "Signals.InSlotsGet",
-            vec![],
-            vec![],
-            vec!["i", "*i"],
-        )?;
-
-        if result.len() >= 2 {
-            Ok(result[1].as_i32_array()?.to_vec())
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid input slots response".to_string(),
-            ))
-        }
+    pub fn signals_in_slots_get(&mut self) -> Result<(Vec<String>, Vec<i32>), NanonisError> {
+        let response = self.send(&commands::SignalsInSlotsGet {})?;
+        Ok((response.names, response.indexes))
     }
 }
@@ -0,0 +1,588 @@
+use super::{SignalIndex, SignalSlot};
+use crate::client::NanonisClient;
+use crate::error::NanonisError;
+use crate::files::split_unit;
+use serde::{Deserialize, Serialize};
+
+/// Number of suggestions offered when a signal name does not resolve.
+const SUGGESTIONS: usize = 3;
+
+/// A signal given by index, name or slot, accepted wherever a signal index
+/// is.
+///
+/// Names are resolved through the client's [`SignalRegistry`] and match
+/// either the full name, e.g. `"Current (A)"`, or the name without the unit,
+/// ignoring case. Slots are mapped to the signal assigned to them. APIs
+/// recording slots take a [`SlotRef`] instead.
+/// Serializes as a bare number, a string, or `{ "slot": n }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SignalRef {
+    /// Signal index as used on the wire
+    Index(i32),
+    /// Signal name, with or without the unit
+    Name(String),
+    /// Signal slot of the Signals Manager
+    Slot(#[serde(with = "slot_field")] SignalSlot),
+}
+
+impl From<SignalSlot> for SignalRef {
+    fn from(slot: SignalSlot) -> Self {
+        SignalRef::Slot(slot)
+    }
+}
+
+impl From<SignalIndex> for SignalRef {
+    fn from(index: SignalIndex) -> Self {
+        SignalRef::Index(index.0 as i32)
+    }
+}
+
+impl From<i32> for SignalRef {
+    fn from(index: i32) -> Self {
+        SignalRef::Index(index)
+    }
+}
+
+impl From<u8> for SignalRef {
+    fn from(index: u8) -> Self {
+        SignalRef::Index(index as i32)
+    }
+}
+
+impl From<usize> for SignalRef {
+    fn from(index: usize) -> Self {
+        SignalRef::Index(index as i32)
+    }
+}
+
+impl From<&str> for SignalRef {
+    fn from(name: &str) -> Self {
+        SignalRef::Name(name.to_string())
+    }
+}
+
+impl From<String> for SignalRef {
+    fn from(name: String) -> Self {
+        SignalRef::Name(name)
+    }
+}
+
+impl From<&String> for SignalRef {
+    fn from(name: &String) -> Self {
+        SignalRef::Name(name.clone())
+    }
+}
+
+/// A recorded channel given by slot, name or signal index, accepted by the
+/// APIs that record signal slots, such as the scan buffer, the oscilloscopes,
+/// the spectroscopy and data logger channels and the TCP Logger.
+///
+/// A bare integer is a slot (0-23), as returned by the matching getters, so
+/// their result can be passed back unchanged. Names and [`SignalIndex`]es
+/// are mapped through the client's [`SignalRegistry`] to the slot their
+/// signal is assigned to. Serializes as a bare number, a string, or
+/// `{ "index": n }`.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::signals::{SignalIndex, SlotRef};
+///
+/// assert_eq!(SlotRef::from(2), SlotRef::Slot(2));
+/// assert_eq!(SlotRef::from(SignalIndex(2)), SlotRef::Signal(SignalIndex(2)));
+/// assert_eq!(SlotRef::from("Z"), SlotRef::Name("Z".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SlotRef {
+    /// Slot of the Signals Manager, as recorded on the wire
+    Slot(i32),
+    /// Signal name, with or without the unit
+    Name(String),
+    /// Signal index, mapped to the slot its signal is assigned to
+    Signal(#[serde(with = "index_field")] SignalIndex),
+}
+
+impl From<i32> for SlotRef {
+    fn from(slot: i32) -> Self {
+        SlotRef::Slot(slot)
+    }
+}
+
+impl From<u8> for SlotRef {
+    fn from(slot: u8) -> Self {
+        SlotRef::Slot(slot as i32)
+    }
+}
+
+impl From<SignalSlot> for SlotRef {
+    fn from(slot: SignalSlot) -> Self {
+        SlotRef::Slot(slot.0 as i32)
+    }
+}
+
+impl From<SignalIndex> for SlotRef {
+    fn from(index: SignalIndex) -> Self {
+        SlotRef::Signal(index)
+    }
+}
+
+impl From<&str> for SlotRef {
+    fn from(name: &str) -> Self {
+        SlotRef::Name(name.to_string())
+    }
+}
+
+impl From<String> for SlotRef {
+    fn from(name: String) -> Self {
+        SlotRef::Name(name)
+    }
+}
+
+impl From<&String> for SlotRef {
+    fn from(name: &String) -> Self {
+        SlotRef::Name(name.clone())
+    }
+}
+
+impl TryFrom<SignalRef> for SlotRef {
+    type Error = NanonisError;
+
+    /// Keeps the meaning of the reference: an index names a signal.
+    ///
+    /// # Errors
+    /// Returns `NanonisError::Protocol` if the index is not a valid signal
+    /// index.
+    fn try_from(signal: SignalRef) -> Result<Self, Self::Error> {
+        match signal {
+            SignalRef::Index(index) => u8::try_from(index)
+                .map(|index| SlotRef::Signal(SignalIndex(index)))
+                .map_err(|_| NanonisError::Protocol(format!("Invalid signal index {index}"))),
+            SignalRef::Name(name) => Ok(SlotRef::Name(name)),
+            SignalRef::Slot(slot) => Ok(SlotRef::from(slot)),
+        }
+    }
+}
+
+/// Name, unit, slot, calibration and range of one signal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalInfo {
+    /// Signal index
+    pub index: SignalIndex,
+    /// Full name including the unit, e.g. `"Current (A)"`
+    pub name: String,
+    /// Unit parsed from the name, e.g. `"A"`; empty if the name has none
+    pub unit: String,
+    /// Slot the signal is assigned to according to `Signals.InSlotsGet`,
+    /// if any
+    pub slot: Option<i32>,
+    /// Calibration per volt
+    pub calibration: f32,
+    /// Offset in physical units
+    pub offset: f32,
+    /// Upper range limit in physical units
+    pub max: f32,
+    /// Lower range limit in physical units
+    pub min: f32,
+}
+
+impl SignalInfo {
+    /// Name without the unit, e.g. `"Current"`.
+    pub fn base_name(&self) -> &str {
+        split_unit(&self.name).0
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        let name = name.trim();
+        self.name.eq_ignore_ascii_case(name) || self.base_name().eq_ignore_ascii_case(name)
+    }
+}
+
+/// Lookup table of the instrument's signals by index and name.
+///
+/// Built from `Signals.NamesGet`, `Signals.InSlotsGet`, `Signals.CalibrGet`
+/// and `Signals.RangeGet`. The client keeps one in a cache so that APIs
+/// taking a [`SignalRef`] can resolve names; see
+/// [`NanonisClient::signal_registry`]. Call
+/// [`refresh`](Self::refresh) or [`NanonisClient::signal_registry_refresh`]
+/// after changing the signal configuration.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::MockServer;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+///
+/// let registry = client.signal_registry()?;
+/// let z = registry.resolve("z")?;
+/// assert_eq!(z.name, "Z (m)");
+/// assert_eq!(z.unit, "m");
+///
+/// // Typos are reported with suggestions, never guessed
+/// assert!(registry.resolve("Curent").is_err());
+/// assert_eq!(registry.fuzzy_find("curent")[0].name, "Current (A)");
+///
+/// // APIs selecting signals or slots take names too
+/// let bias = client.signal_val_get("Bias (V)", false)?;
+/// assert_eq!(bias, client.bias_get()?);
+/// client.scan_buffer_set(["Current", "Z"], 16, 16)?;
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignalRegistry {
+    signals: Vec<SignalInfo>,
+}
+
+impl SignalRegistry {
+    /// Read all signals from the instrument.
+    pub fn load(client: &mut NanonisClient) -> Result<Self, NanonisError> {
+        let names = client.signal_names_get()?;
+        let (_, slotted) = client.signals_in_slots_get()?;
+        let mut calibrations = Vec::with_capacity(names.len());
+        let mut ranges = Vec::with_capacity(names.len());
+        for index in 0..names.len() {
+            calibrations.push(client.signals_calibr_get(SignalIndex::from(index))?);
+            ranges.push(client.signals_range_get(SignalIndex::from(index))?);
+        }
+        Ok(Self::from_parts(names, &slotted, &calibrations, &ranges))
+    }
+
+    /// Reload all signals from the instrument.
+    pub fn refresh(&mut self, client: &mut NanonisClient) -> Result<(), NanonisError> {
+        *self = Self::load(client)?;
+        Ok(())
+    }
+
+    /// Assemble from the replies of the four `Signals.*` commands.
+    ///
+    /// `slotted` is the signal index in each slot, as returned by
+    /// `Signals.InSlotsGet`; it is inverted to the slot of each signal.
+    pub(crate) fn from_parts(
+        names: Vec<String>,
+        slotted: &[i32],
+        calibrations: &[(f32, f32)],
+        ranges: &[(f32, f32)],
+    ) -> Self {
+        let mut slots = vec![None; names.len()];
+        for (slot, &index) in slotted.iter().enumerate() {
+            if let Some(entry) = usize::try_from(index).ok().and_then(|i| slots.get_mut(i)) {
+                *entry = Some(slot as i32);
+            }
+        }
+
+        let signals = names
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let (calibration, offset) = calibrations.get(index).copied().unwrap_or((1.0, 0.0));
+                let (max, min) = ranges.get(index).copied().unwrap_or((f32::NAN, f32::NAN));
+                SignalInfo {
+                    index: SignalIndex::from(index),
+                    unit: split_unit(&name).1.to_string(),
+                    name,
+                    slot: slots[index],
+                    calibration,
+                    offset,
+                    max,
+                    min,
+                }
+            })
+            .collect();
+        Self { signals }
+    }
+
+    /// Number of signals.
+    pub fn len(&self) -> usize {
+        self.signals.len()
+    }
+
+    /// Whether the registry holds no signals.
+    pub fn is_empty(&self) -> bool {
+        self.signals.is_empty()
+    }
+
+    /// All signals in index order.
+    pub fn iter(&self) -> impl Iterator<Item = &SignalInfo> + '_ {
+        self.signals.iter()
+    }
+
+    /// Signal with the given index.
+    pub fn get(&self, index: impl Into<SignalIndex>) -> Option<&SignalInfo> {
+        self.signals.get(index.into().0 as usize)
+    }
+
+    /// Signal with the given full name or name without unit, ignoring case.
+    pub fn find(&self, name: &str) -> Option<&SignalInfo> {
+        // Prefer an exact full-name match over a case-insensitive one
+        self.signals
+            .iter()
+            .find(|signal| signal.name == name)
+            .or_else(|| self.signals.iter().find(|signal| signal.matches(name)))
+    }
+
+    /// Like [`find`](Self::find), with close names in the error if there is
+    /// no match.
+    pub fn resolve(&self, name: &str) -> Result<&SignalInfo, NanonisError> {
+        self.find(name).ok_or_else(|| {
+            let suggestions: Vec<&str> = self
+                .fuzzy_find(name)
+                .into_iter()
+                .take(SUGGESTIONS)
+                .map(|signal| signal.name.as_str())
+                .collect();
+            let hint = if suggestions.is_empty() {
+                String::new()
+            } else {
+                format!(", did you mean {}?", suggestions.join(", "))
+            };
+            NanonisError::Protocol(format!("Unknown signal \"{name}\"{hint}"))
+        })
+    }
+
+    /// Signals whose names resemble `query`, best match first.
+    ///
+    /// Matches on the name without unit, ignoring case, spaces and
+    /// punctuation. Names containing the query rank first, then names
+    /// within an edit distance of a third of the query length.
+    pub fn fuzzy_find(&self, query: &str) -> Vec<&SignalInfo> {
+        let query = normalize(split_unit(query).0);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let max_distance = query.len().div_ceil(3);
+
+        let mut scored: Vec<(usize, &SignalInfo)> = self
+            .signals
+            .iter()
+            .filter_map(|signal| {
+                let name = normalize(signal.base_name());
+                if name == query {
+                    Some((0, signal))
+                } else if name.contains(&query) {
+                    Some((1 + name.len() - query.len(), signal))
+                } else {
+                    let distance = edit_distance(&name, &query);
+                    (distance <= max_distance).then_some((100 + distance, signal))
+                }
+            })
+            .collect();
+        scored.sort_by_key(|(score, signal)| (*score, signal.index.0));
+        scored.into_iter().map(|(_, signal)| signal).collect()
+    }
+
+    /// Signal assigned to `slot`.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::signals::SignalSlot;
+    /// use nanonis_rs::{MockServer, SimulatedStm};
+    ///
+    /// let server = MockServer::start()?;
+    /// let sim = SimulatedStm::new();
+    /// sim.attach(&server);
+    /// // Z in slot 0, Current in slot 1, Bias in slot 2
+    /// sim.update(|state| state.slot_signals = vec![2, 0, 1]);
+    /// let mut client = server.client()?;
+    ///
+    /// let registry = client.signal_registry()?;
+    /// assert_eq!(registry.in_slot(SignalSlot(0)).unwrap().name, "Z (m)");
+    /// assert_eq!(registry.slot_of(&"Current".into())?, 1);
+    /// assert_eq!(registry.slot_of(&"Bias".into())?, 2);
+    /// assert!(registry.slot_of(&"X".into()).is_err());
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn in_slot(&self, slot: SignalSlot) -> Option<&SignalInfo> {
+        self.signals
+            .iter()
+            .find(|signal| signal.slot == Some(slot.0 as i32))
+    }
+
+    /// Index for a signal reference, resolving names and slots.
+    pub fn index_of(&self, signal: &SignalRef) -> Result<i32, NanonisError> {
+        match signal {
+            SignalRef::Index(index) => Ok(*index),
+            SignalRef::Name(name) => Ok(self.resolve(name)?.index.0 as i32),
+            SignalRef::Slot(slot) => self
+                .in_slot(*slot)
+                .map(|signal| signal.index.0 as i32)
+                .ok_or_else(|| {
+                    NanonisError::Protocol(format!("No signal is assigned to slot {}", slot.0))
+                }),
+        }
+    }
+
+    /// Slot for a channel reference, resolving names and signal indexes to
+    /// the slot their signal is assigned to.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::signals::{SignalIndex, SignalRegistry, SignalSlot, SlotRef};
+    ///
+    /// let registry: SignalRegistry = serde_json::from_value(serde_json::json!({ "signals": [
+    ///     { "index": 0, "name": "Current (A)", "unit": "A", "slot": 3,
+    ///       "calibration": 1.0, "offset": 0.0, "max": 1.0, "min": -1.0 },
+    ///     { "index": 1, "name": "Bias (V)", "unit": "V", "slot": null,
+    ///       "calibration": 1.0, "offset": 0.0, "max": 10.0, "min": -10.0 },
+    /// ]}))?;
+    ///
+    /// assert_eq!(registry.slot_of(&SignalIndex(0).into())?, 3);
+    /// assert_eq!(registry.slot_of(&"Current".into())?, 3);
+    /// assert_eq!(registry.slot_of(&SlotRef::from(5))?, 5);
+    /// assert_eq!(registry.index_of(&SignalSlot(3).into())?, 0);
+    /// // Not assigned to a slot
+    /// assert!(registry.slot_of(&SignalIndex(1).into()).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn slot_of(&self, channel: &SlotRef) -> Result<i32, NanonisError> {
+        let info = match channel {
+            SlotRef::Slot(slot) => return Ok(*slot),
+            SlotRef::Name(name) => self.resolve(name)?,
+            SlotRef::Signal(index) => self.get(*index).ok_or_else(|| {
+                NanonisError::Protocol(format!("Unknown signal index {}", index.0))
+            })?,
+        };
+        info.slot.ok_or_else(|| {
+            NanonisError::Protocol(format!(
+                "Signal \"{}\" is not assigned to a slot and cannot be recorded",
+                info.name
+            ))
+        })
+    }
+}
+
+impl NanonisClient {
+    /// Signal registry, read from the instrument on first use and cached.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if loading the registry fails.
+    pub fn signal_registry(&mut self) -> Result<&SignalRegistry, NanonisError> {
+        if self.signal_registry.is_none() {
+            let registry = SignalRegistry::load(self)?;
+            self.signal_registry = Some(registry);
+        }
+        Ok(self.signal_registry.as_ref().expect("loaded above"))
+    }
+
+    /// Reload the cached signal registry from the instrument.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if loading the registry fails.
+    pub fn signal_registry_refresh(&mut self) -> Result<&SignalRegistry, NanonisError> {
+        self.signal_registry = None;
+        self.signal_registry()
+    }
+
+    /// Index for a signal reference, resolving names and slots through the
+    /// cached registry. A failed lookup reloads the registry once before
+    /// failing, in case the signal configuration has changed.
+    pub(crate) fn resolve_signal(
+        &mut self,
+        signal: impl Into<SignalRef>,
+    ) -> Result<i32, NanonisError> {
+        match signal.into() {
+            SignalRef::Index(index) => Ok(index),
+            signal => self.with_registry(|registry| registry.index_of(&signal)),
+        }
+    }
+
+    /// Signal slot for a channel reference, as recorded by the scan buffer
+    /// and the TCP Logger. Names and signal indexes are resolved through the
+    /// cached registry and must be assigned to a slot; slots are taken as
+    /// they are.
+    pub(crate) fn resolve_slot(
+        &mut self,
+        channel: impl Into<SlotRef>,
+    ) -> Result<i32, NanonisError> {
+        match channel.into() {
+            SlotRef::Slot(slot) => Ok(slot),
+            channel => self.with_registry(|registry| registry.slot_of(&channel)),
+        }
+    }
+
+    /// Look up in the cached registry, reloading it once if the lookup
+    /// fails on a cached registry.
+    fn with_registry<T>(
+        &mut self,
+        lookup: impl Fn(&SignalRegistry) -> Result<T, NanonisError>,
+    ) -> Result<T, NanonisError> {
+        let cached = self.signal_registry.is_some();
+        match lookup(self.signal_registry()?) {
+            Err(_) if cached => lookup(self.signal_registry_refresh()?),
+            result => result,
+        }
+    }
+}
+
+/// Serde adapter storing a slot as `{ "slot": n }`, which keeps it apart
+/// from signal indexes in recipes.
+mod slot_field {
+    use super::SignalSlot;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Field {
+        slot: u8,
+    }
+
+    pub fn serialize<S: Serializer>(slot: &SignalSlot, serializer: S) -> Result<S::Ok, S::Error> {
+        Field { slot: slot.0 }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SignalSlot, D::Error> {
+        Field::deserialize(deserializer).map(|field| SignalSlot(field.slot))
+    }
+}
+
+/// Serde adapter storing a signal index as `{ "index": n }`, which keeps it
+/// apart from slots.
+mod index_field {
+    use super::SignalIndex;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Field {
+        index: u8,
+    }
+
+    pub fn serialize<S: Serializer>(
+        index: &SignalIndex,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Field { index: index.0 }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SignalIndex, D::Error> {
+        Field::deserialize(deserializer).map(|field| SignalIndex(field.index))
+    }
+}
+
+/// Lowercase alphanumerics only, for fuzzy comparison.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1)
+                .min(row[j] + 1)
+                .min(diagonal + usize::from(ca != cb));
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
@@ -1,4 +1,6 @@
+use crate::client::tcplog::TCPLogStatus;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 // ==================== Signal Types ====================
 
@@ -40,8 +42,29 @@ impl From<u8> for SignalIndex {
     }
 }
 
+/// Signal slot (0-23) of the Signals Manager, as recorded by the scan
+/// buffer and the TCP Logger
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SignalSlot(pub u8);
+
+impl From<SignalSlot> for i32 {
+    fn from(slot: SignalSlot) -> Self {
+        slot.0 as i32
+    }
+}
+
+/// One TCP Logger data frame.
 #[derive(Debug, Clone)]
 pub struct SignalFrame {
+    /// Frame counter, incremented by the logger for every frame
     pub counter: u64,
+    /// Value of every logged channel
     pub data: Vec<f32>,
+    /// Number of RT samples averaged into one frame
+    pub oversampling: f32,
+    /// Logger state when the frame was sent
+    pub state: TCPLogStatus,
+    /// Time since the logger started, `counter * oversampling / rt_frequency`;
+    /// `None` unless the stream knows the RT frequency
+    pub timestamp: Option<Duration>,
 }
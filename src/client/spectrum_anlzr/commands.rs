@@ -0,0 +1,227 @@
+//! Typed descriptors for the `SpectrumAnlzr.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `SpectrumAnlzr.ChSet`: set the channel to display in the spectrum analyzer.
+    pub struct SpectrumAnlzrChSet: "SpectrumAnlzr.ChSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// Signal slot index (0-23)
+        pub channel_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.ChGet`: get the channel displayed in the spectrum analyzer.
+    pub struct SpectrumAnlzrChGet: "SpectrumAnlzr.ChGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrChGetResponse {
+        /// Signal slot index (0-23)
+        pub channel_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.FreqRangeSet`: set the frequency range in the spectrum analyzer.
+    pub struct SpectrumAnlzrFreqRangeSet: "SpectrumAnlzr.FreqRangeSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// Index of the desired frequency range
+        pub range_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.FreqRangeGet`: get the frequency range configuration from the spectrum analyzer.
+    pub struct SpectrumAnlzrFreqRangeGet: "SpectrumAnlzr.FreqRangeGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrFreqRangeGetResponse {
+        /// Index of the selected frequency range
+        pub selected_index: i32 = "i",
+        /// Number of available frequency ranges
+        pub num_available_ranges_hz: i32 = "i",
+        /// Available frequency ranges in Hz
+        pub available_ranges_hz: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.FreqResSet`: set the frequency resolution in the spectrum analyzer.
+    pub struct SpectrumAnlzrFreqResSet: "SpectrumAnlzr.FreqResSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// Index of the desired frequency resolution
+        pub resolution_index: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.FreqResGet`: get the frequency resolution configuration from the spectrum analyzer.
+    pub struct SpectrumAnlzrFreqResGet: "SpectrumAnlzr.FreqResGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrFreqResGetResponse {
+        /// Index of the selected frequency resolution
+        pub selected_index: u16 = "H",
+        /// Number of available frequency resolutions
+        pub num_available_resolutions_hz: i32 = "i",
+        /// Available frequency resolutions in Hz
+        pub available_resolutions_hz: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.FFTWindowSet`: set the FFT window in the spectrum analyzer.
+    pub struct SpectrumAnlzrFftWindowSet: "SpectrumAnlzr.FFTWindowSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// FFT window type
+        pub window: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.FFTWindowGet`: get the FFT window from the spectrum analyzer.
+    pub struct SpectrumAnlzrFftWindowGet: "SpectrumAnlzr.FFTWindowGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrFftWindowGetResponse {
+        /// FFT window type
+        pub window: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.AveragSet`: set the averaging parameters in the spectrum analyzer.
+    pub struct SpectrumAnlzrAveragSet: "SpectrumAnlzr.AveragSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// Averaging mode
+        pub mode: u16 = "H",
+        /// Weighting mode
+        pub weighting: u16 = "H",
+        /// Number of averages
+        pub count: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.AveragGet`: get the averaging parameters from the spectrum analyzer.
+    pub struct SpectrumAnlzrAveragGet: "SpectrumAnlzr.AveragGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrAveragGetResponse {
+        /// Averaging mode
+        pub mode: u16 = "H",
+        /// Weighting mode
+        pub weighting: u16 = "H",
+        /// Number of averages
+        pub count: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.ACCouplingSet`: set the AC coupling mode in the spectrum analyzer.
+    pub struct SpectrumAnlzrAcCouplingSet: "SpectrumAnlzr.ACCouplingSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// True to enable AC coupling, false to disable
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.ACCouplingGet`: get the AC coupling mode from the spectrum analyzer.
+    pub struct SpectrumAnlzrAcCouplingGet: "SpectrumAnlzr.ACCouplingGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrAcCouplingGetResponse {
+        /// True to enable AC coupling, false to disable
+        pub enabled: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.CursorPosSet`: set the cursor positions in the spectrum analyzer.
+    pub struct SpectrumAnlzrCursorPosSet: "SpectrumAnlzr.CursorPosSet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// Type of cursor to display
+        pub cursor_type: u16 = "H",
+        /// X position of cursor 1 in Hz
+        pub cursor1_x_hz: f64 = "d",
+        /// X position of cursor 2 in Hz
+        pub cursor2_x_hz: f64 = "d",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.CursorPosGet`: get the cursor positions from the spectrum analyzer.
+    pub struct SpectrumAnlzrCursorPosGet: "SpectrumAnlzr.CursorPosGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+        /// Type of cursor to display (also sets the cursor type)
+        pub cursor_type: u16 = "H",
+    } -> SpectrumAnlzrCursorPosGetResponse {
+        /// X position of cursor 1 in Hz
+        pub cursor1_x_hz: f64 = "d",
+        /// X position of cursor 2 in Hz
+        pub cursor2_x_hz: f64 = "d",
+        /// Y position of cursor 1
+        pub cursor1_y: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.BandRMSGet`: get the RMS value in the frequency band from the spectrum analyzer.
+    pub struct SpectrumAnlzrBandRmsGet: "SpectrumAnlzr.BandRMSGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrBandRmsGetResponse {
+        /// RMS value in the frequency band
+        pub rms: f64 = "d",
+        /// Minimum frequency of the band in Hz
+        pub min_freq_hz: f64 = "d",
+        /// Maximum frequency of the band in Hz
+        pub max_freq_hz: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.DCGet`: get the DC value from the spectrum analyzer.
+    pub struct SpectrumAnlzrDcGet: "SpectrumAnlzr.DCGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrDcGetResponse {
+        /// DC component of the signal
+        pub dc_value: f64 = "d",
+    }
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.Run`: start the spectrum analyzer.
+    pub struct SpectrumAnlzrRun: "SpectrumAnlzr.Run" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `SpectrumAnlzr.DataGet`: get the data from the spectrum analyzer.
+    pub struct SpectrumAnlzrDataGet: "SpectrumAnlzr.DataGet" {
+        /// Spectrum analyzer instance (1 or 2)
+        pub instance: i32 = "i",
+    } -> SpectrumAnlzrDataGetResponse {
+        /// X coordinate of the first acquired point (Hz)
+        pub f0_hz: f32 = "f",
+        /// Frequency distance between two acquired points (Hz)
+        pub df_hz: f32 = "f",
+        /// Number of data points
+        pub num_data: i32 = "i",
+        /// Acquired spectrum data
+        pub data: Vec<f32> = "*f",
+    }
+}
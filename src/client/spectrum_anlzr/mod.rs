@@ -1,6 +1,8 @@
+pub mod commands;
+
+use super::signals::SlotRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// FFT window type for spectrum analyzer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -229,24 +231,22 @@ impl NanonisClient {
     ///
     /// # Arguments
     /// * `instance` - Spectrum analyzer instance (1 or 2)
-    /// * `channel_index` - Channel index (0-23) corresponding to signal slots
+    /// * `channel` - Slot (0-23) to analyze, or a signal by name or
+    ///   [`SignalIndex`](crate::signals::SignalIndex) assigned to a slot
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if the signal does not resolve or has no slot,
+    /// or communication fails.
     pub fn spectrum_anlzr_ch_set(
         &mut self,
         instance: SpectrumAnalyzerInstance,
-        channel_index: i32,
+        channel: impl Into<SlotRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.ChSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::I32(channel_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let channel_index = self.resolve_slot(channel)?;
+        self.send(&commands::SpectrumAnlzrChSet {
+            instance: instance.into(),
+            channel_index,
+        })?;
         Ok(())
     }
 
@@ -264,14 +264,9 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.ChGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["i"],
-        )?;
-
-        result[0].as_i32()
+        let response = self.send(&commands::SpectrumAnlzrChGet { instance: instance.into() })?;
+
+        Ok(response.channel_index)
     }
 
     /// Set the frequency range in the spectrum analyzer.
@@ -289,15 +284,10 @@ impl NanonisClient {
         instance: SpectrumAnalyzerInstance,
         range_index: i32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.FreqRangeSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::I32(range_index),
-            ],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrFreqRangeSet {
+            instance: instance.into(),
+            range_index,
+        })?;
         Ok(())
     }
 
@@ -315,16 +305,13 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<SpectrumFreqRange, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.FreqRangeGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["i", "i", "*f"],
-        )?;
+        let response = self.send(&commands::SpectrumAnlzrFreqRangeGet {
+            instance: instance.into(),
+        })?;
 
         Ok(SpectrumFreqRange {
-            selected_index: result[0].as_i32()?,
-            available_ranges_hz: result[2].as_f32_array()?.to_vec(),
+            selected_index: response.selected_index,
+            available_ranges_hz: response.available_ranges_hz,
         })
     }
 
@@ -343,15 +330,10 @@ impl NanonisClient {
         instance: SpectrumAnalyzerInstance,
         resolution_index: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.FreqResSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::U16(resolution_index),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrFreqResSet {
+            instance: instance.into(),
+            resolution_index,
+        })?;
         Ok(())
     }
 
@@ -369,16 +351,11 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<SpectrumFreqResolution, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.FreqResGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["H", "i", "*f"],
-        )?;
+        let response = self.send(&commands::SpectrumAnlzrFreqResGet { instance: instance.into() })?;
 
         Ok(SpectrumFreqResolution {
-            selected_index: result[0].as_u16()?,
-            available_resolutions_hz: result[2].as_f32_array()?.to_vec(),
+            selected_index: response.selected_index,
+            available_resolutions_hz: response.available_resolutions_hz,
         })
     }
 
@@ -395,15 +372,10 @@ impl NanonisClient {
         instance: SpectrumAnalyzerInstance,
         window: SpectrumFFTWindow,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.FFTWindowSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::U16(window.into()),
-            ],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrFftWindowSet {
+            instance: instance.into(),
+            window: window.into(),
+        })?;
         Ok(())
     }
 
@@ -421,14 +393,11 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<SpectrumFFTWindow, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.FFTWindowGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["H"],
-        )?;
-
-        SpectrumFFTWindow::try_from(result[0].as_u16()?)
+        let response = self.send(&commands::SpectrumAnlzrFftWindowGet {
+            instance: instance.into(),
+        })?;
+
+        SpectrumFFTWindow::try_from(response.window)
     }
 
     /// Set the averaging parameters in the spectrum analyzer.
@@ -444,17 +413,12 @@ impl NanonisClient {
         instance: SpectrumAnalyzerInstance,
         averaging: &SpectrumAveraging,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.AveragSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::U16(averaging.mode.into()),
-                NanonisValue::U16(averaging.weighting.into()),
-                NanonisValue::U32(averaging.count),
-            ],
-            vec!["i", "H", "H", "I"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrAveragSet {
+            instance: instance.into(),
+            mode: averaging.mode.into(),
+            weighting: averaging.weighting.into(),
+            count: averaging.count,
+        })?;
         Ok(())
     }
 
@@ -472,17 +436,12 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<SpectrumAveraging, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.AveragGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["H", "H", "I"],
-        )?;
+        let response = self.send(&commands::SpectrumAnlzrAveragGet { instance: instance.into() })?;
 
         Ok(SpectrumAveraging {
-            mode: SpectrumAveragingMode::try_from(result[0].as_u16()?)?,
-            weighting: SpectrumWeightingMode::try_from(result[1].as_u16()?)?,
-            count: result[2].as_u32()?,
+            mode: SpectrumAveragingMode::try_from(response.mode)?,
+            weighting: SpectrumWeightingMode::try_from(response.weighting)?,
+            count: response.count,
         })
     }
 
@@ -501,15 +460,10 @@ impl NanonisClient {
         instance: SpectrumAnalyzerInstance,
         enabled: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.ACCouplingSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::U32(if enabled { 1 } else { 0 }),
-            ],
-            vec!["i", "I"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrAcCouplingSet {
+            instance: instance.into(),
+            enabled: if enabled { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -527,14 +481,11 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<bool, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.ACCouplingGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["I"],
-        )?;
-
-        Ok(result[0].as_u32()? != 0)
+        let response = self.send(&commands::SpectrumAnlzrAcCouplingGet {
+            instance: instance.into(),
+        })?;
+
+        Ok(response.enabled != 0)
     }
 
     /// Set the cursor positions in the spectrum analyzer.
@@ -556,17 +507,12 @@ impl NanonisClient {
         cursor1_x_hz: f64,
         cursor2_x_hz: f64,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.CursorPosSet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::U16(cursor_type.into()),
-                NanonisValue::F64(cursor1_x_hz),
-                NanonisValue::F64(cursor2_x_hz),
-            ],
-            vec!["i", "H", "d", "d"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrCursorPosSet {
+            instance: instance.into(),
+            cursor_type: cursor_type.into(),
+            cursor1_x_hz,
+            cursor2_x_hz,
+        })?;
         Ok(())
     }
 
@@ -586,20 +532,15 @@ impl NanonisClient {
         instance: SpectrumAnalyzerInstance,
         cursor_type: SpectrumCursorType,
     ) -> Result<SpectrumCursorPos, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.CursorPosGet",
-            vec![
-                NanonisValue::I32(instance.into()),
-                NanonisValue::U16(cursor_type.into()),
-            ],
-            vec!["i", "H"],
-            vec!["d", "d", "d"],
-        )?;
+        let response = self.send(&commands::SpectrumAnlzrCursorPosGet {
+            instance: instance.into(),
+            cursor_type: cursor_type.into(),
+        })?;
 
         Ok(SpectrumCursorPos {
-            cursor1_x_hz: result[0].as_f64()?,
-            cursor2_x_hz: result[1].as_f64()?,
-            cursor1_y: result[2].as_f64()?,
+            cursor1_x_hz: response.cursor1_x_hz,
+            cursor2_x_hz: response.cursor2_x_hz,
+            cursor1_y: response.cursor1_y,
         })
     }
 
@@ -619,17 +560,12 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<SpectrumBandRMS, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.BandRMSGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["d", "d", "d"],
-        )?;
+        let response = self.send(&commands::SpectrumAnlzrBandRmsGet { instance: instance.into() })?;
 
         Ok(SpectrumBandRMS {
-            rms: result[0].as_f64()?,
-            min_freq_hz: result[1].as_f64()?,
-            max_freq_hz: result[2].as_f64()?,
+            rms: response.rms,
+            min_freq_hz: response.min_freq_hz,
+            max_freq_hz: response.max_freq_hz,
         })
     }
 
@@ -649,14 +585,9 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<f64, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.DCGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["d"],
-        )?;
-
-        result[0].as_f64()
+        let response = self.send(&commands::SpectrumAnlzrDcGet { instance: instance.into() })?;
+
+        Ok(response.dc_value)
     }
 
     /// Start the spectrum analyzer.
@@ -673,12 +604,7 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "SpectrumAnlzr.Run",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::SpectrumAnlzrRun { instance: instance.into() })?;
         Ok(())
     }
 
@@ -696,17 +622,12 @@ impl NanonisClient {
         &mut self,
         instance: SpectrumAnalyzerInstance,
     ) -> Result<SpectrumData, NanonisError> {
-        let result = self.quick_send(
-            "SpectrumAnlzr.DataGet",
-            vec![NanonisValue::I32(instance.into())],
-            vec!["i"],
-            vec!["f", "f", "i", "*f"],
-        )?;
+        let response = self.send(&commands::SpectrumAnlzrDataGet { instance: instance.into() })?;
 
         Ok(SpectrumData {
-            f0_hz: result[0].as_f32()?,
-            df_hz: result[1].as_f32()?,
-            data: result[3].as_f32_array()?.to_vec(),
+            f0_hz: response.f0_hz,
+            df_hz: response.df_hz,
+            data: response.data,
         })
     }
 }
@@ -0,0 +1,737 @@
+//! Snapshot, diff and restore of the instrument configuration.
+
+use super::kelvin_ctrl::{
+    KelvinACMode, KelvinBiasLimits, KelvinGain, KelvinModParams, KelvinSlope,
+};
+use super::lockin::{DemodulatorConfig, ModulatorConfig};
+use super::piezo::{DriftCompConfig, PiezoToggle, TiltCorrection};
+use super::pll::{PLLDemodInput, PLLExcRange, PLLInputProps, PLLOverwrite};
+use super::scan::{AutopasteMode, AutosaveMode, ScanConfig, ScanFrame, ScanPropsBuilder};
+use super::NanonisClient;
+use crate::error::NanonisError;
+use crate::units::{Meters, Seconds, Setpoint, Volts};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Highest modulator and demodulator number probed for lock-in and PLL.
+const MAX_CHANNELS: i32 = 8;
+
+/// Bias voltage.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BiasState {
+    pub bias_v: f32,
+}
+
+/// Z-controller setpoint, gains, limits and retraction settings.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ZCtrlState {
+    pub setpoint: f32,
+    pub p_gain: f32,
+    pub time_constant_s: f32,
+    pub i_gain: f32,
+    pub limits_enabled: bool,
+    pub high_limit_m: f32,
+    pub low_limit_m: f32,
+    pub switch_off_delay_s: f32,
+    /// Whether the home position is relative to the current position
+    pub home_relative: bool,
+    pub home_position_m: f32,
+    pub withdraw_rate_m_s: f32,
+    pub tip_lift_m: f32,
+}
+
+/// Scan frame, speed, buffer and properties.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanState {
+    pub frame: ScanFrame,
+    pub speed: ScanConfig,
+    pub channels: Vec<i32>,
+    pub pixels: i32,
+    pub lines: i32,
+    pub continuous_scan: bool,
+    pub bouncy_scan: bool,
+    pub autosave: AutosaveMode,
+    pub series_name: String,
+    pub comment: String,
+    pub modules_names: Vec<String>,
+    pub autopaste: AutopasteMode,
+}
+
+/// Lock-in modulators and demodulators.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockInState {
+    pub modulators: Vec<ModulatorConfig>,
+    pub demodulators: Vec<DemodulatorConfig>,
+}
+
+/// One PLL modulator with its amplitude and phase controllers.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PllModulatorState {
+    /// Modulator index, starting from 1
+    pub index: i32,
+    pub input_calibration_m_per_v: f32,
+    pub input_range_m: f32,
+    pub differential_input: bool,
+    pub divider_1_10: bool,
+    pub add_on: bool,
+    pub out_on: bool,
+    pub excitation_range: PLLExcRange,
+    pub excitation_v: f32,
+    pub amp_ctrl_on: bool,
+    pub amp_ctrl_setpoint_m: f32,
+    pub amp_ctrl_p_gain_v_per_m: f32,
+    pub amp_ctrl_time_constant_s: f32,
+    pub amp_ctrl_bandwidth_hz: f32,
+    pub phase_ctrl_on: bool,
+    pub phase_ctrl_p_gain_hz_per_deg: f32,
+    pub phase_ctrl_time_constant_s: f32,
+    pub phase_ctrl_bandwidth_hz: f32,
+    pub freq_range_hz: f32,
+    pub center_freq_hz: f64,
+    pub freq_shift_hz: f32,
+    /// Excitation overwrite signal index (-1 for none)
+    pub excitation_overwrite_signal: i32,
+    /// Frequency overwrite signal index (-1 for none)
+    pub frequency_overwrite_signal: i32,
+}
+
+/// One PLL demodulator.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PllDemodulatorState {
+    /// Demodulator index, starting from 1
+    pub index: u16,
+    pub input: u16,
+    pub freq_generator: u16,
+    pub harmonic: u16,
+    pub phase_ref_deg: f32,
+    pub filter_order: u16,
+}
+
+/// PLL modulators and demodulators.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PllState {
+    pub modulators: Vec<PllModulatorState>,
+    pub demodulators: Vec<PllDemodulatorState>,
+}
+
+/// Kelvin controller.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KelvinCtrlState {
+    pub enabled: bool,
+    pub setpoint: f32,
+    pub p_gain: f32,
+    pub time_constant_s: f32,
+    pub slope: KelvinSlope,
+    pub frequency_hz: f32,
+    pub amplitude: f32,
+    pub phase_deg: f32,
+    pub ac_mode: bool,
+    pub modulation: bool,
+    pub signal_index: i32,
+    pub bias_high_limit_v: f32,
+    pub bias_low_limit_v: f32,
+}
+
+/// Piezo tilt and drift compensation.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PiezoState {
+    pub tilt: TiltCorrection,
+    pub drift_comp_enabled: bool,
+    pub drift_vx_m_s: f32,
+    pub drift_vy_m_s: f32,
+    pub drift_vz_m_s: f32,
+    pub drift_saturation_limit: f32,
+}
+
+/// Safe tip protection.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SafeTipState {
+    pub enabled: bool,
+    pub auto_recovery: bool,
+    pub auto_pause_scan: bool,
+    pub threshold: f32,
+}
+
+/// One difference between two [`InstrumentState`]s.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateChange {
+    /// Dotted path of the setting, e.g. `"z_ctrl.setpoint"` or
+    /// `"lockin.modulators.0.amplitude"`
+    pub path: String,
+    /// Value in the first snapshot; `null` if absent there
+    pub before: Value,
+    /// Value in the second snapshot; `null` if absent there
+    pub after: Value,
+}
+
+/// Configuration of the instrument captured by [`NanonisClient::snapshot`].
+///
+/// Each module is read through the existing `*_get` wrappers. Modules the
+/// instrument rejects with a server error, e.g. a PLL on a system without
+/// the oscillation control option, are left `None` and listed in
+/// [`skipped`](Self::skipped); communication errors abort the snapshot.
+///
+/// Snapshots serialize with serde, so they can be stored next to the
+/// measurement data. [`diff`](Self::diff) lists the settings that differ
+/// between two snapshots and [`restore`](Self::restore) replays the `*_set`
+/// calls for every module that has changed since the snapshot was taken.
+///
+/// The tip position, Z-controller on/off state and running scans are not
+/// part of the snapshot.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockServer, Volts};
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+///
+/// let before = client.snapshot()?;
+/// // The simulator has no lock-in module
+/// assert!(before.lockin.is_none());
+/// assert!(before.skipped.contains_key("lockin"));
+///
+/// client.bias_set(Volts::new(1.5))?;
+/// let changes = before.diff(&client.snapshot()?);
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].path, "bias.bias_v");
+///
+/// before.restore(&mut client)?;
+/// assert_eq!(client.bias_get()?, before.bias.unwrap().bias_v);
+///
+/// let json = serde_json::to_string(&before).unwrap();
+/// let loaded: nanonis_rs::state::InstrumentState = serde_json::from_str(&json).unwrap();
+/// assert!(before.diff(&loaded).is_empty());
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InstrumentState {
+    pub bias: Option<BiasState>,
+    pub z_ctrl: Option<ZCtrlState>,
+    pub scan: Option<ScanState>,
+    pub lockin: Option<LockInState>,
+    pub pll: Option<PllState>,
+    pub kelvin_ctrl: Option<KelvinCtrlState>,
+    pub piezo: Option<PiezoState>,
+    pub safe_tip: Option<SafeTipState>,
+    /// Modules not captured, with the server error that was returned
+    pub skipped: BTreeMap<String, String>,
+}
+
+impl NanonisClient {
+    /// Capture the configuration of all supported modules.
+    ///
+    /// See [`InstrumentState`].
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if communication fails. Server errors only
+    /// skip the module that raised them.
+    pub fn snapshot(&mut self) -> Result<InstrumentState, NanonisError> {
+        let mut state = InstrumentState::default();
+        let skipped = &mut state.skipped;
+        state.bias = capture(skipped, "bias", snapshot_bias(self))?;
+        state.z_ctrl = capture(skipped, "z_ctrl", snapshot_z_ctrl(self))?;
+        state.scan = capture(skipped, "scan", snapshot_scan(self))?;
+        state.lockin = capture(skipped, "lockin", snapshot_lockin(self))?;
+        state.pll = capture(skipped, "pll", snapshot_pll(self))?;
+        state.kelvin_ctrl = capture(skipped, "kelvin_ctrl", snapshot_kelvin_ctrl(self))?;
+        state.piezo = capture(skipped, "piezo", snapshot_piezo(self))?;
+        state.safe_tip = capture(skipped, "safe_tip", snapshot_safe_tip(self))?;
+        Ok(state)
+    }
+}
+
+impl InstrumentState {
+    /// Settings that differ from `other`, in field order.
+    ///
+    /// Modules present in only one snapshot are reported as a single
+    /// change of the whole module. [`skipped`](Self::skipped) is ignored.
+    pub fn diff(&self, other: &InstrumentState) -> Vec<StateChange> {
+        let mut changes = Vec::new();
+        diff_values(
+            String::new(),
+            &self.modules_json(),
+            &other.modules_json(),
+            &mut changes,
+        );
+        changes
+    }
+
+    /// Bring the instrument back to this state.
+    ///
+    /// Takes a fresh snapshot and replays the `*_set` calls of each module
+    /// that differs from it. Modules that are `None` here are left alone.
+    /// Returns the changes that were undone.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the fresh snapshot or a setter fails.
+    /// Modules restored before the failure stay restored.
+    pub fn restore(&self, client: &mut NanonisClient) -> Result<Vec<StateChange>, NanonisError> {
+        let current = client.snapshot()?;
+        let changes = current.diff(self);
+        let changed = |module: &str| {
+            changes
+                .iter()
+                .any(|change| change.path.split('.').next() == Some(module))
+        };
+
+        // Piezo and scan geometry first, controllers after their parameters
+        if let Some(piezo) = self.piezo.filter(|_| changed("piezo")) {
+            restore_piezo(client, &piezo)?;
+        }
+        if let Some(scan) = self.scan.as_ref().filter(|_| changed("scan")) {
+            restore_scan(client, scan)?;
+        }
+        if let Some(lockin) = self.lockin.as_ref().filter(|_| changed("lockin")) {
+            restore_lockin(client, lockin)?;
+        }
+        if let Some(pll) = self.pll.as_ref().filter(|_| changed("pll")) {
+            restore_pll(client, pll)?;
+        }
+        if let Some(z_ctrl) = self.z_ctrl.filter(|_| changed("z_ctrl")) {
+            restore_z_ctrl(client, &z_ctrl)?;
+        }
+        if let Some(safe_tip) = self.safe_tip.filter(|_| changed("safe_tip")) {
+            restore_safe_tip(client, &safe_tip)?;
+        }
+        if let Some(bias) = self.bias.filter(|_| changed("bias")) {
+            client.bias_set(Volts::new(bias.bias_v.into()))?;
+        }
+        if let Some(kelvin) = self.kelvin_ctrl.filter(|_| changed("kelvin_ctrl")) {
+            restore_kelvin_ctrl(client, &kelvin)?;
+        }
+        Ok(changes)
+    }
+
+    /// All modules as JSON, without the skipped list.
+    fn modules_json(&self) -> Value {
+        let mut value = serde_json::to_value(self).unwrap_or(Value::Null);
+        if let Value::Object(map) = &mut value {
+            map.remove("skipped");
+        }
+        value
+    }
+}
+
+/// Keep `Ok` values, record server errors as a skipped module and pass
+/// other errors on.
+fn capture<T>(
+    skipped: &mut BTreeMap<String, String>,
+    module: &str,
+    result: Result<T, NanonisError>,
+) -> Result<Option<T>, NanonisError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.is_server_error() => {
+            skipped.insert(module.to_string(), e.to_string());
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Read numbered channels from 1 until the instrument rejects one.
+fn capture_channels<T>(
+    client: &mut NanonisClient,
+    mut read: impl FnMut(&mut NanonisClient, i32) -> Result<T, NanonisError>,
+) -> Result<Vec<T>, NanonisError> {
+    let mut channels = Vec::new();
+    for number in 1..=MAX_CHANNELS {
+        match read(client, number) {
+            Ok(channel) => channels.push(channel),
+            // Channel 1 missing means the whole module is missing
+            Err(e) if e.is_server_error() && number > 1 => break,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(channels)
+}
+
+fn diff_values(path: String, before: &Value, after: &Value, changes: &mut Vec<StateChange>) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+    match (before, after) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, value) in a {
+                diff_values(
+                    child(key),
+                    value,
+                    b.get(key).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+            for (key, value) in b.iter().filter(|(key, _)| !a.contains_key(*key)) {
+                diff_values(child(key), &Value::Null, value, changes);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+            for (index, (a, b)) in a.iter().zip(b).enumerate() {
+                diff_values(child(&index.to_string()), a, b, changes);
+            }
+        }
+        (a, b) if a != b => changes.push(StateChange {
+            path,
+            before: a.clone(),
+            after: b.clone(),
+        }),
+        _ => {}
+    }
+}
+
+// ==================== Capture ====================
+
+fn snapshot_bias(client: &mut NanonisClient) -> Result<BiasState, NanonisError> {
+    Ok(BiasState {
+        bias_v: client.bias_get()?,
+    })
+}
+
+fn snapshot_z_ctrl(client: &mut NanonisClient) -> Result<ZCtrlState, NanonisError> {
+    let (p_gain, time_constant_s, i_gain) = client.z_ctrl_gain_get()?;
+    let (high_limit_m, low_limit_m) = client.z_ctrl_limits_get()?;
+    let (home_relative, home_position_m) = client.z_ctrl_home_props_get()?;
+    Ok(ZCtrlState {
+        setpoint: client.z_ctrl_setpoint_get()?,
+        p_gain,
+        time_constant_s,
+        i_gain,
+        limits_enabled: client.z_ctrl_limits_enabled_get()?,
+        high_limit_m,
+        low_limit_m,
+        switch_off_delay_s: client.z_ctrl_switch_off_delay_get()?,
+        home_relative,
+        home_position_m,
+        withdraw_rate_m_s: client.z_ctrl_withdraw_rate_get()?,
+        tip_lift_m: client.z_ctrl_tip_lift_get()?,
+    })
+}
+
+fn snapshot_scan(client: &mut NanonisClient) -> Result<ScanState, NanonisError> {
+    let (channels, pixels, lines) = client.scan_buffer_get()?;
+    let props = client.scan_props_get()?;
+    Ok(ScanState {
+        frame: client.scan_frame_get()?,
+        speed: client.scan_speed_get()?,
+        channels,
+        pixels,
+        lines,
+        continuous_scan: props.continuous_scan,
+        bouncy_scan: props.bouncy_scan,
+        autosave: props.autosave,
+        series_name: props.series_name,
+        comment: props.comment,
+        modules_names: props.modules_names,
+        autopaste: props.autopaste,
+    })
+}
+
+fn snapshot_lockin(client: &mut NanonisClient) -> Result<LockInState, NanonisError> {
+    let modulators = capture_channels(client, |client, number| {
+        Ok(ModulatorConfig {
+            number,
+            enabled: client.lockin_mod_on_off_get(number)?,
+            signal_index: client.lockin_mod_signal_get(number)?,
+            phase_register: client.lockin_mod_phas_reg_get(number)?,
+            harmonic: client.lockin_mod_harmonic_get(number)?,
+            phase_deg: client.lockin_mod_phas_get(number)?,
+            amplitude: client.lockin_mod_amp_get(number)?,
+            frequency_hz: client.lockin_mod_phas_freq_get(number)?,
+        })
+    })?;
+    let demodulators = capture_channels(client, |client, number| {
+        let hp_filter = client.lockin_demod_hp_filter_get(number)?;
+        let lp_filter = client.lockin_demod_lp_filter_get(number)?;
+        Ok(DemodulatorConfig {
+            number,
+            signal_index: client.lockin_demod_signal_get(number)?,
+            harmonic: client.lockin_demod_harmonic_get(number)?,
+            hp_filter_order: hp_filter.order,
+            hp_filter_cutoff_hz: hp_filter.cutoff_hz,
+            lp_filter_order: lp_filter.order,
+            lp_filter_cutoff_hz: lp_filter.cutoff_hz,
+            phase_register: client.lockin_demod_phas_reg_get(number)?,
+            phase_deg: client.lockin_demod_phas_get(number)?,
+            sync_filter: client.lockin_demod_sync_filter_get(number)?,
+            rt_signal_mode: client.lockin_demod_rt_signals_get(number)?,
+        })
+    })?;
+    Ok(LockInState {
+        modulators,
+        demodulators,
+    })
+}
+
+fn snapshot_pll(client: &mut NanonisClient) -> Result<PllState, NanonisError> {
+    let modulators = capture_channels(client, |client, index| {
+        let props = client.pll_inp_props_get(index)?;
+        let (amp_ctrl_p_gain_v_per_m, amp_ctrl_time_constant_s) =
+            client.pll_amp_ctrl_gain_get(index)?;
+        let phase_gain = client.pll_phas_ctrl_gain_get(index)?;
+        let overwrite = client.pll_freq_exc_overwrite_get(index)?;
+        Ok(PllModulatorState {
+            index,
+            input_calibration_m_per_v: client.pll_inp_calibr_get(index)?,
+            input_range_m: client.pll_inp_range_get(index)?,
+            differential_input: props.differential_input,
+            divider_1_10: props.divider_1_10,
+            add_on: client.pll_add_on_off_get(index)?,
+            out_on: client.pll_out_on_off_get(index)?,
+            excitation_range: client.pll_exc_range_get(index)?,
+            excitation_v: client.pll_excitation_get(index)?,
+            amp_ctrl_on: client.pll_amp_ctrl_on_off_get(index)?,
+            amp_ctrl_setpoint_m: client.pll_amp_ctrl_setpnt_get(index)?,
+            amp_ctrl_p_gain_v_per_m,
+            amp_ctrl_time_constant_s,
+            amp_ctrl_bandwidth_hz: client.pll_amp_ctrl_bandwidth_get(index)?,
+            phase_ctrl_on: client.pll_phas_ctrl_on_off_get(index)?,
+            phase_ctrl_p_gain_hz_per_deg: phase_gain.p_gain_hz_per_deg,
+            phase_ctrl_time_constant_s: phase_gain.time_constant_s,
+            phase_ctrl_bandwidth_hz: client.pll_phas_ctrl_bandwidth_get(index)?,
+            freq_range_hz: client.pll_freq_range_get(index)?,
+            center_freq_hz: client.pll_center_freq_get(index)?,
+            freq_shift_hz: client.pll_freq_shift_get(index)?,
+            excitation_overwrite_signal: overwrite.excitation_signal_index,
+            frequency_overwrite_signal: overwrite.frequency_signal_index,
+        })
+    })?;
+    let demodulators = capture_channels(client, |client, index| {
+        let index = index as u16;
+        let input = client.pll_demod_input_get(index)?;
+        Ok(PllDemodulatorState {
+            index,
+            input: input.input,
+            freq_generator: input.freq_generator,
+            harmonic: client.pll_demod_harmonic_get(index)?,
+            phase_ref_deg: client.pll_demod_phas_ref_get(index)?,
+            filter_order: client.pll_demod_filter_get(index)?,
+        })
+    })?;
+    Ok(PllState {
+        modulators,
+        demodulators,
+    })
+}
+
+fn snapshot_kelvin_ctrl(client: &mut NanonisClient) -> Result<KelvinCtrlState, NanonisError> {
+    let gain = client.kelvin_ctrl_gain_get()?;
+    let modulation = client.kelvin_ctrl_mod_params_get()?;
+    let status = client.kelvin_ctrl_mod_on_off_get()?;
+    let limits = client.kelvin_ctrl_bias_limits_get()?;
+    Ok(KelvinCtrlState {
+        enabled: client.kelvin_ctrl_on_off_get()?,
+        setpoint: client.kelvin_ctrl_setpnt_get()?,
+        p_gain: gain.p_gain,
+        time_constant_s: gain.time_constant_s,
+        slope: gain.slope,
+        frequency_hz: modulation.frequency_hz,
+        amplitude: modulation.amplitude,
+        phase_deg: modulation.phase_deg,
+        ac_mode: status.ac_mode,
+        modulation: status.modulation,
+        signal_index: client.kelvin_ctrl_signal_get()?,
+        bias_high_limit_v: limits.high_limit_v,
+        bias_low_limit_v: limits.low_limit_v,
+    })
+}
+
+fn snapshot_piezo(client: &mut NanonisClient) -> Result<PiezoState, NanonisError> {
+    let drift = client.piezo_drift_comp_get()?;
+    Ok(PiezoState {
+        tilt: client.piezo_tilt_get()?,
+        drift_comp_enabled: drift.enabled,
+        drift_vx_m_s: drift.vx_m_s,
+        drift_vy_m_s: drift.vy_m_s,
+        drift_vz_m_s: drift.vz_m_s,
+        drift_saturation_limit: drift.saturation_limit,
+    })
+}
+
+fn snapshot_safe_tip(client: &mut NanonisClient) -> Result<SafeTipState, NanonisError> {
+    let (auto_recovery, auto_pause_scan, threshold) = client.safe_tip_props_get()?;
+    Ok(SafeTipState {
+        enabled: client.safe_tip_on_off_get()?,
+        auto_recovery,
+        auto_pause_scan,
+        threshold,
+    })
+}
+
+// ==================== Restore ====================
+
+fn restore_z_ctrl(client: &mut NanonisClient, state: &ZCtrlState) -> Result<(), NanonisError> {
+    let meters = |value: f32| Meters::new(value.into());
+    let seconds = |value: f32| Seconds::new(value.into());
+    client.z_ctrl_setpoint_set(Setpoint::new(state.setpoint.into()))?;
+    client.z_ctrl_gain_set(state.p_gain, seconds(state.time_constant_s), state.i_gain)?;
+    client.z_ctrl_limits_set(meters(state.high_limit_m), meters(state.low_limit_m))?;
+    client.z_ctrl_limits_enabled_set(state.limits_enabled)?;
+    client.z_ctrl_switch_off_delay_set(seconds(state.switch_off_delay_s))?;
+    let home_mode = if state.home_relative { 2 } else { 1 };
+    client.z_ctrl_home_props_set(home_mode, meters(state.home_position_m))?;
+    client.z_ctrl_withdraw_rate_set(state.withdraw_rate_m_s)?;
+    client.z_ctrl_tip_lift_set(state.tip_lift_m)
+}
+
+fn restore_scan(client: &mut NanonisClient, state: &ScanState) -> Result<(), NanonisError> {
+    client.scan_frame_set(state.frame)?;
+    client.scan_config_set(state.speed)?;
+    client.scan_buffer_set(state.channels.iter().copied(), state.pixels, state.lines)?;
+    let mut props = ScanPropsBuilder::new()
+        .continuous_scan(state.continuous_scan)
+        .bouncy_scan(state.bouncy_scan)
+        .autosave(state.autosave)
+        .autopaste(state.autopaste);
+    // Empty strings and lists mean "no change" to Scan.PropsSet
+    props.series_name = Some(state.series_name.clone());
+    props.comment = Some(state.comment.clone());
+    props.modules_names = Some(state.modules_names.clone());
+    client.scan_props_set(props)
+}
+
+fn restore_lockin(client: &mut NanonisClient, state: &LockInState) -> Result<(), NanonisError> {
+    for modulator in &state.modulators {
+        let number = modulator.number;
+        client.lockin_mod_signal_set(number, modulator.signal_index)?;
+        client.lockin_mod_phas_reg_set(number, modulator.phase_register)?;
+        client.lockin_mod_harmonic_set(number, modulator.harmonic)?;
+        client.lockin_mod_phas_set(number, modulator.phase_deg)?;
+        client.lockin_mod_amp_set(number, modulator.amplitude)?;
+        client.lockin_mod_phas_freq_set(number, modulator.frequency_hz)?;
+        client.lockin_mod_on_off_set(number, modulator.enabled)?;
+    }
+    for demodulator in &state.demodulators {
+        let number = demodulator.number;
+        client.lockin_demod_signal_set(number, demodulator.signal_index)?;
+        client.lockin_demod_harmonic_set(number, demodulator.harmonic)?;
+        client.lockin_demod_hp_filter_set(
+            number,
+            demodulator.hp_filter_order,
+            demodulator.hp_filter_cutoff_hz,
+        )?;
+        client.lockin_demod_lp_filter_set(
+            number,
+            demodulator.lp_filter_order,
+            demodulator.lp_filter_cutoff_hz,
+        )?;
+        client.lockin_demod_phas_reg_set(number, demodulator.phase_register)?;
+        client.lockin_demod_phas_set(number, demodulator.phase_deg)?;
+        client.lockin_demod_sync_filter_set(number, demodulator.sync_filter)?;
+        client.lockin_demod_rt_signals_set(number, demodulator.rt_signal_mode)?;
+    }
+    Ok(())
+}
+
+fn restore_pll(client: &mut NanonisClient, state: &PllState) -> Result<(), NanonisError> {
+    for demodulator in &state.demodulators {
+        let index = demodulator.index;
+        client.pll_demod_input_set(
+            index,
+            &PLLDemodInput {
+                input: demodulator.input,
+                freq_generator: demodulator.freq_generator,
+            },
+        )?;
+        client.pll_demod_harmonic_set(index, demodulator.harmonic)?;
+        client.pll_demod_phas_ref_set(index, demodulator.phase_ref_deg)?;
+        client.pll_demod_filter_set(index, demodulator.filter_order)?;
+    }
+    for modulator in &state.modulators {
+        let index = modulator.index;
+        client.pll_inp_calibr_set(index, modulator.input_calibration_m_per_v)?;
+        client.pll_inp_range_set(index, modulator.input_range_m)?;
+        client.pll_inp_props_set(
+            index,
+            &PLLInputProps {
+                differential_input: modulator.differential_input,
+                divider_1_10: modulator.divider_1_10,
+            },
+        )?;
+        client.pll_exc_range_set(index, modulator.excitation_range)?;
+        client.pll_excitation_set(index, modulator.excitation_v)?;
+        client.pll_amp_ctrl_setpnt_set(index, modulator.amp_ctrl_setpoint_m)?;
+        client.pll_amp_ctrl_gain_set(
+            index,
+            modulator.amp_ctrl_p_gain_v_per_m,
+            modulator.amp_ctrl_time_constant_s,
+        )?;
+        client.pll_phas_ctrl_gain_set(
+            index,
+            modulator.phase_ctrl_p_gain_hz_per_deg,
+            modulator.phase_ctrl_time_constant_s,
+        )?;
+        client.pll_freq_range_set(index, modulator.freq_range_hz)?;
+        client.pll_center_freq_set(index, modulator.center_freq_hz)?;
+        client.pll_freq_shift_set(index, modulator.freq_shift_hz)?;
+        client.pll_freq_exc_overwrite_set(
+            index,
+            &PLLOverwrite {
+                excitation_signal_index: modulator.excitation_overwrite_signal,
+                frequency_signal_index: modulator.frequency_overwrite_signal,
+            },
+        )?;
+        client.pll_add_on_off_set(index, modulator.add_on)?;
+        client.pll_out_on_off_set(index, modulator.out_on)?;
+        client.pll_amp_ctrl_on_off_set(index, modulator.amp_ctrl_on)?;
+        client.pll_phas_ctrl_on_off_set(index, modulator.phase_ctrl_on)?;
+    }
+    Ok(())
+}
+
+fn restore_kelvin_ctrl(
+    client: &mut NanonisClient,
+    state: &KelvinCtrlState,
+) -> Result<(), NanonisError> {
+    client.kelvin_ctrl_setpnt_set(state.setpoint)?;
+    client.kelvin_ctrl_gain_set(&KelvinGain {
+        p_gain: state.p_gain,
+        time_constant_s: state.time_constant_s,
+        slope: state.slope,
+    })?;
+    client.kelvin_ctrl_mod_params_set(&KelvinModParams {
+        frequency_hz: state.frequency_hz,
+        amplitude: state.amplitude,
+        phase_deg: state.phase_deg,
+    })?;
+    client.kelvin_ctrl_signal_set(state.signal_index)?;
+    client.kelvin_ctrl_bias_limits_set(&KelvinBiasLimits {
+        high_limit_v: state.bias_high_limit_v,
+        low_limit_v: state.bias_low_limit_v,
+    })?;
+    let ac_mode = if state.ac_mode {
+        KelvinACMode::On
+    } else {
+        KelvinACMode::Off
+    };
+    client.kelvin_ctrl_mod_on_off_set(ac_mode, state.modulation)?;
+    client.kelvin_ctrl_on_off_set(state.enabled)
+}
+
+fn restore_piezo(client: &mut NanonisClient, state: &PiezoState) -> Result<(), NanonisError> {
+    client.piezo_tilt_set(state.tilt.tilt_x_deg, state.tilt.tilt_y_deg)?;
+    client.piezo_drift_comp_set(&DriftCompConfig {
+        enabled: if state.drift_comp_enabled {
+            PiezoToggle::On
+        } else {
+            PiezoToggle::Off
+        },
+        vx_m_s: state.drift_vx_m_s,
+        vy_m_s: state.drift_vy_m_s,
+        vz_m_s: state.drift_vz_m_s,
+        saturation_limit: state.drift_saturation_limit,
+    })
+}
+
+fn restore_safe_tip(client: &mut NanonisClient, state: &SafeTipState) -> Result<(), NanonisError> {
+    client.safe_tip_props_set(
+        state.auto_recovery,
+        state.auto_pause_scan,
+        Setpoint::new(state.threshold.into()),
+    )?;
+    client.safe_tip_on_off_set(state.enabled)
+}
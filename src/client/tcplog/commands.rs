@@ -0,0 +1,39 @@
+//! Typed descriptors for the `TCPLog.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `TCPLog.Start`: start the acquisition in the TCP Logger module.
+    pub struct TcpLogStart: "TCPLog.Start" {} -> ()
+}
+
+nanonis_command! {
+    /// `TCPLog.Stop`: stop the acquisition in the TCP Logger module.
+    pub struct TcpLogStop: "TCPLog.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `TCPLog.ChsSet`: set the list of recorded channels in the TCP Logger module.
+    pub struct TcpLogChsSet: "TCPLog.ChsSet" {
+        /// Number of channels
+        pub num_channels: i32 = "i",
+        /// Signal slot indexes (0-23) of the logged channels
+        pub channel_indexes: Vec<i32> = "*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `TCPLog.OversamplSet`: set the oversampling value in the TCP Logger.
+    pub struct TcpLogOversamplSet: "TCPLog.OversamplSet" {
+        /// Oversampling index (0-1000)
+        pub oversampling_value: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `TCPLog.StatusGet`: return the current status of the TCP Logger.
+    pub struct TcpLogStatusGet: "TCPLog.StatusGet" {} -> TcpLogStatusGetResponse {
+        /// Logger status code
+        pub status: i32 = "i",
+    }
+}
@@ -1,9 +1,13 @@
+mod session;
 mod types;
+pub use session::*;
 pub use types::*;
 
+pub mod commands;
+
+use super::signals::SlotRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     /// Start the acquisition in the TCP Logger module.
@@ -25,15 +29,15 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
-    /// // Configure channels first (using signal slots, not full indices)
-    /// client.tcplog_chs_set(vec![0, 1, 2])?; // First 3 signal slots
+    /// // Configure channels first
+    /// client.tcplog_chs_set(["Current (A)", "Z (m)"])?;
     ///
     /// // Start logging
     /// client.tcplog_start()?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tcplog_start(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("TCPLog.Start", vec![], vec![], vec![])?;
+        self.send(&commands::TcpLogStart {})?;
         Ok(())
     }
 
@@ -58,26 +62,28 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tcplog_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("TCPLog.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::TcpLogStop {})?;
         Ok(())
     }
 
     /// Set the list of recorded channels in the TCP Logger module.
     ///
-    /// The channel indexes are comprised between 0 and 23 for the 24 signals
-    /// assigned in the Signals Manager. To get the signal name and its
-    /// corresponding index in the list of the 128 available signals in the
-    /// Nanonis Controller, use the `signal_names_get()` function.
+    /// The logger records the 24 signal slots of the Signals Manager.
+    /// Bare integers are slots (0-23), as returned by the matching getter;
+    /// names and [`SignalIndex`](crate::signals::SignalIndex)es are mapped
+    /// to the slot their signal is assigned to, see
+    /// [`SlotRef`](crate::signals::SlotRef).
     ///
     /// # Arguments
-    /// * `channel_indexes` - Vector of channel indexes to record (0-23)
+    /// * `channels` - Signals to record
     ///
     /// # Returns
     /// `Ok(())` if the command succeeds.
     ///
     /// # Errors
     /// Returns `NanonisError` if:
-    /// - Invalid channel indexes provided
+    /// - A signal does not resolve or is not assigned to a slot
+    /// - Invalid slots provided
     /// - Communication with the server fails
     /// - Protocol error occurs
     ///
@@ -87,14 +93,21 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
-    /// // Record first few signal slots (current, height, etc.)
-    /// client.tcplog_chs_set(vec![0, 1, 2])?;
+    /// // Record current and height by name
+    /// client.tcplog_chs_set(["Current", "Z"])?;
     ///
-    /// // Record only the first slot (typically current)
-    /// client.tcplog_chs_set(vec![0])?;
+    /// // Record the first slot, whichever signal is assigned to it
+    /// client.tcplog_chs_set([0])?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn tcplog_chs_set(&mut self, channel_indexes: Vec<i32>) -> Result<(), NanonisError> {
+    pub fn tcplog_chs_set<S: Into<SlotRef>>(
+        &mut self,
+        channels: impl IntoIterator<Item = S>,
+    ) -> Result<(), NanonisError> {
+        let channel_indexes = channels
+            .into_iter()
+            .map(|channel| self.resolve_slot(channel))
+            .collect::<Result<Vec<_>, _>>()?;
         for &index in &channel_indexes {
             if !(0..=23).contains(&index) {
                 return Err(NanonisError::Protocol(
@@ -104,15 +117,7 @@ impl NanonisClient {
         }
         let num_channels = channel_indexes.len() as i32;
 
-        self.quick_send(
-            "TCPLog.ChsSet",
-            vec![
-                NanonisValue::I32(num_channels),
-                NanonisValue::ArrayI32(channel_indexes),
-            ],
-            vec!["i", "*i"],
-            vec![],
-        )?;
+        self.send(&commands::TcpLogChsSet { num_channels, channel_indexes })?;
         Ok(())
     }
 
@@ -153,12 +158,7 @@ impl NanonisClient {
             )));
         }
 
-        self.quick_send(
-            "TCPLog.OversamplSet",
-            vec![NanonisValue::I32(oversampling_value)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::TcpLogOversamplSet { oversampling_value })?;
         Ok(())
     }
 
@@ -190,28 +190,18 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tcplog_status_get(&mut self) -> Result<TCPLogStatus, NanonisError> {
-        let result = self.quick_send("TCPLog.StatusGet", vec![], vec![], vec!["i"])?;
-
-        println!("{result:?}");
+        let response = self.send(&commands::TcpLogStatusGet {})?;
 
-        match result.first() {
-            Some(value) => {
-                let value = value.as_i32()?;
-                match value {
-                    0 => Ok(TCPLogStatus::Disconnected),
-                    1 => Ok(TCPLogStatus::Idle),
-                    2 => Ok(TCPLogStatus::Start),
-                    3 => Ok(TCPLogStatus::Stop),
-                    4 => Ok(TCPLogStatus::Running),
-                    5 => Ok(TCPLogStatus::TCPConnect),
-                    6 => Ok(TCPLogStatus::TCPDisconnect),
-                    7 => Ok(TCPLogStatus::BufferOverflow),
-                    _ => Err(NanonisError::Protocol("Invalid Status value".to_string())),
-                }
-            }
-            None => Err(NanonisError::Protocol(
-                "No status value returned".to_string(),
-            )),
+        match response.status {
+            0 => Ok(TCPLogStatus::Disconnected),
+            1 => Ok(TCPLogStatus::Idle),
+            2 => Ok(TCPLogStatus::Start),
+            3 => Ok(TCPLogStatus::Stop),
+            4 => Ok(TCPLogStatus::Running),
+            5 => Ok(TCPLogStatus::TCPConnect),
+            6 => Ok(TCPLogStatus::TCPDisconnect),
+            7 => Ok(TCPLogStatus::BufferOverflow),
+            _ => Err(NanonisError::Protocol("Invalid Status value".to_string())),
         }
     }
 }
@@ -0,0 +1,306 @@
+use crate::client::signals::{SignalFrame, SignalSlot, SlotRef};
+use crate::client::NanonisClient;
+use crate::error::NanonisError;
+use crate::files::split_unit;
+use crate::shared_client::SharedNanonisClient;
+use crate::tcplogger_stream::{StreamStats, TCPLoggerStream};
+use crate::units::Hertz;
+use log::{debug, warn};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A TCP Logger frame with its values keyed by signal name.
+#[derive(Debug, Clone)]
+pub struct NamedFrame {
+    /// Names of the logged signals in channel order, shared by all frames
+    /// of a session
+    pub names: Arc<[String]>,
+    /// The frame; `frame.data[i]` is the value of `names[i]`
+    pub frame: SignalFrame,
+}
+
+impl NamedFrame {
+    /// Frame counter.
+    pub fn counter(&self) -> u64 {
+        self.frame.counter
+    }
+
+    /// Time since the logger started, see [`SignalFrame::timestamp`].
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.frame.timestamp
+    }
+
+    /// Value of a signal, matched by its full name or by the name without
+    /// the unit, ignoring case.
+    pub fn get(&self, name: &str) -> Option<f32> {
+        let name = name.trim();
+        self.names
+            .iter()
+            .position(|candidate| {
+                candidate.eq_ignore_ascii_case(name)
+                    || split_unit(candidate).0.eq_ignore_ascii_case(name)
+            })
+            .and_then(|index| self.frame.data.get(index).copied())
+    }
+
+    /// Signal names and values in channel order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> + '_ {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.frame.data.iter().copied())
+    }
+
+    /// Values keyed by full signal name.
+    pub fn to_map(&self) -> HashMap<String, f32> {
+        self.iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect()
+    }
+}
+
+/// A running TCP Logger acquisition: control and data stream in one.
+///
+/// Created by [`SharedNanonisClient::tcplog_session`] or
+/// [`SharedNanonisClient::tcplog_session_with_stream`], which select the
+/// channels, set the oversampling, read the RT frequency for frame
+/// timestamps and start the logger. Frames are returned as
+/// [`NamedFrame`]s. The logger is stopped by [`stop`](Self::stop) or when
+/// the session is dropped.
+///
+/// The session keeps a handle to the shared client, so other handles can
+/// keep driving the instrument while frames are recorded.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::tcplog::TCPLogStatus;
+/// use nanonis_rs::{MemoryTransport, MockServer, SharedNanonisClient, SimulatedStm, TCPLoggerStream, Volts};
+/// use std::io::Write;
+/// use std::time::Duration;
+///
+/// let server = MockServer::start()?;
+/// let sim = SimulatedStm::new();
+/// sim.attach(&server);
+/// let client = SharedNanonisClient::new(server.client()?);
+///
+/// // The simulation serves no data stream; write a frame by hand
+/// let (stream_end, mut logger_end) = MemoryTransport::pair();
+/// let mut frame = Vec::new();
+/// frame.extend_from_slice(&2u32.to_be_bytes()); // channels
+/// frame.extend_from_slice(&10f32.to_be_bytes()); // oversampling
+/// frame.extend_from_slice(&2u64.to_be_bytes()); // counter
+/// frame.extend_from_slice(&4u16.to_be_bytes()); // state
+/// frame.extend_from_slice(&1e-10f32.to_be_bytes());
+/// frame.extend_from_slice(&2e-9f32.to_be_bytes());
+/// logger_end.write_all(&frame)?;
+///
+/// let mut session = client.tcplog_session_with_stream(
+///     ["Current", "Z (m)"],
+///     10,
+///     TCPLoggerStream::from_transport(stream_end),
+/// )?;
+/// assert_eq!(sim.state().tcplog_status, TCPLogStatus::Running);
+///
+/// // The client stays usable while the logger runs
+/// client.call(|client| client.bias_set(Volts::new(0.5)))?;
+///
+/// let frame = session.next_frame()?;
+/// assert_eq!(frame.get("current"), Some(1e-10));
+/// assert_eq!(frame.get("Z (m)"), Some(2e-9));
+/// // Counter 2 at 10x oversampling of the 20 kHz RT loop
+/// assert_eq!(frame.timestamp(), Some(Duration::from_millis(1)));
+///
+/// drop(session);
+/// assert_eq!(sim.state().tcplog_status, TCPLogStatus::Idle);
+/// assert_eq!(sim.state().tcplog_channels, vec![0, 2]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct TcpLoggerSession {
+    client: SharedNanonisClient,
+    stream: TCPLoggerStream,
+    names: Arc<[String]>,
+    running: bool,
+}
+
+impl SharedNanonisClient {
+    /// Configure and start the TCP Logger and open its data stream on the
+    /// host of this connection.
+    ///
+    /// `channels` are slots (0-23), or signal names and
+    /// [`SignalIndex`](crate::signals::SignalIndex)es assigned to a slot, see
+    /// [`SlotRef`]. See [`TcpLoggerSession`].
+    ///
+    /// # Arguments
+    /// * `channels` - Signals to record
+    /// * `oversampling` - Oversampling value (0-1000)
+    /// * `stream_port` - TCP Logger data stream port (typically 6590)
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the transport has no remote address, a
+    /// signal does not resolve or has no slot, or configuring the logger or
+    /// opening the stream fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use nanonis_rs::SharedNanonisClient;
+    ///
+    /// let client = SharedNanonisClient::connect("127.0.0.1", &[6501])?;
+    /// let mut session = client.tcplog_session(["Current (A)", "Z (m)"], 10, 6590)?;
+    ///
+    /// for _ in 0..1000 {
+    ///     let frame = session.next_frame()?;
+    ///     println!("{:?} {:?}", frame.timestamp(), frame.get("Current"));
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tcplog_session<S: Into<SlotRef>>(
+        &self,
+        channels: impl IntoIterator<Item = S>,
+        oversampling: i32,
+        stream_port: u16,
+    ) -> Result<TcpLoggerSession, NanonisError> {
+        let addr = self
+            .call(|client| Ok(client.stream.remote_addr()))?
+            .ok_or_else(|| {
+                NanonisError::Protocol(
+                    "Transport has no remote address, open the stream with tcplog_session_with_stream"
+                        .to_string(),
+                )
+            })?;
+        let stream = TCPLoggerStream::connect(SocketAddr::new(addr.ip(), stream_port))?;
+        self.tcplog_session_with_stream(channels, oversampling, stream)
+    }
+
+    /// Configure and start the TCP Logger, reading frames from an already
+    /// open stream.
+    ///
+    /// Use this for streams over a custom transport or with a reconnect
+    /// policy. An RT frequency set on the stream is replaced by the one
+    /// read from the instrument.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if a signal does not resolve or has no slot,
+    /// or configuring the logger fails.
+    pub fn tcplog_session_with_stream<S: Into<SlotRef>>(
+        &self,
+        channels: impl IntoIterator<Item = S>,
+        oversampling: i32,
+        stream: TCPLoggerStream,
+    ) -> Result<TcpLoggerSession, NanonisError> {
+        let channels: Vec<SlotRef> = channels.into_iter().map(Into::into).collect();
+        // Configure and start in one call, so no other call interleaves
+        let (names, rt_frequency) =
+            self.call(move |client| client.tcplog_session_start(channels, oversampling))?;
+
+        Ok(TcpLoggerSession {
+            client: self.clone(),
+            stream: stream.with_rt_frequency(rt_frequency),
+            names,
+            running: true,
+        })
+    }
+}
+
+impl NanonisClient {
+    /// Select the channels, set the oversampling and start the logger,
+    /// returning the channel names and the RT frequency.
+    fn tcplog_session_start(
+        &mut self,
+        channels: Vec<SlotRef>,
+        oversampling: i32,
+    ) -> Result<(Arc<[String]>, Hertz), NanonisError> {
+        let slots = channels
+            .into_iter()
+            .map(|channel| self.resolve_slot(channel))
+            .collect::<Result<Vec<_>, _>>()?;
+        if slots.is_empty() {
+            return Err(NanonisError::Protocol(
+                "TCP Logger session needs at least one channel".to_string(),
+            ));
+        }
+
+        let registry = self.signal_registry()?;
+        let names: Arc<[String]> = slots
+            .iter()
+            .map(|&slot| {
+                u8::try_from(slot)
+                    .ok()
+                    .and_then(|slot| registry.in_slot(SignalSlot(slot)))
+                    .map_or_else(|| format!("Slot {slot}"), |signal| signal.name.clone())
+            })
+            .collect();
+
+        let rt_frequency = self.util_rt_freq_get()?;
+        self.tcplog_chs_set(slots.iter().copied())?;
+        self.tcplog_oversampl_set(oversampling)?;
+        self.tcplog_start()?;
+        debug!("TCP Logger started with {names:?}");
+
+        Ok((names, Hertz::new(rt_frequency.into())))
+    }
+}
+
+impl TcpLoggerSession {
+    /// Names of the logged signals in channel order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The client controlling the logger.
+    pub fn client(&self) -> &SharedNanonisClient {
+        &self.client
+    }
+
+    /// Block until the next frame arrives.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if reading fails or the frame does not have
+    /// one value per logged signal.
+    pub fn next_frame(&mut self) -> Result<NamedFrame, NanonisError> {
+        let frame = self.stream.read_frame()?;
+        if frame.data.len() != self.names.len() {
+            return Err(NanonisError::Protocol(format!(
+                "TCP Logger frame has {} channels, expected {}",
+                frame.data.len(),
+                self.names.len()
+            )));
+        }
+        Ok(NamedFrame {
+            names: Arc::clone(&self.names),
+            frame,
+        })
+    }
+
+    /// Counters of the underlying stream, see [`TCPLoggerStream::stats`].
+    pub fn stats(&self) -> StreamStats {
+        self.stream.stats()
+    }
+
+    /// The underlying stream, e.g. to read
+    /// [`StreamEvent`](crate::StreamEvent)s directly.
+    pub fn stream_mut(&mut self) -> &mut TCPLoggerStream {
+        &mut self.stream
+    }
+
+    /// Stop the logger and return the stream counters.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if stopping the logger fails.
+    pub fn stop(mut self) -> Result<StreamStats, NanonisError> {
+        self.running = false;
+        self.client.call(|client| client.tcplog_stop())?;
+        Ok(self.stream.stats())
+    }
+}
+
+impl Drop for TcpLoggerSession {
+    fn drop(&mut self) {
+        if self.running {
+            if let Err(e) = self.client.call(|client| client.tcplog_stop()) {
+                warn!("Failed to stop TCP Logger: {e}");
+            }
+        }
+    }
+}
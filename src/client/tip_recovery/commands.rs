@@ -0,0 +1,117 @@
+//! Typed descriptors for the `TipRec.*` and `TipShaper.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `TipRec.BufferSizeSet`: set the buffer size of the Tip Move Recorder.
+    pub struct TipRecBufferSizeSet: "TipRec.BufferSizeSet" {
+        /// Number of data elements to store in the recorder buffer
+        pub buffer_size: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `TipRec.BufferSizeGet`: get the current buffer size of the Tip Move Recorder.
+    pub struct TipRecBufferSizeGet: "TipRec.BufferSizeGet" {} -> TipRecBufferSizeGetResponse {
+        /// Number of data elements to store in the recorder buffer
+        pub buffer_size: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `TipRec.BufferClear`: clear the buffer of the Tip Move Recorder.
+    pub struct TipRecBufferClear: "TipRec.BufferClear" {} -> ()
+}
+
+nanonis_command! {
+    /// `TipRec.DataGet`: get the recorded data from the Tip Move Recorder.
+    pub struct TipRecDataGet: "TipRec.DataGet" {} -> TipRecDataGetResponse {
+        /// Number of channel indexes
+        pub num_channel_indexes: i32 = "i",
+        /// Channel indexes (0-23 for Signals Manager slots)
+        pub channel_indexes: Vec<i32> = "*i",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// 2D data array \[rows\]\[columns\] with recorded measurements
+        pub data_2d: Array2D<f32> = "2f",
+    }
+}
+
+nanonis_command! {
+    /// `TipRec.DataSave`: save the tip movement data to a file.
+    pub struct TipRecDataSave: "TipRec.DataSave" {
+        /// If `true`, clears buffer after saving
+        pub clear_buffer: u32 = "I",
+        /// Base filename for saved data (empty to use last basename)
+        pub basename: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `TipShaper.Start`: start the tip shaper procedure for tip conditioning.
+    pub struct TipShaperStart: "TipShaper.Start" blocking if wait_until_finished {
+        /// If `true`, waits for procedure completion
+        pub wait_until_finished: u32 = "I",
+        /// Timeout in milliseconds, -1 to wait forever
+        pub timeout: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `TipShaper.PropsSet`: set the tip shaper procedure configuration.
+    pub struct TipShaperPropsSet: "TipShaper.PropsSet" {
+        /// Switch off delay (s)
+        pub switch_off_delay: f32 = "f",
+        /// Change bias flag (0=no change, 1=true, 2=false)
+        pub change_bias: u32 = "I",
+        /// Bias voltage (V)
+        pub bias_v: f32 = "f",
+        /// Tip lift distance (m)
+        pub tip_lift_m: f32 = "f",
+        /// First lift time (s)
+        pub lift_time_1: f32 = "f",
+        /// Bias lift voltage (V)
+        pub bias_lift_v: f32 = "f",
+        /// Bias settling time (s)
+        pub bias_settling_time: f32 = "f",
+        /// Second lift height (m)
+        pub lift_height_m: f32 = "f",
+        /// Second lift time (s)
+        pub lift_time_2: f32 = "f",
+        /// End wait time (s)
+        pub end_wait_time: f32 = "f",
+        /// Restore feedback flag (0=no change, 1=true, 2=false)
+        pub restore_feedback: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `TipShaper.PropsGet`: get the current tip shaper procedure configuration.
+    pub struct TipShaperPropsGet: "TipShaper.PropsGet" {} -> TipShaperPropsGetResponse {
+        /// Switch off delay (s)
+        pub switch_off_delay: f32 = "f",
+        /// Change bias flag (0=no change, 1=true, 2=false)
+        pub change_bias: u32 = "I",
+        /// Bias voltage (V)
+        pub bias_v: f32 = "f",
+        /// Tip lift distance (m)
+        pub tip_lift_m: f32 = "f",
+        /// First lift time (s)
+        pub lift_time_1: f32 = "f",
+        /// Bias lift voltage (V)
+        pub bias_lift_v: f32 = "f",
+        /// Bias settling time (s)
+        pub bias_settling_time: f32 = "f",
+        /// Second lift height (m)
+        pub lift_height_m: f32 = "f",
+        /// Second lift time (s)
+        pub lift_time_2: f32 = "f",
+        /// End wait time (s)
+        pub end_wait_time: f32 = "f",
+        /// Restore feedback flag (0=no change, 1=true, 2=false)
+        pub restore_feedback: u32 = "I",
+    }
+}
@@ -1,6 +1,8 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 use std::time::Duration;
 
 /// Configuration parameters for tip shaper
@@ -49,12 +51,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tip_rec_buffer_size_set(&mut self, buffer_size: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "TipRec.BufferSizeSet",
-            vec![NanonisValue::I32(buffer_size)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::TipRecBufferSizeSet { buffer_size })?;
         Ok(())
     }
 
@@ -79,14 +76,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tip_rec_buffer_size_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("TipRec.BufferSizeGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::TipRecBufferSizeGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No buffer size returned".to_string(),
-            )),
-        }
+        Ok(response.buffer_size)
     }
 
     /// Clear the buffer of the Tip Move Recorder.
@@ -109,7 +101,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tip_rec_buffer_clear(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("TipRec.BufferClear", vec![], vec![], vec![])?;
+        self.send(&commands::TipRecBufferClear {})?;
         Ok(())
     }
 
@@ -122,7 +114,7 @@ impl NanonisClient {
     /// # Returns
     /// A tuple containing:
     /// - `Vec<i32>` - Channel indexes (0-23 for Signals Manager slots)
-    /// - `Vec<Vec<f32>>` - 2D data array \[rows\]\[columns\] with recorded measurements
+    /// - `Array2D<f32>` - 2D data array \[rows\]\[columns\] with recorded measurements
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails or no data available.
@@ -141,40 +133,19 @@ impl NanonisClient {
     ///
     /// // Analyze data for each channel
     /// for (i, &channel_idx) in channel_indexes.iter().enumerate() {
-    ///     if i < data[0].len() {
+    ///     if i < data.cols() {
     ///         println!("Channel {}: {} values", channel_idx, data.len());
     ///     }
     /// }
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn tip_rec_data_get(&mut self) -> Result<(Vec<i32>, Vec<Vec<f32>>), NanonisError> {
-        let result = self.quick_send(
-            "TipRec.DataGet",
-            vec![],
-            vec![],
-            vec!["i", "*i", "i", "i", "2f"],
-        )?;
-
-        if result.len() >= 5 {
-            let channel_indexes = result[1].as_i32_array()?.to_vec();
-            let rows = result[2].as_i32()? as usize;
-            let cols = result[3].as_i32()? as usize;
+    pub fn tip_rec_data_get(&mut self) -> Result<(Vec<i32>, Array2D<f32>), NanonisError> {
+        let response = self.send(&commands::TipRecDataGet {})?;
 
-            // Parse 2D data array
-            let flat_data = result[4].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                data_2d.push(flat_data[start_idx..end_idx].to_vec());
-            }
+        let channel_indexes = response.channel_indexes;
+        let data_2d = response.data_2d;
 
-            Ok((channel_indexes, data_2d))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid tip recorder data response".to_string(),
-            ))
-        }
+        Ok((channel_indexes, data_2d))
     }
 
     /// Save the tip movement data to a file.
@@ -209,15 +180,10 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let clear_flag = if clear_buffer { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "TipRec.DataSave",
-            vec![
-                NanonisValue::U32(clear_flag),
-                NanonisValue::String(basename.to_string()),
-            ],
-            vec!["I", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::TipRecDataSave {
+            clear_buffer: clear_flag,
+            basename: basename.to_string(),
+        })?;
         Ok(())
     }
 
@@ -257,12 +223,7 @@ impl NanonisClient {
         let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
         let timeout = timeout.as_millis().min(u32::MAX as u128) as i32;
 
-        self.quick_send(
-            "TipShaper.Start",
-            vec![NanonisValue::U32(wait_flag), NanonisValue::I32(timeout)],
-            vec!["I", "i"],
-            vec![],
-        )?;
+        self.send(&commands::TipShaperStart { wait_until_finished: wait_flag, timeout })?;
         Ok(())
     }
 
@@ -304,24 +265,19 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tip_shaper_props_set(&mut self, config: TipShaperConfig) -> Result<(), NanonisError> {
-        self.quick_send(
-            "TipShaper.PropsSet",
-            vec![
-                NanonisValue::F32(config.switch_off_delay.as_secs_f32()),
-                NanonisValue::U32(config.change_bias.into()),
-                NanonisValue::F32(config.bias_v),
-                NanonisValue::F32(config.tip_lift_m),
-                NanonisValue::F32(config.lift_time_1.as_secs_f32()),
-                NanonisValue::F32(config.bias_lift_v),
-                NanonisValue::F32(config.bias_settling_time.as_secs_f32()),
-                NanonisValue::F32(config.lift_height_m),
-                NanonisValue::F32(config.lift_time_2.as_secs_f32()),
-                NanonisValue::F32(config.end_wait_time.as_secs_f32()),
-                NanonisValue::U32(config.restore_feedback.into()),
-            ],
-            vec!["f", "I", "f", "f", "f", "f", "f", "f", "f", "f", "I"],
-            vec![],
-        )?;
+        self.send(&commands::TipShaperPropsSet {
+            switch_off_delay: config.switch_off_delay.as_secs_f32(),
+            change_bias: config.change_bias.into(),
+            bias_v: config.bias_v,
+            tip_lift_m: config.tip_lift_m,
+            lift_time_1: config.lift_time_1.as_secs_f32(),
+            bias_lift_v: config.bias_lift_v,
+            bias_settling_time: config.bias_settling_time.as_secs_f32(),
+            lift_height_m: config.lift_height_m,
+            lift_time_2: config.lift_time_2.as_secs_f32(),
+            end_wait_time: config.end_wait_time.as_secs_f32(),
+            restore_feedback: config.restore_feedback.into(),
+        })?;
         Ok(())
     }
 
@@ -363,32 +319,21 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tip_shaper_props_get(&mut self) -> Result<TipShaperProps, NanonisError> {
-        let result = self.quick_send(
-            "TipShaper.PropsGet",
-            vec![],
-            vec![],
-            vec!["f", "I", "f", "f", "f", "f", "f", "f", "f", "f", "I"],
-        )?;
+        let response = self.send(&commands::TipShaperPropsGet {})?;
 
-        if result.len() >= 11 {
-            Ok((
-                result[0].as_f32()?,  // switch_off_delay
-                result[1].as_u32()?,  // change_bias
-                result[2].as_f32()?,  // bias_v
-                result[3].as_f32()?,  // tip_lift_m
-                result[4].as_f32()?,  // lift_time_1_s
-                result[5].as_f32()?,  // bias_lift_v
-                result[6].as_f32()?,  // bias_settling_time_s
-                result[7].as_f32()?,  // lift_height_m
-                result[8].as_f32()?,  // lift_time_2_s
-                result[9].as_f32()?,  // end_wait_time_s
-                result[10].as_u32()?, // restore_feedback
-            ))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid tip shaper properties response".to_string(),
-            ))
-        }
+        Ok((
+            response.switch_off_delay,
+            response.change_bias,
+            response.bias_v,
+            response.tip_lift_m,
+            response.lift_time_1,
+            response.bias_lift_v,
+            response.bias_settling_time,
+            response.lift_height_m,
+            response.lift_time_2,
+            response.end_wait_time,
+            response.restore_feedback,
+        ))
     }
 
     /// Get the Tip Shaper properties as a type-safe TipShaperConfig struct
@@ -419,43 +364,32 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn tip_shaper_config_get(&mut self) -> Result<TipShaperConfig, NanonisError> {
-        let result = self.quick_send(
-            "TipShaper.PropsGet",
-            vec![],
-            vec![],
-            vec!["f", "I", "f", "f", "f", "f", "f", "f", "f", "f", "I"],
-        )?;
+        let response = self.send(&commands::TipShaperPropsGet {})?;
 
-        if result.len() >= 11 {
-            let restore_feedback = match result[10].as_u32()? {
-                0 => true,
-                1 => false,
-                _ => panic!("Wrong return value for restore_feedback"),
-            };
+        let restore_feedback = match response.restore_feedback {
+            0 => true,
+            1 => false,
+            _ => panic!("Wrong return value for restore_feedback"),
+        };
 
-            let change_bias = match result[1].as_u32()? {
-                0 => true,
-                1 => false,
-                _ => panic!("Wrong return value for change_bias"),
-            };
+        let change_bias = match response.change_bias {
+            0 => true,
+            1 => false,
+            _ => panic!("Wrong return value for change_bias"),
+        };
 
-            Ok(TipShaperConfig {
-                switch_off_delay: Duration::from_secs_f32(result[0].as_f32()?),
-                change_bias,
-                bias_v: result[2].as_f32()?,
-                tip_lift_m: result[3].as_f32()?,
-                lift_time_1: Duration::from_secs_f32(result[4].as_f32()?),
-                bias_lift_v: result[5].as_f32()?,
-                bias_settling_time: Duration::from_secs_f32(result[6].as_f32()?),
-                lift_height_m: result[7].as_f32()?,
-                lift_time_2: Duration::from_secs_f32(result[8].as_f32()?),
-                end_wait_time: Duration::from_secs_f32(result[9].as_f32()?),
-                restore_feedback,
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid tip shaper properties response".to_string(),
-            ))
-        }
+        Ok(TipShaperConfig {
+            switch_off_delay: Duration::from_secs_f32(response.switch_off_delay),
+            change_bias,
+            bias_v: response.bias_v,
+            tip_lift_m: response.tip_lift_m,
+            lift_time_1: Duration::from_secs_f32(response.lift_time_1),
+            bias_lift_v: response.bias_lift_v,
+            bias_settling_time: Duration::from_secs_f32(response.bias_settling_time),
+            lift_height_m: response.lift_height_m,
+            lift_time_2: Duration::from_secs_f32(response.lift_time_2),
+            end_wait_time: Duration::from_secs_f32(response.end_wait_time),
+            restore_feedback,
+        })
     }
 }
@@ -0,0 +1,15 @@
+//! Typed descriptors for the `UserIn.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `UserIn.CalibrSet`: set the calibration of a user input.
+    pub struct UserInCalibrSet: "UserIn.CalibrSet" {
+        /// Input index (1 to available inputs)
+        pub input_index: i32 = "i",
+        /// Calibration value per volt
+        pub calibration_per_volt: f32 = "f",
+        /// Offset in physical units
+        pub offset_physical_units: f32 = "f",
+    } -> ()
+}
@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 impl NanonisClient {
     // ==================== User Inputs ====================
@@ -20,16 +21,11 @@ impl NanonisClient {
         calibration_per_volt: f32,
         offset_physical_units: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserIn.CalibrSet",
-            vec![
-                NanonisValue::I32(input_index),
-                NanonisValue::F32(calibration_per_volt),
-                NanonisValue::F32(offset_physical_units),
-            ],
-            vec!["i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::UserInCalibrSet {
+            input_index,
+            calibration_per_volt,
+            offset_physical_units,
+        })?;
         Ok(())
     }
 }
@@ -0,0 +1,148 @@
+//! Typed descriptors for the `UserOut.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `UserOut.ModeSet`: set the mode (User Output, Monitor, Calculated signal) of the selected user output channel.
+    pub struct UserOutModeSet: "UserOut.ModeSet" {
+        /// Output to be used (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Output mode to set
+        pub output_mode: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.ModeGet`: get the mode (User Output, Monitor, Calculated signal) of the selected user output channel.
+    pub struct UserOutModeGet: "UserOut.ModeGet" {
+        /// Output to query (1 to number of available outputs)
+        pub output_index: i32 = "i",
+    } -> UserOutModeGetResponse {
+        /// Output mode to set
+        pub output_mode: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `UserOut.MonitorChSet`: set the monitor channel of the selected output channel.
+    pub struct UserOutMonitorChSet: "UserOut.MonitorChSet" {
+        /// Output to configure (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Index of the monitored signal
+        pub monitor_channel_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.MonitorChGet`: get the monitor channel of the selected output channel.
+    pub struct UserOutMonitorChGet: "UserOut.MonitorChGet" {
+        /// Output to query (1 to number of available outputs)
+        pub output_index: i32 = "i",
+    } -> UserOutMonitorChGetResponse {
+        /// Index of the monitored signal
+        pub monitor_channel_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `UserOut.ValSet`: set the value of the selected user output channel.
+    pub struct UserOutValSet: "UserOut.ValSet" {
+        /// Output to set (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Value to apply in physical units
+        pub output_value: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.CalibrSet`: set the calibration of the selected user output or monitor channel.
+    pub struct UserOutCalibrSet: "UserOut.CalibrSet" {
+        /// Output to configure (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Calibration factor per volt
+        pub calibration_per_volt: f32 = "f",
+        /// Offset in physical units
+        pub offset_in_physical_units: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.CalcSignalNameSet`: set the Calculated Signal name of the selected output channel.
+    pub struct UserOutCalcSignalNameSet: "UserOut.CalcSignalNameSet" {
+        /// Output to configure (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Name of the calculated signal
+        pub calculated_signal_name: String = "+*c",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.CalcSignalNameGet`: get the Calculated Signal name of the selected output channel.
+    pub struct UserOutCalcSignalNameGet: "UserOut.CalcSignalNameGet" {
+        /// Output to query (1 to number of available outputs)
+        pub output_index: i32 = "i",
+    } -> UserOutCalcSignalNameGetResponse {
+        /// Length of the signal name in bytes
+        pub signal_name_size: i32 = "i",
+        /// Name of the calculated signal
+        pub signal_name: String = "*-c",
+    }
+}
+
+nanonis_command! {
+    /// `UserOut.CalcSignalConfigSet`: set the configuration of the Calculated Signal for the selected output channel.
+    pub struct UserOutCalcSignalConfigSet: "UserOut.CalcSignalConfigSet" {
+        /// Output to configure (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// First signal index (0-127)
+        pub signal_1: u16 = "H",
+        /// Math operation to perform
+        pub operation: u16 = "H",
+        /// Second signal index (0-127)
+        pub signal_2: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.CalcSignalConfigGet`: get the configuration of the Calculated Signal for the selected output channel.
+    pub struct UserOutCalcSignalConfigGet: "UserOut.CalcSignalConfigGet" {
+        /// Output to query (1 to number of available outputs)
+        pub output_index: i32 = "i",
+    } -> UserOutCalcSignalConfigGetResponse {
+        /// First signal index (0-127)
+        pub signal_1: u16 = "H",
+        /// Math operation to perform
+        pub operation: u16 = "H",
+        /// Second signal index (0-127)
+        pub signal_2: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `UserOut.LimitsSet`: set the physical limits (in calibrated units) of the selected output channel.
+    pub struct UserOutLimitsSet: "UserOut.LimitsSet" {
+        /// Output to configure (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Upper physical limit of the user output
+        pub upper_limit: f32 = "f",
+        /// Lower physical limit of the user output
+        pub lower_limit: f32 = "f",
+        /// Whether to set physical limits (false) or raw limits (true)
+        pub raw_limits: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `UserOut.LimitsGet`: get the physical limits (in calibrated units) of the selected output channel.
+    pub struct UserOutLimitsGet: "UserOut.LimitsGet" {
+        /// Output to query (1 to number of available outputs)
+        pub output_index: i32 = "i",
+        /// Whether to get physical limits (false) or raw limits (true)
+        pub raw_limits: u32 = "I",
+    } -> UserOutLimitsGetResponse {
+        /// Upper physical limit of the user output
+        pub upper_limit: f32 = "f",
+        /// Lower physical limit of the user output
+        pub lower_limit: f32 = "f",
+    }
+}
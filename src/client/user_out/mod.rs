@@ -1,6 +1,9 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
+use super::signals::SignalRef;
 use super::NanonisClient;
 use crate::error::NanonisError;
 
@@ -31,12 +34,10 @@ impl NanonisClient {
         output_index: i32,
         output_mode: OutputMode,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserOut.ModeSet",
-            vec![output_index.into(), output_mode.into()],
-            vec!["i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::UserOutModeSet {
+            output_index,
+            output_mode: output_mode.into(),
+        })?;
         Ok(())
     }
 
@@ -62,31 +63,20 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn user_out_mode_get(&mut self, output_index: i32) -> Result<OutputMode, NanonisError> {
-        let result = self.quick_send(
-            "UserOut.ModeGet",
-            vec![output_index.into()],
-            vec!["i"],
-            vec!["H"],
-        )?;
-        match result.first() {
-            Some(value) => {
-                let mode_val = value.as_u16()?;
-                OutputMode::try_from(mode_val)
-            }
-            None => Err(NanonisError::Protocol(
-                "No output mode value returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::UserOutModeGet { output_index })?;
+        let mode_val = response.output_mode;
+        OutputMode::try_from(mode_val)
     }
 
     /// Set the monitor channel of the selected output channel.
     ///
     /// # Arguments
     /// * `output_index` - Output to configure (1 to number of available outputs)
-    /// * `monitor_channel_index` - Index of the channel to monitor (0-127)
+    /// * `monitor_channel` - Signal to monitor by index (0-127) or name
     ///
     /// # Errors
-    /// Returns `NanonisError` if invalid indices provided or communication fails.
+    /// Returns `NanonisError` if the signal does not resolve, invalid indices
+    /// provided or communication fails.
     ///
     /// # Examples
     /// ```no_run
@@ -94,21 +84,17 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
-    /// // Set output 1 to monitor channel 5
-    /// client.user_out_monitor_ch_set(1, 5)?;
+    /// // Set output 1 to monitor the bias
+    /// client.user_out_monitor_ch_set(1, "Bias")?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn user_out_monitor_ch_set(
         &mut self,
         output_index: i32,
-        monitor_channel_index: i32,
+        monitor_channel: impl Into<SignalRef>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserOut.MonitorChSet",
-            vec![output_index.into(), monitor_channel_index.into()],
-            vec!["i", "i"],
-            vec![],
-        )?;
+        let monitor_channel_index = self.resolve_signal(monitor_channel)?;
+        self.send(&commands::UserOutMonitorChSet { output_index, monitor_channel_index })?;
         Ok(())
     }
 
@@ -134,18 +120,8 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn user_out_monitor_ch_get(&mut self, output_index: i32) -> Result<i32, NanonisError> {
-        let result = self.quick_send(
-            "UserOut.MonitorChGet",
-            vec![output_index.into()],
-            vec!["i"],
-            vec!["i"],
-        )?;
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No monitor channel value returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::UserOutMonitorChGet { output_index })?;
+        Ok(response.monitor_channel_index)
     }
 
     /// Set the value of the selected user output channel.
@@ -172,12 +148,7 @@ impl NanonisClient {
         output_index: i32,
         output_value: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserOut.ValSet",
-            vec![output_index.into(), output_value.into()],
-            vec!["i", "f"],
-            vec![],
-        )?;
+        self.send(&commands::UserOutValSet { output_index, output_value })?;
         Ok(())
     }
 
@@ -207,16 +178,11 @@ impl NanonisClient {
         calibration_per_volt: f32,
         offset_in_physical_units: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserOut.CalibrSet",
-            vec![
-                output_index.into(),
-                calibration_per_volt.into(),
-                offset_in_physical_units.into(),
-            ],
-            vec!["i", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::UserOutCalibrSet {
+            output_index,
+            calibration_per_volt,
+            offset_in_physical_units,
+        })?;
         Ok(())
     }
 
@@ -243,12 +209,10 @@ impl NanonisClient {
         output_index: i32,
         calculated_signal_name: String,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserOut.CalcSignalNameSet",
-            vec![output_index.into(), calculated_signal_name.into()],
-            vec!["i", "+*c"],
-            vec![],
-        )?;
+        self.send(&commands::UserOutCalcSignalNameSet {
+            output_index,
+            calculated_signal_name,
+        })?;
         Ok(())
     }
 
@@ -277,19 +241,8 @@ impl NanonisClient {
         &mut self,
         output_index: i32,
     ) -> Result<String, NanonisError> {
-        let result = self.quick_send(
-            "UserOut.CalcSignalNameGet",
-            vec![output_index.into()],
-            vec!["i"],
-            vec!["i", "*-c"],
-        )?;
-        if result.len() >= 2 {
-            Ok(result[1].as_string()?.to_string())
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid calc signal name response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::UserOutCalcSignalNameGet { output_index })?;
+        Ok(response.signal_name)
     }
 
     /// Set the configuration of the Calculated Signal for the selected output channel.
@@ -319,17 +272,12 @@ impl NanonisClient {
         output_index: i32,
         config: CalcSignalConfig,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "UserOut.CalcSignalConfigSet",
-            vec![
-                output_index.into(),
-                config.signal_1.into(),
-                config.operation.into(),
-                config.signal_2.into(),
-            ],
-            vec!["i", "H", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::UserOutCalcSignalConfigSet {
+            output_index,
+            signal_1: config.signal_1,
+            operation: config.operation.into(),
+            signal_2: config.signal_2,
+        })?;
         Ok(())
     }
 
@@ -359,22 +307,11 @@ impl NanonisClient {
         &mut self,
         output_index: i32,
     ) -> Result<CalcSignalConfig, NanonisError> {
-        let result = self.quick_send(
-            "UserOut.CalcSignalConfigGet",
-            vec![output_index.into()],
-            vec!["i"],
-            vec!["H", "H", "H"],
-        )?;
-        if result.len() >= 3 {
-            let signal_1 = result[0].as_u16()?;
-            let operation = CalcOperation::try_from(result[1].as_u16()?)?;
-            let signal_2 = result[2].as_u16()?;
-            Ok(CalcSignalConfig::new(signal_1, operation, signal_2))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid calc signal config response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::UserOutCalcSignalConfigGet { output_index })?;
+        let signal_1 = response.signal_1;
+        let operation = CalcOperation::try_from(response.operation)?;
+        let signal_2 = response.signal_2;
+        Ok(CalcSignalConfig::new(signal_1, operation, signal_2))
     }
 
     /// Set the physical limits (in calibrated units) of the selected output channel.
@@ -406,17 +343,12 @@ impl NanonisClient {
         raw_limits: bool,
     ) -> Result<(), NanonisError> {
         let raw_flag = if raw_limits { 1u32 } else { 0u32 };
-        self.quick_send(
-            "UserOut.LimitsSet",
-            vec![
-                output_index.into(),
-                upper_limit.into(),
-                lower_limit.into(),
-                raw_flag.into(),
-            ],
-            vec!["i", "f", "f", "I"],
-            vec![],
-        )?;
+        self.send(&commands::UserOutLimitsSet {
+            output_index,
+            upper_limit,
+            lower_limit,
+            raw_limits: raw_flag,
+        })?;
         Ok(())
     }
 
@@ -448,20 +380,10 @@ impl NanonisClient {
         raw_limits: bool,
     ) -> Result<(f32, f32), NanonisError> {
         let raw_flag = if raw_limits { 1u32 } else { 0u32 };
-        let result = self.quick_send(
-            "UserOut.LimitsGet",
-            vec![output_index.into(), raw_flag.into()],
-            vec!["i", "I"],
-            vec!["f", "f"],
-        )?;
-        if result.len() >= 2 {
-            let upper_limit = result[0].as_f32()?;
-            let lower_limit = result[1].as_f32()?;
-            Ok((upper_limit, lower_limit))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid limits response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::UserOutLimitsGet {
+            output_index,
+            raw_limits: raw_flag,
+        })?;
+        Ok((response.upper_limit, response.lower_limit))
     }
 }
@@ -0,0 +1,143 @@
+//! Typed descriptors for the `Util.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `Util.SessionPathGet`: get the session path from the Nanonis software.
+    pub struct UtilSessionPathGet: "Util.SessionPathGet" {} -> UtilSessionPathGetResponse {
+        /// Length of the session path in bytes
+        pub session_path_size: i32 = "i",
+        /// Path of the session folder
+        pub session_path: String = "*-c",
+    }
+}
+
+nanonis_command! {
+    /// `Util.SettingsLoad`: load settings from a specified .ini file.
+    pub struct UtilSettingsLoad: "Util.SettingsLoad" {
+        /// Path to the settings file to load
+        pub settings_file_path: String = "+*c",
+        /// If true, automatically loads from session file (bypasses path argument)
+        pub load_session_settings: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.SettingsSave`: save current settings to a specified .ini file.
+    pub struct UtilSettingsSave: "Util.SettingsSave" {
+        /// Path where the settings file will be saved
+        pub settings_file_path: String = "+*c",
+        /// If true, automatically saves to session file (bypasses path argument)
+        pub save_session_settings: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.LayoutLoad`: load a layout from a specified .ini file.
+    pub struct UtilLayoutLoad: "Util.LayoutLoad" {
+        /// Path to the layout file to load
+        pub layout_file_path: String = "+*c",
+        /// If true, automatically loads from session file (bypasses path argument)
+        pub load_session_layout: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.LayoutSave`: save current layout to a specified .ini file.
+    pub struct UtilLayoutSave: "Util.LayoutSave" {
+        /// Path where the layout file will be saved
+        pub layout_file_path: String = "+*c",
+        /// If true, automatically saves to session file (bypasses path argument)
+        pub save_session_layout: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.Lock`: lock the Nanonis software interface.
+    pub struct UtilLock: "Util.Lock" {} -> ()
+}
+
+nanonis_command! {
+    /// `Util.UnLock`: unlock the Nanonis software interface.
+    pub struct UtilUnLock: "Util.UnLock" {} -> ()
+}
+
+nanonis_command! {
+    /// `Util.RTFreqSet`: set the Real Time controller frequency.
+    pub struct UtilRtFreqSet: "Util.RTFreqSet" {
+        /// RT frequency in Hz
+        pub rt_frequency: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.RTFreqGet`: get the Real Time controller frequency.
+    pub struct UtilRtFreqGet: "Util.RTFreqGet" {} -> UtilRtFreqGetResponse {
+        /// RT frequency in Hz
+        pub rt_frequency: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Util.AcqPeriodSet`: set the Acquisition Period in the TCP Receiver.
+    pub struct UtilAcqPeriodSet: "Util.AcqPeriodSet" {
+        /// Acquisition period in seconds
+        pub acquisition_period: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.AcqPeriodGet`: get the Acquisition Period from the TCP Receiver.
+    pub struct UtilAcqPeriodGet: "Util.AcqPeriodGet" {} -> UtilAcqPeriodGetResponse {
+        /// Acquisition period in seconds
+        pub acquisition_period: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `Util.RTOversamplSet`: set the Real-time oversampling in the TCP Receiver.
+    pub struct UtilRtOversamplSet: "Util.RTOversamplSet" {
+        /// RT oversampling factor
+        pub rt_oversampling: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.RTOversamplGet`: get the Real-time oversampling from the TCP Receiver.
+    pub struct UtilRtOversamplGet: "Util.RTOversamplGet" {} -> UtilRtOversamplGetResponse {
+        /// RT oversampling factor
+        pub rt_oversampling: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `Util.Quit`: quit the Nanonis software with options to save settings, layout, and signals.
+    pub struct UtilQuit: "Util.Quit" {
+        /// If true, uses stored quit preferences (ignores other arguments)
+        pub use_stored_values: u32 = "I",
+        /// Name of settings file to save (empty string = don't save)
+        pub settings_name: String = "+*c",
+        /// Name of layout file to save (empty string = don't save)
+        pub layout_name: String = "+*c",
+        /// If true, saves signal configuration
+        pub save_signals: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `Util.VersionGet`: get version information from the Nanonis software.
+    pub struct UtilVersionGet: "Util.VersionGet" {} -> UtilVersionGetResponse {
+        /// Length of the product line name in bytes
+        pub product_line_size: i32 = "i",
+        /// Product line name (e.g., "Nanonis SPM Control Software" or "Nanonis Tramea Software")
+        pub product_line: String = "*-c",
+        /// Length of the version string in bytes
+        pub version_size: i32 = "i",
+        /// Software version string (e.g., "Generic 5")
+        pub version: String = "*-c",
+        /// Host application release number
+        pub host_app_release: u32 = "I",
+        /// RT Engine application release number
+        pub rt_engine_release: u32 = "I",
+    }
+}
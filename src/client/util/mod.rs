@@ -1,6 +1,7 @@
+pub mod commands;
+
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
 
 /// Version information returned by the Nanonis software.
 ///
@@ -41,14 +42,8 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_session_path_get(&mut self) -> Result<String, NanonisError> {
-        let result = self.quick_send("Util.SessionPathGet", vec![], vec![], vec!["i", "*-c"])?;
-
-        match result.get(1) {
-            Some(value) => Ok(value.as_string()?.to_string()),
-            None => Err(NanonisError::Protocol(
-                "No session path returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::UtilSessionPathGet {})?;
+        Ok(response.session_path)
     }
 
     /// Load settings from a specified .ini file.
@@ -81,15 +76,10 @@ impl NanonisClient {
         settings_file_path: &str,
         load_session_settings: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.SettingsLoad",
-            vec![
-                NanonisValue::String(settings_file_path.to_string()),
-                NanonisValue::U32(if load_session_settings { 1 } else { 0 }),
-            ],
-            vec!["+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::UtilSettingsLoad {
+            settings_file_path: settings_file_path.to_string(),
+            load_session_settings: if load_session_settings { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -123,15 +113,10 @@ impl NanonisClient {
         settings_file_path: &str,
         save_session_settings: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.SettingsSave",
-            vec![
-                NanonisValue::String(settings_file_path.to_string()),
-                NanonisValue::U32(if save_session_settings { 1 } else { 0 }),
-            ],
-            vec!["+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::UtilSettingsSave {
+            settings_file_path: settings_file_path.to_string(),
+            save_session_settings: if save_session_settings { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -165,15 +150,10 @@ impl NanonisClient {
         layout_file_path: &str,
         load_session_layout: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.LayoutLoad",
-            vec![
-                NanonisValue::String(layout_file_path.to_string()),
-                NanonisValue::U32(if load_session_layout { 1 } else { 0 }),
-            ],
-            vec!["+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::UtilLayoutLoad {
+            layout_file_path: layout_file_path.to_string(),
+            load_session_layout: if load_session_layout { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -207,15 +187,10 @@ impl NanonisClient {
         layout_file_path: &str,
         save_session_layout: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.LayoutSave",
-            vec![
-                NanonisValue::String(layout_file_path.to_string()),
-                NanonisValue::U32(if save_session_layout { 1 } else { 0 }),
-            ],
-            vec!["+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::UtilLayoutSave {
+            layout_file_path: layout_file_path.to_string(),
+            save_session_layout: if save_session_layout { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -248,7 +223,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_lock(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Util.Lock", vec![], vec![], vec![])?;
+        self.send(&commands::UtilLock {})?;
         Ok(())
     }
 
@@ -272,7 +247,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_unlock(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("Util.UnLock", vec![], vec![], vec![])?;
+        self.send(&commands::UtilUnLock {})?;
         Ok(())
     }
 
@@ -298,12 +273,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_rt_freq_set(&mut self, rt_frequency: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.RTFreqSet",
-            vec![NanonisValue::F32(rt_frequency)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::UtilRtFreqSet { rt_frequency })?;
         Ok(())
     }
 
@@ -328,14 +298,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_rt_freq_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Util.RTFreqGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::UtilRtFreqGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No RT frequency returned".to_string(),
-            )),
-        }
+        Ok(response.rt_frequency)
     }
 
     /// Set the Acquisition Period in the TCP Receiver.
@@ -360,12 +325,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_acq_period_set(&mut self, acquisition_period: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.AcqPeriodSet",
-            vec![NanonisValue::F32(acquisition_period)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::UtilAcqPeriodSet { acquisition_period })?;
         Ok(())
     }
 
@@ -390,14 +350,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_acq_period_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("Util.AcqPeriodGet", vec![], vec![], vec!["f"])?;
+        let response = self.send(&commands::UtilAcqPeriodGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol(
-                "No acquisition period returned".to_string(),
-            )),
-        }
+        Ok(response.acquisition_period)
     }
 
     /// Set the Real-time oversampling in the TCP Receiver.
@@ -423,12 +378,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_rt_oversampl_set(&mut self, rt_oversampling: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.RTOversamplSet",
-            vec![NanonisValue::I32(rt_oversampling)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::UtilRtOversamplSet { rt_oversampling })?;
         Ok(())
     }
 
@@ -453,14 +403,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_rt_oversampl_get(&mut self) -> Result<i32, NanonisError> {
-        let result = self.quick_send("Util.RTOversamplGet", vec![], vec![], vec!["i"])?;
+        let response = self.send(&commands::UtilRtOversamplGet {})?;
 
-        match result.first() {
-            Some(value) => Ok(value.as_i32()?),
-            None => Err(NanonisError::Protocol(
-                "No RT oversampling returned".to_string(),
-            )),
-        }
+        Ok(response.rt_oversampling)
     }
 
     /// Quit the Nanonis software with options to save settings, layout, and signals.
@@ -503,17 +448,12 @@ impl NanonisClient {
         layout_name: &str,
         save_signals: bool,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "Util.Quit",
-            vec![
-                NanonisValue::U32(if use_stored_values { 1 } else { 0 }),
-                NanonisValue::String(settings_name.to_string()),
-                NanonisValue::String(layout_name.to_string()),
-                NanonisValue::U32(if save_signals { 1 } else { 0 }),
-            ],
-            vec!["I", "+*c", "+*c", "I"],
-            vec![],
-        )?;
+        self.send(&commands::UtilQuit {
+            use_stored_values: if use_stored_values { 1 } else { 0 },
+            settings_name: settings_name.to_string(),
+            layout_name: layout_name.to_string(),
+            save_signals: if save_signals { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -543,24 +483,13 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn util_version_get(&mut self) -> Result<VersionInfo, NanonisError> {
-        let result = self.quick_send(
-            "Util.VersionGet",
-            vec![],
-            vec![],
-            vec!["+*c", "+*c", "I", "I"],
-        )?;
+        let response = self.send(&commands::UtilVersionGet {})?;
 
-        if result.len() >= 4 {
-            Ok(VersionInfo {
-                product_line: result[0].as_string()?.to_string(),
-                version: result[1].as_string()?.to_string(),
-                host_app_release: result[2].as_u32()?,
-                rt_engine_release: result[3].as_u32()?,
-            })
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid version info response".to_string(),
-            ))
-        }
+        Ok(VersionInfo {
+            product_line: response.product_line,
+            version: response.version,
+            host_app_release: response.host_app_release,
+            rt_engine_release: response.rt_engine_release,
+        })
     }
 }
@@ -0,0 +1,209 @@
+//! Typed descriptors for the `ZCtrl.*` commands.
+
+use crate::nanonis_command;
+
+nanonis_command! {
+    /// `ZCtrl.OnOffSet`: switch the Z-controller on or off.
+    pub struct ZCtrlOnOffSet: "ZCtrl.OnOffSet" {
+        /// 0 = off, 1 = on
+        pub status: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.OnOffGet`: get whether the Z-controller is on.
+    pub struct ZCtrlOnOffGet: "ZCtrl.OnOffGet" {} -> ZCtrlOnOffGetResponse {
+        /// 0 = off, 1 = on
+        pub status: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.ZPosSet`: set the Z position of the tip.
+    pub struct ZCtrlZPosSet: "ZCtrl.ZPosSet" {
+        /// Z position in meters
+        pub z_position: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.ZPosGet`: get the Z position of the tip.
+    pub struct ZCtrlZPosGet: "ZCtrl.ZPosGet" {} -> ZCtrlZPosGetResponse {
+        /// Z position in meters
+        pub z_position: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.SetpntSet`: set the Z-controller setpoint.
+    pub struct ZCtrlSetpntSet: "ZCtrl.SetpntSet" {
+        /// Setpoint in units of the feedback signal
+        pub setpoint: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.SetpntGet`: get the Z-controller setpoint.
+    pub struct ZCtrlSetpntGet: "ZCtrl.SetpntGet" {} -> ZCtrlSetpntGetResponse {
+        /// Setpoint in units of the feedback signal
+        pub setpoint: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.GainSet`: set the Z-controller gains.
+    pub struct ZCtrlGainSet: "ZCtrl.GainSet" {
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant: f32 = "f",
+        /// Integral gain
+        pub i_gain: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.GainGet`: get the Z-controller gains.
+    pub struct ZCtrlGainGet: "ZCtrl.GainGet" {} -> ZCtrlGainGetResponse {
+        /// Proportional gain
+        pub p_gain: f32 = "f",
+        /// Time constant in seconds
+        pub time_constant: f32 = "f",
+        /// Integral gain
+        pub i_gain: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.Home`: move the tip to its home position.
+    pub struct ZCtrlHome: "ZCtrl.Home" {} -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.Withdraw`: switch off the Z-controller and withdraw the tip.
+    pub struct ZCtrlWithdraw: "ZCtrl.Withdraw" {
+        /// 0 = return immediately, 1 = wait until withdrawn
+        pub wait_until_finished: u32 = "I",
+        /// Timeout in milliseconds, -1 waits forever
+        pub timeout_ms: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.LimitsSet`: set the Z position limits.
+    pub struct ZCtrlLimitsSet: "ZCtrl.LimitsSet" {
+        /// High Z limit in meters
+        pub high_limit: f32 = "f",
+        /// Low Z limit in meters
+        pub low_limit: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.LimitsGet`: get the Z position limits.
+    pub struct ZCtrlLimitsGet: "ZCtrl.LimitsGet" {} -> ZCtrlLimitsGetResponse {
+        /// High Z limit in meters
+        pub high_limit: f32 = "f",
+        /// Low Z limit in meters
+        pub low_limit: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.StatusGet`: get the Z-controller status.
+    pub struct ZCtrlStatusGet: "ZCtrl.StatusGet" {} -> ZCtrlStatusGetResponse {
+        /// 1 = off, 2 = on, 3 = hold, 4 = switching off, 5 = safe tip,
+        /// 6 = withdrawing
+        pub status: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.SwitchOffDelaySet`: set the Z-Controller switch-off delay.
+    pub struct ZCtrlSwitchOffDelaySet: "ZCtrl.SwitchOffDelaySet" {
+        /// Switch-off delay in seconds
+        pub delay_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.SwitchOffDelayGet`: get the Z-Controller switch-off delay.
+    pub struct ZCtrlSwitchOffDelayGet: "ZCtrl.SwitchOffDelayGet" {} -> ZCtrlSwitchOffDelayGetResponse {
+        /// Switch-off delay in seconds
+        pub delay_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.HomePropsSet`: set the home position properties.
+    pub struct ZCtrlHomePropsSet: "ZCtrl.HomePropsSet" {
+        /// Home position mode (0=no change, 1=absolute, 2=relative)
+        pub home_mode: u16 = "H",
+        /// Home position in meters
+        pub home_position_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.HomePropsGet`: get the home position properties.
+    pub struct ZCtrlHomePropsGet: "ZCtrl.HomePropsGet" {} -> ZCtrlHomePropsGetResponse {
+        /// Home position mode (0=no change, 1=absolute, 2=relative)
+        pub home_mode: u16 = "H",
+        /// Home position in meters
+        pub home_position_m: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.ActiveCtrlSet`: set the active Z-Controller.
+    pub struct ZCtrlActiveCtrlSet: "ZCtrl.ActiveCtrlSet" {
+        /// Controller index from the list
+        pub controller_index: i32 = "i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.CtrlListGet`: get the list of Z-Controllers and active controller index.
+    pub struct ZCtrlCtrlListGet: "ZCtrl.CtrlListGet" {} -> ZCtrlCtrlListGetResponse {
+        /// Size of the names array in bytes
+        pub names_size: i32 = "i",
+        /// Number of names
+        pub num_names: i32 = "i",
+        /// Names of the available controllers
+        pub names: Vec<String> = "*+c",
+        /// Index of the active controller
+        pub active_index: i32 = "i",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.WithdrawRateSet`: set the withdraw slew rate.
+    pub struct ZCtrlWithdrawRateSet: "ZCtrl.WithdrawRateSet" {
+        /// Withdraw rate in m/s
+        pub rate_m_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.WithdrawRateGet`: get the withdraw slew rate.
+    pub struct ZCtrlWithdrawRateGet: "ZCtrl.WithdrawRateGet" {} -> ZCtrlWithdrawRateGetResponse {
+        /// Withdraw rate in m/s
+        pub rate_m_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZCtrl.LimitsEnabledSet`: enable or disable Z position limits.
+    pub struct ZCtrlLimitsEnabledSet: "ZCtrl.LimitsEnabledSet" {
+        /// True to enable limits
+        pub enabled: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZCtrl.LimitsEnabledGet`: get the Z position limits enabled status.
+    pub struct ZCtrlLimitsEnabledGet: "ZCtrl.LimitsEnabledGet" {} -> ZCtrlLimitsEnabledGetResponse {
+        /// True to enable limits
+        pub enabled: u32 = "I",
+    }
+}
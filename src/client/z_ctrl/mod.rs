@@ -1,11 +1,13 @@
 mod types;
 pub use types::*;
 
+pub mod commands;
+
 use std::time::Duration;
 
 use super::NanonisClient;
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::units::{Meters, Seconds, Setpoint};
 
 impl NanonisClient {
     /// Switch the Z-Controller on or off.
@@ -38,13 +40,9 @@ impl NanonisClient {
     ) -> Result<(), NanonisError> {
         let status_flag = if controller_on { 1u32 } else { 0u32 };
 
-        self.quick_send(
-            "ZCtrl.OnOffSet",
-            vec![NanonisValue::U32(status_flag)],
-            vec!["I"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ZCtrlOnOffSet {
+            status: status_flag,
+        })
     }
 
     /// Get the current status of the Z-Controller.
@@ -74,14 +72,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_on_off_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("ZCtrl.OnOffGet", vec![], vec![], vec!["I"])?;
-
-        match result.first() {
-            Some(value) => Ok(value.as_u32()? == 1),
-            None => Err(NanonisError::Protocol(
-                "No Z-controller status returned".to_string(),
-            )),
-        }
+        Ok(self.send(&commands::ZCtrlOnOffGet {})?.status == 1)
     }
 
     /// Set the Z position of the tip.
@@ -90,7 +81,8 @@ impl NanonisClient {
     /// This function directly sets the tip's Z coordinate for manual positioning.
     ///
     /// # Arguments
-    /// * `z_position_m` - Z position in meters
+    /// * `z_position_m` - Z position as [`Meters`](crate::Meters); a raw value in
+    ///   meters goes through [`Meters::new`](crate::Meters::new)
     ///
     /// # Errors
     /// Returns `NanonisError` if:
@@ -100,7 +92,7 @@ impl NanonisClient {
     ///
     /// # Examples
     /// ```no_run
-    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::{Meters, NanonisClient};
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
@@ -108,23 +100,33 @@ impl NanonisClient {
     /// client.z_ctrl_on_off_set(false)?;
     ///
     /// // Move tip to specific Z position (10 nm above surface)
-    /// client.z_ctrl_z_pos_set(10e-9)?;
+    /// client.z_ctrl_z_pos_set(Meters::nanometers(10.0))?;
     ///
     /// // Move tip closer to surface (2 nm)
-    /// client.z_ctrl_z_pos_set(2e-9)?;
+    /// client.z_ctrl_z_pos_set(Meters::nanometers(2.0))?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// A raw value, e.g. read from a config file, is wrapped explicitly:
+    /// ```
+    /// use nanonis_rs::{Meters, MockServer};
+    ///
+    /// let server = MockServer::simulated()?;
+    /// let mut client = server.client()?;
+    /// client.z_ctrl_on_off_set(false)?;
+    ///
+    /// let raw_meters: f64 = 5e-9;
+    /// client.z_ctrl_z_pos_set(Meters::new(raw_meters))?;
+    /// assert_eq!(client.z_ctrl_z_pos_get()?, 5e-9);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn z_ctrl_z_pos_set(
         &mut self,
-        z_position_m: f32,
+        z_position_m: impl Into<Meters>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.ZPosSet",
-            vec![NanonisValue::F32(z_position_m)],
-            vec!["f"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ZCtrlZPosSet {
+            z_position: z_position_m.into().as_f32(),
+        })
     }
 
     /// Get the current Z position of the tip.
@@ -154,14 +156,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_z_pos_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("ZCtrl.ZPosGet", vec![], vec![], vec!["f"])?;
-
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => {
-                Err(NanonisError::Protocol("No Z position returned".to_string()))
-            }
-        }
+        Ok(self.send(&commands::ZCtrlZPosGet {})?.z_position)
     }
 
     /// Set the setpoint of the Z-Controller.
@@ -170,35 +165,46 @@ impl NanonisClient {
     /// tries to maintain by adjusting the tip-sample distance.
     ///
     /// # Arguments
-    /// * `setpoint` - Z-controller setpoint value (units depend on feedback signal)
+    /// * `setpoint` - Z-controller setpoint in the unit of the feedback signal,
+    ///   e.g. [`Amperes`](crate::Amperes); a raw value goes through
+    ///   [`Setpoint::new`], see [`Setpoint`]
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails or protocol error occurs.
     ///
     /// # Examples
     /// ```no_run
-    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::{Amperes, NanonisClient, Setpoint};
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
     /// // Set tunneling current setpoint to 100 pA
-    /// client.z_ctrl_setpoint_set(100e-12)?;
+    /// client.z_ctrl_setpoint_set(Amperes::picoamperes(100.0))?;
     ///
-    /// // Set force setpoint for AFM mode
-    /// client.z_ctrl_setpoint_set(1e-9)?;  // 1 nN
+    /// // Set force setpoint for AFM mode, in the unit of the signal
+    /// client.z_ctrl_setpoint_set(Setpoint::new(1e-9))?;  // 1 nN
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// A raw value, e.g. read from a config file, is wrapped explicitly:
+    /// ```
+    /// use nanonis_rs::{MockServer, Setpoint};
+    ///
+    /// let server = MockServer::simulated()?;
+    /// let mut client = server.client()?;
+    ///
+    /// let raw_setpoint: f64 = 2e-10;
+    /// client.z_ctrl_setpoint_set(Setpoint::new(raw_setpoint))?;
+    /// assert_eq!(client.z_ctrl_setpoint_get()?, 2e-10);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
     pub fn z_ctrl_setpoint_set(
         &mut self,
-        setpoint: f32,
+        setpoint: impl Into<Setpoint>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.SetpntSet",
-            vec![NanonisValue::F32(setpoint)],
-            vec!["f"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ZCtrlSetpntSet {
+            setpoint: setpoint.into().as_f32(),
+        })
     }
 
     /// Get the current setpoint of the Z-Controller.
@@ -222,13 +228,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_setpoint_get(&mut self) -> Result<f32, NanonisError> {
-        let result =
-            self.quick_send("ZCtrl.SetpntGet", vec![], vec![], vec!["f"])?;
-
-        match result.first() {
-            Some(value) => Ok(value.as_f32()?),
-            None => Err(NanonisError::Protocol("No setpoint returned".to_string())),
-        }
+        Ok(self.send(&commands::ZCtrlSetpntGet {})?.setpoint)
     }
 
     /// Set the Z-Controller gains and time settings.
@@ -247,34 +247,28 @@ impl NanonisClient {
     ///
     /// # Examples
     /// ```no_run
-    /// use nanonis_rs::NanonisClient;
+    /// use nanonis_rs::{NanonisClient, Seconds};
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
     /// // Set moderate feedback gains for stable operation
-    /// client.z_ctrl_gain_set(1.0, 0.1, 10.0)?;
+    /// client.z_ctrl_gain_set(1.0, Seconds::milliseconds(100.0), 10.0)?;
     ///
     /// // Set aggressive gains for fast response
-    /// client.z_ctrl_gain_set(5.0, 0.05, 100.0)?;
+    /// client.z_ctrl_gain_set(5.0, Seconds::milliseconds(50.0), 100.0)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_gain_set(
         &mut self,
         p_gain: f32,
-        time_constant_s: f32,
+        time_constant_s: impl Into<Seconds>,
         i_gain: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.GainSet",
-            vec![
-                NanonisValue::F32(p_gain),
-                NanonisValue::F32(time_constant_s),
-                NanonisValue::F32(i_gain),
-            ],
-            vec!["f", "f", "f"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ZCtrlGainSet {
+            p_gain,
+            time_constant: time_constant_s.into().as_f32(),
+            i_gain,
+        })
     }
 
     /// Get the current Z-Controller gains and time settings.
@@ -306,18 +300,8 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_gain_get(&mut self) -> Result<(f32, f32, f32), NanonisError> {
-        let result =
-            self.quick_send("ZCtrl.GainGet", vec![], vec![], vec!["f", "f", "f"])?;
-
-        if result.len() >= 3 {
-            Ok((
-                result[0].as_f32()?,
-                result[1].as_f32()?,
-                result[2].as_f32()?,
-            ))
-        } else {
-            Err(NanonisError::Protocol("Invalid gain response".to_string()))
-        }
+        let gains = self.send(&commands::ZCtrlGainGet {})?;
+        Ok((gains.p_gain, gains.time_constant, gains.i_gain))
     }
 
     /// Move the tip to its home position.
@@ -347,8 +331,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_ctrl_home(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("ZCtrl.Home", vec![], vec![], vec![])?;
-        Ok(())
+        self.send(&commands::ZCtrlHome {})
     }
 
     /// Withdraw the tip.
@@ -385,16 +368,10 @@ impl NanonisClient {
         timeout_ms: Duration,
     ) -> Result<(), NanonisError> {
         let wait_flag = if wait_until_finished { 1u32 } else { 0u32 };
-        self.quick_send(
-            "ZCtrl.Withdraw",
-            vec![
-                NanonisValue::U32(wait_flag),
-                NanonisValue::I32(timeout_ms.as_millis() as i32),
-            ],
-            vec!["I", "i"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ZCtrlWithdraw {
+            wait_until_finished: wait_flag,
+            timeout_ms: timeout_ms.as_millis() as i32,
+        })
     }
 
     /// Set the Z-Controller switch-off delay.
@@ -407,13 +384,11 @@ impl NanonisClient {
     ///
     /// # Errors
     /// Returns `NanonisError` if communication fails.
-    pub fn z_ctrl_switch_off_delay_set(&mut self, delay_s: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.SwitchOffDelaySet",
-            vec![NanonisValue::F32(delay_s)],
-            vec!["f"],
-            vec![],
-        )?;
+    pub fn z_ctrl_switch_off_delay_set(
+        &mut self,
+        delay_s: impl Into<Seconds>,
+    ) -> Result<(), NanonisError> {
+        self.send(&commands::ZCtrlSwitchOffDelaySet { delay_s: delay_s.into().as_f32() })?;
         Ok(())
     }
 
@@ -425,8 +400,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_switch_off_delay_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("ZCtrl.SwitchOffDelayGet", vec![], vec![], vec!["f"])?;
-        result[0].as_f32()
+        let response = self.send(&commands::ZCtrlSwitchOffDelayGet {})?;
+        Ok(response.delay_s)
     }
 
     // NOTE: z_ctrl_tip_lift_set/get are implemented in safe_tip.rs
@@ -442,17 +417,12 @@ impl NanonisClient {
     pub fn z_ctrl_home_props_set(
         &mut self,
         home_mode: u16,
-        home_position_m: f32,
+        home_position_m: impl Into<Meters>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.HomePropsSet",
-            vec![
-                NanonisValue::U16(home_mode),
-                NanonisValue::F32(home_position_m),
-            ],
-            vec!["H", "f"],
-            vec![],
-        )?;
+        self.send(&commands::ZCtrlHomePropsSet {
+            home_mode,
+            home_position_m: home_position_m.into().as_f32(),
+        })?;
         Ok(())
     }
 
@@ -464,9 +434,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_home_props_get(&mut self) -> Result<(bool, f32), NanonisError> {
-        let result = self.quick_send("ZCtrl.HomePropsGet", vec![], vec![], vec!["H", "f"])?;
+        let response = self.send(&commands::ZCtrlHomePropsGet {})?;
 
-        Ok((result[0].as_u16()? != 0, result[1].as_f32()?))
+        Ok((response.home_mode != 0, response.home_position_m))
     }
 
     /// Set the active Z-Controller.
@@ -477,12 +447,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_active_ctrl_set(&mut self, controller_index: i32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.ActiveCtrlSet",
-            vec![NanonisValue::I32(controller_index)],
-            vec!["i"],
-            vec![],
-        )?;
+        self.send(&commands::ZCtrlActiveCtrlSet { controller_index })?;
         Ok(())
     }
 
@@ -494,14 +459,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_ctrl_list_get(&mut self) -> Result<(Vec<String>, i32), NanonisError> {
-        let result = self.quick_send(
-            "ZCtrl.CtrlListGet",
-            vec![],
-            vec![],
-            vec!["i", "i", "*+c", "i"],
-        )?;
+        let response = self.send(&commands::ZCtrlCtrlListGet {})?;
 
-        Ok((result[2].as_string_array()?.to_vec(), result[3].as_i32()?))
+        Ok((response.names, response.active_index))
     }
 
     /// Set the withdraw slew rate.
@@ -512,12 +472,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_withdraw_rate_set(&mut self, rate_m_s: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.WithdrawRateSet",
-            vec![NanonisValue::F32(rate_m_s)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::ZCtrlWithdrawRateSet { rate_m_s })?;
         Ok(())
     }
 
@@ -529,8 +484,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_withdraw_rate_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("ZCtrl.WithdrawRateGet", vec![], vec![], vec!["f"])?;
-        result[0].as_f32()
+        let response = self.send(&commands::ZCtrlWithdrawRateGet {})?;
+        Ok(response.rate_m_s)
     }
 
     /// Enable or disable Z position limits.
@@ -541,12 +496,9 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_limits_enabled_set(&mut self, enabled: bool) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.LimitsEnabledSet",
-            vec![NanonisValue::U32(if enabled { 1 } else { 0 })],
-            vec!["I"],
-            vec![],
-        )?;
+        self.send(&commands::ZCtrlLimitsEnabledSet {
+            enabled: if enabled { 1 } else { 0 },
+        })?;
         Ok(())
     }
 
@@ -558,8 +510,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_limits_enabled_get(&mut self) -> Result<bool, NanonisError> {
-        let result = self.quick_send("ZCtrl.LimitsEnabledGet", vec![], vec![], vec!["I"])?;
-        Ok(result[0].as_u32()? != 0)
+        let response = self.send(&commands::ZCtrlLimitsEnabledGet {})?;
+        Ok(response.enabled != 0)
     }
 
     /// Set the Z position limits.
@@ -572,19 +524,13 @@ impl NanonisClient {
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_limits_set(
         &mut self,
-        high_limit_m: f32,
-        low_limit_m: f32,
+        high_limit_m: impl Into<Meters>,
+        low_limit_m: impl Into<Meters>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZCtrl.LimitsSet",
-            vec![
-                NanonisValue::F32(high_limit_m),
-                NanonisValue::F32(low_limit_m),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
-        Ok(())
+        self.send(&commands::ZCtrlLimitsSet {
+            high_limit: high_limit_m.into().as_f32(),
+            low_limit: low_limit_m.into().as_f32(),
+        })
     }
 
     /// Get the Z position limits.
@@ -595,9 +541,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_limits_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result = self.quick_send("ZCtrl.LimitsGet", vec![], vec![], vec!["f", "f"])?;
-
-        Ok((result[0].as_f32()?, result[1].as_f32()?))
+        let limits = self.send(&commands::ZCtrlLimitsGet {})?;
+        Ok((limits.high_limit, limits.low_limit))
     }
 
     /// Get the current Z-Controller status.
@@ -608,7 +553,6 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_ctrl_status_get(&mut self) -> Result<ZControllerStatus, NanonisError> {
-        let result = self.quick_send("ZCtrl.StatusGet", vec![], vec![], vec!["H"])?;
-        ZControllerStatus::try_from(result[0].as_u16()?)
+        ZControllerStatus::try_from(self.send(&commands::ZCtrlStatusGet {})?.status)
     }
 }
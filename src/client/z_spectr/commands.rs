@@ -0,0 +1,329 @@
+//! Typed descriptors for the `ZSpectr.*` commands.
+
+use crate::nanonis_command;
+use crate::types::Array2D;
+
+nanonis_command! {
+    /// `ZSpectr.Open`: open the Z Spectroscopy module.
+    pub struct ZSpectrOpen: "ZSpectr.Open" {} -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.Start`: start a Z spectroscopy measurement.
+    pub struct ZSpectrStart: "ZSpectr.Start" blocking if get_data {
+        /// If `true`, returns measurement data; if `false`, only starts measurement
+        pub get_data: u32 = "I",
+        /// Base filename for saving data (empty for no change)
+        pub save_base_name: String = "+*c",
+    } -> ZSpectrStartResponse {
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Channel names
+        pub channel_names: Vec<String> = "*+c",
+        /// Number of rows
+        pub rows: i32 = "i",
+        /// Number of columns
+        pub cols: i32 = "i",
+        /// 2D measurement data, one row per channel
+        pub data_2d: Array2D<f32> = "2f",
+        /// Number of parameters
+        pub num_parameters: i32 = "i",
+        /// Fixed parameters and settings
+        pub parameters: Vec<f32> = "*f",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.Stop`: stop the current Z spectroscopy measurement.
+    pub struct ZSpectrStop: "ZSpectr.Stop" {} -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.StatusGet`: get the status of Z spectroscopy measurement.
+    pub struct ZSpectrStatusGet: "ZSpectr.StatusGet" {} -> ZSpectrStatusGetResponse {
+        /// 1 if a measurement is running
+        pub running: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.ChsSet`: set the channels to record during Z spectroscopy.
+    pub struct ZSpectrChsSet: "ZSpectr.ChsSet" {
+        /// Channel indexes (0-23 for Signals Manager slots)
+        pub channel_indexes: Vec<i32> = "+*i",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.ChsGet`: get the channels configured for Z spectroscopy recording.
+    pub struct ZSpectrChsGet: "ZSpectr.ChsGet" {} -> ZSpectrChsGetResponse {
+        /// Number of channel indexes
+        pub num_channel_indexes: i32 = "i",
+        /// Channel indexes (0-23 for Signals Manager slots)
+        pub channel_indexes: Vec<i32> = "*i",
+        /// Size of the channel names array in bytes
+        pub channel_names_size: i32 = "i",
+        /// Number of channel names
+        pub num_channel_names: i32 = "i",
+        /// Channel names corresponding to the indexes
+        pub channel_names: Vec<String> = "*+c",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.RangeSet`: set the Z range for spectroscopy measurements.
+    pub struct ZSpectrRangeSet: "ZSpectr.RangeSet" {
+        /// Z offset position in meters (center of sweep)
+        pub z_offset_m: f32 = "f",
+        /// Total sweep distance in meters
+        pub z_sweep_distance_m: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.RangeGet`: get the current Z range configuration for spectroscopy.
+    pub struct ZSpectrRangeGet: "ZSpectr.RangeGet" {} -> ZSpectrRangeGetResponse {
+        /// Z offset position in meters (center of sweep)
+        pub z_offset_m: f32 = "f",
+        /// Total sweep distance in meters
+        pub z_sweep_distance_m: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.TimingSet`: set the timing parameters for Z spectroscopy.
+    pub struct ZSpectrTimingSet: "ZSpectr.TimingSet" {
+        /// Time to average signals at each Z position
+        pub z_averaging_time_s: f32 = "f",
+        /// Initial settling time before measurement
+        pub initial_settling_time_s: f32 = "f",
+        /// Maximum slew rate in V/s
+        pub maximum_slew_rate_vdivs: f32 = "f",
+        /// Settling time between measurement points
+        pub settling_time_s: f32 = "f",
+        /// Integration time for each measurement point
+        pub integration_time_s: f32 = "f",
+        /// Settling time at the end of sweep
+        pub end_settling_time_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.TimingGet`: get the current timing parameters for Z spectroscopy.
+    pub struct ZSpectrTimingGet: "ZSpectr.TimingGet" {} -> ZSpectrTimingGetResponse {
+        /// Time to average signals at each Z position
+        pub z_averaging_time_s: f32 = "f",
+        /// Initial settling time before measurement
+        pub initial_settling_time_s: f32 = "f",
+        /// Maximum slew rate in V/s
+        pub maximum_slew_rate_vdivs: f32 = "f",
+        /// Settling time between measurement points
+        pub settling_time_s: f32 = "f",
+        /// Integration time for each measurement point
+        pub integration_time_s: f32 = "f",
+        /// Settling time at the end of sweep
+        pub end_settling_time_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.RetractSet`: set the retraction parameters for tip protection during Z spectroscopy.
+    pub struct ZSpectrRetractSet: "ZSpectr.RetractSet" {
+        /// Enable/disable automatic retraction
+        pub enable: u16 = "H",
+        /// Signal threshold value for retraction trigger
+        pub threshold: f32 = "f",
+        /// Signal index being monitored
+        pub signal_index: i32 = "i",
+        /// Comparison type: 0=greater than, 1=less than
+        pub comparison: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.RetractGet`: get the current retraction configuration for Z spectroscopy.
+    pub struct ZSpectrRetractGet: "ZSpectr.RetractGet" {} -> ZSpectrRetractGetResponse {
+        /// Retraction enabled/disabled
+        pub enabled: u16 = "H",
+        /// Threshold value for retraction
+        pub threshold: f32 = "f",
+        /// Signal index being monitored
+        pub signal_index: i32 = "i",
+        /// Comparison type (0=greater, 1=less than)
+        pub comparison: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.PropsSet`: set the Z spectroscopy properties.
+    pub struct ZSpectrPropsSet: "ZSpectr.PropsSet" {
+        /// 0=no change, 1=enable backward sweep, 2=disable
+        pub backward_sweep: u16 = "H",
+        /// Number of points (0=no change)
+        pub num_points: i32 = "i",
+        /// Number of sweeps to average (0=no change)
+        pub num_sweeps: u16 = "H",
+        /// 0=no change, 1=enable autosave, 2=disable
+        pub autosave: u16 = "H",
+        /// 0=no change, 1=show dialog, 2=don't show
+        pub show_save_dialog: u16 = "H",
+        /// 0=no change, 1=save individual sweeps, 2=don't save
+        pub save_all: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.PropsGet`: get the Z spectroscopy properties.
+    pub struct ZSpectrPropsGet: "ZSpectr.PropsGet" {} -> ZSpectrPropsGetResponse {
+        /// 0=no change, 1=enable backward sweep, 2=disable
+        pub backward_sweep: u16 = "H",
+        /// Number of points
+        pub num_points: i32 = "i",
+        /// Number of sweeps to average (0=no change)
+        pub num_sweeps: u16 = "H",
+        /// 0=no change, 1=enable autosave, 2=disable
+        pub autosave: u32 = "I",
+        /// 0=no change, 1=show dialog, 2=don't show
+        pub show_save_dialog: u32 = "I",
+        /// 0=no change, 1=save individual sweeps, 2=don't save
+        pub save_all: u32 = "I",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.AdvPropsSet`: set the advanced Z spectroscopy properties.
+    pub struct ZSpectrAdvPropsSet: "ZSpectr.AdvPropsSet" {
+        /// Time between forward and backward sweep
+        pub time_between_sweeps_s: f32 = "f",
+        /// 0=no change, 1=on, 2=off
+        pub record_final_z: u16 = "H",
+        /// 0=no change, 1=on, 2=off
+        pub lockin_run: u16 = "H",
+        /// 0=no change, 1=on, 2=off
+        pub reset_z: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.AdvPropsGet`: get the advanced Z spectroscopy properties.
+    pub struct ZSpectrAdvPropsGet: "ZSpectr.AdvPropsGet" {} -> ZSpectrAdvPropsGetResponse {
+        /// Time between forward and backward sweep
+        pub time_between_sweeps_s: f32 = "f",
+        /// 0=no change, 1=on, 2=off
+        pub record_final_z: u16 = "H",
+        /// 0=no change, 1=on, 2=off
+        pub lockin_run: u16 = "H",
+        /// 0=no change, 1=on, 2=off
+        pub reset_z: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.RetractDelaySet`: set the retract delay.
+    pub struct ZSpectrRetractDelaySet: "ZSpectr.RetractDelaySet" {
+        /// Delay in seconds between forward and backward sweep
+        pub delay_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.RetractDelayGet`: get the retract delay.
+    pub struct ZSpectrRetractDelayGet: "ZSpectr.RetractDelayGet" {} -> ZSpectrRetractDelayGetResponse {
+        /// Delay in seconds between forward and backward sweep
+        pub delay_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.RetractSecondSet`: set the second retraction condition.
+    pub struct ZSpectrRetractSecondSet: "ZSpectr.RetractSecondSet" {
+        /// 0=no change, 1=disabled, 2=OR, 3=AND, 4=THEN
+        pub condition: i32 = "i",
+        /// Threshold value
+        pub threshold: f32 = "f",
+        /// Index of the second retract signal
+        pub signal_index: i32 = "i",
+        /// 0=greater than, 1=less than, 2=no change
+        pub comparison: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.RetractSecondGet`: get the second retraction condition.
+    pub struct ZSpectrRetractSecondGet: "ZSpectr.RetractSecondGet" {} -> ZSpectrRetractSecondGetResponse {
+        /// 0=no change, 1=disabled, 2=OR, 3=AND, 4=THEN
+        pub condition: i32 = "i",
+        /// Threshold value
+        pub threshold: f32 = "f",
+        /// Index of the second retract signal
+        pub signal_index: i32 = "i",
+        /// 0=greater than, 1=less than, 2=no change
+        pub comparison: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.DigSyncSet`: set the digital synchronization mode.
+    pub struct ZSpectrDigSyncSet: "ZSpectr.DigSyncSet" {
+        /// 0=no change, 1=off, 2=TTL sync, 3=pulse sequence
+        pub dig_sync: u16 = "H",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.DigSyncGet`: get the digital synchronization mode.
+    pub struct ZSpectrDigSyncGet: "ZSpectr.DigSyncGet" {} -> ZSpectrDigSyncGetResponse {
+        /// 0=no change, 1=off, 2=TTL sync, 3=pulse sequence
+        pub dig_sync: u16 = "H",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.TTLSyncSet`: set the TTL synchronization parameters.
+    pub struct ZSpectrTtlSyncSet: "ZSpectr.TTLSyncSet" {
+        /// 0=no change, 1-4=HS line number
+        pub ttl_line: u16 = "H",
+        /// 0=no change, 1=low active, 2=high active
+        pub polarity: u16 = "H",
+        /// Time to wait before activation
+        pub time_to_on_s: f32 = "f",
+        /// Duration of activation
+        pub on_duration_s: f32 = "f",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.TTLSyncGet`: get the TTL synchronization parameters.
+    pub struct ZSpectrTtlSyncGet: "ZSpectr.TTLSyncGet" {} -> ZSpectrTtlSyncGetResponse {
+        /// 0=no change, 1-4=HS line number
+        pub ttl_line: u16 = "H",
+        /// 0=no change, 1=low active, 2=high active
+        pub polarity: u16 = "H",
+        /// Time to wait before activation
+        pub time_to_on_s: f32 = "f",
+        /// Duration of activation
+        pub on_duration_s: f32 = "f",
+    }
+}
+
+nanonis_command! {
+    /// `ZSpectr.PulseSeqSyncSet`: set the pulse sequence synchronization.
+    pub struct ZSpectrPulseSeqSyncSet: "ZSpectr.PulseSeqSyncSet" {
+        /// Pulse sequence number (0=no change)
+        pub pulse_seq_nr: u16 = "H",
+        /// Number of periods
+        pub num_periods: u32 = "I",
+    } -> ()
+}
+
+nanonis_command! {
+    /// `ZSpectr.PulseSeqSyncGet`: get the pulse sequence synchronization.
+    pub struct ZSpectrPulseSeqSyncGet: "ZSpectr.PulseSeqSyncGet" {} -> ZSpectrPulseSeqSyncGetResponse {
+        /// Pulse sequence number (0=no change)
+        pub pulse_seq_nr: u16 = "H",
+        /// Number of periods
+        pub num_periods: u32 = "I",
+    }
+}
@@ -1,9 +1,12 @@
+pub mod commands;
+
 use super::NanonisClient;
+use super::signals::{SignalRef, SlotRef};
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::Array2D;
 
 /// Return type for Z spectroscopy start operation (channel names, data, bias values)
-pub type ZSpectroscopyResult = (Vec<String>, Vec<Vec<f32>>, Vec<f32>);
+pub type ZSpectroscopyResult = (Vec<String>, Array2D<f32>, Vec<f32>);
 
 impl NanonisClient {
     /// Open the Z Spectroscopy module.
@@ -26,7 +29,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_spectr_open(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("ZSpectr.Open", vec![], vec![], vec![])?;
+        self.send(&commands::ZSpectrOpen {})?;
         Ok(())
     }
 
@@ -42,7 +45,7 @@ impl NanonisClient {
     /// # Returns
     /// If `get_data` is true, returns a tuple containing:
     /// - `Vec<String>` - Channel names
-    /// - `Vec<Vec<f32>>` - 2D measurement data \[rows\]\[columns\]
+    /// - `Array2D<f32>` - 2D measurement data, one row per channel
     /// - `Vec<f32>` - Fixed parameters and settings
     ///
     /// # Errors
@@ -69,37 +72,12 @@ impl NanonisClient {
     ) -> Result<ZSpectroscopyResult, NanonisError> {
         let get_data_flag = if get_data { 1u32 } else { 0u32 };
 
-        let result = self.quick_send(
-            "ZSpectr.Start",
-            vec![
-                NanonisValue::U32(get_data_flag),
-                NanonisValue::String(save_base_name.to_string()),
-            ],
-            vec!["I", "+*c"],
-            vec!["i", "i", "*+c", "i", "i", "2f", "i", "*f"],
-        )?;
-
-        if result.len() >= 8 {
-            let channel_names = result[2].as_string_array()?.to_vec();
-            let rows = result[3].as_i32()? as usize;
-            let cols = result[4].as_i32()? as usize;
-
-            // Parse 2D data array
-            let flat_data = result[5].as_f32_array()?;
-            let mut data_2d = Vec::with_capacity(rows);
-            for row in 0..rows {
-                let start_idx = row * cols;
-                let end_idx = start_idx + cols;
-                data_2d.push(flat_data[start_idx..end_idx].to_vec());
-            }
-
-            let parameters = result[7].as_f32_array()?.to_vec();
-            Ok((channel_names, data_2d, parameters))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Z spectroscopy start response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ZSpectrStart {
+            get_data: get_data_flag,
+            save_base_name: save_base_name.to_string(),
+        })?;
+
+        Ok((response.channel_names, response.data_2d, response.parameters))
     }
 
     /// Stop the current Z spectroscopy measurement.
@@ -125,7 +103,7 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_spectr_stop(&mut self) -> Result<(), NanonisError> {
-        self.quick_send("ZSpectr.Stop", vec![], vec![], vec![])?;
+        self.send(&commands::ZSpectrStop {})?;
         Ok(())
     }
 
@@ -153,27 +131,26 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_spectr_status_get(&mut self) -> Result<bool, NanonisError> {
-        let result =
-            self.quick_send("ZSpectr.StatusGet", vec![], vec![], vec!["I"])?;
-
-        match result.first() {
-            Some(value) => Ok(value.as_u32()? == 1),
-            None => Err(NanonisError::Protocol(
-                "No Z spectroscopy status returned".to_string(),
-            )),
-        }
+        let response = self.send(&commands::ZSpectrStatusGet {})?;
+
+        Ok(response.running == 1)
     }
 
     /// Set the channels to record during Z spectroscopy.
     ///
     /// Configures which signals will be recorded during the Z spectroscopy measurement.
-    /// Channel indexes correspond to the 24 signals assigned in the Signals Manager (0-23).
+    /// Z spectroscopy records the 24 signal slots of the Signals Manager.
+    /// Bare integers are slots (0-23), as returned by the matching getter;
+    /// names and [`SignalIndex`](crate::signals::SignalIndex)es are mapped
+    /// to the slot their signal is assigned to, see
+    /// [`SlotRef`](crate::signals::SlotRef).
     ///
     /// # Arguments
-    /// * `channel_indexes` - Vector of channel indexes to record (0-23)
+    /// * `channels` - Signals to record
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails or invalid channel indexes provided.
+    /// Returns `NanonisError` if a signal does not resolve or has no slot,
+    /// communication fails or invalid channel indexes provided.
     ///
     /// # Examples
     /// ```no_run
@@ -181,23 +158,22 @@ impl NanonisClient {
     ///
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
-    /// // Record current (0), Z position (1), and bias voltage (2)
-    /// client.z_spectr_chs_set(vec![0, 1, 2])?;
+    /// // Record current, Z position and bias voltage
+    /// client.z_spectr_chs_set(["Current", "Z", "Bias"])?;
     ///
-    /// // Record more comprehensive dataset
-    /// client.z_spectr_chs_set(vec![0, 1, 2, 3, 4, 5])?;
+    /// // Record slots 0 to 5
+    /// client.z_spectr_chs_set(0..6)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn z_spectr_chs_set(
+    pub fn z_spectr_chs_set<S: Into<SlotRef>>(
         &mut self,
-        channel_indexes: Vec<i32>,
+        channels: impl IntoIterator<Item = S>,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.ChsSet",
-            vec![NanonisValue::ArrayI32(channel_indexes)],
-            vec!["+*i"],
-            vec![],
-        )?;
+        let channel_indexes = channels
+            .into_iter()
+            .map(|channel| self.resolve_slot(channel))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.send(&commands::ZSpectrChsSet { channel_indexes })?;
         Ok(())
     }
 
@@ -229,22 +205,9 @@ impl NanonisClient {
     pub fn z_spectr_chs_get(
         &mut self,
     ) -> Result<(Vec<i32>, Vec<String>), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.ChsGet",
-            vec![],
-            vec![],
-            vec!["i", "*i", "i", "i", "*+c"],
-        )?;
-
-        if result.len() >= 5 {
-            let channel_indexes = result[1].as_i32_array()?.to_vec();
-            let channel_names = result[4].as_string_array()?.to_vec();
-            Ok((channel_indexes, channel_names))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Z spectroscopy channels response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ZSpectrChsGet {})?;
+
+        Ok((response.channel_indexes, response.channel_names))
     }
 
     /// Set the Z range for spectroscopy measurements.
@@ -277,15 +240,7 @@ impl NanonisClient {
         z_offset_m: f32,
         z_sweep_distance_m: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.RangeSet",
-            vec![
-                NanonisValue::F32(z_offset_m),
-                NanonisValue::F32(z_sweep_distance_m),
-            ],
-            vec!["f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrRangeSet { z_offset_m, z_sweep_distance_m })?;
         Ok(())
     }
 
@@ -312,16 +267,9 @@ impl NanonisClient {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn z_spectr_range_get(&mut self) -> Result<(f32, f32), NanonisError> {
-        let result =
-            self.quick_send("ZSpectr.RangeGet", vec![], vec![], vec!["f", "f"])?;
-
-        if result.len() >= 2 {
-            Ok((result[0].as_f32()?, result[1].as_f32()?))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Z spectroscopy range response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ZSpectrRangeGet {})?;
+
+        Ok((response.z_offset_m, response.z_sweep_distance_m))
     }
 
     /// Set the timing parameters for Z spectroscopy.
@@ -362,19 +310,14 @@ impl NanonisClient {
         integration_time_s: f32,
         end_settling_time_s: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.TimingSet",
-            vec![
-                NanonisValue::F32(z_averaging_time_s),
-                NanonisValue::F32(initial_settling_time_s),
-                NanonisValue::F32(maximum_slew_rate_vdivs),
-                NanonisValue::F32(settling_time_s),
-                NanonisValue::F32(integration_time_s),
-                NanonisValue::F32(end_settling_time_s),
-            ],
-            vec!["f", "f", "f", "f", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrTimingSet {
+            z_averaging_time_s,
+            initial_settling_time_s,
+            maximum_slew_rate_vdivs,
+            settling_time_s,
+            integration_time_s,
+            end_settling_time_s,
+        })?;
         Ok(())
     }
 
@@ -408,27 +351,16 @@ impl NanonisClient {
     pub fn z_spectr_timing_get(
         &mut self,
     ) -> Result<(f32, f32, f32, f32, f32, f32), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.TimingGet",
-            vec![],
-            vec![],
-            vec!["f", "f", "f", "f", "f", "f"],
-        )?;
-
-        if result.len() >= 6 {
-            Ok((
-                result[0].as_f32()?,
-                result[1].as_f32()?,
-                result[2].as_f32()?,
-                result[3].as_f32()?,
-                result[4].as_f32()?,
-                result[5].as_f32()?,
-            ))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Z spectroscopy timing response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ZSpectrTimingGet {})?;
+
+        Ok((
+            response.z_averaging_time_s,
+            response.initial_settling_time_s,
+            response.maximum_slew_rate_vdivs,
+            response.settling_time_s,
+            response.integration_time_s,
+            response.end_settling_time_s,
+        ))
     }
 
     /// Set the retraction parameters for tip protection during Z spectroscopy.
@@ -439,7 +371,7 @@ impl NanonisClient {
     /// # Arguments
     /// * `enable` - Enable/disable automatic retraction
     /// * `threshold` - Signal threshold value for retraction trigger
-    /// * `signal_index` - Index of signal to monitor (0-23)
+    /// * `signal` - Index (0-23) or name of the signal to monitor
     /// * `comparison` - Comparison type: 0=greater than, 1=less than
     ///
     /// # Errors
@@ -452,7 +384,7 @@ impl NanonisClient {
     /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
     ///
     /// // Enable retraction when current exceeds 1 nA (signal 0, greater than)
-    /// client.z_spectr_retract_set(true, 1e-9, 0, 0)?;
+    /// client.z_spectr_retract_set(true, 1e-9, "Current (A)", 0)?;
     ///
     /// // Disable retraction
     /// client.z_spectr_retract_set(false, 0.0, 0, 0)?;
@@ -462,22 +394,18 @@ impl NanonisClient {
         &mut self,
         enable: bool,
         threshold: f32,
-        signal_index: i32,
+        signal: impl Into<SignalRef>,
         comparison: u16,
     ) -> Result<(), NanonisError> {
+        let signal_index = self.resolve_signal(signal)?;
         let enable_flag = if enable { 1u16 } else { 0u16 };
 
-        self.quick_send(
-            "ZSpectr.RetractSet",
-            vec![
-                NanonisValue::U16(enable_flag),
-                NanonisValue::F32(threshold),
-                NanonisValue::I32(signal_index),
-                NanonisValue::U16(comparison),
-            ],
-            vec!["H", "f", "i", "H"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrRetractSet {
+            enable: enable_flag,
+            threshold,
+            signal_index,
+            comparison,
+        })?;
         Ok(())
     }
 
@@ -511,24 +439,13 @@ impl NanonisClient {
     pub fn z_spectr_retract_get(
         &mut self,
     ) -> Result<(bool, f32, i32, u16), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.RetractGet",
-            vec![],
-            vec![],
-            vec!["H", "f", "i", "H"],
-        )?;
-
-        if result.len() >= 4 {
-            let enabled = result[0].as_u16()? == 1;
-            let threshold = result[1].as_f32()?;
-            let signal_index = result[2].as_i32()?;
-            let comparison = result[3].as_u16()?;
-            Ok((enabled, threshold, signal_index, comparison))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Z spectroscopy retract response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ZSpectrRetractGet {})?;
+
+        let enabled = response.enabled == 1;
+        let threshold = response.threshold;
+        let signal_index = response.signal_index;
+        let comparison = response.comparison;
+        Ok((enabled, threshold, signal_index, comparison))
     }
 
     /// Set the Z spectroscopy properties.
@@ -552,19 +469,14 @@ impl NanonisClient {
         show_save_dialog: u16,
         save_all: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.PropsSet",
-            vec![
-                NanonisValue::U16(backward_sweep),
-                NanonisValue::I32(num_points),
-                NanonisValue::U16(num_sweeps),
-                NanonisValue::U16(autosave),
-                NanonisValue::U16(show_save_dialog),
-                NanonisValue::U16(save_all),
-            ],
-            vec!["H", "i", "H", "H", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrPropsSet {
+            backward_sweep,
+            num_points,
+            num_sweeps,
+            autosave,
+            show_save_dialog,
+            save_all,
+        })?;
         Ok(())
     }
 
@@ -598,27 +510,16 @@ impl NanonisClient {
     pub fn z_spectr_props_get(
         &mut self,
     ) -> Result<(bool, i32, u16, bool, bool, bool), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.PropsGet",
-            vec![],
-            vec![],
-            vec!["H", "i", "H", "I", "I", "I"],
-        )?;
-
-        if result.len() >= 6 {
-            Ok((
-                result[0].as_u16()? != 0,
-                result[1].as_i32()?,
-                result[2].as_u16()?,
-                result[3].as_u32()? != 0,
-                result[4].as_u32()? != 0,
-                result[5].as_u32()? != 0,
-            ))
-        } else {
-            Err(NanonisError::Protocol(
-                "Invalid Z spectroscopy props response".to_string(),
-            ))
-        }
+        let response = self.send(&commands::ZSpectrPropsGet {})?;
+
+        Ok((
+            response.backward_sweep != 0,
+            response.num_points,
+            response.num_sweeps,
+            response.autosave != 0,
+            response.show_save_dialog != 0,
+            response.save_all != 0,
+        ))
     }
 
     /// Set the advanced Z spectroscopy properties.
@@ -638,17 +539,12 @@ impl NanonisClient {
         lockin_run: u16,
         reset_z: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.AdvPropsSet",
-            vec![
-                NanonisValue::F32(time_between_sweeps_s),
-                NanonisValue::U16(record_final_z),
-                NanonisValue::U16(lockin_run),
-                NanonisValue::U16(reset_z),
-            ],
-            vec!["f", "H", "H", "H"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrAdvPropsSet {
+            time_between_sweeps_s,
+            record_final_z,
+            lockin_run,
+            reset_z,
+        })?;
         Ok(())
     }
 
@@ -660,18 +556,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_adv_props_get(&mut self) -> Result<(f32, bool, bool, bool), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.AdvPropsGet",
-            vec![],
-            vec![],
-            vec!["f", "H", "H", "H"],
-        )?;
+        let response = self.send(&commands::ZSpectrAdvPropsGet {})?;
 
         Ok((
-            result[0].as_f32()?,
-            result[1].as_u16()? != 0,
-            result[2].as_u16()? != 0,
-            result[3].as_u16()? != 0,
+            response.time_between_sweeps_s,
+            response.record_final_z != 0,
+            response.lockin_run != 0,
+            response.reset_z != 0,
         ))
     }
 
@@ -683,12 +574,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_retract_delay_set(&mut self, delay_s: f32) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.RetractDelaySet",
-            vec![NanonisValue::F32(delay_s)],
-            vec!["f"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrRetractDelaySet { delay_s })?;
         Ok(())
     }
 
@@ -700,8 +586,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_retract_delay_get(&mut self) -> Result<f32, NanonisError> {
-        let result = self.quick_send("ZSpectr.RetractDelayGet", vec![], vec![], vec!["f"])?;
-        result[0].as_f32()
+        let response = self.send(&commands::ZSpectrRetractDelayGet {})?;
+        Ok(response.delay_s)
     }
 
     /// Set the second retraction condition.
@@ -709,29 +595,25 @@ impl NanonisClient {
     /// # Arguments
     /// * `condition` - 0=no change, 1=disabled, 2=OR, 3=AND, 4=THEN
     /// * `threshold` - Threshold value
-    /// * `signal_index` - Signal index (0-127, -1 for no change)
+    /// * `signal` - Signal index (0-127, -1 for no change) or name
     /// * `comparison` - 0=greater than, 1=less than, 2=no change
     ///
     /// # Errors
-    /// Returns `NanonisError` if communication fails.
+    /// Returns `NanonisError` if communication fails or the name is unknown.
     pub fn z_spectr_retract_second_set(
         &mut self,
         condition: i32,
         threshold: f32,
-        signal_index: i32,
+        signal: impl Into<SignalRef>,
         comparison: u16,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.RetractSecondSet",
-            vec![
-                NanonisValue::I32(condition),
-                NanonisValue::F32(threshold),
-                NanonisValue::I32(signal_index),
-                NanonisValue::U16(comparison),
-            ],
-            vec!["i", "f", "i", "H"],
-            vec![],
-        )?;
+        let signal_index = self.resolve_signal(signal)?;
+        self.send(&commands::ZSpectrRetractSecondSet {
+            condition,
+            threshold,
+            signal_index,
+            comparison,
+        })?;
         Ok(())
     }
 
@@ -743,18 +625,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_retract_second_get(&mut self) -> Result<(i32, f32, i32, u16), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.RetractSecondGet",
-            vec![],
-            vec![],
-            vec!["i", "f", "i", "H"],
-        )?;
+        let response = self.send(&commands::ZSpectrRetractSecondGet {})?;
 
         Ok((
-            result[0].as_i32()?,
-            result[1].as_f32()?,
-            result[2].as_i32()?,
-            result[3].as_u16()?,
+            response.condition,
+            response.threshold,
+            response.signal_index,
+            response.comparison,
         ))
     }
 
@@ -766,12 +643,7 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_dig_sync_set(&mut self, dig_sync: u16) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.DigSyncSet",
-            vec![NanonisValue::U16(dig_sync)],
-            vec!["H"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrDigSyncSet { dig_sync })?;
         Ok(())
     }
 
@@ -783,8 +655,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_dig_sync_get(&mut self) -> Result<u16, NanonisError> {
-        let result = self.quick_send("ZSpectr.DigSyncGet", vec![], vec![], vec!["H"])?;
-        result[0].as_u16()
+        let response = self.send(&commands::ZSpectrDigSyncGet {})?;
+        Ok(response.dig_sync)
     }
 
     /// Set the TTL synchronization parameters.
@@ -804,17 +676,12 @@ impl NanonisClient {
         time_to_on_s: f32,
         on_duration_s: f32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.TTLSyncSet",
-            vec![
-                NanonisValue::U16(ttl_line),
-                NanonisValue::U16(polarity),
-                NanonisValue::F32(time_to_on_s),
-                NanonisValue::F32(on_duration_s),
-            ],
-            vec!["H", "H", "f", "f"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrTtlSyncSet {
+            ttl_line,
+            polarity,
+            time_to_on_s,
+            on_duration_s,
+        })?;
         Ok(())
     }
 
@@ -826,18 +693,13 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_ttl_sync_get(&mut self) -> Result<(u16, u16, f32, f32), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.TTLSyncGet",
-            vec![],
-            vec![],
-            vec!["H", "H", "f", "f"],
-        )?;
+        let response = self.send(&commands::ZSpectrTtlSyncGet {})?;
 
         Ok((
-            result[0].as_u16()?,
-            result[1].as_u16()?,
-            result[2].as_f32()?,
-            result[3].as_f32()?,
+            response.ttl_line,
+            response.polarity,
+            response.time_to_on_s,
+            response.on_duration_s,
         ))
     }
 
@@ -854,15 +716,7 @@ impl NanonisClient {
         pulse_seq_nr: u16,
         num_periods: u32,
     ) -> Result<(), NanonisError> {
-        self.quick_send(
-            "ZSpectr.PulseSeqSyncSet",
-            vec![
-                NanonisValue::U16(pulse_seq_nr),
-                NanonisValue::U32(num_periods),
-            ],
-            vec!["H", "I"],
-            vec![],
-        )?;
+        self.send(&commands::ZSpectrPulseSeqSyncSet { pulse_seq_nr, num_periods })?;
         Ok(())
     }
 
@@ -874,13 +728,8 @@ impl NanonisClient {
     /// # Errors
     /// Returns `NanonisError` if communication fails.
     pub fn z_spectr_pulse_seq_sync_get(&mut self) -> Result<(u16, u32), NanonisError> {
-        let result = self.quick_send(
-            "ZSpectr.PulseSeqSyncGet",
-            vec![],
-            vec![],
-            vec!["H", "I"],
-        )?;
-
-        Ok((result[0].as_u16()?, result[1].as_u32()?))
+        let response = self.send(&commands::ZSpectrPulseSeqSyncGet {})?;
+
+        Ok((response.pulse_seq_nr, response.num_periods))
     }
 }
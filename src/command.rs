@@ -0,0 +1,372 @@
+use crate::error::NanonisError;
+use crate::types::{Array2D, NanonisValue};
+
+/// A Rust type that maps to one or more Nanonis protocol type codes.
+///
+/// `ARG_CODES` lists the codes the type can be serialized as when sent to the
+/// server, `RESPONSE_CODES` the codes it can be parsed from. The
+/// [`nanonis_command!`](crate::nanonis_command) macro checks every field of a
+/// command against these lists at compile time.
+pub trait WireValue: Sized {
+    /// Type codes accepted for command arguments
+    const ARG_CODES: &'static [&'static str];
+    /// Type codes accepted for response values
+    const RESPONSE_CODES: &'static [&'static str];
+
+    /// Convert to a protocol value for serialization.
+    fn to_value(&self) -> NanonisValue;
+
+    /// Convert from a parsed protocol value.
+    fn from_value(value: NanonisValue) -> Result<Self, NanonisError>;
+}
+
+macro_rules! impl_wire_value {
+    ($ty:ty, $variant:ident, $args:expr, $responses:expr) => {
+        impl WireValue for $ty {
+            const ARG_CODES: &'static [&'static str] = $args;
+            const RESPONSE_CODES: &'static [&'static str] = $responses;
+
+            fn to_value(&self) -> NanonisValue {
+                NanonisValue::$variant(self.clone())
+            }
+
+            fn from_value(value: NanonisValue) -> Result<Self, NanonisError> {
+                match value {
+                    NanonisValue::$variant(v) => Ok(v),
+                    other => Err(NanonisError::Protocol(format!(
+                        "Expected {}, got {other:?}",
+                        stringify!($ty)
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_wire_value!(u16, U16, &["H"], &["H"]);
+impl_wire_value!(i16, I16, &["h"], &["h"]);
+impl_wire_value!(u32, U32, &["I"], &["I"]);
+impl_wire_value!(i32, I32, &["i"], &["i"]);
+impl_wire_value!(f32, F32, &["f"], &["f"]);
+impl_wire_value!(f64, F64, &["d"], &["d"]);
+impl_wire_value!(String, String, &["*-c", "+*c"], &["*-c"]);
+impl_wire_value!(Vec<String>, ArrayString, &["+*c", "*+c"], &["+*c", "*+c"]);
+impl_wire_value!(Vec<i32>, ArrayI32, &["*i", "+*i"], &["*i", "+*i", "*+i"]);
+impl_wire_value!(Vec<u32>, ArrayU32, &["*I", "+*I"], &["*I", "+*I"]);
+impl_wire_value!(Vec<f32>, ArrayF32, &["*f", "+*f"], &["*f", "+*f"]);
+impl_wire_value!(Vec<f64>, ArrayF64, &["*d", "+*d"], &["*d", "+*d"]);
+impl_wire_value!(Vec<u8>, ArrayU8, &["*b", "+*b"], &[]);
+impl_wire_value!(Array2D<f32>, Array2DF32, &["2f"], &["2f"]);
+
+/// Typed description of a Nanonis TCP command.
+///
+/// Implement this with the [`nanonis_command!`](crate::nanonis_command)
+/// macro, which derives the type codes from the declared fields and rejects
+/// mismatches at compile time. Send commands with
+/// [`NanonisClient::send`](crate::NanonisClient::send).
+pub trait Command {
+    /// Command name, e.g. `"Bias.Set"`
+    const NAME: &'static str;
+    /// Type codes of the arguments, in order
+    const ARG_TYPES: &'static [&'static str];
+    /// Type codes of the response values, in order
+    const RESPONSE_TYPES: &'static [&'static str];
+
+    /// Decoded response of the command
+    type Response: CommandResponse;
+
+    /// Argument values, in the order of [`ARG_TYPES`](Self::ARG_TYPES).
+    fn args(&self) -> Vec<NanonisValue>;
+
+    /// Whether the server keeps the connection busy until a scan, sweep or
+    /// move ends, e.g. a sweep start that waits for its data.
+    ///
+    /// Declared with `blocking` or `blocking if <flag>` in
+    /// [`nanonis_command!`](crate::nanonis_command). A
+    /// [`SharedNanonisClient`](crate::SharedNanonisClient) pool runs blocking
+    /// commands on a connection of their own.
+    fn blocks(&self) -> bool {
+        false
+    }
+}
+
+/// Response of a [`Command`], decoded from the parsed protocol values.
+pub trait CommandResponse: Sized {
+    /// Build the response from values parsed according to the command's
+    /// response types.
+    fn from_values(values: Vec<NanonisValue>) -> Result<Self, NanonisError>;
+}
+
+impl CommandResponse for () {
+    fn from_values(_: Vec<NanonisValue>) -> Result<Self, NanonisError> {
+        Ok(())
+    }
+}
+
+/// Compile-time helpers used by [`nanonis_command!`](crate::nanonis_command).
+#[doc(hidden)]
+pub mod checks {
+    const fn str_eq(a: &str, b: &str) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Whether `code` is one of `codes`.
+    pub const fn accepts(codes: &[&str], code: &str) -> bool {
+        let mut i = 0;
+        while i < codes.len() {
+            if str_eq(codes[i], code) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    const fn is_int(code: &str) -> bool {
+        str_eq(code, "i") || str_eq(code, "I")
+    }
+
+    /// Whether `code` is an array whose length another array can share.
+    const fn is_array(code: &str) -> bool {
+        let code = code.as_bytes();
+        let mut i = 0;
+        while i < code.len() {
+            if code[i] == b'*' {
+                // `*-c` is a single string, not an array
+                return !(code.len() == 3 && code[1] == b'-');
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Whether every array or string whose length comes from a previous
+    /// variable is preceded by an integer, or for numeric arrays by an
+    /// array of the same length.
+    pub const fn lengths_resolvable(codes: &[&str]) -> bool {
+        let mut i = 0;
+        while i < codes.len() {
+            let code = codes[i].as_bytes();
+            if str_eq(codes[i], "2f") {
+                if i < 2 || !str_eq(codes[i - 1], "i") || !str_eq(codes[i - 2], "i") {
+                    return false;
+                }
+            } else if !code.is_empty() && code[0] == b'*' {
+                let numeric = code.len() == 2;
+                if i == 0 || !(is_int(codes[i - 1]) || numeric && is_array(codes[i - 1])) {
+                    return false;
+                }
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// Declare a typed Nanonis command and its response.
+///
+/// Each field is declared with its Rust type and protocol type code. The
+/// argument and response type lists are derived from the fields, so their
+/// order and count always match, and a type code that does not fit the Rust
+/// type, or an array whose length field is missing, fails to compile.
+///
+/// Use `-> ()` for commands without return values. Commands that keep the
+/// connection busy until a scan or sweep ends are marked `blocking`, or
+/// `blocking if <flag>` when an integer argument selects whether they wait,
+/// see [`Command::blocks`].
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{nanonis_command, MockServer, NanonisValue};
+///
+/// nanonis_command! {
+///     /// Set the Z-controller setpoint.
+///     pub struct SetpointSet: "ZCtrl.SetpntSet" {
+///         pub setpoint: f32 = "f",
+///     } -> ()
+/// }
+///
+/// nanonis_command! {
+///     /// Get the scan buffer configuration.
+///     pub struct BufferGet: "Scan.BufferGet" {} -> BufferGetResponse {
+///         pub num_channels: i32 = "i",
+///         pub channel_indexes: Vec<i32> = "*i",
+///         pub pixels: i32 = "i",
+///         pub lines: i32 = "i",
+///     }
+/// }
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+///
+/// client.send(&SetpointSet { setpoint: 50e-12 })?;
+/// let buffer = client.send(&BufferGet {})?;
+/// assert_eq!(buffer.channel_indexes.len(), buffer.num_channels as usize);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+///
+/// A command that only blocks when a flag argument is set:
+/// ```
+/// use nanonis_rs::{nanonis_command, Command};
+///
+/// nanonis_command! {
+///     /// Start a script, waiting for it to finish if `wait` is set.
+///     pub struct ScriptRun: "Script.Run" blocking if wait {
+///         pub script_slot: i32 = "i",
+///         pub wait: u32 = "I",
+///     } -> ()
+/// }
+///
+/// assert!(ScriptRun { script_slot: 1, wait: 1 }.blocks());
+/// assert!(!ScriptRun { script_slot: 1, wait: 0 }.blocks());
+/// ```
+///
+/// Mismatched type codes are rejected by the compiler:
+/// ```compile_fail
+/// use nanonis_rs::nanonis_command;
+///
+/// nanonis_command! {
+///     pub struct BiasSet: "Bias.Set" {
+///         pub voltage: f32 = "d",
+///     } -> ()
+/// }
+/// ```
+///
+/// So are arrays without a preceding length:
+/// ```compile_fail
+/// use nanonis_rs::nanonis_command;
+///
+/// nanonis_command! {
+///     pub struct ValsGet: "Signals.ValsGet" {
+///         pub indexes: Vec<i32> = "+*i",
+///         pub wait: u32 = "I",
+///     } -> ValsGetResponse {
+///         pub values: Vec<f32> = "*f",
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! nanonis_command {
+    (@blocks $self:ident) => { false };
+    (@blocks $self:ident blocking) => { true };
+    (@blocks $self:ident blocking if $flag:ident) => { $self.$flag != 0 };
+
+    (@request
+        $(#[$meta:meta])* $vis:vis struct $name:ident : $command:literal [ $( $blocking:tt )* ]
+        { $( $(#[$arg_meta:meta])* $arg_vis:vis $arg:ident : $arg_ty:ty = $arg_code:literal ),* }
+        $response:ty [ $( $res_code:literal ),* ]
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        $vis struct $name {
+            $( $(#[$arg_meta])* $arg_vis $arg: $arg_ty, )*
+        }
+
+        impl $crate::Command for $name {
+            const NAME: &'static str = $command;
+            const ARG_TYPES: &'static [&'static str] = &[$( $arg_code ),*];
+            const RESPONSE_TYPES: &'static [&'static str] = &[$( $res_code ),*];
+
+            type Response = $response;
+
+            fn args(&self) -> ::std::vec::Vec<$crate::NanonisValue> {
+                vec![$( <$arg_ty as $crate::WireValue>::to_value(&self.$arg) ),*]
+            }
+
+            fn blocks(&self) -> bool {
+                $crate::nanonis_command!(@blocks self $( $blocking )*)
+            }
+        }
+
+        const _: () = {
+            $( assert!(
+                $crate::__command_checks::accepts(<$arg_ty as $crate::WireValue>::ARG_CODES, $arg_code),
+                concat!($command, ": argument type code \"", $arg_code, "\" does not match field `", stringify!($arg), ": ", stringify!($arg_ty), "`"),
+            ); )*
+            assert!(
+                $crate::__command_checks::lengths_resolvable(&[$( $arg_code ),*]),
+                concat!($command, ": argument array or string without preceding length"),
+            );
+            assert!(
+                $crate::__command_checks::lengths_resolvable(&[$( $res_code ),*]),
+                concat!($command, ": response array or string without preceding length"),
+            );
+        };
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident : $command:literal $( blocking $( if $flag:ident )? )? {
+            $( $(#[$arg_meta:meta])* $arg_vis:vis $arg:ident : $arg_ty:ty = $arg_code:literal ),* $(,)?
+        } -> ()
+    ) => {
+        $crate::nanonis_command!(@request
+            $(#[$meta])* $vis struct $name : $command [ $( blocking $( if $flag )? )? ]
+            { $( $(#[$arg_meta])* $arg_vis $arg : $arg_ty = $arg_code ),* }
+            () []
+        );
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident : $command:literal $( blocking $( if $flag:ident )? )? {
+            $( $(#[$arg_meta:meta])* $arg_vis:vis $arg:ident : $arg_ty:ty = $arg_code:literal ),* $(,)?
+        } -> $response:ident {
+            $( $(#[$res_meta:meta])* $res_vis:vis $res:ident : $res_ty:ty = $res_code:literal ),* $(,)?
+        }
+    ) => {
+        $crate::nanonis_command!(@request
+            $(#[$meta])* $vis struct $name : $command [ $( blocking $( if $flag )? )? ]
+            { $( $(#[$arg_meta])* $arg_vis $arg : $arg_ty = $arg_code ),* }
+            $response [ $( $res_code ),* ]
+        );
+
+        #[doc = concat!("Response of [`", stringify!($name), "`].")]
+        #[derive(Debug, Clone)]
+        $vis struct $response {
+            $( $(#[$res_meta])* $res_vis $res: $res_ty, )*
+        }
+
+        impl $crate::CommandResponse for $response {
+            fn from_values(
+                values: ::std::vec::Vec<$crate::NanonisValue>,
+            ) -> ::std::result::Result<Self, $crate::NanonisError> {
+                let expected = <$name as $crate::Command>::RESPONSE_TYPES.len();
+                if values.len() < expected {
+                    return Err($crate::NanonisError::Protocol(format!(
+                        "{} response: expected {} values, got {}",
+                        $command,
+                        expected,
+                        values.len()
+                    )));
+                }
+                let mut values = values.into_iter();
+                Ok(Self {
+                    $( $res: <$res_ty as $crate::WireValue>::from_value(
+                        values.next().ok_or_else(|| {
+                            $crate::NanonisError::Protocol(format!("{} response truncated", $command))
+                        })?,
+                    )?, )*
+                })
+            }
+        }
+
+        const _: () = {
+            $( assert!(
+                $crate::__command_checks::accepts(<$res_ty as $crate::WireValue>::RESPONSE_CODES, $res_code),
+                concat!($command, ": response type code \"", $res_code, "\" does not match field `", stringify!($res), ": ", stringify!($res_ty), "`"),
+            ); )*
+        };
+    };
+}
@@ -2,11 +2,13 @@ use thiserror::Error;
 
 /// Error types for Nanonis communication.
 ///
-/// This enum represents the four categories of errors that can occur:
+/// This enum represents the categories of errors that can occur:
 /// - [`Io`](NanonisError::Io) - Network and I/O errors
 /// - [`Timeout`](NanonisError::Timeout) - Connection or operation timeouts
 /// - [`Protocol`](NanonisError::Protocol) - Binary protocol parsing/validation errors
 /// - [`Server`](NanonisError::Server) - Errors returned by the Nanonis server
+/// - [`ConnectionLost`](NanonisError::ConnectionLost) - Connection dropped during a command that is not retried
+/// - [`InvalidFile`](NanonisError::InvalidFile) - Malformed Nanonis data file
 #[derive(Error, Debug)]
 pub enum NanonisError {
     /// IO error with context describing what operation failed.
@@ -78,6 +80,42 @@ pub enum NanonisError {
     /// ```
     #[error("Server error: {message} (code: {code})")]
     Server { code: i32, message: String },
+
+    /// Connection lost while sending a command that is unsafe to repeat.
+    ///
+    /// Only returned when a [`ReconnectPolicy`](crate::ReconnectPolicy) is
+    /// configured. The command may or may not have been executed by the
+    /// server, so it is not retried; the next command reconnects.
+    ///
+    /// # Example
+    /// ```
+    /// use nanonis_rs::NanonisError;
+    ///
+    /// let err = NanonisError::ConnectionLost {
+    ///     command: "Scan.Action".to_string(),
+    ///     source: std::io::ErrorKind::ConnectionReset.into(),
+    /// };
+    /// assert!(err.is_connection_lost());
+    /// assert!(err.to_string().contains("Scan.Action"));
+    /// ```
+    #[error("Connection lost during {command}, command not retried")]
+    ConnectionLost {
+        command: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A Nanonis data file (`.sxm`, `.dat`, `.3ds`) could not be parsed.
+    ///
+    /// # Example
+    /// ```
+    /// use nanonis_rs::NanonisError;
+    ///
+    /// let err = NanonisError::InvalidFile("missing :SCANIT_END:".to_string());
+    /// assert!(err.to_string().contains("SCANIT_END"));
+    /// ```
+    #[error("Invalid file: {0}")]
+    InvalidFile(String),
 }
 
 impl NanonisError {
@@ -108,6 +146,11 @@ impl NanonisError {
     pub fn is_protocol(&self) -> bool {
         matches!(self, NanonisError::Protocol(_))
     }
+
+    /// Check if the connection was lost during a non-repeatable command.
+    pub fn is_connection_lost(&self) -> bool {
+        matches!(self, NanonisError::ConnectionLost { .. })
+    }
 }
 
 // Allow conversion from std::io::Error
@@ -0,0 +1,288 @@
+use super::{invalid, read, sci, timestamp_now, write};
+use crate::client::bias_spectr::BiasSpectrResult;
+use crate::client::gen_swp::GenSwpResult;
+use crate::client::lockin_freq_swp::LockInFreqSwpResult;
+use crate::client::z_spectr::ZSpectroscopyResult;
+use crate::error::NanonisError;
+use crate::types::Array2D;
+use std::path::Path;
+
+const DATA_MARKER: &str = "[DATA]";
+
+/// Header key holding the extra parameters returned by spectroscopy starts.
+const PARAMETERS_KEY: &str = "Parameters";
+
+/// Nanonis `.dat` spectroscopy or sweep file.
+///
+/// A `.dat` file is a tab-separated text file: a header block of
+/// `key<TAB>value` lines, a `[DATA]` line, a row of column names and one row
+/// of values per sweep point.
+///
+/// [`data`](Self::data) holds one row per channel, the layout returned by
+/// [`bias_spectr_start`](crate::NanonisClient::bias_spectr_start) and the
+/// other sweep commands, so live and archived spectra can be processed the
+/// same way. Conversions to and from [`BiasSpectrResult`], [`GenSwpResult`],
+/// [`LockInFreqSwpResult`] and [`ZSpectroscopyResult`] are provided. Extra
+/// parameters of a result are stored under the `Parameters` header key.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::bias_spectr::BiasSpectrResult;
+/// use nanonis_rs::dat::DatFile;
+/// use nanonis_rs::MockServer;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// let live = client.bias_spectr_start(true, "")?;
+///
+/// let path = std::env::temp_dir().join("nanonis-rs-dat-example.dat");
+/// let mut file = DatFile::from(live.clone());
+/// file.set_header("Comment", "tip over Au(111)");
+/// file.write(&path)?;
+///
+/// let archived = DatFile::read(&path)?;
+/// assert_eq!(archived.header_value("Comment"), Some("tip over Au(111)"));
+/// assert_eq!(archived.column("Current (A)").unwrap().len(), live.data.cols());
+///
+/// let archived = BiasSpectrResult::from(archived);
+/// assert_eq!(archived.channel_names, live.channel_names);
+/// assert_eq!(archived.data.shape(), live.data.shape());
+/// # std::fs::remove_file(&path).ok();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DatFile {
+    /// Header entries in file order
+    pub header: Vec<(String, String)>,
+    /// Column names, including units, e.g. `"Bias calc (V)"`
+    pub channel_names: Vec<String>,
+    /// One row per channel, one column per sweep point
+    pub data: Array2D<f32>,
+}
+
+impl DatFile {
+    /// File with the given channels and an `Experiment` and `Date` header.
+    pub fn new(experiment: &str, channel_names: Vec<String>, data: Array2D<f32>) -> Self {
+        let (date, time) = timestamp_now();
+        let mut file = Self {
+            header: Vec::new(),
+            channel_names,
+            data,
+        };
+        file.set_header("Experiment", experiment);
+        file.set_header("Date", format!("{date} {time}"));
+        file
+    }
+
+    /// Read a `.dat` file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, NanonisError> {
+        Self::parse(&String::from_utf8_lossy(&read(path.as_ref())?))
+    }
+
+    /// Write a `.dat` file.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), NanonisError> {
+        write(path.as_ref(), self.to_text()?.as_bytes())
+    }
+
+    /// Parse the contents of a `.dat` file.
+    pub fn parse(text: &str) -> Result<Self, NanonisError> {
+        let mut lines = text.lines();
+
+        let mut header = Vec::new();
+        for line in lines.by_ref() {
+            if line.trim() == DATA_MARKER {
+                break;
+            }
+            let line = line.trim_end_matches(['\t', '\r']);
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once('\t').unwrap_or((line, ""));
+            header.push((key.to_string(), value.to_string()));
+        }
+
+        let channel_names: Vec<String> = lines
+            .by_ref()
+            .find(|line| !line.trim().is_empty())
+            .ok_or_else(|| invalid("missing [DATA] section"))?
+            .trim_end_matches(['\t', '\r'])
+            .split('\t')
+            .map(str::to_string)
+            .collect();
+
+        // Rows of the file are sweep points; transpose to one row per channel
+        let mut points = Vec::new();
+        for (number, line) in lines.enumerate() {
+            let line = line.trim_end_matches(['\t', '\r']);
+            if line.trim().is_empty() {
+                continue;
+            }
+            let row = line
+                .split('\t')
+                .map(|value| value.trim().parse::<f32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| invalid(format!("invalid number in data row {}", number + 1)))?;
+            if row.len() != channel_names.len() {
+                return Err(invalid(format!(
+                    "data row {} has {} values, expected {}",
+                    number + 1,
+                    row.len(),
+                    channel_names.len()
+                )));
+            }
+            points.push(row);
+        }
+
+        let mut data = vec![0f32; channel_names.len() * points.len()];
+        for (point, row) in points.iter().enumerate() {
+            for (channel, &value) in row.iter().enumerate() {
+                data[channel * points.len() + point] = value;
+            }
+        }
+
+        Ok(Self {
+            header,
+            data: Array2D::from_vec(channel_names.len(), points.len(), data)?,
+            channel_names,
+        })
+    }
+
+    /// Serialize to the contents of a `.dat` file.
+    pub fn to_text(&self) -> Result<String, NanonisError> {
+        if self.data.rows() != self.channel_names.len() {
+            return Err(invalid(format!(
+                "{} channel names for {} data rows",
+                self.channel_names.len(),
+                self.data.rows()
+            )));
+        }
+
+        let mut text = String::new();
+        for (key, value) in &self.header {
+            text.push_str(&format!("{key}\t{value}\t\n"));
+        }
+        text.push('\n');
+        text.push_str(DATA_MARKER);
+        text.push('\n');
+        text.push_str(&self.channel_names.join("\t"));
+        text.push('\n');
+
+        for point in 0..self.data.cols() {
+            let row: Vec<String> = (0..self.data.rows())
+                .map(|channel| sci(self.data[(channel, point)] as f64, 6))
+                .collect();
+            text.push_str(&row.join("\t"));
+            text.push('\n');
+        }
+        Ok(text)
+    }
+
+    /// Value of a header entry, e.g. `header_value("Date")`.
+    pub fn header_value(&self, key: &str) -> Option<&str> {
+        self.header
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Set a header entry, replacing an existing one.
+    pub fn set_header(&mut self, key: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.header.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value,
+            None => self.header.push((key.to_string(), value)),
+        }
+    }
+
+    /// Kind of measurement from the `Experiment` header entry.
+    pub fn experiment(&self) -> Option<&str> {
+        self.header_value("Experiment")
+    }
+
+    /// Data of the channel with the given column name.
+    pub fn column(&self, name: &str) -> Option<&[f32]> {
+        let index = self.channel_names.iter().position(|n| n == name)?;
+        self.data.row(index)
+    }
+
+    fn set_parameters(&mut self, parameters: &[f32]) {
+        if !parameters.is_empty() {
+            let values: Vec<String> = parameters.iter().map(|&p| sci(p as f64, 6)).collect();
+            self.set_header(PARAMETERS_KEY, values.join("\t"));
+        }
+    }
+
+    fn parameters(&self) -> Vec<f32> {
+        self.header_value(PARAMETERS_KEY)
+            .map(|values| {
+                values
+                    .split('\t')
+                    .filter_map(|v| v.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl From<BiasSpectrResult> for DatFile {
+    fn from(result: BiasSpectrResult) -> Self {
+        let mut file = Self::new("bias spectroscopy", result.channel_names, result.data);
+        file.set_parameters(&result.parameters);
+        file
+    }
+}
+
+impl From<DatFile> for BiasSpectrResult {
+    fn from(file: DatFile) -> Self {
+        Self {
+            parameters: file.parameters(),
+            channel_names: file.channel_names,
+            data: file.data,
+        }
+    }
+}
+
+impl From<GenSwpResult> for DatFile {
+    fn from(result: GenSwpResult) -> Self {
+        Self::new("Generic Sweep", result.channel_names, result.data)
+    }
+}
+
+impl From<DatFile> for GenSwpResult {
+    fn from(file: DatFile) -> Self {
+        Self {
+            channel_names: file.channel_names,
+            data: file.data,
+        }
+    }
+}
+
+impl From<LockInFreqSwpResult> for DatFile {
+    fn from(result: LockInFreqSwpResult) -> Self {
+        Self::new("LockIn Freq Sweep", result.channel_names, result.data)
+    }
+}
+
+impl From<DatFile> for LockInFreqSwpResult {
+    fn from(file: DatFile) -> Self {
+        Self {
+            channel_names: file.channel_names,
+            data: file.data,
+        }
+    }
+}
+
+impl From<ZSpectroscopyResult> for DatFile {
+    fn from((channel_names, data, parameters): ZSpectroscopyResult) -> Self {
+        let mut file = Self::new("Z spectroscopy", channel_names, data);
+        file.set_parameters(&parameters);
+        file
+    }
+}
+
+impl From<DatFile> for ZSpectroscopyResult {
+    fn from(file: DatFile) -> Self {
+        let parameters = file.parameters();
+        (file.channel_names, file.data, parameters)
+    }
+}
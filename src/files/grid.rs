@@ -0,0 +1,528 @@
+use super::{find, invalid, read, sci, timestamp_now, write};
+use crate::client::bias_spectr::BiasSpectrResult;
+use crate::client::pattern::GridConfig;
+use crate::error::NanonisError;
+use crate::types::{Array2D, Position};
+use std::ops::{Index, IndexMut};
+use std::path::Path;
+
+const HEADER_END: &str = ":HEADER_END:";
+
+/// Fixed parameters written by Nanonis in front of every point.
+const SWEEP_START: &str = "Sweep Start";
+const SWEEP_END: &str = "Sweep End";
+
+/// One grid point of a `.3ds` file: its parameters and spectrum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridPoint {
+    /// Fixed parameters followed by experiment parameters
+    pub parameters: Vec<f32>,
+    /// One row per channel, one column per sweep point
+    pub data: Array2D<f32>,
+}
+
+impl GridPoint {
+    /// Point that has not been measured, all values `NaN`.
+    fn unmeasured(parameters: usize, channels: usize, points: usize) -> Self {
+        Self {
+            parameters: vec![f32::NAN; parameters],
+            data: Array2D::from_vec(channels, points, vec![f32::NAN; channels * points])
+                .expect("shape matches buffer"),
+        }
+    }
+
+    /// Whether the point holds data, i.e. is not all `NaN`.
+    pub fn is_measured(&self) -> bool {
+        self.data.as_slice().iter().any(|v| !v.is_nan())
+    }
+}
+
+/// Nanonis `.3ds` grid spectroscopy file.
+///
+/// A `.3ds` file is a `key=value` text header ending in `:HEADER_END:`,
+/// followed by one big-endian `f32` record per grid point: the point's
+/// parameters, then the sweep of every channel.
+///
+/// Points are indexed by `(x, y)` with `x` running fastest, the order in
+/// which the Pattern module measures and stores them. Each [`GridPoint`]
+/// holds one row per channel like [`BiasSpectrResult`], so
+/// `grid[(x, y)].data[(channel, index)]` addresses a single value.
+///
+/// Grids can also be assembled from repeated
+/// [`bias_spectr_start`](crate::NanonisClient::bias_spectr_start) calls
+/// with [`set_spectrum`](Self::set_spectrum) and written in the same format.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::grid::GridFile;
+/// use nanonis_rs::pattern::GridConfig;
+/// use nanonis_rs::MockServer;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+///
+/// let config = GridConfig {
+///     num_points_x: 2,
+///     num_points_y: 2,
+///     width_m: 10e-9,
+///     height_m: 10e-9,
+///     ..Default::default()
+/// };
+/// let first = client.bias_spectr_start(true, "")?;
+/// let mut grid = GridFile::new(config, "Bias (V)", vec!["Current (A)".into()], first.data.cols());
+/// grid.set_spectrum(0, 0, &first)?;
+/// grid.set_spectrum(1, 0, &client.bias_spectr_start(true, "")?)?;
+///
+/// let path = std::env::temp_dir().join("nanonis-rs-grid-example.3ds");
+/// grid.write(&path)?;
+/// let grid = GridFile::read(&path)?;
+///
+/// assert_eq!(grid.config, config);
+/// assert_eq!(grid.spectrum(0, 0, "Current (A)"), first.data.row(1));
+/// assert_eq!(grid.sweep(0, 0).unwrap().len(), first.data.cols());
+/// assert!(!grid[(1, 1)].is_measured());
+/// # std::fs::remove_file(&path).ok();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridFile {
+    /// Grid geometry from `Grid dim` and `Grid settings`
+    pub config: GridConfig,
+    /// Swept signal, e.g. `"Bias (V)"`
+    pub sweep_signal: String,
+    /// Names of the parameters shared by all experiments, e.g. `"Sweep Start"`
+    pub fixed_parameters: Vec<String>,
+    /// Names of the parameters recorded per point, e.g. `"X (m)"`
+    pub experiment_parameters: Vec<String>,
+    /// Recorded channels, e.g. `"Current (A)"`
+    pub channels: Vec<String>,
+    /// Number of sweep points per spectrum
+    pub points: usize,
+    /// All header entries in file order
+    pub header: Vec<(String, String)>,
+    grid: Vec<GridPoint>,
+}
+
+impl GridFile {
+    /// Grid with all points unmeasured and a minimal header.
+    ///
+    /// The fixed parameters are `Sweep Start` and `Sweep End`, the
+    /// experiment parameters the tip position `X (m)` and `Y (m)`.
+    pub fn new(
+        config: GridConfig,
+        sweep_signal: impl Into<String>,
+        channels: Vec<String>,
+        points: usize,
+    ) -> Self {
+        let fixed_parameters = vec![SWEEP_START.to_string(), SWEEP_END.to_string()];
+        let experiment_parameters = vec!["X (m)".to_string(), "Y (m)".to_string()];
+        let (width, height) = dimensions(&config);
+        let parameters = fixed_parameters.len() + experiment_parameters.len();
+        let grid = vec![GridPoint::unmeasured(parameters, channels.len(), points); width * height];
+
+        let mut file = Self {
+            config,
+            sweep_signal: sweep_signal.into(),
+            fixed_parameters,
+            experiment_parameters,
+            channels,
+            points,
+            header: Vec::new(),
+            grid,
+        };
+
+        let (date, time) = timestamp_now();
+        for (key, value) in file.grid_entries() {
+            file.set_header(key, value);
+            if key == "Grid settings" {
+                file.set_header("Filetype", "Linear");
+            }
+        }
+        file.set_header("Experiment", "Grid Spectroscopy");
+        file.set_header("Start time", format!("{date} {time}"));
+        file
+    }
+
+    /// Read a `.3ds` file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, NanonisError> {
+        Self::parse(&read(path.as_ref())?)
+    }
+
+    /// Write a `.3ds` file.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), NanonisError> {
+        write(path.as_ref(), &self.to_bytes()?)
+    }
+
+    /// Parse the contents of a `.3ds` file.
+    pub fn parse(bytes: &[u8]) -> Result<Self, NanonisError> {
+        let header_end = find(bytes, HEADER_END.as_bytes())
+            .ok_or_else(|| invalid(format!("missing {HEADER_END}")))?;
+        let mut data = &bytes[header_end + HEADER_END.len()..];
+        data = data.strip_prefix(b"\r").unwrap_or(data);
+        data = data.strip_prefix(b"\n").unwrap_or(data);
+
+        let header: Vec<(String, String)> = String::from_utf8_lossy(&bytes[..header_end])
+            .lines()
+            .filter_map(|line| line.trim_end_matches('\r').split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let value = |key: &str| {
+            header
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.trim().trim_matches('"'))
+                .ok_or_else(|| invalid(format!("missing {key}")))
+        };
+        let count = |key: &str| {
+            value(key)?
+                .parse::<usize>()
+                .map_err(|_| invalid(format!("invalid {key}")))
+        };
+
+        let (num_x, num_y) = value("Grid dim")?
+            .split_once('x')
+            .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+            .ok_or_else(|| invalid("invalid Grid dim"))?;
+        let settings: Vec<f32> = value("Grid settings")?
+            .split(';')
+            .map(|v| v.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid("invalid Grid settings"))?;
+        let [center_x_m, center_y_m, width_m, height_m, angle_deg] = settings[..] else {
+            return Err(invalid("Grid settings needs 5 values"));
+        };
+        let config = GridConfig {
+            num_points_x: num_x,
+            num_points_y: num_y,
+            center_x_m,
+            center_y_m,
+            width_m,
+            height_m,
+            angle_deg,
+        };
+
+        let fixed_parameters = names(value("Fixed parameters")?);
+        let experiment_parameters = names(value("Experiment parameters")?);
+        let channels = names(value("Channels")?);
+        let parameters = count("# Parameters (4 byte)")?;
+        let points = count("Points")?;
+
+        let (width, height) = dimensions(&config);
+        let too_large = || invalid("grid dimensions too large");
+        let record = channels
+            .len()
+            .checked_mul(points)
+            .and_then(|n| n.checked_add(parameters))
+            .ok_or_else(too_large)?;
+        if record == 0 {
+            return Err(invalid("grid has neither parameters nor data points"));
+        }
+        let size = width
+            .checked_mul(height)
+            .and_then(|n| n.checked_mul(record))
+            .and_then(|n| n.checked_mul(4))
+            .ok_or_else(too_large)?;
+        if data.len() < size {
+            return Err(invalid(format!(
+                "grid data truncated: {} of {size} bytes",
+                data.len()
+            )));
+        }
+
+        let values: Vec<f32> = data[..size]
+            .chunks_exact(4)
+            .map(|b| f32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        let grid = values
+            .chunks_exact(record)
+            .take(width * height)
+            .map(|record| {
+                Ok(GridPoint {
+                    parameters: record[..parameters].to_vec(),
+                    data: Array2D::from_vec(channels.len(), points, record[parameters..].to_vec())?,
+                })
+            })
+            .collect::<Result<Vec<_>, NanonisError>>()?;
+
+        Ok(Self {
+            config,
+            sweep_signal: value("Sweep Signal")?.to_string(),
+            fixed_parameters,
+            experiment_parameters,
+            channels,
+            points,
+            header,
+            grid,
+        })
+    }
+
+    /// Serialize to the contents of a `.3ds` file.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, NanonisError> {
+        let parameters = self.fixed_parameters.len() + self.experiment_parameters.len();
+        let (width, height) = dimensions(&self.config);
+        if self.grid.len() != width * height {
+            return Err(invalid(format!(
+                "{} points for a {width}x{height} grid",
+                self.grid.len()
+            )));
+        }
+
+        let mut data = Vec::with_capacity(
+            self.grid.len() * (parameters + self.channels.len() * self.points) * 4,
+        );
+        for (index, point) in self.grid.iter().enumerate() {
+            if point.parameters.len() != parameters
+                || point.data.shape() != (self.channels.len(), self.points)
+            {
+                return Err(invalid(format!(
+                    "point {index} does not match the {parameters} parameters, \
+                     {} channels and {} sweep points of the grid",
+                    self.channels.len(),
+                    self.points
+                )));
+            }
+            for value in point.parameters.iter().chain(point.data.as_slice()) {
+                data.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+
+        let mut managed = self.grid_entries();
+        let mut text = String::new();
+        let mut push = |key: &str, value: &str| {
+            text.push_str(&format!("{key}={value}\r\n"));
+        };
+        for (key, value) in &self.header {
+            match managed.iter().position(|(k, _)| k == key) {
+                Some(i) => {
+                    let (key, value) = managed.remove(i);
+                    push(key, &value);
+                }
+                None => push(key, value),
+            }
+        }
+        for (key, value) in &managed {
+            push(key, value);
+        }
+
+        let mut bytes = text.into_bytes();
+        bytes.extend_from_slice(HEADER_END.as_bytes());
+        bytes.extend_from_slice(b"\r\n");
+        bytes.extend_from_slice(&data);
+        Ok(bytes)
+    }
+
+    /// Header entries derived from the typed fields, in Nanonis order.
+    fn grid_entries(&self) -> Vec<(&'static str, String)> {
+        let config = &self.config;
+        let settings: Vec<String> = [
+            config.center_x_m,
+            config.center_y_m,
+            config.width_m,
+            config.height_m,
+            config.angle_deg,
+        ]
+        .iter()
+        .map(|&v| sci(v as f64, 6))
+        .collect();
+        let parameters = self.fixed_parameters.len() + self.experiment_parameters.len();
+
+        vec![
+            (
+                "Grid dim",
+                format!("\"{} x {}\"", config.num_points_x, config.num_points_y),
+            ),
+            ("Grid settings", settings.join(";")),
+            ("Sweep Signal", format!("\"{}\"", self.sweep_signal)),
+            (
+                "Fixed parameters",
+                format!("\"{}\"", self.fixed_parameters.join(";")),
+            ),
+            (
+                "Experiment parameters",
+                format!("\"{}\"", self.experiment_parameters.join(";")),
+            ),
+            ("# Parameters (4 byte)", parameters.to_string()),
+            (
+                "Experiment size (bytes)",
+                (self.channels.len() * self.points * 4).to_string(),
+            ),
+            ("Points", self.points.to_string()),
+            ("Channels", format!("\"{}\"", self.channels.join(";"))),
+        ]
+    }
+
+    /// Value of a header entry, e.g. `header_value("Comment")`.
+    pub fn header_value(&self, key: &str) -> Option<&str> {
+        self.header
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Set a header entry, replacing an existing one.
+    pub fn set_header(&mut self, key: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.header.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value,
+            None => self.header.push((key.to_string(), value)),
+        }
+    }
+
+    /// Number of points along `x` and `y`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        dimensions(&self.config)
+    }
+
+    /// Point at `x`, `y`, or `None` if out of bounds.
+    pub fn point(&self, x: usize, y: usize) -> Option<&GridPoint> {
+        self.point_index(x, y).map(|i| &self.grid[i])
+    }
+
+    /// Point at `x`, `y` for modification, or `None` if out of bounds.
+    pub fn point_mut(&mut self, x: usize, y: usize) -> Option<&mut GridPoint> {
+        self.point_index(x, y).map(|i| &mut self.grid[i])
+    }
+
+    /// Iterate over all points with their `(x, y)` index.
+    pub fn iter_points(&self) -> impl Iterator<Item = ((usize, usize), &GridPoint)> + '_ {
+        let (width, _) = self.dimensions();
+        self.grid
+            .iter()
+            .enumerate()
+            .map(move |(i, point)| ((i % width, i / width), point))
+    }
+
+    /// Spectrum of the named channel at `x`, `y`.
+    pub fn spectrum(&self, x: usize, y: usize, channel: &str) -> Option<&[f32]> {
+        let index = self.channels.iter().position(|c| c == channel)?;
+        self.point(x, y)?.data.row(index)
+    }
+
+    /// Parameter of a point by name, fixed or experiment.
+    pub fn parameter(&self, x: usize, y: usize, name: &str) -> Option<f32> {
+        let index = self
+            .fixed_parameters
+            .iter()
+            .chain(&self.experiment_parameters)
+            .position(|p| p == name)?;
+        self.point(x, y)?.parameters.get(index).copied()
+    }
+
+    /// Values of the sweep signal at `x`, `y`, spaced linearly between the
+    /// point's `Sweep Start` and `Sweep End` parameters.
+    pub fn sweep(&self, x: usize, y: usize) -> Option<Vec<f32>> {
+        let start = self.parameter(x, y, SWEEP_START)?;
+        let end = self.parameter(x, y, SWEEP_END)?;
+        let step = if self.points > 1 {
+            (end - start) / (self.points - 1) as f32
+        } else {
+            0.0
+        };
+        Some((0..self.points).map(|i| start + step * i as f32).collect())
+    }
+
+    /// Nominal tip position of the point at `x`, `y`; see
+    /// [`GridConfig::position`].
+    pub fn position(&self, x: usize, y: usize) -> Position {
+        self.config.position(x, y)
+    }
+
+    /// Store a bias spectrum measured at `x`, `y`.
+    ///
+    /// The channels of the grid are taken from the spectrum by name. The
+    /// sweep start and end come from its sweep signal channel, and the
+    /// `X (m)` and `Y (m)` parameters, if present, from
+    /// [`position`](Self::position).
+    pub fn set_spectrum(
+        &mut self,
+        x: usize,
+        y: usize,
+        spectrum: &BiasSpectrResult,
+    ) -> Result<(), NanonisError> {
+        let index = self
+            .point_index(x, y)
+            .ok_or_else(|| invalid(format!("point ({x}, {y}) is outside the grid")))?;
+        let row = |name: &str| {
+            spectrum
+                .channel_names
+                .iter()
+                .position(|n| n == name)
+                .and_then(|i| spectrum.data.row(i))
+                .ok_or_else(|| invalid(format!("spectrum has no {name} channel")))
+        };
+
+        let mut data = Vec::with_capacity(self.channels.len() * self.points);
+        for channel in &self.channels {
+            let values = row(channel)?;
+            if values.len() != self.points {
+                return Err(invalid(format!(
+                    "spectrum has {} points, the grid {}",
+                    values.len(),
+                    self.points
+                )));
+            }
+            data.extend_from_slice(values);
+        }
+        let sweep = row(&self.sweep_signal)?;
+
+        let position = self.position(x, y);
+        let parameters = self
+            .fixed_parameters
+            .iter()
+            .chain(&self.experiment_parameters)
+            .map(|name| match name.as_str() {
+                SWEEP_START => sweep.first().copied().unwrap_or(f32::NAN),
+                SWEEP_END => sweep.last().copied().unwrap_or(f32::NAN),
+                "X (m)" => position.x as f32,
+                "Y (m)" => position.y as f32,
+                _ => f32::NAN,
+            })
+            .collect();
+
+        self.grid[index] = GridPoint {
+            parameters,
+            data: Array2D::from_vec(self.channels.len(), self.points, data)?,
+        };
+        Ok(())
+    }
+
+    fn point_index(&self, x: usize, y: usize) -> Option<usize> {
+        let (width, height) = self.dimensions();
+        (x < width && y < height).then_some(y * width + x)
+    }
+}
+
+impl Index<(usize, usize)> for GridFile {
+    type Output = GridPoint;
+
+    fn index(&self, (x, y): (usize, usize)) -> &GridPoint {
+        let (width, height) = self.dimensions();
+        self.point(x, y)
+            .unwrap_or_else(|| panic!("point ({x}, {y}) out of bounds for {width}x{height} grid"))
+    }
+}
+
+impl IndexMut<(usize, usize)> for GridFile {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut GridPoint {
+        let (width, height) = self.dimensions();
+        self.point_mut(x, y)
+            .unwrap_or_else(|| panic!("point ({x}, {y}) out of bounds for {width}x{height} grid"))
+    }
+}
+
+/// Number of points along `x` and `y`, negative counts as zero.
+fn dimensions(config: &GridConfig) -> (usize, usize) {
+    (
+        config.num_points_x.max(0) as usize,
+        config.num_points_y.max(0) as usize,
+    )
+}
+
+/// Split a `;`-separated list of names.
+fn names(value: &str) -> Vec<String> {
+    value
+        .split(';')
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
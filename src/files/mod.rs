@@ -0,0 +1,91 @@
+//! Readers and writers for the Nanonis data file formats.
+
+use crate::error::NanonisError;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub mod dat;
+pub mod grid;
+pub mod sink;
+pub mod sxm;
+
+/// Read a whole data file.
+fn read(path: &Path) -> Result<Vec<u8>, NanonisError> {
+    std::fs::read(path).map_err(|e| NanonisError::Io {
+        source: e,
+        context: format!("Reading {}", path.display()),
+    })
+}
+
+/// Write a whole data file.
+fn write(path: &Path, bytes: &[u8]) -> Result<(), NanonisError> {
+    std::fs::write(path, bytes).map_err(|e| NanonisError::Io {
+        source: e,
+        context: format!("Writing {}", path.display()),
+    })
+}
+
+/// Error for malformed file contents.
+fn invalid(message: impl Into<String>) -> NanonisError {
+    NanonisError::InvalidFile(message.into())
+}
+
+/// Position of `needle` in `haystack`.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Current UTC date and time as `("DD.MM.YYYY", "HH:MM:SS")`.
+fn timestamp_now() -> (String, String) {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |t| t.as_secs() as i64);
+    let (days, secs_of_day) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+
+    // Civil date from days since 1970-01-01 (H. Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (
+        format!("{day:02}.{month:02}.{year:04}"),
+        format!(
+            "{:02}:{:02}:{:02}",
+            secs_of_day / 3600,
+            secs_of_day % 3600 / 60,
+            secs_of_day % 60
+        ),
+    )
+}
+
+/// Format in scientific notation with an explicit exponent sign, e.g.
+/// `1.000E+0`, as written by Nanonis.
+pub(crate) fn sci(value: f64, precision: usize) -> String {
+    let formatted = format!("{value:.precision$E}");
+    match formatted.split_once('E') {
+        Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+            format!("{mantissa}E+{exponent}")
+        }
+        _ => formatted,
+    }
+}
+
+/// Split a signal name such as `"Current (A)"` into name and unit.
+pub(crate) fn split_unit(signal: &str) -> (&str, &str) {
+    match signal
+        .trim_end()
+        .strip_suffix(')')
+        .and_then(|s| s.rsplit_once(" ("))
+    {
+        Some((name, unit)) => (name, unit),
+        None => (signal.trim_end(), ""),
+    }
+}
@@ -0,0 +1,568 @@
+use super::{invalid, read};
+use crate::error::NanonisError;
+use crate::signals::SignalFrame;
+use crate::types::Array2D;
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use log::{debug, warn};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Cursor, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Magic bytes at the start of a binary frame log.
+pub const BINARY_MAGIC: [u8; 4] = *b"NLOG";
+
+/// Layout version written to binary frame logs.
+pub const BINARY_VERSION: u16 = 1;
+
+/// Consumer of TCP Logger frames.
+///
+/// Implemented by [`RotatingSink`]; implement it for other storage to plug
+/// it into the same acquisition code.
+pub trait FrameSink {
+    /// Write one frame.
+    fn write_frame(&mut self, frame: &SignalFrame) -> Result<(), NanonisError>;
+
+    /// Write buffered frames to the underlying storage.
+    fn flush(&mut self) -> Result<(), NanonisError>;
+}
+
+/// File format written by a [`RotatingSink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinkFormat {
+    /// Comma-separated text: a header row `Counter,Time (s),<channel names>`
+    /// and one row per frame
+    Csv,
+    /// Binary columnar layout described at [`BinaryLog`]; frames are
+    /// written in blocks of `block_rows` frames
+    Binary {
+        /// Frames per block
+        block_rows: usize,
+    },
+}
+
+impl SinkFormat {
+    /// Binary layout with blocks of 1024 frames.
+    pub const BINARY: SinkFormat = SinkFormat::Binary { block_rows: 1024 };
+
+    /// File extension without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SinkFormat::Csv => "csv",
+            SinkFormat::Binary { .. } => "nlog",
+        }
+    }
+}
+
+/// When a [`RotatingSink`] starts a new file.
+///
+/// Limits are checked before each row or block is written, so a file can
+/// exceed `max_bytes` by one row or block.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rotation {
+    /// Start a new file once the current one holds this many bytes
+    pub max_bytes: Option<u64>,
+    /// Start a new file once the current one is this old
+    pub max_age: Option<Duration>,
+}
+
+/// Writes frames to a series of files, starting a new one by size or age.
+///
+/// Files are named `<prefix>_0000.<ext>`, `<prefix>_0001.<ext>`, ... in the
+/// output directory; existing files are never overwritten, numbering
+/// continues after them. Every file starts with the channel names, so each
+/// can be read on its own.
+///
+/// Each frame is written with its counter and a time in seconds: the
+/// frame's [`timestamp`](SignalFrame::timestamp) if the stream knows the RT
+/// frequency, otherwise the time since the sink received its first frame.
+///
+/// Buffered data is flushed by [`flush`](FrameSink::flush),
+/// [`finish`](Self::finish) and on drop.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::signals::SignalFrame;
+/// use nanonis_rs::sink::{FrameSink, Rotation, RotatingSink, SinkFormat};
+/// use nanonis_rs::tcplog::TCPLogStatus;
+/// use std::time::Duration;
+///
+/// let dir = std::env::temp_dir().join("nanonis-rs-sink-example");
+/// # std::fs::remove_dir_all(&dir).ok();
+/// let mut sink = RotatingSink::create(&dir, "current", ["Current (A)", "Z (m)"], SinkFormat::Csv)?
+///     .with_rotation(Rotation { max_bytes: Some(64), max_age: None });
+///
+/// for counter in 0..4 {
+///     sink.write_frame(&SignalFrame {
+///         counter,
+///         data: vec![1e-10, 2e-9],
+///         oversampling: 10.0,
+///         state: TCPLogStatus::Running,
+///         timestamp: Some(Duration::from_millis(counter)),
+///     })?;
+/// }
+/// let files = sink.finish()?;
+///
+/// assert_eq!(files.len(), 2);
+/// let first = std::fs::read_to_string(&files[0])?;
+/// assert_eq!(first.lines().next(), Some("Counter,Time (s),Current (A),Z (m)"));
+/// assert_eq!(first.lines().nth(2), Some("1,0.001,1e-10,2e-9"));
+/// # std::fs::remove_dir_all(&dir).ok();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct RotatingSink {
+    dir: PathBuf,
+    prefix: String,
+    channel_names: Vec<String>,
+    format: SinkFormat,
+    rotation: Rotation,
+    file: Option<OpenFile>,
+    block: Block,
+    files: Vec<PathBuf>,
+    next_index: u32,
+    started: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct OpenFile {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    bytes: u64,
+    opened: Instant,
+}
+
+/// Frames collected for the next binary block, stored by column.
+#[derive(Debug, Default)]
+struct Block {
+    counters: Vec<u64>,
+    times: Vec<f64>,
+    columns: Vec<Vec<f32>>,
+}
+
+impl RotatingSink {
+    /// Sink writing files to `dir`, which is created if needed.
+    ///
+    /// No file is opened before the first frame.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the directory cannot be created or a
+    /// binary format has zero rows per block.
+    pub fn create<S: Into<String>>(
+        dir: impl AsRef<Path>,
+        prefix: &str,
+        channel_names: impl IntoIterator<Item = S>,
+        format: SinkFormat,
+    ) -> Result<Self, NanonisError> {
+        if format == (SinkFormat::Binary { block_rows: 0 }) {
+            return Err(NanonisError::Protocol(
+                "Binary sink needs at least one row per block".to_string(),
+            ));
+        }
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).map_err(|e| NanonisError::Io {
+            source: e,
+            context: format!("Creating {}", dir.display()),
+        })?;
+
+        let channel_names: Vec<String> = channel_names.into_iter().map(Into::into).collect();
+        Ok(Self {
+            dir,
+            prefix: prefix.to_string(),
+            block: Block {
+                columns: vec![Vec::new(); channel_names.len()],
+                ..Block::default()
+            },
+            channel_names,
+            format,
+            rotation: Rotation::default(),
+            file: None,
+            files: Vec::new(),
+            next_index: 0,
+            started: None,
+        })
+    }
+
+    /// Start new files according to `rotation`.
+    pub fn with_rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Channel names written to every file.
+    pub fn channel_names(&self) -> &[String] {
+        &self.channel_names
+    }
+
+    /// Files opened so far, in order.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Flush and close the current file and return all files written.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if writing buffered data fails.
+    pub fn finish(mut self) -> Result<Vec<PathBuf>, NanonisError> {
+        FrameSink::flush(&mut self)?;
+        self.file = None;
+        Ok(std::mem::take(&mut self.files))
+    }
+
+    /// Write to the current file, first starting a new one if the current
+    /// file exceeds a rotation limit.
+    fn write(&mut self, bytes: &[u8]) -> Result<(), NanonisError> {
+        let expired = self.file.as_ref().is_some_and(|file| {
+            self.rotation.max_bytes.is_some_and(|max| file.bytes >= max)
+                || self
+                    .rotation
+                    .max_age
+                    .is_some_and(|max| file.opened.elapsed() >= max)
+        });
+        if expired {
+            if let Some(mut file) = self.file.take() {
+                file.flush()?;
+            }
+        }
+
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => {
+                let file = self.open_next()?;
+                self.file.insert(file)
+            }
+        };
+        file.write(bytes)
+    }
+
+    /// Create the next unused file and write its header.
+    fn open_next(&mut self) -> Result<OpenFile, NanonisError> {
+        let (path, handle) = loop {
+            let path = self.dir.join(format!(
+                "{}_{:04}.{}",
+                self.prefix,
+                self.next_index,
+                self.format.extension()
+            ));
+            self.next_index += 1;
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(handle) => break (path, handle),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => {
+                    return Err(NanonisError::Io {
+                        source: e,
+                        context: format!("Creating {}", path.display()),
+                    })
+                }
+            }
+        };
+        debug!("Writing frames to {}", path.display());
+
+        let header = match self.format {
+            SinkFormat::Csv => {
+                let mut line = String::from("Counter,Time (s)");
+                for name in &self.channel_names {
+                    line.push(',');
+                    line.push_str(&csv_field(name));
+                }
+                line.push('\n');
+                line.into_bytes()
+            }
+            SinkFormat::Binary { .. } => binary_header(&self.channel_names)?,
+        };
+
+        let mut file = OpenFile {
+            writer: BufWriter::new(handle),
+            path: path.clone(),
+            bytes: 0,
+            opened: Instant::now(),
+        };
+        file.write(&header)?;
+        self.files.push(path);
+        Ok(file)
+    }
+
+    /// Write the collected binary block, if any.
+    fn write_block(&mut self) -> Result<(), NanonisError> {
+        if self.block.counters.is_empty() {
+            return Ok(());
+        }
+
+        let rows = self.block.counters.len();
+        let mut bytes = Vec::with_capacity(4 + rows * (12 + 4 * self.channel_names.len()));
+        bytes.write_u32::<BigEndian>(rows as u32)?;
+        for &counter in &self.block.counters {
+            bytes.write_u64::<BigEndian>(counter)?;
+        }
+        for &time in &self.block.times {
+            bytes.write_f64::<BigEndian>(time)?;
+        }
+        for column in &self.block.columns {
+            for &value in column {
+                bytes.write_f32::<BigEndian>(value)?;
+            }
+        }
+
+        self.block.counters.clear();
+        self.block.times.clear();
+        self.block.columns.iter_mut().for_each(Vec::clear);
+
+        self.write(&bytes)
+    }
+}
+
+impl FrameSink for RotatingSink {
+    fn write_frame(&mut self, frame: &SignalFrame) -> Result<(), NanonisError> {
+        if frame.data.len() != self.channel_names.len() {
+            return Err(NanonisError::Protocol(format!(
+                "Frame has {} channels, sink expects {}",
+                frame.data.len(),
+                self.channel_names.len()
+            )));
+        }
+        let started = *self.started.get_or_insert_with(Instant::now);
+        let time = frame
+            .timestamp
+            .unwrap_or_else(|| started.elapsed())
+            .as_secs_f64();
+
+        match self.format {
+            SinkFormat::Csv => {
+                let mut line = format!("{},{time}", frame.counter);
+                for value in &frame.data {
+                    line.push_str(&format!(",{value:e}"));
+                }
+                line.push('\n');
+                self.write(line.as_bytes())
+            }
+            SinkFormat::Binary { block_rows } => {
+                self.block.counters.push(frame.counter);
+                self.block.times.push(time);
+                for (column, &value) in self.block.columns.iter_mut().zip(&frame.data) {
+                    column.push(value);
+                }
+                if self.block.counters.len() >= block_rows {
+                    self.write_block()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), NanonisError> {
+        self.write_block()?;
+        match self.file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for RotatingSink {
+    fn drop(&mut self) {
+        if let Err(e) = FrameSink::flush(self) {
+            warn!("Failed to flush frame sink: {e}");
+        }
+    }
+}
+
+/// Contents of a binary frame log written with [`SinkFormat::Binary`].
+///
+/// # Layout
+///
+/// All numbers are big-endian, like the Nanonis protocol. The header is
+///
+/// | Field              | Type      | Value                 |
+/// |--------------------|-----------|-----------------------|
+/// | magic              | 4 bytes   | `NLOG`                |
+/// | version            | `u16`     | 1                     |
+/// | channel count `n`  | `u32`     |                       |
+/// | per channel: name  | `u16` length, then UTF-8 bytes | e.g. `Current (A)` |
+///
+/// followed by blocks up to the end of the file, each holding `r` frames
+/// column by column:
+///
+/// | Field       | Type          | Value                        |
+/// |-------------|---------------|------------------------------|
+/// | rows `r`    | `u32`         |                              |
+/// | counters    | `r` × `u64`   | frame counters               |
+/// | times       | `r` × `f64`   | seconds, see [`RotatingSink`] |
+/// | channel 0   | `r` × `f32`   | values of the first channel  |
+/// | ...         |               |                              |
+/// | channel n-1 | `r` × `f32`   |                              |
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::signals::SignalFrame;
+/// use nanonis_rs::sink::{BinaryLog, FrameSink, RotatingSink, SinkFormat};
+/// use nanonis_rs::tcplog::TCPLogStatus;
+///
+/// let dir = std::env::temp_dir().join("nanonis-rs-binary-sink-example");
+/// # std::fs::remove_dir_all(&dir).ok();
+/// let format = SinkFormat::Binary { block_rows: 3 };
+/// let mut sink = RotatingSink::create(&dir, "z", ["Z (m)"], format)?;
+/// for counter in 0..5 {
+///     sink.write_frame(&SignalFrame {
+///         counter,
+///         data: vec![counter as f32 * 1e-12],
+///         oversampling: 1.0,
+///         state: TCPLogStatus::Running,
+///         timestamp: None,
+///     })?;
+/// }
+/// let files = sink.finish()?;
+///
+/// let log = BinaryLog::read(&files[0])?;
+/// assert_eq!(log.channel_names, vec!["Z (m)"]);
+/// assert_eq!(log.counters, vec![0, 1, 2, 3, 4]);
+/// assert_eq!(log.column("Z (m)").unwrap()[4], 4e-12);
+///
+/// // A log cut off inside its last block keeps the complete blocks
+/// let bytes = std::fs::read(&files[0])?;
+/// let log = BinaryLog::parse(&bytes[..bytes.len() - 2])?;
+/// assert!(log.truncated);
+/// assert_eq!(log.counters, vec![0, 1, 2]);
+/// # std::fs::remove_dir_all(&dir).ok();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BinaryLog {
+    /// Channel names, including units
+    pub channel_names: Vec<String>,
+    /// Frame counters
+    pub counters: Vec<u64>,
+    /// Frame times in seconds
+    pub times: Vec<f64>,
+    /// One row per channel, one column per frame
+    pub data: Array2D<f32>,
+    /// Whether the file ends in an incomplete block, e.g. after a crash or
+    /// power loss; the frames of that block are not included
+    pub truncated: bool,
+}
+
+impl BinaryLog {
+    /// Read a binary frame log.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, NanonisError> {
+        Self::parse(&read(path.as_ref())?)
+    }
+
+    /// Parse a binary frame log from its bytes.
+    ///
+    /// An incomplete last block is skipped and reported through
+    /// [`truncated`](Self::truncated); only a damaged header is an error.
+    pub fn parse(bytes: &[u8]) -> Result<Self, NanonisError> {
+        let mut cursor = Cursor::new(bytes);
+        let truncated = |_| invalid("Binary frame log is truncated");
+
+        let mut magic = [0u8; 4];
+        std::io::Read::read_exact(&mut cursor, &mut magic).map_err(truncated)?;
+        if magic != BINARY_MAGIC {
+            return Err(invalid("Not a binary frame log"));
+        }
+        let version = cursor.read_u16::<BigEndian>().map_err(truncated)?;
+        if version != BINARY_VERSION {
+            return Err(invalid(format!(
+                "Unsupported binary frame log version {version}"
+            )));
+        }
+
+        let channels = cursor.read_u32::<BigEndian>().map_err(truncated)? as usize;
+        let mut channel_names = Vec::with_capacity(channels.min(1024));
+        for _ in 0..channels {
+            let len = cursor.read_u16::<BigEndian>().map_err(truncated)? as usize;
+            let mut name = vec![0u8; len];
+            std::io::Read::read_exact(&mut cursor, &mut name).map_err(truncated)?;
+            channel_names
+                .push(String::from_utf8(name).map_err(|_| invalid("Channel name is not UTF-8"))?);
+        }
+
+        let mut counters = Vec::new();
+        let mut times = Vec::new();
+        let mut columns = vec![Vec::new(); channels];
+        let mut incomplete = false;
+        while (cursor.position() as usize) < bytes.len() {
+            let Ok(rows) = cursor.read_u32::<BigEndian>() else {
+                incomplete = true;
+                break;
+            };
+            let remaining = bytes.len() - cursor.position() as usize;
+            let size = channels
+                .checked_mul(4)
+                .and_then(|n| n.checked_add(16))
+                .and_then(|n| n.checked_mul(rows as usize));
+            if size.is_none_or(|size| size > remaining) {
+                incomplete = true;
+                break;
+            }
+            for _ in 0..rows {
+                counters.push(cursor.read_u64::<BigEndian>()?);
+            }
+            for _ in 0..rows {
+                times.push(cursor.read_f64::<BigEndian>()?);
+            }
+            for column in &mut columns {
+                for _ in 0..rows {
+                    column.push(cursor.read_f32::<BigEndian>()?);
+                }
+            }
+        }
+
+        let frames = counters.len();
+        let data = Array2D::from_vec(channels, frames, columns.concat())?;
+        Ok(Self {
+            channel_names,
+            counters,
+            times,
+            data,
+            truncated: incomplete,
+        })
+    }
+
+    /// Values of the channel with the given name.
+    pub fn column(&self, name: &str) -> Option<&[f32]> {
+        let index = self.channel_names.iter().position(|n| n == name)?;
+        self.data.row(index)
+    }
+}
+
+/// Header of a binary frame log.
+fn binary_header(channel_names: &[String]) -> Result<Vec<u8>, NanonisError> {
+    let mut bytes = BINARY_MAGIC.to_vec();
+    bytes.write_u16::<BigEndian>(BINARY_VERSION)?;
+    bytes.write_u32::<BigEndian>(channel_names.len() as u32)?;
+    for name in channel_names {
+        let len = u16::try_from(name.len())
+            .map_err(|_| NanonisError::Protocol(format!("Channel name too long: {name}")))?;
+        bytes.write_u16::<BigEndian>(len)?;
+        bytes.extend_from_slice(name.as_bytes());
+    }
+    Ok(bytes)
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl OpenFile {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), NanonisError> {
+        self.writer.write_all(bytes).map_err(|e| self.error(e))?;
+        self.bytes += bytes.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), NanonisError> {
+        self.writer.flush().map_err(|e| self.error(e))
+    }
+
+    fn error(&self, source: std::io::Error) -> NanonisError {
+        NanonisError::Io {
+            source,
+            context: format!("Writing {}", self.path.display()),
+        }
+    }
+}
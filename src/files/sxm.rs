@@ -0,0 +1,513 @@
+use super::{find, invalid, read, sci, split_unit, timestamp_now, write};
+use crate::client::scan::{ScanDirection, ScanFrame};
+use crate::client::NanonisClient;
+use crate::error::NanonisError;
+use crate::signals::SignalSlot;
+use crate::types::{Array2D, Position};
+use std::path::Path;
+
+const HEADER_END: &str = ":SCANIT_END:";
+const DATA_START: [u8; 2] = [0x1a, 0x04];
+
+/// Scan directions recorded for a channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelDirection {
+    Forward,
+    Backward,
+    Both,
+}
+
+impl ChannelDirection {
+    fn as_str(self) -> &'static str {
+        match self {
+            ChannelDirection::Forward => "forward",
+            ChannelDirection::Backward => "backward",
+            ChannelDirection::Both => "both",
+        }
+    }
+}
+
+/// One channel of an `.sxm` image, a row of `:DATA_INFO:` plus its data.
+///
+/// Images are stored as in the file: one row per scan line in recording
+/// order, each row in the order the pixels were acquired.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SxmChannel {
+    /// Signal index in the Signals Manager
+    pub index: i32,
+    /// Signal name without unit, e.g. `"Z"`
+    pub name: String,
+    /// Physical unit, e.g. `"m"`
+    pub unit: String,
+    /// Calibration factor from `:DATA_INFO:`
+    pub calibration: f64,
+    /// Offset from `:DATA_INFO:`
+    pub offset: f64,
+    /// Forward scan image, `lines` × `pixels`
+    pub forward: Option<Array2D<f32>>,
+    /// Backward scan image, `lines` × `pixels`
+    pub backward: Option<Array2D<f32>>,
+}
+
+impl SxmChannel {
+    /// Channel without data, calibration 1 and offset 0.
+    pub fn new(index: i32, name: impl Into<String>, unit: impl Into<String>) -> Self {
+        Self {
+            index,
+            name: name.into(),
+            unit: unit.into(),
+            calibration: 1.0,
+            offset: 0.0,
+            forward: None,
+            backward: None,
+        }
+    }
+
+    /// Set the forward scan image.
+    pub fn with_forward(mut self, data: Array2D<f32>) -> Self {
+        self.forward = Some(data);
+        self
+    }
+
+    /// Set the backward scan image.
+    pub fn with_backward(mut self, data: Array2D<f32>) -> Self {
+        self.backward = Some(data);
+        self
+    }
+
+    /// Directions with data, or `None` if the channel holds no image.
+    pub fn direction(&self) -> Option<ChannelDirection> {
+        match (&self.forward, &self.backward) {
+            (Some(_), Some(_)) => Some(ChannelDirection::Both),
+            (Some(_), None) => Some(ChannelDirection::Forward),
+            (None, Some(_)) => Some(ChannelDirection::Backward),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Nanonis `.sxm` scan image.
+///
+/// An `.sxm` file is an ASCII header of `:KEY:` lines, each followed by its
+/// value, terminated by `:SCANIT_END:`. The channel images follow as
+/// big-endian `f32` blocks, in `:DATA_INFO:` order with the forward image
+/// before the backward one.
+///
+/// The typed fields take precedence over the corresponding entries of
+/// [`header`](Self::header) when writing; all other entries are written
+/// back unchanged.
+///
+/// # Examples
+/// Grab a scan from the instrument and round-trip it through a file:
+/// ```
+/// use nanonis_rs::sxm::SxmFile;
+/// use nanonis_rs::scan::{ScanAction, ScanDirection};
+/// use nanonis_rs::MockServer;
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// # client.scan_buffer_set(vec![0, 2], 16, 16)?;
+/// # client.scan_config_set(nanonis_rs::scan::ScanConfig {
+/// #     forward_linear_speed_m_s: 1e-6,
+/// #     backward_linear_speed_m_s: 1e-6,
+/// #     forward_time_per_line_s: 0.005,
+/// #     backward_time_per_line_s: 0.005,
+/// #     keep_parameter_constant: 2,
+/// #     speed_ratio: 1.0,
+/// # })?;
+/// client.scan_action(ScanAction::Start, ScanDirection::Up)?;
+/// client.scan_wait_end_of_scan(Duration::from_secs(5))?;
+///
+/// let image = SxmFile::grab(&mut client)?;
+/// let path = std::env::temp_dir().join("nanonis-rs-sxm-example.sxm");
+/// image.write(&path)?;
+///
+/// let read = SxmFile::read(&path)?;
+/// let z = read.channel("Z").unwrap();
+/// assert_eq!(z.unit, "m");
+/// assert_eq!(z.forward, image.channel("Z").unwrap().forward);
+/// assert_eq!((read.pixels, read.lines), (16, 16));
+/// # std::fs::remove_file(&path).ok();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SxmFile {
+    /// Scan frame from `:SCAN_OFFSET:`, `:SCAN_RANGE:` and `:SCAN_ANGLE:`
+    pub frame: ScanFrame,
+    /// Pixels per line
+    pub pixels: usize,
+    /// Number of lines
+    pub lines: usize,
+    /// Scan direction from `:SCAN_DIR:`
+    pub direction: ScanDirection,
+    /// Channels in `:DATA_INFO:` order
+    pub channels: Vec<SxmChannel>,
+    /// All header entries in file order, keys without the colons
+    pub header: Vec<(String, String)>,
+}
+
+impl SxmFile {
+    /// Empty image of `pixels` × `lines` with a minimal header.
+    pub fn new(frame: ScanFrame, pixels: usize, lines: usize, direction: ScanDirection) -> Self {
+        let mut image = Self {
+            frame,
+            pixels,
+            lines,
+            direction,
+            channels: Vec::new(),
+            header: Vec::new(),
+        };
+
+        let (date, time) = timestamp_now();
+        image.set_header("NANONIS_VERSION", "2");
+        for (key, value) in image.scan_entries() {
+            image.set_header(key, value);
+            if key == "SCANIT_TYPE" {
+                image.set_header("REC_DATE", format!(" {date}"));
+                image.set_header("REC_TIME", time.clone());
+            }
+        }
+        image
+    }
+
+    /// Grab the current scan buffer from the instrument.
+    ///
+    /// Reads the scan frame and the forward and backward image of every
+    /// channel in the scan buffer. The buffer's slots are mapped to signal
+    /// indexes through the [signal registry](NanonisClient::signal_registry).
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::sxm::SxmFile;
+    /// use nanonis_rs::{MockServer, SimulatedStm};
+    ///
+    /// let server = MockServer::start()?;
+    /// let sim = SimulatedStm::new();
+    /// sim.attach(&server);
+    /// // Current (signal 0) in slot 2, Z (signal 2) in slot 0
+    /// sim.update(|state| state.slot_signals.swap(0, 2));
+    /// let mut client = server.client()?;
+    /// client.scan_buffer_set(["Z"], 16, 16)?;
+    /// assert_eq!(client.scan_buffer_get()?.0, vec![0]);
+    ///
+    /// let image = SxmFile::grab(&mut client)?;
+    /// assert_eq!((image.channels[0].name.as_str(), image.channels[0].index), ("Z", 2));
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn grab(client: &mut NanonisClient) -> Result<Self, NanonisError> {
+        let frame = client.scan_frame_get()?;
+        let (slots, pixels, lines) = client.scan_buffer_get()?;
+
+        let mut direction = ScanDirection::Down;
+        let mut channels = Vec::with_capacity(slots.len());
+        for slot in slots {
+            let index = client.resolve_signal(SignalSlot(slot as u8))?;
+            let (signal, forward, scan_up) = client.scan_frame_data_grab(slot as u32, true)?;
+            let (_, backward, _) = client.scan_frame_data_grab(slot as u32, false)?;
+            if scan_up {
+                direction = ScanDirection::Up;
+            }
+
+            let (name, unit) = split_unit(&signal);
+            channels.push(
+                SxmChannel::new(index, name, unit)
+                    .with_forward(forward)
+                    .with_backward(backward),
+            );
+        }
+
+        let mut image = Self::new(frame, pixels as usize, lines as usize, direction);
+        image.channels = channels;
+        Ok(image)
+    }
+
+    /// Read an `.sxm` file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, NanonisError> {
+        Self::parse(&read(path.as_ref())?)
+    }
+
+    /// Write an `.sxm` file.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), NanonisError> {
+        write(path.as_ref(), &self.to_bytes()?)
+    }
+
+    /// Parse the contents of an `.sxm` file.
+    pub fn parse(bytes: &[u8]) -> Result<Self, NanonisError> {
+        let header_end = find(bytes, HEADER_END.as_bytes())
+            .ok_or_else(|| invalid(format!("missing {HEADER_END}")))?;
+        let data_start = find(&bytes[header_end..], &DATA_START)
+            .map(|offset| header_end + offset + DATA_START.len())
+            .ok_or_else(|| invalid("missing data start marker"))?;
+
+        let header = parse_header(&String::from_utf8_lossy(&bytes[..header_end]));
+        let value = |key: &str| {
+            header
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+                .ok_or_else(|| invalid(format!("missing :{key}:")))
+        };
+
+        let scanit_type = value("SCANIT_TYPE")?;
+        if !scanit_type.contains("FLOAT") {
+            return Err(invalid(format!(
+                "unsupported data type {}",
+                scanit_type.trim()
+            )));
+        }
+        let big_endian = !scanit_type.contains("LSBFIRST");
+
+        let [pixels, lines] = numbers::<2>(value("SCAN_PIXELS")?, "SCAN_PIXELS")?;
+        let (pixels, lines) = (pixels as usize, lines as usize);
+        let [width, height] = numbers::<2>(value("SCAN_RANGE")?, "SCAN_RANGE")?;
+        let [x, y] = numbers::<2>(value("SCAN_OFFSET")?, "SCAN_OFFSET")?;
+        let angle = match value("SCAN_ANGLE") {
+            Ok(angle) => numbers::<1>(angle, "SCAN_ANGLE")?[0],
+            Err(_) => 0.0,
+        };
+        let direction = match value("SCAN_DIR").map(str::trim) {
+            Ok("up") => ScanDirection::Up,
+            _ => ScanDirection::Down,
+        };
+
+        let mut data = &bytes[data_start..];
+        let mut channels = Vec::new();
+        for row in value("DATA_INFO")?.lines().skip(1) {
+            let columns: Vec<&str> = row.trim().split('\t').map(str::trim).collect();
+            if columns.len() < 6 {
+                continue;
+            }
+
+            let mut channel = SxmChannel::new(
+                columns[0]
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid channel index {}", columns[0])))?,
+                columns[1],
+                columns[2],
+            );
+            channel.calibration = columns[4].parse().unwrap_or(1.0);
+            channel.offset = columns[5].parse().unwrap_or(0.0);
+
+            let (has_forward, has_backward) = match columns[3] {
+                "forward" => (true, false),
+                "backward" => (false, true),
+                _ => (true, true),
+            };
+            if has_forward {
+                channel.forward = Some(take_image(&mut data, pixels, lines, big_endian)?);
+            }
+            if has_backward {
+                channel.backward = Some(take_image(&mut data, pixels, lines, big_endian)?);
+            }
+            channels.push(channel);
+        }
+
+        Ok(Self {
+            frame: ScanFrame {
+                center: Position { x, y },
+                width_m: width as f32,
+                height_m: height as f32,
+                angle_deg: angle as f32,
+            },
+            pixels,
+            lines,
+            direction,
+            channels,
+            header,
+        })
+    }
+
+    /// Serialize to the contents of an `.sxm` file.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, NanonisError> {
+        let mut managed = self.scan_entries();
+
+        let mut data_info = "\tChannel\tName\tUnit\tDirection\tCalibration\tOffset".to_string();
+        let mut data = Vec::new();
+        for channel in &self.channels {
+            let Some(direction) = channel.direction() else {
+                continue;
+            };
+            data_info.push_str(&format!(
+                "\n\t{}\t{}\t{}\t{}\t{}\t{}",
+                channel.index,
+                channel.name,
+                channel.unit,
+                direction.as_str(),
+                sci(channel.calibration, 3),
+                sci(channel.offset, 3)
+            ));
+
+            for image in [&channel.forward, &channel.backward].into_iter().flatten() {
+                if image.shape() != (self.lines, self.pixels) {
+                    return Err(invalid(format!(
+                        "{} image is {}x{}, expected {}x{}",
+                        channel.name,
+                        image.rows(),
+                        image.cols(),
+                        self.lines,
+                        self.pixels
+                    )));
+                }
+                data.reserve(image.len() * 4);
+                for value in image.as_slice() {
+                    data.extend_from_slice(&value.to_be_bytes());
+                }
+            }
+        }
+        data_info.push('\n');
+        managed.push(("DATA_INFO", data_info));
+
+        let mut text = String::new();
+        let mut push = |key: &str, value: &str| {
+            text.push_str(&format!(":{key}:\n{value}\n"));
+        };
+        for (key, value) in &self.header {
+            if key == "DATA_INFO" || key == "SCANIT_END" {
+                continue;
+            }
+            match managed.iter().position(|(k, _)| k == key) {
+                Some(i) => {
+                    let (key, value) = managed.remove(i);
+                    push(key, &value);
+                }
+                None => push(key, value),
+            }
+        }
+        // Keys missing from the header, DATA_INFO last
+        for (key, value) in &managed {
+            push(key, value);
+        }
+
+        let mut bytes = text.into_bytes();
+        bytes.extend_from_slice(HEADER_END.as_bytes());
+        bytes.extend_from_slice(b"\n\n");
+        bytes.extend_from_slice(&DATA_START);
+        bytes.extend_from_slice(&data);
+        Ok(bytes)
+    }
+
+    /// Header entries derived from the typed fields, in Nanonis order.
+    fn scan_entries(&self) -> Vec<(&'static str, String)> {
+        let pair = |a: f64, b: f64| format!("{:>22}{:>22}", sci(a, 6), sci(b, 6));
+        vec![
+            (
+                "SCANIT_TYPE",
+                "              FLOAT            MSBFIRST".to_string(),
+            ),
+            (
+                "SCAN_PIXELS",
+                format!("{:>10}{:>10}", self.pixels, self.lines),
+            ),
+            (
+                "SCAN_RANGE",
+                pair(self.frame.width_m as f64, self.frame.height_m as f64),
+            ),
+            (
+                "SCAN_OFFSET",
+                pair(self.frame.center.x, self.frame.center.y),
+            ),
+            (
+                "SCAN_ANGLE",
+                format!("{:>20}", sci(self.frame.angle_deg as f64, 3)),
+            ),
+            (
+                "SCAN_DIR",
+                match self.direction {
+                    ScanDirection::Up => "up",
+                    ScanDirection::Down => "down",
+                }
+                .to_string(),
+            ),
+        ]
+    }
+
+    /// Value of a header entry, e.g. `header_value("BIAS")`.
+    pub fn header_value(&self, key: &str) -> Option<&str> {
+        self.header
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Set a header entry, replacing an existing one.
+    pub fn set_header(&mut self, key: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.header.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value,
+            None => self.header.push((key.to_string(), value)),
+        }
+    }
+
+    /// Channel with the given name.
+    pub fn channel(&self, name: &str) -> Option<&SxmChannel> {
+        self.channels.iter().find(|c| c.name == name)
+    }
+}
+
+/// Split the header into `(key, value)` entries, keeping multi-line values.
+fn parse_header(text: &str) -> Vec<(String, String)> {
+    let mut header: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        let is_key = line.len() > 2 && line.starts_with(':') && line.ends_with(':');
+        if is_key && !line[1..line.len() - 1].contains(':') {
+            header.push((line[1..line.len() - 1].to_string(), String::new()));
+        } else if let Some((_, value)) = header.last_mut() {
+            if !value.is_empty() {
+                value.push('\n');
+            }
+            value.push_str(line);
+        }
+    }
+    for (_, value) in &mut header {
+        value.truncate(value.trim_end_matches(['\n', '\r']).len());
+    }
+    header
+}
+
+/// Parse `N` whitespace-separated numbers of a header value.
+fn numbers<const N: usize>(value: &str, key: &str) -> Result<[f64; N], NanonisError> {
+    let parsed: Vec<f64> = value
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid(format!("invalid :{key}: value {}", value.trim())))?;
+    parsed
+        .get(..N)
+        .and_then(|slice| slice.try_into().ok())
+        .ok_or_else(|| invalid(format!(":{key}: needs {N} values")))
+}
+
+/// Decode the next `lines` × `pixels` image and advance `data`.
+fn take_image(
+    data: &mut &[u8],
+    pixels: usize,
+    lines: usize,
+    big_endian: bool,
+) -> Result<Array2D<f32>, NanonisError> {
+    let size = pixels
+        .checked_mul(lines)
+        .and_then(|n| n.checked_mul(4))
+        .ok_or_else(|| invalid(format!("image size {pixels} x {lines} too large")))?;
+    if data.len() < size {
+        return Err(invalid(format!(
+            "image data truncated: {} of {size} bytes",
+            data.len()
+        )));
+    }
+    let (image, rest) = data.split_at(size);
+    *data = rest;
+
+    let values = image
+        .chunks_exact(4)
+        .map(|b| {
+            let b = [b[0], b[1], b[2], b[3]];
+            if big_endian {
+                f32::from_be_bytes(b)
+            } else {
+                f32::from_le_bytes(b)
+            }
+        })
+        .collect();
+    Array2D::from_vec(lines, pixels, values)
+}
@@ -5,7 +5,7 @@
 //! ## Quick Start
 //!
 //! ```no_run
-//! use nanonis_rs::{NanonisClient, NanonisError};
+//! use nanonis_rs::{NanonisClient, NanonisError, Volts};
 //!
 //! fn main() -> Result<(), NanonisError> {
 //!     let mut client = NanonisClient::new("192.168.1.100", 6501)?;
@@ -15,7 +15,7 @@
 //!     println!("Current bias: {} V", bias);
 //!
 //!     // Set new bias voltage
-//!     client.bias_set(0.5)?;
+//!     client.bias_set(Volts::new(0.5))?;
 //!
 //!     Ok(())
 //! }
@@ -57,22 +57,88 @@
 //! println!("Got {} channels", frame.data.len());
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+//!
+//! ## Async
+//!
+//! With the `tokio` feature, `AsyncNanonisClient` offers every command
+//! method of `NanonisClient` as an `async fn` and `AsyncTCPLoggerStream`
+//! yields frames as a `Stream`:
+//!
+//! ```toml
+//! nanonis-rs = { version = "0.1", features = ["tokio"] }
+//! ```
+//!
+//! ## Testing Without Hardware
+//!
+//! With the `mock` feature, `MockServer` speaks the Nanonis TCP protocol on
+//! a local port and answers with registered replies, so client code can be
+//! tested offline:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! nanonis-rs = { version = "0.1", features = ["mock"] }
+//! ```
+//!
+//! ```
+//! use nanonis_rs::{MockServer, NanonisValue};
+//!
+//! let server = MockServer::start()?;
+//! server.respond("Bias.Get", vec![NanonisValue::F32(0.5)], vec!["f"]);
+//!
+//! let mut client = server.client()?;
+//! assert_eq!(client.bias_get()?, 0.5);
+//! # Ok::<(), nanonis_rs::NanonisError>(())
+//! ```
 
 // Internal modules
+#[cfg(feature = "tokio")]
+mod async_client;
 mod client;
+mod command;
 mod error;
+mod files;
+mod live_stats;
+#[cfg(feature = "mock")]
+mod mock;
 mod protocol;
+mod recording;
+mod shared_client;
+mod tcplogger_reader;
 mod tcplogger_stream;
+mod transport;
 mod types;
+mod units;
+mod watchdog;
 
 // ==================== Public API ====================
 
+pub use command::{Command, CommandResponse, WireValue};
 pub use error::NanonisError;
-pub use client::{NanonisClient, NanonisClientBuilder, ConnectionConfig};
-pub use tcplogger_stream::TCPLoggerStream;
+pub use client::{NanonisClient, NanonisClientBuilder, ConnectionConfig, ReconnectPolicy};
+pub use recording::{RecordedExchange, Recording, ReplayTransport};
+pub use live_stats::{Condition, RollingStats, Trigger, TriggerEvent, TriggerSet, WindowStats};
+pub use shared_client::{Priority, SharedNanonisClient};
+pub use tcplogger_reader::{
+    BackgroundReader, Backpressure, ExitReason, ReaderConfig, ReaderExit, ReaderStats,
+};
+pub use tcplogger_stream::{StreamEvent, StreamStats, TCPLoggerStream};
+pub use transport::{MemoryTransport, TcpTransport, Transport};
+#[cfg(feature = "mock")]
+pub use mock::{MockRequest, MockResponse, MockServer, SimState, SimulatedStm};
+pub use watchdog::{
+    EscapeAction, EscapeOutcome, Incident, TipWatchdog, Violation, WatchdogConfig,
+    WatchdogSource,
+};
+
+#[cfg(feature = "tokio")]
+pub use async_client::{AsyncNanonisClient, AsyncTCPLoggerStream, SignalFrameStream};
 
 // Re-export commonly used types from the internal types module
-pub use types::{NanonisValue, Position};
+pub use types::{Array2D, NanonisValue, Position};
+pub use units::{Amperes, Hertz, Meters, MetersPerSecond, Seconds, Setpoint, Volts};
+
+#[doc(hidden)]
+pub use command::checks as __command_checks;
 
 // ==================== Domain Type Modules ====================
 //
@@ -280,3 +346,108 @@ pub mod z_spectr {
 pub mod util {
     pub use crate::client::util::*;
 }
+
+/// Auto-approach command descriptors.
+pub mod auto_approach {
+    pub use crate::client::auto_approach::*;
+}
+
+/// Bias sweep command descriptors.
+pub mod bias_sweep {
+    pub use crate::client::bias_sweep::*;
+}
+
+/// Current measurement command descriptors.
+pub mod current {
+    pub use crate::client::current::*;
+}
+
+/// Laser command descriptors.
+pub mod laser {
+    pub use crate::client::laser::*;
+}
+
+/// Multi-pass command descriptors.
+pub mod mpass {
+    pub use crate::client::mpass::*;
+}
+
+/// PLL frequency sweep command descriptors.
+pub mod pll_freq_swp {
+    pub use crate::client::pll_freq_swp::*;
+}
+
+/// Safe tip command descriptors.
+pub mod safe_tip {
+    pub use crate::client::safe_tip::*;
+}
+
+/// User input command descriptors.
+pub mod user_in {
+    pub use crate::client::user_in::*;
+}
+
+/// Instrument state snapshot, diff and restore.
+///
+/// ```
+/// use nanonis_rs::state::{InstrumentState, StateChange};
+/// ```
+pub mod state {
+    pub use crate::client::state::*;
+}
+
+/// Safe-shutdown policies run on drop, on demand and on signals.
+///
+/// ```
+/// use nanonis_rs::shutdown::{ShutdownAction, ShutdownPolicy};
+/// ```
+pub mod shutdown {
+    pub use crate::client::shutdown::*;
+}
+
+/// Declarative experiment recipes and their runner.
+///
+/// ```
+/// use nanonis_rs::recipe::{Recipe, RecipeRunner, Step};
+/// ```
+pub mod recipe {
+    pub use crate::client::recipe::*;
+}
+
+// ==================== File Formats ====================
+
+/// Nanonis `.dat` spectroscopy and sweep files.
+///
+/// ```
+/// use nanonis_rs::dat::DatFile;
+/// ```
+pub mod dat {
+    pub use crate::files::dat::*;
+}
+
+/// Nanonis `.3ds` grid spectroscopy files.
+///
+/// ```
+/// use nanonis_rs::grid::{GridFile, GridPoint};
+/// ```
+pub mod grid {
+    pub use crate::files::grid::*;
+}
+
+/// Rotating CSV and binary files for TCP Logger frames.
+///
+/// ```
+/// use nanonis_rs::sink::{FrameSink, Rotation, RotatingSink, SinkFormat};
+/// ```
+pub mod sink {
+    pub use crate::files::sink::*;
+}
+
+/// Nanonis `.sxm` scan images.
+///
+/// ```
+/// use nanonis_rs::sxm::{SxmChannel, SxmFile};
+/// ```
+pub mod sxm {
+    pub use crate::files::sxm::*;
+}
@@ -0,0 +1,561 @@
+use crate::error::NanonisError;
+use crate::oscilloscope::SignalStats;
+use crate::signals::SignalFrame;
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Statistics of the samples currently in a [`RollingStats`] window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowStats {
+    /// Number of samples
+    pub count: usize,
+    /// Time between the oldest and the newest sample
+    pub span: Duration,
+    /// Mean value
+    pub mean: f64,
+    /// Population standard deviation
+    pub std_dev: f64,
+    /// `std_dev / |mean|`, infinite for a zero mean
+    pub relative_std: f64,
+    /// Smallest value
+    pub min: f64,
+    /// Largest value
+    pub max: f64,
+    /// Least-squares slope in units per second, zero for a single sample
+    pub slope: f64,
+    /// Newest value
+    pub last: f64,
+}
+
+impl From<&WindowStats> for SignalStats {
+    fn from(stats: &WindowStats) -> Self {
+        SignalStats {
+            mean: stats.mean,
+            std_dev: stats.std_dev,
+            relative_std: stats.relative_std,
+            window_size: stats.count,
+            stability_method: "rolling window".to_string(),
+        }
+    }
+}
+
+/// Rolling-window statistics of one channel of a frame stream.
+///
+/// The window covers a fixed time span, measured with the frame
+/// [`timestamp`](SignalFrame::timestamp) if the stream knows the RT
+/// frequency and with the time of arrival otherwise. Pushing a sample
+/// removes those older than the window. Mean, standard deviation and slope
+/// are kept as running sums and min and max as monotonic queues, so each
+/// update is O(1) amortized.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::RollingStats;
+/// use std::time::Duration;
+///
+/// let mut stats = RollingStats::new(0, Duration::from_millis(100));
+/// for ms in 0..=300u64 {
+///     // Ramp of 1 unit per second
+///     stats.push(Duration::from_millis(ms), ms as f64 / 1000.0);
+/// }
+///
+/// let window = stats.stats().unwrap();
+/// assert!(stats.is_full());
+/// assert_eq!(window.count, 100);
+/// assert_eq!((window.min, window.max), (0.201, 0.3));
+/// assert!((window.slope - 1.0).abs() < 1e-9);
+///
+/// // After a gap longer than the window, the window has to fill again
+/// stats.push(Duration::from_millis(1000), 1.0);
+/// assert!(!stats.is_full());
+///
+/// // Time running backwards starts a new window
+/// stats.push(Duration::from_millis(5), 0.0);
+/// assert_eq!(stats.stats().unwrap().count, 1);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    channel: usize,
+    window: Duration,
+    samples: VecDeque<(Duration, f64)>,
+    minima: VecDeque<(Duration, f64)>,
+    maxima: VecDeque<(Duration, f64)>,
+    sums: Sums,
+    removed: usize,
+    clock: Instant,
+}
+
+/// Running sums relative to a reference sample, which keeps the
+/// cancellation error small for values with a large offset.
+#[derive(Debug, Clone, Copy, Default)]
+struct Sums {
+    origin: (Duration, f64),
+    t: f64,
+    v: f64,
+    tt: f64,
+    vv: f64,
+    tv: f64,
+}
+
+impl Sums {
+    fn add(&mut self, (time, value): (Duration, f64), sign: f64) {
+        let t = time.as_secs_f64() - self.origin.0.as_secs_f64();
+        let v = value - self.origin.1;
+        self.t += sign * t;
+        self.v += sign * v;
+        self.tt += sign * t * t;
+        self.vv += sign * v * v;
+        self.tv += sign * t * v;
+    }
+}
+
+impl RollingStats {
+    /// Statistics of frame channel `channel` over the last `window`.
+    pub fn new(channel: usize, window: Duration) -> Self {
+        Self {
+            channel,
+            window,
+            samples: VecDeque::new(),
+            minima: VecDeque::new(),
+            maxima: VecDeque::new(),
+            sums: Sums::default(),
+            removed: 0,
+            clock: Instant::now(),
+        }
+    }
+
+    /// Channel index within the frames.
+    pub fn channel(&self) -> usize {
+        self.channel
+    }
+
+    /// Add the channel value of a frame.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the frame has no value for the channel.
+    pub fn push_frame(&mut self, frame: &SignalFrame) -> Result<(), NanonisError> {
+        let value = *frame.data.get(self.channel).ok_or_else(|| {
+            NanonisError::Protocol(format!(
+                "Frame has {} channels, no channel {}",
+                frame.data.len(),
+                self.channel
+            ))
+        })?;
+        let time = frame.timestamp.unwrap_or_else(|| self.clock.elapsed());
+        self.push(time, value as f64);
+        Ok(())
+    }
+
+    /// Add a sample taken at `time`.
+    ///
+    /// A sample older than the newest one, e.g. after the frame counter
+    /// restarted, [resets](Self::reset) the window and starts a new one.
+    pub fn push(&mut self, time: Duration, value: f64) {
+        if self.samples.back().is_some_and(|&(last, _)| time < last) {
+            self.reset();
+        }
+        let sample = (time, value);
+        if self.samples.is_empty() {
+            self.sums = Sums {
+                origin: sample,
+                ..Sums::default()
+            };
+        }
+
+        self.samples.push_back(sample);
+        self.sums.add(sample, 1.0);
+        while self.minima.back().is_some_and(|&(_, v)| v >= value) {
+            self.minima.pop_back();
+        }
+        self.minima.push_back(sample);
+        while self.maxima.back().is_some_and(|&(_, v)| v <= value) {
+            self.maxima.pop_back();
+        }
+        self.maxima.push_back(sample);
+
+        // The newest sample always stays, even with a zero window
+        let cutoff = time.saturating_sub(self.window);
+        let expired = |queue: &VecDeque<(Duration, f64)>| {
+            queue.len() > 1 && queue.front().is_some_and(|&(t, _)| t <= cutoff)
+        };
+        while expired(&self.samples) {
+            if let Some(oldest) = self.samples.pop_front() {
+                self.sums.add(oldest, -1.0);
+                self.removed += 1;
+            }
+        }
+        while expired(&self.minima) {
+            self.minima.pop_front();
+        }
+        while expired(&self.maxima) {
+            self.maxima.pop_front();
+        }
+
+        // Recompute the sums once a window's worth of samples has been
+        // removed, so rounding errors cannot accumulate over long runs
+        if self.removed >= self.samples.len() {
+            self.rebase();
+        }
+    }
+
+    /// Whether the samples in the window cover all of it.
+    ///
+    /// The oldest sample must lie within one mean sample interval of the
+    /// start of the window, so a window that only holds the few samples
+    /// after a frame gap or reconnect is not full.
+    pub fn is_full(&self) -> bool {
+        let (Some(&(first, _)), Some(&(last, _))) = (self.samples.front(), self.samples.back())
+        else {
+            return false;
+        };
+        if self.samples.len() < 2 || last < self.window {
+            return false;
+        }
+        let interval = (last - first) / (self.samples.len() - 1) as u32;
+        first <= last - self.window + interval
+    }
+
+    /// Statistics of the current window, `None` before the first sample.
+    pub fn stats(&self) -> Option<WindowStats> {
+        let &(last_time, last) = self.samples.back()?;
+        let &(first_time, _) = self.samples.front()?;
+        let n = self.samples.len() as f64;
+        let s = &self.sums;
+
+        let mean = s.origin.1 + s.v / n;
+        let std_dev = ((s.vv - s.v * s.v / n) / n).max(0.0).sqrt();
+        let denominator = n * s.tt - s.t * s.t;
+        let slope = if self.samples.len() > 1 && denominator > 0.0 {
+            (n * s.tv - s.t * s.v) / denominator
+        } else {
+            0.0
+        };
+
+        Some(WindowStats {
+            count: self.samples.len(),
+            span: last_time.saturating_sub(first_time),
+            mean,
+            std_dev,
+            relative_std: std_dev / mean.abs(),
+            min: self.minima.front()?.1,
+            max: self.maxima.front()?.1,
+            slope,
+            last,
+        })
+    }
+
+    /// Discard all samples.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.minima.clear();
+        self.maxima.clear();
+        self.sums = Sums::default();
+        self.removed = 0;
+    }
+
+    fn rebase(&mut self) {
+        self.removed = 0;
+        let Some(&origin) = self.samples.front() else {
+            return;
+        };
+        self.sums = Sums {
+            origin,
+            ..Sums::default()
+        };
+        for &sample in &self.samples {
+            self.sums.add(sample, 1.0);
+        }
+    }
+}
+
+/// Condition checked by a [`Trigger`] on every frame.
+///
+/// Thresholds apply to the window mean; with a short window that is the
+/// latest value. [`Stable`](Self::Stable), [`RelativeStdBelow`](Self::RelativeStdBelow)
+/// and [`Drift`](Self::Drift) are only checked once the window is full.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    /// Mean above the value
+    Above(f64),
+    /// Mean below the value
+    Below(f64),
+    /// Every sample within `tolerance × |mean|` of the mean, e.g. `0.02`
+    /// for "stable within 2 %"
+    Stable {
+        /// Relative tolerance
+        tolerance: f64,
+    },
+    /// Relative standard deviation below the value, as in
+    /// [`SignalStats::relative_std`]
+    RelativeStdBelow(f64),
+    /// Mean moved more than `delta` away from the mean of the first full
+    /// window after the trigger was created or reset
+    Drift {
+        /// Absolute change in signal units
+        delta: f64,
+    },
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::Above(value) => write!(f, "above {value}"),
+            Condition::Below(value) => write!(f, "below {value}"),
+            Condition::Stable { tolerance } => write!(f, "stable within {}%", tolerance * 100.0),
+            Condition::RelativeStdBelow(value) => write!(f, "relative std below {value}"),
+            Condition::Drift { delta } => write!(f, "drift over {delta}"),
+        }
+    }
+}
+
+/// A [`Trigger`] that fired.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerEvent {
+    /// Condition that became true
+    pub condition: Condition,
+    /// Frame counter of the sample that fired the trigger
+    pub counter: u64,
+    /// Window statistics at that sample
+    pub stats: WindowStats,
+}
+
+type Callback = Box<dyn FnMut(&TriggerEvent) + Send>;
+
+/// Rolling-window condition on one channel that fires when it becomes true.
+///
+/// Feed frames with [`update`](Self::update), which returns an event on
+/// the frame where the condition changes from false to true; the trigger
+/// fires again only after the condition was false in between. Events also
+/// go to the callback set with [`on_fire`](Self::on_fire).
+/// [`wait`](Self::wait) blocks until the trigger fires.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::signals::SignalFrame;
+/// use nanonis_rs::tcplog::TCPLogStatus;
+/// use nanonis_rs::{Condition, Trigger};
+/// use std::time::Duration;
+///
+/// // Settling current: 1 nA with a decaying overshoot, one frame per ms
+/// let mut frames = (0..2000u64).map(|counter| SignalFrame {
+///     counter,
+///     data: vec![1e-9 * (1.0 + 0.5 * (-(counter as f32) / 100.0).exp())],
+///     oversampling: 20.0,
+///     state: TCPLogStatus::Running,
+///     timestamp: Some(Duration::from_millis(counter)),
+/// });
+///
+/// // Wait until the current is stable within 2 % for 500 ms
+/// let mut stable = Trigger::new(0, Duration::from_millis(500), Condition::Stable { tolerance: 0.02 });
+/// let event = stable.wait(Duration::from_secs(5), || {
+///     frames.next().ok_or(nanonis_rs::NanonisError::Protocol("stream ended".into()))
+/// })?;
+/// assert!(event.counter > 500 && event.counter < 1000);
+/// assert!((event.stats.mean - 1e-9).abs() < 2e-11);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+pub struct Trigger {
+    stats: RollingStats,
+    condition: Condition,
+    reference: Option<f64>,
+    active: bool,
+    callback: Option<Callback>,
+}
+
+impl fmt::Debug for Trigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Trigger")
+            .field("channel", &self.stats.channel)
+            .field("condition", &self.condition)
+            .field("active", &self.active)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Trigger {
+    /// Trigger on frame channel `channel` with a window of `window`.
+    pub fn new(channel: usize, window: Duration, condition: Condition) -> Self {
+        Self {
+            stats: RollingStats::new(channel, window),
+            condition,
+            reference: None,
+            active: false,
+            callback: None,
+        }
+    }
+
+    /// Call `callback` every time the trigger fires.
+    pub fn on_fire(mut self, callback: impl FnMut(&TriggerEvent) + Send + 'static) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// The condition checked.
+    pub fn condition(&self) -> Condition {
+        self.condition
+    }
+
+    /// Statistics of the current window.
+    pub fn stats(&self) -> Option<WindowStats> {
+        self.stats.stats()
+    }
+
+    /// Add a frame and return an event if the trigger fires on it.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the frame has no value for the channel.
+    pub fn update(&mut self, frame: &SignalFrame) -> Result<Option<TriggerEvent>, NanonisError> {
+        self.stats.push_frame(frame)?;
+        let Some(stats) = self.stats.stats() else {
+            return Ok(None);
+        };
+
+        let full = self.stats.is_full();
+        if full && self.reference.is_none() {
+            self.reference = Some(stats.mean);
+        }
+        let holds = match self.condition {
+            Condition::Above(value) => stats.mean > value,
+            Condition::Below(value) => stats.mean < value,
+            Condition::Stable { tolerance } => {
+                let band = tolerance * stats.mean.abs();
+                full && stats.max - stats.mean <= band && stats.mean - stats.min <= band
+            }
+            Condition::RelativeStdBelow(value) => full && stats.relative_std < value,
+            Condition::Drift { delta } => self
+                .reference
+                .is_some_and(|reference| (stats.mean - reference).abs() > delta),
+        };
+
+        let fired = holds && !self.active;
+        self.active = holds;
+        if !fired {
+            return Ok(None);
+        }
+
+        let event = TriggerEvent {
+            condition: self.condition,
+            counter: frame.counter,
+            stats,
+        };
+        if let Some(callback) = self.callback.as_mut() {
+            callback(&event);
+        }
+        Ok(Some(event))
+    }
+
+    /// Feed frames from `next_frame` until the trigger fires.
+    ///
+    /// `next_frame` is typically a closure around
+    /// [`TCPLoggerStream::read_frame`](crate::TCPLoggerStream::read_frame)
+    /// or [`BackgroundReader::recv`](crate::BackgroundReader::recv). The
+    /// timeout is checked between frames, so a blocking `next_frame` can
+    /// exceed it by its own timeout.
+    ///
+    /// # Errors
+    /// Returns [`NanonisError::Timeout`] if the trigger did not fire in
+    /// time, or the error of `next_frame`.
+    pub fn wait(
+        &mut self,
+        timeout: Duration,
+        mut next_frame: impl FnMut() -> Result<SignalFrame, NanonisError>,
+    ) -> Result<TriggerEvent, NanonisError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(event) = self.update(&next_frame()?)? {
+                return Ok(event);
+            }
+            if Instant::now() >= deadline {
+                return Err(NanonisError::Timeout(format!(
+                    "Trigger {} did not fire within {timeout:?}",
+                    self.condition
+                )));
+            }
+        }
+    }
+
+    /// Discard the window, the drift reference and the fired state.
+    pub fn reset(&mut self) {
+        self.stats.reset();
+        self.reference = None;
+        self.active = false;
+    }
+}
+
+/// Several triggers fed from one frame stream.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::signals::SignalFrame;
+/// use nanonis_rs::tcplog::TCPLogStatus;
+/// use nanonis_rs::{Condition, Trigger, TriggerSet};
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+///
+/// let (events, fired) = mpsc::channel();
+/// let mut triggers = TriggerSet::new();
+/// // Z drifts by more than 50 pm from its first 10 ms mean
+/// triggers.push(
+///     Trigger::new(1, Duration::from_millis(10), Condition::Drift { delta: 50e-12 })
+///         .on_fire(move |event| events.send(event.counter).unwrap()),
+/// );
+///
+/// for counter in 0..100u64 {
+///     let frame = SignalFrame {
+///         counter,
+///         // Z creeps up by 1 pm per frame
+///         data: vec![1e-10, 1e-9 + counter as f32 * 1e-12],
+///         oversampling: 20.0,
+///         state: TCPLogStatus::Running,
+///         timestamp: Some(Duration::from_millis(counter)),
+///     };
+///     triggers.update(&frame)?;
+/// }
+///
+/// let counter = fired.try_recv().unwrap();
+/// assert!((55..=70).contains(&counter));
+/// assert!(fired.try_recv().is_err());
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct TriggerSet {
+    triggers: Vec<Trigger>,
+}
+
+impl TriggerSet {
+    /// Set without triggers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a trigger.
+    pub fn push(&mut self, trigger: Trigger) {
+        self.triggers.push(trigger);
+    }
+
+    /// The triggers, in the order added.
+    pub fn triggers(&self) -> &[Trigger] {
+        &self.triggers
+    }
+
+    /// Feed a frame to every trigger and return the events fired on it,
+    /// with the index of their trigger.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the frame has no value for a trigger's
+    /// channel.
+    pub fn update(
+        &mut self,
+        frame: &SignalFrame,
+    ) -> Result<Vec<(usize, TriggerEvent)>, NanonisError> {
+        let mut events = Vec::new();
+        for (index, trigger) in self.triggers.iter_mut().enumerate() {
+            if let Some(event) = trigger.update(frame)? {
+                events.push((index, event));
+            }
+        }
+        Ok(events)
+    }
+}
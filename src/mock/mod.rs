@@ -0,0 +1,371 @@
+use crate::client::NanonisClient;
+use crate::error::NanonisError;
+use crate::protocol::{Protocol, COMMAND_SIZE, HEADER_SIZE};
+use crate::transport::MemoryTransport;
+use crate::types::NanonisValue;
+use log::{debug, warn};
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+mod sim;
+pub use sim::*;
+
+/// Error code used by the mock when a command has no registered handler.
+pub const UNKNOWN_COMMAND_CODE: i32 = -1;
+
+type Handler = Arc<Mutex<dyn FnMut(&MockRequest) -> MockResponse + Send>>;
+
+/// A command received by the [`MockServer`].
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// Command name from the request header, e.g. `"Bias.Set"`
+    pub command: String,
+    /// Raw big-endian request body
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Decode the request body using the given type codes.
+    ///
+    /// Decoding follows the same rules as response parsing, so length-prefixed
+    /// arguments such as `"+*i"` decode as expected. A `"+*c"` argument always
+    /// decodes as a string array.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::MockRequest;
+    ///
+    /// let request = MockRequest {
+    ///     command: "Bias.Set".to_string(),
+    ///     body: 1.5f32.to_be_bytes().to_vec(),
+    /// };
+    /// let args = request.args(&["f"])?;
+    /// assert_eq!(args[0].as_f32()?, 1.5);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn args(&self, types: &[&str]) -> Result<Vec<NanonisValue>, NanonisError> {
+        Protocol::parse_response(&self.body, types)
+    }
+}
+
+/// Reply sent by the [`MockServer`] for a single command.
+///
+/// Every reply carries the error-info trailer read by
+/// `Protocol::parse_error_info`. A successful reply has status `0` and an
+/// empty message.
+#[derive(Debug, Clone, Default)]
+pub struct MockResponse {
+    body: Vec<u8>,
+    error: Option<(i32, String)>,
+    disconnect: bool,
+}
+
+impl MockResponse {
+    /// Reply without return values.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Reply with values serialized according to `types`.
+    ///
+    /// The values must mirror the response layout of the command, including
+    /// the count and size fields preceding arrays and strings. If the values
+    /// cannot be serialized, the reply carries a server error describing the
+    /// mismatch instead.
+    pub fn values(values: Vec<NanonisValue>, types: Vec<&str>) -> Self {
+        let mut body = Vec::new();
+        for (value, value_type) in values.iter().zip(types.iter()) {
+            if let Err(e) = Protocol::serialize_value(value, value_type, &mut body) {
+                return Self::error(UNKNOWN_COMMAND_CODE, format!("Mock response invalid: {e}"));
+            }
+        }
+        Self::raw(body)
+    }
+
+    /// Reply with a pre-serialized body, sent verbatim before the trailer.
+    pub fn raw(body: Vec<u8>) -> Self {
+        Self {
+            body,
+            ..Self::default()
+        }
+    }
+
+    /// Close the connection instead of replying.
+    ///
+    /// Simulates a dropped connection, e.g. after a Nanonis restart.
+    pub fn disconnect() -> Self {
+        Self {
+            disconnect: true,
+            ..Self::default()
+        }
+    }
+
+    /// Reply with a server error and no return values.
+    ///
+    /// Use [`with_error`](Self::with_error) for commands that return values,
+    /// since the client parses the values before it reads the trailer.
+    pub fn error(code: i32, message: impl Into<String>) -> Self {
+        Self::empty().with_error(code, message)
+    }
+
+    /// Attach a server error to the trailer of this reply.
+    pub fn with_error(mut self, code: i32, message: impl Into<String>) -> Self {
+        self.error = Some((code, message.into()));
+        self
+    }
+
+    fn into_body(self) -> Vec<u8> {
+        let mut body = self.body;
+        let (code, message) = self.error.unwrap_or((0, String::new()));
+        body.extend_from_slice(&code.to_be_bytes());
+        body.extend_from_slice(&(message.len() as i32).to_be_bytes());
+        body.extend_from_slice(message.as_bytes());
+        body
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    handlers: Mutex<HashMap<String, Handler>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+/// In-process Nanonis TCP server for testing without hardware.
+///
+/// The server listens on a local port and speaks the 40-byte header /
+/// big-endian body protocol. Replies are registered per command name, either
+/// as canned [`MockResponse`]s or as closures that inspect the request.
+/// Commands without a handler are answered with a server error.
+///
+/// The server shuts down when dropped.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockResponse, MockServer, NanonisValue, Volts};
+///
+/// let server = MockServer::start()?;
+/// server.respond("Bias.Get", vec![NanonisValue::F32(0.25)], vec!["f"]);
+/// server.on("Bias.Set", |request| {
+///     let voltage = request.args(&["f"]).unwrap()[0].as_f32().unwrap();
+///     if voltage.abs() > 10.0 {
+///         MockResponse::error(-2, "Bias out of range")
+///     } else {
+///         MockResponse::empty()
+///     }
+/// });
+///
+/// let mut client = server.client()?;
+/// assert_eq!(client.bias_get()?, 0.25);
+/// client.bias_set(Volts::new(1.0))?;
+///
+/// let err = client.bias_set(Volts::new(20.0)).unwrap_err();
+/// assert_eq!(err.error_code(), Some(-2));
+/// assert_eq!(server.requests().len(), 3);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+pub struct MockServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    shutdown: Arc<AtomicBool>,
+    accept_thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start a mock server on a free port of the loopback interface.
+    pub fn start() -> Result<Self, NanonisError> {
+        let listener = TcpListener::bind("127.0.0.1:0").map_err(|e| NanonisError::Io {
+            source: e,
+            context: "Binding mock server".to_string(),
+        })?;
+        let addr = listener.local_addr()?;
+        let shared = Arc::new(Shared::default());
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let accept_thread = {
+            let shared = Arc::clone(&shared);
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    match stream {
+                        Ok(stream) => {
+                            let shared = Arc::clone(&shared);
+                            thread::spawn(move || serve_connection(stream, shared));
+                        }
+                        Err(e) => warn!("Mock server failed to accept connection: {e}"),
+                    }
+                }
+            })
+        };
+
+        debug!("Mock server listening on {addr}");
+
+        Ok(Self {
+            addr,
+            shared,
+            shutdown,
+            accept_thread: Some(accept_thread),
+        })
+    }
+
+    /// Socket address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Port the server listens on.
+    pub fn port(&self) -> u16 {
+        self.addr.port()
+    }
+
+    /// Connect a new [`NanonisClient`] to this server.
+    pub fn client(&self) -> Result<NanonisClient, NanonisError> {
+        NanonisClient::new(&self.addr.ip().to_string(), self.port())
+    }
+
+    /// Connect a new [`NanonisClient`] over a [`MemoryTransport`].
+    ///
+    /// The client shares handlers and the request log with TCP clients, but
+    /// its traffic never touches a socket.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{MockServer, NanonisValue};
+    ///
+    /// let server = MockServer::start()?;
+    /// server.respond("Bias.Get", vec![NanonisValue::F32(0.5)], vec!["f"]);
+    ///
+    /// let mut client = server.memory_client();
+    /// assert_eq!(client.bias_get()?, 0.5);
+    /// assert_eq!(server.requests().len(), 1);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn memory_client(&self) -> NanonisClient {
+        let (client_end, server_end) = MemoryTransport::pair();
+        let shared = Arc::clone(&self.shared);
+        thread::spawn(move || serve_connection(server_end, shared));
+        NanonisClient::from_transport(client_end)
+    }
+
+    /// Register a closure that produces the reply for `command`.
+    ///
+    /// Replaces any handler previously registered for the same command.
+    pub fn on<F>(&self, command: &str, handler: F)
+    where
+        F: FnMut(&MockRequest) -> MockResponse + Send + 'static,
+    {
+        let handler: Handler = Arc::new(Mutex::new(handler));
+        lock(&self.shared.handlers).insert(command.to_string(), handler);
+    }
+
+    /// Register a canned reply with values serialized according to `types`.
+    pub fn respond(&self, command: &str, values: Vec<NanonisValue>, types: Vec<&str>) {
+        let response = MockResponse::values(values, types);
+        self.on(command, move |_| response.clone());
+    }
+
+    /// Register a canned server error for `command`.
+    pub fn fail(&self, command: &str, code: i32, message: &str) {
+        let response = MockResponse::error(code, message);
+        self.on(command, move |_| response.clone());
+    }
+
+    /// Remove the handler registered for `command`.
+    pub fn remove(&self, command: &str) {
+        lock(&self.shared.handlers).remove(command);
+    }
+
+    /// All requests received so far, in arrival order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        lock(&self.shared.requests).clone()
+    }
+
+    /// Forget all recorded requests.
+    pub fn clear_requests(&self) {
+        lock(&self.shared.requests).clear();
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it observes the shutdown flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.accept_thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn lock<T: ?Sized>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn serve_connection(mut stream: impl Read + Write, shared: Arc<Shared>) {
+    loop {
+        let header = match Protocol::read_exact_bytes::<HEADER_SIZE>(&mut stream) {
+            Ok(header) => header,
+            Err(NanonisError::Io { source, .. }) if source.kind() == ErrorKind::UnexpectedEof => {
+                debug!("Mock client disconnected");
+                return;
+            }
+            Err(e) => {
+                debug!("Mock server stopped reading: {e}");
+                return;
+            }
+        };
+
+        let command = String::from_utf8_lossy(&header[..COMMAND_SIZE])
+            .trim_end_matches('\0')
+            .to_string();
+        let body_size = u32::from_be_bytes([header[32], header[33], header[34], header[35]]);
+        let send_response = u16::from_be_bytes([header[36], header[37]]) != 0;
+
+        let body = match Protocol::read_variable_bytes(&mut stream, body_size as usize) {
+            Ok(body) => body,
+            Err(e) => {
+                debug!("Mock server failed to read body of {command}: {e}");
+                return;
+            }
+        };
+
+        let request = MockRequest { command, body };
+        lock(&shared.requests).push(request.clone());
+
+        let handler = lock(&shared.handlers).get(&request.command).cloned();
+        let response = match handler {
+            Some(handler) => (lock(&handler))(&request),
+            None => MockResponse::error(
+                UNKNOWN_COMMAND_CODE,
+                format!("Command not registered in mock server: {}", request.command),
+            ),
+        };
+
+        if response.disconnect {
+            debug!("Mock server dropping connection on {}", request.command);
+            return;
+        }
+
+        if !send_response {
+            continue;
+        }
+
+        let body = response.into_body();
+        let reply_header = Protocol::create_command_header(&request.command, body.len() as u32);
+        if let Err(e) = stream
+            .write_all(&reply_header)
+            .and_then(|_| stream.write_all(&body))
+        {
+            debug!("Mock server failed to reply to {}: {e}", request.command);
+            return;
+        }
+    }
+}
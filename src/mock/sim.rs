@@ -0,0 +1,1123 @@
+use super::{lock, MockRequest, MockResponse, MockServer, UNKNOWN_COMMAND_CODE};
+use crate::client::scan::{ScanAction, ScanConfig, ScanFrame};
+use crate::client::tcplog::TCPLogStatus;
+use crate::error::NanonisError;
+use crate::types::{Array2D, NanonisValue, Position};
+use crate::units::Meters;
+use std::f32::consts::PI;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Tunneling model: I = G * exp(-2 * KAPPA * gap) * f(V)
+const KAPPA: f32 = 1.0e10;
+const CONDUCTANCE: f32 = 1.0e-6;
+const CURRENT_NOISE_A: f32 = 1.0e-12;
+const Z_NOISE_M: f32 = 2.0e-12;
+const LATTICE_M: f32 = 0.5e-9;
+const CORRUGATION_M: f32 = 20.0e-12;
+
+/// State of the simulated STM.
+///
+/// All fields can be inspected and modified through
+/// [`SimulatedStm::state`] and [`SimulatedStm::update`].
+#[derive(Debug, Clone)]
+pub struct SimState {
+    /// Bias voltage in volts
+    pub bias_v: f32,
+    /// Whether the Z-controller is on
+    pub z_ctrl_on: bool,
+    /// Current setpoint in amperes
+    pub setpoint_a: f32,
+    /// Tip height in meters while the Z-controller is off
+    pub z_m: f32,
+    /// Z limits in meters as (high, low)
+    pub z_limits_m: (f32, f32),
+    /// Tip position in meters set through `FolMe.XYPosSet` while no scan
+    /// is running; `None` parks the tip at the scan frame center
+    pub tip_xy_m: Option<(f64, f64)>,
+    /// Scan frame
+    pub frame: ScanFrame,
+    /// Scan speed configuration
+    pub scan_config: ScanConfig,
+    /// Whether a scan starts over when the frame ends instead of stopping,
+    /// set through `Scan.PropsSet`. Lines of the new frame overwrite those
+    /// of the previous one as they are scanned.
+    pub continuous_scan: bool,
+    /// Signal index in each slot, served by `Signals.InSlotsGet`; signals
+    /// not listed have no slot
+    pub slot_signals: Vec<i32>,
+    /// Signal slots recorded by the scan buffer
+    pub buffer_channels: Vec<i32>,
+    /// Pixels per scan line
+    pub pixels: i32,
+    /// Lines per scan frame
+    pub lines: i32,
+    /// Motor step counters as (x, y, z)
+    pub step_counter: (i32, i32, i32),
+    /// Bias spectroscopy sweep limits in volts as (start, end)
+    pub spectr_limits_v: (f32, f32),
+    /// Number of points per bias spectroscopy sweep
+    pub spectr_points: i32,
+    /// Signal slots recorded by the TCP Logger
+    pub tcplog_channels: Vec<i32>,
+    /// TCP Logger oversampling
+    pub tcplog_oversampling: i32,
+    /// TCP Logger status
+    pub tcplog_status: TCPLogStatus,
+    /// Real-time frequency of the controller in hertz
+    pub rt_frequency_hz: f32,
+    scan: ScanRun,
+    rng: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ScanRun {
+    active: bool,
+    up: bool,
+    anchor: Option<Instant>,
+    accumulated: Duration,
+}
+
+impl ScanRun {
+    fn elapsed(&self) -> Duration {
+        self.accumulated + self.anchor.map(|a| a.elapsed()).unwrap_or_default()
+    }
+}
+
+impl Default for SimState {
+    fn default() -> Self {
+        Self {
+            bias_v: 1.0,
+            z_ctrl_on: true,
+            setpoint_a: 100.0e-12,
+            z_m: 0.0,
+            z_limits_m: (100.0e-9, -100.0e-9),
+            tip_xy_m: None,
+            frame: ScanFrame::new(
+                Position { x: 0.0, y: 0.0 },
+                Meters::nanometers(10.0),
+                Meters::nanometers(10.0),
+                0.0,
+            ),
+            scan_config: ScanConfig {
+                forward_linear_speed_m_s: 200.0e-9,
+                backward_linear_speed_m_s: 200.0e-9,
+                forward_time_per_line_s: 0.05,
+                backward_time_per_line_s: 0.05,
+                keep_parameter_constant: 2,
+                speed_ratio: 1.0,
+            },
+            continuous_scan: false,
+            slot_signals: (0..SimulatedStm::SIGNAL_NAMES.len() as i32).collect(),
+            buffer_channels: vec![SimulatedStm::CURRENT, SimulatedStm::Z],
+            pixels: 64,
+            lines: 64,
+            step_counter: (0, 0, 0),
+            spectr_limits_v: (-1.0, 1.0),
+            spectr_points: 128,
+            tcplog_channels: Vec::new(),
+            tcplog_oversampling: 1,
+            tcplog_status: TCPLogStatus::Idle,
+            rt_frequency_hz: 20.0e3,
+            scan: ScanRun::default(),
+            rng: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+impl SimState {
+    /// Whether a scan is currently running.
+    pub fn scan_running(&self) -> bool {
+        self.scan.active && (self.continuous_scan || self.scanned_lines() < self.lines)
+    }
+
+    /// Number of fully scanned lines of the current frame.
+    pub fn completed_lines(&self) -> i32 {
+        let lines = self.scanned_lines();
+        if self.continuous_scan && self.scan.active && self.lines > 0 {
+            lines % self.lines
+        } else {
+            lines.min(self.lines)
+        }
+    }
+
+    /// Lines scanned since the scan started, over all frames.
+    fn scanned_lines(&self) -> i32 {
+        let line_time = self.line_time().as_secs_f64();
+        if line_time <= 0.0 {
+            return self.lines;
+        }
+        (self.scan.elapsed().as_secs_f64() / line_time).min(i32::MAX as f64) as i32
+    }
+
+    /// Height of the sample surface at (x, y) in meters.
+    pub fn topography(&self, x: f32, y: f32) -> f32 {
+        let lattice = (2.0 * PI * x / LATTICE_M).cos() + (2.0 * PI * y / LATTICE_M).cos();
+        let step = if x > self.frame.center.x as f32 {
+            0.2e-9
+        } else {
+            0.0
+        };
+        CORRUGATION_M * lattice + step
+    }
+
+    fn line_time(&self) -> Duration {
+        let secs =
+            self.scan_config.forward_time_per_line_s + self.scan_config.backward_time_per_line_s;
+        Duration::from_secs_f32(secs.max(0.0))
+    }
+
+    /// Time until the current frame ends.
+    fn remaining_scan_time(&self) -> Duration {
+        if !self.scan_running() {
+            return Duration::ZERO;
+        }
+        let frame_time = self.line_time() * self.lines as u32;
+        let elapsed = if self.continuous_scan && !frame_time.is_zero() {
+            Duration::from_secs_f64(self.scan.elapsed().as_secs_f64() % frame_time.as_secs_f64())
+        } else {
+            self.scan.elapsed()
+        };
+        frame_time.saturating_sub(elapsed)
+    }
+
+    fn tip_position(&self) -> (f32, f32) {
+        if !self.scan_running() {
+            let (x, y) = self
+                .tip_xy_m
+                .unwrap_or((self.frame.center.x, self.frame.center.y));
+            return (x as f32, y as f32);
+        }
+        let line = self.completed_lines();
+        self.pixel_position(self.pixels / 2, line)
+    }
+
+    fn pixel_position(&self, pixel: i32, line: i32) -> (f32, f32) {
+        let frac = |i: i32, n: i32| {
+            if n > 1 {
+                i as f32 / (n - 1) as f32 - 0.5
+            } else {
+                0.0
+            }
+        };
+        let u = frac(pixel, self.pixels) * self.frame.width_m;
+        let mut v = frac(line, self.lines) * self.frame.height_m;
+        if !self.scan.up {
+            v = -v;
+        }
+        let (sin, cos) = self.frame.angle_deg.to_radians().sin_cos();
+        (
+            self.frame.center.x as f32 + u * cos - v * sin,
+            self.frame.center.y as f32 + u * sin + v * cos,
+        )
+    }
+
+    /// Tip-sample gap that yields the setpoint current at the present bias.
+    fn regulated_gap(&self) -> f32 {
+        let ratio = (CONDUCTANCE * self.bias_v.abs()
+            / self.setpoint_a.abs().max(f32::MIN_POSITIVE))
+        .max(1.0);
+        ratio.ln() / (2.0 * KAPPA)
+    }
+
+    fn tip_height(&self, x: f32, y: f32) -> f32 {
+        if self.z_ctrl_on {
+            self.topography(x, y) + self.regulated_gap()
+        } else {
+            self.z_m
+        }
+    }
+
+    fn current_at(&self, x: f32, y: f32, bias_v: f32) -> f32 {
+        let gap = (self.tip_height(x, y) - self.topography(x, y)).max(0.0);
+        // Slightly non-linear I(V) to make spectra look like spectra
+        CONDUCTANCE * (-2.0 * KAPPA * gap).exp() * (bias_v + 0.3 * bias_v.powi(3))
+    }
+
+    /// Index of the signal assigned to `slot`.
+    fn signal_in_slot(&self, slot: i32) -> Option<i32> {
+        usize::try_from(slot)
+            .ok()
+            .and_then(|slot| self.slot_signals.get(slot).copied())
+    }
+
+    fn signal_value(&mut self, index: i32) -> f32 {
+        let (x, y) = self.tip_position();
+        self.value_at(index, x, y)
+    }
+
+    fn pixel_value(&mut self, index: i32, pixel: i32, line: i32) -> f32 {
+        let (x, y) = self.pixel_position(pixel, line);
+        self.value_at(index, x, y)
+    }
+
+    fn value_at(&mut self, index: i32, x: f32, y: f32) -> f32 {
+        match index {
+            SimulatedStm::CURRENT => {
+                self.current_at(x, y, self.bias_v) + self.noise(CURRENT_NOISE_A)
+            }
+            SimulatedStm::BIAS => self.bias_v,
+            SimulatedStm::Z => self.tip_height(x, y) + self.noise(Z_NOISE_M),
+            3 => x,
+            4 => y,
+            _ => self.noise(1.0e-4),
+        }
+    }
+
+    /// Gaussian noise with the given standard deviation.
+    fn noise(&mut self, sigma: f32) -> f32 {
+        let u1 = self.uniform().max(f32::MIN_POSITIVE);
+        let u2 = self.uniform();
+        sigma * (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+
+    fn uniform(&mut self) -> f32 {
+        // xorshift64
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn withdraw(&mut self) {
+        self.z_ctrl_on = false;
+        self.z_m = self.z_limits_m.0;
+    }
+}
+
+/// Stateful simulated STM served through a [`MockServer`].
+///
+/// The simulation keeps bias, Z-controller, scan, motor and spectroscopy state
+/// and answers the corresponding commands plausibly: a `bias_set` is visible
+/// in `bias_get` and `signals_vals_get`, a started scan runs for
+/// `lines × time per line`, `scan_frame_data_grab` returns synthetic
+/// topography of a square lattice with a step edge, and `bias_spectr_start`
+/// returns an I(V) curve for the current tip height.
+///
+/// Commands not listed below fall through to the mock server and can be
+/// registered separately. Registering a handler for a simulated command after
+/// [`attach`](Self::attach) overrides the simulation for that command.
+///
+/// Simulated commands: `Bias.Set/Get`, `ZCtrl.OnOffSet/OnOffGet`,
+/// `ZCtrl.SetpntSet/SetpntGet`, `ZCtrl.ZPosSet/ZPosGet`, `ZCtrl.LimitsSet/LimitsGet`,
+/// `ZCtrl.StatusGet`, `ZCtrl.Withdraw`, `Scan.Action`, `Scan.StatusGet`,
+/// `Scan.FrameSet/FrameGet`, `Scan.BufferSet/BufferGet`, `Scan.SpeedSet/SpeedGet`,
+/// `Scan.PropsSet` (continuous scan only), `Scan.FrameDataGrab`, `Scan.WaitEndOfScan`, `Motor.StartMove`,
+/// `Motor.StopMove`, `Motor.StepCounterGet`, `Signals.NamesGet`,
+/// `Signals.InSlotsGet`, `Signals.ValsGet`, `Signals.ValGet`,
+/// `Signals.CalibrGet`, `Signals.RangeGet`,
+/// `BiasSpectr.LimitsSet/LimitsGet`, `BiasSpectr.PropsSet`, `BiasSpectr.Start`,
+/// `FolMe.XYPosSet/XYPosGet`, `TCPLog.ChsSet`, `TCPLog.OversamplSet`,
+/// `TCPLog.Start/Stop`, `TCPLog.StatusGet`, `Util.RTFreqGet`. The TCP Logger
+/// only keeps its configuration and status; no data stream is served.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockServer, SimulatedStm, Volts};
+/// use nanonis_rs::scan::{ScanAction, ScanConfig, ScanDirection};
+///
+/// let server = MockServer::start()?;
+/// let sim = SimulatedStm::new();
+/// sim.attach(&server);
+///
+/// let mut client = server.client()?;
+/// client.bias_set(Volts::new(0.5))?;
+/// assert_eq!(client.bias_get()?, 0.5);
+///
+/// // Speed up the scan to 16 lines of 20 ms each
+/// client.scan_buffer_set(vec![0, 2], 16, 16)?;
+/// client.scan_config_set(ScanConfig {
+///     forward_linear_speed_m_s: 1e-6,
+///     backward_linear_speed_m_s: 1e-6,
+///     forward_time_per_line_s: 0.01,
+///     backward_time_per_line_s: 0.01,
+///     keep_parameter_constant: 2,
+///     speed_ratio: 1.0,
+/// })?;
+///
+/// client.scan_action(ScanAction::Start, ScanDirection::Up)?;
+/// assert!(client.scan_status_get()?);
+/// let (timed_out, _) = client.scan_wait_end_of_scan(std::time::Duration::from_secs(5))?;
+/// assert!(!timed_out);
+/// assert!(!client.scan_status_get()?);
+///
+/// let (name, data, _) = client.scan_frame_data_grab(2, true)?;
+/// assert_eq!(name, "Z (m)");
+/// assert_eq!(data.shape(), (16, 16));
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedStm {
+    state: Arc<Mutex<SimState>>,
+}
+
+type SimHandler = fn(&mut SimState, &MockRequest) -> Result<MockResponse, NanonisError>;
+
+const SIM_HANDLERS: &[(&str, SimHandler)] = &[
+    ("Bias.Set", bias_set),
+    ("Bias.Get", bias_get),
+    ("ZCtrl.OnOffSet", z_ctrl_on_off_set),
+    ("ZCtrl.OnOffGet", z_ctrl_on_off_get),
+    ("ZCtrl.SetpntSet", z_ctrl_setpoint_set),
+    ("ZCtrl.SetpntGet", z_ctrl_setpoint_get),
+    ("ZCtrl.ZPosSet", z_ctrl_z_pos_set),
+    ("ZCtrl.ZPosGet", z_ctrl_z_pos_get),
+    ("ZCtrl.LimitsSet", z_ctrl_limits_set),
+    ("ZCtrl.LimitsGet", z_ctrl_limits_get),
+    ("ZCtrl.StatusGet", z_ctrl_status_get),
+    ("ZCtrl.Withdraw", z_ctrl_withdraw),
+    ("Scan.Action", scan_action),
+    ("Scan.StatusGet", scan_status_get),
+    ("Scan.FrameSet", scan_frame_set),
+    ("Scan.FrameGet", scan_frame_get),
+    ("Scan.BufferSet", scan_buffer_set),
+    ("Scan.BufferGet", scan_buffer_get),
+    ("Scan.SpeedSet", scan_speed_set),
+    ("Scan.SpeedGet", scan_speed_get),
+    ("Scan.PropsSet", scan_props_set),
+    ("Scan.FrameDataGrab", scan_frame_data_grab),
+    ("Motor.StartMove", motor_start_move),
+    ("Motor.StopMove", |_, _| Ok(MockResponse::empty())),
+    ("Motor.StepCounterGet", motor_step_counter_get),
+    ("Signals.NamesGet", signals_names_get),
+    ("Signals.InSlotsGet", signals_in_slots_get),
+    ("Signals.ValsGet", signals_vals_get),
+    ("Signals.ValGet", signals_val_get),
+    ("Signals.CalibrGet", signals_calibr_get),
+    ("Signals.RangeGet", signals_range_get),
+    ("BiasSpectr.LimitsSet", bias_spectr_limits_set),
+    ("BiasSpectr.LimitsGet", bias_spectr_limits_get),
+    ("BiasSpectr.PropsSet", bias_spectr_props_set),
+    ("BiasSpectr.Start", bias_spectr_start),
+    ("FolMe.XYPosSet", folme_xy_pos_set),
+    ("FolMe.XYPosGet", folme_xy_pos_get),
+    ("TCPLog.ChsSet", tcplog_chs_set),
+    ("TCPLog.OversamplSet", tcplog_oversampl_set),
+    ("TCPLog.Start", tcplog_start),
+    ("TCPLog.Stop", tcplog_stop),
+    ("TCPLog.StatusGet", tcplog_status_get),
+    ("Util.RTFreqGet", util_rt_freq_get),
+];
+
+impl SimulatedStm {
+    /// Signal names served by `Signals.NamesGet`.
+    ///
+    /// By default the simulation maps signal slot `i` to signal index `i`, so
+    /// these indexes are valid both for `signals_vals_get` and for the scan
+    /// buffer; change [`SimState::slot_signals`] to assign other slots.
+    pub const SIGNAL_NAMES: [&'static str; 24] = [
+        "Current (A)",
+        "Bias (V)",
+        "Z (m)",
+        "X (m)",
+        "Y (m)",
+        "Phase (deg)",
+        "Amplitude (m)",
+        "Frequency Shift (Hz)",
+        "Input 1 (V)",
+        "Input 2 (V)",
+        "Input 3 (V)",
+        "Input 4 (V)",
+        "Input 5 (V)",
+        "Input 6 (V)",
+        "Input 7 (V)",
+        "Input 8 (V)",
+        "Output 1 (V)",
+        "Output 2 (V)",
+        "Output 3 (V)",
+        "Output 4 (V)",
+        "Output 5 (V)",
+        "Output 6 (V)",
+        "Output 7 (V)",
+        "Output 8 (V)",
+    ];
+    /// Signal index of the tunneling current.
+    pub const CURRENT: i32 = 0;
+    /// Signal index of the bias voltage.
+    pub const BIAS: i32 = 1;
+    /// Signal index of the tip height.
+    pub const Z: i32 = 2;
+
+    /// Create a simulated STM in its default state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a simulated STM starting from the given state.
+    pub fn with_state(state: SimState) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Register the simulated commands on `server`.
+    pub fn attach(&self, server: &MockServer) {
+        for &(command, handler) in SIM_HANDLERS {
+            let state = Arc::clone(&self.state);
+            server.on(command, move |request| {
+                handler(&mut lock(&state), request)
+                    .unwrap_or_else(|e| MockResponse::error(UNKNOWN_COMMAND_CODE, e.to_string()))
+            });
+        }
+
+        // Waiting must not hold the state lock, or the scan could not be stopped
+        let state = Arc::clone(&self.state);
+        server.on("Scan.WaitEndOfScan", move |request| {
+            scan_wait_end_of_scan(&state, request)
+                .unwrap_or_else(|e| MockResponse::error(UNKNOWN_COMMAND_CODE, e.to_string()))
+        });
+    }
+
+    /// Snapshot of the current state.
+    pub fn state(&self) -> SimState {
+        lock(&self.state).clone()
+    }
+
+    /// Modify the state in place, e.g. to inject a fault.
+    pub fn update(&self, f: impl FnOnce(&mut SimState)) {
+        f(&mut lock(&self.state));
+    }
+}
+
+impl MockServer {
+    /// Start a mock server backed by a [`SimulatedStm`] in its default state.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::MockServer;
+    ///
+    /// let server = MockServer::simulated()?;
+    /// let mut client = server.client()?;
+    /// assert!(client.z_ctrl_on_off_get()?);
+    ///
+    /// let spectrum = client.bias_spectr_start(true, "")?;
+    /// assert_eq!(spectrum.channel_names, ["Bias (V)", "Current (A)"]);
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn simulated() -> Result<Self, NanonisError> {
+        let server = Self::start()?;
+        SimulatedStm::new().attach(&server);
+        Ok(server)
+    }
+}
+
+fn f32_reply(values: &[f32]) -> MockResponse {
+    MockResponse::values(
+        values.iter().map(|&v| NanonisValue::F32(v)).collect(),
+        vec!["f"; values.len()],
+    )
+}
+
+fn bias_set(state: &mut SimState, request: &MockRequest) -> Result<MockResponse, NanonisError> {
+    state.bias_v = request.args(&["f"])?[0].as_f32()?;
+    Ok(MockResponse::empty())
+}
+
+fn bias_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(f32_reply(&[state.bias_v]))
+}
+
+fn z_ctrl_on_off_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let on = request.args(&["I"])?[0].as_u32()? == 1;
+    if state.z_ctrl_on && !on {
+        // Hold the last regulated height
+        let (x, y) = state.tip_position();
+        state.z_m = state.tip_height(x, y);
+    }
+    state.z_ctrl_on = on;
+    Ok(MockResponse::empty())
+}
+
+fn z_ctrl_on_off_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(MockResponse::values(
+        vec![NanonisValue::U32(state.z_ctrl_on as u32)],
+        vec!["I"],
+    ))
+}
+
+fn z_ctrl_setpoint_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    state.setpoint_a = request.args(&["f"])?[0].as_f32()?;
+    Ok(MockResponse::empty())
+}
+
+fn z_ctrl_setpoint_get(
+    state: &mut SimState,
+    _: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    Ok(f32_reply(&[state.setpoint_a]))
+}
+
+fn z_ctrl_z_pos_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let (high, low) = state.z_limits_m;
+    state.z_m = request.args(&["f"])?[0].as_f32()?.clamp(low, high);
+    Ok(MockResponse::empty())
+}
+
+fn z_ctrl_z_pos_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let (x, y) = state.tip_position();
+    Ok(f32_reply(&[state.tip_height(x, y)]))
+}
+
+fn z_ctrl_limits_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["f", "f"])?;
+    state.z_limits_m = (args[0].as_f32()?, args[1].as_f32()?);
+    Ok(MockResponse::empty())
+}
+
+fn z_ctrl_limits_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(f32_reply(&[state.z_limits_m.0, state.z_limits_m.1]))
+}
+
+fn z_ctrl_status_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let status = if state.z_ctrl_on { 2u16 } else { 1u16 };
+    Ok(MockResponse::values(
+        vec![NanonisValue::U16(status)],
+        vec!["H"],
+    ))
+}
+
+fn z_ctrl_withdraw(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    state.withdraw();
+    Ok(MockResponse::empty())
+}
+
+fn scan_action(state: &mut SimState, request: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["H", "I"])?;
+    let action = ScanAction::try_from(args[0].as_u16()?)?;
+    let up = args[1].as_u32()? == 1;
+    let run = &mut state.scan;
+    match action {
+        ScanAction::Start => {
+            state.tip_xy_m = None;
+            *run = ScanRun {
+                active: true,
+                up,
+                anchor: Some(Instant::now()),
+                accumulated: Duration::ZERO,
+            };
+        }
+        ScanAction::Stop => {
+            run.accumulated = run.elapsed();
+            run.anchor = None;
+            run.active = false;
+        }
+        ScanAction::Pause | ScanAction::Freeze => {
+            run.accumulated = run.elapsed();
+            run.anchor = None;
+        }
+        ScanAction::Resume | ScanAction::Unfreeze => {
+            if run.active && run.anchor.is_none() {
+                run.anchor = Some(Instant::now());
+            }
+        }
+        ScanAction::GoToCenter => {}
+    }
+    Ok(MockResponse::empty())
+}
+
+fn scan_status_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(MockResponse::values(
+        vec![NanonisValue::U32(state.scan_running() as u32)],
+        vec!["I"],
+    ))
+}
+
+fn scan_frame_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["f", "f", "f", "f", "f"])?;
+    state.frame = ScanFrame {
+        center: Position {
+            x: args[0].as_f32()? as f64,
+            y: args[1].as_f32()? as f64,
+        },
+        width_m: args[2].as_f32()?,
+        height_m: args[3].as_f32()?,
+        angle_deg: args[4].as_f32()?,
+    };
+    Ok(MockResponse::empty())
+}
+
+fn scan_frame_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let frame = state.frame;
+    Ok(f32_reply(&[
+        frame.center.x as f32,
+        frame.center.y as f32,
+        frame.width_m,
+        frame.height_m,
+        frame.angle_deg,
+    ]))
+}
+
+fn scan_buffer_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["+*i", "i", "i"])?;
+    let channels = args[0].as_i32_array()?.to_vec();
+    if let Some(bad) = channels.iter().find(|&&c| !(0..24).contains(&c)) {
+        return Err(NanonisError::Protocol(format!(
+            "Invalid buffer channel: {bad}"
+        )));
+    }
+    state.buffer_channels = channels;
+    // Scan data is sent in packages of 16 pixels
+    state.pixels = ((args[1].as_i32()? + 8) / 16 * 16).max(16);
+    state.lines = args[2].as_i32()?.max(1);
+    Ok(MockResponse::empty())
+}
+
+fn scan_buffer_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::I32(state.buffer_channels.len() as i32),
+            NanonisValue::ArrayI32(state.buffer_channels.clone()),
+            NanonisValue::I32(state.pixels),
+            NanonisValue::I32(state.lines),
+        ],
+        vec!["i", "*i", "i", "i"],
+    ))
+}
+
+/// Only the continuous scan flag is simulated.
+fn scan_props_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    match request.args(&["I"])?[0].as_u32()? {
+        1 => state.continuous_scan = true,
+        2 => state.continuous_scan = false,
+        _ => {}
+    }
+    Ok(MockResponse::empty())
+}
+
+fn scan_speed_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["f", "f", "f", "f", "H", "f"])?;
+    state.scan_config = ScanConfig {
+        forward_linear_speed_m_s: args[0].as_f32()?,
+        backward_linear_speed_m_s: args[1].as_f32()?,
+        forward_time_per_line_s: args[2].as_f32()?,
+        backward_time_per_line_s: args[3].as_f32()?,
+        keep_parameter_constant: args[4].as_u16()?,
+        speed_ratio: args[5].as_f32()?,
+    };
+    Ok(MockResponse::empty())
+}
+
+fn scan_speed_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let config = state.scan_config;
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::F32(config.forward_linear_speed_m_s),
+            NanonisValue::F32(config.backward_linear_speed_m_s),
+            NanonisValue::F32(config.forward_time_per_line_s),
+            NanonisValue::F32(config.backward_time_per_line_s),
+            NanonisValue::U16(config.keep_parameter_constant),
+            NanonisValue::F32(config.speed_ratio),
+        ],
+        vec!["f", "f", "f", "f", "H", "f"],
+    ))
+}
+
+fn scan_frame_data_grab(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["I", "I"])?;
+    let channel = args[0].as_u32()? as i32;
+    let types = vec!["i", "*-c", "i", "i", "2f", "I"];
+
+    // The buffer records slots; the data is that of the signal in the slot
+    let signal = state
+        .buffer_channels
+        .contains(&channel)
+        .then(|| state.signal_in_slot(channel))
+        .flatten();
+    let Some(signal) = signal else {
+        let placeholder = vec![
+            NanonisValue::I32(0),
+            NanonisValue::String(String::new()),
+            NanonisValue::I32(0),
+            NanonisValue::I32(0),
+            NanonisValue::Array2DF32(Array2D::default()),
+            NanonisValue::U32(0),
+        ];
+        return Ok(MockResponse::values(placeholder, types).with_error(
+            UNKNOWN_COMMAND_CODE,
+            format!("Channel {channel} is not in the scan buffer"),
+        ));
+    };
+
+    // Lines not scanned yet are NaN, as on the instrument; a continuous
+    // scan keeps the previous frame until a line is scanned again
+    let completed = state.completed_lines();
+    let previous_frame = state.scanned_lines() >= state.lines;
+    let mut data = Vec::with_capacity((state.lines * state.pixels) as usize);
+    for line in 0..state.lines {
+        data.extend((0..state.pixels).map(|pixel| {
+            if line < completed || previous_frame {
+                state.pixel_value(signal, pixel, line)
+            } else {
+                f32::NAN
+            }
+        }));
+    }
+    let data = Array2D::from_vec(state.lines as usize, state.pixels as usize, data)?;
+
+    let name = SimulatedStm::SIGNAL_NAMES[signal as usize].to_string();
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::I32(name.len() as i32),
+            NanonisValue::String(name),
+            NanonisValue::I32(state.lines),
+            NanonisValue::I32(state.pixels),
+            NanonisValue::Array2DF32(data),
+            NanonisValue::U32(state.scan.up as u32),
+        ],
+        types,
+    ))
+}
+
+fn scan_wait_end_of_scan(
+    state: &Mutex<SimState>,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let timeout_ms = request.args(&["i"])?[0].as_i32()?;
+    let deadline =
+        (timeout_ms >= 0).then(|| Instant::now() + Duration::from_millis(timeout_ms as u64));
+
+    let mut last = Duration::MAX;
+    let timed_out = loop {
+        let remaining = lock(state).remaining_scan_time();
+        // A continuous scan starting the next frame ends the wait too
+        if remaining.is_zero() || remaining > last {
+            break false;
+        }
+        last = remaining;
+        let mut nap = remaining.min(Duration::from_millis(10));
+        if let Some(deadline) = deadline {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                break true;
+            }
+            nap = nap.min(left);
+        }
+        thread::sleep(nap);
+    };
+
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::U32(timed_out as u32),
+            NanonisValue::U32(0),
+            NanonisValue::String(String::new()),
+        ],
+        vec!["I", "I", "*-c"],
+    ))
+}
+
+fn motor_start_move(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["I", "H", "I", "I"])?;
+    let steps = args[1].as_u16()? as i32;
+    let (x, y, z) = &mut state.step_counter;
+    match args[0].as_u32()? {
+        0 => *x += steps,
+        1 => *x -= steps,
+        2 => *y += steps,
+        3 => *y -= steps,
+        4 => *z += steps,
+        5 => *z -= steps,
+        other => {
+            return Err(NanonisError::Protocol(format!(
+                "Invalid motor direction: {other}"
+            )))
+        }
+    }
+    Ok(MockResponse::empty())
+}
+
+fn motor_step_counter_get(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["I", "I", "I"])?;
+    let counter = state.step_counter;
+    let (x, y, z) = &mut state.step_counter;
+    for (reset, value) in args.iter().zip([x, y, z]) {
+        if reset.as_u32()? == 1 {
+            *value = 0;
+        }
+    }
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::I32(counter.0),
+            NanonisValue::I32(counter.1),
+            NanonisValue::I32(counter.2),
+        ],
+        vec!["i", "i", "i"],
+    ))
+}
+
+fn signals_names_get(_: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let names = SimulatedStm::SIGNAL_NAMES
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    Ok(MockResponse::values(
+        vec![NanonisValue::ArrayString(names)],
+        vec!["+*c"],
+    ))
+}
+
+fn signals_in_slots_get(
+    state: &mut SimState,
+    _: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let names: Vec<String> = state
+        .slot_signals
+        .iter()
+        .map(|&index| SimulatedStm::SIGNAL_NAMES[index as usize].to_string())
+        .collect();
+    let names_size: usize = names.iter().map(|s| 4 + s.len()).sum();
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::I32(names_size as i32),
+            NanonisValue::I32(names.len() as i32),
+            NanonisValue::ArrayString(names),
+            NanonisValue::I32(state.slot_signals.len() as i32),
+            NanonisValue::ArrayI32(state.slot_signals.clone()),
+        ],
+        vec!["i", "i", "*+c", "i", "*i"],
+    ))
+}
+
+fn signals_vals_get(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["+*i", "I"])?;
+    let values: Vec<f32> = args[0]
+        .as_i32_array()?
+        .iter()
+        .map(|&index| state.signal_value(index))
+        .collect();
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::I32(values.len() as i32),
+            NanonisValue::ArrayF32(values),
+        ],
+        vec!["i", "*f"],
+    ))
+}
+
+fn signals_val_get(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let index = request.args(&["i", "I"])?[0].as_i32()?;
+    let value = state.signal_value(index);
+    Ok(f32_reply(&[value]))
+}
+
+fn signals_calibr_get(
+    _: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    signal_name(request.args(&["i"])?[0].as_i32()?)?;
+    Ok(f32_reply(&[1.0, 0.0]))
+}
+
+fn signals_range_get(
+    _: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    // Full scale of a +-10 V input in the signal's unit
+    let name = signal_name(request.args(&["i"])?[0].as_i32()?)?;
+    let max = match crate::files::split_unit(name).1 {
+        "A" => 1e-7,
+        "m" => 1e-6,
+        "deg" => 180.0,
+        "Hz" => 1e3,
+        _ => 10.0,
+    };
+    Ok(f32_reply(&[max, -max]))
+}
+
+fn signal_name(index: i32) -> Result<&'static str, NanonisError> {
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| SimulatedStm::SIGNAL_NAMES.get(index).copied())
+        .ok_or_else(|| NanonisError::Protocol(format!("Invalid signal index {index}")))
+}
+
+fn bias_spectr_limits_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["f", "f"])?;
+    state.spectr_limits_v = (args[0].as_f32()?, args[1].as_f32()?);
+    Ok(MockResponse::empty())
+}
+
+fn bias_spectr_props_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["H", "i", "H", "i", "f", "H", "H"])?;
+    let num_points = args[3].as_i32()?;
+    if num_points > 0 {
+        state.spectr_points = num_points;
+    }
+    Ok(MockResponse::empty())
+}
+
+fn bias_spectr_limits_get(
+    state: &mut SimState,
+    _: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    Ok(f32_reply(&[
+        state.spectr_limits_v.0,
+        state.spectr_limits_v.1,
+    ]))
+}
+
+/// Sweeps the bias at the current tip height and returns rows of
+/// `[Bias (V), Current (A)]`, one column per sweep point.
+fn bias_spectr_start(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let get_data = request
+        .body
+        .get(..4)
+        .map(|b| b != [0, 0, 0, 0])
+        .unwrap_or(false);
+    let types = vec!["i", "i", "*+c", "i", "i", "2f", "i", "*f"];
+
+    if !get_data {
+        return Ok(MockResponse::values(
+            vec![
+                NanonisValue::I32(0),
+                NanonisValue::I32(0),
+                NanonisValue::ArrayString(vec![]),
+                NanonisValue::I32(0),
+                NanonisValue::I32(0),
+                NanonisValue::Array2DF32(Array2D::default()),
+                NanonisValue::I32(0),
+                NanonisValue::ArrayF32(vec![]),
+            ],
+            types,
+        ));
+    }
+
+    let (start, end) = state.spectr_limits_v;
+    let points = state.spectr_points.max(2);
+    let (x, y) = state.tip_position();
+    let biases: Vec<f32> = (0..points)
+        .map(|i| start + (end - start) * i as f32 / (points - 1) as f32)
+        .collect();
+    let mut data = biases.clone();
+    for &bias in &biases {
+        let current = state.current_at(x, y, bias);
+        data.push(current + state.noise(CURRENT_NOISE_A));
+    }
+    let data = Array2D::from_vec(2, points as usize, data)?;
+
+    let names = vec![
+        SimulatedStm::SIGNAL_NAMES[SimulatedStm::BIAS as usize].to_string(),
+        SimulatedStm::SIGNAL_NAMES[SimulatedStm::CURRENT as usize].to_string(),
+    ];
+    let names_size: usize = names.iter().map(|s| 4 + s.len()).sum();
+
+    Ok(MockResponse::values(
+        vec![
+            NanonisValue::I32(names_size as i32),
+            NanonisValue::I32(names.len() as i32),
+            NanonisValue::ArrayString(names),
+            NanonisValue::I32(2),
+            NanonisValue::I32(points),
+            NanonisValue::Array2DF32(data),
+            NanonisValue::I32(0),
+            NanonisValue::ArrayF32(vec![]),
+        ],
+        types,
+    ))
+}
+
+/// Moves the tip at once; ignored while a scan is running.
+fn folme_xy_pos_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["d", "d", "I"])?;
+    if !state.scan_running() {
+        state.tip_xy_m = Some((args[0].as_f64()?, args[1].as_f64()?));
+    }
+    Ok(MockResponse::empty())
+}
+
+fn folme_xy_pos_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    let (x, y) = state.tip_position();
+    Ok(MockResponse::values(
+        vec![NanonisValue::F64(x as f64), NanonisValue::F64(y as f64)],
+        vec!["d", "d"],
+    ))
+}
+
+fn tcplog_chs_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    let args = request.args(&["i", "*i"])?;
+    state.tcplog_channels = args[1].as_i32_array()?.to_vec();
+    Ok(MockResponse::empty())
+}
+
+fn tcplog_oversampl_set(
+    state: &mut SimState,
+    request: &MockRequest,
+) -> Result<MockResponse, NanonisError> {
+    state.tcplog_oversampling = request.args(&["i"])?[0].as_i32()?;
+    Ok(MockResponse::empty())
+}
+
+fn tcplog_start(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    state.tcplog_status = TCPLogStatus::Running;
+    Ok(MockResponse::empty())
+}
+
+fn tcplog_stop(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    state.tcplog_status = TCPLogStatus::Idle;
+    Ok(MockResponse::empty())
+}
+
+fn tcplog_status_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(MockResponse::values(
+        vec![NanonisValue::I32(state.tcplog_status.into())],
+        vec!["i"],
+    ))
+}
+
+fn util_rt_freq_get(state: &mut SimState, _: &MockRequest) -> Result<MockResponse, NanonisError> {
+    Ok(f32_reply(&[state.rt_frequency_hz]))
+}
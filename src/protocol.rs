@@ -1,8 +1,9 @@
 use crate::error::NanonisError;
-use crate::types::NanonisValue;
+use crate::types::{Array2D, NanonisValue};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use log::debug;
 use std::io::Read;
+use std::mem::size_of;
 
 // Protocol constants
 pub const COMMAND_SIZE: usize = 32;
@@ -184,175 +185,40 @@ impl Protocol {
         response: &[u8],
         response_types: &[&str],
     ) -> Result<Vec<NanonisValue>, NanonisError> {
-        // Parse normal response data first
-        let values = Self::parse_response(response, response_types)?;
-
-        // Calculate cursor position after parsing all response data
-        let cursor = Self::calculate_cursor_position(response, response_types)?;
-
-        // Check for errors at the end
-        Self::parse_error_info(response, cursor)?;
-
-        Ok(values)
+        // Parse normal response data first, remembering where it ends,
+        // then check for errors at the end
+        let result = Self::parse_values(response, response_types).and_then(
+            |(values, cursor)| {
+                Self::parse_error_info(response, cursor)?;
+                Ok(values)
+            },
+        );
+
+        match result {
+            // A reply to a command the server does not know may carry
+            // only the error trailer, without placeholder return values
+            Err(_) if Self::is_bare_error(response) => {
+                Self::parse_error_info(response, 0)?;
+                result
+            }
+            result => result,
+        }
     }
 
-    /// Calculate cursor position after parsing response data
-    fn calculate_cursor_position(
-        response: &[u8],
-        response_types: &[&str],
-    ) -> Result<usize, NanonisError> {
-        let mut cursor = std::io::Cursor::new(response);
-        let mut result = Vec::with_capacity(response_types.len());
-
-        // This is essentially the same parsing logic as parse_response,
-        // but we only track the cursor position without storing values
-        for &response_type in response_types {
-            match response_type {
-                "H" => {
-                    cursor.read_u16::<BigEndian>()?;
-                }
-                "h" => {
-                    cursor.read_i16::<BigEndian>()?;
-                }
-                "I" => {
-                    let val = cursor.read_u32::<BigEndian>()?;
-                    result.push(val);
-                }
-                "i" => {
-                    let val = cursor.read_i32::<BigEndian>()? as u32;
-                    result.push(val);
-                }
-                "f" => {
-                    cursor.read_f32::<BigEndian>()?;
-                }
-                "d" => {
-                    cursor.read_f64::<BigEndian>()?;
-                }
-
-                t if t.contains("*f") => {
-                    let len = if t.starts_with("+") {
-                        cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(&prev_val) = result.last() {
-                        prev_val as usize
-                    } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
-                    };
-
-                    for _ in 0..len {
-                        cursor.read_f32::<BigEndian>()?;
-                    }
-                }
-
-                t if t.contains("*d") => {
-                    let len = if t.starts_with("+") {
-                        cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(&prev_val) = result.last() {
-                        prev_val as usize
-                    } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
-                    };
-
-                    for _ in 0..len {
-                        cursor.read_f64::<BigEndian>()?;
-                    }
-                }
-
-                t if t.contains("*i") => {
-                    let len = if t.starts_with("+") {
-                        cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(&prev_val) = result.last() {
-                        prev_val as usize
-                    } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
-                    };
-
-                    for _ in 0..len {
-                        cursor.read_i32::<BigEndian>()?;
-                    }
-                }
-
-                "+*c" => {
-                    let _total_size = cursor.read_u32::<BigEndian>()?;
-                    let num_strings = cursor.read_u32::<BigEndian>()? as usize;
-
-                    for _ in 0..num_strings {
-                        let string_len = cursor.read_u32::<BigEndian>()? as usize;
-                        let mut string_bytes = vec![0u8; string_len];
-                        cursor.read_exact(&mut string_bytes)?;
-                    }
-                }
-
-                "*+c" => {
-                    let num_strings = if let Some(&prev_val) = result.last() {
-                        prev_val as usize
-                    } else {
-                        return Err(NanonisError::Protocol(
-                            "String count not found for *+c type".to_string(),
-                        ));
-                    };
-
-                    for _ in 0..num_strings {
-                        let string_len = cursor.read_u32::<BigEndian>()? as usize;
-                        let mut string_bytes = vec![0u8; string_len];
-                        cursor.read_exact(&mut string_bytes)?;
-                    }
-                }
-
-                "*-c" => {
-                    let string_length = result.last().ok_or_else(|| {
-                        NanonisError::Protocol(
-                            "String length not found for *-c type".to_string(),
-                        )
-                    })?;
-
-                    let mut string_bytes = vec![0u8; *string_length as usize];
-                    cursor.read_exact(&mut string_bytes)?;
-                }
-
-                "*+i" => {
-                    let array_count = if let Some(&prev_val) = result.last() {
-                        prev_val as usize
-                    } else {
-                        return Err(NanonisError::Protocol(
-                            "Array count not found for *+i type".to_string(),
-                        ));
-                    };
-
-                    for _ in 0..array_count {
-                        cursor.read_i32::<BigEndian>()?;
-                    }
-                }
-
-                "2f" => {
-                    if result.len() < 2 {
-                        return Err(NanonisError::Protocol(
-                            "2D array dimensions not found".to_string(),
-                        ));
-                    }
-
-                    let rows = result[result.len() - 2] as usize;
-                    let cols = result[result.len() - 1] as usize;
-
-                    for _ in 0..(rows * cols) {
-                        cursor.read_f32::<BigEndian>()?;
-                    }
-                }
-
-                _ => {
-                    return Err(NanonisError::Protocol(format!(
-                        "Unsupported response type: {response_type}"
-                    )));
-                }
-            };
+    /// Whether the body consists of nothing but a non-zero error trailer.
+    fn is_bare_error(response: &[u8]) -> bool {
+        let field = |start: usize| {
+            response
+                .get(start..start + 4)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(i32::from_be_bytes)
+        };
+        match (field(0), field(4)) {
+            (Some(status), Some(size)) if status != 0 && size > 0 => {
+                response.len() == ERROR_INFO_SIZE + size as usize
+            }
+            _ => false,
         }
-
-        Ok(cursor.position() as usize)
     }
 
     /// Serialize a value according to its type specification
@@ -378,6 +244,11 @@ impl Protocol {
                 buffer.extend_from_slice(bytes);
             }
 
+            (NanonisValue::String(s), "*-c") => {
+                // Length comes from the previous variable
+                buffer.extend_from_slice(s.as_bytes());
+            }
+
             (NanonisValue::ArrayString(arr), "+*c") => {
                 // Write total byte size (sum of all string lengths + size prefixes)
                 let total_size: usize = arr.iter().map(|s| 4 + s.len()).sum();
@@ -437,6 +308,13 @@ impl Protocol {
                 }
             }
 
+            (NanonisValue::Array2DF32(array), "2f") => {
+                // Dimensions come from the two previous variables
+                for &val in array.as_slice() {
+                    buffer.write_f32::<BigEndian>(val)?;
+                }
+            }
+
             (NanonisValue::ArrayU8(arr), t) if t.contains("*b") => {
                 if t.starts_with("+") {
                     buffer.write_i32::<BigEndian>(arr.len() as i32)?;
@@ -454,10 +332,19 @@ impl Protocol {
     }
 
     /// Parse response data according to type specifications
+    #[cfg(feature = "mock")]
     pub fn parse_response(
         response: &[u8],
         response_types: &[&str],
     ) -> Result<Vec<NanonisValue>, NanonisError> {
+        Ok(Self::parse_values(response, response_types)?.0)
+    }
+
+    /// Parse response data and return the position where the values end.
+    fn parse_values(
+        response: &[u8],
+        response_types: &[&str],
+    ) -> Result<(Vec<NanonisValue>, usize), NanonisError> {
         let mut cursor = std::io::Cursor::new(response);
         let mut result = Vec::with_capacity(response_types.len());
 
@@ -473,104 +360,52 @@ impl Protocol {
                 t if t.contains("*f") => {
                     let len = if t.starts_with("+") {
                         cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(prev_val) = result.last() {
-                        match prev_val {
-                            NanonisValue::U32(len) => *len as usize,
-                            NanonisValue::I32(len) => *len as usize,
-                            _ => {
-                                return Err(NanonisError::Protocol(
-                                    "Array length not found".to_string(),
-                                ))
-                            }
-                        }
                     } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
+                        Self::preceding_len(&result)?
                     };
 
-                    let mut arr = Vec::with_capacity(len);
-                    for _ in 0..len {
-                        arr.push(cursor.read_f32::<BigEndian>()?);
-                    }
+                    Self::ensure_remaining(&cursor, len, size_of::<f32>())?;
+                    let mut arr = vec![0f32; len];
+                    cursor.read_f32_into::<BigEndian>(&mut arr)?;
                     NanonisValue::ArrayF32(arr)
                 }
 
                 t if t.contains("*d") => {
                     let len = if t.starts_with("+") {
                         cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(prev_val) = result.last() {
-                        match prev_val {
-                            NanonisValue::U32(len) => *len as usize,
-                            NanonisValue::I32(len) => *len as usize,
-                            _ => {
-                                return Err(NanonisError::Protocol(
-                                    "Array length not found".to_string(),
-                                ))
-                            }
-                        }
                     } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
+                        Self::preceding_len(&result)?
                     };
 
-                    let mut arr = Vec::with_capacity(len);
-                    for _ in 0..len {
-                        arr.push(cursor.read_f64::<BigEndian>()?);
-                    }
+                    Self::ensure_remaining(&cursor, len, size_of::<f64>())?;
+                    let mut arr = vec![0f64; len];
+                    cursor.read_f64_into::<BigEndian>(&mut arr)?;
                     NanonisValue::ArrayF64(arr)
                 }
 
                 t if t.contains("*i") => {
                     let len = if t.starts_with("+") {
                         cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(prev_val) = result.last() {
-                        match prev_val {
-                            NanonisValue::U32(len) => *len as usize,
-                            NanonisValue::I32(len) => *len as usize,
-                            _ => {
-                                return Err(NanonisError::Protocol(
-                                    "Array length not found".to_string(),
-                                ))
-                            }
-                        }
                     } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
+                        Self::preceding_len(&result)?
                     };
 
-                    let mut arr = Vec::with_capacity(len);
-                    for _ in 0..len {
-                        arr.push(cursor.read_i32::<BigEndian>()?);
-                    }
+                    Self::ensure_remaining(&cursor, len, size_of::<i32>())?;
+                    let mut arr = vec![0i32; len];
+                    cursor.read_i32_into::<BigEndian>(&mut arr)?;
                     NanonisValue::ArrayI32(arr)
                 }
 
                 t if t.contains("*I") => {
                     let len = if t.starts_with("+") {
                         cursor.read_u32::<BigEndian>()? as usize
-                    } else if let Some(prev_val) = result.last() {
-                        match prev_val {
-                            NanonisValue::U32(len) => *len as usize,
-                            NanonisValue::I32(len) => *len as usize,
-                            _ => {
-                                return Err(NanonisError::Protocol(
-                                    "Array length not found".to_string(),
-                                ))
-                            }
-                        }
                     } else {
-                        return Err(NanonisError::Protocol(
-                            "Array length not specified".to_string(),
-                        ));
+                        Self::preceding_len(&result)?
                     };
 
-                    let mut arr = Vec::with_capacity(len);
-                    for _ in 0..len {
-                        arr.push(cursor.read_u32::<BigEndian>()?);
-                    }
+                    Self::ensure_remaining(&cursor, len, size_of::<u32>())?;
+                    let mut arr = vec![0u32; len];
+                    cursor.read_u32_into::<BigEndian>(&mut arr)?;
                     NanonisValue::ArrayU32(arr)
                 }
 
@@ -654,10 +489,9 @@ impl Protocol {
                     };
 
                     // Read the integer array
-                    let mut arr = Vec::with_capacity(array_count);
-                    for _ in 0..array_count {
-                        arr.push(cursor.read_i32::<BigEndian>()?);
-                    }
+                    Self::ensure_remaining(&cursor, array_count, size_of::<i32>())?;
+                    let mut arr = vec![0i32; array_count];
+                    cursor.read_i32_into::<BigEndian>(&mut arr)?;
 
                     NanonisValue::ArrayI32(arr)
                 }
@@ -688,16 +522,14 @@ impl Protocol {
                         }
                     };
 
-                    // Read the flat array data
-                    let mut data_2d = Vec::with_capacity(rows);
-
-                    for _ in 0..rows {
-                        let mut row_data = Vec::with_capacity(cols);
-                        for _ in 0..cols {
-                            row_data.push(cursor.read_f32::<BigEndian>()?);
-                        }
-                        data_2d.push(row_data);
-                    }
+                    // Decode the row-major data in one pass
+                    let len = rows.checked_mul(cols).ok_or_else(|| {
+                        NanonisError::Protocol(format!("2D array too large: {rows}x{cols}"))
+                    })?;
+                    Self::ensure_remaining(&cursor, len, size_of::<f32>())?;
+                    let mut data = vec![0f32; len];
+                    cursor.read_f32_into::<BigEndian>(&mut data)?;
+                    let data_2d = Array2D::from_vec(rows, cols, data)?;
 
                     NanonisValue::Array2DF32(data_2d)
                 }
@@ -712,7 +544,47 @@ impl Protocol {
             result.push(value);
         }
 
-        Ok(result)
+        Ok((result, cursor.position() as usize))
+    }
+
+    /// Length of an array sent without its own length prefix.
+    ///
+    /// The length is the preceding integer, or the length of the preceding
+    /// array: Nanonis sends a single count for several arrays of the same
+    /// length, e.g. `"i", "*f", "*f"` for X and Y coordinates.
+    fn preceding_len(result: &[NanonisValue]) -> Result<usize, NanonisError> {
+        match result.last() {
+            Some(NanonisValue::U32(len)) => Ok(*len as usize),
+            Some(NanonisValue::I32(len)) => Ok(*len as usize),
+            Some(NanonisValue::ArrayF32(arr)) => Ok(arr.len()),
+            Some(NanonisValue::ArrayF64(arr)) => Ok(arr.len()),
+            Some(NanonisValue::ArrayI32(arr)) => Ok(arr.len()),
+            Some(NanonisValue::ArrayU32(arr)) => Ok(arr.len()),
+            Some(NanonisValue::ArrayString(arr)) => Ok(arr.len()),
+            Some(_) => Err(NanonisError::Protocol(
+                "Array length not found".to_string(),
+            )),
+            None => Err(NanonisError::Protocol(
+                "Array length not specified".to_string(),
+            )),
+        }
+    }
+
+    /// Ensure `count` elements of `size` bytes remain before allocating them.
+    ///
+    /// Guards against corrupted length fields requesting huge buffers.
+    fn ensure_remaining(
+        cursor: &std::io::Cursor<&[u8]>,
+        count: usize,
+        size: usize,
+    ) -> Result<(), NanonisError> {
+        let remaining = cursor.get_ref().len().saturating_sub(cursor.position() as usize);
+        match count.checked_mul(size) {
+            Some(bytes) if bytes <= remaining => Ok(()),
+            _ => Err(NanonisError::Protocol(format!(
+                "Array of {count} elements exceeds the {remaining} remaining response bytes"
+            ))),
+        }
     }
 
     /// Create command header with proper padding using safe serialization
@@ -0,0 +1,318 @@
+use crate::error::NanonisError;
+use crate::protocol::HEADER_SIZE;
+use crate::transport::Transport;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One request/response pair captured by the recorder.
+///
+/// Headers and bodies are stored as hex strings, so a recording file can be
+/// attached to a bug report and inspected by hand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedExchange {
+    /// Time the response was received, in seconds since the Unix epoch
+    pub timestamp_s: f64,
+    /// Command name, e.g. `"Bias.Get"`
+    pub command: String,
+    /// 40-byte request header
+    #[serde(with = "hex")]
+    pub request_header: Vec<u8>,
+    /// Request body
+    #[serde(with = "hex")]
+    pub request_body: Vec<u8>,
+    /// 40-byte response header
+    #[serde(with = "hex")]
+    pub response_header: Vec<u8>,
+    /// Response body, including the error trailer
+    #[serde(with = "hex")]
+    pub response_body: Vec<u8>,
+}
+
+/// Writes every exchange of a client as one JSON line.
+pub(crate) struct Recorder {
+    writer: BufWriter<File>,
+}
+
+impl Recorder {
+    pub fn create(path: &Path) -> Result<Self, NanonisError> {
+        let file = File::create(path).map_err(|e| NanonisError::Io {
+            source: e,
+            context: format!("Creating recording {}", path.display()),
+        })?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn record(
+        &mut self,
+        command: &str,
+        request_header: &[u8],
+        request_body: &[u8],
+        response_header: &[u8],
+        response_body: &[u8],
+    ) {
+        let exchange = RecordedExchange {
+            timestamp_s: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |t| t.as_secs_f64()),
+            command: command.to_string(),
+            request_header: request_header.to_vec(),
+            request_body: request_body.to_vec(),
+            response_header: response_header.to_vec(),
+            response_body: response_body.to_vec(),
+        };
+
+        // Flush every line so the recording survives a crash
+        let result = serde_json::to_writer(&mut self.writer, &exchange)
+            .map_err(std::io::Error::from)
+            .and_then(|_| self.writer.write_all(b"\n"))
+            .and_then(|_| self.writer.flush());
+        if let Err(e) = result {
+            warn!("Failed to record {command}: {e}");
+        }
+    }
+}
+
+/// Traffic captured with [`NanonisClient::record_to`](crate::NanonisClient::record_to).
+///
+/// A recording is a JSON Lines file with one [`RecordedExchange`] per line.
+/// [`replay`](Self::replay) serves it back as a [`Transport`], so the exact
+/// bytes from the instrument can be turned into a regression test.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockServer, NanonisClient, Recording, Volts};
+///
+/// let path = std::env::temp_dir().join("nanonis-rs-recording-example.jsonl");
+///
+/// // Capture traffic from the instrument (here: the simulator)
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// client.record_to(&path)?;
+/// client.bias_set(Volts::new(0.42))?;
+/// let bias = client.bias_get()?;
+/// client.stop_recording();
+///
+/// // Serve it back without the instrument
+/// let recording = Recording::load(&path)?;
+/// assert_eq!(recording.exchanges().len(), 2);
+///
+/// let mut client = NanonisClient::from_transport(recording.replay());
+/// client.bias_set(Volts::new(0.42))?;
+/// assert_eq!(client.bias_get()?, bias);
+///
+/// // Requests beyond the recording fail
+/// assert!(client.bias_get().unwrap_err().is_protocol());
+/// # std::fs::remove_file(&path).ok();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Recording {
+    exchanges: Vec<RecordedExchange>,
+}
+
+impl Recording {
+    /// Load a recording file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, NanonisError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| NanonisError::Io {
+            source: e,
+            context: format!("Opening recording {}", path.display()),
+        })?;
+
+        let mut exchanges = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                exchanges.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(Self { exchanges })
+    }
+
+    /// Build a recording from captured exchanges.
+    pub fn from_exchanges(exchanges: Vec<RecordedExchange>) -> Self {
+        Self { exchanges }
+    }
+
+    /// Captured exchanges, in recording order.
+    pub fn exchanges(&self) -> &[RecordedExchange] {
+        &self.exchanges
+    }
+
+    /// Serve the recorded responses to a client, in recording order.
+    ///
+    /// Use the transport with
+    /// [`NanonisClient::from_transport`](crate::NanonisClient::from_transport).
+    /// Every request must match the next recorded exchange byte for byte;
+    /// see [`ReplayTransport`] for what happens when it does not.
+    pub fn replay(&self) -> ReplayTransport {
+        ReplayTransport {
+            exchanges: self.exchanges.clone(),
+            next: 0,
+            request: Vec::new(),
+            reply: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+/// [`Transport`] answering requests from a [`Recording`], see
+/// [`Recording::replay`].
+///
+/// The recording is served as one global sequence: each request is compared
+/// with the next recorded exchange, whatever its command. A request for a
+/// different command or with different arguments, or one sent after the
+/// last recorded exchange, fails with [`NanonisError::Protocol`] naming the
+/// divergence, and the sequence does not advance. Reads return end-of-file
+/// when no reply is pending.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockServer, NanonisClient, Recording, Volts};
+///
+/// let path = std::env::temp_dir().join("nanonis-rs-replay-example.jsonl");
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// client.record_to(&path)?;
+/// client.bias_set(Volts::new(0.42))?;
+/// client.bias_get()?;
+/// client.stop_recording();
+///
+/// let recording = Recording::load(&path)?;
+/// let mut client = NanonisClient::from_transport(recording.replay());
+///
+/// // Out of order: the recording continues with Bias.Set
+/// let err = client.bias_get().unwrap_err();
+/// assert!(err.to_string().contains("expected Bias.Set, got Bias.Get"));
+///
+/// // Different arguments
+/// let err = client.bias_set(Volts::new(0.5)).unwrap_err();
+/// assert!(err.to_string().contains("different arguments"));
+///
+/// // The sequence resumes once the requests match again
+/// client.bias_set(Volts::new(0.42))?;
+/// client.bias_get()?;
+/// # std::fs::remove_file(&path).ok();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    exchanges: Vec<RecordedExchange>,
+    next: usize,
+    request: Vec<u8>,
+    reply: Vec<u8>,
+    position: usize,
+}
+
+impl ReplayTransport {
+    /// Match a complete request against the next recorded exchange and
+    /// queue its response.
+    fn serve(&mut self, request: &[u8]) -> Result<(), NanonisError> {
+        let command = String::from_utf8_lossy(&request[..32])
+            .trim_end_matches('\0')
+            .to_string();
+        let Some(exchange) = self.exchanges.get(self.next) else {
+            return Err(NanonisError::Protocol(format!(
+                "Replay exhausted: {command} sent after all {} recorded exchanges",
+                self.exchanges.len()
+            )));
+        };
+
+        if exchange.command != command {
+            return Err(NanonisError::Protocol(format!(
+                "Replay diverged at exchange {}: expected {}, got {command}",
+                self.next + 1,
+                exchange.command
+            )));
+        }
+        if exchange.request_header != request[..HEADER_SIZE]
+            || exchange.request_body != request[HEADER_SIZE..]
+        {
+            return Err(NanonisError::Protocol(format!(
+                "Replay diverged at exchange {}: {command} sent different arguments",
+                self.next + 1
+            )));
+        }
+
+        self.reply = [&exchange.response_header[..], &exchange.response_body[..]].concat();
+        self.position = 0;
+        self.next += 1;
+        Ok(())
+    }
+}
+
+impl Read for ReplayTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.reply.len() - self.position);
+        buf[..n].copy_from_slice(&self.reply[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+impl Write for ReplayTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.request.extend_from_slice(buf);
+        while self.request.len() >= HEADER_SIZE {
+            let body_size = u32::from_be_bytes([
+                self.request[32],
+                self.request[33],
+                self.request[34],
+                self.request[35],
+            ]) as usize;
+            if self.request.len() < HEADER_SIZE + body_size {
+                break;
+            }
+            let rest = self.request.split_off(HEADER_SIZE + body_size);
+            let request = std::mem::replace(&mut self.request, rest);
+            if let Err(e) = self.serve(&request) {
+                self.request.clear();
+                return Err(io::Error::other(e));
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Transport for ReplayTransport {}
+
+/// Serde adapter storing bytes as a lowercase hex string.
+mod hex {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let hex: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+        serializer.serialize_str(&hex)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        let digits = hex.as_bytes();
+        if digits.len() % 2 != 0 {
+            return Err(D::Error::custom("odd number of hex digits"));
+        }
+        digits
+            .chunks_exact(2)
+            .map(|pair| Ok(digit(pair[0])? << 4 | digit(pair[1])?))
+            .collect()
+    }
+
+    fn digit<E: Error>(byte: u8) -> Result<u8, E> {
+        match byte {
+            b'0'..=b'9' => Ok(byte - b'0'),
+            b'a'..=b'f' => Ok(byte - b'a' + 10),
+            b'A'..=b'F' => Ok(byte - b'A' + 10),
+            _ => Err(E::custom(format!("invalid hex digit {:?}", byte as char))),
+        }
+    }
+}
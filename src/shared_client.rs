@@ -0,0 +1,595 @@
+use crate::bias_spectr::BiasSpectrResult;
+use crate::client::shutdown::{ShutdownOutcome, ShutdownPolicy};
+#[cfg(any(unix, windows))]
+use crate::client::shutdown;
+use crate::client::{NanonisClient, Offload};
+use crate::command::Command;
+use crate::error::NanonisError;
+use log::{debug, warn};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Priority of a call on a [`SharedNanonisClient`].
+///
+/// Queued calls run in order of priority, and in submission order within
+/// the same priority. A running call is never interrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Background work that may wait, e.g. periodic status polling
+    Low,
+    /// Regular calls
+    #[default]
+    Normal,
+    /// Safety actions such as withdrawing the tip
+    High,
+}
+
+type Task = Box<dyn FnOnce(&mut NanonisClient) + Send>;
+
+struct Job {
+    priority: Priority,
+    seq: u64,
+    task: Task,
+}
+
+impl PartialEq for Job {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Job {}
+
+impl PartialOrd for Job {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Job {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Max-heap: higher priority first, then lower sequence number
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+#[derive(Default)]
+struct Queue {
+    jobs: BinaryHeap<Job>,
+    blocking_jobs: BinaryHeap<Job>,
+    next_seq: u64,
+    closed: bool,
+}
+
+#[derive(Default)]
+struct Dispatcher {
+    queue: Mutex<Queue>,
+    available: Condvar,
+}
+
+impl Dispatcher {
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn push(&self, priority: Priority, blocking: bool, task: Task) {
+        let mut queue = self.lock();
+        if queue.closed {
+            // Dropping the task aborts the call
+            return;
+        }
+        let seq = queue.next_seq;
+        queue.next_seq += 1;
+        let job = Job {
+            priority,
+            seq,
+            task,
+        };
+        if blocking {
+            queue.blocking_jobs.push(job);
+        } else {
+            queue.jobs.push(job);
+        }
+        drop(queue);
+        // Not every worker accepts blocking jobs, so wake all of them
+        self.available.notify_all();
+    }
+
+    /// Wait for the next job, or `None` once the dispatcher is closed.
+    ///
+    /// Blocking jobs are only returned if `accept_blocking` is set.
+    fn pop(&self, accept_blocking: bool) -> Option<Job> {
+        let mut queue = self.lock();
+        loop {
+            if queue.closed {
+                return None;
+            }
+            let take_blocking = accept_blocking
+                && match (queue.jobs.peek(), queue.blocking_jobs.peek()) {
+                    (Some(job), Some(blocking)) => blocking > job,
+                    (None, Some(_)) => true,
+                    _ => false,
+                };
+            let job = if take_blocking {
+                queue.blocking_jobs.pop()
+            } else {
+                queue.jobs.pop()
+            };
+            if let Some(job) = job {
+                return Some(job);
+            }
+            queue = self
+                .available
+                .wait(queue)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.available.notify_all();
+    }
+
+    /// Queue `f` as a blocking job and run short jobs on `client` until it
+    /// has finished.
+    fn call_serving<R, F>(
+        self: &Arc<Self>,
+        client: &mut NanonisClient,
+        f: F,
+    ) -> Result<R, NanonisError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut NanonisClient) -> Result<R, NanonisError> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let dispatcher = Arc::downgrade(self);
+        self.push(
+            Priority::Normal,
+            true,
+            Box::new(move |client| {
+                let _ = sender.send(f(client));
+                if let Some(dispatcher) = Weak::upgrade(&dispatcher) {
+                    // Wake the waiting connection under the lock
+                    let _queue = dispatcher.lock();
+                    dispatcher.available.notify_all();
+                }
+            }),
+        );
+
+        let aborted = || {
+            Err(NanonisError::Protocol(
+                "Shared client call was aborted".to_string(),
+            ))
+        };
+        let mut queue = self.lock();
+        loop {
+            match receiver.try_recv() {
+                Ok(result) => return result,
+                Err(mpsc::TryRecvError::Disconnected) => return aborted(),
+                Err(mpsc::TryRecvError::Empty) => {}
+            }
+            if queue.closed {
+                drop(queue);
+                return receiver.recv().unwrap_or_else(|_| aborted());
+            }
+            if let Some(job) = queue.jobs.pop() {
+                drop(queue);
+                run(job, client);
+                queue = self.lock();
+            } else {
+                queue = self
+                    .available
+                    .wait(queue)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+            }
+        }
+    }
+
+    /// Queue `f` and wait for its result.
+    fn call<R, F>(&self, priority: Priority, blocking: bool, f: F) -> Result<R, NanonisError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut NanonisClient) -> Result<R, NanonisError> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        self.push(
+            priority,
+            blocking,
+            Box::new(move |client| {
+                let _ = sender.send(f(client));
+            }),
+        );
+
+        receiver.recv().unwrap_or_else(|_| {
+            Err(NanonisError::Protocol(
+                "Shared client call was aborted".to_string(),
+            ))
+        })
+    }
+}
+
+struct Inner {
+    dispatcher: Arc<Dispatcher>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        self.dispatcher.close();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn run(job: Job, client: &mut NanonisClient) {
+    let task = job.task;
+    if panic::catch_unwind(AssertUnwindSafe(|| task(client))).is_err() {
+        warn!("Shared client call panicked");
+    }
+}
+
+/// Offload that runs blocking commands as blocking jobs of `dispatcher`,
+/// serving short calls on the offloading connection meanwhile.
+fn offload(dispatcher: &Arc<Dispatcher>) -> Offload {
+    let dispatcher = Arc::downgrade(dispatcher);
+    Arc::new(move |client, command, args, argument_types, return_types| {
+        let Some(dispatcher) = dispatcher.upgrade() else {
+            return Err(NanonisError::Protocol(
+                "Shared client call was aborted".to_string(),
+            ));
+        };
+        let command = command.to_string();
+        let argument_types: Vec<String> = argument_types.iter().map(|&t| t.to_string()).collect();
+        let return_types: Vec<String> = return_types.iter().map(|&t| t.to_string()).collect();
+        dispatcher.call_serving(client, move |client| {
+            client.quick_send(
+                &command,
+                args,
+                argument_types.iter().map(String::as_str).collect(),
+                return_types.iter().map(String::as_str).collect(),
+            )
+        })
+    })
+}
+
+/// Cloneable, thread-safe handle to one or more Nanonis connections.
+///
+/// Each connection is owned by a worker thread. Calls are queued and picked
+/// up by the next free worker, so with a single connection all calls are
+/// serialized over one socket, and with several connections (e.g. one per
+/// server port 6501-6504) independent calls run in parallel.
+///
+/// With several connections the first one is kept free for short calls:
+/// long-blocking calls submitted through [`call_blocking`](Self::call_blocking)
+/// or the blocking command methods run on the other connections, so
+/// monitoring reads keep flowing during a scan. Commands that block until a
+/// scan or sweep ends, such as `Scan.WaitEndOfScan` or a sweep start that
+/// waits for its data, are recognized from their descriptor (see
+/// [`Command::blocks`]) and sent over another connection even when issued
+/// inside [`call`](Self::call). Create such a
+/// pool with [`NanonisClientBuilder::ports`](crate::NanonisClientBuilder::ports).
+///
+/// Queued calls run by [`Priority`]: a [`Priority::High`] call, such as a
+/// safety withdraw, jumps ahead of every queued lower-priority call. It still
+/// waits for calls that are already running, so give long-blocking calls like
+/// `scan_wait_end_of_scan` their own connection if the withdraw must not wait.
+///
+/// The connections are closed when the last handle is dropped.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockServer, SharedNanonisClient, Volts};
+/// use std::thread;
+///
+/// let server = MockServer::simulated()?;
+/// let shared = SharedNanonisClient::new(server.client()?);
+///
+/// let poller = {
+///     let shared = shared.clone();
+///     thread::spawn(move || shared.call(|client| client.bias_get()))
+/// };
+///
+/// shared.call(|client| client.bias_set(Volts::new(0.3)))?;
+/// poller.join().unwrap()?;
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+///
+/// In a pool, a scan wait issued inside [`call`](Self::call) still leaves
+/// the first connection free:
+/// ```
+/// use nanonis_rs::{MockServer, NanonisClient};
+/// use nanonis_rs::scan::{ScanAction, ScanDirection};
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let pool = NanonisClient::builder()
+///     .address("127.0.0.1")
+///     .ports([server.port(), server.port()])
+///     .build_pool()?;
+/// # pool.call(|client| client.scan_buffer_set(vec![0], 64, 8))?;
+///
+/// let scans: Vec<_> = (0..2)
+///     .map(|_| {
+///         let pool = pool.clone();
+///         thread::spawn(move || {
+///             pool.call(|client| {
+///                 client.scan_action(ScanAction::Start, ScanDirection::Down)?;
+///                 client.scan_wait_end_of_scan(Duration::from_secs(10))
+///             })
+///         })
+///     })
+///     .collect();
+/// thread::sleep(Duration::from_millis(50));
+///
+/// pool.call(|client| client.bias_get())?;
+/// assert!(scans.iter().any(|scan| !scan.is_finished()));
+/// for scan in scans {
+///     scan.join().unwrap()?;
+/// }
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+///
+/// A high-priority call overtakes queued calls:
+/// ```
+/// use nanonis_rs::{MockServer, Priority, SharedNanonisClient};
+/// use nanonis_rs::scan::{ScanAction, ScanDirection};
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let shared = SharedNanonisClient::new(server.client()?);
+/// # shared.call(|client| client.scan_buffer_set(vec![0], 64, 4))?;
+/// shared.call(|client| client.scan_action(ScanAction::Start, ScanDirection::Down))?;
+///
+/// // Occupy the connection until the scan ends
+/// let scan = shared.clone();
+/// let scan = thread::spawn(move || {
+///     scan.call(|client| client.scan_wait_end_of_scan(Duration::from_secs(10)))
+/// });
+/// thread::sleep(Duration::from_millis(50));
+///
+/// let poll = shared.clone();
+/// let poll = thread::spawn(move || poll.call(|client| client.bias_get()));
+/// thread::sleep(Duration::from_millis(50));
+///
+/// shared.call_with_priority(Priority::High, |client| {
+///     client.z_ctrl_withdraw(false, Duration::from_secs(1))
+/// })?;
+/// scan.join().unwrap()?;
+/// poll.join().unwrap()?;
+///
+/// let order: Vec<_> = server.requests().into_iter().map(|r| r.command).collect();
+/// assert_eq!(
+///     order[order.len() - 3..],
+///     ["Scan.WaitEndOfScan", "ZCtrl.Withdraw", "Bias.Get"]
+/// );
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Clone)]
+pub struct SharedNanonisClient {
+    inner: Arc<Inner>,
+}
+
+impl SharedNanonisClient {
+    /// Share a single connection; all calls are serialized over it.
+    pub fn new(client: NanonisClient) -> Self {
+        Self::spawn(vec![client])
+    }
+
+    /// Share several connections, one worker thread per connection.
+    ///
+    /// # Errors
+    /// Returns `NanonisError::Protocol` if `clients` is empty.
+    pub fn from_clients(clients: Vec<NanonisClient>) -> Result<Self, NanonisError> {
+        if clients.is_empty() {
+            return Err(NanonisError::Protocol(
+                "Shared client needs at least one connection".to_string(),
+            ));
+        }
+        Ok(Self::spawn(clients))
+    }
+
+    /// Connect to each of `ports` on `addr` and share the connections.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use nanonis_rs::SharedNanonisClient;
+    ///
+    /// let shared = SharedNanonisClient::connect("127.0.0.1", &[6501, 6502, 6503, 6504])?;
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn connect(addr: &str, ports: &[u16]) -> Result<Self, NanonisError> {
+        let clients = ports
+            .iter()
+            .map(|&port| NanonisClient::new(addr, port))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_clients(clients)
+    }
+
+    fn spawn(clients: Vec<NanonisClient>) -> Self {
+        let dispatcher = Arc::new(Dispatcher::default());
+        let pooled = clients.len() > 1;
+        let workers = clients
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut client)| {
+                // Keep the first connection free for short calls
+                let accept_blocking = !pooled || index > 0;
+                if !accept_blocking {
+                    client.set_offload(offload(&dispatcher));
+                }
+                let dispatcher = Arc::clone(&dispatcher);
+                thread::Builder::new()
+                    .name(format!("nanonis-shared-{index}"))
+                    .spawn(move || {
+                        while let Some(job) = dispatcher.pop(accept_blocking) {
+                            run(job, &mut client);
+                        }
+                        debug!("Shared client worker {index} stopped");
+                    })
+                    .expect("failed to spawn shared client worker")
+            })
+            .collect();
+
+        Self {
+            inner: Arc::new(Inner {
+                dispatcher,
+                workers,
+            }),
+        }
+    }
+
+    /// Number of connections shared by this handle.
+    pub fn connections(&self) -> usize {
+        self.inner.workers.len()
+    }
+
+    /// Run `f` on the next free connection with [`Priority::Normal`].
+    ///
+    /// Blocks until the call has finished and returns its result.
+    pub fn call<R, F>(&self, f: F) -> Result<R, NanonisError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut NanonisClient) -> Result<R, NanonisError> + Send + 'static,
+    {
+        self.call_with_priority(Priority::Normal, f)
+    }
+
+    /// Run `f` on the next free connection with the given priority.
+    ///
+    /// Blocks until the call has finished and returns its result.
+    pub fn call_with_priority<R, F>(&self, priority: Priority, f: F) -> Result<R, NanonisError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut NanonisClient) -> Result<R, NanonisError> + Send + 'static,
+    {
+        self.submit(priority, false, f)
+    }
+
+    /// Run a long-blocking call, such as waiting for the end of a scan.
+    ///
+    /// With several connections, blocking calls never run on the first
+    /// connection, so short calls keep flowing while they wait. With a single
+    /// connection this is the same as [`call`](Self::call).
+    pub fn call_blocking<R, F>(&self, f: F) -> Result<R, NanonisError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut NanonisClient) -> Result<R, NanonisError> + Send + 'static,
+    {
+        self.submit(Priority::Normal, true, f)
+    }
+
+    fn submit<R, F>(&self, priority: Priority, blocking: bool, f: F) -> Result<R, NanonisError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut NanonisClient) -> Result<R, NanonisError> + Send + 'static,
+    {
+        self.inner.dispatcher.call(priority, blocking, f)
+    }
+
+    /// Send a typed command on the next free connection, on a blocking
+    /// connection if [`Command::blocks`] is set.
+    pub fn send<C>(&self, command: C) -> Result<C::Response, NanonisError>
+    where
+        C: Command + Send + 'static,
+        C::Response: Send + 'static,
+    {
+        let blocking = command.blocks();
+        self.submit(Priority::Normal, blocking, move |client| client.send(&command))
+    }
+
+    /// Wait for the end of the running scan on a blocking connection.
+    ///
+    /// See [`NanonisClient::scan_wait_end_of_scan`].
+    pub fn scan_wait_end_of_scan(&self, timeout: Duration) -> Result<(bool, String), NanonisError> {
+        self.call_blocking(move |client| client.scan_wait_end_of_scan(timeout))
+    }
+
+    /// Start a bias spectroscopy, on a blocking connection if `get_data` is set.
+    ///
+    /// See [`NanonisClient::bias_spectr_start`].
+    pub fn bias_spectr_start(
+        &self,
+        get_data: bool,
+        save_base_name: &str,
+    ) -> Result<BiasSpectrResult, NanonisError> {
+        let save_base_name = save_base_name.to_string();
+        self.submit(Priority::Normal, get_data, move |client| {
+            client.bias_spectr_start(get_data, &save_base_name)
+        })
+    }
+
+    /// Start a high-speed sweep, on a blocking connection if `wait_until_done`
+    /// is set.
+    ///
+    /// See [`NanonisClient::hs_swp_start`].
+    pub fn hs_swp_start(&self, wait_until_done: bool, timeout_ms: i32) -> Result<(), NanonisError> {
+        self.submit(Priority::Normal, wait_until_done, move |client| {
+            client.hs_swp_start(wait_until_done, timeout_ms)
+        })
+    }
+
+    /// Run `policy` with [`Priority::High`] on the next free connection.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the connections have shut down; failures of
+    /// individual actions are reported in the outcomes.
+    pub fn shutdown(&self, policy: &ShutdownPolicy) -> Result<Vec<ShutdownOutcome>, NanonisError> {
+        let policy = policy.clone();
+        self.call_with_priority(Priority::High, move |client| Ok(policy.run(client)))
+    }
+
+    /// Run `policy` when the process is interrupted, then exit.
+    ///
+    /// The policy runs on a dedicated thread through
+    /// [`shutdown`](Self::shutdown), so it waits for calls already running
+    /// on the connections. A second interrupt while the policy runs
+    /// terminates the process at once. Can be installed once per process.
+    /// The handler does not keep the connections open: once the last handle
+    /// is dropped, an interrupt only exits. See
+    /// [`NanonisClient::shutdown_on_signals`] for the interrupts and
+    /// exit statuses on each platform.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the handlers cannot be installed or are
+    /// installed already.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use nanonis_rs::shutdown::{ShutdownAction, ShutdownPolicy};
+    /// use nanonis_rs::SharedNanonisClient;
+    /// use std::time::Duration;
+    ///
+    /// let shared = SharedNanonisClient::connect("127.0.0.1", &[6501, 6502])?;
+    /// shared.shutdown_on_signals(
+    ///     ShutdownPolicy::new()
+    ///         .then(ShutdownAction::StopScan)
+    ///         .then(ShutdownAction::Withdraw(Duration::from_secs(2))),
+    /// )?;
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    #[cfg(any(unix, windows))]
+    pub fn shutdown_on_signals(&self, policy: ShutdownPolicy) -> Result<(), NanonisError> {
+        // A weak handle, so the handler does not keep the connections open
+        let inner = Arc::downgrade(&self.inner);
+        shutdown::on_interrupt(move || {
+            let Some(inner) = inner.upgrade() else {
+                debug!("Interrupted after the shared client was dropped");
+                return;
+            };
+            if let Err(e) = (SharedNanonisClient { inner }).shutdown(&policy) {
+                warn!("Shutdown after interrupt failed: {e}");
+            }
+        })
+    }
+}
@@ -0,0 +1,386 @@
+use crate::error::NanonisError;
+use crate::signals::SignalFrame;
+use crate::tcplogger_stream::{StreamStats, TCPLoggerStream};
+use log::{debug, error, warn};
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// What a [`BackgroundReader`] does when its buffer is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Discard the oldest buffered frame to make room; the consumer always
+    /// sees the most recent data
+    #[default]
+    DropOldest,
+    /// Stop reading until the consumer catches up; frames back up in the
+    /// logger and may overflow there instead
+    Block,
+}
+
+/// Buffer configuration of a [`BackgroundReader`].
+#[derive(Debug, Clone)]
+pub struct ReaderConfig {
+    /// Maximum number of buffered frames
+    pub capacity: usize,
+    /// Behavior when the buffer is full
+    pub backpressure: Backpressure,
+}
+
+impl Default for ReaderConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 4096,
+            backpressure: Backpressure::DropOldest,
+        }
+    }
+}
+
+/// Why a [`BackgroundReader`] thread ended.
+#[derive(Debug)]
+pub enum ExitReason {
+    /// Stopped with [`BackgroundReader::stop`] or by dropping the reader
+    Stopped,
+    /// Reading from the stream failed
+    Failed(NanonisError),
+}
+
+/// Result of a finished [`BackgroundReader`] thread.
+#[derive(Debug)]
+pub struct ReaderExit {
+    /// Why the thread ended
+    pub reason: ExitReason,
+    /// Final counters of the stream
+    pub stream: StreamStats,
+}
+
+/// Counters of a [`BackgroundReader`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReaderStats {
+    /// Frames read from the stream
+    pub received: u64,
+    /// Frames discarded because the buffer was full
+    pub dropped: u64,
+    /// Frames currently buffered
+    pub buffered: usize,
+}
+
+#[derive(Debug, Default)]
+struct Buffer {
+    frames: VecDeque<SignalFrame>,
+    received: u64,
+    dropped: u64,
+    finished: bool,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    buffer: Mutex<Buffer>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    stop: AtomicBool,
+}
+
+/// Thread reading a [`TCPLoggerStream`] into a bounded ring buffer.
+///
+/// Created by [`TCPLoggerStream::spawn_reader`]. The buffer holds at most
+/// [`ReaderConfig::capacity`] frames; when the consumer falls behind, the
+/// [`Backpressure`] policy decides whether old frames are dropped or
+/// reading pauses. Dropped frames are counted in [`stats`](Self::stats).
+///
+/// Read timeouts are not errors: the thread checks for a stop request and
+/// keeps reading. Any other error ends the thread; buffered frames can
+/// still be received, and [`join`](Self::join) reports the error.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{Backpressure, ExitReason, MemoryTransport, ReaderConfig, TCPLoggerStream};
+/// use std::io::Write;
+///
+/// let (stream_end, mut logger_end) = MemoryTransport::pair();
+/// for counter in 0..5u64 {
+///     let mut frame = Vec::new();
+///     frame.extend_from_slice(&1u32.to_be_bytes());
+///     frame.extend_from_slice(&1f32.to_be_bytes());
+///     frame.extend_from_slice(&counter.to_be_bytes());
+///     frame.extend_from_slice(&4u16.to_be_bytes());
+///     frame.extend_from_slice(&0.5f32.to_be_bytes());
+///     logger_end.write_all(&frame)?;
+/// }
+/// drop(logger_end);
+///
+/// let reader = TCPLoggerStream::from_transport(stream_end).spawn_reader(ReaderConfig {
+///     capacity: 2,
+///     backpressure: Backpressure::DropOldest,
+/// });
+/// while !reader.is_finished() {
+///     std::thread::sleep(std::time::Duration::from_millis(1));
+/// }
+///
+/// // Only the newest frames are kept
+/// let counters: Vec<u64> = reader.iter().map(|frame| frame.counter).collect();
+/// assert_eq!(counters, vec![3, 4]);
+/// assert_eq!(reader.stats().dropped, 3);
+///
+/// // The logger closed the connection
+/// let exit = reader.join();
+/// assert!(matches!(exit.reason, ExitReason::Failed(_)));
+/// assert_eq!(exit.stream.frames, 5);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct BackgroundReader {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<ReaderExit>>,
+}
+
+impl TCPLoggerStream {
+    /// Read frames on a background thread into a bounded ring buffer.
+    ///
+    /// See [`BackgroundReader`].
+    pub fn spawn_reader(self, config: ReaderConfig) -> BackgroundReader {
+        let shared = Arc::new(Shared::default());
+        let thread = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || read_loop(self, config, shared))
+        };
+
+        BackgroundReader {
+            shared,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl BackgroundReader {
+    /// Block until a frame is available.
+    ///
+    /// Returns `None` once the thread has ended and the buffer is empty.
+    pub fn recv(&self) -> Option<SignalFrame> {
+        let mut buffer = lock(&self.shared.buffer);
+        loop {
+            if let Some(frame) = self.pop(&mut buffer) {
+                return Some(frame);
+            }
+            if buffer.finished {
+                return None;
+            }
+            buffer = self
+                .shared
+                .not_empty
+                .wait(buffer)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    /// Wait up to `timeout` for a frame.
+    ///
+    /// # Errors
+    /// Returns [`RecvTimeoutError::Timeout`] if no frame arrived in time and
+    /// [`RecvTimeoutError::Disconnected`] once the thread has ended and the
+    /// buffer is empty.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<SignalFrame, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut buffer = lock(&self.shared.buffer);
+        loop {
+            if let Some(frame) = self.pop(&mut buffer) {
+                return Ok(frame);
+            }
+            if buffer.finished {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            buffer = self
+                .shared
+                .not_empty
+                .wait_timeout(buffer, remaining)
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .0;
+        }
+    }
+
+    /// Take a buffered frame without waiting.
+    pub fn try_recv(&self) -> Option<SignalFrame> {
+        self.pop(&mut lock(&self.shared.buffer))
+    }
+
+    /// Iterator blocking on [`recv`](Self::recv); ends when the thread has
+    /// ended and the buffer is empty.
+    pub fn iter(&self) -> impl Iterator<Item = SignalFrame> + '_ {
+        std::iter::from_fn(|| self.recv())
+    }
+
+    /// Current counters.
+    pub fn stats(&self) -> ReaderStats {
+        let buffer = lock(&self.shared.buffer);
+        ReaderStats {
+            received: buffer.received,
+            dropped: buffer.dropped,
+            buffered: buffer.frames.len(),
+        }
+    }
+
+    /// Whether the thread has ended.
+    pub fn is_finished(&self) -> bool {
+        lock(&self.shared.buffer).finished
+    }
+
+    /// Ask the thread to stop and wait for it.
+    ///
+    /// A read in progress is not interrupted, so this returns once the next
+    /// frame arrives or the stream's read timeout expires.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{Backpressure, ExitReason, MemoryTransport, ReaderConfig, TCPLoggerStream};
+    /// use std::io::Write;
+    /// use std::time::Duration;
+    ///
+    /// let (mut stream_end, mut logger_end) = MemoryTransport::pair();
+    /// stream_end.set_read_timeout(Some(Duration::from_millis(20)));
+    /// for counter in 0..3u64 {
+    ///     let mut frame = Vec::new();
+    ///     frame.extend_from_slice(&1u32.to_be_bytes());
+    ///     frame.extend_from_slice(&1f32.to_be_bytes());
+    ///     frame.extend_from_slice(&counter.to_be_bytes());
+    ///     frame.extend_from_slice(&4u16.to_be_bytes());
+    ///     frame.extend_from_slice(&0.5f32.to_be_bytes());
+    ///     logger_end.write_all(&frame)?;
+    /// }
+    ///
+    /// // The reader waits for room instead of dropping frames
+    /// let reader = TCPLoggerStream::from_transport(stream_end).spawn_reader(ReaderConfig {
+    ///     capacity: 1,
+    ///     backpressure: Backpressure::Block,
+    /// });
+    /// assert_eq!(reader.recv_timeout(Duration::from_secs(5))?.counter, 0);
+    /// assert_eq!(reader.recv_timeout(Duration::from_secs(5))?.counter, 1);
+    ///
+    /// let exit = reader.stop();
+    /// assert!(matches!(exit.reason, ExitReason::Stopped));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stop(mut self) -> ReaderExit {
+        self.request_stop();
+        self.wait()
+    }
+
+    /// Wait for the thread to end without asking it to stop.
+    pub fn join(mut self) -> ReaderExit {
+        self.wait()
+    }
+
+    fn pop(&self, buffer: &mut Buffer) -> Option<SignalFrame> {
+        let frame = buffer.frames.pop_front()?;
+        self.shared.not_full.notify_one();
+        Some(frame)
+    }
+
+    fn request_stop(&self) {
+        self.shared.stop.store(true, Ordering::SeqCst);
+        // Take the lock so a blocked writer cannot miss the notification
+        let _buffer = lock(&self.shared.buffer);
+        self.shared.not_full.notify_all();
+    }
+
+    fn wait(&mut self) -> ReaderExit {
+        let thread = self.thread.take().expect("reader thread is joined once");
+        thread.join().unwrap_or_else(|_| ReaderExit {
+            reason: ExitReason::Failed(NanonisError::Protocol(
+                "TCP Logger reader thread panicked".to_string(),
+            )),
+            stream: StreamStats::default(),
+        })
+    }
+}
+
+/// Stops the thread without waiting for it; it ends after the current read.
+impl Drop for BackgroundReader {
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            self.request_stop();
+        }
+    }
+}
+
+fn read_loop(mut stream: TCPLoggerStream, config: ReaderConfig, shared: Arc<Shared>) -> ReaderExit {
+    let capacity = config.capacity.max(1);
+    let reason = loop {
+        if shared.stop.load(Ordering::SeqCst) {
+            break ExitReason::Stopped;
+        }
+
+        let frame = match stream.read_frame() {
+            Ok(frame) => frame,
+            Err(e) if is_timeout(&e) => continue,
+            Err(e) => {
+                error!("TCP Logger reader stopped: {e}");
+                break ExitReason::Failed(e);
+            }
+        };
+
+        let mut buffer = lock(&shared.buffer);
+        if buffer.frames.len() >= capacity {
+            match config.backpressure {
+                Backpressure::DropOldest => {
+                    buffer.frames.pop_front();
+                    buffer.dropped += 1;
+                    // Log at 1, 2, 4, ... dropped frames to avoid flooding
+                    if buffer.dropped.is_power_of_two() {
+                        warn!(
+                            "TCP Logger reader buffer full, {} frames dropped",
+                            buffer.dropped
+                        );
+                    }
+                }
+                Backpressure::Block => {
+                    while buffer.frames.len() >= capacity && !shared.stop.load(Ordering::SeqCst) {
+                        buffer = shared
+                            .not_full
+                            .wait(buffer)
+                            .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    }
+                    if shared.stop.load(Ordering::SeqCst) {
+                        break ExitReason::Stopped;
+                    }
+                }
+            }
+        }
+        buffer.frames.push_back(frame);
+        buffer.received += 1;
+        shared.not_empty.notify_one();
+    };
+
+    debug!("TCP Logger reader exiting: {reason:?}");
+    lock(&shared.buffer).finished = true;
+    shared.not_empty.notify_all();
+
+    ReaderExit {
+        reason,
+        stream: stream.stats(),
+    }
+}
+
+/// Whether a read failed only because no data arrived in time.
+fn is_timeout(error: &NanonisError) -> bool {
+    match error {
+        NanonisError::Io { source, .. } => {
+            matches!(source.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock)
+        }
+        NanonisError::Timeout(_) => true,
+        _ => false,
+    }
+}
+
+/// Lock a mutex, recovering the data if a thread panicked while holding it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -1,17 +1,110 @@
+use crate::client::{is_connection_error, ConnectionConfig, ReconnectPolicy};
 use crate::error::NanonisError;
 use crate::signals::SignalFrame;
 use crate::tcplog::TCPLogStatus;
+use crate::tcplogger_reader::ReaderConfig;
+use crate::transport::{TcpTransport, Transport};
+use crate::units::Hertz;
 use byteorder::{BigEndian, ReadBytesExt};
-use std::io::{Cursor, Read};
-use std::net::{SocketAddr, TcpStream};
+use log::{debug, warn};
+use std::collections::VecDeque;
+use std::io::{Cursor, ErrorKind, Read};
+use std::net::SocketAddr;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
+/// Event reported by [`TCPLoggerStream::next_event`].
+#[derive(Debug, Clone)]
+pub enum StreamEvent {
+    /// A data frame
+    Frame(SignalFrame),
+    /// The frame counter jumped, frames were lost before `received`
+    Gap {
+        /// Counter the next frame should have had
+        expected: u64,
+        /// Counter of the frame actually received
+        received: u64,
+        /// Number of lost frames
+        missing: u64,
+    },
+    /// The logger reported a buffer overflow; samples were discarded on the
+    /// instrument
+    Overflow {
+        /// Counter of the first frame sent in the overflow state
+        counter: u64,
+    },
+    /// The logger state differs from the previous frame
+    StateChanged {
+        /// State of the previous frame
+        from: TCPLogStatus,
+        /// State of the current frame
+        to: TCPLogStatus,
+    },
+    /// The frame counter went backwards, usually because the logger was
+    /// restarted
+    CounterReset {
+        /// Counter of the previous frame
+        previous: u64,
+        /// Counter of the current frame
+        received: u64,
+    },
+    /// The connection dropped; the next call reconnects
+    Disconnected {
+        /// Error that ended the connection
+        error: String,
+    },
+    /// The connection was re-established
+    Reconnected {
+        /// Number of connection attempts needed
+        attempts: u32,
+    },
+    /// The stream lost frame alignment; bytes were discarded until two
+    /// consecutive valid frame headers were found
+    Resynchronized {
+        /// Number of bytes discarded
+        skipped: usize,
+    },
+}
+
+/// Counters accumulated by a [`TCPLoggerStream`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamStats {
+    /// Frames received
+    pub frames: u64,
+    /// Frames lost according to counter gaps
+    pub missing: u64,
+    /// Buffer overflows reported by the logger
+    pub overflows: u64,
+    /// Counter resets
+    pub resets: u64,
+    /// Successful reconnects
+    pub reconnects: u64,
+    /// Times the stream lost frame alignment and resynchronised
+    pub resyncs: u64,
+}
+
 /// Simple TCP Logger Stream - connects to data stream only, no control
+///
+/// Besides the frames themselves, the stream checks the frame counter and
+/// the logger state of every frame. Dropped frames, buffer overflows, state
+/// transitions and connection losses are reported as [`StreamEvent`]s by
+/// [`next_event`](Self::next_event), logged, and counted in
+/// [`stats`](Self::stats).
 pub struct TCPLoggerStream {
-    stream: TcpStream,
+    stream: Box<dyn Transport>,
     buffer: Vec<u8>,
+    /// Bytes received but not consumed yet, starting at a frame boundary
+    /// unless the stream is out of sync
+    filled: usize,
+    /// Bytes discarded while searching for the next frame header
+    skipped: usize,
+    rt_frequency: Option<Hertz>,
+    reconnect_policy: Option<ReconnectPolicy>,
+    last: Option<(u64, TCPLogStatus)>,
+    pending: VecDeque<StreamEvent>,
+    disconnected: bool,
+    stats: StreamStats,
 }
 
 impl TCPLoggerStream {
@@ -31,38 +124,162 @@ impl TCPLoggerStream {
             .parse()
             .map_err(|_| NanonisError::Protocol(format!("Invalid address: {addr}")))?;
 
-        let stream = TcpStream::connect(socket_addr).map_err(|e| NanonisError::Io {
-            source: e,
-            context: format!("Failed to connect to TCP stream at {}", socket_addr),
-        })?;
+        Self::connect(socket_addr)
+    }
 
-        // Set read timeout for continuous reading
-        stream
-            .set_read_timeout(Some(Duration::from_secs(30)))
-            .map_err(|e| NanonisError::Io {
-                source: e,
-                context: "Setting TCP stream read timeout".to_string(),
-            })?;
+    /// Connect to the TCP Logger data stream at a socket address.
+    pub fn connect(addr: SocketAddr) -> Result<Self, NanonisError> {
+        // Long read timeout for continuous reading
+        let config = ConnectionConfig {
+            read_timeout: Duration::from_secs(30),
+            ..ConnectionConfig::default()
+        };
+        let transport = TcpTransport::connect(addr, &config)?;
 
-        Ok(Self {
-            stream,
+        Ok(Self::from_transport(transport))
+    }
+
+    /// Read frames from a custom [`Transport`] instead of a TCP socket.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{MemoryTransport, TCPLoggerStream};
+    /// use nanonis_rs::tcplog::TCPLogStatus;
+    /// use std::io::Write;
+    ///
+    /// let (stream_end, mut logger_end) = MemoryTransport::pair();
+    ///
+    /// let mut frame = Vec::new();
+    /// frame.extend_from_slice(&1u32.to_be_bytes()); // channels
+    /// frame.extend_from_slice(&1f32.to_be_bytes()); // oversampling
+    /// frame.extend_from_slice(&7u64.to_be_bytes()); // counter
+    /// frame.extend_from_slice(&4u16.to_be_bytes()); // state
+    /// frame.extend_from_slice(&0.5f32.to_be_bytes());
+    /// logger_end.write_all(&frame)?;
+    ///
+    /// let mut stream = TCPLoggerStream::from_transport(stream_end);
+    /// let frame = stream.read_frame()?;
+    /// assert_eq!(frame.counter, 7);
+    /// assert_eq!(frame.state, TCPLogStatus::Running);
+    /// assert_eq!(frame.data, vec![0.5]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_transport(transport: impl Transport + 'static) -> Self {
+        Self {
+            stream: Box::new(transport),
             buffer: Vec::with_capacity(1024),
-        })
+            filled: 0,
+            skipped: 0,
+            rt_frequency: None,
+            reconnect_policy: None,
+            last: None,
+            pending: VecDeque::new(),
+            disconnected: false,
+            stats: StreamStats::default(),
+        }
+    }
+
+    /// Stamp frames with the time since the logger started.
+    ///
+    /// `rt_frequency` is the real-time frequency of the controller, as
+    /// returned by [`util_rt_freq_get`](crate::NanonisClient::util_rt_freq_get).
+    /// Frame `n` is stamped with `n * oversampling / rt_frequency`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use nanonis_rs::{Hertz, NanonisClient, TCPLoggerStream};
+    ///
+    /// let mut client = NanonisClient::new("127.0.0.1", 6501)?;
+    /// let rt_frequency = Hertz::new(client.util_rt_freq_get()?.into());
+    /// let mut stream = TCPLoggerStream::new("127.0.0.1", 6590)?.with_rt_frequency(rt_frequency);
+    ///
+    /// let frame = stream.read_frame()?;
+    /// println!("{:?}: {:?}", frame.timestamp, frame.data);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_rt_frequency(mut self, rt_frequency: impl Into<Hertz>) -> Self {
+        self.rt_frequency = Some(rt_frequency.into());
+        self
+    }
+
+    /// Reconnect after the connection dropped.
+    ///
+    /// Without a policy a lost connection fails the read. With a policy the
+    /// stream reports [`StreamEvent::Disconnected`], re-opens the transport
+    /// with the policy's retries and backoff, and reports
+    /// [`StreamEvent::Reconnected`]. Frames lost in between show up as a
+    /// [`StreamEvent::Gap`] once the logger's counter is seen again.
+    ///
+    /// The transport has to support [`Transport::reconnect`], as the one
+    /// opened by [`new`](Self::new) does.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{ReconnectPolicy, StreamEvent, TCPLoggerStream};
+    /// # use std::io::Write;
+    /// # use std::net::TcpListener;
+    ///
+    /// # let listener = TcpListener::bind("127.0.0.1:0")?;
+    /// # let port = listener.local_addr()?.port();
+    /// # let logger = std::thread::spawn(move || {
+    /// #     // Frames 0 and 1, drop the connection, then frame 5
+    /// #     for counters in [&[0u64, 1][..], &[5]] {
+    /// #         let (mut socket, _) = listener.accept().unwrap();
+    /// #         for &counter in counters {
+    /// #             let mut frame = Vec::new();
+    /// #             frame.extend_from_slice(&1u32.to_be_bytes());
+    /// #             frame.extend_from_slice(&1f32.to_be_bytes());
+    /// #             frame.extend_from_slice(&counter.to_be_bytes());
+    /// #             frame.extend_from_slice(&4u16.to_be_bytes());
+    /// #             frame.extend_from_slice(&0.5f32.to_be_bytes());
+    /// #             socket.write_all(&frame).unwrap();
+    /// #         }
+    /// #     }
+    /// # });
+    /// let mut stream = TCPLoggerStream::new("127.0.0.1", port)?
+    ///     .with_reconnect(ReconnectPolicy::default());
+    ///
+    /// assert_eq!(stream.read_frame()?.counter, 0);
+    /// assert_eq!(stream.read_frame()?.counter, 1);
+    /// assert!(matches!(stream.next_event()?, StreamEvent::Disconnected { .. }));
+    /// assert!(matches!(stream.next_event()?, StreamEvent::Reconnected { attempts: 1 }));
+    /// assert!(matches!(stream.next_event()?, StreamEvent::Gap { missing: 3, .. }));
+    /// assert_eq!(stream.read_frame()?.counter, 5);
+    /// assert_eq!(stream.stats().reconnects, 1);
+    /// # logger.join().unwrap();
+    /// # Ok::<(), nanonis_rs::NanonisError>(())
+    /// ```
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = Some(policy);
+        self
+    }
+
+    /// Counters accumulated since the stream was opened.
+    pub fn stats(&self) -> StreamStats {
+        self.stats
     }
 
     /// Spawn background reader thread
     ///
-    /// Creates a background thread that continuously reads TCP Logger data frames
-    /// and sends them through a channel. The thread automatically exits when the
-    /// receiver is dropped.
+    /// Reads frames with a [`BackgroundReader`](crate::BackgroundReader) and
+    /// forwards them through a channel holding at most
+    /// [`ReaderConfig::capacity`] frames. When the consumer falls behind,
+    /// the reader drops the oldest frames. Reading stops when the receiver
+    /// is dropped or a read fails; the error is only logged.
     ///
     /// # Returns
     /// A receiver channel for `TCPLoggerData` frames.
-    pub fn spawn_background_reader(mut self) -> mpsc::Receiver<SignalFrame> {
-        let (sender, receiver) = mpsc::channel();
+    #[deprecated(
+        note = "use `spawn_reader`, whose `BackgroundReader` can be stopped and joined and reports why it ended"
+    )]
+    pub fn spawn_background_reader(self) -> mpsc::Receiver<SignalFrame> {
+        let config = ReaderConfig::default();
+        let (sender, receiver) = mpsc::sync_channel(config.capacity);
+        let reader = self.spawn_reader(config);
 
+        // Dropping the reader at the end stops its thread
         thread::spawn(move || {
-            while let Ok(frame) = self.read_frame() {
+            for frame in reader.iter() {
                 if sender.send(frame).is_err() {
                     break;
                 }
@@ -74,50 +291,432 @@ impl TCPLoggerStream {
 
     /// Read a single data frame from the stream
     ///
+    /// Other [`StreamEvent`]s are skipped; they are still logged and
+    /// counted in [`stats`](Self::stats).
+    ///
     /// # Returns
     /// `TCPLoggerData` containing the frame header and signal data.
     ///
     /// # Frame Format
     /// Always reads 18 bytes header first, then reads data based on num_channels.
+    ///
+    /// A read timeout in the middle of a frame returns the timeout error
+    /// and keeps the bytes received so far; the next call completes the
+    /// frame.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{MemoryTransport, TCPLoggerStream};
+    /// use std::io::Write;
+    /// use std::time::Duration;
+    ///
+    /// let (mut stream_end, mut logger_end) = MemoryTransport::pair();
+    /// stream_end.set_read_timeout(Some(Duration::from_millis(20)));
+    ///
+    /// let mut frame = Vec::new();
+    /// frame.extend_from_slice(&1u32.to_be_bytes());
+    /// frame.extend_from_slice(&1f32.to_be_bytes());
+    /// frame.extend_from_slice(&3u64.to_be_bytes());
+    /// frame.extend_from_slice(&4u16.to_be_bytes());
+    /// frame.extend_from_slice(&0.5f32.to_be_bytes());
+    ///
+    /// let mut stream = TCPLoggerStream::from_transport(stream_end);
+    /// logger_end.write_all(&frame[..10])?;
+    /// assert!(stream.read_frame().is_err());
+    /// logger_end.write_all(&frame[10..20])?;
+    /// assert!(stream.read_frame().is_err());
+    /// logger_end.write_all(&frame[20..])?;
+    /// assert_eq!(stream.read_frame()?.counter, 3);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn read_frame(&mut self) -> Result<SignalFrame, NanonisError> {
-        // First read header to determine frame size
-        let header_size = 18;
-        self.buffer.resize(header_size, 0);
-
-        // Read header into buffer
-        self.stream
-            .read_exact(&mut self.buffer[..header_size])
-            .map_err(|e| NanonisError::Io {
+        loop {
+            if let StreamEvent::Frame(frame) = self.next_event()? {
+                return Ok(frame);
+            }
+        }
+    }
+
+    /// Read the next event from the stream.
+    ///
+    /// Events concerning a frame, such as a [`Gap`](StreamEvent::Gap) or a
+    /// [`StateChanged`](StreamEvent::StateChanged), come right before the
+    /// frame itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use nanonis_rs::{Hertz, MemoryTransport, StreamEvent, TCPLoggerStream};
+    /// use nanonis_rs::tcplog::TCPLogStatus;
+    /// use std::io::Write;
+    ///
+    /// let (stream_end, mut logger_end) = MemoryTransport::pair();
+    /// for (counter, state) in [(0u64, 4u16), (1, 4), (4, 7)] {
+    ///     let mut frame = Vec::new();
+    ///     frame.extend_from_slice(&1u32.to_be_bytes());
+    ///     frame.extend_from_slice(&10f32.to_be_bytes());
+    ///     frame.extend_from_slice(&counter.to_be_bytes());
+    ///     frame.extend_from_slice(&state.to_be_bytes());
+    ///     frame.extend_from_slice(&0.5f32.to_be_bytes());
+    ///     logger_end.write_all(&frame)?;
+    /// }
+    ///
+    /// let mut stream = TCPLoggerStream::from_transport(stream_end).with_rt_frequency(Hertz::kilohertz(10.0));
+    /// let mut events = Vec::new();
+    /// while events.len() < 6 {
+    ///     events.push(stream.next_event()?);
+    /// }
+    ///
+    /// assert!(matches!(events[2], StreamEvent::Gap { expected: 2, received: 4, missing: 2 }));
+    /// assert!(matches!(
+    ///     events[3],
+    ///     StreamEvent::StateChanged { from: TCPLogStatus::Running, to: TCPLogStatus::BufferOverflow }
+    /// ));
+    /// assert!(matches!(events[4], StreamEvent::Overflow { counter: 4 }));
+    /// let StreamEvent::Frame(frame) = &events[5] else { panic!() };
+    /// assert_eq!(frame.timestamp, Some(std::time::Duration::from_millis(4)));
+    /// assert_eq!(stream.stats().missing, 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// Bytes that do not form a valid frame header are skipped until the
+    /// stream is aligned again:
+    /// ```
+    /// use nanonis_rs::{MemoryTransport, StreamEvent, TCPLoggerStream};
+    /// use std::io::Write;
+    ///
+    /// let (stream_end, mut logger_end) = MemoryTransport::pair();
+    /// logger_end.write_all(&[0xff; 5])?; // tail of a torn frame
+    /// for counter in [7u64, 8] {
+    ///     logger_end.write_all(&1u32.to_be_bytes())?;
+    ///     logger_end.write_all(&1f32.to_be_bytes())?;
+    ///     logger_end.write_all(&counter.to_be_bytes())?;
+    ///     logger_end.write_all(&4u16.to_be_bytes())?;
+    ///     logger_end.write_all(&0.5f32.to_be_bytes())?;
+    /// }
+    ///
+    /// // A frame is accepted once the next header confirms the alignment
+    /// let mut stream = TCPLoggerStream::from_transport(stream_end);
+    /// assert!(matches!(stream.next_event()?, StreamEvent::Resynchronized { skipped: 5 }));
+    /// let StreamEvent::Frame(frame) = stream.next_event()? else { panic!() };
+    /// assert_eq!((frame.counter, frame.data), (7, vec![0.5]));
+    /// assert_eq!(stream.read_frame()?.counter, 8);
+    /// assert_eq!(stream.stats().resyncs, 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn next_event(&mut self) -> Result<StreamEvent, NanonisError> {
+        if let Some(event) = self.pending.pop_front() {
+            return Ok(event);
+        }
+
+        if self.disconnected {
+            let attempts = self.reconnect()?;
+            self.disconnected = false;
+            self.stats.reconnects += 1;
+            return Ok(StreamEvent::Reconnected { attempts });
+        }
+
+        let frame = match self.read_raw_frame() {
+            Ok(frame) => frame,
+            Err(e) if self.reconnect_policy.is_some() && is_connection_error(&e) => {
+                warn!("TCP Logger stream lost: {e}");
+                // The new connection starts at a frame boundary
+                self.filled = 0;
+                self.skipped = 0;
+                self.disconnected = true;
+                return Ok(StreamEvent::Disconnected {
+                    error: e.to_string(),
+                });
+            }
+            Err(e) => return Err(e),
+        };
+
+        let skipped = std::mem::take(&mut self.skipped);
+        if skipped > 0 {
+            warn!("TCP Logger stream resynchronised after skipping {skipped} bytes");
+            self.stats.resyncs += 1;
+            self.pending
+                .push_back(StreamEvent::Resynchronized { skipped });
+        }
+
+        self.track(&frame);
+        self.pending.push_back(StreamEvent::Frame(frame));
+        self.next_event()
+    }
+
+    /// Compare a frame against the previous one and queue the resulting
+    /// events.
+    fn track(&mut self, frame: &SignalFrame) {
+        self.stats.frames += 1;
+
+        if let Some((previous, state)) = self.last {
+            let expected = previous.wrapping_add(1);
+            if frame.counter > expected {
+                let missing = frame.counter - expected;
+                warn!(
+                    "TCP Logger lost {missing} frames before frame {}",
+                    frame.counter
+                );
+                self.stats.missing += missing;
+                self.pending.push_back(StreamEvent::Gap {
+                    expected,
+                    received: frame.counter,
+                    missing,
+                });
+            } else if frame.counter < expected {
+                warn!(
+                    "TCP Logger counter reset from {previous} to {}",
+                    frame.counter
+                );
+                self.stats.resets += 1;
+                self.pending.push_back(StreamEvent::CounterReset {
+                    previous,
+                    received: frame.counter,
+                });
+            }
+
+            if frame.state != state {
+                debug!("TCP Logger state {state:?} -> {:?}", frame.state);
+                self.pending.push_back(StreamEvent::StateChanged {
+                    from: state,
+                    to: frame.state,
+                });
+            }
+        }
+
+        let entered_overflow = frame.state == TCPLogStatus::BufferOverflow
+            && self
+                .last
+                .is_none_or(|(_, state)| state != TCPLogStatus::BufferOverflow);
+        if entered_overflow {
+            warn!("TCP Logger buffer overflow at frame {}", frame.counter);
+            self.stats.overflows += 1;
+            self.pending.push_back(StreamEvent::Overflow {
+                counter: frame.counter,
+            });
+        }
+
+        self.last = Some((frame.counter, frame.state));
+    }
+
+    /// Re-open the transport according to the reconnect policy, returning
+    /// the number of attempts.
+    fn reconnect(&mut self) -> Result<u32, NanonisError> {
+        let Some(policy) = self.reconnect_policy.clone() else {
+            return Err(NanonisError::Protocol(
+                "TCP Logger stream lost and no reconnect policy configured".to_string(),
+            ));
+        };
+
+        let mut delay = policy.backoff;
+        let mut last_error = None;
+        for attempt in 1..=policy.max_retries.max(1) {
+            thread::sleep(delay);
+            debug!("Reconnecting TCP Logger stream (attempt {attempt})");
+
+            match self.stream.reconnect(policy.connect_timeout) {
+                Ok(()) => {
+                    debug!("Reconnected TCP Logger stream");
+                    return Ok(attempt);
+                }
+                Err(e) => {
+                    warn!("TCP Logger reconnect attempt {attempt} failed: {e}");
+                    last_error = Some(e);
+                }
+            }
+            delay *= 2;
+        }
+
+        Err(last_error.map_or_else(
+            || NanonisError::Protocol("Failed to reconnect TCP Logger stream".to_string()),
+            |e| NanonisError::Io {
                 source: e,
-                context: "Reading TCP Logger frame header".to_string(),
-            })?;
+                context: "Reconnecting TCP Logger stream".to_string(),
+            },
+        ))
+    }
+
+    /// Read one frame from the transport.
+    ///
+    /// A read that times out partway through a frame keeps the bytes
+    /// received so far; the next call continues the same frame, so the
+    /// stream stays aligned to frame boundaries. If the stream is out of
+    /// sync anyway, the window slides forward one byte at a time until it
+    /// holds a valid header that is followed by another valid header.
+    fn read_raw_frame(&mut self) -> Result<SignalFrame, NanonisError> {
+        loop {
+            match scan_frame(&self.buffer[..self.filled], self.skipped > 0) {
+                Scan::Need(size) => {
+                    let context = if self.filled < FRAME_HEADER_SIZE {
+                        "Reading TCP Logger frame header"
+                    } else {
+                        "Reading TCP Logger frame data"
+                    };
+                    self.fill(size, context)?;
+                }
+                Scan::Invalid(e) => {
+                    if self.skipped == 0 {
+                        warn!("TCP Logger stream out of sync: {e}");
+                    }
+                    self.skipped += 1;
+                    self.buffer.copy_within(1..self.filled, 0);
+                    self.filled -= 1;
+                }
+                Scan::Frame(header, size) => {
+                    let data = header.parse_data(&self.buffer[FRAME_HEADER_SIZE..size])?;
+                    // Keep a following header read to confirm this frame
+                    self.buffer.copy_within(size..self.filled, 0);
+                    self.filled -= size;
+                    return Ok(header.into_frame(data, self.rt_frequency));
+                }
+            }
+        }
+    }
 
-        // Parse header from buffer
-        let mut cursor = Cursor::new(&self.buffer[..header_size]);
+    /// Read until the first `size` bytes of `buffer` are filled, keeping
+    /// the progress in `filled` across failed reads.
+    fn fill(&mut self, size: usize, context: &str) -> Result<(), NanonisError> {
+        if self.buffer.len() < size {
+            self.buffer.resize(size, 0);
+        }
+        while self.filled < size {
+            match self.stream.read(&mut self.buffer[self.filled..size]) {
+                Ok(0) => {
+                    return Err(NanonisError::Io {
+                        source: ErrorKind::UnexpectedEof.into(),
+                        context: context.to_string(),
+                    })
+                }
+                Ok(n) => self.filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    return Err(NanonisError::Io {
+                        source: e,
+                        context: context.to_string(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Size of the header preceding every TCP Logger data frame
+pub(crate) const FRAME_HEADER_SIZE: usize = 18;
+
+/// Number of channels the TCP Logger can record
+pub(crate) const MAX_CHANNELS: u32 = 24;
+
+/// What the start of the received bytes holds, see [`scan_frame`].
+pub(crate) enum Scan {
+    /// At least this many bytes are needed to decide
+    Need(usize),
+    /// No frame starts here; the stream is out of sync
+    Invalid(NanonisError),
+    /// A frame of the given total size starts here
+    Frame(FrameHeader, usize),
+}
+
+/// Check whether `bytes` start with a complete frame.
+///
+/// With `confirm`, used while resynchronising, a frame only counts once the
+/// header of the following frame parses as well, so a header-like run of
+/// data bytes is not mistaken for a frame.
+pub(crate) fn scan_frame(bytes: &[u8], confirm: bool) -> Scan {
+    let Some(header_bytes) = bytes.get(..FRAME_HEADER_SIZE) else {
+        return Scan::Need(FRAME_HEADER_SIZE);
+    };
+    let header = match FrameHeader::parse(header_bytes) {
+        Ok(header) => header,
+        Err(e) => return Scan::Invalid(e),
+    };
+
+    let size = FRAME_HEADER_SIZE + header.data_size();
+    if !confirm {
+        return if bytes.len() < size {
+            Scan::Need(size)
+        } else {
+            Scan::Frame(header, size)
+        };
+    }
+    match bytes.get(size..size + FRAME_HEADER_SIZE) {
+        None => Scan::Need(size + FRAME_HEADER_SIZE),
+        Some(next) => match FrameHeader::parse(next) {
+            Ok(_) => Scan::Frame(header, size),
+            Err(e) => Scan::Invalid(e),
+        },
+    }
+}
+
+/// Header of a TCP Logger data frame
+pub(crate) struct FrameHeader {
+    pub num_channels: u32,
+    pub oversampling: f32,
+    pub counter: u64,
+    pub state: TCPLogStatus,
+}
+
+impl FrameHeader {
+    /// Parse the frame header from exactly [`FRAME_HEADER_SIZE`] bytes.
+    ///
+    /// A channel count above [`MAX_CHANNELS`], an oversampling that is not
+    /// a positive number or an unknown state means the bytes are not a
+    /// frame header, i.e. the stream is out of sync.
+    pub fn parse(bytes: &[u8]) -> Result<Self, NanonisError> {
+        let mut cursor = Cursor::new(bytes);
         let num_channels = cursor.read_u32::<BigEndian>()?;
-        let _oversampling = cursor.read_f32::<BigEndian>()?;
+        if num_channels > MAX_CHANNELS {
+            return Err(NanonisError::Protocol(format!(
+                "TCP Logger frame header claims {num_channels} channels, at most {MAX_CHANNELS} possible"
+            )));
+        }
+        let oversampling = cursor.read_f32::<BigEndian>()?;
+        if !(oversampling.is_finite() && oversampling > 0.0) {
+            return Err(NanonisError::Protocol(format!(
+                "TCP Logger frame header has invalid oversampling {oversampling}"
+            )));
+        }
         let counter = cursor.read_u64::<BigEndian>()?;
         let state_val = cursor.read_u16::<BigEndian>()?;
-        let _state = TCPLogStatus::try_from(state_val as i32)?;
+        let state = TCPLogStatus::try_from(state_val as i32)?;
 
-        // Calculate total frame size and read data portion
-        let data_size = (num_channels * 4) as usize; // 4 bytes per f32
-        self.buffer.resize(data_size, 0);
+        Ok(Self {
+            num_channels,
+            oversampling,
+            counter,
+            state,
+        })
+    }
 
-        self.stream
-            .read_exact(&mut self.buffer[..data_size])
-            .map_err(|e| NanonisError::Io {
-                source: e,
-                context: "Reading TCP Logger frame data".to_string(),
-            })?;
+    /// Size of the data portion following the header.
+    pub fn data_size(&self) -> usize {
+        self.num_channels as usize * 4 // 4 bytes per f32
+    }
 
-        // Parse data values from buffer
-        let mut cursor = Cursor::new(&self.buffer[..data_size]);
-        let mut data = Vec::with_capacity(num_channels as usize);
-        for _ in 0..num_channels {
+    /// Parse the data portion of the frame.
+    pub fn parse_data(&self, bytes: &[u8]) -> Result<Vec<f32>, NanonisError> {
+        let mut cursor = Cursor::new(bytes);
+        let mut data = Vec::with_capacity(self.num_channels as usize);
+        for _ in 0..self.num_channels {
             data.push(cursor.read_f32::<BigEndian>()?);
         }
+        Ok(data)
+    }
 
-        Ok(SignalFrame { counter, data })
+    /// Combine the header with the parsed data; the timestamp is derived
+    /// from the counter if the RT frequency is known and the oversampling
+    /// gives a valid duration.
+    pub fn into_frame(self, data: Vec<f32>, rt_frequency: Option<Hertz>) -> SignalFrame {
+        let timestamp = rt_frequency.filter(|rt| rt.0 > 0.0).and_then(|rt| {
+            Duration::try_from_secs_f64(self.counter as f64 * self.oversampling as f64 / rt.0)
+                .ok()
+        });
+
+        SignalFrame {
+            counter: self.counter,
+            data,
+            oversampling: self.oversampling,
+            state: self.state,
+            timestamp,
+        }
     }
 }
@@ -0,0 +1,285 @@
+use crate::client::ConnectionConfig;
+use crate::error::NanonisError;
+use log::{debug, warn};
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+/// Byte channel carrying Nanonis protocol traffic.
+///
+/// [`NanonisClient`](crate::NanonisClient) and
+/// [`TCPLoggerStream`](crate::TCPLoggerStream) only read and write bytes
+/// through this trait, so any channel can stand in for the TCP socket: an
+/// SSH tunnel, a relay, a wrapper counting bytes for metrics, or a
+/// [`MemoryTransport`] in tests.
+///
+/// Read timeouts are the transport's responsibility. A read that times out
+/// should fail instead of blocking forever. A transport can fail a write
+/// with a specific [`NanonisError`] by wrapping it in [`io::Error::other`];
+/// the client returns it unchanged instead of as an I/O error.
+pub trait Transport: Read + Write + Send {
+    /// Re-open the channel after the connection was lost.
+    ///
+    /// Called by the client's [`ReconnectPolicy`](crate::ReconnectPolicy).
+    /// `timeout` bounds a single connection attempt. Transports that cannot
+    /// reconnect keep the default, which fails with
+    /// [`ErrorKind::Unsupported`].
+    fn reconnect(&mut self, timeout: Duration) -> io::Result<()> {
+        let _ = timeout;
+        Err(io::Error::new(
+            ErrorKind::Unsupported,
+            "transport does not support reconnecting",
+        ))
+    }
+
+    /// Address of the remote end, if the channel has one.
+    ///
+    /// Used to open the TCP Logger data stream on the same host as the
+    /// control connection. The default returns `None`.
+    fn remote_addr(&self) -> Option<SocketAddr> {
+        None
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn reconnect(&mut self, timeout: Duration) -> io::Result<()> {
+        (**self).reconnect(timeout)
+    }
+
+    fn remote_addr(&self) -> Option<SocketAddr> {
+        (**self).remote_addr()
+    }
+}
+
+/// Convert a failed write on a [`Transport`], unwrapping a [`NanonisError`]
+/// the transport wrapped in the I/O error.
+pub(crate) fn write_error(error: io::Error, context: &str) -> NanonisError {
+    if error
+        .get_ref()
+        .is_some_and(|inner| inner.is::<NanonisError>())
+    {
+        if let Some(Ok(inner)) = error.into_inner().map(|e| e.downcast::<NanonisError>()) {
+            return *inner;
+        }
+        unreachable!("checked for a wrapped NanonisError");
+    }
+    NanonisError::Io {
+        source: error,
+        context: context.to_string(),
+    }
+}
+
+/// A plain TCP socket. Cannot reconnect; use [`TcpTransport`] for that.
+impl Transport for TcpStream {
+    fn remote_addr(&self) -> Option<SocketAddr> {
+        self.peer_addr().ok()
+    }
+}
+
+/// TCP connection to a Nanonis server, the default transport.
+///
+/// Remembers the address and timeouts, so it can reconnect after the
+/// connection dropped.
+#[derive(Debug)]
+pub struct TcpTransport {
+    stream: TcpStream,
+    addr: SocketAddr,
+    config: ConnectionConfig,
+}
+
+impl TcpTransport {
+    /// Connect to `addr` and apply the socket timeouts of `config`.
+    pub fn connect(addr: SocketAddr, config: &ConnectionConfig) -> Result<Self, NanonisError> {
+        let stream = open(&addr, config, config.connect_timeout).map_err(|e| {
+            warn!("Failed to connect to {addr}: {e}");
+            if e.kind() == ErrorKind::TimedOut {
+                NanonisError::Timeout(format!("Connection to {addr} timed out"))
+            } else {
+                NanonisError::Io {
+                    source: e,
+                    context: format!("Failed to connect to {addr}"),
+                }
+            }
+        })?;
+
+        Ok(Self {
+            stream,
+            addr,
+            config: config.clone(),
+        })
+    }
+
+    /// Address of the Nanonis server.
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The underlying socket.
+    pub fn stream(&self) -> &TcpStream {
+        &self.stream
+    }
+}
+
+/// Open a socket and apply the read and write timeouts of `config`.
+fn open(
+    addr: &SocketAddr,
+    config: &ConnectionConfig,
+    connect_timeout: Duration,
+) -> io::Result<TcpStream> {
+    let stream = TcpStream::connect_timeout(addr, connect_timeout)?;
+    stream.set_read_timeout(Some(config.read_timeout))?;
+    stream.set_write_timeout(Some(config.write_timeout))?;
+    Ok(stream)
+}
+
+impl Read for TcpTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.read(buf)
+    }
+}
+
+impl Write for TcpTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+impl Transport for TcpTransport {
+    fn reconnect(&mut self, timeout: Duration) -> io::Result<()> {
+        debug!("Reconnecting to {}", self.addr);
+        self.stream = open(&self.addr, &self.config, timeout)?;
+        Ok(())
+    }
+
+    fn remote_addr(&self) -> Option<SocketAddr> {
+        Some(self.addr)
+    }
+}
+
+/// In-memory duplex channel, one end of a [`MemoryTransport::pair`].
+///
+/// Bytes written to one end are read from the other. Dropping an end closes
+/// the channel: reads on the other end return end-of-file and writes fail
+/// with [`ErrorKind::BrokenPipe`].
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MemoryTransport, NanonisClient};
+/// use std::io::{Read, Write};
+/// use std::thread;
+///
+/// let (client_end, mut server_end) = MemoryTransport::pair();
+///
+/// // Answer a single Bias.Get by hand
+/// let server = thread::spawn(move || {
+///     let mut request = [0u8; 40];
+///     server_end.read_exact(&mut request).unwrap();
+///
+///     let mut body = 1.25f32.to_be_bytes().to_vec();
+///     body.extend_from_slice(&[0; 8]); // error trailer: status 0, no message
+///     let mut reply = request.to_vec();
+///     reply[32..36].copy_from_slice(&(body.len() as u32).to_be_bytes());
+///     reply.extend_from_slice(&body);
+///     server_end.write_all(&reply).unwrap();
+/// });
+///
+/// let mut client = NanonisClient::from_transport(client_end);
+/// assert_eq!(client.bias_get()?, 1.25);
+/// server.join().unwrap();
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug)]
+pub struct MemoryTransport {
+    sender: Sender<Vec<u8>>,
+    receiver: Receiver<Vec<u8>>,
+    pending: Vec<u8>,
+    position: usize,
+    read_timeout: Option<Duration>,
+}
+
+impl MemoryTransport {
+    /// Create two connected ends.
+    pub fn pair() -> (Self, Self) {
+        let (a_sender, b_receiver) = mpsc::channel();
+        let (b_sender, a_receiver) = mpsc::channel();
+        (
+            Self::new(a_sender, a_receiver),
+            Self::new(b_sender, b_receiver),
+        )
+    }
+
+    fn new(sender: Sender<Vec<u8>>, receiver: Receiver<Vec<u8>>) -> Self {
+        Self {
+            sender,
+            receiver,
+            pending: Vec::new(),
+            position: 0,
+            read_timeout: None,
+        }
+    }
+
+    /// Fail reads with [`ErrorKind::TimedOut`] after waiting this long.
+    ///
+    /// `None`, the default, blocks until data arrives or the other end is
+    /// dropped.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) {
+        self.read_timeout = timeout;
+    }
+}
+
+impl Read for MemoryTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.position == self.pending.len() {
+            let chunk = match self.read_timeout {
+                Some(timeout) => match self.receiver.recv_timeout(timeout) {
+                    Ok(chunk) => chunk,
+                    Err(RecvTimeoutError::Timeout) => {
+                        return Err(io::Error::new(
+                            ErrorKind::TimedOut,
+                            "in-memory read timed out",
+                        ))
+                    }
+                    Err(RecvTimeoutError::Disconnected) => return Ok(0),
+                },
+                None => match self.receiver.recv() {
+                    Ok(chunk) => chunk,
+                    Err(_) => return Ok(0),
+                },
+            };
+            self.pending = chunk;
+            self.position = 0;
+        }
+
+        let n = buf.len().min(self.pending.len() - self.position);
+        buf[..n].copy_from_slice(&self.pending[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+impl Write for MemoryTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        self.sender
+            .send(buf.to_vec())
+            .map_err(|_| io::Error::new(ErrorKind::BrokenPipe, "in-memory peer closed"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Transport for MemoryTransport {}
@@ -1,5 +1,7 @@
 use crate::error::NanonisError;
+use crate::units::Meters;
 use serde::{Deserialize, Serialize};
+use std::ops::{Index, IndexMut};
 
 // ==================== Core Protocol Value Type ====================
 
@@ -21,7 +23,7 @@ pub enum NanonisValue {
     ArrayF32(Vec<f32>),
     ArrayF64(Vec<f64>),
     ArrayString(Vec<String>),
-    Array2DF32(Vec<Vec<f32>>),
+    Array2DF32(Array2D<f32>),
 }
 
 // From implementations for NanonisValue
@@ -165,6 +167,19 @@ impl TryFrom<NanonisValue> for Vec<f32> {
     }
 }
 
+impl TryFrom<NanonisValue> for Array2D<f32> {
+    type Error = NanonisError;
+
+    fn try_from(value: NanonisValue) -> Result<Self, Self::Error> {
+        match value {
+            NanonisValue::Array2DF32(v) => Ok(v),
+            _ => Err(NanonisError::Protocol(format!(
+                "Expected Array2D<f32>, got {value:?}"
+            ))),
+        }
+    }
+}
+
 impl TryFrom<NanonisValue> for Vec<String> {
     type Error = NanonisError;
 
@@ -287,7 +302,7 @@ impl NanonisValue {
         }
     }
 
-    pub fn as_f32_2d_array(&self) -> Result<&Vec<Vec<f32>>, NanonisError> {
+    pub fn as_f32_2d_array(&self) -> Result<&Array2D<f32>, NanonisError> {
         match self {
             NanonisValue::Array2DF32(arr) => Ok(arr),
             _ => Err(NanonisError::Protocol(format!(
@@ -297,6 +312,164 @@ impl NanonisValue {
     }
 }
 
+// ==================== 2D Array ====================
+
+/// Row-major 2D array stored in one contiguous buffer.
+///
+/// Returned for `2f` response values such as scan frames and spectroscopy
+/// data. Rows are borrowed as slices, so no per-row allocation is needed.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::Array2D;
+///
+/// let data = Array2D::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])?;
+/// assert_eq!(data.shape(), (2, 3));
+/// assert_eq!(data.row(1), Some(&[4.0, 5.0, 6.0][..]));
+/// assert_eq!(data[(0, 2)], 3.0);
+///
+/// let sums: Vec<f32> = data.iter_rows().map(|row| row.iter().sum()).collect();
+/// assert_eq!(sums, vec![6.0, 15.0]);
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Array2D<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Array2D<T> {
+    /// Wrap a row-major buffer of `rows * cols` elements.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, NanonisError> {
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(NanonisError::Protocol(format!(
+                "Array of {} elements does not match shape {rows}x{cols}",
+                data.len()
+            )));
+        }
+        Ok(Self { rows, cols, data })
+    }
+
+    /// Build from nested rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, NanonisError> {
+        let row_count = rows.len();
+        let cols = rows.first().map_or(0, Vec::len);
+        let mut data = Vec::with_capacity(row_count * cols);
+        for (index, row) in rows.into_iter().enumerate() {
+            if row.len() != cols {
+                return Err(NanonisError::Protocol(format!(
+                    "Row {index} has {} elements, expected {cols}",
+                    row.len()
+                )));
+            }
+            data.extend(row);
+        }
+        Ok(Self {
+            rows: row_count,
+            cols,
+            data,
+        })
+    }
+
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Total number of elements.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the array holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Element at `row`, `col`, or `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    /// Row `index` as a slice, or `None` if out of bounds.
+    pub fn row(&self, index: usize) -> Option<&[T]> {
+        (index < self.rows).then(|| &self.data[index * self.cols..(index + 1) * self.cols])
+    }
+
+    /// Row `index` as a mutable slice, or `None` if out of bounds.
+    pub fn row_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        (index < self.rows).then(|| &mut self.data[index * self.cols..(index + 1) * self.cols])
+    }
+
+    /// Iterate over the rows as slices.
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        (0..self.rows).map(move |row| &self.data[row * self.cols..(row + 1) * self.cols])
+    }
+
+    /// The row-major buffer.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// The row-major buffer, mutable.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Take the row-major buffer.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T: Clone> Array2D<T> {
+    /// Copy into nested rows.
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        self.iter_rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Array2D<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds for {}x{} array",
+            self.rows,
+            self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Array2D<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) out of bounds for {}x{} array",
+            self.rows,
+            self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
 // ==================== Domain-Specific Types ====================
 // Note: Domain-specific types have been moved to their respective modules:
 // - Motor types -> client::motor::types
@@ -316,7 +489,10 @@ pub struct Position {
 }
 
 impl Position {
-    pub fn new(x: f64, y: f64) -> Self {
-        Self { x, y }
+    pub fn new(x: impl Into<Meters>, y: impl Into<Meters>) -> Self {
+        Self {
+            x: x.into().value(),
+            y: y.into().value(),
+        }
     }
 }
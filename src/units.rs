@@ -0,0 +1,257 @@
+//! Physical quantities with their SI unit in the type.
+//!
+//! Setters for bias, Z controller, scan frame, piezo range, tip position,
+//! FolMe speed, high-speed sweep delays and motor parameters take
+//! `impl Into<Volts>`, `impl Into<Meters>` and so on. Raw `f32` and `f64`
+//! values do not convert, so a length in nanometers can not be sent as
+//! meters by accident; pass the SI value explicitly with `new` or pick a
+//! prefixed constructor. Configuration structs such as `ScanConfig` keep
+//! plain `f32` fields in SI base units.
+//!
+//! ```
+//! use nanonis_rs::{Meters, Volts};
+//!
+//! assert_eq!(Meters::nanometers(5.0), Meters::new(5e-9));
+//! assert_eq!(Volts::millivolts(-250.0).as_millivolts(), -250.0);
+//! assert_eq!(format!("{:.2}", Volts::millivolts(1500.0)), "1.50 V");
+//! ```
+//!
+//! A bare number is rejected where a unit is expected:
+//!
+//! ```compile_fail
+//! # fn main() -> Result<(), nanonis_rs::NanonisError> {
+//! # let mut client = nanonis_rs::NanonisClient::new("127.0.0.1", 6501)?;
+//! client.z_ctrl_z_pos_set(5.0)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::time::Duration;
+
+/// Define an SI newtype with prefixed constructors and getters.
+macro_rules! unit {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $symbol:literal,
+        $( $ctor:ident / $getter:ident = $num:literal / $den:literal ),* $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            #[doc = concat!("Value in ", $symbol, ".")]
+            pub const fn new(value: f64) -> Self {
+                Self(value)
+            }
+
+            #[doc = concat!("Value in ", $symbol, ".")]
+            pub fn value(self) -> f64 {
+                self.0
+            }
+
+            /// Value as `f32`, the precision of most protocol arguments.
+            pub fn as_f32(self) -> f32 {
+                self.0 as f32
+            }
+
+            $(
+                #[doc = concat!("From `", stringify!($ctor), "`.")]
+                pub fn $ctor(value: f64) -> Self {
+                    // Dividing by the exact power of ten rounds correctly
+                    Self(value * $num / $den)
+                }
+
+                #[doc = concat!("Value in `", stringify!($ctor), "`.")]
+                pub fn $getter(self) -> f64 {
+                    self.0 * $den / $num
+                }
+            )*
+        }
+
+        impl From<$name> for f32 {
+            fn from(value: $name) -> Self {
+                value.0 as f32
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)?;
+                f.write_str(concat!(" ", $symbol))
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+    };
+}
+
+unit! {
+    /// Electric potential in volts.
+    Volts, "V",
+    millivolts / as_millivolts = 1.0 / 1e3,
+    microvolts / as_microvolts = 1.0 / 1e6,
+}
+
+unit! {
+    /// Length in meters.
+    Meters, "m",
+    micrometers / as_micrometers = 1.0 / 1e6,
+    nanometers / as_nanometers = 1.0 / 1e9,
+    angstroms / as_angstroms = 1.0 / 1e10,
+    picometers / as_picometers = 1.0 / 1e12,
+}
+
+unit! {
+    /// Electric current in amperes.
+    Amperes, "A",
+    microamperes / as_microamperes = 1.0 / 1e6,
+    nanoamperes / as_nanoamperes = 1.0 / 1e9,
+    picoamperes / as_picoamperes = 1.0 / 1e12,
+}
+
+unit! {
+    /// Frequency in hertz.
+    Hertz, "Hz",
+    kilohertz / as_kilohertz = 1e3 / 1.0,
+    megahertz / as_megahertz = 1e6 / 1.0,
+}
+
+unit! {
+    /// Speed in meters per second.
+    MetersPerSecond, "m/s",
+    micrometers_per_second / as_micrometers_per_second = 1.0 / 1e6,
+    nanometers_per_second / as_nanometers_per_second = 1.0 / 1e9,
+}
+
+unit! {
+    /// Time in seconds.
+    Seconds, "s",
+    milliseconds / as_milliseconds = 1.0 / 1e3,
+    microseconds / as_microseconds = 1.0 / 1e6,
+}
+
+/// Setpoint or threshold of a feedback signal, in the unit of that signal.
+///
+/// Converts from the units feedback signals are measured in: [`Amperes`]
+/// for the tunneling current, [`Hertz`] for a frequency shift, [`Volts`]
+/// and [`Meters`] for amplitudes and other signals. A signal in another
+/// unit takes its value through [`Setpoint::new`]. Raw numbers, times and
+/// other quantities do not convert.
+///
+/// ```
+/// use nanonis_rs::{Amperes, Setpoint};
+///
+/// assert_eq!(Setpoint::from(Amperes::picoamperes(100.0)), Setpoint(1e-10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Setpoint(pub f64);
+
+impl Setpoint {
+    /// Value in the unit of the feedback signal.
+    pub const fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    /// Value in the unit of the feedback signal.
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Value as `f32`, the precision of most protocol arguments.
+    pub fn as_f32(self) -> f32 {
+        self.0 as f32
+    }
+}
+
+impl fmt::Display for Setpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Amperes> for Setpoint {
+    fn from(value: Amperes) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Hertz> for Setpoint {
+    fn from(value: Hertz) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Volts> for Setpoint {
+    fn from(value: Volts) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Meters> for Setpoint {
+    fn from(value: Meters) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Duration> for Seconds {
+    fn from(duration: Duration) -> Self {
+        Self(duration.as_secs_f64())
+    }
+}
+
+impl From<Seconds> for Duration {
+    /// Negative times saturate to zero.
+    fn from(seconds: Seconds) -> Self {
+        Duration::try_from_secs_f64(seconds.0.max(0.0)).unwrap_or(Duration::MAX)
+    }
+}
@@ -0,0 +1,580 @@
+use crate::client::shutdown::{ShutdownAction, ShutdownOutcome, ShutdownPolicy};
+use crate::client::signals::SignalRef;
+use crate::client::NanonisClient;
+use crate::error::NanonisError;
+use crate::tcplogger_reader::BackgroundReader;
+use crate::units::{Amperes, Meters};
+use log::{debug, error, info, warn};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+/// One action of the escape sequence run when a [`TipWatchdog`] trips.
+///
+/// The same type as [`ShutdownAction`], so an escape sequence and a
+/// shutdown policy can share their steps.
+pub type EscapeAction = ShutdownAction;
+
+/// Outcome of one escape action.
+pub type EscapeOutcome = ShutdownOutcome;
+
+/// Limits watched by a [`TipWatchdog`] and the response to a violation.
+///
+/// The defaults suit a typical STM setup but should be adapted to the
+/// experiment, in particular `max_current`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchdogConfig {
+    /// Current signal
+    pub current_signal: SignalRef,
+    /// Z signal
+    pub z_signal: SignalRef,
+    /// Largest absolute tunneling current; `None` disables the check
+    pub max_current: Option<Amperes>,
+    /// Trip when Z comes this close to either limit of `z_ctrl_limits_get`
+    /// while the Z-controller is on; `None` disables the check. The limits
+    /// are read again with every status poll
+    pub z_limit_margin: Option<Meters>,
+    /// Trip when the Z-controller switches off while the watchdog is armed
+    pub z_ctrl_off: bool,
+    /// Interval between polls of the instrument
+    pub poll_interval: Duration,
+    /// Trip with [`Violation::MonitorFailed`] after this many consecutive
+    /// failed polls; `None` only logs the failures
+    pub max_poll_failures: Option<u32>,
+    /// Actions run in order when a limit is violated
+    pub escape: Vec<EscapeAction>,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            current_signal: SignalRef::from("Current"),
+            z_signal: SignalRef::from("Z"),
+            max_current: Some(Amperes::nanoamperes(10.0)),
+            z_limit_margin: Some(Meters::nanometers(1.0)),
+            z_ctrl_off: true,
+            poll_interval: Duration::from_millis(50),
+            max_poll_failures: Some(5),
+            escape: ShutdownPolicy::default().actions,
+        }
+    }
+}
+
+/// Where a [`TipWatchdog`] reads current and Z from.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{Amperes, MemoryTransport, MockServer, ReaderConfig, SharedNanonisClient, TCPLoggerStream, Volts};
+/// use nanonis_rs::{EscapeAction, TipWatchdog, Violation, WatchdogConfig, WatchdogSource};
+/// use std::io::Write;
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let shared = SharedNanonisClient::new(server.client()?);
+/// let (stream_end, mut logger_end) = MemoryTransport::pair();
+/// let reader = TCPLoggerStream::from_transport(stream_end).spawn_reader(ReaderConfig::default());
+/// let watchdog = TipWatchdog::start(
+///     server.client()?,
+///     WatchdogConfig {
+///         escape: vec![EscapeAction::SetBias(Volts::new(0.1))],
+///         ..WatchdogConfig::default()
+///     },
+///     WatchdogSource::Frames { frames: reader, current: Some(0), z: None },
+/// )?;
+/// let incidents = watchdog.subscribe();
+///
+/// for (counter, current) in [(0u64, 1e-10f32), (1, 5e-8)] {
+///     let mut frame = Vec::new();
+///     frame.extend_from_slice(&1u32.to_be_bytes());
+///     frame.extend_from_slice(&1f32.to_be_bytes());
+///     frame.extend_from_slice(&counter.to_be_bytes());
+///     frame.extend_from_slice(&4u16.to_be_bytes());
+///     frame.extend_from_slice(&current.to_be_bytes());
+///     logger_end.write_all(&frame)?;
+/// }
+///
+/// let incident = incidents.recv_timeout(Duration::from_secs(5)).unwrap();
+/// assert!(matches!(incident.violation, Violation::CurrentSpike { .. }));
+/// assert_eq!(incident.current, Some(Amperes(5e-8f32 as f64)));
+/// assert_eq!(shared.call(|client| client.bias_get())?, 0.1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub enum WatchdogSource {
+    /// Poll `signals_vals_get` every poll interval
+    Poll,
+    /// Take the values from TCP logger frames read by
+    /// [`TCPLoggerStream::spawn_reader`](crate::TCPLoggerStream::spawn_reader).
+    /// The Z-controller status is still polled. Falls back to polling if
+    /// the reader ends.
+    Frames {
+        /// Reader of the TCP logger frames
+        frames: BackgroundReader,
+        /// Column of the current in the frame data
+        current: Option<usize>,
+        /// Column of Z in the frame data
+        z: Option<usize>,
+    },
+}
+
+/// A limit violated while the watchdog was armed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// The absolute current exceeded `max_current`
+    CurrentSpike {
+        /// Measured current
+        current: Amperes,
+        /// Configured limit
+        limit: Amperes,
+    },
+    /// Z came within the margin of a Z limit
+    ZAtLimit {
+        /// Measured Z
+        z: Meters,
+        /// The limit reached
+        limit: Meters,
+    },
+    /// The Z-controller switched off
+    ZCtrlOff,
+    /// Reading the instrument failed `failures` times in a row, so the
+    /// limits could not be checked
+    MonitorFailed {
+        /// Consecutive failed polls
+        failures: u32,
+        /// Error of the last failed poll
+        error: String,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::CurrentSpike { current, limit } => {
+                write!(f, "current {current} exceeds {limit}")
+            }
+            Violation::ZAtLimit { z, limit } => write!(f, "Z {z} at limit {limit}"),
+            Violation::ZCtrlOff => f.write_str("Z-controller switched off"),
+            Violation::MonitorFailed { failures, error } => {
+                write!(
+                    f,
+                    "reading the instrument failed {failures} times in a row: {error}"
+                )
+            }
+        }
+    }
+}
+
+/// Report of a tripped [`TipWatchdog`], sent to subscribers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incident {
+    /// When the violation was detected
+    pub time: SystemTime,
+    /// Limit that was violated
+    pub violation: Violation,
+    /// Last current reading, if any
+    pub current: Option<Amperes>,
+    /// Last Z reading, if any
+    pub z: Option<Meters>,
+    /// Escape actions in the order they ran
+    pub escape: Vec<EscapeOutcome>,
+}
+
+impl fmt::Display for Incident {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tip watchdog tripped: {}", self.violation)?;
+        if let Some(current) = self.current {
+            write!(f, "; current {current}")?;
+        }
+        if let Some(z) = self.z {
+            write!(f, "; Z {z}")?;
+        }
+        for outcome in &self.escape {
+            match &outcome.error {
+                None => write!(f, "; {}: ok", outcome.action)?,
+                Some(e) => write!(f, "; {}: failed: {e}", outcome.action)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// State shared between the handle and the monitor thread.
+#[derive(Default)]
+struct Shared {
+    stop: AtomicBool,
+    armed: AtomicBool,
+    subscribers: Mutex<Vec<mpsc::Sender<Incident>>>,
+    incidents: Mutex<Vec<Incident>>,
+}
+
+/// Background thread guarding the tip while an experiment runs.
+///
+/// Watches the current, Z against the Z-controller limits, and the
+/// Z-controller status, and runs the [escape sequence](WatchdogConfig::escape)
+/// on the first violation. The watchdog owns its connection, so connect it
+/// on a port the experiment does not use: its reads and the escape sequence
+/// never wait for calls of the experiment, such as a scan wait holding every
+/// connection of a [`SharedNanonisClient`](crate::SharedNanonisClient).
+/// The incident is logged at error level, kept
+/// for [`incidents`](Self::incidents) and sent to every
+/// [subscriber](Self::subscribe). The watchdog then disarms itself until
+/// [`arm`](Self::arm) is called.
+///
+/// [`disarm`](Self::disarm) suspends the checks, e.g. while switching the
+/// Z-controller off on purpose. The thread stops when the watchdog is
+/// dropped.
+///
+/// # Examples
+/// ```
+/// use nanonis_rs::{MockServer, SharedNanonisClient};
+/// use nanonis_rs::{TipWatchdog, Violation, WatchdogConfig, WatchdogSource};
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let shared = SharedNanonisClient::new(server.client()?);
+/// let watchdog = TipWatchdog::start(
+///     server.client()?,
+///     WatchdogConfig {
+///         poll_interval: Duration::from_millis(10),
+///         ..WatchdogConfig::default()
+///     },
+///     WatchdogSource::Poll,
+/// )?;
+/// let incidents = watchdog.subscribe();
+///
+/// // Something switches the feedback off behind the experiment's back
+/// shared.call(|client| client.z_ctrl_on_off_set(false))?;
+///
+/// let incident = incidents.recv_timeout(Duration::from_secs(5)).unwrap();
+/// assert_eq!(incident.violation, Violation::ZCtrlOff);
+/// assert!(incident.escape.iter().all(|outcome| outcome.error.is_none()));
+/// assert!(!watchdog.is_armed());
+///
+/// let commands: Vec<_> = server.requests().into_iter().map(|r| r.command).collect();
+/// assert!(commands.contains(&"ZCtrl.Withdraw".to_string()));
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+///
+/// Z limits changed by the experiment are picked up:
+/// ```
+/// use nanonis_rs::{Meters, MockServer, NanonisClient};
+/// use nanonis_rs::{TipWatchdog, Violation, WatchdogConfig, WatchdogSource};
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let mut client = server.client()?;
+/// let watchdog = TipWatchdog::start(
+///     server.client()?,
+///     WatchdogConfig {
+///         escape: Vec::new(),
+///         poll_interval: Duration::from_millis(10),
+///         ..WatchdogConfig::default()
+///     },
+///     WatchdogSource::Poll,
+/// )?;
+/// let incidents = watchdog.subscribe();
+///
+/// client.z_ctrl_limits_set(Meters::nanometers(0.5), Meters::nanometers(-100.0))?;
+///
+/// let incident = incidents.recv_timeout(Duration::from_secs(5)).unwrap();
+/// assert!(matches!(
+///     incident.violation,
+///     Violation::ZAtLimit { limit, .. } if (limit.0 - 0.5e-9).abs() < 1e-12
+/// ));
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+///
+/// So is an instrument that stops answering:
+/// ```
+/// use nanonis_rs::MockServer;
+/// use nanonis_rs::{TipWatchdog, Violation, WatchdogConfig, WatchdogSource};
+/// use std::time::Duration;
+///
+/// let server = MockServer::simulated()?;
+/// let watchdog = TipWatchdog::start(
+///     server.client()?,
+///     WatchdogConfig {
+///         escape: Vec::new(),
+///         poll_interval: Duration::from_millis(10),
+///         max_poll_failures: Some(3),
+///         ..WatchdogConfig::default()
+///     },
+///     WatchdogSource::Poll,
+/// )?;
+/// let incidents = watchdog.subscribe();
+///
+/// server.fail("ZCtrl.OnOffGet", -1, "Z-controller not responding");
+///
+/// let incident = incidents.recv_timeout(Duration::from_secs(5)).unwrap();
+/// assert!(matches!(incident.violation, Violation::MonitorFailed { failures: 3, .. }));
+/// # Ok::<(), nanonis_rs::NanonisError>(())
+/// ```
+pub struct TipWatchdog {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TipWatchdog {
+    /// Read the Z limits and the Z-controller status over `client`, and
+    /// start watching.
+    ///
+    /// `client` becomes the watchdog's own connection.
+    ///
+    /// # Errors
+    /// Returns `NanonisError` if the Z limits cannot be read.
+    pub fn start(
+        mut client: NanonisClient,
+        config: WatchdogConfig,
+        source: WatchdogSource,
+    ) -> Result<Self, NanonisError> {
+        let (high, low) = client.z_ctrl_limits_get()?;
+        let z_ctrl_on = client.z_ctrl_on_off_get()?;
+        let shared = Arc::new(Shared::default());
+        shared.armed.store(true, Ordering::SeqCst);
+
+        let mut monitor = Monitor {
+            client,
+            config,
+            shared: Arc::clone(&shared),
+            z_limits: (Meters::new(high.into()), Meters::new(low.into())),
+            z_ctrl_on: Some(z_ctrl_on),
+            last_poll: None,
+            failures: 0,
+            current: None,
+            z: None,
+        };
+        let thread = thread::Builder::new()
+            .name("nanonis-tip-watchdog".to_string())
+            .spawn(move || monitor.run(source))
+            .map_err(|e| NanonisError::Io {
+                source: e,
+                context: "Spawning tip watchdog".to_string(),
+            })?;
+        info!("Tip watchdog started");
+
+        Ok(Self {
+            shared,
+            thread: Some(thread),
+        })
+    }
+
+    /// Receive every future incident.
+    pub fn subscribe(&self) -> mpsc::Receiver<Incident> {
+        let (sender, receiver) = mpsc::channel();
+        lock(&self.shared.subscribers).push(sender);
+        receiver
+    }
+
+    /// Incidents so far, oldest first.
+    pub fn incidents(&self) -> Vec<Incident> {
+        lock(&self.shared.incidents).clone()
+    }
+
+    /// Whether violations currently trigger the escape sequence.
+    pub fn is_armed(&self) -> bool {
+        self.shared.armed.load(Ordering::SeqCst)
+    }
+
+    /// Resume the checks after [`disarm`](Self::disarm) or an incident.
+    pub fn arm(&self) {
+        self.shared.armed.store(true, Ordering::SeqCst);
+        self.wake();
+    }
+
+    /// Suspend the checks until [`arm`](Self::arm).
+    pub fn disarm(&self) {
+        self.shared.armed.store(false, Ordering::SeqCst);
+    }
+
+    fn wake(&self) {
+        if let Some(thread) = &self.thread {
+            thread.thread().unpark();
+        }
+    }
+}
+
+impl Drop for TipWatchdog {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::SeqCst);
+        self.wake();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The watchdog thread.
+struct Monitor {
+    client: NanonisClient,
+    config: WatchdogConfig,
+    shared: Arc<Shared>,
+    z_limits: (Meters, Meters),
+    /// Z-controller status of the last poll while armed
+    z_ctrl_on: Option<bool>,
+    last_poll: Option<Instant>,
+    /// Consecutive failed polls
+    failures: u32,
+    current: Option<Amperes>,
+    z: Option<Meters>,
+}
+
+impl Monitor {
+    fn run(&mut self, mut source: WatchdogSource) {
+        while !self.shared.stop.load(Ordering::SeqCst) {
+            if !self.shared.armed.load(Ordering::SeqCst) {
+                // Forget the status so a deliberate switch-off is not
+                // reported once re-armed
+                self.z_ctrl_on = None;
+                thread::park_timeout(self.config.poll_interval);
+                continue;
+            }
+
+            let frame_violation = match &mut source {
+                WatchdogSource::Poll => None,
+                WatchdogSource::Frames { frames, current, z } => {
+                    match frames.recv_timeout(self.config.poll_interval) {
+                        Ok(frame) => {
+                            let column = |index: &Option<usize>| {
+                                index.and_then(|i| frame.data.get(i).copied())
+                            };
+                            let (current, z) = (column(current), column(z));
+                            self.check_values(
+                                current.map(|i| Amperes::new(i.into())),
+                                z.map(|z| Meters::new(z.into())),
+                            )
+                        }
+                        Err(mpsc::RecvTimeoutError::Timeout) => None,
+                        Err(mpsc::RecvTimeoutError::Disconnected) => {
+                            warn!("Tip watchdog: frames stopped, polling instead");
+                            source = WatchdogSource::Poll;
+                            None
+                        }
+                    }
+                }
+            };
+
+            let polling = matches!(source, WatchdogSource::Poll);
+            // Frames may arrive much faster than the status needs polling
+            let poll_due = polling
+                || self
+                    .last_poll
+                    .is_none_or(|last| last.elapsed() >= self.config.poll_interval);
+            let violation = match frame_violation {
+                Some(violation) => Some(violation),
+                None if poll_due => match self.poll(polling) {
+                    Ok(violation) => {
+                        self.failures = 0;
+                        violation
+                    }
+                    Err(e) => {
+                        self.failures += 1;
+                        warn!("Tip watchdog: reading the instrument failed: {e}");
+                        self.config
+                            .max_poll_failures
+                            .is_some_and(|max| self.failures >= max)
+                            .then(|| Violation::MonitorFailed {
+                                failures: self.failures,
+                                error: e.to_string(),
+                            })
+                    }
+                },
+                None => None,
+            };
+            if let Some(violation) = violation {
+                // Another thread may have disarmed in the meantime
+                if self.shared.armed.swap(false, Ordering::SeqCst) {
+                    self.escape(violation);
+                }
+                continue;
+            }
+
+            if polling {
+                thread::park_timeout(self.config.poll_interval);
+            }
+        }
+        debug!("Tip watchdog stopped");
+    }
+
+    /// Read the Z-controller status and limits, and current and Z if
+    /// `values`.
+    fn poll(&mut self, values: bool) -> Result<Option<Violation>, NanonisError> {
+        self.last_poll = Some(Instant::now());
+        let on = self.client.z_ctrl_on_off_get()?;
+        // The limits may have been changed through z_ctrl_limits_set
+        let (high, low) = self.client.z_ctrl_limits_get()?;
+        self.z_limits = (Meters::new(high.into()), Meters::new(low.into()));
+        let readings = if values {
+            let signals = [
+                self.config.current_signal.clone(),
+                self.config.z_signal.clone(),
+            ];
+            Some(self.client.signals_vals_get(signals, false)?)
+        } else {
+            None
+        };
+
+        let was_on = self.z_ctrl_on.replace(on);
+        if self.config.z_ctrl_off && was_on == Some(true) && !on {
+            return Ok(Some(Violation::ZCtrlOff));
+        }
+        Ok(match readings {
+            Some(readings) => self.check_values(
+                readings.first().map(|&i| Amperes::new(i.into())),
+                readings.get(1).map(|&z| Meters::new(z.into())),
+            ),
+            None => None,
+        })
+    }
+
+    fn check_values(&mut self, current: Option<Amperes>, z: Option<Meters>) -> Option<Violation> {
+        self.current = current.or(self.current);
+        self.z = z.or(self.z);
+
+        if let (Some(current), Some(limit)) = (current, self.config.max_current) {
+            if current.0.abs() > limit.0 {
+                return Some(Violation::CurrentSpike { current, limit });
+            }
+        }
+        // Z only saturates meaningfully while the controller regulates
+        if let (Some(z), Some(margin), Some(true)) = (z, self.config.z_limit_margin, self.z_ctrl_on)
+        {
+            let (high, low) = self.z_limits;
+            if z.0 >= high.0 - margin.0 {
+                return Some(Violation::ZAtLimit { z, limit: high });
+            }
+            if z.0 <= low.0 + margin.0 {
+                return Some(Violation::ZAtLimit { z, limit: low });
+            }
+        }
+        None
+    }
+
+    fn escape(&mut self, violation: Violation) {
+        error!("Tip watchdog: {violation}, running escape sequence");
+        let policy = ShutdownPolicy {
+            actions: self.config.escape.clone(),
+        };
+        let escape = policy.run(&mut self.client);
+
+        let incident = Incident {
+            time: SystemTime::now(),
+            violation,
+            current: self.current,
+            z: self.z,
+            escape,
+        };
+        error!("{incident}");
+        self.z_ctrl_on = None;
+        self.failures = 0;
+
+        lock(&self.shared.incidents).push(incident.clone());
+        lock(&self.shared.subscribers).retain(|sender| sender.send(incident.clone()).is_ok());
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}